@@ -1,37 +1,120 @@
 use super::{
     config::{Config, ResourceType},
+    eviction::{EvictedResource, EvictionHook},
+    write_through::WriteThrough,
     InMemoryCache,
 };
+use std::sync::{mpsc::Sender, Arc};
+use twilight_model::gateway::event::Event;
 
 /// Builder to configure and construct an [`InMemoryCache`].
 #[derive(Debug, Default)]
-pub struct InMemoryCacheBuilder(Config);
+pub struct InMemoryCacheBuilder {
+    config: Config,
+    on_evict: Option<EvictionHook>,
+    write_through: Option<WriteThrough>,
+}
 
 impl InMemoryCacheBuilder {
     /// Creates a builder to configure and construct an [`InMemoryCache`].
     pub const fn new() -> Self {
-        Self(Config::new())
+        Self {
+            config: Config::new(),
+            on_evict: None,
+            write_through: None,
+        }
     }
 
     /// Consume the builder, returning a configured cache.
     pub fn build(self) -> InMemoryCache {
-        InMemoryCache::new_with_config(self.0)
+        InMemoryCache::new_with_config(self.config, self.on_evict, self.write_through)
     }
 
     /// Sets the list of resource types for the cache to handle.
     ///
     /// Defaults to all types.
     pub const fn resource_types(mut self, resource_types: ResourceType) -> Self {
-        self.0.resource_types = resource_types;
+        self.config.resource_types = resource_types;
 
         self
     }
 
     /// Sets the number of messages to cache per channel.
     ///
+    /// When a channel exceeds this number of cached messages, its oldest
+    /// messages by ID are evicted. A size of 0 disables caching messages.
+    ///
     /// Defaults to 100.
     pub const fn message_cache_size(mut self, message_cache_size: usize) -> Self {
-        self.0.message_cache_size = message_cache_size;
+        self.config.message_cache_size = message_cache_size;
+
+        self
+    }
+
+    /// Sets a hook to invoke when the cache evicts a resource.
+    ///
+    /// Resources are evicted when a channel exceeds the [message cache size]
+    /// or when the current user is removed from a guild, taking its channels
+    /// and their messages, emojis, members, presences, roles, and stickers
+    /// with it. Guilds that become unavailable keep their resources cached.
+    ///
+    /// The hook is invoked once the update evicting the resources is done and
+    /// the cache has released its internal locks, so it may access and update
+    /// the cache without deadlocking.
+    ///
+    /// # Examples
+    ///
+    /// Log the IDs of evicted messages:
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::{EvictedResource, InMemoryCache};
+    ///
+    /// let cache = InMemoryCache::builder()
+    ///     .on_evict(|resource| {
+    ///         if let EvictedResource::Message(message_id) = resource {
+    ///             println!("evicted message {message_id}");
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    ///
+    /// [message cache size]: Self::message_cache_size
+    pub fn on_evict(mut self, hook: impl Fn(EvictedResource) + Send + Sync + 'static) -> Self {
+        self.on_evict = Some(EvictionHook(Arc::new(hook)));
+
+        self
+    }
+
+    /// Sets a channel to write updates through to, such as to keep a secondary
+    /// cache shared with other processes up to date.
+    ///
+    /// Each update passed to [`InMemoryCache::update`] is sent to the channel
+    /// as an [`Event`] after it has been applied to the cache. Sending doesn't
+    /// block, so writing updates to a slow backend doesn't hold up the
+    /// processing of events; updates are dropped if the receiver hangs up.
+    ///
+    /// # Examples
+    ///
+    /// Write updates through to a secondary cache from a separate thread:
+    ///
+    /// ```
+    /// use std::{sync::mpsc, thread};
+    /// use twilight_cache_inmemory::{Cache, InMemoryCache};
+    ///
+    /// fn write_through(secondary: impl Cache + Send + 'static) -> InMemoryCache {
+    ///     let (tx, rx) = mpsc::channel();
+    ///
+    ///     thread::spawn(move || {
+    ///         for event in rx {
+    ///             secondary.update(&event);
+    ///         }
+    ///     });
+    ///
+    ///     InMemoryCache::builder().write_through(tx).build()
+    /// }
+    /// ```
+    pub fn write_through(mut self, sender: Sender<Event>) -> Self {
+        self.write_through = Some(WriteThrough::new(sender));
 
         self
     }
@@ -0,0 +1,238 @@
+//! Backend-agnostic interface over a cache of Discord data.
+
+use crate::{model::CachedMember, InMemoryCache};
+use twilight_model::{
+    channel::Channel,
+    gateway::event::Event,
+    guild::Role,
+    id::{
+        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+    user::{CurrentUser, User},
+};
+
+/// Update and query surface of a cache.
+///
+/// [`InMemoryCache`] is one implementation of this trait. Alternative
+/// backends, such as a cache shared between processes by an external store,
+/// may implement it to be used in place of the in-memory cache by code that
+/// is generic over the cache.
+///
+/// Getters return owned values because a backend may not be able to hand out
+/// references into its storage.
+///
+/// # Examples
+///
+/// Write a function that updates any cache with an event and then reads the
+/// current user from it:
+///
+/// ```
+/// use twilight_cache_inmemory::{Cache, InMemoryCache};
+/// use twilight_model::{gateway::event::Event, user::CurrentUser};
+///
+/// fn process(cache: &impl Cache, event: &Event) -> Option<CurrentUser> {
+///     cache.update(event);
+///
+///     cache.current_user()
+/// }
+///
+/// let cache = InMemoryCache::new();
+/// assert!(process(&cache, &Event::GatewayHeartbeatAck).is_none());
+/// ```
+pub trait Cache {
+    /// Update the cache with an event from the gateway.
+    fn update(&self, event: &Event);
+
+    /// Gets a channel by ID.
+    fn channel(&self, channel_id: Id<ChannelMarker>) -> Option<Channel>;
+
+    /// Gets the current user.
+    fn current_user(&self) -> Option<CurrentUser>;
+
+    /// Gets a member by guild ID and user ID.
+    ///
+    /// Backends may construct the returned [`CachedMember`] from a [`Member`]
+    /// through its [`From`] implementation.
+    ///
+    /// [`Member`]: twilight_model::guild::Member
+    fn member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> Option<CachedMember>;
+
+    /// Gets a role by ID.
+    fn role(&self, role_id: Id<RoleMarker>) -> Option<Role>;
+
+    /// Gets a user by ID.
+    fn user(&self, user_id: Id<UserMarker>) -> Option<User>;
+}
+
+impl Cache for InMemoryCache {
+    fn update(&self, event: &Event) {
+        InMemoryCache::update(self, event);
+    }
+
+    fn channel(&self, channel_id: Id<ChannelMarker>) -> Option<Channel> {
+        InMemoryCache::channel(self, channel_id).map(|channel| channel.value().clone())
+    }
+
+    fn current_user(&self) -> Option<CurrentUser> {
+        InMemoryCache::current_user(self)
+    }
+
+    fn member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> Option<CachedMember> {
+        InMemoryCache::member(self, guild_id, user_id).map(|member| member.value().clone())
+    }
+
+    fn role(&self, role_id: Id<RoleMarker>) -> Option<Role> {
+        InMemoryCache::role(self, role_id).map(|role| role.resource().clone())
+    }
+
+    fn user(&self, user_id: Id<UserMarker>) -> Option<User> {
+        InMemoryCache::user(self, user_id).map(|user| user.value().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cache;
+    use crate::{model::CachedMember, test, InMemoryCache};
+    use static_assertions::assert_obj_safe;
+    use std::{collections::HashMap, sync::Mutex};
+    use twilight_model::{
+        channel::Channel,
+        gateway::{
+            event::Event,
+            payload::incoming::{ChannelCreate, MemberAdd, RoleCreate, UserUpdate},
+        },
+        guild::Role,
+        id::{
+            marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+            Id,
+        },
+        user::{CurrentUser, User},
+    };
+
+    assert_obj_safe!(Cache);
+
+    /// Minimal backend storing resources in plain maps, standing in for an
+    /// external store.
+    #[derive(Default)]
+    struct MockCache {
+        channels: Mutex<HashMap<Id<ChannelMarker>, Channel>>,
+        current_user: Mutex<Option<CurrentUser>>,
+        #[allow(clippy::type_complexity)]
+        members: Mutex<HashMap<(Id<GuildMarker>, Id<UserMarker>), CachedMember>>,
+        roles: Mutex<HashMap<Id<RoleMarker>, Role>>,
+        users: Mutex<HashMap<Id<UserMarker>, User>>,
+    }
+
+    impl Cache for MockCache {
+        fn update(&self, event: &Event) {
+            match event {
+                Event::ChannelCreate(channel) => {
+                    self.channels
+                        .lock()
+                        .unwrap()
+                        .insert(channel.id, channel.0.clone());
+                }
+                Event::MemberAdd(member) => {
+                    self.users
+                        .lock()
+                        .unwrap()
+                        .insert(member.user.id, member.user.clone());
+                    self.members.lock().unwrap().insert(
+                        (member.guild_id, member.user.id),
+                        CachedMember::from(member.0.clone()),
+                    );
+                }
+                Event::Ready(ready) => {
+                    self.current_user
+                        .lock()
+                        .unwrap()
+                        .replace(ready.user.clone());
+                }
+                Event::RoleCreate(role) => {
+                    self.roles
+                        .lock()
+                        .unwrap()
+                        .insert(role.role.id, role.role.clone());
+                }
+                Event::UserUpdate(user) => {
+                    self.current_user.lock().unwrap().replace(user.0.clone());
+                }
+                _ => {}
+            }
+        }
+
+        fn channel(&self, channel_id: Id<ChannelMarker>) -> Option<Channel> {
+            self.channels.lock().unwrap().get(&channel_id).cloned()
+        }
+
+        fn current_user(&self) -> Option<CurrentUser> {
+            self.current_user.lock().unwrap().clone()
+        }
+
+        fn member(
+            &self,
+            guild_id: Id<GuildMarker>,
+            user_id: Id<UserMarker>,
+        ) -> Option<CachedMember> {
+            self.members
+                .lock()
+                .unwrap()
+                .get(&(guild_id, user_id))
+                .cloned()
+        }
+
+        fn role(&self, role_id: Id<RoleMarker>) -> Option<Role> {
+            self.roles.lock().unwrap().get(&role_id).cloned()
+        }
+
+        fn user(&self, user_id: Id<UserMarker>) -> Option<User> {
+            self.users.lock().unwrap().get(&user_id).cloned()
+        }
+    }
+
+    /// Drive a cache through the trait alone and assert it answers queries
+    /// the same way regardless of backend.
+    fn assert_backend(cache: &dyn Cache) {
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        let user_id = Id::new(5);
+        let role_id = Id::new(6);
+
+        assert!(cache.current_user().is_none());
+        assert!(cache.channel(channel_id).is_none());
+
+        cache.update(&Event::UserUpdate(UserUpdate(test::current_user(1))));
+        cache.update(&Event::ChannelCreate(Box::new(ChannelCreate(
+            channel.clone(),
+        ))));
+        cache.update(&Event::MemberAdd(Box::new(MemberAdd(test::member(
+            user_id, guild_id,
+        )))));
+        cache.update(&Event::RoleCreate(RoleCreate {
+            guild_id,
+            role: test::role(role_id),
+        }));
+
+        assert_eq!(Some(test::current_user(1)), cache.current_user());
+        assert_eq!(Some(channel), cache.channel(channel_id));
+        assert_eq!(Some(test::user(user_id)), cache.user(user_id));
+        assert_eq!(
+            Some(user_id),
+            cache
+                .member(guild_id, user_id)
+                .map(|member| member.user_id())
+        );
+        assert_eq!(Some(test::role(role_id)), cache.role(role_id));
+    }
+
+    #[test]
+    fn test_in_memory_cache() {
+        assert_backend(&InMemoryCache::new());
+    }
+
+    #[test]
+    fn test_mock_cache() {
+        assert_backend(&MockCache::default());
+    }
+}
@@ -61,6 +61,9 @@ impl Config {
 
     /// Returns an immutable reference to the message cache size.
     ///
+    /// This is the number of messages cached per channel, beyond which the
+    /// oldest messages by ID are evicted.
+    ///
     /// Defaults to 100.
     pub const fn message_cache_size(&self) -> usize {
         self.message_cache_size
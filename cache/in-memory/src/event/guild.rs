@@ -1,14 +1,17 @@
 use crate::{
     config::ResourceType,
     model::{CachedGuild, CachedPresence},
-    InMemoryCache, UpdateCache,
+    EvictedResource, InMemoryCache, RemovedGuild, UpdateCache,
 };
 use dashmap::DashMap;
 use std::{collections::HashSet, hash::Hash};
 use twilight_model::{
     gateway::payload::incoming::{GuildCreate, GuildDelete, GuildUpdate},
     guild::Guild,
-    id::{marker::GuildMarker, Id},
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
 };
 
 impl InMemoryCache {
@@ -63,6 +66,12 @@ impl InMemoryCache {
             widget_enabled,
         } = guild;
 
+        // A guild sent again, such as when it becomes available after an
+        // outage, replaces the resources previously cached for it.
+        if self.guilds.contains_key(&id) {
+            self.remove_guild_resources(id, None);
+        }
+
         // The map and set creation needs to occur first, so caching states and
         // objects always has a place to put them.
         if self.wants(ResourceType::CHANNEL) {
@@ -161,67 +170,167 @@ impl InMemoryCache {
         self.guilds.insert(guild.id(), guild);
     }
 
-    pub(crate) fn delete_guild(&self, id: Id<GuildMarker>, unavailable: bool) {
-        fn remove_ids<T: Eq + Hash, U>(
-            guild_map: &DashMap<Id<GuildMarker>, HashSet<T>>,
-            container: &DashMap<T, U>,
-            guild_id: Id<GuildMarker>,
-        ) {
-            if let Some((_, ids)) = guild_map.remove(&guild_id) {
-                for id in ids {
-                    container.remove(&id);
-                }
-            }
+    /// Apply a guild delete, returning the resources it evicted.
+    pub(crate) fn guild_delete(&self, guild_delete: &GuildDelete) -> Vec<EvictedResource> {
+        // An outage keeps the guild around, marked as unavailable, until it is
+        // sent again in a guild create.
+        if guild_delete.is_removed() {
+            return self.delete_guild(guild_delete.id, false);
+        }
+
+        if self.wants(ResourceType::GUILD) {
+            self.unavailable_guild(guild_delete.id);
         }
 
+        Vec::new()
+    }
+
+    /// Delete a guild, returning the resources it evicted.
+    pub(crate) fn delete_guild(
+        &self,
+        id: Id<GuildMarker>,
+        unavailable: bool,
+    ) -> Vec<EvictedResource> {
         if !self.wants(ResourceType::GUILD) {
-            return;
+            return Vec::new();
         }
 
+        // An unavailable guild keeps its resources cached until it is sent
+        // again, so only mark it as such.
         if unavailable {
             if let Some(mut guild) = self.guilds.get_mut(&id) {
                 guild.unavailable = true;
             }
-        } else {
-            self.guilds.remove(&id);
-        }
 
-        if self.wants(ResourceType::CHANNEL) {
-            remove_ids(&self.guild_channels, &self.channels, id);
+            return Vec::new();
         }
 
-        if self.wants(ResourceType::EMOJI) {
-            remove_ids(&self.guild_emojis, &self.emojis, id);
-        }
+        self.unavailable_guilds.remove(&id);
+        let guild = self.guilds.remove(&id).map(|(_, guild)| guild);
 
-        if self.wants(ResourceType::ROLE) {
-            remove_ids(&self.guild_roles, &self.roles, id);
-        }
+        self.remove_guild_resources(id, guild).evicted(id)
+    }
 
-        if self.wants(ResourceType::STICKER) {
-            remove_ids(&self.guild_stickers, &self.stickers, id);
+    /// Remove the resources of a guild and the indexes referencing them.
+    ///
+    /// The guild itself must already have been removed by the caller, if it
+    /// is to be removed.
+    pub(crate) fn remove_guild_resources(
+        &self,
+        guild_id: Id<GuildMarker>,
+        guild: Option<CachedGuild>,
+    ) -> RemovedGuild {
+        fn remove_ids<T: Copy + Eq + Hash, U>(
+            guild_map: &DashMap<Id<GuildMarker>, HashSet<T>>,
+            container: &DashMap<T, U>,
+            guild_id: Id<GuildMarker>,
+        ) -> Vec<U> {
+            guild_map
+                .remove(&guild_id)
+                .map(|(_, ids)| {
+                    ids.into_iter()
+                        .filter_map(|id| container.remove(&id).map(|(_, value)| value))
+                        .collect()
+                })
+                .unwrap_or_default()
         }
 
-        if self.wants(ResourceType::VOICE_STATE) {
-            // Clear out a guilds voice states when a guild leaves
-            self.voice_state_guilds.remove(&id);
+        fn remove_user_ids<T>(
+            guild_map: &DashMap<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
+            container: &DashMap<(Id<GuildMarker>, Id<UserMarker>), T>,
+            guild_id: Id<GuildMarker>,
+        ) -> Vec<T> {
+            guild_map
+                .remove(&guild_id)
+                .map(|(_, ids)| {
+                    ids.into_iter()
+                        .filter_map(|user_id| {
+                            container
+                                .remove(&(guild_id, user_id))
+                                .map(|(_, value)| value)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
         }
 
-        if self.wants(ResourceType::MEMBER) {
-            if let Some((_, ids)) = self.guild_members.remove(&id) {
-                for user_id in ids {
-                    self.members.remove(&(id, user_id));
-                }
+        let channels = remove_ids(&self.guild_channels, &self.channels, guild_id);
+        let emojis = remove_ids(&self.guild_emojis, &self.emojis, guild_id);
+        let roles = remove_ids(&self.guild_roles, &self.roles, guild_id);
+        let stage_instances =
+            remove_ids(&self.guild_stage_instances, &self.stage_instances, guild_id);
+        let stickers = remove_ids(&self.guild_stickers, &self.stickers, guild_id);
+
+        let integrations = self
+            .guild_integrations
+            .remove(&guild_id)
+            .map(|(_, ids)| {
+                ids.into_iter()
+                    .filter_map(|id| {
+                        self.integrations
+                            .remove(&(guild_id, id))
+                            .map(|(_, integration)| integration.value)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let messages = channels
+            .iter()
+            .filter_map(|channel| self.channel_messages.remove(&channel.id))
+            .flat_map(|(_, ids)| ids)
+            .filter_map(|id| self.messages.remove(&id).map(|(_, message)| message))
+            .collect();
+
+        let members = remove_user_ids(&self.guild_members, &self.members, guild_id);
+        let presences = remove_user_ids(&self.guild_presences, &self.presences, guild_id);
+        let voice_states = remove_user_ids(&self.voice_state_guilds, &self.voice_states, guild_id);
+
+        for voice_state in &voice_states {
+            let channel_id = voice_state.channel_id();
+
+            if let Some(mut channel_voice_states) = self.voice_state_channels.get_mut(&channel_id) {
+                channel_voice_states.remove(&(guild_id, voice_state.user_id()));
             }
+
+            self.voice_state_channels
+                .remove_if(&channel_id, |_, voice_states| voice_states.is_empty());
         }
 
-        if self.wants(ResourceType::PRESENCE) {
-            if let Some((_, ids)) = self.guild_presences.remove(&id) {
-                for user_id in ids {
-                    self.presences.remove(&(id, user_id));
-                }
+        // Avoid a deadlock by mutating the user's guilds, dropping the lock to
+        // the map, and then removing the user if they are in no guilds.
+        for member in &members {
+            let user_id = member.user_id();
+
+            if let Some(mut user_guilds) = self.user_guilds.get_mut(&user_id) {
+                user_guilds.remove(&guild_id);
+            }
+
+            if self
+                .user_guilds
+                .remove_if(&user_id, |_, user_guilds| user_guilds.is_empty())
+                .is_some()
+            {
+                self.users.remove(&user_id);
             }
         }
+
+        RemovedGuild {
+            channels,
+            emojis: emojis.into_iter().map(|emoji| emoji.value).collect(),
+            guild,
+            integrations,
+            members,
+            messages,
+            presences,
+            roles: roles.into_iter().map(|role| role.value).collect(),
+            stage_instances: stage_instances
+                .into_iter()
+                .map(|stage_instance| stage_instance.value)
+                .collect(),
+            stickers: stickers.into_iter().map(|sticker| sticker.value).collect(),
+            voice_states,
+        }
     }
 }
 
@@ -237,7 +346,7 @@ impl UpdateCache for GuildCreate {
 
 impl UpdateCache for GuildDelete {
     fn update(&self, cache: &InMemoryCache) {
-        cache.delete_guild(self.id, false);
+        cache.guild_delete(self);
     }
 }
 
@@ -280,7 +389,7 @@ impl UpdateCache for GuildUpdate {
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use std::{str::FromStr, sync::Arc, thread, time::Duration};
 
     use super::*;
     use crate::test;
@@ -289,7 +398,9 @@ mod tests {
             thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
             Channel, ChannelType,
         },
-        gateway::payload::incoming::{MemberAdd, MemberRemove, UnavailableGuild},
+        gateway::payload::incoming::{
+            MemberAdd, MemberRemove, MessageCreate, UnavailableGuild, VoiceStateUpdate,
+        },
         guild::{
             DefaultMessageNotificationLevel, ExplicitContentFilter, MfaLevel, NSFWLevel,
             PartialGuild, Permissions, PremiumTier, SystemChannelFlags, VerificationLevel,
@@ -306,8 +417,13 @@ mod tests {
 
         let channels = Vec::from([Channel {
             application_id: None,
+            available_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
             guild_id: None,
             icon: None,
             id: Id::new(111),
@@ -336,8 +452,13 @@ mod tests {
 
         let threads = Vec::from([Channel {
             application_id: None,
+            available_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
             guild_id: None,
             icon: None,
             id: Id::new(222),
@@ -537,7 +658,7 @@ mod tests {
         cache.update(&UnavailableGuild { id: guild_id });
 
         assert_eq!(
-            0,
+            1,
             cache
                 .guild_members(guild_id)
                 .map(|members| members.len())
@@ -556,4 +677,155 @@ mod tests {
         );
         assert!(!cache.guild(guild_id).unwrap().unavailable);
     }
+
+    #[test]
+    fn test_guild_delete_unavailable() {
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        let role_id = Id::new(3);
+        let user_id = Id::new(4);
+        let cache = InMemoryCache::new();
+
+        let mut guild = test::guild(guild_id, None);
+        guild.channels = Vec::from([channel]);
+        guild.members = Vec::from([test::member(user_id, guild_id)]);
+        guild.roles = Vec::from([test::role(role_id)]);
+        cache.update(&GuildCreate(guild));
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: true,
+        });
+
+        assert!(cache.guild(guild_id).unwrap().unavailable);
+        assert_eq!(1, cache.stats().guilds());
+        assert_eq!(1, cache.stats().unavailable_guilds());
+        assert!(cache.channel(channel_id).is_some());
+        assert!(cache.role(role_id).is_some());
+        assert!(cache.member(guild_id, user_id).is_some());
+        assert!(cache
+            .guild_channels(guild_id)
+            .map_or(false, |channels| channels.contains(&channel_id)));
+        assert!(cache
+            .guild_members(guild_id)
+            .map_or(false, |members| members.contains(&user_id)));
+    }
+
+    #[test]
+    fn test_guild_delete_removed() {
+        let guild_id = Id::new(1);
+        let cache = InMemoryCache::new();
+        cache.update(&GuildCreate(test::guild(guild_id, None)));
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: true,
+        });
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        });
+
+        assert!(cache.guild(guild_id).is_none());
+        assert_eq!(0, cache.stats().guilds());
+        assert_eq!(0, cache.stats().unavailable_guilds());
+    }
+
+    #[test]
+    fn test_remove_guild() {
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        let other_guild_id = Id::new(10);
+        let shared_user_id = Id::new(8);
+        let user_id = Id::new(4);
+        let role_id = Id::new(5);
+        let emoji_id = Id::new(6);
+        let message_id = Id::new(7);
+
+        let cache = InMemoryCache::new();
+        let mut guild = test::guild(guild_id, None);
+        guild.channels = Vec::from([channel]);
+        guild.emojis = Vec::from([test::emoji(emoji_id, None)]);
+        guild.members = Vec::from([
+            test::member(shared_user_id, guild_id),
+            test::member(user_id, guild_id),
+        ]);
+        guild.roles = Vec::from([test::role(role_id)]);
+        cache.update(&GuildCreate(guild));
+        cache.update(&GuildCreate(test::guild(other_guild_id, None)));
+        cache.update(&MemberAdd(test::member(shared_user_id, other_guild_id)));
+        // The message's author is cached as a member of the guild.
+        cache.update(&MessageCreate(test::message(message_id)));
+        cache.update(&VoiceStateUpdate(test::voice_state(
+            guild_id,
+            Some(channel_id),
+            user_id,
+        )));
+
+        let removed = cache.remove_guild(guild_id).expect("guild was cached");
+        assert_eq!(Some(guild_id), removed.guild.as_ref().map(CachedGuild::id));
+        assert_eq!(1, removed.channels.len());
+        assert_eq!(1, removed.emojis.len());
+        assert_eq!(3, removed.members.len());
+        assert_eq!(1, removed.messages.len());
+        assert_eq!(1, removed.roles.len());
+        assert_eq!(1, removed.voice_states.len());
+
+        assert!(cache.guild(guild_id).is_none());
+        assert!(cache.channel(channel_id).is_none());
+        assert!(cache.channel_messages(channel_id).is_none());
+        assert!(cache.emoji(emoji_id).is_none());
+        assert!(cache.guild_channels(guild_id).is_none());
+        assert!(cache.guild_emojis(guild_id).is_none());
+        assert!(cache.guild_members(guild_id).is_none());
+        assert!(cache.guild_roles(guild_id).is_none());
+        assert!(cache.guild_voice_states(guild_id).is_none());
+        assert!(cache.member(guild_id, user_id).is_none());
+        assert!(cache.message(message_id).is_none());
+        assert!(cache.role(role_id).is_none());
+        assert!(cache.voice_channel_states(channel_id).is_none());
+        assert!(cache.voice_state(user_id, guild_id).is_none());
+        assert!(cache.user(user_id).is_none());
+        assert!(cache.user_guilds.get(&user_id).is_none());
+
+        // Resources of other guilds are kept, including users that are still
+        // in them.
+        assert!(cache.guild(other_guild_id).is_some());
+        assert!(cache.member(other_guild_id, shared_user_id).is_some());
+        assert!(cache.user(shared_user_id).is_some());
+        assert_eq!(
+            [other_guild_id].as_slice(),
+            cache
+                .user_guilds
+                .get(&shared_user_id)
+                .unwrap()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+
+        assert!(cache.remove_guild(guild_id).is_none());
+    }
+
+    #[test]
+    fn test_remove_guild_blocks_updates() {
+        let guild_id = Id::new(1);
+        let user_id = Id::new(2);
+        let cache = Arc::new(InMemoryCache::new());
+        cache.update(&GuildCreate(test::guild(guild_id, None)));
+
+        // Hold the update lock as a guild removal does.
+        let update_lock = cache.update_lock.write().unwrap();
+
+        let handle = thread::spawn({
+            let cache = Arc::clone(&cache);
+
+            move || cache.update(&MemberAdd(test::member(user_id, guild_id)))
+        });
+
+        thread::sleep(Duration::from_millis(50));
+        assert!(cache.member(guild_id, user_id).is_none());
+
+        drop(update_lock);
+        handle.join().unwrap();
+        assert!(cache.member(guild_id, user_id).is_some());
+    }
 }
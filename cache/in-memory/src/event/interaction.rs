@@ -173,6 +173,8 @@ mod tests {
                                 mention_everyone: false,
                                 mention_roles: Vec::new(),
                                 mentions: Vec::new(),
+                                message_snapshots: Vec::new(),
+                                nonce: None,
                                 pinned: false,
                                 reactions: Vec::new(),
                                 reference: None,
@@ -230,6 +232,7 @@ mod tests {
                     }),
                     target_id: None,
                 },
+                entitlements: Vec::new(),
                 guild_id: Some(Id::new(3)),
                 guild_locale: None,
                 id: Id::new(4),
@@ -1,6 +1,6 @@
 use crate::{
     config::ResourceType,
-    model::{member::ComputedInteractionMemberFields, CachedMember},
+    model::{member::ComputedInteractionMemberFields, CachedMember, CachedPresence},
     InMemoryCache, UpdateCache,
 };
 use std::borrow::Cow;
@@ -121,6 +121,15 @@ impl UpdateCache for MemberAdd {
 
 impl UpdateCache for MemberChunk {
     fn update(&self, cache: &InMemoryCache) {
+        // Presences of large guilds aren't sent in their guild create, but in
+        // the member chunks of requests for members with their presences.
+        if cache.wants(ResourceType::PRESENCE) {
+            cache.cache_presences(
+                self.guild_id,
+                self.presences.iter().cloned().map(CachedPresence::from),
+            );
+        }
+
         if !cache.wants(ResourceType::MEMBER) {
             return;
         }
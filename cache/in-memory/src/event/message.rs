@@ -1,43 +1,78 @@
-use crate::{config::ResourceType, model::CachedMessage, InMemoryCache, UpdateCache};
+use crate::{
+    config::ResourceType, model::CachedMessage, EvictedResource, InMemoryCache, UpdateCache,
+};
 use std::borrow::Cow;
 use twilight_model::gateway::payload::incoming::{
     MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
 };
 
-impl UpdateCache for MessageCreate {
-    fn update(&self, cache: &InMemoryCache) {
-        if cache.wants(ResourceType::USER) {
-            cache.cache_user(Cow::Borrowed(&self.author), self.guild_id);
+impl InMemoryCache {
+    /// Cache a created message, returning the messages it evicted.
+    pub(crate) fn message_create(&self, message: &MessageCreate) -> Vec<EvictedResource> {
+        if self.wants(ResourceType::USER) {
+            self.cache_user(Cow::Borrowed(&message.author), message.guild_id);
         }
 
         if let (Some(member), Some(guild_id), true) = (
-            &self.member,
-            self.guild_id,
-            cache.wants(ResourceType::MEMBER),
+            &message.member,
+            message.guild_id,
+            self.wants(ResourceType::MEMBER),
         ) {
-            cache.cache_borrowed_partial_member(guild_id, member, self.author.id)
+            self.cache_borrowed_partial_member(guild_id, member, message.author.id)
         }
 
-        if !cache.wants(ResourceType::MESSAGE) {
-            return;
+        if !self.wants(ResourceType::MESSAGE) {
+            return Vec::new();
         }
 
-        let mut channel_messages = cache.channel_messages.entry(self.0.channel_id).or_default();
+        let mut channel_messages = self
+            .channel_messages
+            .entry(message.0.channel_id)
+            .or_default();
 
-        // If the channel has more messages than the cache size the user has
-        // requested then we pop a message ID out. Once we have the popped ID we
-        // can remove it from the message cache. This prevents the cache from
-        // filling up with old messages that aren't in any channel cache.
-        if channel_messages.len() >= cache.config.message_cache_size() {
+        // Message IDs are kept in descending order so that the oldest messages
+        // by snowflake, which may not be the ones received first, are evicted
+        // when the channel exceeds the cache size the user has requested.
+        if !channel_messages.contains(&message.0.id) {
+            let index = channel_messages
+                .iter()
+                .position(|id| *id < message.0.id)
+                .unwrap_or(channel_messages.len());
+
+            // The message is older than all cached messages of a full channel,
+            // so caching it would only evict it again.
+            if index >= self.config.message_cache_size() {
+                return Vec::new();
+            }
+
+            channel_messages.insert(index, message.0.id);
+        }
+
+        // Once we have the popped IDs we can remove them from the message
+        // cache. This prevents the cache from filling up with old messages that
+        // aren't in any channel cache. Only the messages themselves are
+        // removed, the channel and its pin state are kept.
+        let mut evicted = Vec::new();
+
+        while channel_messages.len() > self.config.message_cache_size() {
             if let Some(popped_id) = channel_messages.pop_back() {
-                cache.messages.remove(&popped_id);
+                self.messages.remove(&popped_id);
+                evicted.push(EvictedResource::Message(popped_id));
             }
         }
 
-        channel_messages.push_front(self.0.id);
-        cache
-            .messages
-            .insert(self.0.id, CachedMessage::from(self.0.clone()));
+        self.messages
+            .insert(message.0.id, CachedMessage::from(message.0.clone()));
+
+        drop(channel_messages);
+
+        evicted
+    }
+}
+
+impl UpdateCache for MessageCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        cache.message_create(self);
     }
 }
 
@@ -131,6 +166,7 @@ impl UpdateCache for MessageUpdate {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test;
     use twilight_model::{
         channel::message::{Message, MessageFlags, MessageType},
         guild::PartialMember,
@@ -196,6 +232,8 @@ mod tests {
             mention_everyone: false,
             mention_roles: Vec::new(),
             mentions: Vec::new(),
+            message_snapshots: Vec::new(),
+            nonce: None,
             pinned: false,
             reactions: Vec::new(),
             reference: None,
@@ -228,11 +266,76 @@ mod tests {
             .channel_messages(Id::new(2))
             .expect("channel is in cache");
 
-        // messages are iterated over in descending order from insertion
+        // messages are iterated over in descending order by ID
         assert_eq!(Some(5), iter.next().map(Id::get));
         assert_eq!(Some(4), iter.next().map(Id::get));
         assert!(iter.next().is_none());
 
         Ok(())
     }
+
+    #[test]
+    fn test_message_create_evicts_oldest() {
+        let cache = InMemoryCache::builder().message_cache_size(5).build();
+        let (guild_id, channel_id, mut channel) = test::guild_channel_text();
+        let pin_timestamp = Timestamp::from_secs(1_632_072_645).expect("non zero");
+        channel.last_pin_timestamp = Some(pin_timestamp);
+        cache.cache_channel(channel);
+
+        let mut other_channel_message = test::message(Id::new(1));
+        other_channel_message.channel_id = Id::new(100);
+        cache.update(&MessageCreate(other_channel_message));
+
+        // Messages are received out of order, the oldest of which is pinned.
+        for id in [16, 12, 14, 11, 18, 19, 13, 20, 17, 15] {
+            let mut message = test::message(Id::new(id));
+            message.pinned = id == 11;
+            cache.update(&MessageCreate(message));
+        }
+
+        let ids = cache
+            .channel_messages(channel_id)
+            .expect("channel is in cache")
+            .map(Id::get)
+            .collect::<Vec<_>>();
+        assert_eq!([20, 19, 18, 17, 16].as_slice(), ids);
+
+        for id in 11..=15 {
+            assert!(cache.message(Id::new(id)).is_none());
+        }
+
+        for id in 16..=20 {
+            assert!(cache.message(Id::new(id)).is_some());
+        }
+
+        // A message older than all cached messages of a full channel isn't
+        // cached.
+        cache.update(&MessageCreate(test::message(Id::new(10))));
+        assert!(cache.message(Id::new(10)).is_none());
+        assert_eq!(Some(5), cache.stats().channel_messages(channel_id));
+
+        // Eviction doesn't affect the channel or other channels.
+        assert_eq!(
+            Some(pin_timestamp),
+            cache.channel(channel_id).unwrap().last_pin_timestamp
+        );
+        assert_eq!(Some(guild_id), cache.channel(channel_id).unwrap().guild_id);
+        assert!(cache.message(Id::new(1)).is_some());
+        assert_eq!(Some(1), cache.stats().channel_messages(Id::new(100)));
+    }
+
+    #[test]
+    fn test_message_create_duplicate() {
+        let cache = InMemoryCache::builder().message_cache_size(2).build();
+        cache.update(&MessageCreate(test::message(Id::new(4))));
+        cache.update(&MessageCreate(test::message(Id::new(4))));
+        cache.update(&MessageCreate(test::message(Id::new(5))));
+
+        let ids = cache
+            .channel_messages(Id::new(2))
+            .expect("channel is in cache")
+            .map(Id::get)
+            .collect::<Vec<_>>();
+        assert_eq!([5, 4].as_slice(), ids);
+    }
 }
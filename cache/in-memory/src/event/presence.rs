@@ -42,11 +42,29 @@ impl UpdateCache for PresenceUpdate {
 mod tests {
     use super::*;
     use crate::test;
-    use twilight_model::gateway::{
-        event::Event,
-        presence::{ClientStatus, Presence, Status, UserOrId},
+    use twilight_model::{
+        gateway::{
+            event::Event,
+            payload::incoming::{GuildCreate, MemberChunk},
+            presence::{ClientStatus, Presence, Status, UserOrId},
+        },
+        id::marker::UserMarker,
     };
 
+    fn presence(guild_id: Id<GuildMarker>, user_id: Id<UserMarker>, status: Status) -> Presence {
+        Presence {
+            activities: Vec::new(),
+            client_status: ClientStatus {
+                desktop: Some(status),
+                mobile: None,
+                web: None,
+            },
+            guild_id,
+            status,
+            user: UserOrId::UserId { id: user_id },
+        }
+    }
+
     #[test]
     fn test_presence_update() {
         let cache = InMemoryCache::new();
@@ -75,4 +93,50 @@ mod tests {
             .unwrap()
             .contains(&user_id));
     }
+
+    /// Test that presences of a guild create, presence updates after the guild
+    /// create, and presences of member chunks of large guilds are all
+    /// associated with the guild.
+    #[test]
+    fn test_presences_after_guild_create() {
+        let cache = InMemoryCache::new();
+        let guild_id = Id::new(1);
+
+        let mut guild = test::guild(guild_id, None);
+        guild.large = true;
+        guild.presences = Vec::from([presence(guild_id, Id::new(2), Status::Online)]);
+        cache.update(&GuildCreate(guild));
+
+        cache.update(&PresenceUpdate(presence(
+            guild_id,
+            Id::new(2),
+            Status::Idle,
+        )));
+        cache.update(&PresenceUpdate(presence(
+            guild_id,
+            Id::new(3),
+            Status::Online,
+        )));
+        cache.update(&MemberChunk {
+            chunk_count: 1,
+            chunk_index: 0,
+            guild_id,
+            members: Vec::new(),
+            nonce: None,
+            not_found: Vec::new(),
+            presences: Vec::from([presence(guild_id, Id::new(4), Status::DoNotDisturb)]),
+        });
+
+        let user_ids = cache.guild_presences(guild_id).unwrap();
+        assert_eq!(3, user_ids.len());
+
+        for (user_id, status) in [
+            (Id::new(2), Status::Idle),
+            (Id::new(3), Status::Online),
+            (Id::new(4), Status::DoNotDisturb),
+        ] {
+            assert!(user_ids.contains(&user_id));
+            assert_eq!(status, cache.presence(guild_id, user_id).unwrap().status());
+        }
+    }
 }
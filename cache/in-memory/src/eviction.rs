@@ -0,0 +1,212 @@
+use crate::config::ResourceType;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+use twilight_model::id::{
+    marker::{
+        ChannelMarker, EmojiMarker, GuildMarker, MessageMarker, RoleMarker, StickerMarker,
+        UserMarker,
+    },
+    Id,
+};
+
+/// Resource evicted from the cache.
+///
+/// Resources are evicted when the cache drops them on its own accord rather
+/// than in response to a deletion event for the resource itself, such as when
+/// the per-channel message limit is exceeded or when the current user is
+/// removed from a guild. Guilds that become unavailable keep their resources
+/// cached.
+///
+/// Refer to [`InMemoryCacheBuilder::on_evict`] for registering a hook that is
+/// invoked with evicted resources.
+///
+/// [`InMemoryCacheBuilder::on_evict`]: crate::InMemoryCacheBuilder::on_evict
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EvictedResource {
+    /// Channel was evicted.
+    Channel(Id<ChannelMarker>),
+    /// Emoji was evicted.
+    Emoji(Id<EmojiMarker>),
+    /// Guild was evicted.
+    Guild(Id<GuildMarker>),
+    /// Member was evicted.
+    Member {
+        /// ID of the guild the member was in.
+        guild_id: Id<GuildMarker>,
+        /// ID of the member's user.
+        user_id: Id<UserMarker>,
+    },
+    /// Message was evicted.
+    Message(Id<MessageMarker>),
+    /// Presence was evicted.
+    Presence {
+        /// ID of the guild the presence was in.
+        guild_id: Id<GuildMarker>,
+        /// ID of the presence's user.
+        user_id: Id<UserMarker>,
+    },
+    /// Role was evicted.
+    Role(Id<RoleMarker>),
+    /// Sticker was evicted.
+    Sticker(Id<StickerMarker>),
+}
+
+impl EvictedResource {
+    /// Type of the evicted resource.
+    pub const fn kind(self) -> ResourceType {
+        match self {
+            Self::Channel(_) => ResourceType::CHANNEL,
+            Self::Emoji(_) => ResourceType::EMOJI,
+            Self::Guild(_) => ResourceType::GUILD,
+            Self::Member { .. } => ResourceType::MEMBER,
+            Self::Message(_) => ResourceType::MESSAGE,
+            Self::Presence { .. } => ResourceType::PRESENCE,
+            Self::Role(_) => ResourceType::ROLE,
+            Self::Sticker(_) => ResourceType::STICKER,
+        }
+    }
+}
+
+/// Hook invoked with resources evicted from the cache.
+#[derive(Clone)]
+pub(crate) struct EvictionHook(pub(crate) Arc<dyn Fn(EvictedResource) + Send + Sync>);
+
+impl Debug for EvictionHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("EvictionHook").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EvictedResource;
+    use crate::{config::ResourceType, test, InMemoryCache, UpdateCache};
+    use static_assertions::assert_impl_all;
+    use std::{
+        collections::HashSet,
+        fmt::Debug,
+        hash::Hash,
+        sync::{Arc, Mutex, Weak},
+    };
+    use twilight_model::{
+        gateway::payload::incoming::{GuildCreate, GuildDelete, MessageCreate},
+        id::Id,
+    };
+
+    assert_impl_all!(EvictedResource: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(
+            ResourceType::MEMBER,
+            EvictedResource::Member {
+                guild_id: Id::new(1),
+                user_id: Id::new(2),
+            }
+            .kind()
+        );
+        assert_eq!(
+            ResourceType::MESSAGE,
+            EvictedResource::Message(Id::new(1)).kind()
+        );
+    }
+
+    #[test]
+    fn test_on_evict_message_cache_size() {
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let hook_evicted = Arc::clone(&evicted);
+        let cache = InMemoryCache::builder()
+            .message_cache_size(1)
+            .on_evict(move |resource| hook_evicted.lock().unwrap().push(resource))
+            .build();
+
+        cache.update(&MessageCreate(test::message(Id::new(4))));
+        assert!(evicted.lock().unwrap().is_empty());
+
+        cache.update(&MessageCreate(test::message(Id::new(5))));
+        assert_eq!(
+            [EvictedResource::Message(Id::new(4))].as_slice(),
+            evicted.lock().unwrap().as_slice()
+        );
+        assert!(cache.message(Id::new(4)).is_none());
+        assert!(cache.message(Id::new(5)).is_some());
+    }
+
+    /// Test that the hook is only passed the resources evicted by the update
+    /// it is invoked for.
+    #[test]
+    fn test_on_evict_per_update() {
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let hook_evicted = Arc::clone(&evicted);
+        let cache = InMemoryCache::builder()
+            .message_cache_size(1)
+            .on_evict(move |resource| hook_evicted.lock().unwrap().push(resource))
+            .build();
+
+        // Updating with the event directly bypasses the hook.
+        MessageCreate(test::message(Id::new(4))).update(&cache);
+        MessageCreate(test::message(Id::new(5))).update(&cache);
+        assert!(evicted.lock().unwrap().is_empty());
+
+        cache.update(&MessageCreate(test::message(Id::new(6))));
+        assert_eq!(
+            [EvictedResource::Message(Id::new(5))].as_slice(),
+            evicted.lock().unwrap().as_slice()
+        );
+    }
+
+    #[test]
+    fn test_on_evict_guild_delete() {
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        let role_id = Id::new(3);
+        let user_id = Id::new(4);
+
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let hook_evicted = Arc::clone(&evicted);
+
+        // The hook reads from the cache to ensure it is invoked without any
+        // locks into the cache being held.
+        let cache = Arc::new_cyclic(|cache: &Weak<InMemoryCache>| {
+            let cache = Weak::clone(cache);
+
+            InMemoryCache::builder()
+                .on_evict(move |resource| {
+                    let cache = cache.upgrade().unwrap();
+                    assert!(cache.guild(guild_id).is_none());
+                    assert!(cache.channel(channel_id).is_none());
+
+                    hook_evicted.lock().unwrap().push(resource);
+                })
+                .build()
+        });
+
+        let mut guild = test::guild(guild_id, None);
+        guild.channels = Vec::from([channel]);
+        guild.members = Vec::from([test::member(user_id, guild_id)]);
+        guild.roles = Vec::from([test::role(role_id)]);
+        cache.update(&GuildCreate(guild));
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        });
+
+        let evicted = evicted
+            .lock()
+            .unwrap()
+            .iter()
+            .copied()
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            HashSet::from([
+                EvictedResource::Guild(guild_id),
+                EvictedResource::Channel(channel_id),
+                EvictedResource::Member { guild_id, user_id },
+                EvictedResource::Role(role_id),
+            ]),
+            evicted
+        );
+    }
+}
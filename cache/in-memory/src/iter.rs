@@ -17,13 +17,13 @@ use crate::{
         CachedEmoji, CachedGuild, CachedMember, CachedMessage, CachedPresence, CachedSticker,
         CachedVoiceState,
     },
-    GuildResource, InMemoryCache,
+    GuildResource, InMemoryCache, Reference,
 };
 use dashmap::{
     iter::Iter,
     mapref::{multiple::RefMulti, one::Ref},
 };
-use std::{collections::VecDeque, hash::Hash, ops::Deref};
+use std::{collections::VecDeque, hash::Hash, ops::Deref, vec::IntoIter};
 use twilight_model::{
     channel::{Channel, StageInstance},
     guild::{GuildIntegration, Role},
@@ -105,9 +105,11 @@ impl<K: Eq + Hash, V> Deref for IterReference<'_, K, V> {
 ///
 /// Resource iterators over the entire cache are inefficient when the goal is to
 /// iterate over a resource in a specific guild. For example, when performing a
-/// task such as iterating over the members of a specific guild, retrieving the
-/// list of members via [`InMemoryCache::guild_members`] and then calling
-/// [`InMemoryCache::member`] for each item is more efficient. That might look
+/// task such as iterating over the members of a specific guild,
+/// [`InMemoryCacheIter::guild_members`] only visits the members of that guild.
+/// For other resources, retrieving the list of IDs in a guild and then looking
+/// up each item is more efficient. For members, doing this manually via
+/// [`InMemoryCache::guild_members`] and [`InMemoryCache::member`] might look
 /// like:
 ///
 /// ```no_run
@@ -161,6 +163,24 @@ impl<'a> InMemoryCacheIter<'a> {
         ResourceIter::new(self.0.guilds.iter())
     }
 
+    /// Create an iterator over the members of a guild in the cache.
+    ///
+    /// Unlike [`InMemoryCache::guild_members`], which returns a reference to
+    /// the set of the guild's member IDs and holds a lock on it while the
+    /// reference is alive, this yields the members themselves and doesn't hold
+    /// a lock in between items. Refer to [`GuildMembers`] for the consistency
+    /// guarantees of the iterator.
+    pub fn guild_members(&self, guild_id: Id<GuildMarker>) -> GuildMembers<'a> {
+        let user_ids = self
+            .0
+            .guild_members
+            .get(&guild_id)
+            .map(|user_ids| user_ids.iter().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        GuildMembers::new(self.0, guild_id, user_ids)
+    }
+
     /// Create an iterator over the integrations in the cache.
     pub fn integrations(
         &self,
@@ -316,18 +336,88 @@ impl<'a> Iterator for ChannelMessages<'a> {
     }
 }
 
+/// Iterator over the cached members of a guild.
+///
+/// The iteration order is unspecified.
+///
+/// The IDs of the guild's members are collected when the iterator is created,
+/// while each member is retrieved only when it is reached. Members removed from
+/// the cache in the meantime are skipped, and members added after the iterator
+/// was created are not returned. No lock is held in between items, so the
+/// cache may be updated while iterating.
+///
+/// # Examples
+///
+/// Count the pending members of a guild:
+///
+/// ```no_run
+/// use twilight_cache_inmemory::InMemoryCache;
+/// use twilight_model::id::Id;
+///
+/// let cache = InMemoryCache::new();
+///
+/// // later in the application...
+/// let count = cache
+///     .iter()
+///     .guild_members(Id::new(1))
+///     .filter(|member| member.pending())
+///     .count();
+///
+/// println!("pending members: {count}");
+/// ```
+pub struct GuildMembers<'a> {
+    cache: &'a InMemoryCache,
+    guild_id: Id<GuildMarker>,
+    user_ids: IntoIter<Id<UserMarker>>,
+}
+
+impl<'a> GuildMembers<'a> {
+    /// Create a new iterator over the members of a guild.
+    fn new(
+        cache: &'a InMemoryCache,
+        guild_id: Id<GuildMarker>,
+        user_ids: Vec<Id<UserMarker>>,
+    ) -> Self {
+        Self {
+            cache,
+            guild_id,
+            user_ids: user_ids.into_iter(),
+        }
+    }
+}
+
+impl<'a> Iterator for GuildMembers<'a> {
+    type Item = Reference<'a, (Id<GuildMarker>, Id<UserMarker>), CachedMember>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for user_id in self.user_ids.by_ref() {
+            if let Some(member) = self.cache.members.get(&(self.guild_id, user_id)) {
+                return Some(Reference::new(member));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.user_ids.size_hint().1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{ChannelMessages, InMemoryCacheIter, IterReference, ResourceIter};
+    use super::{ChannelMessages, GuildMembers, InMemoryCacheIter, IterReference, ResourceIter};
     use crate::{test, InMemoryCache};
     use static_assertions::assert_impl_all;
     use std::{borrow::Cow, fmt::Debug};
     use twilight_model::{
+        gateway::payload::incoming::{MemberAdd, MemberRemove},
         id::{marker::UserMarker, Id},
         user::User,
     };
 
     assert_impl_all!(ChannelMessages<'_>: Iterator, Send, Sync);
+    assert_impl_all!(GuildMembers<'_>: Iterator, Send, Sync);
     assert_impl_all!(InMemoryCacheIter<'_>: Debug, Send, Sync);
     assert_impl_all!(IterReference<'_, Id<UserMarker>, User>: Send, Sync);
     assert_impl_all!(ResourceIter<'_, Id<UserMarker>, User>: Iterator, Send, Sync);
@@ -353,4 +443,39 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_guild_members() {
+        let guild_id = Id::new(1);
+        let other_guild_id = Id::new(2);
+        let cache = InMemoryCache::new();
+
+        for user_id in [Id::new(3), Id::new(4), Id::new(5)] {
+            cache.update(&MemberAdd(test::member(user_id, guild_id)));
+        }
+
+        cache.update(&MemberAdd(test::member(Id::new(6), other_guild_id)));
+
+        let mut actual = cache
+            .iter()
+            .guild_members(guild_id)
+            .map(|member| member.user_id())
+            .collect::<Vec<_>>();
+        actual.sort_unstable();
+
+        assert_eq!(vec![Id::new(3), Id::new(4), Id::new(5)], actual);
+
+        // Members removed after the iterator is created are skipped.
+        let members = cache.iter().guild_members(guild_id);
+        cache.update(&MemberRemove {
+            guild_id,
+            user: test::user(Id::new(4)),
+        });
+
+        let mut actual = members.map(|member| member.user_id()).collect::<Vec<_>>();
+        actual.sort_unstable();
+
+        assert_eq!(vec![Id::new(3), Id::new(5)], actual);
+        assert!(cache.iter().guild_members(Id::new(7)).next().is_none());
+    }
 }
@@ -14,6 +14,12 @@
 //! resource within the cache as a whole or on a guild-level can be retrieved
 //! via [`InMemoryCache::stats`].
 //!
+//! ## Custom Backends
+//!
+//! The [`Cache`] trait exposes the update entry point and core getters of a
+//! cache. [`InMemoryCache`] implements it, and alternative backends may
+//! implement it to be used interchangeably by code generic over the trait.
+//!
 //! ## Features
 //!
 //! By default no feature is enabled.
@@ -87,23 +93,32 @@ pub mod model;
 pub mod permission;
 
 mod builder;
+mod cache;
 mod config;
 mod event;
+mod eviction;
+mod removed_guild;
 mod stats;
+mod write_through;
 
 #[cfg(test)]
 mod test;
 
 pub use self::{
     builder::InMemoryCacheBuilder,
+    cache::Cache,
     config::{Config, ResourceType},
+    eviction::EvictedResource,
+    removed_guild::RemovedGuild,
     stats::InMemoryCacheStats,
 };
 
 #[cfg(feature = "permission-calculator")]
 pub use self::permission::InMemoryCachePermissions;
 
-use self::{iter::InMemoryCacheIter, model::*};
+use self::{
+    eviction::EvictionHook, iter::InMemoryCacheIter, model::*, write_through::WriteThrough,
+};
 use dashmap::{
     mapref::{entry::Entry, one::Ref},
     DashMap, DashSet,
@@ -114,7 +129,7 @@ use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
     hash::Hash,
     ops::Deref,
-    sync::Mutex,
+    sync::{Mutex, PoisonError, RwLock},
 };
 use twilight_model::{
     channel::{Channel, StageInstance},
@@ -252,6 +267,12 @@ fn upsert_guild_item<K: Eq + Hash, V: PartialEq>(
 #[derive(Debug, Default)]
 pub struct InMemoryCache {
     config: Config,
+    on_evict: Option<EvictionHook>,
+    /// Lock held for reading while an event updates the cache and for
+    /// writing while a guild is removed, so updates can't cache resources of
+    /// a guild midway through its removal.
+    update_lock: RwLock<()>,
+    write_through: Option<WriteThrough>,
     channels: DashMap<Id<ChannelMarker>, Channel>,
     channel_messages: DashMap<Id<ChannelMarker>, VecDeque<Id<MessageMarker>>>,
     // So long as the lock isn't held across await or panic points this is fine.
@@ -342,6 +363,56 @@ impl InMemoryCache {
         self.voice_states.clear();
     }
 
+    /// Remove a guild and everything cached for it, returning the removed
+    /// resources.
+    ///
+    /// This removes the guild's channels and their messages, emojis,
+    /// integrations, members, presences, roles, stage instances, stickers, and
+    /// voice states, along with the indexes referencing them. Users that are
+    /// no longer in any cached guild are removed as well.
+    ///
+    /// Unlike the cache removing a guild on its own accord, such as when the
+    /// current user is removed from it, the [eviction hook] isn't invoked.
+    ///
+    /// The removal is atomic with respect to [updating] the cache: updates
+    /// wait until the guild has been removed, so no resources of the guild
+    /// are cached while it is being removed. Reading the cache isn't blocked,
+    /// so concurrent readers may observe the guild partially removed.
+    ///
+    /// Returns `None` if nothing was cached for the guild.
+    ///
+    /// # Examples
+    ///
+    /// Remove a guild and print how many members were cached for it:
+    ///
+    /// ```no_run
+    /// use twilight_cache_inmemory::InMemoryCache;
+    /// use twilight_model::id::Id;
+    ///
+    /// let cache = InMemoryCache::new();
+    ///
+    /// // later in the application...
+    /// if let Some(removed) = cache.remove_guild(Id::new(1)) {
+    ///     println!("removed {} members", removed.members.len());
+    /// }
+    /// ```
+    ///
+    /// [eviction hook]: InMemoryCacheBuilder::on_evict
+    /// [updating]: Self::update
+    pub fn remove_guild(&self, guild_id: Id<GuildMarker>) -> Option<RemovedGuild> {
+        // The lock guards no data, so a poisoned lock is still usable.
+        let _update_lock = self
+            .update_lock
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        self.unavailable_guilds.remove(&guild_id);
+        let guild = self.guilds.remove(&guild_id).map(|(_, guild)| guild);
+        let removed = self.remove_guild_resources(guild_id, guild);
+
+        (!removed.is_empty()).then(|| removed)
+    }
+
     /// Returns a copy of the config cache.
     pub const fn config(&self) -> &Config {
         &self.config
@@ -427,8 +498,30 @@ impl InMemoryCache {
     }
 
     /// Update the cache with an event from the gateway.
+    ///
+    /// If a [write-through] channel is configured, the update is sent to it
+    /// after it has been applied.
+    ///
+    /// [write-through]: InMemoryCacheBuilder::write_through
     pub fn update(&self, value: &impl UpdateCache) {
-        value.update(self);
+        let evicted = {
+            let _update_lock = self
+                .update_lock
+                .read()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            private::Sealed::update_evicting(value, self)
+        };
+
+        // The hook is invoked once the update lock is released, since it is
+        // allowed to access and update the cache.
+        if let Some(hook) = &self.on_evict {
+            evicted.into_iter().for_each(|resource| (hook.0)(resource));
+        }
+
+        if let Some(write_through) = &self.write_through {
+            write_through.send(private::Sealed::to_event(value));
+        }
     }
 
     /// Gets the current user.
@@ -786,9 +879,15 @@ impl InMemoryCache {
         highest_role.map(|(_, id)| id)
     }
 
-    fn new_with_config(config: Config) -> Self {
+    fn new_with_config(
+        config: Config,
+        on_evict: Option<EvictionHook>,
+        write_through: Option<WriteThrough>,
+    ) -> Self {
         Self {
             config,
+            on_evict,
+            write_through,
             ..Default::default()
         }
     }
@@ -801,6 +900,7 @@ impl InMemoryCache {
 }
 
 mod private {
+    use super::{EvictedResource, InMemoryCache, UpdateCache};
     use twilight_model::gateway::{
         event::Event,
         payload::incoming::{
@@ -815,49 +915,287 @@ mod private {
         },
     };
 
-    pub trait Sealed {}
-
-    impl Sealed for Event {}
-    impl Sealed for ChannelCreate {}
-    impl Sealed for ChannelDelete {}
-    impl Sealed for ChannelPinsUpdate {}
-    impl Sealed for ChannelUpdate {}
-    impl Sealed for GuildCreate {}
-    impl Sealed for GuildEmojisUpdate {}
-    impl Sealed for GuildDelete {}
-    impl Sealed for GuildStickersUpdate {}
-    impl Sealed for GuildUpdate {}
-    impl Sealed for IntegrationCreate {}
-    impl Sealed for IntegrationDelete {}
-    impl Sealed for IntegrationUpdate {}
-    impl Sealed for InteractionCreate {}
-    impl Sealed for MemberAdd {}
-    impl Sealed for MemberChunk {}
-    impl Sealed for MemberRemove {}
-    impl Sealed for MemberUpdate {}
-    impl Sealed for MessageCreate {}
-    impl Sealed for MessageDelete {}
-    impl Sealed for MessageDeleteBulk {}
-    impl Sealed for MessageUpdate {}
-    impl Sealed for PresenceUpdate {}
-    impl Sealed for ReactionAdd {}
-    impl Sealed for ReactionRemove {}
-    impl Sealed for ReactionRemoveAll {}
-    impl Sealed for ReactionRemoveEmoji {}
-    impl Sealed for Ready {}
-    impl Sealed for RoleCreate {}
-    impl Sealed for RoleDelete {}
-    impl Sealed for RoleUpdate {}
-    impl Sealed for StageInstanceCreate {}
-    impl Sealed for StageInstanceDelete {}
-    impl Sealed for StageInstanceUpdate {}
-    impl Sealed for ThreadCreate {}
-    impl Sealed for ThreadDelete {}
-    impl Sealed for ThreadListSync {}
-    impl Sealed for ThreadUpdate {}
-    impl Sealed for UnavailableGuild {}
-    impl Sealed for UserUpdate {}
-    impl Sealed for VoiceStateUpdate {}
+    pub trait Sealed {
+        /// Clone the update into an event to write through to a secondary
+        /// cache.
+        fn to_event(&self) -> Event;
+
+        /// Update the cache, returning the resources evicted by the update.
+        fn update_evicting(&self, cache: &InMemoryCache) -> Vec<EvictedResource>
+        where
+            Self: UpdateCache,
+        {
+            self.update(cache);
+
+            Vec::new()
+        }
+    }
+
+    impl Sealed for Event {
+        fn to_event(&self) -> Event {
+            self.clone()
+        }
+
+        fn update_evicting(&self, cache: &InMemoryCache) -> Vec<EvictedResource> {
+            match self {
+                Event::GuildDelete(guild_delete) => guild_delete.update_evicting(cache),
+                Event::MessageCreate(message_create) => message_create.update_evicting(cache),
+                _ => {
+                    self.update(cache);
+
+                    Vec::new()
+                }
+            }
+        }
+    }
+
+    impl Sealed for ChannelCreate {
+        fn to_event(&self) -> Event {
+            Event::ChannelCreate(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for ChannelDelete {
+        fn to_event(&self) -> Event {
+            Event::ChannelDelete(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for ChannelPinsUpdate {
+        fn to_event(&self) -> Event {
+            Event::ChannelPinsUpdate(self.clone())
+        }
+    }
+
+    impl Sealed for ChannelUpdate {
+        fn to_event(&self) -> Event {
+            Event::ChannelUpdate(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for GuildCreate {
+        fn to_event(&self) -> Event {
+            Event::GuildCreate(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for GuildEmojisUpdate {
+        fn to_event(&self) -> Event {
+            Event::GuildEmojisUpdate(self.clone())
+        }
+    }
+
+    impl Sealed for GuildDelete {
+        fn to_event(&self) -> Event {
+            Event::GuildDelete(self.clone())
+        }
+
+        fn update_evicting(&self, cache: &InMemoryCache) -> Vec<EvictedResource> {
+            cache.guild_delete(self)
+        }
+    }
+
+    impl Sealed for GuildStickersUpdate {
+        fn to_event(&self) -> Event {
+            Event::GuildStickersUpdate(self.clone())
+        }
+    }
+
+    impl Sealed for GuildUpdate {
+        fn to_event(&self) -> Event {
+            Event::GuildUpdate(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for IntegrationCreate {
+        fn to_event(&self) -> Event {
+            Event::IntegrationCreate(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for IntegrationDelete {
+        fn to_event(&self) -> Event {
+            Event::IntegrationDelete(self.clone())
+        }
+    }
+
+    impl Sealed for IntegrationUpdate {
+        fn to_event(&self) -> Event {
+            Event::IntegrationUpdate(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for InteractionCreate {
+        fn to_event(&self) -> Event {
+            Event::InteractionCreate(self.clone())
+        }
+    }
+
+    impl Sealed for MemberAdd {
+        fn to_event(&self) -> Event {
+            Event::MemberAdd(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for MemberChunk {
+        fn to_event(&self) -> Event {
+            Event::MemberChunk(self.clone())
+        }
+    }
+
+    impl Sealed for MemberRemove {
+        fn to_event(&self) -> Event {
+            Event::MemberRemove(self.clone())
+        }
+    }
+
+    impl Sealed for MemberUpdate {
+        fn to_event(&self) -> Event {
+            Event::MemberUpdate(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for MessageCreate {
+        fn to_event(&self) -> Event {
+            Event::MessageCreate(Box::new(self.clone()))
+        }
+
+        fn update_evicting(&self, cache: &InMemoryCache) -> Vec<EvictedResource> {
+            cache.message_create(self)
+        }
+    }
+
+    impl Sealed for MessageDelete {
+        fn to_event(&self) -> Event {
+            Event::MessageDelete(self.clone())
+        }
+    }
+
+    impl Sealed for MessageDeleteBulk {
+        fn to_event(&self) -> Event {
+            Event::MessageDeleteBulk(self.clone())
+        }
+    }
+
+    impl Sealed for MessageUpdate {
+        fn to_event(&self) -> Event {
+            Event::MessageUpdate(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for PresenceUpdate {
+        fn to_event(&self) -> Event {
+            Event::PresenceUpdate(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for ReactionAdd {
+        fn to_event(&self) -> Event {
+            Event::ReactionAdd(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for ReactionRemove {
+        fn to_event(&self) -> Event {
+            Event::ReactionRemove(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for ReactionRemoveAll {
+        fn to_event(&self) -> Event {
+            Event::ReactionRemoveAll(self.clone())
+        }
+    }
+
+    impl Sealed for ReactionRemoveEmoji {
+        fn to_event(&self) -> Event {
+            Event::ReactionRemoveEmoji(self.clone())
+        }
+    }
+
+    impl Sealed for Ready {
+        fn to_event(&self) -> Event {
+            Event::Ready(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for RoleCreate {
+        fn to_event(&self) -> Event {
+            Event::RoleCreate(self.clone())
+        }
+    }
+
+    impl Sealed for RoleDelete {
+        fn to_event(&self) -> Event {
+            Event::RoleDelete(self.clone())
+        }
+    }
+
+    impl Sealed for RoleUpdate {
+        fn to_event(&self) -> Event {
+            Event::RoleUpdate(self.clone())
+        }
+    }
+
+    impl Sealed for StageInstanceCreate {
+        fn to_event(&self) -> Event {
+            Event::StageInstanceCreate(self.clone())
+        }
+    }
+
+    impl Sealed for StageInstanceDelete {
+        fn to_event(&self) -> Event {
+            Event::StageInstanceDelete(self.clone())
+        }
+    }
+
+    impl Sealed for StageInstanceUpdate {
+        fn to_event(&self) -> Event {
+            Event::StageInstanceUpdate(self.clone())
+        }
+    }
+
+    impl Sealed for ThreadCreate {
+        fn to_event(&self) -> Event {
+            Event::ThreadCreate(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for ThreadDelete {
+        fn to_event(&self) -> Event {
+            Event::ThreadDelete(self.clone())
+        }
+    }
+
+    impl Sealed for ThreadListSync {
+        fn to_event(&self) -> Event {
+            Event::ThreadListSync(self.clone())
+        }
+    }
+
+    impl Sealed for ThreadUpdate {
+        fn to_event(&self) -> Event {
+            Event::ThreadUpdate(Box::new(self.clone()))
+        }
+    }
+
+    impl Sealed for UnavailableGuild {
+        fn to_event(&self) -> Event {
+            Event::UnavailableGuild(self.clone())
+        }
+    }
+
+    impl Sealed for UserUpdate {
+        fn to_event(&self) -> Event {
+            Event::UserUpdate(self.clone())
+        }
+    }
+
+    impl Sealed for VoiceStateUpdate {
+        fn to_event(&self) -> Event {
+            Event::VoiceStateUpdate(Box::new(self.clone()))
+        }
+    }
 }
 
 /// Implemented for dispatch events.
@@ -902,10 +1240,10 @@ impl UpdateCache for Event {
         match self {
             BanAdd(_) => {}
             BanRemove(_) => {}
-            ChannelCreate(v) => c.update(v.deref()),
-            ChannelDelete(v) => c.update(v.deref()),
-            ChannelPinsUpdate(v) => c.update(v),
-            ChannelUpdate(v) => c.update(v.deref()),
+            ChannelCreate(v) => v.update(c),
+            ChannelDelete(v) => v.update(c),
+            ChannelPinsUpdate(v) => v.update(c),
+            ChannelUpdate(v) => v.update(c),
             CommandPermissionsUpdate(_) => {}
             GatewayHeartbeat(_) => {}
             GatewayHeartbeatAck => {}
@@ -913,42 +1251,47 @@ impl UpdateCache for Event {
             GatewayInvalidateSession(_v) => {}
             GatewayReconnect => {}
             GiftCodeUpdate => {}
-            GuildCreate(v) => c.update(v.deref()),
-            GuildDelete(v) => c.update(v),
-            GuildEmojisUpdate(v) => c.update(v),
-            GuildStickersUpdate(v) => c.update(v),
+            GuildAuditLogEntryCreate(_) => {}
+            GuildCreate(v) => v.update(c),
+            GuildDelete(v) => v.update(c),
+            GuildEmojisUpdate(v) => v.update(c),
+            GuildStickersUpdate(v) => v.update(c),
             GuildIntegrationsUpdate(_) => {}
             GuildScheduledEventCreate(_) => {}
             GuildScheduledEventDelete(_) => {}
             GuildScheduledEventUpdate(_) => {}
             GuildScheduledEventUserAdd(_) => {}
             GuildScheduledEventUserRemove(_) => {}
-            GuildUpdate(v) => c.update(v.deref()),
-            IntegrationCreate(v) => c.update(v.deref()),
-            IntegrationDelete(v) => c.update(v.deref()),
-            IntegrationUpdate(v) => c.update(v.deref()),
-            InteractionCreate(v) => c.update(v),
+            GuildSoundboardSoundCreate(_) => {}
+            GuildSoundboardSoundDelete(_) => {}
+            GuildSoundboardSoundUpdate(_) => {}
+            GuildSoundboardSoundsUpdate(_) => {}
+            GuildUpdate(v) => v.update(c),
+            IntegrationCreate(v) => v.update(c),
+            IntegrationDelete(v) => v.update(c),
+            IntegrationUpdate(v) => v.update(c),
+            InteractionCreate(v) => v.update(c),
             InviteCreate(_) => {}
             InviteDelete(_) => {}
-            MemberAdd(v) => c.update(v.deref()),
-            MemberRemove(v) => c.update(v),
-            MemberUpdate(v) => c.update(v.deref()),
-            MemberChunk(v) => c.update(v),
-            MessageCreate(v) => c.update(v.deref()),
-            MessageDelete(v) => c.update(v),
-            MessageDeleteBulk(v) => c.update(v),
-            MessageUpdate(v) => c.update(v.deref()),
-            PresenceUpdate(v) => c.update(v.deref()),
+            MemberAdd(v) => v.update(c),
+            MemberRemove(v) => v.update(c),
+            MemberUpdate(v) => v.update(c),
+            MemberChunk(v) => v.update(c),
+            MessageCreate(v) => v.update(c),
+            MessageDelete(v) => v.update(c),
+            MessageDeleteBulk(v) => v.update(c),
+            MessageUpdate(v) => v.update(c),
+            PresenceUpdate(v) => v.update(c),
             PresencesReplace => {}
-            ReactionAdd(v) => c.update(v.deref()),
-            ReactionRemove(v) => c.update(v.deref()),
-            ReactionRemoveAll(v) => c.update(v),
-            ReactionRemoveEmoji(v) => c.update(v),
-            Ready(v) => c.update(v.deref()),
+            ReactionAdd(v) => v.update(c),
+            ReactionRemove(v) => v.update(c),
+            ReactionRemoveAll(v) => v.update(c),
+            ReactionRemoveEmoji(v) => v.update(c),
+            Ready(v) => v.update(c),
             Resumed => {}
-            RoleCreate(v) => c.update(v),
-            RoleDelete(v) => c.update(v),
-            RoleUpdate(v) => c.update(v),
+            RoleCreate(v) => v.update(c),
+            RoleDelete(v) => v.update(c),
+            RoleUpdate(v) => v.update(c),
             ShardConnected(_) => {}
             ShardConnecting(_) => {}
             ShardDisconnected(_) => {}
@@ -956,20 +1299,22 @@ impl UpdateCache for Event {
             ShardReconnecting(_) => {}
             ShardPayload(_) => {}
             ShardResuming(_) => {}
-            StageInstanceCreate(v) => c.update(v),
-            StageInstanceDelete(v) => c.update(v),
-            StageInstanceUpdate(v) => c.update(v),
-            ThreadCreate(v) => c.update(v.deref()),
-            ThreadUpdate(v) => c.update(v.deref()),
-            ThreadDelete(v) => c.update(v),
-            ThreadListSync(v) => c.update(v),
+            SoundboardSounds(_) => {}
+            StageInstanceCreate(v) => v.update(c),
+            StageInstanceDelete(v) => v.update(c),
+            StageInstanceUpdate(v) => v.update(c),
+            ThreadCreate(v) => v.update(c),
+            ThreadUpdate(v) => v.update(c),
+            ThreadDelete(v) => v.update(c),
+            ThreadListSync(v) => v.update(c),
             ThreadMemberUpdate(_) => {}
             ThreadMembersUpdate(_) => {}
             TypingStart(_) => {}
-            UnavailableGuild(v) => c.update(v),
-            UserUpdate(v) => c.update(v),
+            UnavailableGuild(v) => v.update(c),
+            UserUpdate(v) => v.update(c),
+            VoiceChannelEffectSend(_) => {}
             VoiceServerUpdate(_) => {}
-            VoiceStateUpdate(v) => c.update(v.deref()),
+            VoiceStateUpdate(v) => v.update(c),
             WebhooksUpdate(_) => {}
         }
     }
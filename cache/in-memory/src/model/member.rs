@@ -1,7 +1,7 @@
 use serde::Serialize;
 use twilight_model::{
     application::interaction::application_command::InteractionMember,
-    guild::{Member, PartialMember},
+    guild::{Member, MemberLike, PartialMember},
     id::{
         marker::{GuildMarker, RoleMarker, UserMarker},
         Id,
@@ -200,6 +200,34 @@ impl CachedMember {
     }
 }
 
+impl From<Member> for CachedMember {
+    fn from(member: Member) -> Self {
+        Self::from_model(member)
+    }
+}
+
+impl MemberLike for CachedMember {
+    fn communication_disabled_until(&self) -> Option<Timestamp> {
+        self.communication_disabled_until
+    }
+
+    fn joined_at(&self) -> Timestamp {
+        self.joined_at
+    }
+
+    fn nick(&self) -> Option<&str> {
+        self.nick.as_deref()
+    }
+
+    fn premium_since(&self) -> Option<Timestamp> {
+        self.premium_since
+    }
+
+    fn roles(&self) -> &[Id<RoleMarker>] {
+        &self.roles
+    }
+}
+
 impl PartialEq<Member> for CachedMember {
     fn eq(&self, other: &Member) -> bool {
         self.avatar == other.avatar
@@ -239,9 +267,9 @@ impl PartialEq<InteractionMember> for CachedMember {
 #[cfg(test)]
 mod tests {
     use super::CachedMember;
-    use static_assertions::assert_fields;
+    use static_assertions::{assert_fields, assert_impl_all};
     use twilight_model::{
-        guild::{Member, PartialMember},
+        guild::{Member, MemberLike, PartialMember},
         id::Id,
         user::User,
         util::Timestamp,
@@ -258,6 +286,7 @@ mod tests {
         roles,
         user_id
     );
+    assert_impl_all!(CachedMember: From<Member>, MemberLike);
 
     fn cached_member() -> CachedMember {
         let joined_at = Timestamp::from_secs(1_632_072_645).expect("non zero");
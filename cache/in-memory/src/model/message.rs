@@ -7,7 +7,7 @@ use twilight_model::{
         embed::Embed,
         message::{
             sticker::MessageSticker, Message, MessageActivity, MessageApplication, MessageFlags,
-            MessageInteraction, MessageReaction, MessageReference, MessageType,
+            MessageInteraction, MessageReaction, MessageReference, MessageSnapshot, MessageType,
         },
         Attachment, ChannelMention,
     },
@@ -101,6 +101,7 @@ pub struct CachedMessage {
     pub(crate) mention_everyone: bool,
     pub(crate) mention_roles: Vec<Id<RoleMarker>>,
     pub(crate) mentions: Vec<Id<UserMarker>>,
+    message_snapshots: Vec<MessageSnapshot>,
     pub(crate) pinned: bool,
     pub(crate) reactions: Vec<MessageReaction>,
     reference: Option<MessageReference>,
@@ -236,6 +237,11 @@ impl CachedMessage {
         &self.mentions
     }
 
+    /// Minimal copies of the messages forwarded by this message.
+    pub fn message_snapshots(&self) -> &[MessageSnapshot] {
+        &self.message_snapshots
+    }
+
     /// Whether or not the message is pinned.
     pub const fn pinned(&self) -> bool {
         self.pinned
@@ -299,6 +305,8 @@ impl CachedMessage {
             mention_everyone,
             mention_roles,
             mentions,
+            message_snapshots,
+            nonce: _,
             pinned,
             reactions,
             reference,
@@ -331,6 +339,7 @@ impl CachedMessage {
             mention_everyone,
             mention_roles,
             mentions: mentions.into_iter().map(|mention| mention.id).collect(),
+            message_snapshots,
             pinned,
             reactions,
             reference,
@@ -378,6 +387,7 @@ mod tests {
         mention_everyone,
         mention_roles,
         mentions,
+        message_snapshots,
         pinned,
         reactions,
         reference,
@@ -39,6 +39,7 @@
 use super::InMemoryCache;
 use crate::model::member::CachedMember;
 use std::{
+    cmp::Reverse,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     time::{Duration, SystemTime},
@@ -505,6 +506,66 @@ impl<'a> InMemoryCachePermissions<'a> {
         Ok(self.disable_member_communication(&member, permissions))
     }
 
+    /// Determine whether a member can perform moderation actions on another
+    /// member based on the guild's role hierarchy.
+    ///
+    /// The owner of the guild can target any other member, and the owner can
+    /// never be targeted. Otherwise, the actor can target the target if the
+    /// actor's highest role is above the target's highest role. Roles are
+    /// ordered by position, and roles sharing a position by ID with the lower
+    /// ID being higher. Members without roles are considered to only have the
+    /// `@everyone` role.
+    ///
+    /// This only considers the role hierarchy; the actor may additionally
+    /// require permissions such as [`Permissions::BAN_MEMBERS`], which can be
+    /// checked via [`root`].
+    ///
+    /// Returns [`None`] if the guild, either member, or any of their roles are
+    /// not in the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use twilight_cache_inmemory::InMemoryCache;
+    /// use twilight_model::id::Id;
+    ///
+    /// let cache = InMemoryCache::new();
+    ///
+    /// // Later on...
+    ///
+    /// let guild_id = Id::new(1);
+    /// let moderator_id = Id::new(2);
+    /// let target_id = Id::new(3);
+    ///
+    /// if cache.permissions().can_target(guild_id, moderator_id, target_id) == Some(true) {
+    ///     println!("moderator can act on the target");
+    /// }
+    /// ```
+    ///
+    /// [`Permissions::BAN_MEMBERS`]: twilight_model::guild::Permissions::BAN_MEMBERS
+    /// [`root`]: Self::root
+    pub fn can_target(
+        &self,
+        guild_id: Id<GuildMarker>,
+        actor_id: Id<UserMarker>,
+        target_id: Id<UserMarker>,
+    ) -> Option<bool> {
+        let owner_id = self.cache.guilds.get(&guild_id)?.owner_id;
+
+        if target_id == owner_id {
+            return Some(false);
+        }
+
+        if actor_id == owner_id {
+            return Some(true);
+        }
+
+        let actor = self.highest_role(guild_id, actor_id)?;
+        let target = self.highest_role(guild_id, target_id)?;
+
+        Some(actor > target)
+    }
+
     /// Determine whether the provided member is disabled and restrict them to
     /// [read-only permissions] if they are.
     ///
@@ -561,6 +622,40 @@ impl<'a> InMemoryCachePermissions<'a> {
             .unwrap_or_default()
     }
 
+    /// Retrieve the position and ID of a member's highest role, falling back
+    /// to the guild's `@everyone` role.
+    ///
+    /// The ID is reversed since Discord considers the role with the lower ID
+    /// to be higher when two roles share a position.
+    ///
+    /// Returns [`None`] if the member or any of their roles are not in the
+    /// cache.
+    fn highest_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Option<(i64, Reverse<Id<RoleMarker>>)> {
+        let member = self.cache.member(guild_id, user_id)?;
+
+        let mut highest = self
+            .cache
+            .roles
+            .get(&guild_id.cast())
+            .map(|role| (role.position, Reverse(role.id)))?;
+
+        for role_id in &member.roles {
+            let role = self
+                .cache
+                .roles
+                .get(role_id)
+                .map(|role| (role.position, Reverse(role.id)))?;
+
+            highest = highest.max(role);
+        }
+
+        Some(highest)
+    }
+
     /// Retrieve a member's roles' permissions and the guild's `@everyone`
     /// role's permissions.
     ///
@@ -766,8 +861,13 @@ mod tests {
     fn channel() -> Channel {
         Channel {
             application_id: None,
+            available_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
             guild_id: Some(GUILD_ID),
             icon: None,
             id: CHANNEL_ID,
@@ -811,8 +911,13 @@ mod tests {
     fn thread() -> Channel {
         Channel {
             application_id: None,
+            available_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
             guild_id: Some(GUILD_ID),
             icon: None,
             id: THREAD_ID,
@@ -985,6 +1090,89 @@ mod tests {
         Ok(())
     }
 
+    /// Test that [`in_channel`] applies overwrites of a member's roles before
+    /// the member's own overwrite, so that the member overwrite takes
+    /// precedence.
+    ///
+    /// [`in_channel`]: super::InMemoryCachePermissions::in_channel
+    #[test]
+    fn test_in_channel_overwrites() -> Result<(), Box<dyn Error>> {
+        let cache = InMemoryCache::new();
+        let permissions = cache.permissions();
+
+        cache.update(&GuildCreate(base_guild()));
+        cache.update(&role_create(
+            GUILD_ID,
+            role_with_permissions(OTHER_ROLE_ID, Permissions::SEND_MESSAGES),
+        ));
+        cache.update(&MemberAdd({
+            let mut member = test::member(USER_ID, GUILD_ID);
+            member.roles.push(OTHER_ROLE_ID);
+
+            member
+        }));
+
+        let mut channel = channel();
+        channel.permission_overwrites = Some(Vec::from([
+            PermissionOverwrite {
+                allow: Permissions::ADD_REACTIONS | Permissions::ATTACH_FILES,
+                deny: Permissions::SEND_MESSAGES,
+                id: OTHER_ROLE_ID.cast(),
+                kind: PermissionOverwriteType::Role,
+            },
+            PermissionOverwrite {
+                allow: Permissions::SEND_MESSAGES,
+                deny: Permissions::ATTACH_FILES,
+                id: USER_ID.cast(),
+                kind: PermissionOverwriteType::Member,
+            },
+        ]));
+        cache.update(&ChannelCreate(channel));
+
+        assert_eq!(
+            Permissions::CREATE_INVITE | Permissions::ADD_REACTIONS | Permissions::SEND_MESSAGES,
+            permissions.in_channel(USER_ID, CHANNEL_ID)?,
+        );
+
+        Ok(())
+    }
+
+    /// Test that [`in_channel`] returns [`Permissions::all`] if one of the
+    /// member's roles grants the administrator permission, regardless of the
+    /// channel's overwrites.
+    ///
+    /// [`in_channel`]: super::InMemoryCachePermissions::in_channel
+    #[test]
+    fn test_in_channel_administrator() -> Result<(), Box<dyn Error>> {
+        let cache = InMemoryCache::new();
+        let permissions = cache.permissions();
+
+        cache.update(&GuildCreate(base_guild()));
+        cache.update(&role_create(
+            GUILD_ID,
+            role_with_permissions(OTHER_ROLE_ID, Permissions::ADMINISTRATOR),
+        ));
+        cache.update(&MemberAdd({
+            let mut member = test::member(USER_ID, GUILD_ID);
+            member.roles.push(OTHER_ROLE_ID);
+
+            member
+        }));
+
+        let mut channel = channel();
+        channel.permission_overwrites = Some(Vec::from([PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::all(),
+            id: USER_ID.cast(),
+            kind: PermissionOverwriteType::Member,
+        }]));
+        cache.update(&ChannelCreate(channel));
+
+        assert!(permissions.in_channel(USER_ID, CHANNEL_ID)?.is_all());
+
+        Ok(())
+    }
+
     /// Test that [`in_channel`] and [`root`] both return [`Permissions::all`]
     /// if the user is also the owner of the guild.
     ///
@@ -1007,6 +1195,129 @@ mod tests {
         Ok(())
     }
 
+    /// Test that a member can only target members whose highest role is below
+    /// their own, and that the owner can target anyone but can't be targeted.
+    #[test]
+    fn test_can_target() {
+        const HIGH_ROLE_ID: Id<RoleMarker> = Id::new(6);
+        const LOW_ROLE_ID: Id<RoleMarker> = Id::new(7);
+        const TARGET_ID: Id<UserMarker> = Id::new(8);
+
+        fn set_roles(cache: &InMemoryCache, user_id: Id<UserMarker>, roles: Vec<Id<RoleMarker>>) {
+            cache.update(&MemberAdd(test::member(user_id, GUILD_ID)));
+            cache.update(&MemberUpdate {
+                avatar: None,
+                communication_disabled_until: None,
+                guild_id: GUILD_ID,
+                deaf: None,
+                joined_at: Timestamp::from_secs(1_632_072_645).unwrap(),
+                mute: None,
+                nick: None,
+                pending: false,
+                premium_since: None,
+                roles,
+                user: test::user(user_id),
+            });
+        }
+
+        let cache = InMemoryCache::new();
+        let permissions = cache.permissions();
+
+        assert_eq!(None, permissions.can_target(GUILD_ID, USER_ID, TARGET_ID));
+
+        cache.update(&GuildCreate(base_guild()));
+
+        let mut high_role = test::role(HIGH_ROLE_ID);
+        high_role.position = 2;
+        cache.update(&role_create(GUILD_ID, high_role));
+
+        let mut low_role = test::role(LOW_ROLE_ID);
+        low_role.position = 1;
+        cache.update(&role_create(GUILD_ID, low_role));
+
+        // Members aren't in the cache yet.
+        assert_eq!(None, permissions.can_target(GUILD_ID, USER_ID, TARGET_ID));
+
+        // Actor above target.
+        set_roles(&cache, USER_ID, Vec::from([LOW_ROLE_ID, HIGH_ROLE_ID]));
+        set_roles(&cache, TARGET_ID, Vec::from([LOW_ROLE_ID]));
+        assert_eq!(
+            Some(true),
+            permissions.can_target(GUILD_ID, USER_ID, TARGET_ID)
+        );
+        assert_eq!(
+            Some(false),
+            permissions.can_target(GUILD_ID, TARGET_ID, USER_ID)
+        );
+
+        // Equal highest roles.
+        set_roles(&cache, TARGET_ID, Vec::from([HIGH_ROLE_ID]));
+        assert_eq!(
+            Some(false),
+            permissions.can_target(GUILD_ID, USER_ID, TARGET_ID)
+        );
+
+        // Members without roles only have the `@everyone` role.
+        set_roles(&cache, TARGET_ID, Vec::new());
+        assert_eq!(
+            Some(true),
+            permissions.can_target(GUILD_ID, USER_ID, TARGET_ID)
+        );
+
+        // The owner can target anyone, but can't be targeted, even if they
+        // aren't in the cache.
+        assert_eq!(
+            Some(true),
+            permissions.can_target(GUILD_ID, OWNER_ID, USER_ID)
+        );
+        assert_eq!(
+            Some(false),
+            permissions.can_target(GUILD_ID, USER_ID, OWNER_ID)
+        );
+    }
+
+    /// Test that when the highest roles of two members share a position, the
+    /// member whose role has the lower ID is higher, as it is in Discord.
+    #[test]
+    fn test_can_target_same_position() {
+        const LOWER_ID_ROLE_ID: Id<RoleMarker> = Id::new(6);
+        const HIGHER_ID_ROLE_ID: Id<RoleMarker> = Id::new(7);
+        const TARGET_ID: Id<UserMarker> = Id::new(8);
+
+        let cache = InMemoryCache::new();
+        let permissions = cache.permissions();
+        cache.update(&GuildCreate(base_guild()));
+
+        let mut lower_id_role = test::role(LOWER_ID_ROLE_ID);
+        lower_id_role.position = 1;
+        let mut higher_id_role = test::role(HIGHER_ID_ROLE_ID);
+        higher_id_role.position = 1;
+
+        cache.update(&role_create(GUILD_ID, lower_id_role));
+        cache.update(&role_create(GUILD_ID, higher_id_role));
+        cache.update(&MemberAdd({
+            let mut member = test::member(USER_ID, GUILD_ID);
+            member.roles.push(LOWER_ID_ROLE_ID);
+
+            member
+        }));
+        cache.update(&MemberAdd({
+            let mut member = test::member(TARGET_ID, GUILD_ID);
+            member.roles.push(HIGHER_ID_ROLE_ID);
+
+            member
+        }));
+
+        assert_eq!(
+            Some(true),
+            permissions.can_target(GUILD_ID, USER_ID, TARGET_ID)
+        );
+        assert_eq!(
+            Some(false),
+            permissions.can_target(GUILD_ID, TARGET_ID, USER_ID)
+        );
+    }
+
     /// Test the behavior of a member having their communication disabled.
     ///
     /// In particular, we want to test that:
@@ -0,0 +1,127 @@
+use crate::{
+    model::{
+        CachedEmoji, CachedGuild, CachedMember, CachedMessage, CachedPresence, CachedSticker,
+        CachedVoiceState,
+    },
+    EvictedResource,
+};
+use twilight_model::{
+    channel::{Channel, StageInstance},
+    guild::{GuildIntegration, Role},
+    id::{marker::GuildMarker, Id},
+};
+
+/// Resources of a guild removed from the cache.
+///
+/// Returned by [`InMemoryCache::remove_guild`], giving ownership of everything
+/// the cache held for the guild, such as to inspect it or account for the
+/// memory that was freed.
+///
+/// The order of the resources in each list is arbitrary.
+///
+/// [`InMemoryCache::remove_guild`]: crate::InMemoryCache::remove_guild
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RemovedGuild {
+    /// Channels and threads of the guild.
+    pub channels: Vec<Channel>,
+    /// Emojis of the guild.
+    pub emojis: Vec<CachedEmoji>,
+    /// Guild itself, if it was cached.
+    pub guild: Option<CachedGuild>,
+    /// Integrations of the guild.
+    pub integrations: Vec<GuildIntegration>,
+    /// Members of the guild.
+    pub members: Vec<CachedMember>,
+    /// Messages sent in the channels of the guild.
+    pub messages: Vec<CachedMessage>,
+    /// Presences of the guild's members.
+    pub presences: Vec<CachedPresence>,
+    /// Roles of the guild.
+    pub roles: Vec<Role>,
+    /// Stage instances of the guild.
+    pub stage_instances: Vec<StageInstance>,
+    /// Stickers of the guild.
+    pub stickers: Vec<CachedSticker>,
+    /// Voice states of users connected to the guild's voice channels.
+    pub voice_states: Vec<CachedVoiceState>,
+}
+
+impl RemovedGuild {
+    /// Whether no resources of the guild were removed.
+    pub fn is_empty(&self) -> bool {
+        self.guild.is_none()
+            && self.channels.is_empty()
+            && self.emojis.is_empty()
+            && self.integrations.is_empty()
+            && self.members.is_empty()
+            && self.messages.is_empty()
+            && self.presences.is_empty()
+            && self.roles.is_empty()
+            && self.stage_instances.is_empty()
+            && self.stickers.is_empty()
+            && self.voice_states.is_empty()
+    }
+
+    /// Resources to report as evicted when the cache removes the guild on
+    /// its own accord.
+    pub(crate) fn evicted(&self, guild_id: Id<GuildMarker>) -> Vec<EvictedResource> {
+        let guild = self
+            .guild
+            .as_ref()
+            .map(|guild| EvictedResource::Guild(guild.id()));
+        let channels = self
+            .channels
+            .iter()
+            .map(|channel| EvictedResource::Channel(channel.id));
+        let emojis = self
+            .emojis
+            .iter()
+            .map(|emoji| EvictedResource::Emoji(emoji.id()));
+        let members = self.members.iter().map(|member| EvictedResource::Member {
+            guild_id,
+            user_id: member.user_id(),
+        });
+        let messages = self
+            .messages
+            .iter()
+            .map(|message| EvictedResource::Message(message.id()));
+        let presences = self
+            .presences
+            .iter()
+            .map(|presence| EvictedResource::Presence {
+                guild_id,
+                user_id: presence.user_id(),
+            });
+        let roles = self.roles.iter().map(|role| EvictedResource::Role(role.id));
+        let stickers = self
+            .stickers
+            .iter()
+            .map(|sticker| EvictedResource::Sticker(sticker.id()));
+
+        guild
+            .into_iter()
+            .chain(channels)
+            .chain(emojis)
+            .chain(members)
+            .chain(messages)
+            .chain(presences)
+            .chain(roles)
+            .chain(stickers)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RemovedGuild;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(RemovedGuild: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_is_empty() {
+        assert!(RemovedGuild::default().is_empty());
+    }
+}
@@ -15,7 +15,7 @@ use twilight_model::{
         VerificationLevel,
     },
     id::{
-        marker::{ChannelMarker, EmojiMarker, GuildMarker, RoleMarker, UserMarker},
+        marker::{ChannelMarker, EmojiMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker},
         Id,
     },
     user::{CurrentUser, User},
@@ -32,66 +32,7 @@ pub fn cache_with_message_and_reactions() -> InMemoryCache {
     let cache = InMemoryCache::new();
     let avatar = ImageHash::parse(b"6961d9f1fdb5880bf4a3ec6348d3bbcf").unwrap();
 
-    let msg = Message {
-        activity: None,
-        application: None,
-        application_id: None,
-        attachments: Vec::new(),
-        author: User {
-            accent_color: None,
-            avatar: Some(avatar),
-            banner: None,
-            bot: false,
-            discriminator: 1,
-            email: None,
-            flags: None,
-            id: Id::new(3),
-            locale: None,
-            mfa_enabled: None,
-            name: "test".to_owned(),
-            premium_type: None,
-            public_flags: None,
-            system: None,
-            verified: None,
-        },
-        channel_id: Id::new(2),
-        components: Vec::new(),
-        content: "ping".to_owned(),
-        edited_timestamp: None,
-        embeds: Vec::new(),
-        flags: Some(MessageFlags::empty()),
-        guild_id: Some(Id::new(1)),
-        id: Id::new(4),
-        interaction: None,
-        kind: MessageType::Regular,
-        member: Some(PartialMember {
-            avatar: None,
-            communication_disabled_until: None,
-            deaf: false,
-            joined_at,
-            mute: false,
-            nick: Some("member nick".to_owned()),
-            permissions: None,
-            premium_since: None,
-            roles: Vec::new(),
-            user: None,
-        }),
-        mention_channels: Vec::new(),
-        mention_everyone: false,
-        mention_roles: Vec::new(),
-        mentions: Vec::new(),
-        pinned: false,
-        reactions: Vec::new(),
-        reference: None,
-        sticker_items: Vec::new(),
-        thread: None,
-        referenced_message: None,
-        timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
-        tts: false,
-        webhook_id: None,
-    };
-
-    cache.update(&MessageCreate(msg));
+    cache.update(&MessageCreate(message(Id::new(4))));
 
     let mut reaction = ReactionAdd(Reaction {
         channel_id: Id::new(2),
@@ -179,6 +120,72 @@ pub fn cache_with_message_and_reactions() -> InMemoryCache {
     cache
 }
 
+pub fn message(id: Id<MessageMarker>) -> Message {
+    let avatar = ImageHash::parse(b"6961d9f1fdb5880bf4a3ec6348d3bbcf").unwrap();
+    let joined_at = Timestamp::from_secs(1_632_072_645).expect("non zero");
+
+    Message {
+        activity: None,
+        application: None,
+        application_id: None,
+        attachments: Vec::new(),
+        author: User {
+            accent_color: None,
+            avatar: Some(avatar),
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            id: Id::new(3),
+            locale: None,
+            mfa_enabled: None,
+            name: "test".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        },
+        channel_id: Id::new(2),
+        components: Vec::new(),
+        content: "ping".to_owned(),
+        edited_timestamp: None,
+        embeds: Vec::new(),
+        flags: Some(MessageFlags::empty()),
+        guild_id: Some(Id::new(1)),
+        id,
+        interaction: None,
+        kind: MessageType::Regular,
+        member: Some(PartialMember {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: false,
+            joined_at,
+            mute: false,
+            nick: Some("member nick".to_owned()),
+            permissions: None,
+            premium_since: None,
+            roles: Vec::new(),
+            user: None,
+        }),
+        mention_channels: Vec::new(),
+        mention_everyone: false,
+        mention_roles: Vec::new(),
+        mentions: Vec::new(),
+        message_snapshots: Vec::new(),
+        nonce: None,
+        pinned: false,
+        reactions: Vec::new(),
+        reference: None,
+        sticker_items: Vec::new(),
+        thread: None,
+        referenced_message: None,
+        timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+        tts: false,
+        webhook_id: None,
+    }
+}
+
 pub fn current_user(id: u64) -> CurrentUser {
     CurrentUser {
         accent_color: Some(16711680),
@@ -216,8 +223,13 @@ pub fn guild_channel_text() -> (Id<GuildMarker>, Id<ChannelMarker>, Channel) {
     let channel_id = Id::new(2);
     let channel = Channel {
         application_id: None,
+        available_tags: None,
         bitrate: None,
         default_auto_archive_duration: None,
+        default_forum_layout: None,
+        default_reaction_emoji: None,
+        default_sort_order: None,
+        default_thread_rate_limit_per_user: None,
         guild_id: Some(guild_id),
         icon: None,
         id: channel_id,
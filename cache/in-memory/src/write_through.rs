@@ -0,0 +1,90 @@
+use std::sync::{mpsc::Sender, Mutex};
+use twilight_model::gateway::event::Event;
+
+/// Channel to write updates applied to the cache through to.
+///
+/// The sender is behind a mutex because it isn't [`Sync`] on the minimum
+/// supported Rust version; the lock is only held to enqueue an update.
+#[derive(Debug)]
+pub(crate) struct WriteThrough(Mutex<Sender<Event>>);
+
+impl WriteThrough {
+    pub(crate) fn new(sender: Sender<Event>) -> Self {
+        Self(Mutex::new(sender))
+    }
+
+    /// Enqueue an applied update.
+    ///
+    /// Updates are dropped if the receiver has hung up.
+    pub(crate) fn send(&self, event: Event) {
+        let _res = self.0.lock().expect("write through poisoned").send(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test, InMemoryCache};
+    use std::sync::mpsc;
+    use twilight_model::{
+        gateway::{
+            event::Event,
+            payload::incoming::{MessageCreate, MessageDelete},
+        },
+        id::Id,
+    };
+
+    #[test]
+    fn test_write_through_in_order() {
+        let (tx, rx) = mpsc::channel();
+        let cache = InMemoryCache::builder().write_through(tx).build();
+
+        let first = MessageCreate(test::message(Id::new(4)));
+        let second = Event::MessageCreate(Box::new(MessageCreate(test::message(Id::new(5)))));
+        let third = MessageDelete {
+            channel_id: Id::new(2),
+            guild_id: None,
+            id: Id::new(4),
+        };
+
+        cache.update(&first);
+        cache.update(&second);
+        cache.update(&third);
+
+        // Updates nested in an event are only written through once.
+        assert_eq!(
+            Event::MessageCreate(Box::new(first)),
+            rx.try_recv().unwrap()
+        );
+        assert_eq!(second, rx.try_recv().unwrap());
+        assert_eq!(Event::MessageDelete(third), rx.try_recv().unwrap());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_write_through_secondary() {
+        let (tx, rx) = mpsc::channel();
+        let cache = InMemoryCache::builder().write_through(tx).build();
+        let secondary = InMemoryCache::new();
+
+        cache.update(&MessageCreate(test::message(Id::new(4))));
+        cache.update(&MessageCreate(test::message(Id::new(5))));
+        drop(cache);
+
+        for event in rx {
+            secondary.update(&event);
+        }
+
+        assert!(secondary.message(Id::new(4)).is_some());
+        assert!(secondary.message(Id::new(5)).is_some());
+    }
+
+    #[test]
+    fn test_write_through_receiver_dropped() {
+        let (tx, rx) = mpsc::channel();
+        let cache = InMemoryCache::builder().write_through(tx).build();
+        drop(rx);
+
+        cache.update(&MessageCreate(test::message(Id::new(4))));
+        assert!(cache.message(Id::new(4)).is_some());
+    }
+}
@@ -1,11 +1,12 @@
 use super::{Cluster, ClusterStartError, ClusterStartErrorType, Config, Events, ShardScheme};
 use crate::{
-    shard::{ResumeSession, ShardBuilder},
+    shard::{CompressionMethod, MetricsSink, ResumeSession, ShardBuilder},
     EventTypeFlags,
 };
 use std::{
     collections::HashMap,
     fmt::{Debug, Formatter, Result as FmtResult},
+    ops::RangeInclusive,
     sync::Arc,
 };
 use twilight_gateway_queue::{LocalQueue, Queue};
@@ -50,6 +51,7 @@ pub struct ClusterBuilder {
     queue: Arc<dyn Queue>,
     resume_sessions: HashMap<u64, ResumeSession>,
     shard: ShardBuilder,
+    shard_intents: Vec<(RangeInclusive<u64>, Intents)>,
     shard_presence:
         Option<Box<dyn Fn(u64) -> Option<UpdatePresencePayload> + Send + Sync + 'static>>,
     shard_scheme: Option<ShardScheme>,
@@ -62,6 +64,7 @@ impl ClusterBuilder {
             queue: Arc::new(LocalQueue::new()),
             resume_sessions: HashMap::new(),
             shard: ShardBuilder::new(token, intents),
+            shard_intents: Vec::new(),
             shard_presence: None,
             shard_scheme: None,
         }
@@ -74,7 +77,13 @@ impl ClusterBuilder {
     /// Returns a [`ClusterStartErrorType::RetrievingGatewayInfo`] error type if
     /// there was an HTTP error Retrieving the gateway information.
     ///
+    /// Returns a [`ClusterStartErrorType::PrivilegedIntentsUndeclared`] error
+    /// type if the [intents of a range of shards] contain privileged intents
+    /// that the cluster's intents don't.
+    ///
+    /// [`ClusterStartErrorType::PrivilegedIntentsUndeclared`]: super::ClusterStartErrorType::PrivilegedIntentsUndeclared
     /// [`ClusterStartErrorType::RetrievingGatewayInfo`]: super::ClusterStartErrorType::RetrievingGatewayInfo
+    /// [intents of a range of shards]: Self::shard_intents
     pub async fn build(mut self) -> Result<(Cluster, Events), ClusterStartError> {
         if self.shard.gateway_url.is_none() || self.shard_scheme.is_none() {
             let gateway = Self::retrieve_connect_info(&self.shard.http_client).await?;
@@ -119,6 +128,7 @@ impl ClusterBuilder {
         let config = Config {
             queue: self.queue,
             resume_sessions: self.resume_sessions,
+            shard_intents: self.shard_intents,
             shard_presence: self.shard_presence,
             shard_scheme: self.shard_scheme.expect("always set"),
         };
@@ -145,6 +155,20 @@ impl ClusterBuilder {
             })
     }
 
+    /// Set the method used by the gateway to compress payloads.
+    ///
+    /// Refer to the shard's [`ShardBuilder::compression`] for more
+    /// information.
+    ///
+    /// [`ShardBuilder::compression`]: crate::shard::ShardBuilder::compression
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "has no effect if not built"]
+    pub fn compression(mut self, compression: CompressionMethod) -> Self {
+        self.shard = self.shard.compression(compression);
+
+        self
+    }
+
     /// Set the event types to process.
     ///
     /// This is an optimization technique; all events not included in the
@@ -230,6 +254,55 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set the number of recent heartbeat latencies kept by shards.
+    ///
+    /// Refer to the shard's [`ShardBuilder::latency_history`] for more
+    /// information.
+    ///
+    /// [`ShardBuilder::latency_history`]: crate::shard::ShardBuilder::latency_history
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "has no effect if not built"]
+    pub fn latency_history(mut self, latency_history: usize) -> Self {
+        self.shard = self.shard.latency_history(latency_history);
+
+        self
+    }
+
+    /// Set the maximum size in bytes of a decompressed frame received by
+    /// shards.
+    ///
+    /// Refer to the shard's [`ShardBuilder::max_frame_size`] for more
+    /// information.
+    #[must_use = "has no effect if not built"]
+    pub fn max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.shard = self.shard.max_frame_size(max_frame_size);
+
+        self
+    }
+
+    /// Set the maximum number of consecutive attempts of shards to resume a
+    /// session.
+    ///
+    /// Refer to the shard's [`ShardBuilder::max_resume_attempts`] for more
+    /// information.
+    #[must_use = "has no effect if not built"]
+    pub fn max_resume_attempts(mut self, max_resume_attempts: u8) -> Self {
+        self.shard = self.shard.max_resume_attempts(max_resume_attempts);
+
+        self
+    }
+
+    /// Set the sink to report metrics about shards' connections to.
+    ///
+    /// The sink is shared by all shards. Refer to the shard's
+    /// [`ShardBuilder::metrics`] for more information.
+    #[must_use = "has no effect if not built"]
+    pub fn metrics(mut self, metrics: Arc<dyn MetricsSink>) -> Self {
+        self.shard = self.shard.metrics(metrics);
+
+        self
+    }
+
     /// Set the presence to use when identifying with the gateway.
     ///
     /// Refer to the shard's [`ShardBuilder::presence`] for more information.
@@ -254,6 +327,60 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set the event types to send as raw JSON payloads.
+    ///
+    /// Refer to the shard's [`ShardBuilder::raw_event_types`] for more
+    /// information.
+    ///
+    /// [`ShardBuilder::raw_event_types`]: crate::shard::ShardBuilder::raw_event_types
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "has no effect if not built"]
+    pub fn raw_event_types(mut self, raw_event_types: EventTypeFlags) -> Self {
+        self.shard = self.shard.raw_event_types(raw_event_types);
+
+        self
+    }
+
+    /// Set the intents of a range of shards, overriding the cluster's intents.
+    ///
+    /// This can be used to only enable intents on the shards that need them,
+    /// such as receiving message content on some shards but not on others.
+    /// Can be called multiple times to configure multiple ranges; if ranges
+    /// overlap then the intents set last take precedence. Shards not in any
+    /// range use the intents the cluster was created with.
+    ///
+    /// The cluster's intents declare which [privileged intents] are enabled
+    /// for the application, so the intents of each range may only contain
+    /// privileged intents that the cluster's intents also contain.
+    ///
+    /// # Examples
+    ///
+    /// Only receive message content on shards 0-3 out of 8 shards total:
+    ///
+    /// ```no_run
+    /// use std::env;
+    /// use twilight_gateway::{cluster::{Cluster, ShardScheme}, Intents};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let intents = Intents::GUILDS | Intents::GUILD_MESSAGES;
+    ///
+    /// let cluster = Cluster::builder(token, intents | Intents::MESSAGE_CONTENT)
+    ///     .shard_scheme(ShardScheme::try_from((0..=7, 8))?)
+    ///     .shard_intents(4..=7, intents)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [privileged intents]: Intents::PRIVILEGED
+    #[must_use = "has no effect if not built"]
+    pub fn shard_intents(mut self, shards: RangeInclusive<u64>, intents: Intents) -> Self {
+        self.shard_intents.push((shards, intents));
+
+        self
+    }
+
     /// Set specific shard presences to use when identifying with the gateway.
     ///
     /// Accepts a closure. The closure accepts a [`u64`] and returns an
@@ -344,6 +471,7 @@ impl Debug for ClusterBuilder {
             .field("queue", &self.queue)
             .field("resume_sessions", &self.resume_sessions)
             .field("shard", &self.shard)
+            .field("shard_intents", &self.shard_intents)
             .field("shard_presence", &"<Fn>")
             .field("shard_scheme", &self.shard_scheme)
             .finish()
@@ -2,10 +2,11 @@ use crate::{cluster::ShardScheme, shard::ResumeSession};
 use std::{
     collections::HashMap,
     fmt::{Debug, Formatter, Result as FmtResult},
+    ops::RangeInclusive,
     sync::Arc,
 };
 use twilight_gateway_queue::Queue;
-use twilight_model::gateway::payload::outgoing::update_presence::UpdatePresencePayload;
+use twilight_model::gateway::{payload::outgoing::update_presence::UpdatePresencePayload, Intents};
 
 /// Built configuration for a [`Cluster`].
 ///
@@ -13,6 +14,7 @@ use twilight_model::gateway::payload::outgoing::update_presence::UpdatePresenceP
 pub struct Config {
     pub(super) queue: Arc<dyn Queue>,
     pub(super) resume_sessions: HashMap<u64, ResumeSession>,
+    pub(super) shard_intents: Vec<(RangeInclusive<u64>, Intents)>,
     pub(super) shard_presence:
         Option<Box<dyn Fn(u64) -> Option<UpdatePresencePayload> + Send + Sync + 'static>>,
     pub(super) shard_scheme: ShardScheme,
@@ -33,6 +35,19 @@ impl Config {
     pub fn queue(&self) -> &Arc<dyn Queue> {
         &self.queue
     }
+
+    /// Return the intents of a shard if they are overridden.
+    ///
+    /// Refer to [`ClusterBuilder::shard_intents`] for more information.
+    ///
+    /// [`ClusterBuilder::shard_intents`]: super::ClusterBuilder::shard_intents
+    pub fn shard_intents(&self, shard_id: u64) -> Option<Intents> {
+        self.shard_intents
+            .iter()
+            .rev()
+            .find(|(shards, _)| shards.contains(&shard_id))
+            .map(|(_, intents)| *intents)
+    }
 }
 
 impl Debug for Config {
@@ -40,6 +55,7 @@ impl Debug for Config {
         f.debug_struct("Config")
             .field("queue", &self.queue)
             .field("resume_sessions", &self.resume_sessions)
+            .field("shard_intents", &self.shard_intents)
             .field("shard_presence", &"<Fn>")
             .field("shard_scheme", &self.shard_scheme)
             .finish()
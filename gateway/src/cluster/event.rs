@@ -28,6 +28,12 @@ use twilight_model::gateway::event::Event;
 ///
 /// This implements [`futures_util::stream::Stream`].
 ///
+/// # Ordering
+///
+/// Events of an individual shard are yielded in the order the shard received
+/// them, as described by the shard's [`Events`] stream. Events of different
+/// shards are interleaved in no particular order.
+///
 /// # Examples
 ///
 /// Refer to [`Cluster`] for an example of how to use this.
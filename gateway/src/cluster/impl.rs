@@ -10,8 +10,10 @@ use futures_util::{future, stream::SelectAll};
 use std::{
     collections::{hash_map::Values, HashMap},
     error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     iter::FusedIterator,
+    ops::RangeInclusive,
+    time::Duration,
 };
 
 /// Sending a command to a shard failed.
@@ -176,6 +178,16 @@ impl ClusterStartError {
 impl Display for ClusterStartError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ClusterStartErrorType::PrivilegedIntentsUndeclared { intents, shards } => {
+                f.write_str("intents of shards ")?;
+                Display::fmt(shards.start(), f)?;
+                f.write_str("-")?;
+                Display::fmt(shards.end(), f)?;
+                f.write_str(" contain privileged intents ")?;
+                Debug::fmt(intents, f)?;
+
+                f.write_str(" that the cluster's intents don't")
+            }
             ClusterStartErrorType::RetrievingGatewayInfo { .. } => {
                 f.write_str("getting the bot's gateway info failed")
             }
@@ -198,6 +210,17 @@ impl Error for ClusterStartError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ClusterStartErrorType {
+    /// Intents of a range of shards contain privileged intents that the
+    /// cluster's intents don't.
+    ///
+    /// The cluster's intents declare which privileged intents are enabled for
+    /// the application.
+    PrivilegedIntentsUndeclared {
+        /// Privileged intents missing from the cluster's intents.
+        intents: Intents,
+        /// Range of shards the intents were set for.
+        shards: RangeInclusive<u64>,
+    },
     /// Retrieving the bot's gateway information via the HTTP API failed.
     ///
     /// This can occur when using automatic sharding and retrieval of the
@@ -282,6 +305,22 @@ impl Cluster {
             streams: Vec<ShardEventsWithId>,
         }
 
+        let privileged = shard_config.intents.privileged();
+
+        for (shards, intents) in &config.shard_intents {
+            let undeclared = intents.privileged() - privileged;
+
+            if !undeclared.is_empty() {
+                return Err(ClusterStartError {
+                    kind: ClusterStartErrorType::PrivilegedIntentsUndeclared {
+                        intents: undeclared,
+                        shards: shards.clone(),
+                    },
+                    source: None,
+                });
+            }
+        }
+
         let total = config.shard_scheme().total();
 
         #[cfg(feature = "metrics")]
@@ -303,6 +342,10 @@ impl Cluster {
                         shard_config.sequence = Some(data.sequence);
                     }
 
+                    if let Some(intents) = config.shard_intents(idx) {
+                        shard_config.intents = intents;
+                    }
+
                     if let Some(shard_presence) = &config.shard_presence {
                         shard_config.presence = shard_presence(idx)
                     }
@@ -401,6 +444,57 @@ impl Cluster {
         }
     }
 
+    /// Bring down the cluster, waiting for Discord to acknowledge the closes.
+    ///
+    /// All of the shards are closed concurrently with a normal close code, as
+    /// with [`down`]. Each shard waits up to the given timeout for its close to
+    /// be acknowledged before its connection is closed forcefully. Returns once
+    /// all shards are closed, which is at most after the timeout.
+    ///
+    /// Returns the IDs of the shards whose connections had to be closed
+    /// forcefully.
+    ///
+    /// # Examples
+    ///
+    /// Bring down the cluster when deploying a new version of the bot:
+    ///
+    /// ```no_run
+    /// use std::{env, time::Duration};
+    /// use twilight_gateway::{Cluster, Intents};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let (cluster, _events) = Cluster::new(token, Intents::GUILDS).await?;
+    /// cluster.up().await;
+    ///
+    /// // ...
+    ///
+    /// let forced = cluster.down_graceful(Duration::from_secs(5)).await;
+    ///
+    /// if !forced.is_empty() {
+    ///     println!("shards {forced:?} had to be closed forcefully");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`down`]: Self::down
+    pub async fn down_graceful(&self, timeout: Duration) -> Vec<u64> {
+        let shutdowns = self.shards.iter().map(|(id, shard)| async move {
+            if shard.shutdown_graceful(timeout).await {
+                None
+            } else {
+                Some(*id)
+            }
+        });
+
+        future::join_all(shutdowns)
+            .await
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
     /// Bring down the cluster in a resumable way and returns all info needed
     /// for resuming.
     ///
@@ -608,8 +702,15 @@ mod tests {
         Cluster, ClusterCommandError, ClusterCommandErrorType, ClusterSendError,
         ClusterSendErrorType, ClusterStartError, ClusterStartErrorType,
     };
+    use crate::{cluster::ShardScheme, queue::NoOpQueue, Intents};
+    use futures_util::{future, StreamExt};
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::{error::Error, fmt::Debug};
+    use std::{error::Error, fmt::Debug, sync::Arc, time::Duration};
+    use tokio::{
+        net::TcpListener,
+        sync::oneshot::{self, Sender},
+    };
+    use tokio_tungstenite::tungstenite::{protocol::frame::coding::CloseCode, Message};
 
     assert_impl_all!(ClusterCommandErrorType: Debug, Send, Sync);
     assert_fields!(ClusterCommandErrorType::ShardNonexistent: id);
@@ -620,4 +721,51 @@ mod tests {
     assert_impl_all!(ClusterStartErrorType: Debug, Send, Sync);
     assert_impl_all!(ClusterStartError: Error, Send, Sync);
     assert_impl_all!(Cluster: Debug, Send, Sync);
+
+    /// Serve two gateway connections, the first acknowledging its close and
+    /// sending the close code it received, and the second never responding.
+    async fn serve(listener: TcpListener, tx: Sender<Option<CloseCode>>) {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut responsive = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+        tokio::spawn(async move {
+            let mut code = None;
+
+            // Reading the close frame makes tungstenite acknowledge it.
+            while let Some(Ok(message)) = responsive.next().await {
+                if let Message::Close(frame) = message {
+                    code = frame.map(|frame| frame.code);
+                }
+            }
+
+            let _res = tx.send(code);
+        });
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let _unresponsive = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+        future::pending::<()>().await;
+    }
+
+    #[tokio::test]
+    async fn test_down_graceful() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = oneshot::channel();
+        tokio::spawn(serve(listener, tx));
+
+        let (cluster, _events) = Cluster::builder("token".to_owned(), Intents::empty())
+            .gateway_url(format!("ws://{addr}"))
+            .queue(Arc::new(NoOpQueue))
+            .shard_scheme(ShardScheme::try_from((0..=1, 2)).unwrap())
+            .build()
+            .await
+            .unwrap();
+        cluster.up().await;
+
+        let forced = cluster.down_graceful(Duration::from_millis(500)).await;
+
+        assert_eq!(1, forced.len());
+        assert_eq!(Some(CloseCode::Normal), rx.await.unwrap());
+    }
 }
@@ -33,6 +33,8 @@ bitflags! {
         const GATEWAY_RECONNECT = 1 << 9;
         /// Gift code sent in a channel has been updated.
         const GIFT_CODE_UPDATE = 1 << 49;
+        /// An audit log entry has been created in a guild.
+        const GUILD_AUDIT_LOG_ENTRY_CREATE = 1 << 77;
         /// A guild has been created.
         const GUILD_CREATE = 1 << 10;
         /// A guild has been deleted or the current user has been removed from a guild.
@@ -51,6 +53,14 @@ bitflags! {
         const GUILD_SCHEDULED_EVENT_USER_ADD = 1 << 67;
         /// A guild's integrations have been updated.
         const GUILD_SCHEDULED_EVENT_USER_REMOVE = 1 << 68;
+        /// A guild soundboard sound has been created.
+        const GUILD_SOUNDBOARD_SOUND_CREATE = 1 << 71;
+        /// A guild soundboard sound has been deleted.
+        const GUILD_SOUNDBOARD_SOUND_DELETE = 1 << 72;
+        /// A guild soundboard sound has been updated.
+        const GUILD_SOUNDBOARD_SOUND_UPDATE = 1 << 73;
+        /// Multiple guild soundboard sounds have been updated.
+        const GUILD_SOUNDBOARD_SOUNDS_UPDATE = 1 << 74;
         /// A guild's stickers have been updated.
         const GUILD_STICKERS_UPDATE = 1 << 63;
         /// A guild has been updated.
@@ -125,6 +135,9 @@ bitflags! {
         const SHARD_RECONNECTING = 1 << 37;
         /// Shard is resuming a session with the gateway.
         const SHARD_RESUMING = 1 << 38;
+        /// A guild's soundboard sounds have been received in response to a
+        /// request.
+        const SOUNDBOARD_SOUNDS = 1 << 75;
         /// Stage instance was created in a stage channel.
         const STAGE_INSTANCE_CREATE = 1 << 57;
         /// Stage instance was deleted in a stage channel.
@@ -150,6 +163,8 @@ bitflags! {
         const UNAVAILABLE_GUILD = 1 << 40;
         /// Current user's profile has been updated.
         const USER_UPDATE = 1 << 41;
+        /// An effect has been sent in a voice channel.
+        const VOICE_CHANNEL_EFFECT_SEND = 1 << 76;
         /// Voice server has provided an update with voice session details.
         const VOICE_SERVER_UPDATE = 1 << 42;
         /// User's state in a voice channel has been updated.
@@ -206,14 +221,21 @@ impl EventTypeFlags {
     ///
     /// [`Intents::GUILD_BANS`]: crate::Intents::GUILD_BANS
     pub const GUILD_BANS: EventTypeFlags = EventTypeFlags::from_bits_truncate(
-        EventTypeFlags::BAN_ADD.bits() | EventTypeFlags::BAN_REMOVE.bits(),
+        EventTypeFlags::BAN_ADD.bits()
+            | EventTypeFlags::BAN_REMOVE.bits()
+            | EventTypeFlags::GUILD_AUDIT_LOG_ENTRY_CREATE.bits(),
     );
 
     /// All [`EventTypeFlags`] in [`Intents::GUILD_EMOJIS_AND_STICKERS`].
     ///
     /// [`Intents::GUILD_EMOJIS_AND_STICKERS`]: crate::Intents::GUILD_EMOJIS_AND_STICKERS
     pub const GUILD_EMOJIS_AND_STICKERS: EventTypeFlags = EventTypeFlags::from_bits_truncate(
-        EventTypeFlags::GUILD_EMOJIS_UPDATE.bits() | EventTypeFlags::GUILD_STICKERS_UPDATE.bits(),
+        EventTypeFlags::GUILD_EMOJIS_UPDATE.bits()
+            | EventTypeFlags::GUILD_SOUNDBOARD_SOUND_CREATE.bits()
+            | EventTypeFlags::GUILD_SOUNDBOARD_SOUND_DELETE.bits()
+            | EventTypeFlags::GUILD_SOUNDBOARD_SOUND_UPDATE.bits()
+            | EventTypeFlags::GUILD_SOUNDBOARD_SOUNDS_UPDATE.bits()
+            | EventTypeFlags::GUILD_STICKERS_UPDATE.bits(),
     );
 
     /// All [`EventTypeFlags`] in [`Intents::GUILD_INTEGRATIONS`].
@@ -284,8 +306,10 @@ impl EventTypeFlags {
     /// All [`EventTypeFlags`] in [`Intents::GUILD_VOICE_STATES`].
     ///
     /// [`Intents::GUILD_VOICE_STATES`]: crate::Intents::GUILD_VOICE_STATES
-    pub const GUILD_VOICE_STATES: EventTypeFlags =
-        EventTypeFlags::from_bits_truncate(EventTypeFlags::VOICE_STATE_UPDATE.bits());
+    pub const GUILD_VOICE_STATES: EventTypeFlags = EventTypeFlags::from_bits_truncate(
+        EventTypeFlags::VOICE_CHANNEL_EFFECT_SEND.bits()
+            | EventTypeFlags::VOICE_STATE_UPDATE.bits(),
+    );
 
     /// All [`EventTypeFlags`] in [`Intents::GUILD_WEBHOOKS`].
     ///
@@ -310,6 +334,7 @@ impl From<EventType> for EventTypeFlags {
             EventType::GatewayInvalidateSession => EventTypeFlags::GATEWAY_INVALIDATE_SESSION,
             EventType::GatewayReconnect => EventTypeFlags::GATEWAY_RECONNECT,
             EventType::GiftCodeUpdate => EventTypeFlags::GIFT_CODE_UPDATE,
+            EventType::GuildAuditLogEntryCreate => EventTypeFlags::GUILD_AUDIT_LOG_ENTRY_CREATE,
             EventType::GuildCreate => EventTypeFlags::GUILD_CREATE,
             EventType::GuildDelete => EventTypeFlags::GUILD_DELETE,
             EventType::GuildEmojisUpdate => EventTypeFlags::GUILD_EMOJIS_UPDATE,
@@ -321,6 +346,12 @@ impl From<EventType> for EventTypeFlags {
             EventType::GuildScheduledEventUserRemove => {
                 EventTypeFlags::GUILD_SCHEDULED_EVENT_USER_REMOVE
             }
+            EventType::GuildSoundboardSoundCreate => EventTypeFlags::GUILD_SOUNDBOARD_SOUND_CREATE,
+            EventType::GuildSoundboardSoundDelete => EventTypeFlags::GUILD_SOUNDBOARD_SOUND_DELETE,
+            EventType::GuildSoundboardSoundUpdate => EventTypeFlags::GUILD_SOUNDBOARD_SOUND_UPDATE,
+            EventType::GuildSoundboardSoundsUpdate => {
+                EventTypeFlags::GUILD_SOUNDBOARD_SOUNDS_UPDATE
+            }
             EventType::GuildStickersUpdate => EventTypeFlags::GUILD_STICKERS_UPDATE,
             EventType::GuildUpdate => EventTypeFlags::GUILD_UPDATE,
             EventType::IntegrationCreate => EventTypeFlags::INTEGRATION_CREATE,
@@ -355,6 +386,7 @@ impl From<EventType> for EventTypeFlags {
             EventType::ShardReconnecting => EventTypeFlags::SHARD_RECONNECTING,
             EventType::ShardPayload => EventTypeFlags::SHARD_PAYLOAD,
             EventType::ShardResuming => EventTypeFlags::SHARD_RESUMING,
+            EventType::SoundboardSounds => EventTypeFlags::SOUNDBOARD_SOUNDS,
             EventType::StageInstanceCreate => EventTypeFlags::STAGE_INSTANCE_CREATE,
             EventType::StageInstanceDelete => EventTypeFlags::STAGE_INSTANCE_DELETE,
             EventType::StageInstanceUpdate => EventTypeFlags::STAGE_INSTANCE_UPDATE,
@@ -367,6 +399,7 @@ impl From<EventType> for EventTypeFlags {
             EventType::TypingStart => EventTypeFlags::TYPING_START,
             EventType::UnavailableGuild => EventTypeFlags::UNAVAILABLE_GUILD,
             EventType::UserUpdate => EventTypeFlags::USER_UPDATE,
+            EventType::VoiceChannelEffectSend => EventTypeFlags::VOICE_CHANNEL_EFFECT_SEND,
             EventType::VoiceServerUpdate => EventTypeFlags::VOICE_SERVER_UPDATE,
             EventType::VoiceStateUpdate => EventTypeFlags::VOICE_STATE_UPDATE,
             EventType::WebhooksUpdate => EventTypeFlags::WEBHOOKS_UPDATE,
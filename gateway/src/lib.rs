@@ -99,6 +99,14 @@
 //! Enabling **only** `zlib-simd` will make the library use [`zlib-ng`] which is a modern
 //! fork of zlib that is faster and more effective, but it needs `cmake` to compile.
 //!
+//! ### zstd
+//!
+//! The `zstd` feature enables Zstandard compression via the [`zstd`] crate, which
+//! decompresses faster and compresses better than zlib. Shards use it when
+//! configured with `CompressionMethod::Zstd`, while zlib remains the default.
+//!
+//! This is disabled by default.
+//!
 //! ### Metrics
 //!
 //! The `metrics` feature provides metrics information via the `metrics` crate.
@@ -117,6 +125,7 @@
 //! [`twilight-http`]: https://twilight-rs.github.io/twilight/twilight_http/index.html
 //! [`webpki-roots`]: https://crates.io/crates/webpki-roots
 //! [`zlib-ng`]: https://github.com/zlib-ng/zlib-ng
+//! [`zstd`]: https://crates.io/crates/zstd
 //! [codecov badge]: https://img.shields.io/codecov/c/gh/twilight-rs/twilight?logo=codecov&style=for-the-badge&token=E9ERLJL0L2
 //! [codecov link]: https://app.codecov.io/gh/twilight-rs/twilight/
 //! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
@@ -1,4 +1,7 @@
-use super::{Config, Events, Shard, ShardStartError, ShardStartErrorType};
+use super::{
+    CompressionMethod, Config, Events, MetricsSink, NoopMetricsSink, ResumeSession, Shard,
+    ShardStartError, ShardStartErrorType,
+};
 use crate::EventTypeFlags;
 use std::{
     error::Error,
@@ -97,15 +100,22 @@ pub enum ShardIdErrorType {
 /// [`shard`]: Self::shard
 #[derive(Debug)]
 pub struct ShardBuilder {
+    compression: CompressionMethod,
     event_types: EventTypeFlags,
     pub(crate) gateway_url: Option<Box<str>>,
     pub(crate) http_client: Arc<Client>,
     identify_properties: Option<IdentifyProperties>,
     intents: Intents,
     large_threshold: u64,
+    latency_history: usize,
+    max_frame_size: usize,
+    max_resume_attempts: u8,
+    metrics: Arc<dyn MetricsSink>,
     presence: Option<UpdatePresencePayload>,
     queue: Arc<dyn Queue>,
     ratelimit_payloads: bool,
+    raw_event_types: EventTypeFlags,
+    resume_session: Option<ResumeSession>,
     shard: [u64; 2],
     token: Box<str>,
 }
@@ -120,15 +130,22 @@ impl ShardBuilder {
         }
 
         Self {
+            compression: CompressionMethod::Zlib,
             event_types: EventTypeFlags::default(),
             gateway_url: None,
             http_client: Arc::new(Client::new(token.clone())),
             identify_properties: None,
             intents,
             large_threshold: 50,
+            latency_history: 5,
+            max_frame_size: 128 * 1024 * 1024,
+            max_resume_attempts: 3,
+            metrics: Arc::new(NoopMetricsSink),
             presence: None,
             queue: Arc::new(LocalQueue::new()),
             ratelimit_payloads: true,
+            raw_event_types: EventTypeFlags::empty(),
+            resume_session: None,
             shard: [0, 1],
             token: token.into_boxed_str(),
         }
@@ -138,18 +155,32 @@ impl ShardBuilder {
     ///
     /// Panics if `gateway_url` is [`None`]
     pub(crate) fn into_config(self) -> Config {
+        let (session_id, sequence) = match self.resume_session {
+            Some(resume_session) => (
+                Some(resume_session.session_id.into_boxed_str()),
+                Some(resume_session.sequence),
+            ),
+            None => (None, None),
+        };
+
         Config {
+            compression: self.compression,
             event_types: self.event_types,
             gateway_url: self.gateway_url.unwrap(),
             http_client: self.http_client,
             identify_properties: self.identify_properties,
             intents: self.intents,
             large_threshold: self.large_threshold,
+            latency_history: self.latency_history,
+            max_frame_size: self.max_frame_size,
+            max_resume_attempts: self.max_resume_attempts,
+            metrics: self.metrics,
             presence: self.presence,
             queue: self.queue,
             ratelimit_payloads: self.ratelimit_payloads,
-            session_id: None,
-            sequence: None,
+            raw_event_types: self.raw_event_types,
+            session_id,
+            sequence,
             shard: self.shard,
             #[cfg(any(
                 feature = "native",
@@ -195,6 +226,20 @@ impl ShardBuilder {
         Ok(Shard::new_with_config(self.into_config()))
     }
 
+    /// Set the method used by the gateway to compress payloads.
+    ///
+    /// Compressing payloads requires enabling the crate feature of the chosen
+    /// method, refer to [`CompressionMethod`]'s variants. If the feature is
+    /// disabled then payloads are received uncompressed.
+    ///
+    /// Default value is [`CompressionMethod::Zlib`].
+    #[must_use = "has no effect if not built"]
+    pub const fn compression(mut self, compression: CompressionMethod) -> Self {
+        self.compression = compression;
+
+        self
+    }
+
     /// Set the event types to process.
     ///
     /// This is an optimization technique; all events not included in the
@@ -290,6 +335,68 @@ impl ShardBuilder {
         self
     }
 
+    /// Set the number of recent heartbeat latencies to keep.
+    ///
+    /// The latencies of the most recent heartbeats are available via
+    /// [`Latency::recent`], which can be used to monitor the jitter of the
+    /// connection.
+    ///
+    /// Default value is 5.
+    ///
+    /// [`Latency::recent`]: super::Latency::recent
+    #[must_use = "has no effect if not built"]
+    pub const fn latency_history(mut self, latency_history: usize) -> Self {
+        self.latency_history = latency_history;
+
+        self
+    }
+
+    /// Set the maximum size in bytes of a decompressed frame received from the
+    /// gateway.
+    ///
+    /// Frames exceeding this size are discarded before being fully allocated
+    /// and the shard reconnects to the gateway.
+    ///
+    /// Default value is 128 MiB.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "has no effect if not built"]
+    pub fn max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.max_frame_size = max_frame_size;
+
+        self
+    }
+
+    /// Set the maximum number of consecutive attempts to resume a session.
+    ///
+    /// Sessions are resumed against the resume gateway URL provided in the
+    /// [`Ready`] event, if any. If resuming the session fails this many times
+    /// in a row, the shard gives up on the session and identifies a new one on
+    /// the configured gateway URL instead.
+    ///
+    /// Default value is 3.
+    ///
+    /// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "has no effect if not built"]
+    pub fn max_resume_attempts(mut self, max_resume_attempts: u8) -> Self {
+        self.max_resume_attempts = max_resume_attempts;
+
+        self
+    }
+
+    /// Set the sink to report metrics about the shard's connection to.
+    ///
+    /// Refer to [`MetricsSink`] for the reported metrics.
+    ///
+    /// Default value is [`NoopMetricsSink`], which discards all metrics.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "has no effect if not built"]
+    pub fn metrics(mut self, metrics: Arc<dyn MetricsSink>) -> Self {
+        self.metrics = metrics;
+
+        self
+    }
+
     /// Set the presence to use automatically when starting a new session.
     ///
     /// Default is no presence, which defaults to strictly being "online"
@@ -363,6 +470,87 @@ impl ShardBuilder {
         self
     }
 
+    /// Set the event types to send as raw JSON payloads.
+    ///
+    /// Payloads of these event types are sent as [`Event::ShardPayload`]
+    /// before being deserialized, so that handlers on a hot path may parse
+    /// them themselves. If an event type is also included in
+    /// [`event_types`], then the deserialized event is sent after its raw
+    /// payload; otherwise the payload is never deserialized.
+    ///
+    /// Raw payloads are sent even if [`EventTypeFlags::SHARD_PAYLOAD`] isn't
+    /// enabled. If it is, then payloads of these event types are sent twice.
+    ///
+    /// Defaults to no event types.
+    ///
+    /// # Examples
+    ///
+    /// Receive message create events as raw payloads instead of deserializing
+    /// them:
+    ///
+    /// ```no_run
+    /// use std::env;
+    /// use twilight_gateway::{EventTypeFlags, Intents, Shard};
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let event_types = EventTypeFlags::default() - EventTypeFlags::MESSAGE_CREATE;
+    ///
+    /// let shard = Shard::builder(token, Intents::GUILD_MESSAGES)
+    ///     .event_types(event_types)
+    ///     .raw_event_types(EventTypeFlags::MESSAGE_CREATE);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`Event::ShardPayload`]: crate::Event::ShardPayload
+    /// [`EventTypeFlags::SHARD_PAYLOAD`]: crate::EventTypeFlags::SHARD_PAYLOAD
+    /// [`event_types`]: Self::event_types
+    #[must_use = "has no effect if not built"]
+    pub const fn raw_event_types(mut self, raw_event_types: EventTypeFlags) -> Self {
+        self.raw_event_types = raw_event_types;
+
+        self
+    }
+
+    /// Set a session to resume instead of identifying a new session.
+    ///
+    /// The session can be obtained from a previous run of the shard via
+    /// [`Shard::resume_session`] or [`Shard::shutdown_resumable`], such as
+    /// when restarting the process. If the gateway invalidates the session,
+    /// then the shard identifies a new session instead.
+    ///
+    /// Defaults to identifying a new session.
+    ///
+    /// # Examples
+    ///
+    /// Restore a session stored as JSON before restarting:
+    ///
+    /// ```no_run
+    /// use std::env;
+    /// use twilight_gateway::{shard::ResumeSession, Intents, Shard};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let json = env::var("RESUME_SESSION")?;
+    /// let resume_session = serde_json::from_str::<ResumeSession>(&json)?;
+    ///
+    /// let (shard, _events) = Shard::builder(token, Intents::GUILD_MESSAGES)
+    ///     .resume_session(resume_session)
+    ///     .build()
+    ///     .await?;
+    /// shard.start().await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Shard::resume_session`]: super::Shard::resume_session
+    /// [`Shard::shutdown_resumable`]: super::Shard::shutdown_resumable
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "has no effect if not built"]
+    pub fn resume_session(mut self, resume_session: ResumeSession) -> Self {
+        self.resume_session = Some(resume_session);
+
+        self
+    }
+
     /// Set the shard ID to connect as, and the total number of shards used by
     /// the bot.
     ///
@@ -3,25 +3,65 @@
 //! [`Shard::command`]: super::Shard::command
 
 use twilight_model::gateway::payload::outgoing::{
-    identify::Identify, resume::Resume, Heartbeat, RequestGuildMembers, UpdatePresence,
-    UpdateVoiceState,
+    identify::Identify, resume::Resume, Heartbeat, RequestGuildMembers, RequestSoundboardSounds,
+    UpdatePresence, UpdateVoiceState,
 };
 
 mod private {
+    use super::super::processor::{Queueing, StaleKey};
     use serde::Serialize;
     use twilight_model::gateway::payload::outgoing::{
-        identify::Identify, resume::Resume, Heartbeat, RequestGuildMembers, UpdatePresence,
-        UpdateVoiceState,
+        identify::Identify, resume::Resume, Heartbeat, RequestGuildMembers,
+        RequestSoundboardSounds, UpdatePresence, UpdateVoiceState,
     };
 
-    pub trait Sealed: Serialize {}
+    pub trait Sealed: Serialize {
+        /// How the command is handled when sent while the session is not
+        /// connected.
+        fn queueing(&self) -> Queueing;
+    }
 
-    impl Sealed for Heartbeat {}
-    impl Sealed for Identify {}
-    impl Sealed for RequestGuildMembers {}
-    impl Sealed for Resume {}
-    impl Sealed for UpdatePresence {}
-    impl Sealed for UpdateVoiceState {}
+    impl Sealed for Heartbeat {
+        fn queueing(&self) -> Queueing {
+            Queueing::Never
+        }
+    }
+
+    impl Sealed for Identify {
+        fn queueing(&self) -> Queueing {
+            Queueing::Never
+        }
+    }
+
+    impl Sealed for RequestGuildMembers {
+        fn queueing(&self) -> Queueing {
+            Queueing::Ordered
+        }
+    }
+
+    impl Sealed for RequestSoundboardSounds {
+        fn queueing(&self) -> Queueing {
+            Queueing::Ordered
+        }
+    }
+
+    impl Sealed for Resume {
+        fn queueing(&self) -> Queueing {
+            Queueing::Never
+        }
+    }
+
+    impl Sealed for UpdatePresence {
+        fn queueing(&self) -> Queueing {
+            Queueing::Latest(StaleKey::Presence)
+        }
+    }
+
+    impl Sealed for UpdateVoiceState {
+        fn queueing(&self) -> Queueing {
+            Queueing::Latest(StaleKey::VoiceState(self.d.guild_id))
+        }
+    }
 }
 
 /// Trait marker to denote what can be provided to [`Shard::command`].
@@ -39,6 +79,7 @@ pub trait Command: private::Sealed {}
 impl Command for Heartbeat {}
 impl Command for Identify {}
 impl Command for RequestGuildMembers {}
+impl Command for RequestSoundboardSounds {}
 impl Command for Resume {}
 impl Command for UpdatePresence {}
 impl Command for UpdateVoiceState {}
@@ -48,13 +89,14 @@ mod tests {
     use super::Command;
     use static_assertions::assert_impl_all;
     use twilight_model::gateway::payload::outgoing::{
-        identify::Identify, resume::Resume, Heartbeat, RequestGuildMembers, UpdatePresence,
-        UpdateVoiceState,
+        identify::Identify, resume::Resume, Heartbeat, RequestGuildMembers,
+        RequestSoundboardSounds, UpdatePresence, UpdateVoiceState,
     };
 
     assert_impl_all!(Heartbeat: Command);
     assert_impl_all!(Identify: Command);
     assert_impl_all!(RequestGuildMembers: Command);
+    assert_impl_all!(RequestSoundboardSounds: Command);
     assert_impl_all!(Resume: Command);
     assert_impl_all!(UpdatePresence: Command);
     assert_impl_all!(UpdateVoiceState: Command);
@@ -0,0 +1,71 @@
+/// Method used by the gateway to compress the payloads it sends to a shard.
+///
+/// Compressing payloads requires enabling the crate feature of the chosen
+/// method. If the feature is disabled then payloads are received
+/// uncompressed.
+///
+/// Defaults to [`Zlib`].
+///
+/// [`Zlib`]: Self::Zlib
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CompressionMethod {
+    /// Compress payloads as a zlib stream.
+    ///
+    /// Requires the `zlib-stock` or `zlib-simd` feature.
+    Zlib,
+    /// Compress payloads as a Zstandard stream, which is faster to decompress
+    /// and compresses better than zlib.
+    ///
+    /// Requires the `zstd` feature.
+    Zstd,
+}
+
+impl CompressionMethod {
+    /// Name of the method, sent to the gateway when connecting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_gateway::shard::CompressionMethod;
+    ///
+    /// assert_eq!("zstd-stream", CompressionMethod::Zstd.name());
+    /// ```
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Zlib => "zlib-stream",
+            Self::Zstd => "zstd-stream",
+        }
+    }
+}
+
+impl Default for CompressionMethod {
+    fn default() -> Self {
+        Self::Zlib
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompressionMethod;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        CompressionMethod: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_name() {
+        assert_eq!("zlib-stream", CompressionMethod::Zlib.name());
+        assert_eq!("zstd-stream", CompressionMethod::Zstd.name());
+        assert_eq!(CompressionMethod::Zlib, CompressionMethod::default());
+    }
+}
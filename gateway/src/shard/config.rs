@@ -1,3 +1,4 @@
+use super::{CompressionMethod, MetricsSink};
 use crate::EventTypeFlags;
 use std::sync::Arc;
 use twilight_gateway_queue::Queue;
@@ -22,15 +23,21 @@ use super::tls::TlsContainer;
 /// [`Shard::builder`]: super::Shard::builder
 #[derive(Clone, Debug)]
 pub struct Config {
+    pub(super) compression: CompressionMethod,
     pub(super) event_types: EventTypeFlags,
     pub(super) gateway_url: Box<str>,
     pub(super) http_client: Arc<Client>,
     pub(super) identify_properties: Option<IdentifyProperties>,
-    pub(super) intents: Intents,
+    pub(crate) intents: Intents,
     pub(super) large_threshold: u64,
+    pub(super) latency_history: usize,
+    pub(super) max_frame_size: usize,
+    pub(super) max_resume_attempts: u8,
+    pub(crate) metrics: Arc<dyn MetricsSink>,
     pub(crate) presence: Option<UpdatePresencePayload>,
     pub(super) queue: Arc<dyn Queue>,
     pub(crate) ratelimit_payloads: bool,
+    pub(super) raw_event_types: EventTypeFlags,
     pub(crate) session_id: Option<Box<str>>,
     pub(crate) sequence: Option<u64>,
     pub(crate) shard: [u64; 2],
@@ -44,6 +51,11 @@ pub struct Config {
 }
 
 impl Config {
+    /// Copy of the method used by the gateway to compress payloads.
+    pub const fn compression(&self) -> CompressionMethod {
+        self.compression
+    }
+
     /// Copy of the event type flags.
     pub const fn event_types(&self) -> EventTypeFlags {
         self.event_types
@@ -77,6 +89,23 @@ impl Config {
         self.large_threshold
     }
 
+    /// Return the number of recent heartbeat latencies to keep.
+    pub const fn latency_history(&self) -> usize {
+        self.latency_history
+    }
+
+    /// Return the maximum size in bytes of a decompressed frame received from
+    /// the gateway.
+    pub const fn max_frame_size(&self) -> usize {
+        self.max_frame_size
+    }
+
+    /// Return the maximum number of consecutive attempts to resume a session
+    /// before identifying a new session.
+    pub const fn max_resume_attempts(&self) -> u8 {
+        self.max_resume_attempts
+    }
+
     /// Return an immutable reference to the presence to set when identifying
     /// with the gateway.
     ///
@@ -91,6 +120,11 @@ impl Config {
         self.ratelimit_payloads
     }
 
+    /// Copy of the event type flags of payloads sent raw.
+    pub const fn raw_event_types(&self) -> EventTypeFlags {
+        self.raw_event_types
+    }
+
     /// The shard's ID and the total number of shards used by the bot.
     pub const fn shard(&self) -> [u64; 2] {
         self.shard
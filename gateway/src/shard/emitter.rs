@@ -64,15 +64,26 @@ pub enum EmitJsonErrorType {
 #[derive(Clone, Debug)]
 pub struct Emitter {
     event_types: EventTypeFlags,
+    raw_event_types: EventTypeFlags,
     tx: UnboundedSender<Event>,
 }
 
 impl Emitter {
     /// Create a new emitter for events and bytes.
-    pub fn new(event_types: EventTypeFlags) -> (Self, UnboundedReceiver<Event>) {
+    pub fn new(
+        event_types: EventTypeFlags,
+        raw_event_types: EventTypeFlags,
+    ) -> (Self, UnboundedReceiver<Event>) {
         let (tx, rx) = mpsc::unbounded_channel();
 
-        (Self { event_types, tx }, rx)
+        (
+            Self {
+                event_types,
+                raw_event_types,
+                tx,
+            },
+            rx,
+        )
     }
 
     /// Whether the configured event types include an individual event type.
@@ -97,6 +108,19 @@ impl Emitter {
         }
     }
 
+    /// Send the JSON payload of an event to the listener if it has
+    /// subscribed to receiving its event type raw.
+    ///
+    /// Must be called before the payload is deserialized, since
+    /// deserialization may modify the payload in place.
+    pub fn raw(&self, event_type: EventTypeFlags, json: &[u8]) {
+        if !event_type.is_empty() && self.raw_event_types.contains(event_type) {
+            self.send(Event::ShardPayload(Payload {
+                bytes: json.to_vec(),
+            }))
+        }
+    }
+
     /// Send an event to the listener if it has subscribed to its event type.
     #[tracing::instrument(level = "trace")]
     pub fn event(&self, event: Event) {
@@ -110,6 +134,9 @@ impl Emitter {
     /// Emit a JSON payload that hasn't been deserialized yet, but only if the
     /// listener wants the event type.
     ///
+    /// The payload is deserialized before returning so that events are sent
+    /// to the listener in the order the payloads were received.
+    ///
     /// # Errors
     ///
     /// Returns a [`EmitJsonErrorType::EventTypeUnknown`] error type if the
@@ -135,6 +162,8 @@ impl Emitter {
             }
         })?;
 
+        self.raw(flag, json);
+
         if self.wants(flag) {
             let gateway_event =
                 json::parse_gateway_event(op, seq, event_type, json).map_err(|source| {
@@ -158,19 +187,51 @@ impl Emitter {
 mod tests {
     use super::Emitter;
     use crate::{Event, EventTypeFlags};
+    use std::fmt::Write;
 
     #[test]
     fn test_bytes_send() {
-        let (emitter, mut rx) = Emitter::new(EventTypeFlags::SHARD_PAYLOAD);
+        let (emitter, mut rx) =
+            Emitter::new(EventTypeFlags::SHARD_PAYLOAD, EventTypeFlags::empty());
         emitter.bytes(&[1]);
 
         assert!(rx.try_recv().is_ok());
         assert!(rx.try_recv().is_err());
     }
 
+    #[test]
+    fn test_json_raw() {
+        const JSON: &str = r#"{"op":0,"s":1,"t":"TYPING_START","d":{"channel_id":"1","timestamp":1,"user_id":"2"}}"#;
+
+        // Raw instead of deserialized.
+        let (emitter, mut rx) = Emitter::new(EventTypeFlags::empty(), EventTypeFlags::TYPING_START);
+        let mut bytes = JSON.as_bytes().to_vec();
+        emitter
+            .json(0, Some(1), Some("TYPING_START"), &mut bytes)
+            .unwrap();
+
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(Event::ShardPayload(payload)) if payload.bytes == JSON.as_bytes()
+        ));
+        assert!(rx.try_recv().is_err());
+
+        // Raw alongside deserialized.
+        let (emitter, mut rx) =
+            Emitter::new(EventTypeFlags::TYPING_START, EventTypeFlags::TYPING_START);
+        let mut bytes = JSON.as_bytes().to_vec();
+        emitter
+            .json(0, Some(1), Some("TYPING_START"), &mut bytes)
+            .unwrap();
+
+        assert!(matches!(rx.try_recv(), Ok(Event::ShardPayload(_))));
+        assert!(matches!(rx.try_recv(), Ok(Event::TypingStart(_))));
+        assert!(rx.try_recv().is_err());
+    }
+
     #[test]
     fn test_event_sends_to_rx() {
-        let (emitter, mut rx) = Emitter::new(EventTypeFlags::default());
+        let (emitter, mut rx) = Emitter::new(EventTypeFlags::default(), EventTypeFlags::empty());
         emitter.event(Event::GatewayReconnect);
 
         assert!(rx.try_recv().is_ok());
@@ -178,4 +239,62 @@ mod tests {
         // now check that the event didn't send the event twice
         assert!(rx.try_recv().is_err());
     }
+
+    /// Test that events are emitted in the order their payloads are received,
+    /// even when payloads that are slow to parse are interleaved with
+    /// payloads that are fast to parse.
+    #[test]
+    fn test_json_order() {
+        let (emitter, mut rx) = Emitter::new(
+            EventTypeFlags::MESSAGE_DELETE_BULK | EventTypeFlags::TYPING_START,
+            EventTypeFlags::empty(),
+        );
+
+        for seq in 1..=10 {
+            let (event_type, json) = if seq % 2 == 0 {
+                let mut ids = String::new();
+
+                for id in 1..=10_000 {
+                    if id > 1 {
+                        ids.push(',');
+                    }
+
+                    write!(ids, r#""{id}""#).unwrap();
+                }
+
+                (
+                    "MESSAGE_DELETE_BULK",
+                    format!(
+                        r#"{{"op":0,"s":{seq},"t":"MESSAGE_DELETE_BULK","d":{{"channel_id":"{seq}","ids":[{ids}]}}}}"#
+                    ),
+                )
+            } else {
+                (
+                    "TYPING_START",
+                    format!(
+                        r#"{{"op":0,"s":{seq},"t":"TYPING_START","d":{{"channel_id":"{seq}","timestamp":1,"user_id":"1"}}}}"#
+                    ),
+                )
+            };
+
+            let mut bytes = json.into_bytes();
+            emitter
+                .json(0, Some(seq), Some(event_type), &mut bytes)
+                .unwrap();
+        }
+
+        let mut channel_ids = Vec::new();
+
+        while let Ok(event) = rx.try_recv() {
+            let channel_id = match event {
+                Event::MessageDeleteBulk(delete) => delete.channel_id,
+                Event::TypingStart(typing) => typing.channel_id,
+                other => panic!("unexpected event: {other:?}"),
+            };
+
+            channel_ids.push(channel_id.get());
+        }
+
+        assert_eq!((1..=10).collect::<Vec<_>>(), channel_ids);
+    }
 }
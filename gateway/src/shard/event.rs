@@ -13,7 +13,7 @@
 //! [`ShardBuilder::event_types`]: crate::shard::ShardBuilder::event_types
 
 use crate::EventTypeFlags;
-use futures_util::stream::Stream;
+use futures_util::stream::{FusedStream, Stream};
 use std::{
     pin::Pin,
     task::{Context, Poll},
@@ -27,24 +27,75 @@ use twilight_model::gateway::event::Event;
 /// event types returned by [`Events::event_types`] to see what events can come
 /// in through this stream.
 ///
-/// This implements [`futures::stream::Stream`].
+/// This implements [`futures::stream::Stream`], so combinators of
+/// [`StreamExt`] can be used on it. The shard's connection is driven by a
+/// background task, so the stream doesn't have to be polled for the shard to
+/// stay connected; events received in the meantime are buffered. Connection
+/// errors are handled by the shard and surface as shard events such as
+/// [`Event::ShardDisconnected`] instead of being yielded.
+///
+/// The stream ends once the shard has been shut down.
+///
+/// # Ordering
+///
+/// Events are yielded in the order the shard received them from the gateway.
+/// Each payload is decompressed and deserialized in full before the next
+/// payload is read, so an event that is slow to deserialize, such as a large
+/// [`GuildCreate`], is never overtaken by events received after it.
 ///
 /// # Examples
 ///
-/// Refer to [`Shard`] for an example of how to use this.
+/// Wait for the first message containing "ping":
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use futures::StreamExt;
+/// use std::env;
+/// use twilight_gateway::{Event, Intents, Shard};
+///
+/// let intents = Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT;
+/// let (shard, events) = Shard::new(env::var("DISCORD_TOKEN")?, intents).await?;
+/// shard.start().await?;
+///
+/// let mut pings = events
+///     .filter_map(|event| async move {
+///         match event {
+///             Event::MessageCreate(message) if message.content.contains("ping") => {
+///                 Some(message)
+///             }
+///             _ => None,
+///         }
+///     })
+///     .boxed();
+///
+/// if let Some(message) = pings.next().await {
+///     println!("ping in channel {}", message.channel_id);
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// Refer to [`Shard`] for more examples of how to use this.
 ///
 /// [`Events::event_types`]: Self::event_types
+/// [`StreamExt`]: https://docs.rs/futures/*/futures/stream/trait.StreamExt.html
+/// [`GuildCreate`]: twilight_model::gateway::payload::incoming::GuildCreate
 /// [`Shard`]: super::Shard
 /// [`futures::stream::Stream`]: https://docs.rs/futures/*/futures/stream/trait.Stream.html
 #[derive(Debug)]
 pub struct Events {
     event_types: EventTypeFlags,
     rx: UnboundedReceiver<Event>,
+    /// Whether the stream has ended.
+    terminated: bool,
 }
 
 impl Events {
     pub(super) const fn new(event_types: EventTypeFlags, rx: UnboundedReceiver<Event>) -> Self {
-        Self { event_types, rx }
+        Self {
+            event_types,
+            rx,
+            terminated: false,
+        }
     }
 
     /// Returns the event types that can be passed to this stream.
@@ -57,16 +108,50 @@ impl Stream for Events {
     type Item = Event;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.rx.poll_recv(cx)
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        let poll = self.rx.poll_recv(cx);
+        self.terminated = matches!(poll, Poll::Ready(None));
+
+        poll
+    }
+}
+
+impl FusedStream for Events {
+    fn is_terminated(&self) -> bool {
+        self.terminated
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Events;
-    use futures_util::stream::Stream;
+    use crate::EventTypeFlags;
+    use futures_util::stream::{FusedStream, Stream, StreamExt};
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
+    use tokio::sync::mpsc;
+    use twilight_model::gateway::event::Event;
 
-    assert_impl_all!(Events: Debug, Send, Stream, Sync);
+    assert_impl_all!(Events: Debug, FusedStream, Send, Stream, Sync);
+
+    #[tokio::test]
+    async fn test_terminated() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut events = Events::new(EventTypeFlags::all(), rx);
+
+        tx.send(Event::GatewayHeartbeatAck).unwrap();
+        drop(tx);
+
+        assert!(matches!(
+            events.next().await,
+            Some(Event::GatewayHeartbeatAck)
+        ));
+        assert!(!events.is_terminated());
+        assert!(events.next().await.is_none());
+        assert!(events.is_terminated());
+        assert!(events.next().await.is_none());
+    }
 }
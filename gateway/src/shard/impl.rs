@@ -5,7 +5,10 @@ use super::{
     emitter::Emitter,
     event::Events,
     json,
-    processor::{ConnectingErrorType, Latency, Session, ShardProcessor},
+    processor::{
+        CommandQueue, ConnectingErrorType, Latency, QueueFullError, Queueing, Session,
+        ShardProcessor,
+    },
     raw_message::Message,
     stage::Stage,
 };
@@ -15,17 +18,22 @@ use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
     error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     sync::{atomic::Ordering, Arc, Mutex},
+    time::Duration,
 };
 use tokio::{
     sync::{watch::Receiver as WatchReceiver, OnceCell},
     task::JoinHandle,
-    time::Instant,
+    time::{self, Instant},
 };
 use tokio_tungstenite::tungstenite::protocol::{
     frame::coding::CloseCode, CloseFrame as TungsteniteCloseFrame,
 };
+use twilight_model::gateway::{
+    payload::outgoing::{update_presence::UpdatePresencePayload, UpdatePresence},
+    OpCode,
+};
 
 /// Sending a command failed.
 #[derive(Debug)]
@@ -69,6 +77,13 @@ impl CommandError {
             source,
         }
     }
+
+    pub(crate) const fn from_queue_full(_: QueueFullError) -> Self {
+        Self {
+            kind: CommandErrorType::QueueFull,
+            source: None,
+        }
+    }
 }
 
 impl Display for CommandError {
@@ -77,6 +92,9 @@ impl Display for CommandError {
             CommandErrorType::HeartbeaterNotStarted => {
                 f.write_str("heartbeater task hasn't been started yet")
             }
+            CommandErrorType::QueueFull => {
+                f.write_str("command queue of the reconnecting shard is full")
+            }
             CommandErrorType::Sending => {
                 f.write_str("sending the message over the websocket failed")
             }
@@ -100,6 +118,9 @@ impl Error for CommandError {
 pub enum CommandErrorType {
     /// Heartbeater task has not been started yet.
     HeartbeaterNotStarted,
+    /// Shard is reconnecting and its queue of commands to send once
+    /// connected is full.
+    QueueFull,
     /// Sending the payload over the WebSocket failed. This is indicative of a
     /// shutdown shard.
     Sending,
@@ -186,6 +207,128 @@ pub enum SendErrorType {
     SessionInactive,
 }
 
+/// Gateway closed the connection of a shard for a reason that can't be
+/// recovered from by reconnecting.
+///
+/// Retrieved via [`Shard::fatal_error`] once the shard has stopped.
+#[derive(Debug)]
+pub struct FatalError {
+    pub(super) kind: FatalErrorType,
+}
+
+impl FatalError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &FatalErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (FatalErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for FatalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            FatalErrorType::ApiVersionInvalid => {
+                f.write_str("the gateway doesn't support the api version used to identify")
+            }
+            FatalErrorType::AuthorizationInvalid { shard_id } => {
+                f.write_str("the authorization token of shard ")?;
+                Display::fmt(shard_id, f)?;
+
+                f.write_str(" is invalid")
+            }
+            FatalErrorType::IntentsDisallowed { intents, shard_id } => {
+                f.write_str("shard ")?;
+                Display::fmt(shard_id, f)?;
+                f.write_str(" requested privileged intents (")?;
+                Debug::fmt(intents, f)?;
+
+                f.write_str(
+                    ") that aren't enabled for the application; enable them in the developer \
+                    portal or remove them from the shard's intents",
+                )
+            }
+            FatalErrorType::IntentsInvalid { intents, shard_id } => {
+                f.write_str("at least one of the intents (")?;
+                Debug::fmt(intents, f)?;
+                f.write_str(") of shard ")?;
+                Display::fmt(shard_id, f)?;
+
+                f.write_str(" is invalid")
+            }
+            FatalErrorType::ShardInvalid {
+                shard_count,
+                shard_id,
+            } => {
+                f.write_str("shard [")?;
+                Display::fmt(shard_id, f)?;
+                f.write_str(", ")?;
+                Display::fmt(shard_count, f)?;
+
+                f.write_str("] is invalid")
+            }
+            FatalErrorType::ShardingRequired => {
+                f.write_str("the session handles too many guilds, sharding is required")
+            }
+        }
+    }
+}
+
+impl Error for FatalError {}
+
+/// Type of [`FatalError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FatalErrorType {
+    /// Gateway doesn't support the API version used to identify.
+    ApiVersionInvalid,
+    /// Provided authorization token is invalid.
+    AuthorizationInvalid {
+        /// ID of the shard.
+        shard_id: u64,
+    },
+    /// Shard requested privileged intents that aren't enabled for the
+    /// application.
+    ///
+    /// Privileged intents must be enabled in the developer portal, and
+    /// applications in more than 100 guilds must be approved for them. Refer to
+    /// [`Intents::PRIVILEGED`] for the list of privileged intents.
+    IntentsDisallowed {
+        /// Privileged intents requested by the shard.
+        intents: Intents,
+        /// ID of the shard.
+        shard_id: u64,
+    },
+    /// Configured intents aren't supported by the gateway.
+    IntentsInvalid {
+        /// Configured intents of the shard.
+        intents: Intents,
+        /// ID of the shard.
+        shard_id: u64,
+    },
+    /// Shard ID or shard count used to identify is invalid.
+    ShardInvalid {
+        /// Total number of shards.
+        shard_count: u64,
+        /// ID of the shard.
+        shard_id: u64,
+    },
+    /// Session handles too many guilds, so sharding is required.
+    ShardingRequired,
+}
+
 /// Starting a shard and connecting to the gateway failed.
 #[derive(Debug)]
 pub struct ShardStartError {
@@ -266,6 +409,7 @@ pub enum ShardStartErrorType {
 /// and connection stage.
 #[derive(Clone, Debug)]
 pub struct Information {
+    heartbeat_interval: Option<Duration>,
     id: u64,
     latency: Latency,
     ratelimit_refill: Option<Instant>,
@@ -276,6 +420,14 @@ pub struct Information {
 }
 
 impl Information {
+    /// Interval between heartbeats, as sent by the gateway in its hello
+    /// payload.
+    ///
+    /// This will be `None` if the hello payload hasn't been received yet.
+    pub const fn heartbeat_interval(&self) -> Option<Duration> {
+        self.heartbeat_interval
+    }
+
     /// Return the ID of the shard.
     pub const fn id(&self) -> u64 {
         self.id
@@ -284,7 +436,7 @@ impl Information {
     /// Return an immutable reference to the latency information for the shard.
     ///
     /// This includes the average latency over all time, and the latency
-    /// information for the 5 most recent heartbeats.
+    /// information for the most recent heartbeats.
     pub const fn latency(&self) -> &Latency {
         &self.latency
     }
@@ -403,7 +555,9 @@ pub struct ResumeSession {
 pub struct Shard {
     config: Arc<Config>,
     emitter: Mutex<Option<Emitter>>,
+    fatal_error: Arc<OnceCell<FatalError>>,
     processor_handle: OnceCell<JoinHandle<()>>,
+    queue: Arc<CommandQueue>,
     session: OnceCell<WatchReceiver<Arc<Session>>>,
 }
 
@@ -451,12 +605,14 @@ impl Shard {
         let config = Arc::new(config);
         let event_types = config.event_types();
 
-        let (emitter, rx) = Emitter::new(event_types);
+        let (emitter, rx) = Emitter::new(event_types, config.raw_event_types());
 
         let this = Self {
             config,
             emitter: Mutex::new(Some(emitter)),
+            fatal_error: Arc::new(OnceCell::new()),
             processor_handle: OnceCell::new(),
+            queue: Arc::new(CommandQueue::default()),
             session: OnceCell::new(),
         };
 
@@ -507,23 +663,28 @@ impl Shard {
             })?;
 
         let config = Arc::clone(&self.config);
-        let (processor, wrx) = ShardProcessor::new(config, emitter)
-            .await
-            .map_err(|source| {
-                let (kind, source) = source.into_parts();
-
-                let new_kind = match kind {
-                    ConnectingErrorType::Establishing => ShardStartErrorType::Establishing,
-                    ConnectingErrorType::ParsingUrl { url } => {
-                        ShardStartErrorType::ParsingGatewayUrl { url }
-                    }
-                };
-
-                ShardStartError {
-                    source,
-                    kind: new_kind,
+        let (processor, wrx) = ShardProcessor::new(
+            config,
+            emitter,
+            Arc::clone(&self.fatal_error),
+            Arc::clone(&self.queue),
+        )
+        .await
+        .map_err(|source| {
+            let (kind, source) = source.into_parts();
+
+            let new_kind = match kind {
+                ConnectingErrorType::Establishing => ShardStartErrorType::Establishing,
+                ConnectingErrorType::ParsingUrl { url } => {
+                    ShardStartErrorType::ParsingGatewayUrl { url }
                 }
-            })?;
+            };
+
+            ShardStartError {
+                source,
+                kind: new_kind,
+            }
+        })?;
 
         let handle = tokio::spawn(async {
             processor.run().await;
@@ -538,6 +699,41 @@ impl Shard {
         Ok(())
     }
 
+    /// Error that stopped the shard, if the gateway closed its connection for
+    /// a reason that can't be recovered from.
+    ///
+    /// The shard doesn't reconnect after such an error, and its event stream
+    /// ends once the error is available. This is commonly caused by requesting
+    /// privileged intents that aren't enabled for the application.
+    ///
+    /// # Examples
+    ///
+    /// Print why the shard stopped once its event stream ends:
+    ///
+    /// ```no_run
+    /// use futures::stream::StreamExt;
+    /// use std::env;
+    /// use twilight_gateway::{Intents, Shard};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let (shard, mut events) = Shard::new(token, Intents::GUILD_MEMBERS).await?;
+    /// shard.start().await?;
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     println!("received event: {:?}", event.kind());
+    /// }
+    ///
+    /// if let Some(error) = shard.fatal_error() {
+    ///     eprintln!("shard stopped: {error}");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn fatal_error(&self) -> Option<&FatalError> {
+        self.fatal_error.get()
+    }
+
     /// Retrieve information about the running of the shard, such as the current
     /// connection stage.
     ///
@@ -557,7 +753,13 @@ impl Shard {
             return Err(SessionInactiveError);
         };
 
+        let heartbeat_interval = match session.heartbeat_interval() {
+            0 => None,
+            interval => Some(Duration::from_millis(interval)),
+        };
+
         Ok(Information {
+            heartbeat_interval,
             id: self.config().shard()[0],
             latency: session.heartbeats.latency(),
             ratelimit_refill,
@@ -568,6 +770,23 @@ impl Shard {
         })
     }
 
+    /// Retrieve the latency of the shard's heartbeats.
+    ///
+    /// This includes the average latency over the session and the latencies of
+    /// the most recent heartbeats, the number of which is configured via
+    /// [`ShardBuilder::latency_history`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SessionInactiveError`] if the shard's session is inactive.
+    ///
+    /// [`ShardBuilder::latency_history`]: super::ShardBuilder::latency_history
+    pub fn latency(&self) -> Result<Latency, SessionInactiveError> {
+        let session = self.session()?;
+
+        Ok(session.heartbeats.latency())
+    }
+
     /// Send a command over the gateway.
     ///
     /// # Examples
@@ -607,8 +826,23 @@ impl Shard {
     /// # Ok(()) }
     /// ```
     ///
+    /// # Queueing
+    ///
+    /// Commands sent while the shard is reconnecting or resuming are held
+    /// until the new session is connected, after which they're sent in order.
+    /// A queued [`UpdatePresence`], or an [`UpdateVoiceState`] for the same
+    /// guild, replaces the previously queued one, which is dropped as stale.
+    /// Refer to [`stale_commands`] for the number of stale commands.
+    ///
+    /// Session-bound commands, such as [`Heartbeat`], [`Identify`], and
+    /// [`Resume`], are never queued. At most 120 commands are queued, after
+    /// which further commands are rejected until the shard is connected.
+    ///
     /// # Errors
     ///
+    /// Returns a [`CommandErrorType::QueueFull`] error type if the shard is
+    /// reconnecting and too many commands are already queued.
+    ///
     /// Returns a [`CommandErrorType::Sending`] error type if the message could
     /// not be sent over the websocket. This indicates the shard is currently
     /// restarting.
@@ -619,16 +853,112 @@ impl Shard {
     /// Returns a [`CommandErrorType::SessionInactive`] error type if the shard
     /// has not been started.
     ///
-    /// [`UpdatePresence`]: twilight_model::gateway::payload::outgoing::UpdatePresence
+    /// [`Heartbeat`]: twilight_model::gateway::payload::outgoing::Heartbeat
+    /// [`Identify`]: twilight_model::gateway::payload::outgoing::Identify
+    /// [`Resume`]: twilight_model::gateway::payload::outgoing::Resume
+    /// [`UpdateVoiceState`]: twilight_model::gateway::payload::outgoing::UpdateVoiceState
+    /// [`stale_commands`]: Self::stale_commands
     pub async fn command(&self, value: &impl Command) -> Result<(), CommandError> {
         let json = json::to_vec(value).map_err(|source| CommandError {
             source: Some(Box::new(source)),
             kind: CommandErrorType::Serializing,
         })?;
 
-        self.send(Message::Binary(json))
-            .await
-            .map_err(CommandError::from_send)
+        let key = match value.queueing() {
+            Queueing::Never => {
+                return self
+                    .send(Message::Binary(json))
+                    .await
+                    .map_err(CommandError::from_send)
+            }
+            Queueing::Ordered => None,
+            Queueing::Latest(key) => Some(key),
+        };
+
+        let json = match self
+            .queue
+            .push(&*self.command_session()?, key, json)
+            .map_err(CommandError::from_queue_full)?
+        {
+            Some(json) => json,
+            None => return Ok(()),
+        };
+
+        if let Err(source) = self.send(Message::Binary(json.clone())).await {
+            // The session may have closed since it was checked, in which case
+            // the command is held for the next one.
+            if !matches!(source.kind(), SendErrorType::Sending)
+                || self
+                    .queue
+                    .push(&*self.command_session()?, key, json)
+                    .map_err(CommandError::from_queue_full)?
+                    .is_some()
+            {
+                return Err(CommandError::from_send(source));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update the presence of the shard's session.
+    ///
+    /// This sends an [`UpdatePresence`] command via [`command`], so the
+    /// presence is queued while the shard is reconnecting. To set the
+    /// presence when identifying, use [`ShardBuilder::presence`] instead.
+    ///
+    /// # Examples
+    ///
+    /// Show the bot as watching the server:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::env;
+    /// use twilight_gateway::{shard::Shard, Intents};
+    /// use twilight_model::gateway::{
+    ///     payload::outgoing::update_presence::UpdatePresencePayload,
+    ///     presence::{Activity, ActivityType, MinimalActivity, Status},
+    /// };
+    ///
+    /// let (shard, _events) = Shard::new(env::var("DISCORD_TOKEN")?, Intents::GUILDS).await?;
+    /// shard.start().await?;
+    ///
+    /// let activity = Activity::from(MinimalActivity {
+    ///     kind: ActivityType::Watching,
+    ///     name: "the server".to_owned(),
+    ///     url: None,
+    /// });
+    /// let presence = UpdatePresencePayload::new([activity], false, None, Status::Online)?;
+    /// shard.update_presence(presence).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of [`command`] for possible errors.
+    ///
+    /// [`ShardBuilder::presence`]: super::ShardBuilder::presence
+    /// [`command`]: Self::command
+    pub async fn update_presence(
+        &self,
+        presence: UpdatePresencePayload,
+    ) -> Result<(), CommandError> {
+        self.command(&UpdatePresence {
+            d: presence,
+            op: OpCode::PresenceUpdate,
+        })
+        .await
+    }
+
+    /// Number of queued commands dropped because a newer command replaced
+    /// them while the shard was reconnecting.
+    ///
+    /// Refer to [`command`] for which commands are queued.
+    ///
+    /// [`command`]: Self::command
+    pub fn stale_commands(&self) -> u64 {
+        self.queue.stale()
     }
 
     /// Send a raw websocket message.
@@ -737,14 +1067,94 @@ impl Shard {
         }
     }
 
+    /// Shut down the shard, waiting for Discord to acknowledge the close.
+    ///
+    /// Like [`shutdown`], the shard will cleanly close the connection by
+    /// sending a normal close code and the session will not be resumable. This
+    /// additionally waits up to the given timeout for Discord to acknowledge
+    /// the close before returning. If the close isn't acknowledged in time the
+    /// connection is closed forcefully.
+    ///
+    /// Returns whether the close was acknowledged in time. Shards without an
+    /// active session are considered to be closed.
+    ///
+    /// [`shutdown`]: Self::shutdown
+    pub async fn shutdown_graceful(&self, timeout: Duration) -> bool {
+        let session = if let Ok(session) = self.session() {
+            session
+        } else {
+            if let Some(processor_handle) = self.processor_handle.get() {
+                processor_handle.abort();
+            }
+
+            return true;
+        };
+
+        session.stop_heartbeater();
+
+        // The processor keeps handling messages until the close is
+        // acknowledged, such as dispatches the gateway sent before it, but
+        // doesn't resume the session once the connection ends.
+        let acknowledged = if session
+            .close_permanently(Some(TungsteniteCloseFrame {
+                code: CloseCode::Normal,
+                reason: "".into(),
+            }))
+            .is_err()
+        {
+            // The socket forwarder has already stopped.
+            true
+        } else {
+            // The socket forwarder drops its receiver once the socket is
+            // closed.
+            time::timeout(timeout, session.tx.closed()).await.is_ok()
+        };
+
+        if !acknowledged {
+            tracing::debug!(
+                "shard {:?} close not acknowledged in time, closing forcefully",
+                self.config().shard()
+            );
+            session.forwarder_handle.abort();
+        }
+
+        if let Some(processor_handle) = self.processor_handle.get() {
+            processor_handle.abort();
+        }
+
+        acknowledged
+    }
+
+    /// Details to resume the shard's current session, such as after
+    /// restarting the process.
+    ///
+    /// Pass the details to [`ShardBuilder::resume_session`] to resume the
+    /// session with a new shard. [`ResumeSession`] implements serde's traits,
+    /// so it can be persisted across process restarts.
+    ///
+    /// Returns `None` if the shard's session is inactive or hasn't been
+    /// identified yet.
+    ///
+    /// [`ShardBuilder::resume_session`]: super::ShardBuilder::resume_session
+    pub fn resume_session(&self) -> Option<ResumeSession> {
+        let session = self.session().ok()?;
+
+        Some(ResumeSession {
+            session_id: session.id()?.into_string(),
+            sequence: session.seq(),
+        })
+    }
+
     /// Shut down the shard in a resumable fashion.
     ///
     /// The shard will cleanly close the connection by sending a restart close
     /// code, causing Discord to keep the bot as showing online. The connection
     /// will be resumable by using the provided session resume information
-    /// to [`ClusterBuilder::resume_sessions`].
+    /// to [`ClusterBuilder::resume_sessions`] or
+    /// [`ShardBuilder::resume_session`].
     ///
     /// [`ClusterBuilder::resume_sessions`]: crate::cluster::ClusterBuilder::resume_sessions
+    /// [`ShardBuilder::resume_session`]: super::ShardBuilder::resume_session
     pub fn shutdown_resumable(&self) -> (u64, Option<ResumeSession>) {
         if let Some(processor_handle) = self.processor_handle.get() {
             processor_handle.abort();
@@ -786,6 +1196,19 @@ impl Shard {
 
         Ok(Arc::clone(&session.borrow()))
     }
+
+    /// Retrieve the session to send a command over.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CommandErrorType::SessionInactive`] error type if the
+    /// shard's session is inactive.
+    fn command_session(&self) -> Result<Arc<Session>, CommandError> {
+        self.session().map_err(|source| CommandError {
+            kind: CommandErrorType::SessionInactive,
+            source: Some(Box::new(source)),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,63 @@
+//! Sink for metrics about a shard's connection to the gateway.
+
+use std::{fmt::Debug, time::Duration};
+
+/// Sink receiving metrics about a shard's connection to the gateway.
+///
+/// The shard calls the sink on key events of its session, allowing metrics to
+/// be exported to a monitoring system such as Prometheus or statsd without
+/// polling the shard. Register a sink via [`ShardBuilder::metrics`]; the
+/// default is [`NoopMetricsSink`].
+///
+/// Methods are called from the task processing the shard's events and must
+/// not block.
+///
+/// [`ShardBuilder::metrics`]: super::ShardBuilder::metrics
+pub trait MetricsSink: Debug + Send + Sync {
+    /// Payload was received from the gateway.
+    ///
+    /// `event_type` is the type of dispatch events, such as `MESSAGE_CREATE`,
+    /// and is [`None`] for other opcodes.
+    fn event_received(&self, shard_id: u64, op: u8, event_type: Option<&str>);
+
+    /// Gateway acknowledged a heartbeat.
+    ///
+    /// `latency` is the time since the heartbeat was sent, or [`None`] if the
+    /// acknowledgement was received before any heartbeat was sent.
+    fn heartbeat_ack(&self, shard_id: u64, latency: Option<Duration>);
+
+    /// Shard is identifying with the gateway to create a new session.
+    fn identify(&self, shard_id: u64);
+
+    /// Shard is reconnecting to the gateway.
+    ///
+    /// `resume` is whether the shard is attempting to resume its session
+    /// rather than creating a new one.
+    fn reconnect(&self, shard_id: u64, resume: bool);
+}
+
+/// Sink discarding all metrics.
+///
+/// This is the default sink of shards.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopMetricsSink;
+
+impl MetricsSink for NoopMetricsSink {
+    fn event_received(&self, _: u64, _: u8, _: Option<&str>) {}
+
+    fn heartbeat_ack(&self, _: u64, _: Option<Duration>) {}
+
+    fn identify(&self, _: u64) {}
+
+    fn reconnect(&self, _: u64, _: bool) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MetricsSink, NoopMetricsSink};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::fmt::Debug;
+
+    assert_impl_all!(NoopMetricsSink: Clone, Copy, Debug, Default, MetricsSink, Send, Sync);
+    assert_obj_safe!(MetricsSink);
+}
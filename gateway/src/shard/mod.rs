@@ -14,6 +14,12 @@
 //!
 //! # Member Chunking
 //!
+//! Shards never request chunks of members on their own, so no bandwidth is
+//! spent on members unless requested. With the [`GUILD_MEMBERS`] intent,
+//! Discord only sends the members of guilds with fewer members than the
+//! [large threshold] when a guild is created; the members of larger guilds
+//! must be requested.
+//!
 //! Requesting chunks of a guild's members may be done via [`Shard::command`]
 //! and [`RequestGuildMembers`]. For example, requesting chunks of members whose
 //! names start with "tw":
@@ -45,11 +51,13 @@
 //! ```
 //!
 //! [`Disconnected`]: Stage::Disconnected
+//! [`GUILD_MEMBERS`]: crate::Intents::GUILD_MEMBERS
 //! [`Event`]: ::twilight_model::gateway::event::Event
 //! [`RequestGuildMembers`]: twilight_model::gateway::payload::outgoing::RequestGuildMembers
 //! [`Resuming`]: Stage::Resuming
 //! [channel deletions]: ::twilight_model::gateway::event::Event::ChannelDelete
 //! [information about itself]: Shard::info
+//! [large threshold]: ShardBuilder::large_threshold
 //! [new messages]: ::twilight_model::gateway::event::Event::MessageCreate
 
 pub mod raw_message;
@@ -57,11 +65,13 @@ pub mod stage;
 
 mod builder;
 mod command;
+mod compression_method;
 mod config;
 mod emitter;
 mod event;
 mod r#impl;
 mod json;
+mod metrics;
 mod processor;
 #[cfg(any(
     feature = "native",
@@ -73,12 +83,15 @@ pub(crate) mod tls;
 pub use self::{
     builder::{ShardBuilder, ShardIdError, ShardIdErrorType},
     command::Command,
+    compression_method::CompressionMethod,
     config::Config,
     event::Events,
+    metrics::{MetricsSink, NoopMetricsSink},
     processor::heartbeat::Latency,
     r#impl::{
-        CommandError, CommandErrorType, Information, ResumeSession, SendError, SendErrorType,
-        SessionInactiveError, Shard, ShardStartError, ShardStartErrorType,
+        CommandError, CommandErrorType, FatalError, FatalErrorType, Information, ResumeSession,
+        SendError, SendErrorType, SessionInactiveError, Shard, ShardStartError,
+        ShardStartErrorType,
     },
     stage::Stage,
 };
@@ -1,4 +1,5 @@
-use flate2::{Decompress, DecompressError, FlushDecompress};
+use super::super::r#impl::{ReceivingEventError, ReceivingEventErrorType};
+use flate2::{Decompress, FlushDecompress};
 use std::{mem, time::Instant};
 
 const ZLIB_SUFFIX: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
@@ -11,18 +12,20 @@ pub struct Inflater {
     internal_buffer: Vec<u8>,
     buffer: Vec<u8>,
     last_resize: Instant,
+    max_frame_size: usize,
     shard: [u64; 2],
 }
 
 impl Inflater {
     /// Create a new inflater for a shard.
-    pub fn new(shard: [u64; 2]) -> Self {
+    pub fn new(shard: [u64; 2], max_frame_size: usize) -> Self {
         Self {
             buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
             compressed: Vec::new(),
             decompress: Decompress::new(true),
             internal_buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
             last_resize: Instant::now(),
+            max_frame_size,
             shard,
         }
     }
@@ -43,11 +46,16 @@ impl Inflater {
     ///
     /// # Errors
     ///
-    /// This returns `flate2`'s `DecompressError` as its method's type signature
-    /// indicates it can return an error, however in reality in versions up to
-    /// 1.0.17 it won't.
+    /// Returns a [`ReceivingEventErrorType::Decompressing`] error type wrapping
+    /// `flate2`'s `DecompressError` as its method's type signature indicates it
+    /// can return an error, however in reality in versions up to 1.0.17 it
+    /// won't.
+    ///
+    /// Returns a [`ReceivingEventErrorType::FrameTooLarge`] error type if the
+    /// decompressed message exceeds the maximum frame size. Decompression is
+    /// stopped as soon as the limit is exceeded and the buffers are cleared.
     #[tracing::instrument(level = "trace")]
-    pub fn msg(&mut self) -> Result<Option<&mut [u8]>, DecompressError> {
+    pub fn msg(&mut self) -> Result<Option<&mut [u8]>, ReceivingEventError> {
         let length = self.compressed.len();
 
         // Check if a partial payload was received. If it was, we can just
@@ -62,15 +70,41 @@ impl Inflater {
         loop {
             self.internal_buffer.clear();
 
-            self.decompress.decompress_vec(
-                &self.compressed[offset..],
-                &mut self.internal_buffer,
-                FlushDecompress::Sync,
-            )?;
+            self.decompress
+                .decompress_vec(
+                    &self.compressed[offset..],
+                    &mut self.internal_buffer,
+                    FlushDecompress::Sync,
+                )
+                .map_err(|source| ReceivingEventError {
+                    kind: ReceivingEventErrorType::Decompressing,
+                    source: Some(Box::new(source)),
+                })?;
 
             offset = (self.decompress.total_in() - before)
                 .try_into()
                 .unwrap_or_default();
+
+            if self.buffer.len() + self.internal_buffer.len() > self.max_frame_size {
+                tracing::error!(
+                    max_frame_size = self.max_frame_size,
+                    shard_id = self.shard[0],
+                    shard_total = self.shard[1],
+                    "decompressed frame exceeded the maximum size",
+                );
+
+                self.compressed.clear();
+                self.internal_buffer.clear();
+                self.buffer.clear();
+
+                return Err(ReceivingEventError {
+                    kind: ReceivingEventErrorType::FrameTooLarge {
+                        max_frame_size: self.max_frame_size,
+                    },
+                    source: None,
+                });
+            }
+
             self.buffer.extend_from_slice(&self.internal_buffer[..]);
 
             let not_at_capacity = self.internal_buffer.len() < self.internal_buffer.capacity();
@@ -96,7 +130,11 @@ impl Inflater {
             let saved_percentage =
                 self.decompress.total_in() as f64 / self.decompress.total_out() as f64;
             let saved_percentage_readable = saved_percentage * 100.0;
-            let saved_kib = (self.decompress.total_out() - self.decompress.total_in()) / 1_024;
+            let saved_kib = self
+                .decompress
+                .total_out()
+                .saturating_sub(self.decompress.total_in())
+                / 1_024;
 
             tracing::trace!(
                 saved_kib = saved_kib,
@@ -132,7 +170,7 @@ impl Inflater {
 
     /// Reset the state of the inflater back to its default state.
     pub fn reset(&mut self) {
-        let _old_inflater = mem::replace(self, Self::new(self.shard));
+        let _old_inflater = mem::replace(self, Self::new(self.shard, self.max_frame_size));
     }
 
     /// Log metrics about the inflater.
@@ -183,6 +221,7 @@ impl Inflater {
 #[cfg(test)]
 mod tests {
     use super::Inflater;
+    use crate::shard::processor::r#impl::ReceivingEventErrorType;
     use std::error::Error;
 
     const MESSAGE: &[u8] = &[
@@ -205,7 +244,7 @@ mod tests {
 
     #[test]
     fn test_inflater() -> Result<(), Box<dyn Error>> {
-        let mut inflater = Inflater::new(SHARD);
+        let mut inflater = Inflater::new(SHARD, usize::MAX);
         inflater.extend(&MESSAGE[0..MESSAGE.len() - 2]);
         assert_eq!(None, inflater.msg()?);
 
@@ -244,4 +283,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_inflater_frame_too_large() -> Result<(), Box<dyn Error>> {
+        let mut inflater = Inflater::new(SHARD, OUTPUT.len() - 1);
+        inflater.extend(MESSAGE);
+
+        let error = inflater.msg().unwrap_err();
+        assert!(matches!(
+            error.kind,
+            ReceivingEventErrorType::FrameTooLarge { max_frame_size }
+            if max_frame_size == OUTPUT.len() - 1
+        ));
+
+        // The oversized frame was discarded without being buffered.
+        assert!(inflater.compressed.is_empty());
+        assert!(inflater.buffer.is_empty());
+
+        // A frame exactly at the limit is accepted.
+        let mut inflater = Inflater::new(SHARD, OUTPUT.len());
+        inflater.extend(MESSAGE);
+        assert_eq!(Some(OUTPUT), inflater.msg()?.as_deref());
+
+        Ok(())
+    }
 }
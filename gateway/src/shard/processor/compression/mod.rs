@@ -1,38 +1,55 @@
 #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
 mod inflater;
+#[cfg(feature = "zstd")]
+mod zstd_inflater;
 
 use super::r#impl::ReceivingEventError;
+use crate::shard::CompressionMethod;
 
 #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
 use inflater::Inflater;
+#[cfg(feature = "zstd")]
+use zstd_inflater::ZstdInflater;
 
-/// Interface for working with buffers variable on the `zlib-stock` and
-/// `zlib-simd` feature flags.
+/// Buffer of payloads, decompressing them with the configured
+/// [`CompressionMethod`] if its feature is enabled.
 #[derive(Debug)]
-pub struct Compression {
-    /// Inflater for use with compression.
-    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-    inner: Inflater,
+enum Inner {
     /// Buffer for use without compression.
-    #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
-    inner: Vec<u8>,
+    Uncompressed(Vec<u8>),
+    /// Inflater for use with zlib compression.
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    Zlib(Inflater),
+    /// Inflater for use with Zstandard compression.
+    #[cfg(feature = "zstd")]
+    Zstd(ZstdInflater),
+}
+
+/// Interface for working with buffers variable on the compression method and
+/// the `zlib-stock`, `zlib-simd`, and `zstd` feature flags.
+#[derive(Debug)]
+pub struct Compression {
+    inner: Inner,
 }
 
 impl Compression {
-    /// Create a new buffer, abstracting over an inflater if `zlib-stock` or
-    /// `zlib-simd` features are enabled or a simple `Vec` if the features are
-    /// disabled.
+    /// Create a new buffer, abstracting over an inflater of the compression
+    /// method if its feature is enabled or a simple `Vec` if it is disabled.
     #[cfg_attr(
-        not(any(feature = "zlib-stock", feature = "zlib-simd")),
+        not(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd")),
         allow(clippy::missing_const_for_fn, unused_variables)
     )]
-    pub fn new(shard_id: [u64; 2]) -> Self {
-        Self {
+    pub fn new(method: CompressionMethod, shard_id: [u64; 2], max_frame_size: usize) -> Self {
+        let inner = match method {
             #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-            inner: Inflater::new(shard_id),
-            #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
-            inner: Vec::new(),
-        }
+            CompressionMethod::Zlib => Inner::Zlib(Inflater::new(shard_id, max_frame_size)),
+            #[cfg(feature = "zstd")]
+            CompressionMethod::Zstd => Inner::Zstd(ZstdInflater::new(shard_id, max_frame_size)),
+            #[allow(unreachable_patterns)]
+            _ => Inner::Uncompressed(Vec::new()),
+        };
+
+        Self { inner }
     }
 
     /// Mutable reference to the internal buffer slice.
@@ -43,18 +60,24 @@ impl Compression {
     /// When compression is disabled this will mutably reference the standard
     /// buffer.
     pub fn buffer_slice_mut(&mut self) -> &mut [u8] {
-        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-        {
-            self.inner.buffer_mut()
+        match &mut self.inner {
+            Inner::Uncompressed(buffer) => buffer.as_mut_slice(),
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Inner::Zlib(inflater) => inflater.buffer_mut(),
+            #[cfg(feature = "zstd")]
+            Inner::Zstd(inflater) => inflater.buffer_mut(),
         }
-
-        #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
-        self.inner.as_mut_slice()
     }
 
     /// Clear the inner buffer.
     pub fn clear(&mut self) {
-        self.inner.clear();
+        match &mut self.inner {
+            Inner::Uncompressed(buffer) => buffer.clear(),
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Inner::Zlib(inflater) => inflater.clear(),
+            #[cfg(feature = "zstd")]
+            Inner::Zstd(inflater) => inflater.clear(),
+        }
     }
 
     /// Extend the buffer with bytes from a Binary websocket message.
@@ -63,20 +86,26 @@ impl Compression {
     ///
     /// Returns whether the inner buffer was extended.
     #[cfg_attr(
-        not(any(feature = "zlib-stock", feature = "zlib-simd")),
-        allow(clippy::unused_self, unused_variables)
+        not(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd")),
+        allow(unused_variables)
     )]
     pub fn extend_binary(&mut self, bytes: &[u8]) -> bool {
-        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-        {
-            self.inner.extend(bytes);
+        match &mut self.inner {
+            // Binary payloads are not received when compression is disabled.
+            Inner::Uncompressed(_) => false,
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Inner::Zlib(inflater) => {
+                inflater.extend(bytes);
 
-            true
-        }
+                true
+            }
+            #[cfg(feature = "zstd")]
+            Inner::Zstd(inflater) => {
+                inflater.extend(bytes);
 
-        #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
-        // Binary payloads are not received when compression is disabled.
-        false
+                true
+            }
+        }
     }
 
     /// Extend the buffer with bytes from a Text websocket message.
@@ -84,21 +113,19 @@ impl Compression {
     /// If compression is enabled then this will do nothing.
     ///
     /// Returns whether the inner buffer was extended.
-    #[cfg_attr(
-        any(feature = "zlib-stock", feature = "zlib-simd"),
-        allow(clippy::unused_self, unused_variables)
-    )]
     pub fn extend_text(&mut self, bytes: &[u8]) -> bool {
-        #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
-        {
-            self.inner.extend_from_slice(bytes);
+        match &mut self.inner {
+            Inner::Uncompressed(buffer) => {
+                buffer.extend_from_slice(bytes);
 
-            true
+                true
+            }
+            // Text payloads are not received when compression is enabled.
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Inner::Zlib(_) => false,
+            #[cfg(feature = "zstd")]
+            Inner::Zstd(_) => false,
         }
-
-        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-        // Text payloads are not received when compression is enabled.
-        false
     }
 
     /// Mutable reference to the inner completed message if compression is
@@ -117,55 +144,66 @@ impl Compression {
     /// If compression is enabled then this returns a
     /// `ReceivingEventErrorType::Decompressing` error type if decompressing the
     /// message failed.
+    ///
+    /// If compression is enabled then this returns a
+    /// `ReceivingEventErrorType::FrameTooLarge` error type if the decompressed
+    /// message exceeds the maximum frame size.
     #[cfg_attr(
-        not(any(feature = "zlib-stock", feature = "zlib-simd")),
-        allow(clippy::unnecessary_wraps, clippy::unused_self)
+        not(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd")),
+        allow(clippy::unnecessary_wraps)
     )]
     pub fn message_mut(&mut self) -> Result<Option<&mut [u8]>, ReceivingEventError> {
-        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-        {
-            use super::r#impl::ReceivingEventErrorType;
-
-            self.inner.msg().map_err(|source| ReceivingEventError {
-                kind: ReceivingEventErrorType::Decompressing,
-                source: Some(Box::new(source)),
-            })
+        match &mut self.inner {
+            Inner::Uncompressed(_) => Ok(None),
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Inner::Zlib(inflater) => inflater.msg(),
+            #[cfg(feature = "zstd")]
+            Inner::Zstd(inflater) => inflater.msg(),
         }
-
-        #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
-        Ok(None)
     }
 
     /// Reset the buffer for a new gateway session.
     pub fn reset(&mut self) {
-        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-        self.inner.reset();
-
-        #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
-        self.clear();
+        match &mut self.inner {
+            Inner::Uncompressed(buffer) => buffer.clear(),
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Inner::Zlib(inflater) => inflater.reset(),
+            #[cfg(feature = "zstd")]
+            Inner::Zstd(inflater) => inflater.reset(),
+        }
     }
-}
 
-/// Add a toggle to a gateway connection URL depending on whether compression is
-/// enabled.
-///
-/// If compression is enabled then the `compress` query parameter is appended
-/// with a value of `zlib-stream`.
-#[cfg_attr(
-    not(any(feature = "zlib-stock", feature = "zlib-simd")),
-    allow(unused_variables)
-)]
-pub fn add_url_feature(buf: &mut String) {
-    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-    buf.push_str("&compress=zlib-stream");
+    /// Add a toggle to a gateway connection URL depending on whether
+    /// compression is enabled.
+    ///
+    /// If compression is enabled then the `compress` query parameter is
+    /// appended with the name of the compression method, such as
+    /// `zlib-stream`.
+    pub fn add_url_feature(&self, buf: &mut String) {
+        let method: Option<CompressionMethod> = match &self.inner {
+            Inner::Uncompressed(_) => None,
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Inner::Zlib(_) => Some(CompressionMethod::Zlib),
+            #[cfg(feature = "zstd")]
+            Inner::Zstd(_) => Some(CompressionMethod::Zstd),
+        };
+
+        if let Some(method) = method {
+            buf.push_str("&compress=");
+            buf.push_str(method.name());
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::Compression;
+    use crate::shard::CompressionMethod;
+
     #[test]
     fn test_add_url_features() {
         let mut buf = String::new();
-        super::add_url_feature(&mut buf);
+        Compression::new(CompressionMethod::Zlib, [0, 1], usize::MAX).add_url_feature(&mut buf);
 
         #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
         {
@@ -174,5 +212,16 @@ mod tests {
 
         #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
         assert!(buf.is_empty());
+
+        let mut buf = String::new();
+        Compression::new(CompressionMethod::Zstd, [0, 1], usize::MAX).add_url_feature(&mut buf);
+
+        #[cfg(feature = "zstd")]
+        {
+            assert_eq!("&compress=zstd-stream", buf);
+        }
+
+        #[cfg(not(feature = "zstd"))]
+        assert!(buf.is_empty());
     }
 }
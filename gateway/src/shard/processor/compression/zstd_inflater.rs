@@ -0,0 +1,472 @@
+use super::super::r#impl::{ReceivingEventError, ReceivingEventErrorType};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem,
+    sync::{Mutex, PoisonError},
+    time::Instant,
+};
+use zstd::stream::raw::{Decoder, InBuffer, Operation, OutBuffer};
+
+const INTERNAL_BUFFER_SIZE: usize = 32 * 1024;
+
+/// Decompressor of a `zstd-stream` connection.
+///
+/// The gateway sends all payloads of a connection as a single Zstandard frame,
+/// flushing the compressed stream at the end of every payload. A flush doesn't
+/// mark the end of a payload in the stream, and payloads larger than the
+/// maximum block size of 128 KiB span multiple blocks, so the end of a payload
+/// is instead determined from the decompressed JSON object being closed, or
+/// from the frame ending. Decompressed bytes are buffered until then, so
+/// payloads may span multiple websocket messages.
+pub struct ZstdInflater {
+    buffer: Vec<u8>,
+    compressed: Vec<u8>,
+    /// Decoder of the stream.
+    ///
+    /// The decoder isn't `Sync`, while the shard processor has to be. It is
+    /// only accessed mutably, so the mutex is never locked.
+    decoder: Mutex<Decoder<'static>>,
+    internal_buffer: Vec<u8>,
+    last_resize: Instant,
+    max_frame_size: usize,
+    /// Progress of finding the end of the buffered payload.
+    payload_end: PayloadEnd,
+    shard: [u64; 2],
+    total_in: u64,
+    total_out: u64,
+}
+
+impl ZstdInflater {
+    /// Create a new inflater for a shard.
+    pub fn new(shard: [u64; 2], max_frame_size: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
+            compressed: Vec::new(),
+            decoder: Mutex::new(Decoder::new().expect("zstd decoder allocation failed")),
+            internal_buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
+            last_resize: Instant::now(),
+            max_frame_size,
+            payload_end: PayloadEnd::new(),
+            shard,
+            total_in: 0,
+            total_out: 0,
+        }
+    }
+
+    /// Return a mutable reference to the buffer.
+    pub fn buffer_mut(&mut self) -> &mut [u8] {
+        self.buffer.as_mut_slice()
+    }
+
+    /// Extend the internal compressed buffer with bytes.
+    pub fn extend(&mut self, slice: &[u8]) {
+        self.compressed.extend_from_slice(slice);
+    }
+
+    /// Decompress the received bytes, returning the message if a complete
+    /// payload was received.
+    ///
+    /// Returns `None` if an incomplete payload was received, keeping the
+    /// decompressed bytes until the rest of the payload is received.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReceivingEventErrorType::Decompressing`] error type if the
+    /// received bytes are not a valid Zstandard stream.
+    ///
+    /// Returns a [`ReceivingEventErrorType::FrameTooLarge`] error type if the
+    /// decompressed message exceeds the maximum frame size. Decompression is
+    /// stopped as soon as the limit is exceeded and the buffers are cleared.
+    #[tracing::instrument(level = "trace")]
+    pub fn msg(&mut self) -> Result<Option<&mut [u8]>, ReceivingEventError> {
+        let decoder = self
+            .decoder
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        let mut input = InBuffer::around(&self.compressed);
+
+        let hint = loop {
+            self.internal_buffer.clear();
+            let mut output = OutBuffer::around(&mut self.internal_buffer);
+
+            let hint =
+                decoder
+                    .run(&mut input, &mut output)
+                    .map_err(|source| ReceivingEventError {
+                        kind: ReceivingEventErrorType::Decompressing,
+                        source: Some(Box::new(source)),
+                    })?;
+
+            if self.buffer.len() + self.internal_buffer.len() > self.max_frame_size {
+                tracing::error!(
+                    max_frame_size = self.max_frame_size,
+                    shard_id = self.shard[0],
+                    shard_total = self.shard[1],
+                    "decompressed frame exceeded the maximum size",
+                );
+
+                self.compressed.clear();
+                self.internal_buffer.clear();
+                self.buffer.clear();
+
+                return Err(ReceivingEventError {
+                    kind: ReceivingEventErrorType::FrameTooLarge {
+                        max_frame_size: self.max_frame_size,
+                    },
+                    source: None,
+                });
+            }
+
+            self.buffer.extend_from_slice(&self.internal_buffer);
+
+            let at_capacity = self.internal_buffer.len() == self.internal_buffer.capacity();
+
+            if input.pos() == self.compressed.len() && !at_capacity {
+                break hint;
+            }
+        };
+
+        self.total_in += self.compressed.len() as u64;
+        self.compressed.clear();
+
+        // The decoder hints at needing no more input once the frame has ended.
+        let frame_ended = hint == 0 && !self.buffer.is_empty();
+
+        if !self.payload_end.scan(&self.buffer) && !frame_ended {
+            return Ok(None);
+        }
+
+        self.total_out += self.buffer.len() as u64;
+
+        tracing::trace!(
+            bytes_out = self.buffer.len(),
+            shard_id = self.shard[0],
+            shard_total = self.shard[1],
+            total_in = self.total_in,
+            total_out = self.total_out,
+            "payload lengths",
+        );
+
+        #[cfg(feature = "metrics")]
+        self.inflater_metrics();
+
+        Ok(Some(&mut self.buffer))
+    }
+
+    /// Clear the buffer and shrink it if the capacity is too large.
+    #[tracing::instrument(level = "trace")]
+    pub fn clear(&mut self) {
+        self.shrink();
+
+        self.compressed.clear();
+        self.internal_buffer.clear();
+        self.buffer.clear();
+        self.payload_end = PayloadEnd::new();
+    }
+
+    /// Reset the state of the inflater back to its default state.
+    pub fn reset(&mut self) {
+        let _old_inflater = mem::replace(self, Self::new(self.shard, self.max_frame_size));
+    }
+
+    /// Log metrics about the inflater.
+    #[cfg(feature = "metrics")]
+    #[allow(clippy::cast_precision_loss)]
+    fn inflater_metrics(&self) {
+        metrics::gauge!(
+            format!("Inflater-Capacity-{}", self.shard[0]),
+            self.buffer.capacity() as f64
+        );
+        metrics::gauge!(
+            format!("Inflater-In-{}", self.shard[0]),
+            self.total_in as f64
+        );
+        metrics::gauge!(
+            format!("Inflater-Out-{}", self.shard[0]),
+            self.total_out as f64
+        );
+    }
+
+    /// Shrink the capacity of the compressed buffer and payload buffer if at
+    /// least 60 seconds have passed since the last shrink.
+    fn shrink(&mut self) {
+        if self.last_resize.elapsed().as_secs() < 60 {
+            return;
+        }
+
+        self.compressed.shrink_to_fit();
+        self.buffer.shrink_to_fit();
+
+        self.last_resize = Instant::now();
+    }
+}
+
+/// Scanner finding the end of a JSON payload across decompressed chunks.
+///
+/// Payloads are JSON objects, so a payload ends once the braces opened by its
+/// object have all been closed. Braces in strings are skipped.
+#[derive(Debug)]
+struct PayloadEnd {
+    /// Number of unclosed objects and arrays.
+    depth: usize,
+    /// Whether the previous byte was a backslash escaping the next byte of a
+    /// string.
+    escaped: bool,
+    /// Whether the scanner is in a string.
+    in_string: bool,
+    /// Whether the payload's object has been opened.
+    opened: bool,
+    /// Number of bytes of the payload scanned so far.
+    scanned: usize,
+}
+
+impl PayloadEnd {
+    /// Create a new scanner for the start of a payload.
+    const fn new() -> Self {
+        Self {
+            depth: 0,
+            escaped: false,
+            in_string: false,
+            opened: false,
+            scanned: 0,
+        }
+    }
+
+    /// Scan the bytes of a payload received since the last scan, returning
+    /// whether the payload is complete.
+    fn scan(&mut self, payload: &[u8]) -> bool {
+        for &byte in &payload[self.scanned..] {
+            self.scanned += 1;
+
+            if self.in_string {
+                match byte {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => self.in_string = false,
+                    _ => {}
+                }
+
+                continue;
+            }
+
+            match byte {
+                b'"' => self.in_string = true,
+                b'{' | b'[' => {
+                    self.depth += 1;
+                    self.opened = true;
+                }
+                b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+                _ => {}
+            }
+
+            if self.opened && self.depth == 0 {
+                return true;
+            }
+        }
+
+        self.opened && self.depth == 0
+    }
+}
+
+impl Debug for ZstdInflater {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ZstdInflater")
+            .field("buffer", &self.buffer)
+            .field("compressed", &self.compressed)
+            .field("max_frame_size", &self.max_frame_size)
+            .field("shard", &self.shard)
+            .field("total_in", &self.total_in)
+            .field("total_out", &self.total_out)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ZstdInflater;
+    use crate::shard::processor::r#impl::ReceivingEventErrorType;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, io::Write};
+    use zstd::stream::write::Encoder;
+
+    assert_impl_all!(ZstdInflater: Debug, Send, Sync);
+
+    const HELLO: &[u8] = br#"{"t":null,"s":null,"op":10,"d":{"heartbeat_interval":41250,"_trace":["[\"gateway-prd-main-858d\",{\"micros\":0.0}]"]}}"#;
+    const SHARD: [u64; 2] = [2, 5];
+
+    /// Payload of a number of members, larger than the inflater's internal
+    /// buffer.
+    fn large_payload(member_count: u64) -> Vec<u8> {
+        let members = (0..member_count)
+            .map(|id| format!(r#"{{"user":{{"id":"{id}","username":"user {id}"}}}}"#))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!(r#"{{"t":"GUILD_MEMBERS_CHUNK","s":2,"op":0,"d":{{"members":[{members}]}}}}"#)
+            .into_bytes()
+    }
+
+    /// Compress payloads as a single stream, flushing it after every payload
+    /// like the gateway does, and return the bytes of each payload.
+    fn compress(payloads: &[&[u8]]) -> Result<Vec<Vec<u8>>, Box<dyn Error>> {
+        let mut encoder = Encoder::new(Vec::new(), 0)?;
+        let mut messages = Vec::new();
+
+        for payload in payloads {
+            encoder.write_all(payload)?;
+            encoder.flush()?;
+            messages.push(encoder.get_mut().split_off(0));
+        }
+
+        Ok(messages)
+    }
+
+    #[test]
+    fn test_zstd_inflater() -> Result<(), Box<dyn Error>> {
+        let large = large_payload(2_000);
+        let messages = compress(&[HELLO, &large, HELLO])?;
+        let mut inflater = ZstdInflater::new(SHARD, usize::MAX);
+
+        for (message, payload) in messages.iter().zip([HELLO, &large, HELLO]) {
+            inflater.clear();
+            inflater.extend(message);
+
+            assert_eq!(Some(payload), inflater.msg()?.as_deref());
+            assert_eq!(payload, inflater.buffer_mut());
+        }
+
+        // Resetting the inflater starts a new stream.
+        inflater.reset();
+        inflater.extend(&messages[0]);
+        assert_eq!(Some(HELLO), inflater.msg()?.as_deref());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zstd_inflater_chunked() -> Result<(), Box<dyn Error>> {
+        let large = large_payload(2_000);
+        let messages = compress(&[HELLO, &large])?;
+
+        // Split each payload at every possible position, receiving it across
+        // two websocket messages.
+        for (index, payload) in [HELLO, &large].into_iter().enumerate() {
+            let message = &messages[index];
+
+            for split in 1..message.len() {
+                let mut inflater = ZstdInflater::new(SHARD, usize::MAX);
+
+                for previous in &messages[..index] {
+                    inflater.extend(previous);
+                    assert!(inflater.msg()?.is_some());
+                    inflater.clear();
+                }
+
+                inflater.extend(&message[..split]);
+                assert_eq!(None, inflater.msg()?, "split at {split}");
+
+                inflater.extend(&message[split..]);
+                assert_eq!(Some(payload), inflater.msg()?.as_deref());
+            }
+        }
+
+        // Receive a payload byte by byte.
+        let mut inflater = ZstdInflater::new(SHARD, usize::MAX);
+        let (last, chunks) = messages[0].split_last().unwrap();
+
+        for chunk in chunks {
+            inflater.extend(&[*chunk]);
+            assert_eq!(None, inflater.msg()?);
+        }
+
+        inflater.extend(&[*last]);
+        assert_eq!(Some(HELLO), inflater.msg()?.as_deref());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zstd_inflater_block_boundary() -> Result<(), Box<dyn Error>> {
+        /// Offsets at which the blocks of a flushed message end.
+        fn block_ends(message: &[u8]) -> Vec<usize> {
+            let mut ends = Vec::new();
+            let mut offset = 0;
+
+            while offset < message.len() {
+                let header = u32::from_le_bytes([
+                    message[offset],
+                    message[offset + 1],
+                    message[offset + 2],
+                    0,
+                ]);
+                let kind = (header >> 1) & 0b11;
+                let size = (header >> 3) as usize;
+
+                // Run length encoded blocks only contain the repeated byte.
+                offset += 3 + if kind == 1 { 1 } else { size };
+                ends.push(offset);
+            }
+
+            ends
+        }
+
+        // A payload larger than the maximum block size of 128 KiB.
+        let large = large_payload(10_000);
+        assert!(large.len() > 128 * 1024);
+
+        // The first message starts with the frame header, so split the
+        // second one, which starts with a block.
+        let messages = compress(&[HELLO, &large])?;
+        let ends = block_ends(&messages[1]);
+        assert_eq!(Some(&messages[1].len()), ends.last());
+        assert!(ends.len() > 1);
+
+        for end in &ends[..ends.len() - 1] {
+            let mut inflater = ZstdInflater::new(SHARD, usize::MAX);
+            inflater.extend(&messages[0]);
+            assert_eq!(Some(HELLO), inflater.msg()?.as_deref());
+            inflater.clear();
+
+            inflater.extend(&messages[1][..*end]);
+            assert_eq!(None, inflater.msg()?, "split at {end}");
+
+            inflater.extend(&messages[1][*end..]);
+            assert_eq!(Some(large.as_slice()), inflater.msg()?.as_deref());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zstd_inflater_frame_too_large() -> Result<(), Box<dyn Error>> {
+        let messages = compress(&[HELLO])?;
+        let mut inflater = ZstdInflater::new(SHARD, HELLO.len() - 1);
+        inflater.extend(&messages[0]);
+
+        let error = inflater.msg().unwrap_err();
+        assert!(matches!(
+            error.kind,
+            ReceivingEventErrorType::FrameTooLarge { max_frame_size }
+            if max_frame_size == HELLO.len() - 1
+        ));
+        assert!(inflater.buffer.is_empty());
+
+        // A frame exactly at the limit is accepted.
+        let mut inflater = ZstdInflater::new(SHARD, HELLO.len());
+        inflater.extend(&messages[0]);
+        assert_eq!(Some(HELLO), inflater.msg()?.as_deref());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zstd_inflater_invalid() {
+        let mut inflater = ZstdInflater::new(SHARD, usize::MAX);
+        inflater.extend(b"not zstd");
+
+        assert!(matches!(
+            inflater.msg().unwrap_err().kind,
+            ReceivingEventErrorType::Decompressing
+        ));
+    }
+}
@@ -17,10 +17,11 @@ use twilight_model::gateway::payload::outgoing::Heartbeat;
 
 /// Information about the latency of a [`Shard`]'s websocket connection.
 ///
-/// This is obtained through [`Shard::info`].
+/// This is obtained through [`Shard::latency`] or [`Shard::info`].
 ///
 /// [`Shard`]: crate::shard::Shard
 /// [`Shard::info`]: crate::shard::Shard::info
+/// [`Shard::latency`]: crate::shard::Shard::latency
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Latency {
     average: Option<Duration>,
@@ -46,14 +47,19 @@ impl Latency {
         self.average
     }
 
-    /// The total number of heartbeats that have been sent during this session.
+    /// The total number of heartbeats that have been acknowledged during this
+    /// session.
     pub const fn heartbeats(&self) -> u32 {
         self.heartbeats
     }
 
-    /// The 5 most recent latency times.
+    /// The most recent latency times.
     ///
-    /// Index 0 is the oldest, 4 is the most recent.
+    /// The number of kept latencies is configured via
+    /// [`ShardBuilder::latency_history`] and defaults to 5. The first latency
+    /// is the oldest and the last is the most recent.
+    ///
+    /// [`ShardBuilder::latency_history`]: crate::shard::ShardBuilder::latency_history
     pub const fn recent(&self) -> &VecDeque<Duration> {
         &self.recent
     }
@@ -71,6 +77,10 @@ impl Latency {
 
 #[derive(Debug)]
 pub struct Heartbeats {
+    /// Maximum number of latencies kept in [`recent`].
+    ///
+    /// [`recent`]: Self::recent
+    history: usize,
     received: Mutex<Option<Instant>>,
     recent: Mutex<VecDeque<u64>>,
     sent: Mutex<Option<Instant>>,
@@ -79,6 +89,20 @@ pub struct Heartbeats {
 }
 
 impl Heartbeats {
+    /// Create new heartbeat information, keeping a number of recent latencies.
+    pub fn new(history: usize) -> Self {
+        Self {
+            history,
+            received: Mutex::new(None),
+            // The history is user provided, so latencies are allocated as
+            // they're recorded rather than up front.
+            recent: Mutex::new(VecDeque::new()),
+            sent: Mutex::new(None),
+            total_iterations: AtomicU32::new(0),
+            total_time: AtomicU64::new(0),
+        }
+    }
+
     pub fn latency(&self) -> Latency {
         let iterations = self.total_iterations();
         let recent = self
@@ -103,29 +127,39 @@ impl Heartbeats {
         self.received().is_some()
     }
 
-    pub fn receive(&self) {
+    /// Record a heartbeat acknowledgement, returning the latency of the sent
+    /// heartbeat.
+    pub fn receive(&self) -> Option<Duration> {
         self.set_received(Instant::now());
 
-        self.total_iterations.fetch_add(1, Ordering::SeqCst);
+        let dur = self.sent().map(|s| s.elapsed());
 
-        if let Some(dur) = self.sent().map(|s| s.elapsed()) {
-            let millis = if let Ok(millis) = dur.as_millis().try_into() {
-                millis
-            } else {
-                tracing::error!("duration millis is more than u64: {dur:?}");
+        if let Some(dur) = dur {
+            self.record(dur);
+        }
 
-                return;
-            };
+        dur
+    }
 
-            self.total_time.fetch_add(millis, Ordering::SeqCst);
+    /// Record the latency of a heartbeat, dropping the oldest recent latency
+    /// if the history is full.
+    fn record(&self, dur: Duration) {
+        let millis = if let Ok(millis) = dur.as_millis().try_into() {
+            millis
+        } else {
+            tracing::error!("duration millis is more than u64: {dur:?}");
 
-            let mut recent = self.recent.lock().expect("recent poisoned");
+            return;
+        };
 
-            if recent.len() == 5 {
-                recent.pop_front();
-            }
+        self.total_iterations.fetch_add(1, Ordering::SeqCst);
+        self.total_time.fetch_add(millis, Ordering::SeqCst);
+
+        let mut recent = self.recent.lock().expect("recent poisoned");
+        recent.push_back(millis);
 
-            recent.push_back(millis);
+        while recent.len() > self.history {
+            recent.pop_front();
         }
     }
 
@@ -161,18 +195,6 @@ impl Heartbeats {
     }
 }
 
-impl Default for Heartbeats {
-    fn default() -> Self {
-        Self {
-            received: Mutex::new(None),
-            recent: Mutex::new(VecDeque::with_capacity(5)),
-            sent: Mutex::new(None),
-            total_iterations: AtomicU32::new(0),
-            total_time: AtomicU64::new(0),
-        }
-    }
-}
-
 pub struct Heartbeater {
     heartbeats: Arc<Heartbeats>,
     interval: u64,
@@ -208,10 +230,15 @@ impl Heartbeater {
     async fn try_run(self) -> Result<(), SessionSendError> {
         let duration = Duration::from_millis(self.interval);
 
+        // The first heartbeat is sent after a random fraction of the interval,
+        // as required by Discord, so that shards connecting at the same time
+        // don't heartbeat in lockstep.
+        let mut delay = duration.mul_f64(rand::random());
         let mut last = true;
 
         loop {
-            tokio::time::sleep(duration).await;
+            tokio::time::sleep(delay).await;
+            delay = duration;
 
             // Check if a heartbeat acknowledgement was received.
             //
@@ -253,9 +280,96 @@ impl Heartbeater {
 
 #[cfg(test)]
 mod tests {
-    use super::Latency;
+    use super::{Heartbeater, Heartbeats, Latency};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{
+        fmt::Debug,
+        sync::{atomic::AtomicU64, Arc},
+        time::Duration,
+    };
+    use tokio::{sync::mpsc, time::Instant};
 
     assert_impl_all!(Latency: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_latency_history() {
+        let heartbeats = Heartbeats::new(3);
+
+        let latency = heartbeats.latency();
+        assert_eq!(None, latency.average());
+        assert_eq!(0, latency.heartbeats());
+        assert!(latency.recent().is_empty());
+
+        // An acknowledgement without a sent heartbeat, such as the one
+        // recorded when the session becomes ready, has no latency.
+        assert_eq!(None, heartbeats.receive());
+        assert_eq!(None, heartbeats.latency().average());
+
+        for millis in [10, 20, 30, 40, 50] {
+            heartbeats.record(Duration::from_millis(millis));
+        }
+
+        let latency = heartbeats.latency();
+        assert_eq!(Some(Duration::from_millis(30)), latency.average());
+        assert_eq!(5, latency.heartbeats());
+        assert_eq!(
+            [30, 40, 50].map(Duration::from_millis).as_slice(),
+            latency.recent().iter().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_latency_history_empty() {
+        let heartbeats = Heartbeats::new(0);
+        heartbeats.record(Duration::from_millis(10));
+
+        let latency = heartbeats.latency();
+        assert_eq!(Some(Duration::from_millis(10)), latency.average());
+        assert!(latency.recent().is_empty());
+    }
+
+    /// Test that a large history doesn't allocate space for every latency up
+    /// front.
+    #[test]
+    fn test_latency_history_large() {
+        let heartbeats = Heartbeats::new(usize::MAX);
+        heartbeats.record(Duration::from_millis(10));
+
+        assert_eq!(
+            [Duration::from_millis(10)].as_slice(),
+            heartbeats
+                .latency()
+                .recent()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_first_heartbeat_jitter() {
+        const INTERVAL: Duration = Duration::from_millis(41_250);
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let heartbeats = Arc::new(Heartbeats::new(5));
+        let heartbeater = Heartbeater::new(
+            Arc::clone(&heartbeats),
+            INTERVAL.as_millis().try_into().unwrap(),
+            Arc::new(AtomicU64::new(0)),
+            tx,
+        );
+
+        let start = Instant::now();
+        tokio::spawn(heartbeater.run());
+
+        rx.recv().await.expect("first heartbeat");
+        let first = start.elapsed();
+        assert!(first < INTERVAL);
+
+        // Acknowledge the first heartbeat so that the heartbeater continues.
+        heartbeats.receive();
+
+        rx.recv().await.expect("second heartbeat");
+        assert_eq!(first + INTERVAL, start.elapsed());
+    }
 }
@@ -2,9 +2,10 @@ use super::{
     super::{
         emitter::{EmitJsonErrorType, Emitter},
         json::{self, GatewayEventParsingError, GatewayEventParsingErrorType},
-        Config, ShardStream, Stage,
+        Config, FatalError, FatalErrorType, ShardStream, Stage,
     },
-    compression::{self, Compression},
+    compression::Compression,
+    queue::CommandQueue,
     session::{Session, SessionSendError, SessionSendErrorType},
     socket_forwarder::SocketForwarder,
 };
@@ -22,6 +23,7 @@ use std::{
 use tokio::sync::{
     mpsc::UnboundedReceiver,
     watch::{channel as watch_channel, Receiver as WatchReceiver, Sender as WatchSender},
+    OnceCell,
 };
 use tokio_tungstenite::tungstenite::{
     protocol::{frame::coding::CloseCode, CloseFrame, WebSocketConfig},
@@ -39,7 +41,7 @@ use twilight_model::gateway::{
             resume::Resume,
         },
     },
-    Intents, OpCode,
+    CloseCode as GatewayCloseCode, Intents, OpCode,
 };
 use url::Url;
 
@@ -172,20 +174,44 @@ pub struct ReceivingEventError {
 }
 
 impl ReceivingEventError {
-    const fn fatal(&self) -> bool {
-        matches!(
-            self.kind,
-            ReceivingEventErrorType::AuthorizationInvalid { .. }
-                | ReceivingEventErrorType::IntentsDisallowed { .. }
-                | ReceivingEventErrorType::IntentsInvalid { .. }
-                | ReceivingEventErrorType::InvalidApiVersion
-                | ReceivingEventErrorType::InvalidShard { .. }
-                | ReceivingEventErrorType::ShardingRequired
-        )
+    /// Error to surface to the user if the error is fatal, meaning that the
+    /// shard must not reconnect.
+    const fn fatal(&self) -> Option<FatalError> {
+        let kind = match self.kind {
+            ReceivingEventErrorType::AuthorizationInvalid { shard_id, .. } => {
+                FatalErrorType::AuthorizationInvalid { shard_id }
+            }
+            ReceivingEventErrorType::IntentsDisallowed { intents, shard_id } => {
+                FatalErrorType::IntentsDisallowed {
+                    intents: intents.privileged(),
+                    shard_id,
+                }
+            }
+            ReceivingEventErrorType::IntentsInvalid { intents, shard_id } => {
+                FatalErrorType::IntentsInvalid { intents, shard_id }
+            }
+            ReceivingEventErrorType::InvalidApiVersion => FatalErrorType::ApiVersionInvalid,
+            ReceivingEventErrorType::InvalidShard {
+                shard_count,
+                shard_id,
+            } => FatalErrorType::ShardInvalid {
+                shard_count,
+                shard_id,
+            },
+            ReceivingEventErrorType::ShardingRequired => FatalErrorType::ShardingRequired,
+            ReceivingEventErrorType::Decompressing
+            | ReceivingEventErrorType::EventStreamEnded
+            | ReceivingEventErrorType::FrameTooLarge { .. } => return None,
+        };
+
+        Some(FatalError { kind })
     }
 
     const fn reconnectable(&self) -> bool {
-        matches!(self.kind, ReceivingEventErrorType::Decompressing)
+        matches!(
+            self.kind,
+            ReceivingEventErrorType::Decompressing | ReceivingEventErrorType::FrameTooLarge { .. }
+        )
     }
 
     const fn resumable(&self) -> bool {
@@ -224,6 +250,12 @@ impl Display for ReceivingEventError {
             ReceivingEventErrorType::EventStreamEnded => {
                 f.write_str("event stream from gateway ended")
             }
+            ReceivingEventErrorType::FrameTooLarge { max_frame_size } => {
+                f.write_str("a frame exceeded the maximum size of ")?;
+                Display::fmt(max_frame_size, f)?;
+
+                f.write_str(" bytes")
+            }
             ReceivingEventErrorType::InvalidApiVersion => {
                 f.write_str("invalid api version was used for identifying")
             }
@@ -259,9 +291,20 @@ pub enum ReceivingEventErrorType {
     /// Provided authorization token is invalid.
     AuthorizationInvalid { shard_id: u64, token: String },
     /// Decompressing a frame from Discord failed.
+    #[cfg_attr(
+        not(any(feature = "zlib-stock", feature = "zlib-simd")),
+        allow(dead_code)
+    )]
     Decompressing,
     /// The event stream has ended, this is recoverable by resuming.
     EventStreamEnded,
+    /// A decompressed frame exceeded the configured maximum size.
+    ///
+    /// This is recoverable by reconnecting.
+    FrameTooLarge {
+        /// Configured maximum size of a frame in bytes.
+        max_frame_size: usize,
+    },
     /// Current user isn't allowed to use at least one of the configured
     /// intents.
     ///
@@ -305,11 +348,18 @@ struct ReadyMinimal {
 pub struct ShardProcessor {
     pub config: Arc<Config>,
     pub emitter: Emitter,
+    /// Set to the error that stopped the processor, if it was fatal.
+    fatal_error: Arc<OnceCell<FatalError>>,
     pub rx: UnboundedReceiver<Message>,
     pub session: Arc<Session>,
     compression: Compression,
     url: Box<str>,
     resume: Option<(u64, Box<str>)>,
+    /// URL to resume the session against, provided by the gateway.
+    resume_url: Option<Box<str>>,
+    /// Number of consecutive attempts to resume the session.
+    resume_attempts: u8,
+    queue: Arc<CommandQueue>,
     wtx: WatchSender<Arc<Session>>,
 }
 
@@ -317,10 +367,14 @@ impl ShardProcessor {
     pub async fn new(
         config: Arc<Config>,
         emitter: Emitter,
+        fatal_error: Arc<OnceCell<FatalError>>,
+        queue: Arc<CommandQueue>,
     ) -> Result<(Self, WatchReceiver<Arc<Session>>), ConnectingError> {
-        //if we got resume info we don't need to wait
+        // A restored session is resumed rather than identified, so there is no
+        // need to wait for the queue.
         let shard_id = config.shard();
-        let resumable = config.sequence.is_some() && config.session_id.is_some();
+        let resume = config.sequence.zip(config.session_id.clone());
+        let resumable = resume.is_some();
         if !resumable {
             tracing::debug!("shard {shard_id:?} is not resumable");
             tracing::debug!("shard {shard_id:?} queued");
@@ -330,20 +384,8 @@ impl ShardProcessor {
             tracing::debug!("shard {:?} finished queue", config.shard());
         }
 
-        let mut url = config.gateway_url().to_owned();
-
-        url.push_str("?v=");
-        url.push_str(&API_VERSION.to_string());
-
-        // Discord's documentation states:
-        //
-        // "Generally, it is a good idea to explicitly pass the gateway version
-        // and encoding".
-        //
-        // <https://discord.com/developers/docs/topics/gateway#connecting-gateway-url-query-string-params>
-        url.push_str("&encoding=json");
-
-        compression::add_url_feature(&mut url);
+        let compression = Compression::new(config.compression(), shard_id, config.max_frame_size());
+        let url = Self::gateway_url(config.gateway_url(), &compression);
 
         emitter.event(Event::ShardConnecting(Connecting {
             gateway: url.clone(),
@@ -360,47 +402,86 @@ impl ShardProcessor {
         )
         .await?;
         let (forwarder, rx, tx) = SocketForwarder::new(stream);
-        tokio::spawn(forwarder.run());
-
-        let session = Arc::new(Session::new(tx, config.ratelimit_payloads));
+        let forwarder_handle = tokio::spawn(forwarder.run());
+
+        let session = Arc::new(Session::new(
+            tx,
+            forwarder_handle,
+            config.ratelimit_payloads,
+            config.latency_history,
+        ));
+
+        // The restored session is resumed on this connection once the hello
+        // payload is received.
+        if let Some((seq, id)) = &resume {
+            tracing::debug!("resuming shard {shard_id:?}");
 
-        if resumable {
-            session.set_id(config.session_id.clone().unwrap());
-            session
-                .seq
-                .store(config.sequence.unwrap(), Ordering::Relaxed)
+            session.set_id(id.clone());
+            session.seq.store(*seq, Ordering::Relaxed);
+            session.set_stage(Stage::Resuming);
+        } else {
+            session.set_stage(Stage::Handshaking);
         }
 
         let (wtx, wrx) = watch_channel(Arc::clone(&session));
 
-        let mut processor = Self {
-            compression: Compression::new(shard_id),
+        let processor = Self {
+            compression,
             config,
             emitter,
+            fatal_error,
             rx,
             session,
             url: url.into_boxed_str(),
-            resume: None,
+            resume,
+            resume_url: None,
+            resume_attempts: u8::from(resumable),
+            queue,
             wtx,
         };
 
-        if resumable {
-            tracing::debug!("resuming shard {shard_id:?}");
+        Ok((processor, wrx))
+    }
 
-            processor.resume().await;
-        }
+    /// Add the query parameters the shard connects with to a gateway URL.
+    fn gateway_url(base: &str, compression: &Compression) -> String {
+        let mut url = base.to_owned();
 
-        Ok((processor, wrx))
+        url.push_str("?v=");
+        url.push_str(&API_VERSION.to_string());
+
+        // Discord's documentation states:
+        //
+        // "Generally, it is a good idea to explicitly pass the gateway version
+        // and encoding".
+        //
+        // <https://discord.com/developers/docs/topics/gateway#connecting-gateway-url-query-string-params>
+        url.push_str("&encoding=json");
+
+        compression.add_url_feature(&mut url);
+
+        url
     }
 
     pub async fn run(mut self) {
         loop {
             if let Err(source) = self.next_payload().await {
+                // The shard is shutting down, so the connection ending is
+                // expected and mustn't be resumed.
+                if self.session.is_closing() {
+                    break;
+                }
+
                 tracing::warn!("{source}");
 
                 self.emit_disconnected(None, None).await;
 
-                if source.fatal() {
+                if let Some(fatal_error) = source.fatal() {
+                    // Set before the processor, and with it the emitter, is
+                    // dropped, so that the error is available once the event
+                    // stream ends.
+                    let _res = self.fatal_error.set(fatal_error);
+
                     break;
                 }
 
@@ -482,6 +563,10 @@ impl ShardProcessor {
                     });
                 };
 
+            self.config
+                .metrics
+                .event_received(self.config.shard()[0], op, event_type.as_deref());
+
             // We can do a few little optimization tricks here. For the
             // "heartbeat ack" and "reconnect" opcodes we can construct
             // the gateway events without needing to go through a serde
@@ -493,6 +578,10 @@ impl ShardProcessor {
             // This might not be shaving off entire milliseconds for these few
             // events each time, but it certainly adds up.
             if matches!(op, 1 | 7 | 9 | 10 | 11) {
+                if let Ok(flag) = EventTypeFlags::try_from((op, event_type.as_deref())) {
+                    emitter.raw(flag, buffer);
+                }
+
                 // Have to use an if statement here if we want to use the OpCode
                 // enum, since matching with repr values isn't allowed.
                 let gateway_event = if op == OpCode::HeartbeatAck as u8 {
@@ -524,7 +613,9 @@ impl ShardProcessor {
             })?;
 
             if event_type.as_deref() == Some("RESUMED") {
+                emitter.raw(EventTypeFlags::RESUMED, self.compression.buffer_slice_mut());
                 self.process_resumed(seq);
+                self.flush_queue().await;
 
                 if emitter.wants(EventTypeFlags::RESUMED) {
                     let gateway_event =
@@ -535,6 +626,8 @@ impl ShardProcessor {
 
                 return Ok(());
             } else if event_type.as_deref() == Some("READY") {
+                emitter.raw(EventTypeFlags::READY, self.compression.buffer_slice_mut());
+
                 let ready = json::from_slice::<ReadyMinimal>(self.compression.buffer_slice_mut())
                     .map_err(|source| ProcessError {
                     kind: ProcessErrorType::ParsingPayload,
@@ -545,6 +638,7 @@ impl ShardProcessor {
                 })?;
 
                 self.process_ready(&ready.d);
+                self.flush_queue().await;
                 emitter.event(Event::Ready(Box::new(ready.d)));
 
                 return Ok(());
@@ -583,6 +677,11 @@ impl ShardProcessor {
         self.session.set_stage(Stage::Connected);
         self.session
             .set_id(ready.session_id.clone().into_boxed_str());
+        self.resume_attempts = 0;
+        self.resume_url = ready
+            .resume_gateway_url
+            .as_deref()
+            .map(|url| Self::gateway_url(url, &self.compression).into_boxed_str());
 
         self.emitter.event(Event::ShardConnected(Connected {
             heartbeat_interval: self.session.heartbeat_interval(),
@@ -590,10 +689,11 @@ impl ShardProcessor {
         }));
     }
 
-    fn process_resumed(&self, seq: u64) {
+    fn process_resumed(&mut self, seq: u64) {
         #[cfg(feature = "metrics")]
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "Dispatch");
 
+        self.resume_attempts = 0;
         self.session.set_seq(seq);
         self.session.set_stage(Stage::Connected);
         self.emitter.event(Event::ShardConnected(Connected {
@@ -603,6 +703,13 @@ impl ShardProcessor {
         self.session.heartbeats.receive();
     }
 
+    /// Send commands queued while the session was not connected.
+    async fn flush_queue(&self) {
+        if let Err(source) = self.queue.flush(&self.session).await {
+            tracing::warn!("failed to flush queued commands: {source}");
+        }
+    }
+
     async fn process_gateway_event(&mut self, event: &GatewayEvent) -> Result<(), ProcessError> {
         match event {
             GatewayEvent::Dispatch(_, _) => unreachable!("dispatch events separately handled"),
@@ -622,7 +729,10 @@ impl ShardProcessor {
         #[cfg(feature = "metrics")]
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "HeartbeatAck");
 
-        self.session.heartbeats.receive();
+        let latency = self.session.heartbeats.receive();
+        self.config
+            .metrics
+            .heartbeat_ack(self.config.shard()[0], latency);
     }
 
     async fn process_heartbeat(&mut self, seq: u64) {
@@ -630,6 +740,7 @@ impl ShardProcessor {
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "Heartbeat");
 
         if seq > self.session.seq() + 1 {
+            self.emit_disconnected(None, None).await;
             self.resume().await;
         }
 
@@ -794,15 +905,9 @@ impl ShardProcessor {
                 let extended = self.compression.extend_binary(json.as_slice());
 
                 if extended {
-                    match self.compression.message_mut() {
-                        Ok(Some(bytes)) => self.emitter.bytes(bytes),
-                        Ok(None) => return Ok(false),
-                        Err(source) => {
-                            return Err(ReceivingEventError {
-                                kind: ReceivingEventErrorType::Decompressing,
-                                source: Some(Box::new(source)),
-                            })
-                        }
+                    match self.compression.message_mut()? {
+                        Some(bytes) => self.emitter.bytes(bytes),
+                        None => return Ok(false),
                     };
                 }
 
@@ -814,6 +919,23 @@ impl ShardProcessor {
                 Ok(false)
             }
             Message::Text(json) => {
+                let max_frame_size = self.config.max_frame_size();
+
+                if json.len() > max_frame_size {
+                    tracing::error!(
+                        shard_id = self.config.shard()[0],
+                        shard_total = self.config.shard()[1],
+                        size = json.len(),
+                        max_frame_size,
+                        "frame exceeded the maximum size",
+                    );
+
+                    return Err(ReceivingEventError {
+                        kind: ReceivingEventErrorType::FrameTooLarge { max_frame_size },
+                        source: None,
+                    });
+                }
+
                 let extended = self.compression.extend_text(json.as_bytes());
 
                 if extended {
@@ -842,58 +964,18 @@ impl ShardProcessor {
         )
         .await;
 
-        if let Some(close_frame) = close_frame {
-            match close_frame.code {
-                CloseCode::Library(4004) => {
-                    return Err(ReceivingEventError {
-                        kind: ReceivingEventErrorType::AuthorizationInvalid {
-                            shard_id: self.config.shard()[0],
-                            token: self.config.token().to_owned(),
-                        },
-                        source: None,
-                    });
-                }
-                CloseCode::Library(4010) => {
-                    return Err(ReceivingEventError {
-                        kind: ReceivingEventErrorType::InvalidShard {
-                            shard_count: self.config.shard()[1],
-                            shard_id: self.config.shard()[0],
-                        },
-                        source: None,
-                    });
-                }
-                CloseCode::Library(4011) => {
-                    return Err(ReceivingEventError {
-                        kind: ReceivingEventErrorType::ShardingRequired,
-                        source: None,
-                    });
-                }
-                CloseCode::Library(4012) => {
-                    return Err(ReceivingEventError {
-                        kind: ReceivingEventErrorType::InvalidApiVersion,
-                        source: None,
-                    });
-                }
-                CloseCode::Library(4013) => {
-                    return Err(ReceivingEventError {
-                        kind: ReceivingEventErrorType::IntentsInvalid {
-                            intents: self.config.intents(),
-                            shard_id: self.config.shard()[0],
-                        },
-                        source: None,
-                    });
-                }
-                CloseCode::Library(4014) => {
-                    return Err(ReceivingEventError {
-                        kind: ReceivingEventErrorType::IntentsDisallowed {
-                            intents: self.config.intents(),
-                            shard_id: self.config.shard()[0],
-                        },
-                        source: None,
-                    });
-                }
-                _ => {}
-            }
+        // The gateway acknowledged the shard's own close.
+        if self.session.is_closing() {
+            return Ok(());
+        }
+
+        let fatal = close_frame
+            .and_then(|frame| GatewayCloseCode::try_from(u16::from(frame.code)).ok())
+            .filter(|code| !code.can_reconnect())
+            .and_then(|code| self.fatal_close_kind(code));
+
+        if let Some(kind) = fatal {
+            return Err(ReceivingEventError { kind, source: None });
         }
 
         self.resume().await;
@@ -901,6 +983,34 @@ impl ShardProcessor {
         Ok(())
     }
 
+    /// Type of error to stop the shard with after the gateway closed the
+    /// connection with a code that can't be reconnected after.
+    fn fatal_close_kind(&self, code: GatewayCloseCode) -> Option<ReceivingEventErrorType> {
+        Some(match code {
+            GatewayCloseCode::AuthenticationFailed => {
+                ReceivingEventErrorType::AuthorizationInvalid {
+                    shard_id: self.config.shard()[0],
+                    token: self.config.token().to_owned(),
+                }
+            }
+            GatewayCloseCode::InvalidShard => ReceivingEventErrorType::InvalidShard {
+                shard_count: self.config.shard()[1],
+                shard_id: self.config.shard()[0],
+            },
+            GatewayCloseCode::ShardingRequired => ReceivingEventErrorType::ShardingRequired,
+            GatewayCloseCode::InvalidApiVersion => ReceivingEventErrorType::InvalidApiVersion,
+            GatewayCloseCode::InvalidIntents => ReceivingEventErrorType::IntentsInvalid {
+                intents: self.config.intents(),
+                shard_id: self.config.shard()[0],
+            },
+            GatewayCloseCode::DisallowedIntents => ReceivingEventErrorType::IntentsDisallowed {
+                intents: self.config.intents(),
+                shard_id: self.config.shard()[0],
+            },
+            _ => return None,
+        })
+    }
+
     async fn connect(
         url: &str,
         #[cfg(any(
@@ -986,6 +1096,7 @@ impl ShardProcessor {
             shard_id: self.config.shard()[0],
             shard_total: self.config.shard()[1],
         }));
+        self.config.metrics.identify(self.config.shard()[0]);
 
         self.send(identify).await
     }
@@ -993,6 +1104,12 @@ impl ShardProcessor {
     /// Perform a full reconnect to the gateway, instantiating a new session.
     async fn reconnect(&mut self) {
         tracing::info!("reconnection started");
+        self.config.metrics.reconnect(self.config.shard()[0], false);
+
+        // The new session has its own resume URL.
+        self.resume = None;
+        self.resume_url = None;
+        self.resume_attempts = 0;
 
         let mut wait = Duration::from_secs(1);
 
@@ -1036,7 +1153,9 @@ impl ShardProcessor {
                 }
             };
 
-            self.set_session(stream, Stage::Connected);
+            // The session isn't connected until it has identified and
+            // received a READY event.
+            self.set_session(stream, Stage::Handshaking);
 
             break;
         }
@@ -1066,6 +1185,20 @@ impl ShardProcessor {
             return;
         };
 
+        // Resuming against the resume URL may repeatedly fail, in which case
+        // the session is given up on rather than retrying indefinitely.
+        if self.resume_attempts >= self.config.max_resume_attempts() {
+            tracing::info!(
+                attempts = self.resume_attempts,
+                shard_id = self.config.shard()[0],
+                "resuming failed too many times, reconnecting",
+            );
+
+            self.reconnect().await;
+            return;
+        }
+
+        self.resume_attempts += 1;
         self.resume = Some((seq, id));
 
         if let Err(source) = self.try_resume().await {
@@ -1082,13 +1215,14 @@ impl ShardProcessor {
 
     /// Attempt to resume a session.
     async fn try_resume(&mut self) -> Result<(), ConnectingError> {
+        self.config.metrics.reconnect(self.config.shard()[0], true);
         self.emitter.event(Event::ShardResuming(Resuming {
             seq: self.session.seq(),
             shard_id: self.config.shard()[0],
         }));
 
         let stream = Self::connect(
-            &self.url,
+            self.resume_url.as_deref().unwrap_or(&self.url),
             #[cfg(any(
                 feature = "native",
                 feature = "rustls-native-roots",
@@ -1110,10 +1244,15 @@ impl ShardProcessor {
     fn set_session(&mut self, stream: ShardStream, stage: Stage) {
         let (forwarder, rx, tx) = SocketForwarder::new(stream);
 
-        tokio::spawn(forwarder.run());
+        let forwarder_handle = tokio::spawn(forwarder.run());
 
         self.rx = rx;
-        self.session = Arc::new(Session::new(tx, self.config.ratelimit_payloads));
+        self.session = Arc::new(Session::new(
+            tx,
+            forwarder_handle,
+            self.config.ratelimit_payloads,
+            self.config.latency_history,
+        ));
 
         if let Err(source) = self.wtx.send(Arc::clone(&self.session)) {
             tracing::error!("failed to broadcast new session: {source:?}");
@@ -1123,7 +1262,17 @@ impl ShardProcessor {
         self.compression.reset();
     }
 
+    /// Mark the session as disconnected and emit a [`Disconnected`] event.
+    ///
+    /// The event is only emitted once per connection, so that the close code
+    /// of a close frame isn't followed by another event without one when the
+    /// resulting error is handled.
     async fn emit_disconnected(&self, code: Option<u16>, reason: Option<String>) {
+        if self.session.stage() == Stage::Disconnected {
+            return;
+        }
+
+        self.session.set_stage(Stage::Disconnected);
         self.emitter.event(Event::ShardDisconnected(Disconnected {
             code,
             reason,
@@ -2,11 +2,13 @@ pub mod heartbeat;
 
 mod compression;
 mod r#impl;
+mod queue;
 mod session;
 mod socket_forwarder;
 
 pub use self::{
     heartbeat::Latency,
+    queue::{CommandQueue, QueueFullError, Queueing, StaleKey},
     r#impl::{ConnectingError, ConnectingErrorType, ShardProcessor},
     session::Session,
 };
@@ -0,0 +1,261 @@
+//! Queue of commands sent while a shard's session is not connected.
+
+use super::{
+    super::stage::Stage,
+    session::{Session, SessionSendError, SessionSendErrorType},
+};
+use std::{
+    collections::VecDeque,
+    mem,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+use tokio_tungstenite::tungstenite::Message as TungsteniteMessage;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+/// How a command is handled when sent while the session is not connected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Queueing {
+    /// Command is bound to the session it was sent over and is not queued.
+    Never,
+    /// Command is queued and sent in order once the session is connected.
+    Ordered,
+    /// Command is queued, replacing a queued command with the same key, which
+    /// is dropped as stale.
+    Latest(StaleKey),
+}
+
+/// Key of a command that supersedes previous commands with the same key.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StaleKey {
+    /// Presence of the shard.
+    Presence,
+    /// Voice state of the current user in a guild.
+    VoiceState(Id<GuildMarker>),
+}
+
+/// Maximum number of commands held by a [`CommandQueue`].
+///
+/// Matches the number of commands Discord allows a session to send each
+/// minute, so a full queue can be flushed within a minute of connecting.
+pub const CAPACITY: usize = 120;
+
+/// Command queue is at [`CAPACITY`] and can't hold another command.
+#[derive(Debug)]
+pub struct QueueFullError;
+
+/// Serialized command waiting for the session to connect.
+#[derive(Debug)]
+struct QueuedCommand {
+    json: Vec<u8>,
+    key: Option<StaleKey>,
+}
+
+/// Commands held while a shard is connecting, flushed once the session is
+/// connected.
+#[derive(Debug, Default)]
+pub struct CommandQueue {
+    commands: Mutex<VecDeque<QueuedCommand>>,
+    stale: AtomicU64,
+}
+
+impl CommandQueue {
+    /// Queue a command if the session is not connected.
+    ///
+    /// Returns the command if the session is connected, in which case it
+    /// should be sent directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`QueueFullError`] if the queue already holds [`CAPACITY`]
+    /// commands and the command doesn't replace a stale one.
+    pub fn push(
+        &self,
+        session: &Session,
+        key: Option<StaleKey>,
+        json: Vec<u8>,
+    ) -> Result<Option<Vec<u8>>, QueueFullError> {
+        let mut commands = self.commands.lock().expect("command queue poisoned");
+
+        // Checked while holding the lock so a command can't be queued after
+        // the session was flushed.
+        if session.stage() == Stage::Connected {
+            return Ok(Some(json));
+        }
+
+        if let Some(key) = key {
+            let len = commands.len();
+            commands.retain(|command| command.key != Some(key));
+
+            self.stale
+                .fetch_add((len - commands.len()) as u64, Ordering::Relaxed);
+        }
+
+        if commands.len() >= CAPACITY {
+            return Err(QueueFullError);
+        }
+
+        commands.push_back(QueuedCommand { json, key });
+
+        Ok(None)
+    }
+
+    /// Send all queued commands over the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SessionSendErrorType::Sending`] error type if the session
+    /// has hung up. Commands that weren't sent remain queued.
+    pub async fn flush(&self, session: &Session) -> Result<(), SessionSendError> {
+        let mut commands = mem::take(&mut *self.commands.lock().expect("command queue poisoned"));
+
+        while let Some(command) = commands.front() {
+            if let Some(Some(ratelimiter)) = session.ratelimit.get() {
+                ratelimiter.acquire_one().await;
+            }
+
+            if let Err(source) = session
+                .tx
+                .send(TungsteniteMessage::Binary(command.json.clone()))
+            {
+                let mut queue = self.commands.lock().expect("command queue poisoned");
+
+                for command in commands.into_iter().rev() {
+                    queue.push_front(command);
+                }
+
+                return Err(SessionSendError {
+                    kind: SessionSendErrorType::Sending,
+                    source: Some(Box::new(source)),
+                });
+            }
+
+            commands.pop_front();
+        }
+
+        Ok(())
+    }
+
+    /// Number of queued commands dropped because a newer command superseded
+    /// them.
+    pub fn stale(&self) -> u64 {
+        self.stale.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandQueue, StaleKey, CAPACITY};
+    use crate::shard::{processor::Session, stage::Stage};
+    use tokio::sync::mpsc;
+    use tokio_tungstenite::tungstenite::Message as TungsteniteMessage;
+    use twilight_model::id::Id;
+
+    #[tokio::test]
+    async fn test_flush_after_reconnect() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let session = Session::new(tx, tokio::spawn(async {}), false, 5);
+
+        // The new session is still handshaking after a reconnect.
+        session.set_stage(Stage::Handshaking);
+
+        let queue = CommandQueue::default();
+        assert!(queue
+            .push(&session, Some(StaleKey::Presence), b"presence 1".to_vec())
+            .unwrap()
+            .is_none());
+        assert!(queue
+            .push(&session, None, b"members".to_vec())
+            .unwrap()
+            .is_none());
+        assert!(queue
+            .push(
+                &session,
+                Some(StaleKey::VoiceState(Id::new(1))),
+                b"voice".to_vec()
+            )
+            .unwrap()
+            .is_none());
+        assert!(queue
+            .push(&session, Some(StaleKey::Presence), b"presence 2".to_vec())
+            .unwrap()
+            .is_none());
+        assert_eq!(1, queue.stale());
+        assert!(rx.try_recv().is_err());
+
+        // The session is ready.
+        session.set_stage(Stage::Connected);
+        queue.flush(&session).await.unwrap();
+
+        for expected in [&b"members"[..], b"voice", b"presence 2"] {
+            assert_eq!(
+                TungsteniteMessage::Binary(expected.to_vec()),
+                rx.try_recv().unwrap()
+            );
+        }
+        assert!(rx.try_recv().is_err());
+
+        // Commands are no longer queued once the session is connected.
+        assert_eq!(
+            Some(b"presence 3".to_vec()),
+            queue
+                .push(&session, Some(StaleKey::Presence), b"presence 3".to_vec())
+                .unwrap()
+        );
+        assert_eq!(1, queue.stale());
+    }
+
+    #[tokio::test]
+    async fn test_flush_requeues_on_hang_up() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let session = Session::new(tx, tokio::spawn(async {}), false, 5);
+        let queue = CommandQueue::default();
+
+        assert!(queue
+            .push(&session, None, b"members".to_vec())
+            .unwrap()
+            .is_none());
+        drop(rx);
+
+        assert!(queue.flush(&session).await.is_err());
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let session = Session::new(tx, tokio::spawn(async {}), false, 5);
+        queue.flush(&session).await.unwrap();
+
+        assert_eq!(
+            TungsteniteMessage::Binary(b"members".to_vec()),
+            rx.try_recv().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_push_full() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let session = Session::new(tx, tokio::spawn(async {}), false, 5);
+        let queue = CommandQueue::default();
+
+        assert!(queue
+            .push(&session, Some(StaleKey::Presence), b"presence 1".to_vec())
+            .unwrap()
+            .is_none());
+
+        for _ in 1..CAPACITY {
+            assert!(queue
+                .push(&session, None, b"members".to_vec())
+                .unwrap()
+                .is_none());
+        }
+
+        assert!(queue.push(&session, None, b"members".to_vec()).is_err());
+
+        // Replacing a stale command doesn't grow the queue.
+        assert!(queue
+            .push(&session, Some(StaleKey::Presence), b"presence 2".to_vec())
+            .unwrap()
+            .is_none());
+        assert_eq!(1, queue.stale());
+    }
+}
@@ -8,7 +8,7 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     sync::{
-        atomic::{AtomicU64, AtomicU8, Ordering},
+        atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering},
         Arc, Mutex as MutexSync,
     },
     time::Duration,
@@ -65,6 +65,16 @@ pub enum SessionSendErrorType {
 
 #[derive(Debug)]
 pub struct Session {
+    /// Whether the shard is closing the connection, in which case it must not
+    /// be resumed once it ends.
+    closing: AtomicBool,
+    /// Handle of the task forwarding messages to and from the socket.
+    ///
+    /// The task ends, dropping the receiving half of [`tx`], once the socket
+    /// is closed.
+    ///
+    /// [`tx`]: Self::tx
+    pub forwarder_handle: JoinHandle<()>,
     pub heartbeater_handle: MutexSync<Option<JoinHandle<()>>>,
     pub heartbeats: Arc<Heartbeats>,
     pub heartbeat_interval: AtomicU64,
@@ -76,10 +86,17 @@ pub struct Session {
 }
 
 impl Session {
-    pub fn new(tx: UnboundedSender<TungsteniteMessage>, ratelimit_payloads: bool) -> Self {
+    pub fn new(
+        tx: UnboundedSender<TungsteniteMessage>,
+        forwarder_handle: JoinHandle<()>,
+        ratelimit_payloads: bool,
+        latency_history: usize,
+    ) -> Self {
         let session = Self {
+            closing: AtomicBool::new(false),
+            forwarder_handle,
             heartbeater_handle: MutexSync::new(None),
-            heartbeats: Arc::new(Heartbeats::default()),
+            heartbeats: Arc::new(Heartbeats::new(latency_history)),
             heartbeat_interval: AtomicU64::new(0),
             id: MutexSync::new(None),
             seq: Arc::new(AtomicU64::new(0)),
@@ -128,6 +145,22 @@ impl Session {
         self.tx.send(TungsteniteMessage::Close(close_frame))
     }
 
+    /// Close the connection for good, without it being resumed once the
+    /// gateway acknowledges the close.
+    pub fn close_permanently(
+        &self,
+        close_frame: Option<CloseFrame<'static>>,
+    ) -> Result<(), SendError<TungsteniteMessage>> {
+        self.closing.store(true, Ordering::Release);
+
+        self.close(close_frame)
+    }
+
+    /// Whether the connection is being closed for good.
+    pub fn is_closing(&self) -> bool {
+        self.closing.load(Ordering::Acquire)
+    }
+
     fn disable_ratelimiter(&self) {
         let _result = self.ratelimit.set(None);
     }
@@ -76,7 +76,19 @@ pub enum StageConversionErrorType {
 
 /// The current connection stage of a [`Shard`].
 ///
+/// This is the state of the shard's connection, available via
+/// [`Information::stage`]. Every transition between stages is emitted once as
+/// a shard event, such as [`ShardIdentifying`] or [`ShardDisconnected`], the
+/// latter including the close code if the gateway sent one. Shard events are
+/// separate from the events sent by Discord and can be filtered out via
+/// [`EventTypeFlags`].
+///
+/// [`EventTypeFlags`]: crate::EventTypeFlags
+/// [`Information::stage`]: super::Information::stage
 /// [`Shard`]: super::Shard
+/// [`ShardDisconnected`]: twilight_model::gateway::event::Event::ShardDisconnected
+/// [`ShardIdentifying`]: twilight_model::gateway::event::Event::ShardIdentifying
+#[doc(alias = "ShardState")]
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[non_exhaustive]
 #[repr(u8)]
@@ -0,0 +1,105 @@
+//! Utilities shared by integration tests.
+
+use futures::{SinkExt, StreamExt};
+use std::error::Error;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::{
+    tungstenite::{
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Message,
+    },
+    WebSocketStream,
+};
+
+/// Gateway connection sending payloads in the format the shard requested.
+pub struct Gateway {
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    compress: flate2::Compress,
+    stream: WebSocketStream<TcpStream>,
+}
+
+impl Gateway {
+    pub async fn accept(listener: &TcpListener) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let (stream, _) = listener.accept().await?;
+
+        Ok(Self {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            compress: flate2::Compress::new(flate2::Compression::default(), true),
+            stream: tokio_tungstenite::accept_async(stream).await?,
+        })
+    }
+
+    /// Close the connection with a close code.
+    #[allow(dead_code)]
+    pub async fn close(&mut self, code: u16) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.stream
+            .close(Some(CloseFrame {
+                code: CloseCode::from(code),
+                reason: "closing".into(),
+            }))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Read messages until the connection ends, which acknowledges a close
+    /// from the shard, returning the code the shard closed with.
+    #[allow(dead_code)]
+    pub async fn acknowledge_close(&mut self) -> Result<Option<u16>, Box<dyn Error + Send + Sync>> {
+        let mut code = None;
+
+        while let Some(message) = self.stream.next().await {
+            if let Message::Close(frame) = message? {
+                code = frame.map(|frame| frame.code.into());
+            }
+        }
+
+        Ok(code)
+    }
+
+    /// Wait for a payload with an opcode from the shard.
+    #[allow(dead_code)]
+    pub async fn receive(&mut self, op: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.receive_payload(op).await?;
+
+        Ok(())
+    }
+
+    /// Wait for a payload with an opcode from the shard, returning it.
+    pub async fn receive_payload(
+        &mut self,
+        op: u8,
+    ) -> Result<serde_json::Value, Box<dyn Error + Send + Sync>> {
+        while let Some(message) = self.stream.next().await {
+            let payload = match message? {
+                Message::Binary(json) => serde_json::from_slice::<serde_json::Value>(&json)?,
+                Message::Text(json) => serde_json::from_str(&json)?,
+                _ => continue,
+            };
+
+            if payload["op"] == op {
+                return Ok(payload);
+            }
+        }
+
+        Err("connection closed".into())
+    }
+
+    pub async fn send(&mut self, json: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+        let message = {
+            let mut bytes = Vec::with_capacity(json.len() + 64);
+            self.compress
+                .compress_vec(json.as_bytes(), &mut bytes, flate2::FlushCompress::Sync)?;
+
+            Message::Binary(bytes)
+        };
+
+        #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
+        let message = Message::Text(json.to_owned());
+
+        self.stream.send(message).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::Gateway;
+use std::{collections::HashMap, error::Error, sync::Arc, time::Duration};
+use tokio::net::TcpListener;
+use twilight_gateway::{
+    cluster::{ClusterStartErrorType, ShardScheme},
+    queue::NoOpQueue,
+    Cluster, Intents,
+};
+
+const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":45000}}"#;
+
+/// Test that each shard of a cluster identifies with the intents of the range
+/// it is in, or the cluster's intents if it isn't in any.
+#[tokio::test]
+async fn test_shard_intents() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+
+    let gateway = tokio::spawn(async move {
+        let mut intents = HashMap::new();
+        let mut connections = Vec::new();
+
+        for _ in 0..4 {
+            let mut gateway = Gateway::accept(&listener).await?;
+            gateway.send(HELLO).await?;
+
+            let identify = gateway.receive_payload(2).await?;
+            let shard_id = identify["d"]["shard"][0].as_u64().unwrap();
+            let bits = identify["d"]["intents"].as_u64().unwrap();
+            intents.insert(shard_id, Intents::from_bits_truncate(bits));
+
+            // Keep the connection open so that the shard doesn't reconnect.
+            connections.push(gateway);
+        }
+
+        Ok::<_, Box<dyn Error + Send + Sync>>(intents)
+    });
+
+    let intents = Intents::GUILDS | Intents::GUILD_MESSAGES;
+    let (cluster, _events) =
+        Cluster::builder("token".to_owned(), intents | Intents::MESSAGE_CONTENT)
+            .gateway_url(url)
+            .queue(Arc::new(NoOpQueue))
+            .shard_scheme(ShardScheme::try_from((0..=3, 4))?)
+            .shard_intents(1..=3, intents)
+            .shard_intents(3..=3, Intents::GUILDS)
+            .build()
+            .await?;
+    cluster.up().await;
+
+    let identified = tokio::time::timeout(Duration::from_secs(10), gateway).await???;
+    cluster.down();
+
+    assert_eq!(4, identified.len());
+    assert_eq!(intents | Intents::MESSAGE_CONTENT, identified[&0]);
+    assert_eq!(intents, identified[&1]);
+    assert_eq!(intents, identified[&2]);
+    assert_eq!(Intents::GUILDS, identified[&3]);
+
+    Ok(())
+}
+
+/// Test that building a cluster fails if the intents of a range of shards
+/// contain privileged intents that the cluster's intents don't.
+#[tokio::test]
+async fn test_shard_intents_undeclared() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let error = Cluster::builder("token".to_owned(), Intents::GUILDS | Intents::GUILD_MEMBERS)
+        .gateway_url("ws://127.0.0.1:0".to_owned())
+        .queue(Arc::new(NoOpQueue))
+        .shard_scheme(ShardScheme::try_from((0..=1, 2))?)
+        .shard_intents(
+            1..=1,
+            Intents::GUILD_MEMBERS | Intents::GUILD_PRESENCES | Intents::MESSAGE_CONTENT,
+        )
+        .build()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error.kind(),
+        ClusterStartErrorType::PrivilegedIntentsUndeclared { intents, shards }
+        if *intents == Intents::GUILD_PRESENCES | Intents::MESSAGE_CONTENT && *shards == (1..=1)
+    ));
+
+    Ok(())
+}
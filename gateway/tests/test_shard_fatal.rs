@@ -0,0 +1,63 @@
+mod common;
+
+use common::Gateway;
+use futures::stream::StreamExt;
+use std::{error::Error, sync::Arc, time::Duration};
+use tokio::net::TcpListener;
+use twilight_gateway::{
+    queue::NoOpQueue,
+    shard::{FatalErrorType, Shard},
+    EventTypeFlags, Intents,
+};
+
+/// Test that a shard closed with the disallowed intents close code stops
+/// without reconnecting and surfaces the privileged intents it requested.
+#[tokio::test]
+async fn test_shard_intents_disallowed() -> Result<(), Box<dyn Error + Send + Sync>> {
+    const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":45000}}"#;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+
+    let gateway = tokio::spawn(async move {
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+        gateway.receive(2).await?;
+        gateway.close(4014).await?;
+
+        // The shard must not reconnect.
+        let reconnected = tokio::time::timeout(Duration::from_millis(500), listener.accept())
+            .await
+            .is_ok();
+
+        Ok::<_, Box<dyn Error + Send + Sync>>(reconnected)
+    });
+
+    let intents = Intents::GUILDS | Intents::GUILD_MEMBERS | Intents::MESSAGE_CONTENT;
+    let (shard, mut events) = Shard::builder("token".to_owned(), intents)
+        .event_types(EventTypeFlags::SHARD_DISCONNECTED)
+        .gateway_url(url)
+        .queue(Arc::new(NoOpQueue))
+        .build()
+        .await?;
+    shard.start().await?;
+
+    // The event stream ends once the shard has stopped.
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while events.next().await.is_some() {}
+    })
+    .await?;
+
+    let error = shard.fatal_error().expect("shard stopped with an error");
+    assert!(matches!(
+        error.kind(),
+        FatalErrorType::IntentsDisallowed { intents, shard_id: 0 }
+            if *intents == Intents::GUILD_MEMBERS | Intents::MESSAGE_CONTENT
+    ));
+    assert!(error
+        .to_string()
+        .contains("GUILD_MEMBERS | MESSAGE_CONTENT"));
+    assert!(!gateway.await??);
+
+    Ok(())
+}
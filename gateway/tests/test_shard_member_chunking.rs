@@ -0,0 +1,93 @@
+mod common;
+
+use common::Gateway;
+use futures::StreamExt;
+use std::{error::Error, sync::Arc, time::Duration};
+use tokio::net::TcpListener;
+use twilight_gateway::{queue::NoOpQueue, Event, Intents, Shard};
+
+const GUILD_CREATE: &str = r#"{"op":0,"s":2,"t":"GUILD_CREATE","d":{
+    "afk_channel_id":null,
+    "afk_timeout":300,
+    "application_id":null,
+    "banner":null,
+    "default_message_notifications":0,
+    "description":null,
+    "discovery_splash":null,
+    "emojis":[],
+    "explicit_content_filter":0,
+    "features":[],
+    "icon":null,
+    "id":"3",
+    "large":true,
+    "member_count":1000,
+    "mfa_level":0,
+    "name":"twilight",
+    "nsfw_level":0,
+    "owner_id":"4",
+    "preferred_locale":"en-US",
+    "premium_progress_bar_enabled":false,
+    "roles":[],
+    "rules_channel_id":null,
+    "splash":null,
+    "stage_instances":[],
+    "stickers":[],
+    "system_channel_flags":0,
+    "system_channel_id":null,
+    "vanity_url_code":null,
+    "verification_level":0
+}}"#;
+
+const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":45000}}"#;
+
+const READY: &str = r#"{"op":0,"s":1,"t":"READY","d":{
+    "application":{"flags":0,"id":"1"},
+    "guilds":[{"id":"3","unavailable":true}],
+    "session_id":"session",
+    "shard":[0,1],
+    "user":{"avatar":null,"discriminator":"0001","id":"2","mfa_enabled":false,"username":"twilight","verified":true},
+    "v":10
+}}"#;
+
+/// Test that a shard doesn't request the members of a large guild when the
+/// guild is created, even with the `GUILD_MEMBERS` intent.
+#[tokio::test]
+async fn test_no_member_chunk_request() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+
+    let gateway = tokio::spawn(async move {
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+        gateway.receive(2).await?;
+        gateway.send(READY).await?;
+        gateway.send(GUILD_CREATE).await?;
+
+        // Wait for a request guild members payload, which must not be sent.
+        match tokio::time::timeout(Duration::from_secs(1), gateway.receive(8)).await {
+            Ok(_) => Err("shard requested guild members".into()),
+            Err(_) => Ok::<_, Box<dyn Error + Send + Sync>>(()),
+        }
+    });
+
+    let (shard, mut events) = Shard::builder("token".to_owned(), Intents::GUILD_MEMBERS)
+        .gateway_url(url)
+        .queue(Arc::new(NoOpQueue))
+        .build()
+        .await?;
+    shard.start().await?;
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while let Some(event) = events.next().await {
+            if matches!(event, Event::GuildCreate(guild) if guild.id.get() == 3) {
+                break;
+            }
+        }
+    })
+    .await?;
+
+    tokio::time::timeout(Duration::from_secs(10), gateway).await???;
+    shard.shutdown();
+
+    Ok(())
+}
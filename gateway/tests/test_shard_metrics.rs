@@ -0,0 +1,116 @@
+mod common;
+
+use common::Gateway;
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::net::TcpListener;
+use twilight_gateway::{
+    queue::NoOpQueue,
+    shard::{MetricsSink, Shard},
+    Intents,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Metric {
+    EventReceived(u8, Option<String>),
+    HeartbeatAck,
+    Identify,
+    Reconnect { resume: bool },
+}
+
+/// Sink recording the metrics reported by shard 0.
+#[derive(Debug, Default)]
+struct RecordingSink(Mutex<Vec<Metric>>);
+
+impl RecordingSink {
+    fn metrics(&self) -> Vec<Metric> {
+        self.0.lock().unwrap().clone()
+    }
+
+    fn record(&self, shard_id: u64, metric: Metric) {
+        assert_eq!(0, shard_id);
+
+        self.0.lock().unwrap().push(metric);
+    }
+}
+
+impl MetricsSink for RecordingSink {
+    fn event_received(&self, shard_id: u64, op: u8, event_type: Option<&str>) {
+        self.record(
+            shard_id,
+            Metric::EventReceived(op, event_type.map(ToOwned::to_owned)),
+        );
+    }
+
+    fn heartbeat_ack(&self, shard_id: u64, _: Option<Duration>) {
+        self.record(shard_id, Metric::HeartbeatAck);
+    }
+
+    fn identify(&self, shard_id: u64) {
+        self.record(shard_id, Metric::Identify);
+    }
+
+    fn reconnect(&self, shard_id: u64, resume: bool) {
+        self.record(shard_id, Metric::Reconnect { resume });
+    }
+}
+
+const READY: &str = r#"{"op":0,"s":1,"t":"READY","d":{
+    "application":{"flags":0,"id":"1"},
+    "guilds":[],
+    "session_id":"session",
+    "shard":[0,1],
+    "user":{"avatar":null,"discriminator":"0001","id":"2","mfa_enabled":false,"username":"twilight","verified":true},
+    "v":10
+}}"#;
+
+#[tokio::test]
+async fn test_shard_metrics() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+
+    let gateway = tokio::spawn(async move {
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway
+            .send(r#"{"op":10,"d":{"heartbeat_interval":45000}}"#)
+            .await?;
+        gateway.receive(2).await?;
+        gateway.send(READY).await?;
+        gateway.send(r#"{"op":11}"#).await?;
+        gateway.send(r#"{"op":7,"d":null}"#).await?;
+
+        // The shard resumes the session over a new connection.
+        Gateway::accept(&listener).await
+    });
+
+    let sink = Arc::new(RecordingSink::default());
+    let (shard, _events) = Shard::builder("token".to_owned(), Intents::empty())
+        .gateway_url(url)
+        .metrics(Arc::clone(&sink) as Arc<dyn MetricsSink>)
+        .queue(Arc::new(NoOpQueue))
+        .build()
+        .await?;
+    shard.start().await?;
+
+    let _gateway = tokio::time::timeout(Duration::from_secs(10), gateway).await???;
+    shard.shutdown();
+
+    assert_eq!(
+        [
+            Metric::EventReceived(10, None),
+            Metric::Identify,
+            Metric::EventReceived(0, Some("READY".to_owned())),
+            Metric::EventReceived(11, None),
+            Metric::HeartbeatAck,
+            Metric::EventReceived(7, None),
+            Metric::Reconnect { resume: true },
+        ]
+        .as_slice(),
+        sink.metrics().as_slice()
+    );
+
+    Ok(())
+}
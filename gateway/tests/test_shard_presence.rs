@@ -0,0 +1,89 @@
+mod common;
+
+use common::Gateway;
+use futures::StreamExt;
+use std::{error::Error, sync::Arc, time::Duration};
+use tokio::{net::TcpListener, sync::oneshot};
+use twilight_gateway::{queue::NoOpQueue, Event, EventTypeFlags, Intents, Shard};
+use twilight_model::gateway::{
+    payload::outgoing::update_presence::UpdatePresencePayload,
+    presence::{Activity, ActivityType, MinimalActivity, Status},
+};
+
+const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":45000}}"#;
+const READY: &str = r#"{"op":0,"s":1,"t":"READY","d":{
+    "application":{"flags":0,"id":"1"},
+    "guilds":[],
+    "session_id":"session",
+    "shard":[0,1],
+    "user":{"avatar":null,"discriminator":"0001","id":"2","mfa_enabled":false,"username":"twilight","verified":true},
+    "v":10
+}}"#;
+
+fn presence(kind: ActivityType, status: Status) -> UpdatePresencePayload {
+    let activity = Activity::from(MinimalActivity {
+        kind,
+        name: "twilight".to_owned(),
+        url: None,
+    });
+
+    UpdatePresencePayload::new([activity], false, None, status).unwrap()
+}
+
+/// Test that a shard identifies with its initial presence and sends presence
+/// updates on the live connection.
+#[tokio::test]
+async fn test_update_presence() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+    let (update_tx, update_rx) = oneshot::channel();
+
+    let gateway = tokio::spawn(async move {
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+
+        let identify = gateway.receive_payload(2).await?;
+        let initial = &identify["d"]["presence"];
+        assert_eq!("idle", initial["status"]);
+        assert_eq!(0, initial["activities"][0]["type"]);
+
+        gateway.send(READY).await?;
+
+        let update = gateway.receive_payload(3).await?;
+        update_tx.send(update).unwrap();
+
+        Ok::<_, Box<dyn Error + Send + Sync>>(())
+    });
+
+    let (shard, mut events) = Shard::builder("token".to_owned(), Intents::empty())
+        .event_types(EventTypeFlags::READY)
+        .gateway_url(url)
+        .presence(presence(ActivityType::Playing, Status::Idle))
+        .queue(Arc::new(NoOpQueue))
+        .build()
+        .await?;
+    shard.start().await?;
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while let Some(event) = events.next().await {
+            if matches!(event, Event::Ready(_)) {
+                break;
+            }
+        }
+    })
+    .await?;
+
+    shard
+        .update_presence(presence(ActivityType::Watching, Status::DoNotDisturb))
+        .await?;
+
+    let update = tokio::time::timeout(Duration::from_secs(10), update_rx).await??;
+    assert_eq!(3, update["op"]);
+    assert_eq!("dnd", update["d"]["status"]);
+    assert_eq!(3, update["d"]["activities"][0]["type"]);
+
+    tokio::time::timeout(Duration::from_secs(10), gateway).await???;
+    shard.shutdown();
+
+    Ok(())
+}
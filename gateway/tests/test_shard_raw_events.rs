@@ -0,0 +1,75 @@
+mod common;
+
+use common::Gateway;
+use futures::StreamExt;
+use std::{error::Error, sync::Arc, time::Duration};
+use tokio::net::TcpListener;
+use twilight_gateway::{queue::NoOpQueue, Event, EventTypeFlags, Intents, Shard};
+
+const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":45000}}"#;
+
+const READY: &str = r#"{"op":0,"s":1,"t":"READY","d":{
+    "application":{"flags":0,"id":"1"},
+    "guilds":[],
+    "session_id":"session",
+    "shard":[0,1],
+    "user":{"avatar":null,"discriminator":"0001","id":"2","mfa_enabled":false,"username":"twilight","verified":true},
+    "v":10
+}}"#;
+
+const TYPING_START: &str =
+    r#"{"op":0,"s":2,"t":"TYPING_START","d":{"channel_id":"3","timestamp":1,"user_id":"2"}}"#;
+
+/// Test that an event type configured to be sent raw is delivered as its JSON
+/// payload instead of being deserialized.
+#[tokio::test]
+async fn test_raw_event_types() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+
+    let gateway = tokio::spawn(async move {
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+        gateway.receive(2).await?;
+        gateway.send(READY).await?;
+        gateway.send(TYPING_START).await?;
+
+        Ok::<_, Box<dyn Error + Send + Sync>>(gateway)
+    });
+
+    let (shard, mut events) = Shard::builder("token".to_owned(), Intents::GUILD_MESSAGE_TYPING)
+        .event_types(EventTypeFlags::READY)
+        .gateway_url(url)
+        .queue(Arc::new(NoOpQueue))
+        .raw_event_types(EventTypeFlags::TYPING_START)
+        .build()
+        .await?;
+    shard.start().await?;
+
+    let received = tokio::time::timeout(Duration::from_secs(10), async {
+        let mut received = Vec::new();
+
+        while let Some(event) = events.next().await {
+            match event {
+                Event::Ready(_) => received.push("ready".to_owned()),
+                Event::ShardPayload(payload) => {
+                    received.push(String::from_utf8(payload.bytes).unwrap());
+
+                    break;
+                }
+                Event::TypingStart(_) => panic!("raw event type was deserialized"),
+                _ => {}
+            }
+        }
+
+        received
+    })
+    .await?;
+
+    assert_eq!(["ready", TYPING_START].as_slice(), received);
+
+    let _gateway = tokio::time::timeout(Duration::from_secs(10), gateway).await???;
+    shard.shutdown();
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use common::Gateway;
+use std::{error::Error, sync::Arc, time::Duration};
+use tokio::net::TcpListener;
+use twilight_gateway::{queue::NoOpQueue, shard::ResumeSession, Intents, Shard};
+
+const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":45000}}"#;
+
+fn ready(resume_gateway_url: &str) -> String {
+    format!(
+        r#"{{"op":0,"s":1,"t":"READY","d":{{
+            "application":{{"flags":0,"id":"1"}},
+            "guilds":[],
+            "resume_gateway_url":"{resume_gateway_url}",
+            "session_id":"session",
+            "shard":[0,1],
+            "user":{{"avatar":null,"discriminator":"0001","id":"2","mfa_enabled":false,"username":"twilight","verified":true}},
+            "v":10
+        }}}}"#
+    )
+}
+
+/// Test that a shard identifies a new session on the gateway URL after
+/// repeatedly failing to resume against the resume gateway URL.
+#[tokio::test]
+async fn test_resume_fallback() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let resume_listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+    let resume_url = format!("ws://{}", resume_listener.local_addr()?);
+
+    let gateway = tokio::spawn(async move {
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+        gateway.receive(2).await?;
+        gateway.send(&ready(&resume_url)).await?;
+        gateway.send(r#"{"op":7,"d":null}"#).await?;
+
+        // Each resume is attempted against the resume gateway URL, with the
+        // connection being dropped after the shard sends the resume payload.
+        for _ in 0..2 {
+            let mut gateway = Gateway::accept(&resume_listener).await?;
+            gateway.send(HELLO).await?;
+            gateway.receive(6).await?;
+        }
+
+        // The shard then identifies a new session on the gateway URL.
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+        gateway.receive(2).await
+    });
+
+    let (shard, _events) = Shard::builder("token".to_owned(), Intents::empty())
+        .gateway_url(url)
+        .max_resume_attempts(2)
+        .queue(Arc::new(NoOpQueue))
+        .build()
+        .await?;
+    shard.start().await?;
+
+    tokio::time::timeout(Duration::from_secs(10), gateway).await???;
+    shard.shutdown();
+
+    Ok(())
+}
+
+/// Test that a shard with a restored session resumes it on its first
+/// connection, and identifies a new session once the gateway invalidates it.
+#[tokio::test]
+async fn test_resume_restored_session() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+
+    let gateway = tokio::spawn(async move {
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+
+        let resume = gateway.receive_payload(6).await?;
+        assert_eq!("session", resume["d"]["session_id"]);
+        assert_eq!(5, resume["d"]["seq"]);
+
+        gateway.send(r#"{"op":9,"d":false}"#).await?;
+
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+        gateway.receive(2).await
+    });
+
+    let resume_session =
+        serde_json::from_str::<ResumeSession>(&serde_json::to_string(&ResumeSession {
+            session_id: "session".to_owned(),
+            sequence: 5,
+        })?)?;
+
+    let (shard, _events) = Shard::builder("token".to_owned(), Intents::empty())
+        .gateway_url(url)
+        .queue(Arc::new(NoOpQueue))
+        .resume_session(resume_session)
+        .build()
+        .await?;
+    shard.start().await?;
+
+    tokio::time::timeout(Duration::from_secs(10), gateway).await???;
+    shard.shutdown();
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use common::Gateway;
+use futures::stream::StreamExt;
+use std::{error::Error, sync::Arc, time::Duration};
+use tokio::{net::TcpListener, sync::oneshot, time::Instant};
+use twilight_gateway::{queue::NoOpQueue, shard::Shard, Event, EventTypeFlags, Intents};
+
+/// Test that a graceful shutdown waits for the gateway to acknowledge the
+/// close, even if the gateway sends a dispatch before acknowledging it.
+#[tokio::test]
+async fn test_shard_shutdown_graceful() -> Result<(), Box<dyn Error + Send + Sync>> {
+    const DELAY: Duration = Duration::from_millis(100);
+    const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":45000}}"#;
+    const RESUMED: &str = r#"{"op":0,"s":1,"t":"RESUMED","d":{}}"#;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+    let (identified_tx, identified_rx) = oneshot::channel();
+
+    let gateway = tokio::spawn(async move {
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+        gateway.receive(2).await?;
+        let _res = identified_tx.send(());
+
+        // Dispatch an event after the shard has started closing, and only
+        // then read its close frame, acknowledging it.
+        tokio::time::sleep(DELAY).await;
+        gateway.send(RESUMED).await?;
+        tokio::time::sleep(DELAY).await;
+
+        gateway.acknowledge_close().await
+    });
+
+    let (shard, events) = Shard::builder("token".to_owned(), Intents::empty())
+        .event_types(EventTypeFlags::RESUMED | EventTypeFlags::SHARD_DISCONNECTED)
+        .gateway_url(url)
+        .queue(Arc::new(NoOpQueue))
+        .build()
+        .await?;
+    shard.start().await?;
+    identified_rx.await?;
+
+    let start = Instant::now();
+    assert!(shard.shutdown_graceful(Duration::from_secs(5)).await);
+    assert!(start.elapsed() >= DELAY * 2);
+    assert_eq!(Some(1000), gateway.await??);
+
+    // The dispatch sent before the close was acknowledged is still emitted,
+    // followed by the disconnect, after which the shard stops without
+    // resuming.
+    let events = tokio::time::timeout(Duration::from_secs(5), events.collect::<Vec<_>>()).await?;
+    assert!(matches!(events[0], Event::Resumed));
+    assert!(
+        matches!(&events[1], Event::ShardDisconnected(disconnected) if disconnected.code == Some(1000))
+    );
+    assert_eq!(2, events.len());
+
+    Ok(())
+}
@@ -1,9 +1,15 @@
+mod common;
+
+use common::Gateway;
 use futures::stream::StreamExt;
-use std::{env, error::Error};
+use std::{env, error::Error, sync::Arc, time::Duration};
+use tokio::net::TcpListener;
 use twilight_gateway::{
-    shard::{raw_message::Message, Events, Shard},
-    Event, Intents,
+    queue::NoOpQueue,
+    shard::{raw_message::Message, Events, Shard, Stage},
+    Event, EventType, EventTypeFlags, Intents,
 };
+use twilight_model::gateway::event::shard::Disconnected;
 
 async fn shard() -> Result<(Shard, Events), Box<dyn Error>> {
     let token = env::var("DISCORD_TOKEN")?;
@@ -45,3 +51,110 @@ async fn test_shard_event_emits() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Test that a shard emits an event for every transition of its connection
+/// stage, including the close code of a connection closed by the gateway.
+#[tokio::test]
+async fn test_shard_state_transitions() -> Result<(), Box<dyn Error + Send + Sync>> {
+    const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":45000}}"#;
+    const READY: &str = r#"{"op":0,"s":1,"t":"READY","d":{
+        "application":{"flags":0,"id":"1"},
+        "guilds":[],
+        "session_id":"session",
+        "shard":[0,1],
+        "user":{"avatar":null,"discriminator":"0001","id":"2","mfa_enabled":false,"username":"twilight","verified":true},
+        "v":10
+    }}"#;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+
+    let gateway = tokio::spawn(async move {
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+        gateway.receive(2).await?;
+        gateway.send(READY).await?;
+        gateway.close(4000).await?;
+
+        // The session is resumed after the gateway closed the connection.
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+        gateway.receive(6).await?;
+        gateway
+            .send(r#"{"op":0,"s":2,"t":"RESUMED","d":null}"#)
+            .await?;
+        gateway.send(r#"{"op":9,"d":false}"#).await?;
+
+        // The session was invalidated, so a new one is identified.
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+        gateway.receive(2).await?;
+        gateway.send(READY).await?;
+
+        // Keep the connection open until the shard is shut down.
+        gateway.receive(u8::MAX).await.ok();
+
+        Ok::<_, Box<dyn Error + Send + Sync>>(())
+    });
+
+    let (shard, mut events) = Shard::builder("token".to_owned(), Intents::empty())
+        .event_types(
+            EventTypeFlags::SHARD_CONNECTED
+                | EventTypeFlags::SHARD_CONNECTING
+                | EventTypeFlags::SHARD_DISCONNECTED
+                | EventTypeFlags::SHARD_IDENTIFYING
+                | EventTypeFlags::SHARD_RECONNECTING
+                | EventTypeFlags::SHARD_RESUMING,
+        )
+        .gateway_url(url)
+        .queue(Arc::new(NoOpQueue))
+        .build()
+        .await?;
+    shard.start().await?;
+
+    let mut received = Vec::new();
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while received.len() < 11 {
+            received.push(events.next().await.unwrap());
+        }
+    })
+    .await?;
+
+    let kinds = received.iter().map(Event::kind).collect::<Vec<_>>();
+    assert_eq!(
+        [
+            EventType::ShardConnecting,
+            EventType::ShardIdentifying,
+            EventType::ShardConnected,
+            EventType::ShardDisconnected,
+            EventType::ShardResuming,
+            EventType::ShardConnected,
+            EventType::ShardDisconnected,
+            EventType::ShardReconnecting,
+            EventType::ShardConnecting,
+            EventType::ShardIdentifying,
+            EventType::ShardConnected,
+        ]
+        .as_slice(),
+        kinds
+    );
+
+    assert!(matches!(
+        &received[3],
+        Event::ShardDisconnected(Disconnected {
+            code: Some(4000),
+            ..
+        })
+    ));
+    assert!(matches!(
+        &received[6],
+        Event::ShardDisconnected(Disconnected { code: None, .. })
+    ));
+    assert_eq!(Stage::Connected, shard.info()?.stage());
+
+    shard.shutdown();
+    gateway.await??;
+
+    Ok(())
+}
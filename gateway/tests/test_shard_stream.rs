@@ -0,0 +1,79 @@
+mod common;
+
+use common::Gateway;
+use futures::{future, StreamExt};
+use std::{error::Error, sync::Arc, time::Duration};
+use tokio::net::TcpListener;
+use twilight_gateway::{queue::NoOpQueue, Event, EventType, EventTypeFlags, Intents, Shard};
+
+const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":45000}}"#;
+const READY: &str = r#"{"op":0,"s":1,"t":"READY","d":{
+    "application":{"flags":0,"id":"1"},
+    "guilds":[],
+    "session_id":"session",
+    "shard":[0,1],
+    "user":{"avatar":null,"discriminator":"0001","id":"2","mfa_enabled":false,"username":"twilight","verified":true},
+    "v":10
+}}"#;
+
+fn role_delete(seq: u64, role_id: u64) -> String {
+    format!(
+        r#"{{"op":0,"s":{seq},"t":"GUILD_ROLE_DELETE","d":{{"guild_id":"1","role_id":"{role_id}"}}}}"#
+    )
+}
+
+/// Test that a shard's events can be consumed with stream combinators, and
+/// that the stream ends once the shard is shut down.
+#[tokio::test]
+async fn test_shard_stream() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+
+    let gateway = tokio::spawn(async move {
+        let mut gateway = Gateway::accept(&listener).await?;
+        gateway.send(HELLO).await?;
+        gateway.receive(2).await?;
+        gateway.send(READY).await?;
+
+        for (seq, role_id) in (2..).zip(1..=3) {
+            gateway.send(&role_delete(seq, role_id)).await?;
+        }
+
+        // Keep the connection open until the shard is shut down.
+        gateway.receive(u8::MAX).await.ok();
+
+        Ok::<_, Box<dyn Error + Send + Sync>>(())
+    });
+
+    let (shard, mut events) = Shard::builder("token".to_owned(), Intents::GUILDS)
+        .event_types(EventTypeFlags::READY | EventTypeFlags::ROLE_DELETE)
+        .gateway_url(url)
+        .queue(Arc::new(NoOpQueue))
+        .build()
+        .await?;
+    shard.start().await?;
+
+    let ready = tokio::time::timeout(Duration::from_secs(10), events.next()).await?;
+    assert_eq!(Some(EventType::Ready), ready.as_ref().map(Event::kind));
+
+    let role_ids = events
+        .by_ref()
+        .filter_map(|event| {
+            future::ready(match event {
+                Event::RoleDelete(role_delete) => Some(role_delete.role_id.get()),
+                _ => None,
+            })
+        })
+        .take(3)
+        .collect::<Vec<_>>();
+    let role_ids = tokio::time::timeout(Duration::from_secs(10), role_ids).await?;
+    assert_eq!([1, 2, 3].as_slice(), role_ids);
+
+    shard.shutdown();
+    gateway.await??;
+
+    let end = tokio::time::timeout(Duration::from_secs(10), events.next()).await?;
+    assert!(end.is_none());
+
+    Ok(())
+}
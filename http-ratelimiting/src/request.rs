@@ -176,6 +176,8 @@ pub enum Path {
     GuildsIdAuditLogs(u64),
     /// Operating on specific member's ban from one of the user's guilds.
     GuildsIdBansUserId(u64),
+    /// Operating on bans of multiple members from one of the user's guilds.
+    GuildsIdBulkBan(u64),
     /// Operating on one of the user's guilds' channels.
     GuildsIdChannels(u64),
     /// Operating on one of the user's guilds' widget.
@@ -202,6 +204,8 @@ pub enum Path {
     GuildsIdMembersMeNick(u64),
     /// Operating on one of the user's guilds' members by searching.
     GuildsIdMembersSearch(u64),
+    /// Operating on the MFA level of one of the user's guilds.
+    GuildsIdMfa(u64),
     /// Operating on one of the user's guilds' by previewing it.
     GuildsIdPreview(u64),
     /// Operating on one of the user's guilds' by pruning members.
@@ -250,6 +254,10 @@ pub enum Path {
     UsersId,
     /// Operating on the user's application information.
     OauthApplicationsMe,
+    /// Operating on `OAuth2` access tokens.
+    OauthToken,
+    /// Operating on the revocation of `OAuth2` access tokens.
+    OauthTokenRevoke,
     /// Operating on the user's connections.
     UsersIdConnections,
     /// Operating on the user's private channels.
@@ -367,6 +375,7 @@ impl FromStr for Path {
             ["guilds", id, "audit-logs"] => GuildsIdAuditLogs(parse_id(id)?),
             ["guilds", id, "bans"] => GuildsIdBans(parse_id(id)?),
             ["guilds", id, "bans", _] => GuildsIdBansUserId(parse_id(id)?),
+            ["guilds", id, "bulk-ban"] => GuildsIdBulkBan(parse_id(id)?),
             ["guilds", id, "channels"] => GuildsIdChannels(parse_id(id)?),
             ["guilds", id, "widget" | "widget.json"] => GuildsIdWidget(parse_id(id)?),
             ["guilds", id, "emojis"] => GuildsIdEmojis(parse_id(id)?),
@@ -377,6 +386,7 @@ impl FromStr for Path {
             ["guilds", id, "invites"] => GuildsIdInvites(parse_id(id)?),
             ["guilds", id, "members"] => GuildsIdMembers(parse_id(id)?),
             ["guilds", id, "members", "search"] => GuildsIdMembersSearch(parse_id(id)?),
+            ["guilds", id, "mfa"] => GuildsIdMfa(parse_id(id)?),
             ["guilds", id, "members", _] => GuildsIdMembersId(parse_id(id)?),
             ["guilds", id, "members", _, "roles", _] => GuildsIdMembersIdRolesId(parse_id(id)?),
             ["guilds", id, "members", "@me", "nick"] => GuildsIdMembersMeNick(parse_id(id)?),
@@ -408,6 +418,8 @@ impl FromStr for Path {
             ["sticker-packs"] => StickerPacks,
             ["stickers", _] => Stickers,
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
+            ["oauth2", "token"] => OauthToken,
+            ["oauth2", "token", "revoke"] => OauthTokenRevoke,
             ["users", _] => UsersId,
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
@@ -473,6 +485,7 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(Path::GuildsIdMfa(123), Path::from_str("/guilds/123/mfa")?);
 
         Ok(())
     }
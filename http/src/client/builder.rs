@@ -1,5 +1,11 @@
-use crate::{client::connector, Client};
-use hyper::header::HeaderMap;
+use crate::{
+    client::{
+        connector::{self, HttpClient},
+        etag::EtagCache,
+    },
+    Client,
+};
+use hyper::{client::connect::Connect, header::HeaderMap};
 use std::{
     sync::{atomic::AtomicBool, Arc},
     time::Duration,
@@ -10,10 +16,14 @@ use twilight_model::channel::message::allowed_mentions::AllowedMentions;
 #[derive(Debug)]
 /// A builder for [`Client`].
 pub struct ClientBuilder {
+    /// HTTP client using a custom connector, if one is set.
+    connector: Option<Arc<dyn HttpClient>>,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
+    etag_cache: bool,
     pub(crate) proxy: Option<Box<str>>,
     pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
     remember_invalid_token: bool,
+    retry_after: u8,
     pub(crate) default_headers: Option<HeaderMap>,
     pub(crate) timeout: Duration,
     pub(crate) token: Option<Box<str>>,
@@ -28,9 +38,9 @@ impl ClientBuilder {
 
     /// Build the [`Client`].
     pub fn build(self) -> Client {
-        let connector = connector::create();
-
-        let http = hyper::Client::builder().build(connector);
+        let http = self
+            .connector
+            .unwrap_or_else(|| Arc::new(hyper::Client::builder().build(connector::create())));
 
         let token_invalidated = if self.remember_invalid_token {
             Some(Arc::new(AtomicBool::new(false)))
@@ -38,11 +48,15 @@ impl ClientBuilder {
             None
         };
 
+        let etag_cache = self.etag_cache.then(|| Arc::new(EtagCache::default()));
+
         Client {
             http,
             default_headers: self.default_headers,
+            etag_cache,
             proxy: self.proxy,
-            ratelimiter: self.ratelimiter,
+            ratelimiter: self.ratelimiter.map(Arc::from),
+            retry_after: self.retry_after,
             timeout: self.timeout,
             token_invalidated,
             token: self.token,
@@ -51,6 +65,46 @@ impl ClientBuilder {
         }
     }
 
+    /// Set a custom connector to open connections with, in place of the
+    /// built-in connector.
+    ///
+    /// This is useful for routing requests through an authenticated proxy or
+    /// using mutual TLS. The connector is responsible for any TLS, as well as
+    /// for connecting to the [`proxy`] if one is set. Ratelimiting,
+    /// authorization, and the other headers of requests are unaffected.
+    ///
+    /// The [`timeout`] applies to the entire request, including opening a
+    /// connection, so any timeouts of the connector only take effect if they
+    /// are shorter.
+    ///
+    /// Defaults to a connector with the TLS backend of the enabled features.
+    ///
+    /// # Examples
+    ///
+    /// Use a connector with `TCP_NODELAY` enabled:
+    ///
+    /// ```
+    /// use hyper::client::HttpConnector;
+    /// use twilight_http::Client;
+    ///
+    /// let mut connector = HttpConnector::new();
+    /// connector.set_nodelay(true);
+    ///
+    /// let client = Client::builder()
+    ///     .connector(connector)
+    ///     .token("my token".to_owned())
+    ///     .build();
+    /// ```
+    ///
+    /// [`proxy`]: Self::proxy
+    /// [`timeout`]: Self::timeout
+    #[must_use = "has no effect if not built into a Client"]
+    pub fn connector<C: Connect + Clone + Send + Sync + 'static>(mut self, connector: C) -> Self {
+        self.connector = Some(Arc::new(hyper::Client::builder().build(connector)));
+
+        self
+    }
+
     /// Set the default allowed mentions setting to use on all messages sent through the HTTP
     /// client.
     #[must_use = "has no effect if not built into a Client"]
@@ -60,6 +114,28 @@ impl ClientBuilder {
         self
     }
 
+    /// Whether to cache responses carrying an `ETag` header to send
+    /// conditional requests.
+    ///
+    /// When enabled, the response of a `GET` request with an `ETag` is cached
+    /// by its path, and the next request to the same path sends the `ETag` as
+    /// the `If-None-Match` header. If Discord responds with 304 Not Modified,
+    /// the cached response is returned in its place. This is useful for
+    /// endpoints that rarely change, such as a guild's emojis or stickers.
+    ///
+    /// At most the [`ETAG_CACHE_CAPACITY`] most recently used responses are
+    /// cached.
+    ///
+    /// Defaults to false.
+    ///
+    /// [`ETAG_CACHE_CAPACITY`]: crate::client::ETAG_CACHE_CAPACITY
+    #[must_use = "has no effect if not built into a Client"]
+    pub const fn etag_cache(mut self, etag_cache: bool) -> Self {
+        self.etag_cache = etag_cache;
+
+        self
+    }
+
     /// Set the proxy to use for all HTTP(S) requests.
     ///
     /// **Note** that this isn't currently a traditional proxy, but is for
@@ -105,7 +181,13 @@ impl ClientBuilder {
 
     /// Set the timeout for HTTP requests.
     ///
+    /// A zero duration means requests never time out. The timeout may be
+    /// overridden per request via [`RequestBuilder::timeout`] or the
+    /// `timeout` method of typed request builders.
+    ///
     /// The default is 10 seconds.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
     #[must_use = "has no effect if not built into a Client"]
     pub const fn timeout(mut self, duration: Duration) -> Self {
         self.timeout = duration;
@@ -135,6 +217,37 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the maximum number of times to resend a request that received a 429
+    /// Too Many Requests response.
+    ///
+    /// Before each retry the client sleeps for the duration of the response's
+    /// `Retry-After` header, or for the global ratelimit's duration if the
+    /// ratelimit is global. Responses without a valid `Retry-After` header are
+    /// not retried. Each retry waits for a ticket from the ratelimiter like any
+    /// other request, and its response's headers update the ratelimiter.
+    ///
+    /// Defaults to 0, meaning 429 responses are returned as errors without
+    /// retrying.
+    ///
+    /// # Examples
+    ///
+    /// Retry requests that were ratelimited up to 3 times:
+    ///
+    /// ```
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .token("my token".to_owned())
+    ///     .retry_after(3)
+    ///     .build();
+    /// ```
+    #[must_use = "has no effect if not built into a Client"]
+    pub const fn retry_after(mut self, max_retries: u8) -> Self {
+        self.retry_after = max_retries;
+
+        self
+    }
+
     /// Set the token to use for HTTP requests.
     #[must_use = "has no effect if not built into a Client"]
     pub fn token(mut self, mut token: String) -> Self {
@@ -156,11 +269,14 @@ impl ClientBuilder {
 impl Default for ClientBuilder {
     fn default() -> Self {
         Self {
+            connector: None,
             default_allowed_mentions: None,
             default_headers: None,
+            etag_cache: false,
             proxy: None,
             ratelimiter: Some(Box::new(InMemoryRatelimiter::default())),
             remember_invalid_token: true,
+            retry_after: 0,
             timeout: Duration::from_secs(10),
             token: None,
             use_http: false,
@@ -1,5 +1,30 @@
 //! HTTP connectors with different features.
 
+use hyper::{
+    client::{connect::Connect, Client as HyperClient, ResponseFuture},
+    Body, Request,
+};
+use std::fmt::Debug;
+
+/// HTTP client sending requests over a connector, with the type of the
+/// connector erased.
+///
+/// This allows the [`Client`] to use either the built-in connector or a
+/// connector provided via [`ClientBuilder::connector`].
+///
+/// [`Client`]: super::Client
+/// [`ClientBuilder::connector`]: super::ClientBuilder::connector
+pub trait HttpClient: Debug + Send + Sync {
+    /// Send a request.
+    fn request(&self, request: Request<Body>) -> ResponseFuture;
+}
+
+impl<C: Connect + Clone + Send + Sync + 'static> HttpClient for HyperClient<C> {
+    fn request(&self, request: Request<Body>) -> ResponseFuture {
+        HyperClient::request(self, request)
+    }
+}
+
 /// HTTPS connector using `rustls` as a TLS backend.
 #[cfg(any(feature = "rustls-native-roots", feature = "rustls-webpki-roots"))]
 type HttpsConnector<T> = hyper_rustls::HttpsConnector<T>;
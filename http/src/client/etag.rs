@@ -0,0 +1,166 @@
+//! Cache of responses carrying an `ETag`, used to send conditional requests.
+
+use hyper::{
+    body::Bytes,
+    header::{HeaderMap, HeaderValue},
+    Body, Response as HyperResponse, StatusCode as HyperStatusCode,
+};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+/// Maximum number of responses kept by an [`EtagCache`].
+pub const ETAG_CACHE_CAPACITY: usize = 128;
+
+/// Response stored along with its `ETag`.
+#[derive(Debug)]
+pub struct CachedResponse {
+    /// Raw body of the response.
+    body: Bytes,
+    /// Value of the response's `ETag` header.
+    etag: HeaderValue,
+    /// Headers of the response.
+    headers: HeaderMap,
+    /// Status code of the response.
+    status: HyperStatusCode,
+}
+
+impl CachedResponse {
+    pub const fn new(
+        body: Bytes,
+        etag: HeaderValue,
+        headers: HeaderMap,
+        status: HyperStatusCode,
+    ) -> Self {
+        Self {
+            body,
+            etag,
+            headers,
+            status,
+        }
+    }
+
+    /// Value of the response's `ETag` header, to send as `If-None-Match`.
+    pub const fn etag(&self) -> &HeaderValue {
+        &self.etag
+    }
+
+    /// Recreate the response to return in place of a 304 Not Modified
+    /// response.
+    pub fn to_response(&self) -> HyperResponse<Body> {
+        let mut response = HyperResponse::new(Body::from(self.body.clone()));
+        *response.headers_mut() = self.headers.clone();
+        *response.status_mut() = self.status;
+
+        response
+    }
+}
+
+/// Request that may receive a 304 Not Modified response, along with where to
+/// cache its response.
+#[derive(Debug)]
+pub struct ConditionalRequest {
+    /// Cache to store the response in.
+    pub cache: Arc<EtagCache>,
+    /// Previously cached response, returned on a 304 Not Modified response.
+    pub cached: Option<Arc<CachedResponse>>,
+    /// Path of the request.
+    pub key: Box<str>,
+}
+
+/// Bounded least recently used cache of responses keyed by their request's
+/// path.
+#[derive(Debug)]
+pub struct EtagCache {
+    capacity: usize,
+    /// Cached responses, ordered from least to most recently used.
+    entries: Mutex<VecDeque<(Box<str>, Arc<CachedResponse>)>>,
+}
+
+impl EtagCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Get the cached response of a path, marking it as most recently used.
+    pub fn get(&self, key: &str) -> Option<Arc<CachedResponse>> {
+        let mut entries = self.entries.lock().expect("etag cache poisoned");
+        let index = entries
+            .iter()
+            .position(|(entry_key, _)| &**entry_key == key)?;
+        let entry = entries.remove(index)?;
+        let response = Arc::clone(&entry.1);
+        entries.push_back(entry);
+
+        Some(response)
+    }
+
+    /// Cache the response of a path, evicting the least recently used
+    /// response if the cache is full.
+    pub fn insert(&self, key: Box<str>, response: CachedResponse) {
+        let mut entries = self.entries.lock().expect("etag cache poisoned");
+
+        if let Some(index) = entries.iter().position(|(entry_key, _)| *entry_key == key) {
+            entries.remove(index);
+        }
+
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+
+        entries.push_back((key, Arc::new(response)));
+    }
+}
+
+impl Default for EtagCache {
+    fn default() -> Self {
+        Self::new(ETAG_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CachedResponse, EtagCache};
+    use hyper::{
+        body::Bytes,
+        header::{HeaderMap, HeaderValue},
+        StatusCode,
+    };
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(EtagCache: Debug, Default, Send, Sync);
+
+    fn response(etag: &'static str) -> CachedResponse {
+        CachedResponse::new(
+            Bytes::from_static(b"[]"),
+            HeaderValue::from_static(etag),
+            HeaderMap::new(),
+            StatusCode::OK,
+        )
+    }
+
+    #[test]
+    fn test_least_recently_used() {
+        let cache = EtagCache::new(2);
+        cache.insert("a".into(), response("1"));
+        cache.insert("b".into(), response("2"));
+
+        // Using "a" makes "b" the least recently used entry.
+        assert_eq!("1", cache.get("a").unwrap().etag());
+        cache.insert("c".into(), response("3"));
+
+        assert!(cache.get("b").is_none());
+        assert_eq!("1", cache.get("a").unwrap().etag());
+        assert_eq!("3", cache.get("c").unwrap().etag());
+
+        // Replacing an entry doesn't evict others.
+        cache.insert("c".into(), response("4"));
+        assert_eq!("4", cache.get("c").unwrap().etag());
+        assert!(cache.get("a").is_some());
+    }
+}
@@ -1,19 +1,27 @@
 mod builder;
 mod connector;
+pub(crate) mod etag;
 mod interaction;
+mod oauth;
 
-pub use self::{builder::ClientBuilder, interaction::InteractionClient};
+pub use self::{
+    builder::ClientBuilder, etag::ETAG_CACHE_CAPACITY, interaction::InteractionClient,
+    oauth::OauthClient,
+};
 
 #[allow(deprecated)]
 use crate::{
-    client::connector::Connector,
+    client::{
+        connector::HttpClient,
+        etag::{ConditionalRequest, EtagCache},
+    },
     error::{Error, ErrorType},
     request::{
         channel::{
             invite::{CreateInvite, DeleteInvite, GetChannelInvites, GetInvite},
             message::{
                 CreateMessage, CrosspostMessage, DeleteMessage, DeleteMessages, GetChannelMessages,
-                GetMessage, UpdateMessage,
+                GetMessage, PurgeMessages, UpdateMessage,
             },
             reaction::{
                 delete_reaction::TargetUser, CreateReaction, DeleteAllReaction, DeleteAllReactions,
@@ -33,11 +41,12 @@ use crate::{
                 GetChannelWebhooks, GetWebhook, GetWebhookMessage, UpdateWebhook,
                 UpdateWebhookMessage, UpdateWebhookWithToken,
             },
-            CreatePin, CreateTypingTrigger, DeleteChannel, DeleteChannelPermission, DeletePin,
-            FollowNewsChannel, GetChannel, GetPins, UpdateChannel, UpdateChannelPermission,
+            AddGroupRecipient, CreatePin, CreateTypingTrigger, DeleteChannel,
+            DeleteChannelPermission, DeletePin, FollowNewsChannel, GetChannel, GetPins,
+            RemoveGroupRecipient, UpdateChannel, UpdateChannelPermission,
         },
         guild::{
-            ban::{CreateBan, DeleteBan, GetBan, GetBans},
+            ban::{CreateBan, CreateBans, DeleteBan, GetBan, GetBans},
             create_guild::CreateGuildError,
             emoji::{CreateEmoji, DeleteEmoji, GetEmoji, GetEmojis, UpdateEmoji},
             integration::{DeleteGuildIntegration, GetGuildIntegrations},
@@ -55,8 +64,9 @@ use crate::{
             CreateGuild, CreateGuildChannel, CreateGuildPrune, DeleteGuild, GetActiveThreads,
             GetAuditLog, GetGuild, GetGuildChannels, GetGuildInvites, GetGuildPreview,
             GetGuildPruneCount, GetGuildVanityUrl, GetGuildVoiceRegions, GetGuildWebhooks,
-            GetGuildWelcomeScreen, GetGuildWidget, UpdateCurrentMember, UpdateGuild,
-            UpdateGuildChannelPositions, UpdateGuildWelcomeScreen, UpdateGuildWidget,
+            GetGuildWelcomeScreen, GetGuildWidget, GetGuildWidgetSettings, UpdateCurrentMember,
+            UpdateGuild, UpdateGuildChannelPositions, UpdateGuildMfa, UpdateGuildWelcomeScreen,
+            UpdateGuildWidgetSettings,
         },
         scheduled_event::{
             CreateGuildScheduledEvent, DeleteGuildScheduledEvent, GetGuildScheduledEvent,
@@ -68,18 +78,21 @@ use crate::{
             SyncTemplate, UpdateTemplate,
         },
         user::{
-            CreatePrivateChannel, GetCurrentUser, GetCurrentUserConnections,
+            CreateGroupDm, CreatePrivateChannel, GetCurrentUser, GetCurrentUserConnections,
             GetCurrentUserGuildMember, GetCurrentUserGuilds, GetUser, LeaveGuild,
             UpdateCurrentUser,
         },
         GetGateway, GetUserApplicationInfo, GetVoiceRegions, Method, Request,
     },
-    response::ResponseFuture,
+    response::{future::Retry, BatchResponseFuture, ResponseFuture},
     API_VERSION,
 };
 use hyper::{
-    client::Client as HyperClient,
-    header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
+    body::Bytes,
+    header::{
+        HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, IF_NONE_MATCH,
+        USER_AGENT,
+    },
     Body,
 };
 use std::{
@@ -91,9 +104,10 @@ use std::{
     time::Duration,
 };
 use tokio::time;
-use twilight_http_ratelimiting::Ratelimiter;
+use twilight_http_ratelimiting::{Ratelimiter, WaitForTicketFuture};
 use twilight_model::{
     channel::{message::allowed_mentions::AllowedMentions, ChannelType},
+    guild::MfaLevel,
     http::permission_overwrite::PermissionOverwrite,
     id::{
         marker::{
@@ -183,9 +197,16 @@ use twilight_validate::{
 pub struct Client {
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
     default_headers: Option<HeaderMap>,
-    http: HyperClient<Connector>,
+    /// Cache of responses to send conditional requests for, if enabled.
+    etag_cache: Option<Arc<EtagCache>>,
+    /// HTTP client sending requests over the configured connector.
+    http: Arc<dyn HttpClient>,
     proxy: Option<Box<str>>,
-    ratelimiter: Option<Box<dyn Ratelimiter>>,
+    /// Ratelimiter, shared with requests that are resent after a 429
+    /// response.
+    ratelimiter: Option<Arc<dyn Ratelimiter>>,
+    /// Maximum number of times to resend a request after a 429 response.
+    retry_after: u8,
     timeout: Duration,
     /// Whether the token has been invalidated.
     ///
@@ -263,6 +284,19 @@ impl Client {
         InteractionClient::new(self, application_id)
     }
 
+    /// Create an interface for managing `OAuth2` access tokens on behalf of
+    /// users.
+    ///
+    /// Requests made through the returned interface authenticate with the
+    /// application's ID and client secret instead of the client's token.
+    pub const fn oauth<'a>(
+        &'a self,
+        application_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+    ) -> OauthClient<'a> {
+        OauthClient::new(self, application_id, client_secret)
+    }
+
     /// Get an immutable reference to the default [`AllowedMentions`] for sent
     /// messages.
     pub const fn default_allowed_mentions(&self) -> Option<&AllowedMentions> {
@@ -363,6 +397,50 @@ impl Client {
         CreateBan::new(self, guild_id, user_id)
     }
 
+    /// Ban up to 200 users from a guild at once, optionally with the number
+    /// of seconds' worth of messages to delete and the reason.
+    ///
+    /// Responds with the users that were and were not banned.
+    ///
+    /// # Examples
+    ///
+    /// Ban users `200` and `300` from guild `100`, for the reason `"raid"`:
+    ///
+    /// ```no_run
+    /// # use twilight_http::{request::AuditLogReason, Client};
+    /// use twilight_model::id::Id;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new("my token".to_owned());
+    /// #
+    /// let guild_id = Id::new(100);
+    /// let user_ids = [Id::new(200), Id::new(300)];
+    ///
+    /// let bulk_ban = client
+    ///     .create_bans(guild_id, &user_ids)?
+    ///     .reason("raid")?
+    ///     .exec()
+    ///     .await?
+    ///     .model()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`CreateGuildBansUsers`] if the number of
+    /// users is not between 1 and 200.
+    ///
+    /// [`CreateGuildBansUsers`]: twilight_validate::request::ValidationErrorType::CreateGuildBansUsers
+    pub const fn create_bans<'a>(
+        &'a self,
+        guild_id: Id<GuildMarker>,
+        user_ids: &'a [Id<UserMarker>],
+    ) -> Result<CreateBans<'a>, ValidationError> {
+        CreateBans::new(self, guild_id, user_ids)
+    }
+
     /// Remove a ban from a user in a guild.
     ///
     /// # Examples
@@ -781,6 +859,20 @@ impl Client {
         UpdateGuild::new(self, guild_id)
     }
 
+    /// Update the MFA level required for moderation actions in a guild.
+    ///
+    /// The current user must be the owner of the guild. See
+    /// [Discord Docs/Modify Guild MFA Level].
+    ///
+    /// [Discord Docs/Modify Guild MFA Level]: https://discord.com/developers/docs/resources/guild#modify-guild-mfa-level
+    pub const fn update_guild_mfa(
+        &self,
+        guild_id: Id<GuildMarker>,
+        level: MfaLevel,
+    ) -> UpdateGuildMfa<'_> {
+        UpdateGuildMfa::new(self, guild_id, level)
+    }
+
     /// Leave a guild by id.
     pub const fn leave_guild(&self, guild_id: Id<GuildMarker>) -> LeaveGuild<'_> {
         LeaveGuild::new(self, guild_id)
@@ -832,18 +924,53 @@ impl Client {
         UpdateGuildChannelPositions::new(self, guild_id, channel_positions)
     }
 
-    /// Get the guild widget.
+    /// Get a guild's public widget.
+    ///
+    /// This previously got the settings of the guild's widget, which are now
+    /// retrieved via [`guild_widget_settings`].
+    ///
+    /// Use [`widget_image_url`] to get the URL of the widget's image instead.
     ///
     /// See [Discord Docs/Get Guild Widget].
     ///
     /// [Discord Docs/Get Guild Widget]: https://discord.com/developers/docs/resources/guild#get-guild-widget
+    /// [`guild_widget_settings`]: Self::guild_widget_settings
+    /// [`widget_image_url`]: crate::request::guild::widget_image_url
     pub const fn guild_widget(&self, guild_id: Id<GuildMarker>) -> GetGuildWidget<'_> {
         GetGuildWidget::new(self, guild_id)
     }
 
+    /// Get the settings of a guild's widget.
+    ///
+    /// See [Discord Docs/Get Guild Widget Settings].
+    ///
+    /// [Discord Docs/Get Guild Widget Settings]: https://discord.com/developers/docs/resources/guild#get-guild-widget-settings
+    pub const fn guild_widget_settings(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> GetGuildWidgetSettings<'_> {
+        GetGuildWidgetSettings::new(self, guild_id)
+    }
+
     /// Modify the guild widget.
-    pub const fn update_guild_widget(&self, guild_id: Id<GuildMarker>) -> UpdateGuildWidget<'_> {
-        UpdateGuildWidget::new(self, guild_id)
+    #[deprecated(since = "0.11.1", note = "use `update_guild_widget_settings` instead")]
+    pub const fn update_guild_widget(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> UpdateGuildWidgetSettings<'_> {
+        self.update_guild_widget_settings(guild_id)
+    }
+
+    /// Update the settings of a guild's widget.
+    ///
+    /// See [Discord Docs/Modify Guild Widget].
+    ///
+    /// [Discord Docs/Modify Guild Widget]: https://discord.com/developers/docs/resources/guild#modify-guild-widget
+    pub const fn update_guild_widget_settings(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> UpdateGuildWidgetSettings<'_> {
+        UpdateGuildWidgetSettings::new(self, guild_id)
     }
 
     /// Get the guild's integrations.
@@ -1270,6 +1397,43 @@ impl Client {
         DeleteMessages::new(self, channel_id, message_ids)
     }
 
+    /// Delete any number of messages by [`Id<ChannelMarker>`] and a list of
+    /// [`Id<MessageMarker>`]s, regardless of their age.
+    ///
+    /// Messages newer than two weeks are deleted via [`delete_messages`] in
+    /// batches of 100, while older messages are deleted one by one via
+    /// [`delete_message`]. Refer to [`PurgeMessages`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let channel_id = Id::new(1);
+    /// let message_ids = [Id::new(2), Id::new(3), Id::new(4)];
+    ///
+    /// let purged = client.purge_messages(channel_id, &message_ids).exec().await?;
+    /// println!(
+    ///     "bulk deleted {} and individually deleted {} messages",
+    ///     purged.bulk_deleted, purged.individually_deleted,
+    /// );
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`delete_message`]: Self::delete_message
+    /// [`delete_messages`]: Self::delete_messages
+    pub const fn purge_messages<'a>(
+        &'a self,
+        channel_id: Id<ChannelMarker>,
+        message_ids: &'a [Id<MessageMarker>],
+    ) -> PurgeMessages<'a> {
+        PurgeMessages::new(self, channel_id, message_ids)
+    }
+
     /// Update a message by [`Id<ChannelMarker>`] and [`Id<MessageMarker>`].
     ///
     /// You can pass [`None`] to any of the methods to remove the associated
@@ -1456,6 +1620,44 @@ impl Client {
         CreatePrivateChannel::new(self, recipient_id)
     }
 
+    /// Create a group DM with multiple users.
+    ///
+    /// Requires the `OAuth2` access tokens of the users, who must have
+    /// authorized the application with the `gdm.join` scope. Refer to
+    /// [Discord Docs/Create Group DM].
+    ///
+    /// This endpoint is limited to 10 active group DMs.
+    ///
+    /// [Discord Docs/Create Group DM]: https://discord.com/developers/docs/resources/user#create-group-dm
+    pub const fn create_group_dm<'a>(&'a self, access_tokens: &'a [&'a str]) -> CreateGroupDm<'a> {
+        CreateGroupDm::new(self, access_tokens)
+    }
+
+    /// Add a user to a group DM.
+    ///
+    /// Requires the `OAuth2` access token of the user, who must have
+    /// authorized the application with the `gdm.join` scope. Refer to
+    /// [Discord Docs/Group DM Add Recipient].
+    ///
+    /// [Discord Docs/Group DM Add Recipient]: https://discord.com/developers/docs/resources/channel#group-dm-add-recipient
+    pub const fn add_group_recipient<'a>(
+        &'a self,
+        channel_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+        access_token: &'a str,
+    ) -> AddGroupRecipient<'a> {
+        AddGroupRecipient::new(self, channel_id, user_id, access_token)
+    }
+
+    /// Remove a user from a group DM.
+    pub const fn remove_group_recipient(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+    ) -> RemoveGroupRecipient<'_> {
+        RemoveGroupRecipient::new(self, channel_id, user_id)
+    }
+
     /// Get the roles of a guild.
     pub const fn roles(&self, guild_id: Id<GuildMarker>) -> GetGuildRoles<'_> {
         GetGuildRoles::new(self, guild_id)
@@ -2386,6 +2588,64 @@ impl Client {
         }
     }
 
+    /// Execute a batch of requests concurrently, returning a future resolving
+    /// to their [`Response`]s in the order the requests were provided in.
+    ///
+    /// All requests are queued with the ratelimiter at once, rather than
+    /// waiting for each request to complete before sending the next one.
+    /// Requests in the same ratelimit bucket are still sent one at a time as
+    /// the bucket allows.
+    ///
+    /// # Examples
+    ///
+    /// Delete several messages in a channel one by one, without waiting for
+    /// each deletion before starting the next:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::env;
+    /// use twilight_http::{
+    ///     request::TryIntoRequest, response::marker::EmptyBody, Client,
+    /// };
+    /// use twilight_model::id::Id;
+    ///
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    /// let channel_id = Id::new(1);
+    ///
+    /// let requests = [Id::new(2), Id::new(3), Id::new(4)]
+    ///     .into_iter()
+    ///     .map(|message_id| {
+    ///         client
+    ///             .delete_message(channel_id, message_id)
+    ///             .try_into_request()
+    ///     })
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// for result in client.request_all::<EmptyBody>(requests).await {
+    ///     result?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Each request may fail for the same reasons as requests executed via
+    /// [`request`].
+    ///
+    /// [`Response`]: super::response::Response
+    /// [`request`]: Self::request
+    pub fn request_all<T>(
+        &self,
+        requests: impl IntoIterator<Item = Request>,
+    ) -> BatchResponseFuture<T> {
+        let futures = requests
+            .into_iter()
+            .map(|request| self.request(request))
+            .collect();
+
+        BatchResponseFuture::new(futures)
+    }
+
     #[allow(clippy::too_many_lines)]
     fn try_request<T>(&self, request: Request) -> Result<ResponseFuture<T>, Error> {
         if let Some(token_invalidated) = self.token_invalidated.as_ref() {
@@ -2404,9 +2664,17 @@ impl Client {
             method,
             path,
             ratelimit_path,
+            timeout,
             use_authorization_token,
         } = request;
 
+        // A zero timeout means the request never times out, which a timeout
+        // of the maximum duration is equivalent to.
+        let timeout = match timeout.unwrap_or(self.timeout) {
+            Duration::ZERO => Duration::MAX,
+            timeout => timeout,
+        };
+
         let protocol = if self.use_http { "http" } else { "https" };
         let host = self.proxy.as_deref().unwrap_or("discord.com");
 
@@ -2477,38 +2745,85 @@ impl Client {
             }
         }
 
-        let req = if let Some(form) = form {
+        // Send the `ETag` of the previous response to the same path, so that
+        // Discord may respond with a 304 Not Modified instead of the body.
+        let conditional = match &self.etag_cache {
+            Some(cache) if method == Method::Get => {
+                let cached = cache.get(&path);
+
+                if let (Some(cached), Some(headers)) = (&cached, builder.headers_mut()) {
+                    headers.insert(IF_NONE_MATCH, cached.etag().clone());
+                }
+
+                Some(ConditionalRequest {
+                    cache: Arc::clone(cache),
+                    cached,
+                    key: path.into_boxed_str(),
+                })
+            }
+            _ => None,
+        };
+
+        let bytes = if let Some(form) = form {
             let form_bytes = form.build();
 
             if let Some(headers) = builder.headers_mut() {
                 headers.insert(CONTENT_LENGTH, HeaderValue::from(form_bytes.len()));
             };
 
-            builder
-                .body(Body::from(form_bytes))
-                .map_err(|source| Error {
-                    kind: ErrorType::BuildingRequest,
-                    source: Some(Box::new(source)),
-                })?
+            Bytes::from(form_bytes)
         } else if let Some(bytes) = body {
-            builder.body(Body::from(bytes)).map_err(|source| Error {
-                kind: ErrorType::BuildingRequest,
-                source: Some(Box::new(source)),
-            })?
-        } else if method == Method::Put || method == Method::Post || method == Method::Patch {
-            if let Some(headers) = builder.headers_mut() {
-                headers.insert(CONTENT_LENGTH, HeaderValue::from(0));
+            Bytes::from(bytes)
+        } else {
+            if method == Method::Put || method == Method::Post || method == Method::Patch {
+                if let Some(headers) = builder.headers_mut() {
+                    headers.insert(CONTENT_LENGTH, HeaderValue::from(0));
+                }
             }
 
-            builder.body(Body::empty()).map_err(|source| Error {
+            Bytes::new()
+        };
+
+        let req = builder
+            .body(Body::from(bytes.clone()))
+            .map_err(|source| Error {
                 kind: ErrorType::BuildingRequest,
                 source: Some(Box::new(source)),
-            })?
+            })?;
+
+        // Keep a copy of the request around to resend it after a 429
+        // response, as the body of a sent request is consumed.
+        let retry = if self.retry_after > 0 {
+            let http = Arc::clone(&self.http);
+            let headers = req.headers().clone();
+            let method = req.method().clone();
+            let uri = req.uri().clone();
+
+            // Each resent request is a request of its own to the API, so it
+            // waits for a ticket of its own.
+            let wait_for_ticket = self.ratelimiter.as_ref().map(|ratelimiter| {
+                let ratelimiter = Arc::clone(ratelimiter);
+                let ratelimit_path = ratelimit_path.clone();
+
+                Box::new(move || ratelimiter.wait_for_ticket(ratelimit_path.clone()))
+                    as Box<dyn Fn() -> WaitForTicketFuture + Send + Sync>
+            });
+
+            Some(Retry::new(
+                self.retry_after,
+                timeout,
+                Box::new(move || {
+                    let mut req = hyper::Request::new(Body::from(bytes.clone()));
+                    *req.headers_mut() = headers.clone();
+                    *req.method_mut() = method.clone();
+                    *req.uri_mut() = uri.clone();
+
+                    http.request(req)
+                }),
+                wait_for_ticket,
+            ))
         } else {
-            builder.body(Body::empty()).map_err(|source| Error {
-                kind: ErrorType::BuildingRequest,
-                source: Some(Box::new(source)),
-            })?
+            None
         };
 
         let inner = self.http.request(req);
@@ -2532,15 +2847,386 @@ impl Client {
                 None,
                 invalid_token,
                 tx_future,
-                self.timeout,
+                timeout,
                 inner,
+                retry,
+                conditional,
             ))
         } else {
             Ok(ResponseFuture::new(
                 invalid_token,
-                time::timeout(self.timeout, inner),
+                time::timeout(timeout, inner),
                 None,
+                retry,
+                conditional,
             ))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Client;
+    use crate::{
+        api_error::{ApiError, RatelimitedApiError},
+        error::ErrorType,
+        request::Request,
+        response::{marker::EmptyBody, BatchResponseFuture},
+        routing::Route,
+        test_server,
+    };
+    use hyper::{
+        service::Service, Body, Request as HyperRequest, Response as HyperResponse, StatusCode, Uri,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::Infallible,
+        error::Error,
+        fmt::Debug,
+        future::Future,
+        io::Error as IoError,
+        net::SocketAddr,
+        pin::Pin,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc,
+        },
+        task::{Context, Poll},
+        time::Duration,
+    };
+    use tokio::net::TcpStream;
+    use twilight_http_ratelimiting::Path;
+    use twilight_model::id::Id;
+
+    assert_impl_all!(BatchResponseFuture<EmptyBody>: Future, Send);
+    assert_impl_all!(Client: Debug, Send, Sync);
+
+    /// Spawn a server responding to every request with the number of requests
+    /// it has received so far and ratelimit headers for a bucket of 10.
+    fn serve() -> SocketAddr {
+        let count = Arc::new(AtomicU64::new(0));
+
+        test_server::serve(move |_| {
+            let number = count.fetch_add(1, Ordering::Relaxed) + 1;
+
+            async move {
+                HyperResponse::builder()
+                    .header("x-ratelimit-bucket", "bucket")
+                    .header("x-ratelimit-limit", "10")
+                    .header("x-ratelimit-remaining", (10 - number).to_string())
+                    .header("x-ratelimit-reset", "99999999999")
+                    .header("x-ratelimit-reset-after", "60")
+                    .body(Body::from(number.to_string()))
+            }
+        })
+    }
+
+    /// Spawn a server responding to requests with an `ETag` and the number of
+    /// requests it has received so far, or with 304 Not Modified if the
+    /// request's `If-None-Match` matches the `ETag`.
+    fn serve_etag() -> (SocketAddr, Arc<AtomicU64>) {
+        let count = Arc::new(AtomicU64::new(0));
+        let server_count = Arc::clone(&count);
+
+        let addr = test_server::serve(move |request: HyperRequest<Body>| {
+            let number = server_count.fetch_add(1, Ordering::Relaxed) + 1;
+            let not_modified = request
+                .headers()
+                .get("if-none-match")
+                .map_or(false, |etag| etag == r#""etag""#);
+
+            async move {
+                let builder = HyperResponse::builder().header("etag", r#""etag""#);
+
+                if not_modified {
+                    builder.status(StatusCode::NOT_MODIFIED).body(Body::empty())
+                } else {
+                    builder.body(Body::from(number.to_string()))
+                }
+            }
+        });
+
+        (addr, count)
+    }
+
+    /// Connector opening every connection to a local server, regardless of
+    /// the requested host.
+    #[derive(Clone)]
+    struct LocalConnector {
+        addr: SocketAddr,
+        connections: Arc<AtomicU64>,
+    }
+
+    impl Service<Uri> for LocalConnector {
+        type Response = TcpStream;
+        type Error = IoError;
+        type Future = Pin<Box<dyn Future<Output = Result<TcpStream, IoError>> + Send>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: Uri) -> Self::Future {
+            self.connections.fetch_add(1, Ordering::Relaxed);
+
+            Box::pin(TcpStream::connect(self.addr))
+        }
+    }
+
+    /// Spawn a server responding to every request after a delay.
+    fn serve_delayed(delay: Duration) -> SocketAddr {
+        test_server::serve(move |_| async move {
+            tokio::time::sleep(delay).await;
+
+            Ok::<_, Infallible>(HyperResponse::new(Body::from("[]")))
+        })
+    }
+
+    /// Spawn a server responding to the first request with a 429 response
+    /// that has a `Retry-After` of 1 second, and to every other request with
+    /// the number of requests it has received so far and ratelimit headers
+    /// for a bucket of 10.
+    fn serve_ratelimited(global: bool) -> (SocketAddr, Arc<AtomicU64>) {
+        let count = Arc::new(AtomicU64::new(0));
+        let server_count = Arc::clone(&count);
+
+        let addr = test_server::serve(move |_| {
+            let number = server_count.fetch_add(1, Ordering::Relaxed) + 1;
+
+            async move {
+                if number > 1 {
+                    return HyperResponse::builder()
+                        .header("x-ratelimit-bucket", "bucket")
+                        .header("x-ratelimit-limit", "10")
+                        .header("x-ratelimit-remaining", "9")
+                        .header("x-ratelimit-reset", "99999999999")
+                        .header("x-ratelimit-reset-after", "60")
+                        .body(Body::from(number.to_string()));
+                }
+
+                let builder = HyperResponse::builder()
+                    .status(StatusCode::TOO_MANY_REQUESTS)
+                    .header("retry-after", "1");
+
+                let builder = if global {
+                    builder
+                        .header("x-ratelimit-global", "true")
+                        .header("x-ratelimit-scope", "global")
+                } else {
+                    builder
+                        .header("x-ratelimit-bucket", "bucket")
+                        .header("x-ratelimit-limit", "1")
+                        .header("x-ratelimit-remaining", "0")
+                        .header("x-ratelimit-reset", "99999999999")
+                        .header("x-ratelimit-reset-after", "1")
+                        .header("x-ratelimit-scope", "user")
+                };
+
+                builder.body(Body::from(format!(
+                    r#"{{"global":{global},"message":"You are being rate limited.","retry_after":1.0}}"#
+                )))
+            }
+        });
+
+        (addr, count)
+    }
+
+    #[tokio::test]
+    async fn test_request_all() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let addr = serve();
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("Bot token".to_owned())
+            .build();
+
+        let requests = (1..=5)
+            .map(|user_id| Request::from_route(&Route::GetUser { user_id }))
+            .collect::<Vec<_>>();
+
+        let mut bodies = Vec::new();
+
+        for result in client.request_all::<EmptyBody>(requests).await {
+            let response = result?;
+            assert!(response.status().is_success());
+            bodies.push(response.text().await?);
+        }
+
+        // Requests in the same bucket are sent one at a time, so the server
+        // sees them in the order they were provided in.
+        assert_eq!(["1", "2", "3", "4", "5"].as_slice(), bodies);
+
+        let bucket = client
+            .ratelimiter()
+            .expect("default ratelimiter")
+            .bucket(&Path::UsersId)
+            .await?
+            .expect("bucket from ratelimit headers");
+        assert_eq!(10, bucket.limit());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_after() -> Result<(), Box<dyn Error + Send + Sync>> {
+        for global in [false, true] {
+            let (addr, count) = serve_ratelimited(global);
+            let client = Client::builder()
+                .proxy(addr.to_string(), true)
+                .retry_after(2)
+                .token("Bot token".to_owned())
+                .build();
+
+            let response = client
+                .request::<EmptyBody>(Request::from_route(&Route::GetUser { user_id: 1 }))
+                .await?;
+
+            assert!(response.status().is_success());
+            assert_eq!("2", response.text().await?);
+            assert_eq!(2, count.load(Ordering::Relaxed));
+
+            // The retry's ticket reports the successful response's headers,
+            // which the ratelimiter processes in the background.
+            let ratelimiter = client.ratelimiter().expect("default ratelimiter");
+            tokio::time::timeout(Duration::from_secs(1), async {
+                loop {
+                    match ratelimiter.bucket(&Path::UsersId).await {
+                        Ok(Some(bucket)) if bucket.remaining() == 9 => break,
+                        _ => tokio::time::sleep(Duration::from_millis(10)).await,
+                    }
+                }
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_disabled() {
+        let (addr, count) = serve_ratelimited(false);
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("Bot token".to_owned())
+            .build();
+
+        let error = client
+            .request::<EmptyBody>(Request::from_route(&Route::GetUser { user_id: 1 }))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response {
+                error: ApiError::Ratelimited(RatelimitedApiError { global: false, .. }),
+                status,
+                ..
+            } if status.get() == 429
+        ));
+        assert_eq!(1, count.load(Ordering::Relaxed));
+    }
+
+    #[tokio::test]
+    async fn test_etag_cache() -> Result<(), Box<dyn Error + Send + Sync>> {
+        for etag_cache in [false, true] {
+            let (addr, count) = serve_etag();
+            let client = Client::builder()
+                .etag_cache(etag_cache)
+                .proxy(addr.to_string(), true)
+                .token("Bot token".to_owned())
+                .build();
+
+            let mut bodies = Vec::new();
+
+            for _ in 0..2 {
+                let response = client
+                    .request::<EmptyBody>(Request::from_route(&Route::GetEmojis { guild_id: 1 }))
+                    .await?;
+
+                assert!(response.status().is_success());
+                bodies.push(response.text().await?);
+            }
+
+            assert_eq!(2, count.load(Ordering::Relaxed));
+
+            // With the cache, the second request receives a 304 Not Modified
+            // and returns the body of the first response.
+            let expected = if etag_cache { ["1", "1"] } else { ["1", "2"] };
+            assert_eq!(expected.as_slice(), bodies);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let addr = serve_delayed(Duration::from_millis(500));
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .timeout(Duration::from_secs(5))
+            .token("Bot token".to_owned())
+            .build();
+
+        let route = Route::GetEmojis { guild_id: 1 };
+
+        // The request's timeout fires before the client's timeout.
+        let request = Request::builder(&route)
+            .timeout(Duration::from_millis(50))
+            .build();
+        let error = client.request::<EmptyBody>(request).await.unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+
+        // Typed request builders carry their timeout into the request.
+        let error = client
+            .emojis(Id::new(1))
+            .timeout(Duration::from_millis(50))
+            .exec()
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+
+        // Other requests fall back to the client's timeout.
+        let response = client
+            .request::<EmptyBody>(Request::from_route(&route))
+            .await?;
+        assert!(response.status().is_success());
+
+        // A zero timeout means requests never time out.
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .timeout(Duration::ZERO)
+            .token("Bot token".to_owned())
+            .build();
+        let response = client
+            .request::<EmptyBody>(Request::from_route(&route))
+            .await?;
+        assert!(response.status().is_success());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_connector() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let connector = LocalConnector {
+            addr: serve_delayed(Duration::ZERO),
+            connections: Arc::new(AtomicU64::new(0)),
+        };
+        let connections = Arc::clone(&connector.connections);
+
+        // Without a proxy requests are sent to Discord, but the connector
+        // connects to the local server instead.
+        let client = Client::builder()
+            .connector(connector)
+            .token("Bot token".to_owned())
+            .build();
+
+        let response = client
+            .request::<EmptyBody>(Request::from_route(&Route::GetEmojis { guild_id: 1 }))
+            .await?;
+
+        assert!(response.status().is_success());
+        assert_eq!("[]", response.text().await?);
+        assert_eq!(1, connections.load(Ordering::Relaxed));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{
+    request::oauth::{ExchangeOauthCode, RefreshOauthToken, RevokeOauthToken},
+    Client,
+};
+use twilight_model::id::{marker::ApplicationMarker, Id};
+
+/// Client interface for managing `OAuth2` access tokens on behalf of users.
+///
+/// Requests made through this interface authenticate with the application's
+/// client ID and secret and never send the client's bot token. The returned
+/// access tokens can be used to create a separate [`Client`] with a bearer
+/// token.
+///
+/// # Examples
+///
+/// Refresh an access token and create a client using the new token:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+/// let application_id = Id::new(123);
+/// let client_secret = env::var("CLIENT_SECRET")?;
+///
+/// let access_token = client
+///     .oauth(application_id, &client_secret)
+///     .refresh_token("refresh token here")
+///     .exec()
+///     .await?
+///     .model()
+///     .await?;
+///
+/// println!("token expires in {} seconds", access_token.expires_in);
+///
+/// let user_client = Client::new(format!("Bearer {}", access_token.access_token));
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct OauthClient<'a> {
+    application_id: Id<ApplicationMarker>,
+    client: &'a Client,
+    client_secret: &'a str,
+}
+
+impl<'a> OauthClient<'a> {
+    /// Create a new interface for managing `OAuth2` access tokens.
+    pub(super) const fn new(
+        client: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+    ) -> Self {
+        Self {
+            application_id,
+            client,
+            client_secret,
+        }
+    }
+
+    /// Exchange an authorization code for an access token.
+    ///
+    /// The redirect URI must be the one the user was redirected to with the
+    /// code.
+    pub const fn exchange_code(
+        &'a self,
+        code: &'a str,
+        redirect_uri: &'a str,
+    ) -> ExchangeOauthCode<'a> {
+        ExchangeOauthCode::new(
+            self.client,
+            self.application_id,
+            self.client_secret,
+            code,
+            redirect_uri,
+        )
+    }
+
+    /// Refresh an access token by its refresh token.
+    pub const fn refresh_token(&'a self, refresh_token: &'a str) -> RefreshOauthToken<'a> {
+        RefreshOauthToken::new(
+            self.client,
+            self.application_id,
+            self.client_secret,
+            refresh_token,
+        )
+    }
+
+    /// Revoke an access token or refresh token.
+    pub const fn revoke_token(&'a self, token: &'a str) -> RevokeOauthToken<'a> {
+        RevokeOauthToken::new(self.client, self.application_id, self.client_secret, token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OauthClient;
+    use crate::{test_server, Client};
+    use hyper::{
+        body,
+        header::{AUTHORIZATION, CONTENT_TYPE},
+        Body, Request as HyperRequest, Response as HyperResponse, StatusCode,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(OauthClient<'_>: Debug, Send, Sync);
+
+    const ACCESS_TOKEN: &str = r#"{
+        "access_token": "6qrZcUqja7812RVdnEKjpzOL4CvHBFG",
+        "token_type": "Bearer",
+        "expires_in": 604800,
+        "refresh_token": "D43f5y0ahjqew82jZ4NViEr2YafMKhue",
+        "scope": "identify"
+    }"#;
+
+    /// Mock of Discord's token endpoints, validating the form and responding
+    /// with a new access token.
+    async fn token_endpoint(
+        request: HyperRequest<Body>,
+    ) -> Result<HyperResponse<Body>, hyper::http::Error> {
+        let path = request.uri().path().to_owned();
+        let authorized = request.headers().contains_key(AUTHORIZATION);
+        let form = request
+            .headers()
+            .get(CONTENT_TYPE)
+            .map_or(false, |value| value == "application/x-www-form-urlencoded");
+        let bytes = body::to_bytes(request.into_body())
+            .await
+            .unwrap_or_default();
+        let body = String::from_utf8_lossy(&bytes);
+        let credentials = body.starts_with("client_id=1&client_secret=secret&");
+
+        if authorized || !form || !credentials {
+            return HyperResponse::builder()
+                .status(StatusCode::UNAUTHORIZED)
+                .body(Body::from(r#"{"error": "invalid_client"}"#));
+        }
+
+        match (path.as_str(), body.contains("grant_type=refresh_token")) {
+            ("/api/v10/oauth2/token", true) => {
+                HyperResponse::builder().body(Body::from(ACCESS_TOKEN))
+            }
+            ("/api/v10/oauth2/token/revoke", false) => HyperResponse::builder().body(Body::empty()),
+            _ => HyperResponse::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .body(Body::from(r#"{"error": "invalid_request"}"#)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_and_revoke() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let client = Client::builder()
+            .proxy(test_server::serve(token_endpoint).to_string(), true)
+            .token("Bot token".to_owned())
+            .build();
+        let oauth = client.oauth(Id::new(1), "secret");
+
+        let access_token = oauth
+            .refresh_token("D43f5y0ahjqew82jZ4NViEr2YafMKhue")
+            .exec()
+            .await?
+            .model()
+            .await?;
+
+        assert_eq!("6qrZcUqja7812RVdnEKjpzOL4CvHBFG", access_token.access_token);
+        assert_eq!(604_800, access_token.expires_in);
+        assert_eq!(
+            Some("D43f5y0ahjqew82jZ4NViEr2YafMKhue"),
+            access_token.refresh_token.as_deref()
+        );
+
+        let response = oauth
+            .revoke_token(&access_token.access_token)
+            .exec()
+            .await?;
+        assert!(response.status().is_success());
+
+        Ok(())
+    }
+}
@@ -136,6 +136,8 @@ pub mod response;
 pub mod routing;
 
 mod json;
+#[cfg(test)]
+mod test_server;
 
 /// Discord API version used by this crate.
 pub const API_VERSION: u8 = 10;
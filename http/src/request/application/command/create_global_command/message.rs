@@ -2,11 +2,11 @@ use super::super::CommandBorrowed;
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, RequestBuilder, TryIntoRequest},
+    request::{Request, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 use twilight_model::{
     application::command::{Command, CommandType},
     guild::Permissions,
@@ -29,6 +29,7 @@ pub struct CreateGlobalMessageCommand<'a> {
     http: &'a Client,
     name: &'a str,
     name_localizations: Option<&'a HashMap<String, String>>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateGlobalMessageCommand<'a> {
@@ -46,6 +47,7 @@ impl<'a> CreateGlobalMessageCommand<'a> {
             http,
             name,
             name_localizations: None,
+            timeout: None,
         })
     }
 
@@ -83,6 +85,17 @@ impl<'a> CreateGlobalMessageCommand<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -112,6 +125,6 @@ impl TryIntoRequest for CreateGlobalMessageCommand<'_> {
             name_localizations: self.name_localizations,
             options: None,
         })
-        .map(RequestBuilder::build)
+        .map(|request| request.build().with_timeout(self.timeout))
     }
 }
@@ -2,11 +2,11 @@ use super::super::CommandBorrowed;
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{Request, RequestBuilder, TryIntoRequest},
+    request::{Request, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 use twilight_model::{
     application::command::{Command, CommandOption, CommandType},
     guild::Permissions,
@@ -39,6 +39,7 @@ pub struct CreateGuildChatInputCommand<'a> {
     name: &'a str,
     name_localizations: Option<&'a HashMap<String, String>>,
     options: Option<&'a [CommandOption]>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateGuildChatInputCommand<'a> {
@@ -63,6 +64,7 @@ impl<'a> CreateGuildChatInputCommand<'a> {
             name,
             name_localizations: None,
             options: None,
+            timeout: None,
         })
     }
 
@@ -129,6 +131,17 @@ impl<'a> CreateGuildChatInputCommand<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -159,6 +172,6 @@ impl TryIntoRequest for CreateGuildChatInputCommand<'_> {
             name_localizations: self.name_localizations,
             options: self.options,
         })
-        .map(RequestBuilder::build)
+        .map(|request| request.build().with_timeout(self.timeout))
     }
 }
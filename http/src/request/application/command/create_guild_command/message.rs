@@ -2,11 +2,11 @@ use super::super::CommandBorrowed;
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, RequestBuilder, TryIntoRequest},
+    request::{Request, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 use twilight_model::{
     application::command::{Command, CommandType},
     guild::Permissions,
@@ -32,6 +32,7 @@ pub struct CreateGuildMessageCommand<'a> {
     http: &'a Client,
     name: &'a str,
     name_localizations: Option<&'a HashMap<String, String>>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateGuildMessageCommand<'a> {
@@ -50,6 +51,7 @@ impl<'a> CreateGuildMessageCommand<'a> {
             http,
             name,
             name_localizations: None,
+            timeout: None,
         })
     }
 
@@ -78,6 +80,17 @@ impl<'a> CreateGuildMessageCommand<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -108,6 +121,6 @@ impl TryIntoRequest for CreateGuildMessageCommand<'_> {
             name_localizations: self.name_localizations,
             options: None,
         })
-        .map(RequestBuilder::build)
+        .map(|request| request.build().with_timeout(self.timeout))
     }
 }
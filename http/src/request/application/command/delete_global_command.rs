@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ApplicationMarker, CommandMarker},
     Id,
@@ -16,6 +17,7 @@ pub struct DeleteGlobalCommand<'a> {
     application_id: Id<ApplicationMarker>,
     command_id: Id<CommandMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteGlobalCommand<'a> {
@@ -28,9 +30,21 @@ impl<'a> DeleteGlobalCommand<'a> {
             application_id,
             command_id,
             http,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -49,6 +63,7 @@ impl TryIntoRequest for DeleteGlobalCommand<'_> {
         Ok(Request::from_route(&Route::DeleteGlobalCommand {
             application_id: self.application_id.get(),
             command_id: self.command_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
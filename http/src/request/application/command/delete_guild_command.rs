@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ApplicationMarker, CommandMarker, GuildMarker},
     Id,
@@ -17,6 +18,7 @@ pub struct DeleteGuildCommand<'a> {
     command_id: Id<CommandMarker>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteGuildCommand<'a> {
@@ -31,9 +33,21 @@ impl<'a> DeleteGuildCommand<'a> {
             command_id,
             guild_id,
             http,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     pub fn exec(self) -> ResponseFuture<EmptyBody> {
         let http = self.http;
 
@@ -50,6 +64,7 @@ impl TryIntoRequest for DeleteGuildCommand<'_> {
             application_id: self.application_id.get(),
             command_id: self.command_id.get(),
             guild_id: self.guild_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
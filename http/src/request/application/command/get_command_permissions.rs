@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     application::command::permissions::GuildCommandPermissions,
     id::{
@@ -20,6 +21,7 @@ pub struct GetCommandPermissions<'a> {
     command_id: Id<CommandMarker>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetCommandPermissions<'a> {
@@ -34,9 +36,21 @@ impl<'a> GetCommandPermissions<'a> {
             command_id,
             guild_id,
             http,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -56,6 +70,7 @@ impl TryIntoRequest for GetCommandPermissions<'_> {
             application_id: self.application_id.get(),
             command_id: self.command_id.get(),
             guild_id: self.guild_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
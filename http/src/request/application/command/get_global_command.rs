@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     application::command::Command,
     id::{
@@ -19,6 +20,7 @@ pub struct GetGlobalCommand<'a> {
     application_id: Id<ApplicationMarker>,
     command_id: Id<CommandMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetGlobalCommand<'a> {
@@ -31,9 +33,21 @@ impl<'a> GetGlobalCommand<'a> {
             application_id,
             command_id,
             http,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -52,6 +66,7 @@ impl TryIntoRequest for GetGlobalCommand<'_> {
         Ok(Request::from_route(&Route::GetGlobalCommand {
             application_id: self.application_id.get(),
             command_id: self.command_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
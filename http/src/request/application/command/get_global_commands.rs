@@ -5,6 +5,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     application::command::Command,
     id::{marker::ApplicationMarker, Id},
@@ -15,6 +16,7 @@ use twilight_model::{
 pub struct GetGlobalCommands<'a> {
     application_id: Id<ApplicationMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     with_localizations: Option<bool>,
 }
 
@@ -23,6 +25,7 @@ impl<'a> GetGlobalCommands<'a> {
         Self {
             application_id,
             http,
+            timeout: None,
             with_localizations: None,
         }
     }
@@ -36,6 +39,17 @@ impl<'a> GetGlobalCommands<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -54,6 +68,7 @@ impl TryIntoRequest for GetGlobalCommands<'_> {
         Ok(Request::from_route(&Route::GetGlobalCommands {
             application_id: self.application_id.get(),
             with_localizations: self.with_localizations,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
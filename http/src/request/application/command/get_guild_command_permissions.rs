@@ -5,6 +5,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     application::command::permissions::GuildCommandPermissions,
     id::{
@@ -19,6 +20,7 @@ pub struct GetGuildCommandPermissions<'a> {
     application_id: Id<ApplicationMarker>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetGuildCommandPermissions<'a> {
@@ -31,9 +33,21 @@ impl<'a> GetGuildCommandPermissions<'a> {
             application_id,
             guild_id,
             http,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -52,6 +66,7 @@ impl TryIntoRequest for GetGuildCommandPermissions<'_> {
         Ok(Request::from_route(&Route::GetGuildCommandPermissions {
             application_id: self.application_id.get(),
             guild_id: self.guild_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     application::command::Command,
     id::{
@@ -19,6 +20,7 @@ pub struct GetGuildCommands<'a> {
     application_id: Id<ApplicationMarker>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     with_localizations: Option<bool>,
 }
 
@@ -32,6 +34,7 @@ impl<'a> GetGuildCommands<'a> {
             application_id,
             guild_id,
             http,
+            timeout: None,
             with_localizations: None,
         }
     }
@@ -45,6 +48,17 @@ impl<'a> GetGuildCommands<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -64,6 +78,7 @@ impl TryIntoRequest for GetGuildCommands<'_> {
             application_id: self.application_id.get(),
             guild_id: self.guild_id.get(),
             with_localizations: self.with_localizations,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -1,10 +1,11 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, RequestBuilder, TryIntoRequest},
+    request::{Request, TryIntoRequest},
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     application::command::Command,
     id::{marker::ApplicationMarker, Id},
@@ -25,6 +26,7 @@ pub struct SetGlobalCommands<'a> {
     commands: &'a [Command],
     application_id: Id<ApplicationMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> SetGlobalCommands<'a> {
@@ -37,9 +39,21 @@ impl<'a> SetGlobalCommands<'a> {
             commands,
             application_id,
             http,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -59,6 +73,6 @@ impl TryIntoRequest for SetGlobalCommands<'_> {
             application_id: self.application_id.get(),
         })
         .json(&self.commands)
-        .map(RequestBuilder::build)
+        .map(|request| request.build().with_timeout(self.timeout))
     }
 }
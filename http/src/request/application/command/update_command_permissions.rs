@@ -1,11 +1,12 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, RequestBuilder, TryIntoRequest},
+    request::{Request, TryIntoRequest},
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     application::command::permissions::CommandPermissions,
     id::{
@@ -35,6 +36,7 @@ pub struct UpdateCommandPermissions<'a> {
     guild_id: Id<GuildMarker>,
     fields: UpdateCommandPermissionsFields<'a>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateCommandPermissions<'a> {
@@ -53,9 +55,21 @@ impl<'a> UpdateCommandPermissions<'a> {
             guild_id,
             fields: UpdateCommandPermissionsFields { permissions },
             http,
+            timeout: None,
         })
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -77,6 +91,6 @@ impl TryIntoRequest for UpdateCommandPermissions<'_> {
             guild_id: self.guild_id.get(),
         })
         .json(&self.fields)
-        .map(RequestBuilder::build)
+        .map(|request| request.build().with_timeout(self.timeout))
     }
 }
@@ -1,11 +1,12 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, RequestBuilder, TryIntoRequest},
+    request::{Request, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     application::command::{Command, CommandOption},
     id::{
@@ -36,6 +37,7 @@ pub struct UpdateGlobalCommand<'a> {
     command_id: Id<CommandMarker>,
     application_id: Id<ApplicationMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateGlobalCommand<'a> {
@@ -53,6 +55,7 @@ impl<'a> UpdateGlobalCommand<'a> {
                 options: None,
             },
             http,
+            timeout: None,
         }
     }
 
@@ -77,6 +80,17 @@ impl<'a> UpdateGlobalCommand<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -97,6 +111,6 @@ impl TryIntoRequest for UpdateGlobalCommand<'_> {
             command_id: self.command_id.get(),
         })
         .json(&self.fields)
-        .map(RequestBuilder::build)
+        .map(|request| request.build().with_timeout(self.timeout))
     }
 }
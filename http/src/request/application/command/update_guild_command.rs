@@ -1,11 +1,12 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, RequestBuilder, TryIntoRequest},
+    request::{Request, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     application::command::{Command, CommandOption},
     id::{
@@ -37,6 +38,7 @@ pub struct UpdateGuildCommand<'a> {
     command_id: Id<CommandMarker>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateGuildCommand<'a> {
@@ -56,6 +58,7 @@ impl<'a> UpdateGuildCommand<'a> {
             },
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -80,6 +83,17 @@ impl<'a> UpdateGuildCommand<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -101,6 +115,6 @@ impl TryIntoRequest for UpdateGuildCommand<'_> {
             guild_id: self.guild_id.get(),
         })
         .json(&self.fields)
-        .map(RequestBuilder::build)
+        .map(|request| request.build().with_timeout(self.timeout))
     }
 }
@@ -9,6 +9,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     application::component::Component,
     channel::{
@@ -20,7 +21,7 @@ use twilight_model::{
     id::{marker::ApplicationMarker, Id},
 };
 use twilight_validate::message::{
-    attachment_filename as validate_attachment_filename, components as validate_components,
+    attachment as validate_attachment, components as validate_components,
     content as validate_content, embeds as validate_embeds, MessageValidationError,
 };
 
@@ -78,6 +79,7 @@ pub struct CreateFollowup<'a> {
     attachment_manager: AttachmentManager<'a>,
     fields: CreateFollowupFields<'a>,
     http: &'a Client,
+    timeout: Option<Duration>,
     token: &'a str,
 }
 
@@ -101,6 +103,7 @@ impl<'a> CreateFollowup<'a> {
                 flags: None,
             },
             http,
+            timeout: None,
             token,
         }
     }
@@ -121,17 +124,19 @@ impl<'a> CreateFollowup<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentDescriptionTooLarge`] if any
+    /// description is too large.
+    ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentDescriptionTooLarge`]: twilight_validate::message::MessageValidationErrorType::AttachmentDescriptionTooLarge
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(
         mut self,
         attachments: &'a [Attachment],
     ) -> Result<Self, MessageValidationError> {
-        attachments
-            .iter()
-            .try_for_each(|attachment| validate_attachment_filename(&attachment.filename))?;
+        attachments.iter().try_for_each(validate_attachment)?;
 
         self.attachment_manager = self
             .attachment_manager
@@ -243,6 +248,17 @@ impl<'a> CreateFollowup<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -296,7 +312,7 @@ impl TryIntoRequest for CreateFollowup<'_> {
             request = request.json(&self.fields)?;
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
 
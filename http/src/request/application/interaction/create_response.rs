@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     http::interaction::InteractionResponse,
     id::{marker::InteractionMarker, Id},
@@ -17,6 +18,7 @@ pub struct CreateResponse<'a> {
     interaction_token: &'a str,
     response: &'a InteractionResponse,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateResponse<'a> {
@@ -31,9 +33,21 @@ impl<'a> CreateResponse<'a> {
             interaction_token,
             response,
             http,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -77,7 +91,7 @@ impl TryIntoRequest for CreateResponse<'_> {
             request = request.json(&self.response)?;
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
 
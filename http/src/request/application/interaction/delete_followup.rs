@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ApplicationMarker, MessageMarker},
     Id,
@@ -34,6 +35,7 @@ use twilight_model::id::{
 pub struct DeleteFollowup<'a> {
     http: &'a Client,
     message_id: Id<MessageMarker>,
+    timeout: Option<Duration>,
     token: &'a str,
     application_id: Id<ApplicationMarker>,
 }
@@ -48,11 +50,23 @@ impl<'a> DeleteFollowup<'a> {
         Self {
             http,
             message_id,
+            timeout: None,
             token,
             application_id,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -75,7 +89,8 @@ impl TryIntoRequest for DeleteFollowup<'_> {
             webhook_id: self.application_id.get(),
         })
         .use_authorization_token(false)
-        .build())
+        .build()
+        .with_timeout(self.timeout))
     }
 }
 
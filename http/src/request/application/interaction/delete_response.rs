@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{marker::ApplicationMarker, Id};
 
 /// Delete a followup message to an interaction, by its token and message ID.
@@ -33,6 +34,7 @@ use twilight_model::id::{marker::ApplicationMarker, Id};
 pub struct DeleteResponse<'a> {
     application_id: Id<ApplicationMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     token: &'a str,
 }
 
@@ -45,10 +47,22 @@ impl<'a> DeleteResponse<'a> {
         Self {
             application_id,
             http,
+            timeout: None,
             token,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -69,7 +83,8 @@ impl TryIntoRequest for DeleteResponse<'_> {
             interaction_token: self.token,
         })
         .use_authorization_token(false)
-        .build())
+        .build()
+        .with_timeout(self.timeout))
     }
 }
 
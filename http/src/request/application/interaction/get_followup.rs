@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::Message,
     id::{
@@ -22,17 +23,20 @@ use twilight_model::{
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// use std::env;
 /// use twilight_http::Client;
-/// use twilight_http::request::AuditLogReason;
 /// use twilight_model::id::Id;
 ///
 /// let client = Client::new(env::var("DISCORD_TOKEN")?);
 /// let application_id = Id::new(1);
 ///
-/// let response = client
+/// let message = client
 ///     .interaction(application_id)
 ///     .followup("token here", Id::new(2))
 ///     .exec()
+///     .await?
+///     .model()
 ///     .await?;
+///
+/// println!("followup content: {}", message.content);
 /// # Ok(()) }
 /// ```
 #[must_use = "requests must be configured and executed"]
@@ -41,6 +45,7 @@ pub struct GetFollowup<'a> {
     http: &'a Client,
     message_id: Id<MessageMarker>,
     interaction_token: &'a str,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetFollowup<'a> {
@@ -55,9 +60,21 @@ impl<'a> GetFollowup<'a> {
             http,
             message_id,
             interaction_token,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -80,7 +97,8 @@ impl TryIntoRequest for GetFollowup<'_> {
             message_id: self.message_id.get(),
         })
         .use_authorization_token(false)
-        .build())
+        .build()
+        .with_timeout(self.timeout))
     }
 }
 
@@ -89,7 +107,7 @@ mod tests {
     use super::GetFollowup;
     use crate::{
         client::Client,
-        request::{Request, TryIntoRequest},
+        request::{Method, Request, TryIntoRequest},
         routing::Route,
     };
     use static_assertions::assert_impl_all;
@@ -122,7 +140,8 @@ mod tests {
         .use_authorization_token(false)
         .build();
 
-        assert!(expected.body().is_none());
+        assert!(actual.body().is_none());
+        assert_eq!(Method::Get, actual.method());
         assert_eq!(expected.path(), actual.path());
         assert_eq!(expected.ratelimit_path(), actual.ratelimit_path());
         assert_eq!(
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::Message,
     id::{marker::ApplicationMarker, Id},
@@ -36,6 +37,7 @@ use twilight_model::{
 pub struct GetResponse<'a> {
     application_id: Id<ApplicationMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     token: &'a str,
 }
 
@@ -48,10 +50,22 @@ impl<'a> GetResponse<'a> {
         Self {
             application_id,
             http,
+            timeout: None,
             token: interaction_token,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -72,7 +86,8 @@ impl TryIntoRequest for GetResponse<'_> {
             interaction_token: self.token,
         })
         .use_authorization_token(false)
-        .build())
+        .build()
+        .with_timeout(self.timeout))
     }
 }
 
@@ -11,6 +11,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     application::component::Component,
     channel::{embed::Embed, message::AllowedMentions, Message},
@@ -21,7 +22,7 @@ use twilight_model::{
     },
 };
 use twilight_validate::message::{
-    attachment_filename as validate_attachment_filename, components as validate_components,
+    attachment as validate_attachment, components as validate_components,
     content as validate_content, embeds as validate_embeds, MessageValidationError,
 };
 
@@ -88,6 +89,7 @@ pub struct UpdateResponse<'a> {
     attachment_manager: AttachmentManager<'a>,
     fields: UpdateResponseFields<'a>,
     http: &'a Client,
+    timeout: Option<Duration>,
     token: &'a str,
 }
 
@@ -109,6 +111,7 @@ impl<'a> UpdateResponse<'a> {
                 payload_json: None,
             },
             http,
+            timeout: None,
             token: interaction_token,
         }
     }
@@ -129,17 +132,19 @@ impl<'a> UpdateResponse<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentDescriptionTooLarge`] if any
+    /// description is too large.
+    ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentDescriptionTooLarge`]: twilight_validate::message::MessageValidationErrorType::AttachmentDescriptionTooLarge
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(
         mut self,
         attachments: &'a [Attachment],
     ) -> Result<Self, MessageValidationError> {
-        attachments
-            .iter()
-            .try_for_each(|attachment| validate_attachment_filename(&attachment.filename))?;
+        attachments.iter().try_for_each(validate_attachment)?;
 
         self.attachment_manager = self
             .attachment_manager
@@ -306,6 +311,17 @@ impl<'a> UpdateResponse<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     pub fn exec(self) -> ResponseFuture<Message> {
         let http = self.http;
 
@@ -356,7 +372,7 @@ impl TryIntoRequest for UpdateResponse<'_> {
             request = request.json(&self.fields)?;
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
 
@@ -153,6 +153,21 @@ mod tests {
         assert_eq!(max_d[..], max_v[..]);
     }
 
+    #[test]
+    fn test_partial_attachments_description() {
+        let mut attachment = Attachment::from_bytes("image.png".to_owned(), Vec::from([1, 2]), 1);
+        attachment.description("alt text".to_owned());
+
+        let manager = AttachmentManager::new()
+            .set_files(Vec::from([&attachment]))
+            .set_ids(Vec::from([Id::new(2)]));
+
+        assert_eq!(
+            r#"[{"description":"alt text","filename":"image.png","id":1},{"id":2}]"#,
+            serde_json::to_string(&manager.get_partial_attachments()).unwrap()
+        );
+    }
+
     #[test]
     fn test_num_digits() {
         assert_eq!(1, num_digits(0));
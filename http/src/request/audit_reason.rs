@@ -10,20 +10,20 @@ mod private {
     use crate::request::{
         channel::{
             invite::{CreateInvite, DeleteInvite},
-            message::{DeleteMessage, DeleteMessages},
+            message::{DeleteMessage, DeleteMessages, PurgeMessages},
             thread::UpdateThread,
             webhook::{CreateWebhook, DeleteWebhook, DeleteWebhookMessage, UpdateWebhook},
             CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin, UpdateChannel,
             UpdateChannelPermission,
         },
         guild::{
-            ban::{CreateBan, DeleteBan},
+            ban::{CreateBan, CreateBans, DeleteBan},
             emoji::{CreateEmoji, DeleteEmoji, UpdateEmoji},
             integration::DeleteGuildIntegration,
             member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
             role::{CreateRole, DeleteRole, UpdateRole},
             sticker::{CreateGuildSticker, UpdateGuildSticker},
-            CreateGuildChannel, CreateGuildPrune, UpdateCurrentMember, UpdateGuild,
+            CreateGuildChannel, CreateGuildPrune, UpdateCurrentMember, UpdateGuild, UpdateGuildMfa,
         },
         scheduled_event::{
             CreateGuildExternalScheduledEvent, CreateGuildScheduledEvent,
@@ -38,6 +38,7 @@ mod private {
 
     impl Sealed for AddRoleToMember<'_> {}
     impl Sealed for CreateBan<'_> {}
+    impl Sealed for CreateBans<'_> {}
     impl Sealed for CreateEmoji<'_> {}
     impl Sealed for CreateGuildChannel<'_> {}
     impl Sealed for CreateGuildExternalScheduledEvent<'_> {}
@@ -62,6 +63,7 @@ mod private {
     impl Sealed for DeleteRole<'_> {}
     impl Sealed for DeleteWebhook<'_> {}
     impl Sealed for DeleteWebhookMessage<'_> {}
+    impl Sealed for PurgeMessages<'_> {}
     impl Sealed for RemoveMember<'_> {}
     impl Sealed for RemoveRoleFromMember<'_> {}
     impl Sealed for UpdateChannel<'_> {}
@@ -70,6 +72,7 @@ mod private {
     impl Sealed for UpdateCurrentUser<'_> {}
     impl Sealed for UpdateEmoji<'_> {}
     impl Sealed for UpdateGuild<'_> {}
+    impl Sealed for UpdateGuildMfa<'_> {}
     impl Sealed for UpdateGuildMember<'_> {}
     impl Sealed for UpdateGuildScheduledEvent<'_> {}
     impl Sealed for UpdateGuildSticker<'_> {}
@@ -84,19 +87,19 @@ mod test {
     use crate::request::{
         channel::{
             invite::{CreateInvite, DeleteInvite},
-            message::{DeleteMessage, DeleteMessages},
+            message::{DeleteMessage, DeleteMessages, PurgeMessages},
             webhook::{CreateWebhook, DeleteWebhook, UpdateWebhook},
             CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin, UpdateChannel,
             UpdateChannelPermission,
         },
         guild::{
-            ban::{CreateBan, DeleteBan},
+            ban::{CreateBan, CreateBans, DeleteBan},
             emoji::{CreateEmoji, DeleteEmoji, UpdateEmoji},
             integration::DeleteGuildIntegration,
             member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
             role::{CreateRole, DeleteRole, UpdateRole},
             sticker::{CreateGuildSticker, UpdateGuildSticker},
-            CreateGuildChannel, CreateGuildPrune, UpdateCurrentMember, UpdateGuild,
+            CreateGuildChannel, CreateGuildPrune, UpdateCurrentMember, UpdateGuild, UpdateGuildMfa,
         },
         user::UpdateCurrentUser,
     };
@@ -106,6 +109,7 @@ mod test {
 
     assert_impl_all!(AddRoleToMember<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateBan<'_>: AuditLogReason<'static>);
+    assert_impl_all!(CreateBans<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateEmoji<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateGuildChannel<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateGuildPrune<'_>: AuditLogReason<'static>);
@@ -125,6 +129,7 @@ mod test {
     assert_impl_all!(DeletePin<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteRole<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteWebhook<'_>: AuditLogReason<'static>);
+    assert_impl_all!(PurgeMessages<'_>: AuditLogReason<'static>);
     assert_impl_all!(RemoveMember<'_>: AuditLogReason<'static>);
     assert_impl_all!(RemoveRoleFromMember<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateChannel<'_>: AuditLogReason<'static>);
@@ -133,6 +138,7 @@ mod test {
     assert_impl_all!(UpdateCurrentUser<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateEmoji<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateGuild<'_>: AuditLogReason<'static>);
+    assert_impl_all!(UpdateGuildMfa<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateGuildMember<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateGuildSticker<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateRole<'_>: AuditLogReason<'static>);
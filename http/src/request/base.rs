@@ -5,6 +5,7 @@ use crate::{
 };
 use hyper::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::Serialize;
+use std::time::Duration;
 
 /// Builder to create a customized request.
 ///
@@ -72,6 +73,7 @@ impl RequestBuilder {
             method,
             path: path_and_query,
             ratelimit_path,
+            timeout: None,
             use_authorization_token: true,
         })
     }
@@ -123,6 +125,49 @@ impl RequestBuilder {
         Ok(self.body(bytes))
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// This is useful for requests that take longer than most, such as
+    /// fetching many guild members, or for requests that should fail fast.
+    /// A zero duration means the request never times out.
+    ///
+    /// Defaults to the timeout set via [`ClientBuilder::timeout`].
+    ///
+    /// # Examples
+    ///
+    /// Get the members of a guild, allowing the request to take up to a
+    /// minute:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
+    /// use twilight_http::{request::Request, routing::Route, Client};
+    /// use twilight_model::guild::Member;
+    ///
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let request = Request::builder(&Route::GetGuildMembers {
+    ///     after: None,
+    ///     guild_id: 1,
+    ///     limit: Some(1000),
+    ///     presences: None,
+    /// })
+    /// .timeout(Duration::from_secs(60))
+    /// .build();
+    ///
+    /// let response = client.request::<Vec<Member>>(request).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`ClientBuilder::timeout`]: crate::client::ClientBuilder::timeout
+    #[must_use = "request has not been fully built"]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.0.timeout = Some(timeout);
+
+        self
+    }
+
     /// Whether to use the client's authorization token in the request, if one
     /// is set.
     ///
@@ -143,6 +188,7 @@ pub struct Request {
     pub(crate) method: Method,
     pub(crate) path: String,
     pub(crate) ratelimit_path: Path,
+    pub(crate) timeout: Option<Duration>,
     pub(crate) use_authorization_token: bool,
 }
 
@@ -197,6 +243,7 @@ impl Request {
             method: route.method(),
             path: route.to_string(),
             ratelimit_path: route.to_path(),
+            timeout: None,
             use_authorization_token: true,
         }
     }
@@ -231,10 +278,25 @@ impl Request {
         &self.ratelimit_path
     }
 
+    /// Timeout of the request, if it overrides the client's timeout.
+    pub const fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
     /// Whether to use the client's authorization token in the request.
     pub const fn use_authorization_token(&self) -> bool {
         self.use_authorization_token
     }
+
+    /// Override the client's timeout with the timeout set on the typed
+    /// request builder the request was created from, if any.
+    pub(crate) const fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        if let Some(timeout) = timeout {
+            self.timeout = Some(timeout);
+        }
+
+        self
+    }
 }
 
 #[cfg(test)]
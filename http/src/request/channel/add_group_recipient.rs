@@ -0,0 +1,128 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::EmptyBody, ResponseFuture},
+    routing::Route,
+};
+use serde::Serialize;
+use std::time::Duration;
+use twilight_model::id::{
+    marker::{ChannelMarker, UserMarker},
+    Id,
+};
+
+#[derive(Serialize)]
+struct AddGroupRecipientFields<'a> {
+    access_token: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nick: Option<&'a str>,
+}
+
+/// Add a user to a group DM.
+///
+/// This requires the `OAuth2` access token of the user, who must have
+/// authorized the application with the `gdm.join` scope. Refer to
+/// [Discord Docs/Group DM Add Recipient].
+///
+/// [Discord Docs/Group DM Add Recipient]: https://discord.com/developers/docs/resources/channel#group-dm-add-recipient
+#[must_use = "requests must be configured and executed"]
+pub struct AddGroupRecipient<'a> {
+    channel_id: Id<ChannelMarker>,
+    fields: AddGroupRecipientFields<'a>,
+    http: &'a Client,
+    timeout: Option<Duration>,
+    user_id: Id<UserMarker>,
+}
+
+impl<'a> AddGroupRecipient<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        channel_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+        access_token: &'a str,
+    ) -> Self {
+        Self {
+            channel_id,
+            fields: AddGroupRecipientFields {
+                access_token,
+                nick: None,
+            },
+            http,
+            timeout: None,
+            user_id,
+        }
+    }
+
+    /// Set the user's nickname in the group DM.
+    pub const fn nick(mut self, nick: &'a str) -> Self {
+        self.fields.nick = Some(nick);
+
+        self
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<EmptyBody> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for AddGroupRecipient<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let request = Request::builder(&Route::AddGroupRecipient {
+            channel_id: self.channel_id.get(),
+            user_id: self.user_id.get(),
+        });
+
+        let request = request.json(&self.fields)?;
+
+        Ok(request.build().with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddGroupRecipient;
+    use crate::{
+        client::Client,
+        request::{Method, TryIntoRequest},
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let request = AddGroupRecipient::new(&client, Id::new(1), Id::new(2), "token")
+            .nick("nick")
+            .try_into_request()?;
+
+        assert_eq!(Method::Put, request.method());
+        assert_eq!("channels/1/recipients/2", request.path());
+        assert_eq!(
+            Some(br#"{"access_token":"token","nick":"nick"}"#.as_slice()),
+            request.body()
+        );
+
+        Ok(())
+    }
+}
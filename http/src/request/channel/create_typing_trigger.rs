@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{marker::ChannelMarker, Id};
 
 /// Fire a Typing Start event in the channel.
@@ -12,11 +13,27 @@ use twilight_model::id::{marker::ChannelMarker, Id};
 pub struct CreateTypingTrigger<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateTypingTrigger<'a> {
     pub(crate) const fn new(http: &'a Client, channel_id: Id<ChannelMarker>) -> Self {
-        Self { channel_id, http }
+        Self {
+            channel_id,
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -36,6 +53,7 @@ impl TryIntoRequest for CreateTypingTrigger<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         Ok(Request::from_route(&Route::CreateTypingTrigger {
             channel_id: self.channel_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::Channel,
     id::{marker::ChannelMarker, Id},
@@ -17,6 +18,7 @@ pub struct DeleteChannel<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteChannel<'a> {
@@ -25,9 +27,21 @@ impl<'a> DeleteChannel<'a> {
             channel_id,
             http,
             reason: None,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -63,6 +77,6 @@ impl TryIntoRequest for DeleteChannel<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
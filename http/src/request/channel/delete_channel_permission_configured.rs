@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{marker::ChannelMarker, Id};
 use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
 
@@ -17,6 +18,7 @@ pub struct DeleteChannelPermissionConfigured<'a> {
     http: &'a Client,
     reason: Option<&'a str>,
     target_id: u64,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteChannelPermissionConfigured<'a> {
@@ -30,9 +32,21 @@ impl<'a> DeleteChannelPermissionConfigured<'a> {
             http,
             reason: None,
             target_id,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -69,6 +83,6 @@ impl TryIntoRequest for DeleteChannelPermissionConfigured<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ChannelMarker, MessageMarker},
     Id,
@@ -18,6 +19,7 @@ pub struct DeletePin<'a> {
     http: &'a Client,
     message_id: Id<MessageMarker>,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeletePin<'a> {
@@ -31,9 +33,21 @@ impl<'a> DeletePin<'a> {
             http,
             message_id,
             reason: None,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -70,6 +84,6 @@ impl TryIntoRequest for DeletePin<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::FollowedChannel,
     id::{marker::ChannelMarker, Id},
@@ -22,6 +23,7 @@ pub struct FollowNewsChannel<'a> {
     channel_id: Id<ChannelMarker>,
     fields: FollowNewsChannelFields,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> FollowNewsChannel<'a> {
@@ -34,9 +36,21 @@ impl<'a> FollowNewsChannel<'a> {
             channel_id,
             http,
             fields: FollowNewsChannelFields { webhook_channel_id },
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -58,6 +72,6 @@ impl TryIntoRequest for FollowNewsChannel<'_> {
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
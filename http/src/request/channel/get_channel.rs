@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::Channel,
     id::{marker::ChannelMarker, Id},
@@ -33,11 +34,27 @@ use twilight_model::{
 pub struct GetChannel<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetChannel<'a> {
     pub(crate) const fn new(http: &'a Client, channel_id: Id<ChannelMarker>) -> Self {
-        Self { channel_id, http }
+        Self {
+            channel_id,
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -57,6 +74,7 @@ impl TryIntoRequest for GetChannel<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         Ok(Request::from_route(&Route::GetChannel {
             channel_id: self.channel_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
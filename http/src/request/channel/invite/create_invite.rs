@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     id::{
         marker::{ApplicationMarker, ChannelMarker, UserMarker},
@@ -66,6 +67,7 @@ pub struct CreateInvite<'a> {
     fields: CreateInviteFields,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateInvite<'a> {
@@ -83,6 +85,7 @@ impl<'a> CreateInvite<'a> {
             },
             http,
             reason: None,
+            timeout: None,
         }
     }
 
@@ -209,6 +212,17 @@ impl<'a> CreateInvite<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -246,7 +260,7 @@ impl TryIntoRequest for CreateInvite<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
 
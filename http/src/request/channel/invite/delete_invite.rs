@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
 
 /// Delete an invite by its code.
@@ -19,6 +20,7 @@ pub struct DeleteInvite<'a> {
     code: &'a str,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteInvite<'a> {
@@ -27,9 +29,21 @@ impl<'a> DeleteInvite<'a> {
             code,
             http,
             reason: None,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -63,6 +77,6 @@ impl TryIntoRequest for DeleteInvite<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
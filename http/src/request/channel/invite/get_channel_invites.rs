@@ -5,6 +5,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     id::{marker::ChannelMarker, Id},
     invite::Invite,
@@ -20,11 +21,27 @@ use twilight_model::{
 pub struct GetChannelInvites<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetChannelInvites<'a> {
     pub(crate) const fn new(http: &'a Client, channel_id: Id<ChannelMarker>) -> Self {
-        Self { channel_id, http }
+        Self {
+            channel_id,
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -44,6 +61,7 @@ impl TryIntoRequest for GetChannelInvites<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         Ok(Request::from_route(&Route::GetChannelInvites {
             channel_id: self.channel_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
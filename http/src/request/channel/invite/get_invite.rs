@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::invite::Invite;
 
 struct GetInviteFields {
@@ -42,6 +43,7 @@ pub struct GetInvite<'a> {
     code: &'a str,
     fields: GetInviteFields,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetInvite<'a> {
@@ -53,6 +55,7 @@ impl<'a> GetInvite<'a> {
                 with_expiration: false,
             },
             http,
+            timeout: None,
         }
     }
 
@@ -70,6 +73,17 @@ impl<'a> GetInvite<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -89,6 +103,7 @@ impl TryIntoRequest for GetInvite<'_> {
             code: self.code,
             with_counts: self.fields.with_counts,
             with_expiration: self.fields.with_expiration,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
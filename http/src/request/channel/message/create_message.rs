@@ -9,6 +9,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     application::component::Component,
     channel::{
@@ -23,7 +24,7 @@ use twilight_model::{
     },
 };
 use twilight_validate::message::{
-    attachment_filename as validate_attachment_filename, components as validate_components,
+    attachment as validate_attachment, components as validate_components,
     content as validate_content, embeds as validate_embeds, sticker_ids as validate_sticker_ids,
     MessageValidationError,
 };
@@ -88,6 +89,7 @@ pub struct CreateMessage<'a> {
     channel_id: Id<ChannelMarker>,
     fields: CreateMessageFields<'a>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateMessage<'a> {
@@ -109,6 +111,7 @@ impl<'a> CreateMessage<'a> {
                 tts: None,
             },
             http,
+            timeout: None,
         }
     }
 
@@ -128,17 +131,19 @@ impl<'a> CreateMessage<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentDescriptionTooLarge`] if any
+    /// description is too large.
+    ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentDescriptionTooLarge`]: twilight_validate::message::MessageValidationErrorType::AttachmentDescriptionTooLarge
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(
         mut self,
         attachments: &'a [Attachment],
     ) -> Result<Self, MessageValidationError> {
-        attachments
-            .iter()
-            .try_for_each(|attachment| validate_attachment_filename(&attachment.filename))?;
+        attachments.iter().try_for_each(validate_attachment)?;
 
         self.attachment_manager = self
             .attachment_manager
@@ -250,6 +255,10 @@ impl<'a> CreateMessage<'a> {
     }
 
     /// Attach a nonce to the message, for optimistic message sending.
+    ///
+    /// The created message has the nonce as a [`Nonce::Integer`].
+    ///
+    /// [`Nonce::Integer`]: twilight_model::channel::message::Nonce::Integer
     pub const fn nonce(mut self, nonce: u64) -> Self {
         self.fields.nonce = Some(nonce);
 
@@ -325,6 +334,17 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -371,6 +391,39 @@ impl TryIntoRequest for CreateMessage<'_> {
             request = request.json(&self.fields)?;
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::error::Error;
+    use twilight_model::channel::message::Nonce;
+
+    #[test]
+    fn test_nonce() -> Result<(), Box<dyn Error>> {
+        #[derive(Deserialize)]
+        struct Body {
+            nonce: Nonce,
+        }
+
+        const CHANNEL_ID: Id<ChannelMarker> = Id::new(1);
+
+        let client = Client::new("token".into());
+
+        // The nonce is received in the same form it was sent in, including
+        // integers that don't fit in an `i64`.
+        for nonce in [1_234_567, u64::MAX] {
+            let request = CreateMessage::new(&client, CHANNEL_ID)
+                .nonce(nonce)
+                .try_into_request()?;
+            let body = crate::json::from_bytes::<Body>(request.body().expect("json body"))?;
+
+            assert_eq!(Nonce::Integer(nonce), body.nonce);
+        }
+
+        Ok(())
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ChannelMarker, MessageMarker},
     Id,
@@ -31,6 +32,7 @@ pub struct DeleteMessages<'a> {
     fields: DeleteMessagesFields<'a>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteMessages<'a> {
@@ -44,9 +46,21 @@ impl<'a> DeleteMessages<'a> {
             fields: DeleteMessagesFields { messages },
             http,
             reason: None,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -84,6 +98,6 @@ impl TryIntoRequest for DeleteMessages<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::Message,
     id::{
@@ -60,6 +61,7 @@ pub struct GetChannelMessages<'a> {
     channel_id: Id<ChannelMarker>,
     fields: GetChannelMessagesFields,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetChannelMessages<'a> {
@@ -68,6 +70,7 @@ impl<'a> GetChannelMessages<'a> {
             channel_id,
             fields: GetChannelMessagesFields { limit: None },
             http,
+            timeout: None,
         }
     }
 
@@ -124,6 +127,17 @@ impl<'a> GetChannelMessages<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -145,6 +159,7 @@ impl TryIntoRequest for GetChannelMessages<'_> {
             before: None,
             channel_id: self.channel_id.get(),
             limit: self.fields.limit,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::Message,
     id::{
@@ -35,6 +36,7 @@ pub struct GetChannelMessagesConfigured<'a> {
     channel_id: Id<ChannelMarker>,
     fields: GetChannelMessagesConfiguredFields,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetChannelMessagesConfigured<'a> {
@@ -53,6 +55,7 @@ impl<'a> GetChannelMessagesConfigured<'a> {
             channel_id,
             fields: GetChannelMessagesConfiguredFields { limit },
             http,
+            timeout: None,
         }
     }
 
@@ -76,6 +79,17 @@ impl<'a> GetChannelMessagesConfigured<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -97,6 +111,7 @@ impl TryIntoRequest for GetChannelMessagesConfigured<'_> {
             before: self.before.map(Id::get),
             channel_id: self.channel_id.get(),
             limit: self.fields.limit,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
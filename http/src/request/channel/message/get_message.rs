@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::Message,
     id::{
@@ -19,6 +20,7 @@ pub struct GetMessage<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
     message_id: Id<MessageMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetMessage<'a> {
@@ -31,9 +33,21 @@ impl<'a> GetMessage<'a> {
             channel_id,
             http,
             message_id,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -52,6 +66,7 @@ impl TryIntoRequest for GetMessage<'_> {
         Ok(Request::from_route(&Route::GetMessage {
             channel_id: self.channel_id.get(),
             message_id: self.message_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -0,0 +1,371 @@
+use crate::{
+    client::Client,
+    error::{Error, ErrorType},
+    request::AuditLogReason,
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use twilight_model::id::{
+    marker::{ChannelMarker, MessageMarker},
+    Id,
+};
+use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+
+/// Maximum number of messages that can be deleted by one bulk delete request.
+const BULK_DELETE_COUNT_MAX: usize = 100;
+
+/// Maximum age of messages that are bulk deleted.
+///
+/// Discord only bulk deletes messages up to two weeks old. Five minutes are
+/// subtracted so that messages close to the limit aren't rejected due to clock
+/// skew or the time taken by preceding requests.
+const BULK_DELETE_MAX_AGE: Duration = Duration::from_secs(60 * 60 * 24 * 14 - 60 * 5);
+
+/// Milliseconds since the Unix epoch of the first second of 2015, used as the
+/// epoch of snowflakes.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Number of messages deleted by [`PurgeMessages`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PurgedMessages {
+    /// Number of messages deleted via bulk delete requests.
+    pub bulk_deleted: usize,
+    /// Number of messages deleted one by one.
+    pub individually_deleted: usize,
+}
+
+/// Delete any number of messages in a channel, regardless of their age.
+///
+/// Messages are deleted with as few requests as possible: messages newer than
+/// two weeks are bulk deleted in batches of 100, while messages older than
+/// two weeks, as well as a single remaining newer message, are deleted one by
+/// one. The age of a message is determined from the timestamp of its ID, and
+/// messages within a few minutes of being two weeks old are deleted one by one
+/// as well.
+/// Duplicate message IDs are only deleted once.
+///
+/// Requests are sent one after another. If a request fails then its error is
+/// returned and no further requests are sent, though messages deleted by
+/// previous requests stay deleted.
+///
+/// Unlike [`DeleteMessages`], there are no limits on the number of messages.
+///
+/// [`DeleteMessages`]: super::DeleteMessages
+#[must_use = "requests must be configured and executed"]
+pub struct PurgeMessages<'a> {
+    channel_id: Id<ChannelMarker>,
+    http: &'a Client,
+    message_ids: &'a [Id<MessageMarker>],
+    reason: Option<&'a str>,
+}
+
+impl<'a> PurgeMessages<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        channel_id: Id<ChannelMarker>,
+        message_ids: &'a [Id<MessageMarker>],
+    ) -> Self {
+        Self {
+            channel_id,
+            http,
+            message_ids,
+            reason: None,
+        }
+    }
+
+    /// Execute the requests, returning the number of deleted messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first request that failed.
+    pub async fn exec(self) -> Result<PurgedMessages, Error> {
+        let (mut recent, old) = partition(self.message_ids, SystemTime::now());
+        let mut purged = PurgedMessages::default();
+
+        // Bulk deleting requires at least two messages, so a remaining single
+        // recent message is deleted on its own.
+        let single = if recent.len() % BULK_DELETE_COUNT_MAX == 1 {
+            recent.pop()
+        } else {
+            None
+        };
+
+        for batch in recent.chunks(BULK_DELETE_COUNT_MAX) {
+            self.delete_bulk(batch).await?;
+            purged.bulk_deleted += batch.len();
+        }
+
+        for message_id in single.into_iter().chain(old) {
+            let mut request = self.http.delete_message(self.channel_id, message_id);
+
+            if let Some(reason) = self.reason {
+                request = request.reason(reason).map_err(reason_error)?;
+            }
+
+            request.exec().await?;
+            purged.individually_deleted += 1;
+        }
+
+        Ok(purged)
+    }
+
+    /// Bulk delete a batch of messages.
+    async fn delete_bulk(&self, message_ids: &[Id<MessageMarker>]) -> Result<(), Error> {
+        let mut request = self.http.delete_messages(self.channel_id, message_ids);
+
+        if let Some(reason) = self.reason {
+            request = request.reason(reason).map_err(reason_error)?;
+        }
+
+        request.exec().await?;
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason<'a> for PurgeMessages<'a> {
+    fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
+        validate_audit_reason(reason)?;
+
+        self.reason.replace(reason);
+
+        Ok(self)
+    }
+}
+
+/// Convert the error of setting an audit log reason on a request.
+///
+/// The reason has already been validated when set on [`PurgeMessages`], so
+/// this is not expected to occur.
+fn reason_error(source: ValidationError) -> Error {
+    Error {
+        kind: ErrorType::BuildingRequest,
+        source: Some(Box::new(source)),
+    }
+}
+
+/// Split deduplicated message IDs into those that can be bulk deleted at the
+/// given time and those that are too old.
+fn partition(
+    message_ids: &[Id<MessageMarker>],
+    now: SystemTime,
+) -> (Vec<Id<MessageMarker>>, Vec<Id<MessageMarker>>) {
+    let mut message_ids = message_ids.to_vec();
+    message_ids.sort_unstable();
+    message_ids.dedup();
+
+    let cutoff = now
+        .checked_sub(BULK_DELETE_MAX_AGE)
+        .and_then(|cutoff| cutoff.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |cutoff| cutoff.as_millis());
+
+    message_ids
+        .into_iter()
+        .partition(|id| u128::from((id.get() >> 22) + DISCORD_EPOCH) > cutoff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{partition, PurgeMessages, PurgedMessages, DISCORD_EPOCH};
+    use crate::{client::Client, test_server, API_VERSION};
+    use hyper::{Body, Method, Request as HyperRequest, Response as HyperResponse, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::Infallible,
+        error::Error,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+    use twilight_model::id::{marker::MessageMarker, Id};
+
+    assert_impl_all!(PurgeMessages<'_>: Send, Sync);
+
+    /// Requests received by the server, as their method, path, and body.
+    type Requests = Arc<Mutex<Vec<(Method, String, Vec<u8>)>>>;
+
+    /// Create message IDs with a timestamp of the given age.
+    fn message_ids(age: Duration, count: u64) -> Vec<Id<MessageMarker>> {
+        let timestamp = (SystemTime::now() - age)
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        let timestamp = u64::try_from(timestamp).unwrap() - DISCORD_EPOCH;
+
+        (1..=count)
+            .map(|increment| Id::new((timestamp << 22) | increment))
+            .collect()
+    }
+
+    fn recent(count: u64) -> Vec<Id<MessageMarker>> {
+        message_ids(Duration::from_secs(60), count)
+    }
+
+    fn old(count: u64) -> Vec<Id<MessageMarker>> {
+        message_ids(Duration::from_secs(60 * 60 * 24 * 15), count)
+    }
+
+    /// Spawn a server responding to every request with no content, recording
+    /// the requests.
+    fn serve() -> (SocketAddr, Requests) {
+        let requests = Requests::default();
+        let server_requests = Arc::clone(&requests);
+
+        let addr = test_server::serve(move |request: HyperRequest<Body>| {
+            let requests = Arc::clone(&server_requests);
+
+            async move {
+                let method = request.method().clone();
+                let path = request.uri().path().to_owned();
+                let body = hyper::body::to_bytes(request.into_body()).await.unwrap();
+                requests.lock().unwrap().push((method, path, body.to_vec()));
+
+                let mut response = HyperResponse::new(Body::empty());
+                *response.status_mut() = StatusCode::NO_CONTENT;
+
+                Ok::<_, Infallible>(response)
+            }
+        });
+
+        (addr, requests)
+    }
+
+    async fn purge(
+        message_ids: &[Id<MessageMarker>],
+    ) -> Result<(PurgedMessages, Vec<(Method, String, Vec<u8>)>), Box<dyn Error + Send + Sync>>
+    {
+        let (addr, requests) = serve();
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("Bot token".to_owned())
+            .build();
+
+        let purged = client
+            .purge_messages(Id::new(1), message_ids)
+            .exec()
+            .await?;
+        let requests = requests.lock().unwrap().clone();
+
+        Ok((purged, requests))
+    }
+
+    /// Number of message IDs in the body of a bulk delete request.
+    fn bulk_count(body: &[u8]) -> usize {
+        let body: serde_json::Value = serde_json::from_slice(body).unwrap();
+
+        body["messages"].as_array().unwrap().len()
+    }
+
+    #[test]
+    fn test_partition() {
+        let old_ids = old(2);
+        let recent_ids = recent(2);
+        let mut message_ids = [recent_ids.as_slice(), &old_ids, &old_ids[..1]].concat();
+        message_ids.reverse();
+
+        assert_eq!(
+            (recent_ids, old_ids),
+            partition(&message_ids, SystemTime::now())
+        );
+    }
+
+    /// Test that messages within minutes of being two weeks old aren't bulk
+    /// deleted.
+    #[test]
+    fn test_partition_margin() {
+        let near_limit = message_ids(Duration::from_secs(60 * 60 * 24 * 14 - 60), 1);
+        let within_limit = message_ids(Duration::from_secs(60 * 60 * 24 * 14 - 60 * 10), 1);
+        let message_ids = [near_limit.as_slice(), &within_limit].concat();
+
+        assert_eq!(
+            (within_limit, near_limit),
+            partition(&message_ids, SystemTime::now())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exactly_100() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (purged, requests) = purge(&recent(100)).await?;
+
+        assert_eq!(
+            PurgedMessages {
+                bulk_deleted: 100,
+                individually_deleted: 0,
+            },
+            purged
+        );
+        assert_eq!(1, requests.len());
+        assert_eq!(Method::POST, requests[0].0);
+        assert_eq!(
+            format!("/api/v{API_VERSION}/channels/1/messages/bulk-delete"),
+            requests[0].1
+        );
+        assert_eq!(100, bulk_count(&requests[0].2));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_101() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let message_ids = recent(101);
+        let (purged, requests) = purge(&message_ids).await?;
+
+        assert_eq!(
+            PurgedMessages {
+                bulk_deleted: 100,
+                individually_deleted: 1,
+            },
+            purged
+        );
+        assert_eq!(2, requests.len());
+        assert_eq!(Method::POST, requests[0].0);
+        assert_eq!(100, bulk_count(&requests[0].2));
+        assert_eq!(Method::DELETE, requests[1].0);
+        assert_eq!(
+            format!(
+                "/api/v{API_VERSION}/channels/1/messages/{}",
+                message_ids[100]
+            ),
+            requests[1].1
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_old_and_recent() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mut message_ids = old(2);
+        message_ids.extend(recent(150));
+        let (purged, requests) = purge(&message_ids).await?;
+
+        assert_eq!(
+            PurgedMessages {
+                bulk_deleted: 150,
+                individually_deleted: 2,
+            },
+            purged
+        );
+
+        let bulk = requests
+            .iter()
+            .filter(|(method, ..)| *method == Method::POST)
+            .map(|(.., body)| bulk_count(body))
+            .collect::<Vec<_>>();
+        assert_eq!([100, 50].as_slice(), bulk);
+
+        let single = requests
+            .iter()
+            .filter(|(method, ..)| *method == Method::DELETE)
+            .map(|(_, path, _)| path.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            [
+                format!("/api/v{API_VERSION}/channels/1/messages/{}", message_ids[0]),
+                format!("/api/v{API_VERSION}/channels/1/messages/{}", message_ids[1]),
+            ]
+            .as_slice(),
+            single
+        );
+
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ChannelMarker, MessageMarker},
     Id,
@@ -42,6 +43,7 @@ pub struct CreateReaction<'a> {
     emoji: &'a RequestReactionType<'a>,
     http: &'a Client,
     message_id: Id<MessageMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateReaction<'a> {
@@ -56,9 +58,21 @@ impl<'a> CreateReaction<'a> {
             emoji,
             http,
             message_id,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -78,7 +92,8 @@ impl TryIntoRequest for CreateReaction<'_> {
             channel_id: self.channel_id.get(),
             emoji: self.emoji,
             message_id: self.message_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
 
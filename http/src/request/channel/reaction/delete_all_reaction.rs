@@ -6,6 +6,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ChannelMarker, MessageMarker},
     Id,
@@ -18,6 +19,7 @@ pub struct DeleteAllReaction<'a> {
     emoji: &'a RequestReactionType<'a>,
     http: &'a Client,
     message_id: Id<MessageMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteAllReaction<'a> {
@@ -32,9 +34,21 @@ impl<'a> DeleteAllReaction<'a> {
             emoji,
             http,
             message_id,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -54,6 +68,7 @@ impl TryIntoRequest for DeleteAllReaction<'_> {
             channel_id: self.channel_id.get(),
             message_id: self.message_id.get(),
             emoji: self.emoji,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ChannelMarker, MessageMarker},
     Id,
@@ -16,6 +17,7 @@ pub struct DeleteAllReactions<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
     message_id: Id<MessageMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteAllReactions<'a> {
@@ -28,9 +30,21 @@ impl<'a> DeleteAllReactions<'a> {
             channel_id,
             http,
             message_id,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -49,6 +63,7 @@ impl TryIntoRequest for DeleteAllReactions<'_> {
         Ok(Request::from_route(&Route::DeleteMessageReactions {
             channel_id: self.channel_id.get(),
             message_id: self.message_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
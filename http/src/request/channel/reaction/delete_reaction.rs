@@ -6,6 +6,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ChannelMarker, MessageMarker, UserMarker},
     Id,
@@ -27,6 +28,7 @@ pub struct DeleteReaction<'a> {
     http: &'a Client,
     message_id: Id<MessageMarker>,
     target_user: TargetUser,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteReaction<'a> {
@@ -43,9 +45,21 @@ impl<'a> DeleteReaction<'a> {
             http,
             message_id,
             target_user,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -75,6 +89,6 @@ impl TryIntoRequest for DeleteReaction<'_> {
             },
         };
 
-        Ok(Request::from_route(&route))
+        Ok(Request::from_route(&route).with_timeout(self.timeout))
     }
 }
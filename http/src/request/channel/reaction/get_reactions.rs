@@ -6,6 +6,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     id::{
         marker::{ChannelMarker, MessageMarker, UserMarker},
@@ -33,6 +34,7 @@ pub struct GetReactions<'a> {
     fields: GetReactionsFields,
     http: &'a Client,
     message_id: Id<MessageMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetReactions<'a> {
@@ -51,6 +53,7 @@ impl<'a> GetReactions<'a> {
             },
             http,
             message_id,
+            timeout: None,
         }
     }
 
@@ -82,6 +85,17 @@ impl<'a> GetReactions<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -103,6 +117,7 @@ impl TryIntoRequest for GetReactions<'_> {
             emoji: self.emoji,
             limit: self.fields.limit,
             message_id: self.message_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
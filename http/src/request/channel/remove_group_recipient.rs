@@ -0,0 +1,98 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::EmptyBody, ResponseFuture},
+    routing::Route,
+};
+use std::time::Duration;
+use twilight_model::id::{
+    marker::{ChannelMarker, UserMarker},
+    Id,
+};
+
+/// Remove a user from a group DM.
+///
+/// Refer to [Discord Docs/Group DM Remove Recipient].
+///
+/// [Discord Docs/Group DM Remove Recipient]: https://discord.com/developers/docs/resources/channel#group-dm-remove-recipient
+#[must_use = "requests must be configured and executed"]
+pub struct RemoveGroupRecipient<'a> {
+    channel_id: Id<ChannelMarker>,
+    http: &'a Client,
+    timeout: Option<Duration>,
+    user_id: Id<UserMarker>,
+}
+
+impl<'a> RemoveGroupRecipient<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        channel_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Self {
+        Self {
+            channel_id,
+            http,
+            timeout: None,
+            user_id,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<EmptyBody> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for RemoveGroupRecipient<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(&Route::RemoveGroupRecipient {
+            channel_id: self.channel_id.get(),
+            user_id: self.user_id.get(),
+        })
+        .with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RemoveGroupRecipient;
+    use crate::{
+        client::Client,
+        request::{Method, TryIntoRequest},
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let request =
+            RemoveGroupRecipient::new(&client, Id::new(1), Id::new(2)).try_into_request()?;
+
+        assert_eq!(Method::Delete, request.method());
+        assert_eq!("channels/1/recipients/2", request.path());
+        assert!(request.body().is_none());
+
+        Ok(())
+    }
+}
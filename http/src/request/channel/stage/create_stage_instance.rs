@@ -6,9 +6,13 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::{stage_instance::PrivacyLevel, StageInstance},
-    id::{marker::ChannelMarker, Id},
+    id::{
+        marker::{ChannelMarker, ScheduledEventMarker},
+        Id,
+    },
 };
 use twilight_validate::request::{stage_topic as validate_stage_topic, ValidationError};
 
@@ -16,6 +20,8 @@ use twilight_validate::request::{stage_topic as validate_stage_topic, Validation
 struct CreateStageInstanceFields<'a> {
     channel_id: Id<ChannelMarker>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    guild_scheduled_event_id: Option<Id<ScheduledEventMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     privacy_level: Option<PrivacyLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
     send_start_notification: Option<bool>,
@@ -29,6 +35,7 @@ struct CreateStageInstanceFields<'a> {
 pub struct CreateStageInstance<'a> {
     fields: CreateStageInstanceFields<'a>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateStageInstance<'a> {
@@ -42,14 +49,26 @@ impl<'a> CreateStageInstance<'a> {
         Ok(Self {
             fields: CreateStageInstanceFields {
                 channel_id,
+                guild_scheduled_event_id: None,
                 privacy_level: None,
                 send_start_notification: None,
                 topic,
             },
             http,
+            timeout: None,
         })
     }
 
+    /// Set the scheduled event associated with the instance.
+    pub const fn guild_scheduled_event_id(
+        mut self,
+        guild_scheduled_event_id: Id<ScheduledEventMarker>,
+    ) -> Self {
+        self.fields.guild_scheduled_event_id = Some(guild_scheduled_event_id);
+
+        self
+    }
+
     /// Set the [`PrivacyLevel`] of the instance.
     pub const fn privacy_level(mut self, privacy_level: PrivacyLevel) -> Self {
         self.fields.privacy_level = Some(privacy_level);
@@ -69,6 +88,17 @@ impl<'a> CreateStageInstance<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -88,6 +118,36 @@ impl TryIntoRequest for CreateStageInstance<'_> {
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client
+            .create_stage_instance(Id::new(1), "topic")?
+            .guild_scheduled_event_id(Id::new(2))
+            .send_start_notification(true)
+            .try_into_request()?;
+
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("stage-instances", request.path());
+        assert_eq!(
+            Some(
+                br#"{"channel_id":"1","guild_scheduled_event_id":"2","send_start_notification":true,"topic":"topic"}"#
+                    .as_slice()
+            ),
+            request.body()
+        );
+
+        Ok(())
     }
 }
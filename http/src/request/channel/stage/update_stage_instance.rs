@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::{stage_instance::PrivacyLevel, StageInstance},
     id::{marker::ChannelMarker, Id},
@@ -28,6 +29,7 @@ pub struct UpdateStageInstance<'a> {
     channel_id: Id<ChannelMarker>,
     fields: UpdateStageInstanceFields<'a>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateStageInstance<'a> {
@@ -39,6 +41,7 @@ impl<'a> UpdateStageInstance<'a> {
                 topic: None,
             },
             http,
+            timeout: None,
         }
     }
 
@@ -58,6 +61,17 @@ impl<'a> UpdateStageInstance<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -79,6 +93,6 @@ impl TryIntoRequest for UpdateStageInstance<'_> {
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -1,11 +1,12 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, RequestBuilder, TryIntoRequest},
+    request::{Request, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::{thread::AutoArchiveDuration, Channel, ChannelType},
     id::{marker::ChannelMarker, Id},
@@ -41,6 +42,7 @@ pub struct CreateThread<'a> {
     channel_id: Id<ChannelMarker>,
     fields: CreateThreadFields<'a>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateThread<'a> {
@@ -63,6 +65,7 @@ impl<'a> CreateThread<'a> {
                 name,
             },
             http,
+            timeout: None,
         })
     }
 
@@ -90,6 +93,17 @@ impl<'a> CreateThread<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -109,6 +123,6 @@ impl TryIntoRequest for CreateThread<'_> {
             channel_id: self.channel_id.get(),
         })
         .json(&self.fields)
-        .map(RequestBuilder::build)
+        .map(|request| request.build().with_timeout(self.timeout))
     }
 }
@@ -1,11 +1,12 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, RequestBuilder, TryIntoRequest},
+    request::{Request, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::{thread::AutoArchiveDuration, Channel},
     id::{
@@ -47,6 +48,7 @@ pub struct CreateThreadFromMessage<'a> {
     fields: CreateThreadFromMessageFields<'a>,
     http: &'a Client,
     message_id: Id<MessageMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateThreadFromMessage<'a> {
@@ -66,6 +68,7 @@ impl<'a> CreateThreadFromMessage<'a> {
             },
             http,
             message_id,
+            timeout: None,
         })
     }
 
@@ -86,6 +89,17 @@ impl<'a> CreateThreadFromMessage<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -106,6 +120,6 @@ impl TryIntoRequest for CreateThreadFromMessage<'_> {
             message_id: self.message_id.get(),
         })
         .json(&self.fields)
-        .map(RequestBuilder::build)
+        .map(|request| request.build().with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::thread::ThreadsListing,
     id::{marker::ChannelMarker, Id},
@@ -20,6 +21,7 @@ pub struct GetJoinedPrivateArchivedThreads<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
     limit: Option<u64>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetJoinedPrivateArchivedThreads<'a> {
@@ -29,6 +31,7 @@ impl<'a> GetJoinedPrivateArchivedThreads<'a> {
             channel_id,
             http,
             limit: None,
+            timeout: None,
         }
     }
 
@@ -46,6 +49,17 @@ impl<'a> GetJoinedPrivateArchivedThreads<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -61,12 +75,13 @@ impl<'a> GetJoinedPrivateArchivedThreads<'a> {
 
 impl TryIntoRequest for GetJoinedPrivateArchivedThreads<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        Ok(Request::from_route(
-            &Route::GetJoinedPrivateArchivedThreads {
+        Ok(
+            Request::from_route(&Route::GetJoinedPrivateArchivedThreads {
                 before: self.before.map(Id::get),
                 channel_id: self.channel_id.get(),
                 limit: self.limit,
-            },
-        ))
+            })
+            .with_timeout(self.timeout),
+        )
     }
 }
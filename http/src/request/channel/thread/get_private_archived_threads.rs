@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::thread::ThreadsListing,
     id::{marker::ChannelMarker, Id},
@@ -22,6 +23,7 @@ pub struct GetPrivateArchivedThreads<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
     limit: Option<u64>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetPrivateArchivedThreads<'a> {
@@ -31,6 +33,7 @@ impl<'a> GetPrivateArchivedThreads<'a> {
             channel_id,
             http,
             limit: None,
+            timeout: None,
         }
     }
 
@@ -48,6 +51,17 @@ impl<'a> GetPrivateArchivedThreads<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -67,6 +81,7 @@ impl TryIntoRequest for GetPrivateArchivedThreads<'_> {
             before: self.before,
             channel_id: self.channel_id.get(),
             limit: self.limit,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
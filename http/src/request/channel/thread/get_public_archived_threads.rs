@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::thread::ThreadsListing,
     id::{marker::ChannelMarker, Id},
@@ -32,6 +33,7 @@ pub struct GetPublicArchivedThreads<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
     limit: Option<u64>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetPublicArchivedThreads<'a> {
@@ -41,6 +43,7 @@ impl<'a> GetPublicArchivedThreads<'a> {
             channel_id,
             http,
             limit: None,
+            timeout: None,
         }
     }
 
@@ -58,6 +61,17 @@ impl<'a> GetPublicArchivedThreads<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -77,6 +91,7 @@ impl TryIntoRequest for GetPublicArchivedThreads<'_> {
             before: self.before,
             channel_id: self.channel_id.get(),
             limit: self.limit,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
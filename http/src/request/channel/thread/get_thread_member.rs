@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::thread::ThreadMember,
     id::{
@@ -20,6 +21,7 @@ use twilight_model::{
 pub struct GetThreadMember<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     user_id: Id<UserMarker>,
 }
 
@@ -32,10 +34,22 @@ impl<'a> GetThreadMember<'a> {
         Self {
             channel_id,
             http,
+            timeout: None,
             user_id,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -54,6 +68,7 @@ impl TryIntoRequest for GetThreadMember<'_> {
         Ok(Request::from_route(&Route::GetThreadMember {
             channel_id: self.channel_id.get(),
             user_id: self.user_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ChannelMarker, UserMarker},
     Id,
@@ -23,6 +24,7 @@ use twilight_model::id::{
 pub struct RemoveThreadMember<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     user_id: Id<UserMarker>,
 }
 
@@ -35,10 +37,22 @@ impl<'a> RemoveThreadMember<'a> {
         Self {
             channel_id,
             http,
+            timeout: None,
             user_id,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -57,6 +71,7 @@ impl TryIntoRequest for RemoveThreadMember<'_> {
         Ok(Request::from_route(&Route::RemoveThreadMember {
             channel_id: self.channel_id.get(),
             user_id: self.user_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
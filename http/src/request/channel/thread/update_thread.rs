@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::{thread::AutoArchiveDuration, Channel},
     id::{marker::ChannelMarker, Id},
@@ -44,6 +45,7 @@ pub struct UpdateThread<'a> {
     fields: UpdateThreadFields<'a>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateThread<'a> {
@@ -60,6 +62,7 @@ impl<'a> UpdateThread<'a> {
             },
             http,
             reason: None,
+            timeout: None,
         }
     }
 
@@ -155,6 +158,17 @@ impl<'a> UpdateThread<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -189,7 +203,7 @@ impl TryIntoRequest for UpdateThread<'_> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
 
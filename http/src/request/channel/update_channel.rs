@@ -6,12 +6,21 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
-    channel::{permission_overwrite::PermissionOverwrite, Channel, ChannelType, VideoQualityMode},
+    channel::{
+        forum::{DefaultReaction, ForumLayout, ForumSortOrder},
+        permission_overwrite::PermissionOverwrite,
+        Channel, ChannelType, VideoQualityMode,
+    },
+    http::forum_tag::ForumTag,
     id::{marker::ChannelMarker, Id},
 };
 use twilight_validate::{
-    channel::{name as validate_name, topic as validate_topic, ChannelValidationError},
+    channel::{
+        forum_tags as validate_forum_tags, name as validate_name, topic as validate_topic,
+        ChannelValidationError,
+    },
     request::{audit_reason as validate_audit_reason, ValidationError},
 };
 
@@ -19,9 +28,19 @@ use twilight_validate::{
 // but it does require them to be non-null.
 #[derive(Serialize)]
 struct UpdateChannelFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    available_tags: Option<&'a [ForumTag]>,
     #[serde(skip_serializing_if = "Option::is_none")]
     bitrate: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    default_forum_layout: Option<ForumLayout>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_reaction_emoji: Option<NullableField<&'a DefaultReaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_sort_order: Option<ForumSortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_thread_rate_limit_per_user: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     nsfw: Option<bool>,
@@ -54,6 +73,7 @@ pub struct UpdateChannel<'a> {
     fields: UpdateChannelFields<'a>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateChannel<'a> {
@@ -61,7 +81,12 @@ impl<'a> UpdateChannel<'a> {
         Self {
             channel_id,
             fields: UpdateChannelFields {
+                available_tags: None,
                 bitrate: None,
+                default_forum_layout: None,
+                default_reaction_emoji: None,
+                default_sort_order: None,
+                default_thread_rate_limit_per_user: None,
                 name: None,
                 nsfw: None,
                 parent_id: None,
@@ -75,9 +100,38 @@ impl<'a> UpdateChannel<'a> {
             },
             http,
             reason: None,
+            timeout: None,
         }
     }
 
+    /// Set the tags that can be applied to posts of a forum channel.
+    ///
+    /// This replaces all of the channel's existing tags. Tags without an ID
+    /// are created, and existing tags absent from the list are deleted.
+    /// Existing tags of a channel may be converted into this request's tags
+    /// with [`From`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ForumTagCount`] if more than 20 tags are
+    /// provided.
+    ///
+    /// Returns an error of type [`ForumTagName`] if the name of a tag is more
+    /// than 20 characters long.
+    ///
+    /// [`ForumTagCount`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagCount
+    /// [`ForumTagName`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagName
+    pub fn available_tags(
+        mut self,
+        available_tags: &'a [ForumTag],
+    ) -> Result<Self, ChannelValidationError> {
+        validate_forum_tags(available_tags)?;
+
+        self.fields.available_tags = Some(available_tags);
+
+        Ok(self)
+    }
+
     /// Set the bitrate of the channel. Applicable to voice channels only.
     pub const fn bitrate(mut self, bitrate: u32) -> Self {
         self.fields.bitrate = Some(bitrate);
@@ -85,6 +139,59 @@ impl<'a> UpdateChannel<'a> {
         self
     }
 
+    /// Set the default layout used to display posts of a forum channel.
+    pub const fn default_forum_layout(mut self, default_forum_layout: ForumLayout) -> Self {
+        self.fields.default_forum_layout = Some(default_forum_layout);
+
+        self
+    }
+
+    /// Set the default emoji shown in the add reaction button on posts of a
+    /// forum channel.
+    ///
+    /// Pass [`None`] to remove the default emoji.
+    pub const fn default_reaction_emoji(
+        mut self,
+        default_reaction_emoji: Option<&'a DefaultReaction>,
+    ) -> Self {
+        self.fields.default_reaction_emoji = Some(NullableField(default_reaction_emoji));
+
+        self
+    }
+
+    /// Set the default order used to sort posts of a forum channel.
+    pub const fn default_sort_order(mut self, default_sort_order: ForumSortOrder) -> Self {
+        self.fields.default_sort_order = Some(default_sort_order);
+
+        self
+    }
+
+    /// Set the initial rate limit per user of threads created in the channel.
+    ///
+    /// The minimum is 0 and the maximum is 21600. This doesn't affect existing
+    /// threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`RateLimitPerUserInvalid`] if the rate limit
+    /// is invalid.
+    ///
+    /// [`RateLimitPerUserInvalid`]: twilight_validate::channel::ChannelValidationErrorType::RateLimitPerUserInvalid
+    pub const fn default_thread_rate_limit_per_user(
+        mut self,
+        default_thread_rate_limit_per_user: u16,
+    ) -> Result<Self, ChannelValidationError> {
+        if let Err(source) =
+            twilight_validate::channel::rate_limit_per_user(default_thread_rate_limit_per_user)
+        {
+            return Err(source);
+        }
+
+        self.fields.default_thread_rate_limit_per_user = Some(default_thread_rate_limit_per_user);
+
+        Ok(self)
+    }
+
     /// Set the name.
     ///
     /// The minimum length is 1 UTF-16 character and the maximum is 100 UTF-16
@@ -215,6 +322,17 @@ impl<'a> UpdateChannel<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -249,6 +367,119 @@ impl TryIntoRequest for UpdateChannel<'_> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateChannel;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use serde_json::json;
+    use std::error::Error;
+    use twilight_model::{
+        channel::forum::{DefaultReaction, ForumLayout, ForumSortOrder},
+        http::forum_tag::ForumTag,
+        id::Id,
+    };
+    use twilight_validate::channel::ChannelValidationErrorType;
+
+    #[test]
+    fn test_forum_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let channel_id = Id::new(1);
+        let tags = [
+            ForumTag {
+                emoji_id: None,
+                emoji_name: Some("🐛".to_owned()),
+                id: Some(Id::new(2)),
+                moderated: true,
+                name: "bug".to_owned(),
+            },
+            ForumTag {
+                emoji_id: Some(Id::new(4)),
+                emoji_name: None,
+                id: None,
+                moderated: false,
+                name: "feature".to_owned(),
+            },
+        ];
+        let reaction = DefaultReaction {
+            emoji_id: Some(Id::new(3)),
+            emoji_name: None,
+        };
+
+        let actual = UpdateChannel::new(&client, channel_id)
+            .available_tags(&tags)?
+            .default_forum_layout(ForumLayout::GalleryView)
+            .default_reaction_emoji(Some(&reaction))
+            .default_sort_order(ForumSortOrder::CreationDate)
+            .default_thread_rate_limit_per_user(60)?
+            .try_into_request()?;
+
+        let expected = Request::builder(&Route::UpdateChannel {
+            channel_id: channel_id.get(),
+        })
+        .json(&json!({
+            "available_tags": [{
+                "emoji_id": null,
+                "emoji_name": "🐛",
+                "id": "2",
+                "moderated": true,
+                "name": "bug",
+            }, {
+                "emoji_id": "4",
+                "emoji_name": null,
+                "moderated": false,
+                "name": "feature",
+            }],
+            "default_forum_layout": 2,
+            "default_reaction_emoji": {
+                "emoji_id": "3",
+                "emoji_name": null,
+            },
+            "default_sort_order": 1,
+            "default_thread_rate_limit_per_user": 60,
+        }))?
+        .build();
+
+        assert_eq!(expected.body(), actual.body());
+        assert_eq!(expected.path(), actual.path());
+
+        let actual = UpdateChannel::new(&client, channel_id)
+            .default_reaction_emoji(None)
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(br#"{"default_reaction_emoji":null}"#.as_slice()),
+            actual.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_forum_validation() {
+        let client = Client::new("token".to_owned());
+
+        assert!(UpdateChannel::new(&client, Id::new(1))
+            .default_thread_rate_limit_per_user(21_600)
+            .is_ok());
+
+        let error = UpdateChannel::new(&client, Id::new(1))
+            .default_thread_rate_limit_per_user(21_601)
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            error.kind(),
+            ChannelValidationErrorType::RateLimitPerUserInvalid {
+                rate_limit_per_user: 21_601
+            }
+        ));
     }
 }
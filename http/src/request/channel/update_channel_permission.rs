@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     guild::Permissions,
     http::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
@@ -66,6 +67,7 @@ pub struct UpdateChannelPermission<'a> {
     http: &'a Client,
     reason: Option<&'a str>,
     target_id: Id<GenericMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateChannelPermission<'a> {
@@ -84,9 +86,21 @@ impl<'a> UpdateChannelPermission<'a> {
             },
             reason: None,
             target_id: permission_overwrite.id,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -122,7 +136,7 @@ impl TryIntoRequest for UpdateChannelPermission<'_> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
 
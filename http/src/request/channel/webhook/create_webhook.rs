@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::Webhook,
     id::{marker::ChannelMarker, Id},
@@ -47,6 +48,7 @@ pub struct CreateWebhook<'a> {
     fields: CreateWebhookFields<'a>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateWebhook<'a> {
@@ -62,6 +64,7 @@ impl<'a> CreateWebhook<'a> {
             fields: CreateWebhookFields { avatar: None, name },
             http,
             reason: None,
+            timeout: None,
         })
     }
 
@@ -78,6 +81,17 @@ impl<'a> CreateWebhook<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -115,6 +129,6 @@ impl TryIntoRequest for CreateWebhook<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{marker::WebhookMarker, Id};
 use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
 
@@ -19,6 +20,7 @@ pub struct DeleteWebhook<'a> {
     http: &'a Client,
     id: Id<WebhookMarker>,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteWebhook<'a> {
@@ -28,6 +30,7 @@ impl<'a> DeleteWebhook<'a> {
             http,
             id,
             reason: None,
+            timeout: None,
         }
     }
 
@@ -38,6 +41,17 @@ impl<'a> DeleteWebhook<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -74,6 +88,6 @@ impl TryIntoRequest for DeleteWebhook<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ChannelMarker, MessageMarker, WebhookMarker},
     Id,
@@ -36,6 +37,7 @@ pub struct DeleteWebhookMessage<'a> {
     message_id: Id<MessageMarker>,
     reason: Option<&'a str>,
     thread_id: Option<Id<ChannelMarker>>,
+    timeout: Option<Duration>,
     token: &'a str,
     webhook_id: Id<WebhookMarker>,
 }
@@ -52,6 +54,7 @@ impl<'a> DeleteWebhookMessage<'a> {
             message_id,
             reason: None,
             thread_id: None,
+            timeout: None,
             token,
             webhook_id,
         }
@@ -65,6 +68,17 @@ impl<'a> DeleteWebhookMessage<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -102,7 +116,7 @@ impl TryIntoRequest for DeleteWebhookMessage<'_> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
 
@@ -10,6 +10,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     application::component::Component,
     channel::{
@@ -24,8 +25,9 @@ use twilight_model::{
 };
 use twilight_validate::{
     message::{
-        attachment_filename as validate_attachment_filename, components as validate_components,
-        content as validate_content, embeds as validate_embeds, MessageValidationError,
+        attachment as validate_attachment, components as validate_components,
+        content as validate_content, embeds as validate_embeds,
+        webhook_thread as validate_webhook_thread, MessageValidationError,
         MessageValidationErrorType,
     },
     request::webhook_username as validate_webhook_username,
@@ -50,6 +52,8 @@ pub(crate) struct ExecuteWebhookFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     payload_json: Option<&'a [u8]>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    thread_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     tts: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     username: Option<&'a str>,
@@ -87,6 +91,7 @@ pub struct ExecuteWebhook<'a> {
     fields: ExecuteWebhookFields<'a>,
     http: &'a Client,
     thread_id: Option<Id<ChannelMarker>>,
+    timeout: Option<Duration>,
     token: &'a str,
     wait: bool,
     webhook_id: Id<WebhookMarker>,
@@ -108,12 +113,14 @@ impl<'a> ExecuteWebhook<'a> {
                 embeds: None,
                 flags: None,
                 payload_json: None,
+                thread_name: None,
                 tts: None,
                 username: None,
                 allowed_mentions: None,
             },
             http,
             thread_id: None,
+            timeout: None,
             token,
             wait: false,
             webhook_id,
@@ -136,17 +143,19 @@ impl<'a> ExecuteWebhook<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentDescriptionTooLarge`] if any
+    /// description is too large.
+    ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentDescriptionTooLarge`]: twilight_validate::message::MessageValidationErrorType::AttachmentDescriptionTooLarge
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(
         mut self,
         attachments: &'a [Attachment],
     ) -> Result<Self, MessageValidationError> {
-        attachments
-            .iter()
-            .try_for_each(|attachment| validate_attachment_filename(&attachment.filename))?;
+        attachments.iter().try_for_each(validate_attachment)?;
 
         self.attachment_manager = self
             .attachment_manager
@@ -302,12 +311,58 @@ impl<'a> ExecuteWebhook<'a> {
     }
 
     /// Execute in a thread belonging to the channel instead of the channel itself.
+    ///
+    /// Replaces a previously set [thread name], as a webhook can't both be
+    /// executed in an existing thread and create a new one.
+    ///
+    /// [thread name]: Self::thread_name
     pub fn thread_id(mut self, thread_id: Id<ChannelMarker>) -> Self {
+        self.fields.thread_name = None;
         self.thread_id.replace(thread_id);
 
         self
     }
 
+    /// Name of the thread to create, if the webhook's channel is a forum
+    /// channel.
+    ///
+    /// Forum channels require a thread name, creating a post containing the
+    /// message. Replaces a previously set [thread ID], as a webhook can't both
+    /// be executed in an existing thread and create a new one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// client
+    ///     .execute_webhook(Id::new(432), "webhook token")
+    ///     .thread_name("A new post")?
+    ///     .content("The contents of the post")?
+    ///     .exec()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`WebhookThreadName`] if the name is invalid.
+    ///
+    /// [`WebhookThreadName`]: twilight_validate::message::MessageValidationErrorType::WebhookThreadName
+    /// [thread ID]: Self::thread_id
+    pub fn thread_name(mut self, thread_name: &'a str) -> Result<Self, MessageValidationError> {
+        validate_webhook_thread(None, Some(thread_name))?;
+
+        self.fields.thread_name = Some(thread_name);
+        self.thread_id = None;
+
+        Ok(self)
+    }
+
     /// Specify true if the message is TTS.
     pub const fn tts(mut self, tts: bool) -> Self {
         self.fields.tts = Some(tts);
@@ -348,6 +403,17 @@ impl<'a> ExecuteWebhook<'a> {
         ExecuteWebhookAndWait::new(self.http, self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -401,6 +467,62 @@ impl TryIntoRequest for ExecuteWebhook<'_> {
             request = request.json(&self.fields)?;
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExecuteWebhook;
+    use crate::{
+        client::Client,
+        request::{Method, TryIntoRequest},
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn test_forum_post() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let request = ExecuteWebhook::new(&client, Id::new(1), "token")
+            .thread_name("post")?
+            .content("content")?
+            .try_into_request()?;
+
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("webhooks/1/token?wait=false", request.path());
+        assert_eq!(
+            Some(br#"{"content":"content","thread_name":"post"}"#.as_slice()),
+            request.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_thread_name_and_id() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let request = ExecuteWebhook::new(&client, Id::new(1), "token")
+            .thread_id(Id::new(2))
+            .thread_name("post")?
+            .try_into_request()?;
+
+        assert_eq!("webhooks/1/token?wait=false", request.path());
+        assert_eq!(
+            Some(br#"{"thread_name":"post"}"#.as_slice()),
+            request.body()
+        );
+
+        let request = ExecuteWebhook::new(&client, Id::new(1), "token")
+            .thread_name("post")?
+            .thread_id(Id::new(2))
+            .try_into_request()?;
+
+        assert_eq!("webhooks/1/token?thread_id=2&wait=false", request.path());
+        assert_eq!(Some(b"{}".as_slice()), request.body());
+
+        Ok(())
     }
 }
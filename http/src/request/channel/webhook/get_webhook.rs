@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::Webhook,
     id::{marker::WebhookMarker, Id},
@@ -20,6 +21,7 @@ pub struct GetWebhook<'a> {
     fields: GetWebhookFields<'a>,
     http: &'a Client,
     id: Id<WebhookMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetWebhook<'a> {
@@ -28,6 +30,7 @@ impl<'a> GetWebhook<'a> {
             fields: GetWebhookFields { token: None },
             http,
             id,
+            timeout: None,
         }
     }
 
@@ -39,6 +42,17 @@ impl<'a> GetWebhook<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -67,6 +81,6 @@ impl TryIntoRequest for GetWebhook<'_> {
             request = request.use_authorization_token(false);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
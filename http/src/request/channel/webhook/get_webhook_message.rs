@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::Message,
     id::{
@@ -19,6 +20,7 @@ pub struct GetWebhookMessage<'a> {
     http: &'a Client,
     message_id: Id<MessageMarker>,
     thread_id: Option<Id<ChannelMarker>>,
+    timeout: Option<Duration>,
     token: &'a str,
     webhook_id: Id<WebhookMarker>,
 }
@@ -34,6 +36,7 @@ impl<'a> GetWebhookMessage<'a> {
             http,
             message_id,
             thread_id: None,
+            timeout: None,
             token,
             webhook_id,
         }
@@ -47,6 +50,17 @@ impl<'a> GetWebhookMessage<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -69,6 +83,7 @@ impl TryIntoRequest for GetWebhookMessage<'_> {
             webhook_id: self.webhook_id.get(),
         })
         .use_authorization_token(false)
-        .build())
+        .build()
+        .with_timeout(self.timeout))
     }
 }
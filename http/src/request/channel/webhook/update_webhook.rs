@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::Webhook,
     id::{
@@ -33,6 +34,7 @@ struct UpdateWebhookFields<'a> {
 pub struct UpdateWebhook<'a> {
     fields: UpdateWebhookFields<'a>,
     http: &'a Client,
+    timeout: Option<Duration>,
     webhook_id: Id<WebhookMarker>,
     reason: Option<&'a str>,
 }
@@ -47,6 +49,7 @@ impl<'a> UpdateWebhook<'a> {
                 name: None,
             },
             http,
+            timeout: None,
             webhook_id,
             reason: None,
         }
@@ -90,6 +93,17 @@ impl<'a> UpdateWebhook<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -128,6 +142,6 @@ impl TryIntoRequest for UpdateWebhook<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -11,6 +11,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     application::component::Component,
     channel::{embed::Embed, message::AllowedMentions},
@@ -21,7 +22,7 @@ use twilight_model::{
     },
 };
 use twilight_validate::message::{
-    attachment_filename as validate_attachment_filename, components as validate_components,
+    attachment as validate_attachment, components as validate_components,
     content as validate_content, embeds as validate_embeds, MessageValidationError,
 };
 
@@ -84,6 +85,7 @@ pub struct UpdateWebhookMessage<'a> {
     http: &'a Client,
     message_id: Id<MessageMarker>,
     thread_id: Option<Id<ChannelMarker>>,
+    timeout: Option<Duration>,
     token: &'a str,
     webhook_id: Id<WebhookMarker>,
 }
@@ -108,6 +110,7 @@ impl<'a> UpdateWebhookMessage<'a> {
             http,
             message_id,
             thread_id: None,
+            timeout: None,
             token,
             webhook_id,
         }
@@ -129,17 +132,19 @@ impl<'a> UpdateWebhookMessage<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentDescriptionTooLarge`] if any
+    /// description is too large.
+    ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentDescriptionTooLarge`]: twilight_validate::message::MessageValidationErrorType::AttachmentDescriptionTooLarge
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(
         mut self,
         attachments: &'a [Attachment],
     ) -> Result<Self, MessageValidationError> {
-        attachments
-            .iter()
-            .try_for_each(|attachment| validate_attachment_filename(&attachment.filename))?;
+        attachments.iter().try_for_each(validate_attachment)?;
 
         self.attachment_manager = self
             .attachment_manager
@@ -156,13 +161,16 @@ impl<'a> UpdateWebhookMessage<'a> {
     ///
     /// # Editing
     ///
-    /// Pass [`None`] to clear existing components.
+    /// To keep all components, do not call this method. Pass [`None`] or use
+    /// [`clear_components`] to clear existing components.
     ///
     /// # Errors
     ///
     /// Refer to the errors section of
     /// [`twilight_validate::component::component`] for a list of errors that
     /// may be returned as a result of validating each provided component.
+    ///
+    /// [`clear_components`]: Self::clear_components
     pub fn components(
         mut self,
         components: Option<&'a [Component]>,
@@ -176,6 +184,40 @@ impl<'a> UpdateWebhookMessage<'a> {
         Ok(self)
     }
 
+    /// Clear the message's components.
+    ///
+    /// Calling this method will clear previous calls to [`components`]. The
+    /// components are cleared by sending an empty list, the same as clearing
+    /// the components of an interaction response, while leaving the other
+    /// fields of the message untouched.
+    ///
+    /// # Examples
+    ///
+    /// Update the content of a message and remove its buttons:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// let client = Client::new("token".to_owned());
+    ///
+    /// client
+    ///     .update_webhook_message(Id::new(1), "token", Id::new(2))
+    ///     .content(Some("poll closed"))?
+    ///     .clear_components()
+    ///     .exec()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`components`]: Self::components
+    pub const fn clear_components(mut self) -> Self {
+        self.fields.components = Some(NullableField(Some(&[])));
+
+        self
+    }
+
     /// Set the message's content.
     ///
     /// The maximum length is 2000 UTF-16 characters.
@@ -313,6 +355,17 @@ impl<'a> UpdateWebhookMessage<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -368,7 +421,7 @@ impl TryIntoRequest for UpdateWebhookMessage<'_> {
             request = request.json(&self.fields)?;
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
 
@@ -380,6 +433,7 @@ mod tests {
         request::{NullableField, Request, TryIntoRequest},
         routing::Route,
     };
+    use std::error::Error;
     use twilight_model::id::Id;
 
     #[test]
@@ -416,4 +470,34 @@ mod tests {
         assert_eq!(expected.body, actual.body);
         assert_eq!(expected.path, actual.path);
     }
+
+    #[test]
+    fn test_components_omitted() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let request = client
+            .update_webhook_message(Id::new(1), "token", Id::new(2))
+            .content(Some("test"))?
+            .try_into_request()?;
+
+        assert_eq!(Some(br#"{"content":"test"}"#.as_slice()), request.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clear_components() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let request = client
+            .update_webhook_message(Id::new(1), "token", Id::new(2))
+            .content(Some("test"))?
+            .clear_components()
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(br#"{"components":[],"content":"test"}"#.as_slice()),
+            request.body()
+        );
+
+        Ok(())
+    }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::Webhook,
     id::{marker::WebhookMarker, Id},
@@ -25,6 +26,7 @@ struct UpdateWebhookWithTokenFields<'a> {
 pub struct UpdateWebhookWithToken<'a> {
     fields: UpdateWebhookWithTokenFields<'a>,
     http: &'a Client,
+    timeout: Option<Duration>,
     token: &'a str,
     webhook_id: Id<WebhookMarker>,
 }
@@ -41,6 +43,7 @@ impl<'a> UpdateWebhookWithToken<'a> {
                 name: None,
             },
             http,
+            timeout: None,
             token,
             webhook_id,
         }
@@ -77,6 +80,17 @@ impl<'a> UpdateWebhookWithToken<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -100,6 +114,6 @@ impl TryIntoRequest for UpdateWebhookWithToken<'_> {
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::gateway::connection_info::ConnectionInfo;
 
 /// Get information about the gateway, optionally with additional information detailing the
@@ -44,11 +45,15 @@ use twilight_model::gateway::connection_info::ConnectionInfo;
 #[must_use = "requests must be configured and executed"]
 pub struct GetGateway<'a> {
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetGateway<'a> {
     pub(crate) const fn new(http: &'a Client) -> Self {
-        Self { http }
+        Self {
+            http,
+            timeout: None,
+        }
     }
 
     /// Call to authenticate this request.
@@ -59,6 +64,17 @@ impl<'a> GetGateway<'a> {
         GetGatewayAuthed::new(self.http)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -74,6 +90,6 @@ impl<'a> GetGateway<'a> {
 
 impl TryIntoRequest for GetGateway<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        Ok(Request::from_route(&Route::GetGateway))
+        Ok(Request::from_route(&Route::GetGateway).with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::gateway::connection_info::BotConnectionInfo;
 
 /// Get information about the gateway, authenticated as a bot user.
@@ -14,11 +15,26 @@ use twilight_model::gateway::connection_info::BotConnectionInfo;
 #[must_use = "requests must be configured and executed"]
 pub struct GetGatewayAuthed<'a> {
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetGatewayAuthed<'a> {
     pub(crate) const fn new(http: &'a Client) -> Self {
-        Self { http }
+        Self {
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -36,6 +52,6 @@ impl<'a> GetGatewayAuthed<'a> {
 
 impl TryIntoRequest for GetGatewayAuthed<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        Ok(Request::from_route(&Route::GetGatewayBot))
+        Ok(Request::from_route(&Route::GetGatewayBot).with_timeout(self.timeout))
     }
 }
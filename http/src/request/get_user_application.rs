@@ -5,16 +5,32 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::oauth::Application;
 
 #[must_use = "requests must be configured and executed"]
 pub struct GetUserApplicationInfo<'a> {
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetUserApplicationInfo<'a> {
     pub(crate) const fn new(http: &'a Client) -> Self {
-        Self { http }
+        Self {
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -32,6 +48,6 @@ impl<'a> GetUserApplicationInfo<'a> {
 
 impl TryIntoRequest for GetUserApplicationInfo<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        Ok(Request::from_route(&Route::GetCurrentUserApplicationInfo))
+        Ok(Request::from_route(&Route::GetCurrentUserApplicationInfo).with_timeout(self.timeout))
     }
 }
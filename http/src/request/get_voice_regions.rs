@@ -5,17 +5,33 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::voice::VoiceRegion;
 
 /// Get a list of voice regions that can be used when creating a guild.
 #[must_use = "requests must be configured and executed"]
 pub struct GetVoiceRegions<'a> {
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetVoiceRegions<'a> {
     pub(crate) const fn new(http: &'a Client) -> Self {
-        Self { http }
+        Self {
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -33,6 +49,6 @@ impl<'a> GetVoiceRegions<'a> {
 
 impl TryIntoRequest for GetVoiceRegions<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        Ok(Request::from_route(&Route::GetVoiceRegions))
+        Ok(Request::from_route(&Route::GetVoiceRegions).with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{GuildMarker, UserMarker},
     Id,
@@ -50,6 +51,7 @@ pub struct CreateBan<'a> {
     fields: CreateBanFields<'a>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     user_id: Id<UserMarker>,
 }
 
@@ -66,6 +68,7 @@ impl<'a> CreateBan<'a> {
             },
             guild_id,
             http,
+            timeout: None,
             user_id,
         }
     }
@@ -90,6 +93,17 @@ impl<'a> CreateBan<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -127,7 +141,7 @@ impl TryIntoRequest for CreateBan<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
 
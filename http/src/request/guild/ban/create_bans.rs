@@ -0,0 +1,234 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{self, AuditLogReason, Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use serde::Serialize;
+use std::time::Duration;
+use twilight_model::{
+    guild::GuildBulkBan,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_validate::request::{
+    audit_reason as validate_audit_reason,
+    create_guild_ban_delete_message_seconds as validate_create_guild_ban_delete_message_seconds,
+    create_guild_bans_users as validate_create_guild_bans_users, ValidationError,
+};
+
+#[derive(Serialize)]
+struct CreateBansFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_message_seconds: Option<u32>,
+    user_ids: &'a [Id<UserMarker>],
+}
+
+/// Ban up to 200 users from a guild at once, optionally with the number of
+/// seconds' worth of messages to delete and the reason.
+///
+/// Responds with the users that were and were not banned. Users that are
+/// already banned are reported as failed.
+///
+/// Requires the [`BAN_MEMBERS`] and [`MANAGE_GUILD`] permissions.
+///
+/// # Examples
+///
+/// Ban users `200` and `300` from guild `100`, deleting an hour's worth of
+/// their messages:
+///
+/// ```no_run
+/// use twilight_http::{request::AuditLogReason, Client};
+/// use twilight_model::id::Id;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let guild_id = Id::new(100);
+/// let user_ids = [Id::new(200), Id::new(300)];
+///
+/// let response = client
+///     .create_bans(guild_id, &user_ids)?
+///     .delete_message_seconds(3600)?
+///     .reason("raid")?
+///     .exec()
+///     .await?;
+/// let bulk_ban = response.model().await?;
+///
+/// println!("failed to ban: {:?}", bulk_ban.failed_users);
+/// # Ok(()) }
+/// ```
+///
+/// [`BAN_MEMBERS`]: twilight_model::guild::Permissions::BAN_MEMBERS
+/// [`MANAGE_GUILD`]: twilight_model::guild::Permissions::MANAGE_GUILD
+#[must_use = "requests must be configured and executed"]
+pub struct CreateBans<'a> {
+    fields: CreateBansFields<'a>,
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+    reason: Option<&'a str>,
+    timeout: Option<Duration>,
+}
+
+impl<'a> CreateBans<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        guild_id: Id<GuildMarker>,
+        user_ids: &'a [Id<UserMarker>],
+    ) -> Result<Self, ValidationError> {
+        if let Err(source) = validate_create_guild_bans_users(user_ids) {
+            return Err(source);
+        }
+
+        Ok(Self {
+            fields: CreateBansFields {
+                delete_message_seconds: None,
+                user_ids,
+            },
+            guild_id,
+            http,
+            reason: None,
+            timeout: None,
+        })
+    }
+
+    /// Set the number of seconds' worth of messages to delete.
+    ///
+    /// The number of seconds must be less than or equal to 604800 (7 days).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`CreateGuildBanDeleteMessageSeconds`] if the
+    /// number of seconds is greater than 604800.
+    ///
+    /// [`CreateGuildBanDeleteMessageSeconds`]: twilight_validate::request::ValidationErrorType::CreateGuildBanDeleteMessageSeconds
+    pub const fn delete_message_seconds(mut self, seconds: u32) -> Result<Self, ValidationError> {
+        if let Err(source) = validate_create_guild_ban_delete_message_seconds(seconds) {
+            return Err(source);
+        }
+
+        self.fields.delete_message_seconds = Some(seconds);
+
+        Ok(self)
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<GuildBulkBan> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl<'a> AuditLogReason<'a> for CreateBans<'a> {
+    fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
+        validate_audit_reason(reason)?;
+
+        self.reason.replace(reason);
+
+        Ok(self)
+    }
+}
+
+impl TryIntoRequest for CreateBans<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let mut request = Request::builder(&Route::CreateBans {
+            guild_id: self.guild_id.get(),
+        });
+
+        request = request.json(&self.fields)?;
+
+        if let Some(reason) = &self.reason {
+            let header = request::audit_header(reason)?;
+
+            request = request.headers(header);
+        }
+
+        Ok(request.build().with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::Client,
+        request::{AuditLogReason, TryIntoRequest, REASON_HEADER_NAME},
+    };
+    use hyper::header::HeaderValue;
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    };
+    use twilight_validate::request::ValidationErrorType;
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        const REASON: &str = "raid";
+        const USER_IDS: &[Id<UserMarker>] = &[Id::new(2), Id::new(3)];
+
+        let client = Client::new(String::new());
+        let request = client
+            .create_bans(GUILD_ID, USER_IDS)?
+            .delete_message_seconds(3600)?
+            .reason(REASON)?
+            .try_into_request()?;
+
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("guilds/1/bulk-ban", request.path());
+        assert_eq!(
+            Some(br#"{"delete_message_seconds":3600,"user_ids":["2","3"]}"#.as_slice()),
+            request.body()
+        );
+
+        let header = HeaderValue::from_static(REASON);
+        assert!(matches!(
+            request.headers(),
+            Some(map)
+            if map.len() == 1 && map.get(REASON_HEADER_NAME) == Some(&header)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_user_ids() {
+        let client = Client::new(String::new());
+
+        assert!(matches!(
+            client.create_bans(GUILD_ID, &[]),
+            Err(error)
+            if matches!(error.kind(), ValidationErrorType::CreateGuildBansUsers { count: 0 })
+        ));
+
+        let user_ids = (1..=201).map(Id::new).collect::<Vec<_>>();
+        assert!(client.create_bans(GUILD_ID, &user_ids[..200]).is_ok());
+        assert!(matches!(
+            client.create_bans(GUILD_ID, &user_ids),
+            Err(error)
+            if matches!(error.kind(), ValidationErrorType::CreateGuildBansUsers { count: 201 })
+        ));
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{GuildMarker, UserMarker},
     Id,
@@ -35,6 +36,7 @@ use twilight_validate::request::{audit_reason as validate_audit_reason, Validati
 pub struct DeleteBan<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     user_id: Id<UserMarker>,
     reason: Option<&'a str>,
 }
@@ -48,11 +50,23 @@ impl<'a> DeleteBan<'a> {
         Self {
             guild_id,
             http,
+            timeout: None,
             user_id,
             reason: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -89,6 +103,6 @@ impl TryIntoRequest for DeleteBan<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
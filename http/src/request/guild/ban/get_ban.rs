@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     guild::Ban,
     id::{
@@ -20,6 +21,7 @@ use twilight_model::{
 pub struct GetBan<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     user_id: Id<UserMarker>,
 }
 
@@ -32,10 +34,22 @@ impl<'a> GetBan<'a> {
         Self {
             guild_id,
             http,
+            timeout: None,
             user_id,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -54,6 +68,7 @@ impl TryIntoRequest for GetBan<'_> {
         Ok(Request::from_route(&Route::GetBan {
             guild_id: self.guild_id.get(),
             user_id: self.user_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
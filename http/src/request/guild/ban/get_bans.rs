@@ -1,10 +1,11 @@
 use crate::{
     client::Client,
-    error::Error,
+    error::{Error, ErrorType},
     request::{Request, TryIntoRequest},
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     guild::Ban,
     id::{
@@ -16,6 +17,9 @@ use twilight_validate::request::{
     get_guild_bans_limit as validate_get_guild_bans_limit, ValidationError,
 };
 
+/// Number of bans Discord returns per page if no limit is set.
+const DEFAULT_LIMIT: usize = 1000;
+
 struct GetBansFields {
     after: Option<Id<UserMarker>>,
     before: Option<Id<UserMarker>>,
@@ -53,6 +57,7 @@ pub struct GetBans<'a> {
     fields: GetBansFields,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetBans<'a> {
@@ -65,6 +70,7 @@ impl<'a> GetBans<'a> {
             },
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -108,6 +114,62 @@ impl<'a> GetBans<'a> {
         Ok(self)
     }
 
+    /// Paginate through the bans of the guild, starting after the user set
+    /// via [`after`] if any.
+    ///
+    /// Each page contains up to [`limit`] bans, or 1000 if no limit is set.
+    /// Pages are retrieved in ascending order of user IDs, so the user set via
+    /// [`before`] is ignored.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve all of the bans of a guild:
+    ///
+    /// ```no_run
+    /// use std::env;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    ///
+    /// let mut pages = client.bans(Id::new(1)).pages();
+    /// let mut bans = Vec::new();
+    ///
+    /// while let Some(page) = pages.next_page().await {
+    ///     bans.extend(page?);
+    /// }
+    ///
+    /// println!("{} users are banned", bans.len());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub const fn pages(self) -> BanPages<'a> {
+        BanPages {
+            after: self.fields.after,
+            finished: false,
+            guild_id: self.guild_id,
+            http: self.http,
+            limit: self.fields.limit,
+            timeout: self.timeout,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -128,6 +190,239 @@ impl TryIntoRequest for GetBans<'_> {
             before: self.fields.before.map(Id::get),
             limit: self.fields.limit,
             guild_id: self.guild_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
+    }
+}
+
+/// Pages of the bans of a guild.
+///
+/// Created via [`GetBans::pages`].
+#[derive(Debug)]
+#[must_use = "pages must be retrieved via `next_page`"]
+pub struct BanPages<'a> {
+    after: Option<Id<UserMarker>>,
+    finished: bool,
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+    limit: Option<u16>,
+    timeout: Option<Duration>,
+}
+
+impl BanPages<'_> {
+    /// Retrieve the next page of bans.
+    ///
+    /// Returns [`None`] once all bans have been retrieved. If retrieving a
+    /// page fails then the same page is requested on the next call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// Returns an error of type [`ChunkingResponse`] if the response body
+    /// could not be chunked.
+    ///
+    /// Returns an error of type [`Parsing`] if the response body could not be
+    /// deserialized.
+    ///
+    /// [`ChunkingResponse`]: crate::error::ErrorType::ChunkingResponse
+    /// [`Parsing`]: crate::error::ErrorType::Parsing
+    pub async fn next_page(&mut self) -> Option<Result<Vec<Ban>, Error>> {
+        if self.finished {
+            return None;
+        }
+
+        let request = GetBans {
+            fields: GetBansFields {
+                after: self.after,
+                before: None,
+                limit: self.limit,
+            },
+            guild_id: self.guild_id,
+            http: self.http,
+            timeout: self.timeout,
+        };
+
+        let bytes = match request.exec().await {
+            Ok(response) => response.bytes().await,
+            Err(source) => return Some(Err(source)),
+        };
+
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(source) => {
+                return Some(Err(Error {
+                    kind: ErrorType::ChunkingResponse,
+                    source: Some(Box::new(source)),
+                }))
+            }
+        };
+
+        let bans = match crate::json::from_bytes::<Vec<Ban>>(&bytes) {
+            Ok(bans) => bans,
+            Err(source) => {
+                return Some(Err(Error {
+                    kind: ErrorType::Parsing { body: bytes },
+                    source: Some(Box::new(source)),
+                }))
+            }
+        };
+
+        self.advance(bans.len(), bans.last().map(|ban| ban.user.id));
+
+        Some(Ok(bans))
+    }
+
+    /// Advance the cursor past a page of `count` bans, the last of which is
+    /// of the user `last`.
+    fn advance(&mut self, count: usize, last: Option<Id<UserMarker>>) {
+        let limit = self.limit.map_or(DEFAULT_LIMIT, usize::from);
+
+        self.finished = count < limit || last.is_none();
+
+        if let Some(last) = last {
+            self.after = Some(last);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest, test_server};
+    use hyper::{Body, Request as HyperRequest, Response as HyperResponse};
+    use std::{
+        convert::Infallible,
+        error::Error,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::id::Id;
+
+    /// Spawn a server responding with the bans of a guild with the given
+    /// number of bans, recording the query of each request.
+    fn serve_bans(total: u64) -> (SocketAddr, Arc<Mutex<Vec<String>>>) {
+        let queries = Arc::new(Mutex::new(Vec::new()));
+        let server_queries = Arc::clone(&queries);
+
+        let addr = test_server::serve(move |request: HyperRequest<Body>| {
+            let query = request.uri().query().unwrap_or_default().to_owned();
+            let mut after = 0;
+            let mut limit = 1000;
+
+            for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+                match key {
+                    "after" => after = value.parse().unwrap(),
+                    "limit" => limit = value.parse().unwrap(),
+                    _ => {}
+                }
+            }
+
+            server_queries.lock().unwrap().push(query);
+
+            let bans = (after + 1..=total)
+                .take(limit)
+                .map(|id| {
+                    format!(
+                        r#"{{"reason":null,"user":{{"avatar":null,"discriminator":"0001","id":"{id}","username":"user"}}}}"#
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+
+            async move { Ok::<_, Infallible>(HyperResponse::new(Body::from(format!("[{bans}]")))) }
+        });
+
+        (addr, queries)
+    }
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client
+            .bans(Id::new(1))
+            .after(Id::new(2))
+            .limit(25)?
+            .try_into_request()?;
+
+        assert_eq!(Method::Get, request.method());
+        assert_eq!("guilds/1/bans?after=2&limit=25", request.path());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pages_advance() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let mut pages = client.bans(Id::new(1)).after(Id::new(2)).limit(2)?.pages();
+        assert_eq!(Some(Id::new(2)), pages.after);
+
+        // Full pages continue after the last user.
+        pages.advance(2, Some(Id::new(4)));
+        assert_eq!(Some(Id::new(4)), pages.after);
+        assert!(!pages.finished);
+
+        // Partial pages are the last page.
+        pages.advance(1, Some(Id::new(5)));
+        assert_eq!(Some(Id::new(5)), pages.after);
+        assert!(pages.finished);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pages_empty() {
+        let client = Client::new(String::new());
+        let mut pages = client.bans(Id::new(1)).pages();
+
+        pages.advance(0, None);
+        assert!(pages.after.is_none());
+        assert!(pages.finished);
+    }
+
+    #[tokio::test]
+    async fn test_pages() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (addr, queries) = serve_bans(5);
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("Bot token".to_owned())
+            .build();
+
+        let mut pages = client.bans(Id::new(1)).after(Id::new(1)).limit(2)?.pages();
+        let mut ids = Vec::new();
+
+        while let Some(page) = pages.next_page().await {
+            ids.push(
+                page?
+                    .iter()
+                    .map(|ban| ban.user.id.get())
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        assert_eq!(vec![vec![2, 3], vec![4, 5], Vec::new()], ids);
+        assert_eq!(
+            ["after=1&limit=2", "after=3&limit=2", "after=5&limit=2"].as_slice(),
+            queries.lock().unwrap().as_slice()
+        );
+        assert!(pages.next_page().await.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_pages_parsing() {
+        let addr = test_server::serve(|_| async {
+            Ok::<_, Infallible>(HyperResponse::new(Body::from("[{}]")))
+        });
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("Bot token".to_owned())
+            .build();
+
+        let mut pages = client.bans(Id::new(1)).pages();
+        let error = pages.next_page().await.unwrap().unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorType::Parsing { body } if body == b"[{}]"));
     }
 }
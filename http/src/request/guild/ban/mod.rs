@@ -1,6 +1,13 @@
 mod create_ban;
+mod create_bans;
 mod delete_ban;
 mod get_ban;
 mod get_bans;
 
-pub use self::{create_ban::CreateBan, delete_ban::DeleteBan, get_ban::GetBan, get_bans::GetBans};
+pub use self::{
+    create_ban::CreateBan,
+    create_bans::CreateBans,
+    delete_ban::DeleteBan,
+    get_ban::GetBan,
+    get_bans::{BanPages, GetBans},
+};
@@ -9,6 +9,7 @@ use serde::Serialize;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    time::Duration,
 };
 use twilight_model::{
     channel::ChannelType,
@@ -224,6 +225,7 @@ pub struct VoiceFields {
 pub struct CreateGuild<'a> {
     fields: CreateGuildFields<'a>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateGuild<'a> {
@@ -248,6 +250,7 @@ impl<'a> CreateGuild<'a> {
                 verification_level: None,
             },
             http,
+            timeout: None,
         })
     }
 
@@ -453,6 +456,17 @@ impl<'a> CreateGuild<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -472,6 +486,6 @@ impl TryIntoRequest for CreateGuild<'_> {
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
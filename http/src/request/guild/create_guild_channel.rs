@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::{permission_overwrite::PermissionOverwrite, Channel, ChannelType},
     id::{
@@ -54,6 +55,7 @@ pub struct CreateGuildChannel<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateGuildChannel<'a> {
@@ -80,6 +82,7 @@ impl<'a> CreateGuildChannel<'a> {
             guild_id,
             http,
             reason: None,
+            timeout: None,
         })
     }
 
@@ -190,6 +193,17 @@ impl<'a> CreateGuildChannel<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -227,6 +241,6 @@ impl TryIntoRequest for CreateGuildChannel<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     guild::GuildPrune,
     id::{
@@ -34,6 +35,7 @@ pub struct CreateGuildPrune<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateGuildPrune<'a> {
@@ -47,6 +49,7 @@ impl<'a> CreateGuildPrune<'a> {
             guild_id,
             http,
             reason: None,
+            timeout: None,
         }
     }
 
@@ -84,6 +87,17 @@ impl<'a> CreateGuildPrune<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -122,6 +136,6 @@ impl TryIntoRequest for CreateGuildPrune<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     guild::Emoji,
     id::{
@@ -36,6 +37,7 @@ pub struct CreateEmoji<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateEmoji<'a> {
@@ -54,6 +56,7 @@ impl<'a> CreateEmoji<'a> {
             guild_id,
             http,
             reason: None,
+            timeout: None,
         }
     }
 
@@ -68,6 +71,17 @@ impl<'a> CreateEmoji<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -105,6 +119,6 @@ impl TryIntoRequest for CreateEmoji<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     guild::Emoji,
     id::{
@@ -38,6 +39,7 @@ pub struct GetEmoji<'a> {
     emoji_id: Id<EmojiMarker>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetEmoji<'a> {
@@ -50,9 +52,21 @@ impl<'a> GetEmoji<'a> {
             emoji_id,
             guild_id,
             http,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -71,6 +85,7 @@ impl TryIntoRequest for GetEmoji<'_> {
         Ok(Request::from_route(&Route::GetEmoji {
             emoji_id: self.emoji_id.get(),
             guild_id: self.guild_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     guild::Emoji,
     id::{
@@ -31,6 +32,7 @@ pub struct UpdateEmoji<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateEmoji<'a> {
@@ -48,6 +50,7 @@ impl<'a> UpdateEmoji<'a> {
             guild_id,
             http,
             reason: None,
+            timeout: None,
         }
     }
 
@@ -65,6 +68,17 @@ impl<'a> UpdateEmoji<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -103,6 +117,6 @@ impl TryIntoRequest for UpdateEmoji<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::thread::ThreadsListing,
     id::{marker::GuildMarker, Id},
@@ -13,16 +14,34 @@ use twilight_model::{
 /// Returns all active threads in the guild.
 ///
 /// Includes public and private threads. Threads are ordered by their ID in
-/// descending order.
+/// descending order. The response also includes a thread member for each
+/// returned thread the current user has joined, which can be used to resync
+/// thread state after reconnecting to the gateway.
 #[must_use = "requests must be configured and executed"]
 pub struct GetActiveThreads<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetActiveThreads<'a> {
     pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>) -> Self {
-        Self { guild_id, http }
+        Self {
+            guild_id,
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -42,6 +61,75 @@ impl TryIntoRequest for GetActiveThreads<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         Ok(Request::from_route(&Route::GetActiveThreads {
             guild_id: self.guild_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetActiveThreads;
+    use crate::{
+        client::Client,
+        request::{Method, TryIntoRequest},
+        response::Response,
+    };
+    use hyper::{Body, Response as HyperResponse};
+    use std::error::Error;
+    use twilight_model::{channel::thread::ThreadsListing, id::Id};
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = GetActiveThreads::new(&client, Id::new(1)).try_into_request()?;
+
+        assert_eq!(Method::Get, request.method());
+        assert_eq!("guilds/1/threads/active", request.path());
+        assert!(request.body().is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_response() -> Result<(), Box<dyn Error + Send + Sync>> {
+        const BODY: &str = r#"{
+            "members": [{
+                "flags": 1,
+                "id": "3",
+                "join_timestamp": "2021-09-19T14:17:32.000000+00:00",
+                "user_id": "4"
+            }],
+            "threads": [{
+                "guild_id": "1",
+                "id": "3",
+                "name": "thread",
+                "parent_id": "2",
+                "type": 11
+            }, {
+                "guild_id": "1",
+                "id": "5",
+                "name": "private thread",
+                "parent_id": "2",
+                "type": 12
+            }]
+        }"#;
+
+        let response = Response::<ThreadsListing>::new(HyperResponse::new(Body::from(BODY)));
+        let listing = response.model().await?;
+
+        assert_eq!(None, listing.has_more);
+        assert_eq!(
+            [Id::new(3), Id::new(5)].as_slice(),
+            listing
+                .threads
+                .iter()
+                .map(|thread| thread.id)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(1, listing.members.len());
+        assert_eq!(Some(Id::new(3)), listing.members[0].id);
+        assert_eq!(Some(Id::new(4)), listing.members[0].user_id);
+
+        Ok(())
     }
 }
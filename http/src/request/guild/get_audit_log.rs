@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     guild::audit_log::{AuditLog, AuditLogEventType},
     id::{
@@ -59,6 +60,7 @@ pub struct GetAuditLog<'a> {
     fields: GetAuditLogFields,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetAuditLog<'a> {
@@ -72,6 +74,7 @@ impl<'a> GetAuditLog<'a> {
             },
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -118,6 +121,17 @@ impl<'a> GetAuditLog<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -139,6 +153,7 @@ impl TryIntoRequest for GetAuditLog<'_> {
             guild_id: self.guild_id.get(),
             limit: self.fields.limit,
             user_id: self.fields.user_id.map(Id::get),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     guild::Guild,
     id::{marker::GuildMarker, Id},
@@ -20,6 +21,7 @@ pub struct GetGuild<'a> {
     fields: GetGuildFields,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetGuild<'a> {
@@ -28,6 +30,7 @@ impl<'a> GetGuild<'a> {
             fields: GetGuildFields { with_counts: false },
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -39,6 +42,17 @@ impl<'a> GetGuild<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -57,6 +71,7 @@ impl TryIntoRequest for GetGuild<'_> {
         Ok(Request::from_route(&Route::GetGuild {
             guild_id: self.guild_id.get(),
             with_counts: self.fields.with_counts,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
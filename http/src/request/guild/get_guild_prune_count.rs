@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     guild::GuildPrune,
     id::{
@@ -25,6 +26,7 @@ pub struct GetGuildPruneCount<'a> {
     fields: GetGuildPruneCountFields<'a>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetGuildPruneCount<'a> {
@@ -36,6 +38,7 @@ impl<'a> GetGuildPruneCount<'a> {
             },
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -67,6 +70,17 @@ impl<'a> GetGuildPruneCount<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -86,7 +100,8 @@ impl TryIntoRequest for GetGuildPruneCount<'_> {
             days: self.fields.days,
             guild_id: self.guild_id.get(),
             include_roles: self.fields.include_roles,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
 
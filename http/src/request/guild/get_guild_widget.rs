@@ -5,25 +5,48 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     guild::GuildWidget,
     id::{marker::GuildMarker, Id},
 };
 
-/// Get the guild widget.
+/// Get a guild's public widget, including its instant invite, voice channels,
+/// and online members.
+///
+/// The guild's widget must be enabled.
+///
+/// Use [`widget_image_url`] to get the URL of the widget's image instead.
 ///
 /// See [Discord Docs/Get Guild Widget].
 ///
 /// [Discord Docs/Get Guild Widget]: https://discord.com/developers/docs/resources/guild#get-guild-widget
+/// [`widget_image_url`]: super::widget_image_url
 #[must_use = "requests must be configured and executed"]
 pub struct GetGuildWidget<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetGuildWidget<'a> {
     pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>) -> Self {
-        Self { guild_id, http }
+        Self {
+            guild_id,
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -43,6 +66,7 @@ impl TryIntoRequest for GetGuildWidget<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         Ok(Request::from_route(&Route::GetGuildWidget {
             guild_id: self.guild_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
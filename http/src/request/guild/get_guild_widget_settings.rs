@@ -0,0 +1,66 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use std::time::Duration;
+use twilight_model::{
+    guild::GuildWidgetSettings,
+    id::{marker::GuildMarker, Id},
+};
+
+/// Get the settings of a guild's widget.
+///
+/// See [Discord Docs/Get Guild Widget Settings].
+///
+/// [Discord Docs/Get Guild Widget Settings]: https://discord.com/developers/docs/resources/guild#get-guild-widget-settings
+#[must_use = "requests must be configured and executed"]
+pub struct GetGuildWidgetSettings<'a> {
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+    timeout: Option<Duration>,
+}
+
+impl<'a> GetGuildWidgetSettings<'a> {
+    pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self {
+            guild_id,
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<GuildWidgetSettings> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetGuildWidgetSettings<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(&Route::GetGuildWidgetSettings {
+            guild_id: self.guild_id.get(),
+        })
+        .with_timeout(self.timeout))
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     guild::PartialMember,
     id::{
@@ -33,6 +34,7 @@ pub struct AddGuildMember<'a> {
     fields: AddGuildMemberFields<'a>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     user_id: Id<UserMarker>,
 }
 
@@ -59,6 +61,7 @@ impl<'a> AddGuildMember<'a> {
             },
             guild_id,
             http,
+            timeout: None,
             user_id,
         }
     }
@@ -104,6 +107,17 @@ impl<'a> AddGuildMember<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -126,6 +140,6 @@ impl TryIntoRequest for AddGuildMember<'_> {
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{GuildMarker, RoleMarker, UserMarker},
     Id,
@@ -40,6 +41,7 @@ pub struct AddRoleToMember<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     role_id: Id<RoleMarker>,
+    timeout: Option<Duration>,
     user_id: Id<UserMarker>,
     reason: Option<&'a str>,
 }
@@ -55,11 +57,23 @@ impl<'a> AddRoleToMember<'a> {
             guild_id,
             http,
             role_id,
+            timeout: None,
             user_id,
             reason: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -97,6 +111,6 @@ impl TryIntoRequest for AddRoleToMember<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -1,3 +1,4 @@
+use super::GuildMemberStream;
 use crate::{
     client::Client,
     error::Error as HttpError,
@@ -5,6 +6,7 @@ use crate::{
     response::{marker::MemberListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{GuildMarker, UserMarker},
     Id,
@@ -13,6 +15,9 @@ use twilight_validate::request::{
     get_guild_members_limit as validate_get_guild_members_limit, ValidationError,
 };
 
+/// Maximum number of members Discord returns per request.
+const GUILD_MEMBERS_PAGE_SIZE: u16 = 1000;
+
 struct GetGuildMembersFields {
     after: Option<Id<UserMarker>>,
     limit: Option<u16>,
@@ -46,6 +51,7 @@ pub struct GetGuildMembers<'a> {
     fields: GetGuildMembersFields,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetGuildMembers<'a> {
@@ -58,6 +64,7 @@ impl<'a> GetGuildMembers<'a> {
             },
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -95,6 +102,62 @@ impl<'a> GetGuildMembers<'a> {
         self
     }
 
+    /// Fetch all of the guild's members, returning a stream of members that
+    /// requests the next page of members once the current page is exhausted.
+    ///
+    /// The page size is the configured [`limit`], defaulting to the maximum
+    /// of 1000. Pagination starts after the configured [`after`] user ID, and
+    /// the configured [`timeout`] applies to each page's request.
+    ///
+    /// # Examples
+    ///
+    /// Count the members of guild `100`:
+    ///
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let mut members = client.guild_members(Id::new(100)).paginate();
+    /// let mut count = 0;
+    ///
+    /// while let Some(_member) = members.try_next().await? {
+    ///     count += 1;
+    /// }
+    ///
+    /// println!("guild has {count} members");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`after`]: Self::after
+    /// [`limit`]: Self::limit
+    /// [`timeout`]: Self::timeout
+    pub fn paginate(self) -> GuildMemberStream<'a> {
+        GuildMemberStream::new(
+            self.http,
+            self.guild_id,
+            self.fields.after,
+            self.fields.limit.unwrap_or(GUILD_MEMBERS_PAGE_SIZE),
+            self.fields.presences,
+            self.timeout,
+        )
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -121,6 +184,7 @@ impl TryIntoRequest for GetGuildMembers<'_> {
             guild_id: self.guild_id.get(),
             limit: self.fields.limit,
             presences: self.fields.presences,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
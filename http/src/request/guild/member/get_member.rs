@@ -5,6 +5,7 @@ use crate::{
     response::{marker::MemberBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{GuildMarker, UserMarker},
     Id,
@@ -15,6 +16,7 @@ use twilight_model::id::{
 pub struct GetMember<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     user_id: Id<UserMarker>,
 }
 
@@ -27,10 +29,22 @@ impl<'a> GetMember<'a> {
         Self {
             guild_id,
             http,
+            timeout: None,
             user_id,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -55,6 +69,7 @@ impl TryIntoRequest for GetMember<'_> {
         Ok(Request::from_route(&Route::GetMember {
             guild_id: self.guild_id.get(),
             user_id: self.user_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -0,0 +1,304 @@
+use crate::{
+    client::Client,
+    error::{Error, ErrorType},
+    request::Request,
+    response::{
+        json_deserializer, marker::MemberListBody, BytesFuture, DeserializeBodyError,
+        ResponseFuture,
+    },
+    routing::Route,
+};
+use futures_core::Stream;
+use serde::de::DeserializeSeed;
+use std::{
+    error::Error as StdError,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+    vec::IntoIter,
+};
+use twilight_model::{
+    guild::member::{Member, MemberListDeserializer},
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Stage of fetching the current page of members.
+enum PageStage {
+    /// Reading the body of the page's response.
+    Body(BytesFuture),
+    /// Waiting for the page's response.
+    Request(ResponseFuture<MemberListBody>),
+}
+
+/// Stream of all the members of a guild, fetching them page by page.
+///
+/// Pages are requested one at a time, with each request after the first
+/// continuing after the last member of the previous page. The stream ends
+/// once a page has fewer members than the page size, including an empty
+/// page. As every page is sent as its own request, ratelimits are respected
+/// between pages.
+///
+/// After an error the stream ends.
+///
+/// Obtained via [`GetGuildMembers::paginate`].
+///
+/// [`GetGuildMembers::paginate`]: super::GetGuildMembers::paginate
+#[must_use = "streams do nothing unless polled"]
+pub struct GuildMemberStream<'a> {
+    /// User ID of the last member received, used as the next page's cursor.
+    after: Option<Id<UserMarker>>,
+    /// Whether the last page has been received or an error occurred.
+    done: bool,
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+    /// Members of the current page that have not yet been yielded.
+    members: IntoIter<Member>,
+    /// Number of members requested per page.
+    page_size: u16,
+    presences: Option<bool>,
+    /// Stage of fetching the current page, if one is being fetched.
+    stage: Option<PageStage>,
+    /// Timeout of each page's request, if it overrides the client's timeout.
+    timeout: Option<Duration>,
+}
+
+impl<'a> GuildMemberStream<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: Id<GuildMarker>,
+        after: Option<Id<UserMarker>>,
+        page_size: u16,
+        presences: Option<bool>,
+        timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            after,
+            done: false,
+            guild_id,
+            http,
+            members: Vec::new().into_iter(),
+            page_size,
+            presences,
+            stage: None,
+            timeout,
+        }
+    }
+
+    /// Request the page of members after the current cursor.
+    fn request_page(&self) -> ResponseFuture<MemberListBody> {
+        self.http.request(
+            Request::from_route(&Route::GetGuildMembers {
+                after: self.after.map(Id::get),
+                guild_id: self.guild_id.get(),
+                limit: Some(self.page_size),
+                presences: self.presences,
+            })
+            .with_timeout(self.timeout),
+        )
+    }
+}
+
+impl Stream for GuildMemberStream<'_> {
+    type Item = Result<Member, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(member) = self.members.next() {
+                return Poll::Ready(Some(Ok(member)));
+            }
+
+            let stage = match self.stage.take() {
+                Some(stage) => stage,
+                None if self.done => return Poll::Ready(None),
+                None => PageStage::Request(self.request_page()),
+            };
+
+            match stage {
+                PageStage::Body(mut future) => match Pin::new(&mut future).poll(cx) {
+                    Poll::Ready(Ok(mut bytes)) => {
+                        let members = match deserialize_page(self.guild_id, &mut bytes) {
+                            Ok(members) => members,
+                            Err(source) => {
+                                self.done = true;
+
+                                return Poll::Ready(Some(Err(Error {
+                                    kind: ErrorType::Parsing { body: bytes },
+                                    source,
+                                })));
+                            }
+                        };
+
+                        self.done = members.len() < usize::from(self.page_size);
+
+                        if let Some(member) = members.last() {
+                            self.after = Some(member.user.id);
+                        }
+
+                        self.members = members.into_iter();
+                    }
+                    Poll::Ready(Err(source)) => {
+                        self.done = true;
+
+                        return Poll::Ready(Some(Err(Error {
+                            kind: ErrorType::ChunkingResponse,
+                            source: Some(Box::new(source)),
+                        })));
+                    }
+                    Poll::Pending => {
+                        self.stage = Some(PageStage::Body(future));
+
+                        return Poll::Pending;
+                    }
+                },
+                PageStage::Request(mut future) => match Pin::new(&mut future).poll(cx) {
+                    Poll::Ready(Ok(response)) => {
+                        self.stage = Some(PageStage::Body(response.bytes()));
+                    }
+                    Poll::Ready(Err(source)) => {
+                        self.done = true;
+
+                        return Poll::Ready(Some(Err(source)));
+                    }
+                    Poll::Pending => {
+                        self.stage = Some(PageStage::Request(future));
+
+                        return Poll::Pending;
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Deserialize the body of a page into its members.
+fn deserialize_page(
+    guild_id: Id<GuildMarker>,
+    bytes: &mut [u8],
+) -> Result<Vec<Member>, Option<Box<dyn StdError + Send + Sync>>> {
+    let mut deserializer = json_deserializer(bytes).map_err(DeserializeBodyError::into_source)?;
+
+    MemberListDeserializer::new(guild_id)
+        .deserialize(&mut deserializer)
+        .map_err(|source| Some(Box::new(source) as Box<dyn StdError + Send + Sync>))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildMemberStream;
+    use crate::{client::Client, test_server};
+    use futures_util::StreamExt;
+    use hyper::{Body, Request as HyperRequest, Response as HyperResponse};
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::Infallible,
+        error::Error,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::id::Id;
+
+    assert_impl_all!(GuildMemberStream<'_>: Send, Unpin);
+
+    /// Spawn a server responding with the members of a guild with the given
+    /// number of members, recording the `after` cursor of each request.
+    fn serve_members(total: u64) -> (SocketAddr, Arc<Mutex<Vec<Option<u64>>>>) {
+        let cursors = Arc::new(Mutex::new(Vec::new()));
+        let server_cursors = Arc::clone(&cursors);
+
+        let addr = test_server::serve(move |request: HyperRequest<Body>| {
+            let mut after = None;
+            let mut limit = 1;
+
+            for (key, value) in request
+                .uri()
+                .query()
+                .unwrap_or_default()
+                .split('&')
+                .filter_map(|pair| pair.split_once('='))
+            {
+                match key {
+                    "after" => after = value.parse().ok(),
+                    "limit" => limit = value.parse().unwrap(),
+                    _ => {}
+                }
+            }
+
+            server_cursors.lock().unwrap().push(after);
+
+            let members = (after.unwrap_or(0) + 1..=total)
+                .take(limit)
+                .map(|id| {
+                    format!(
+                        r#"{{"deaf":false,"joined_at":"2021-01-01T00:00:00.000000+00:00","mute":false,"roles":[],"user":{{"avatar":null,"discriminator":"0001","id":"{id}","username":"user"}}}}"#
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+
+            async move { Ok::<_, Infallible>(HyperResponse::new(Body::from(format!("[{members}]")))) }
+        });
+
+        (addr, cursors)
+    }
+
+    #[tokio::test]
+    async fn test_partial_last_page() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (addr, cursors) = serve_members(5);
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("Bot token".to_owned())
+            .build();
+
+        let members = client
+            .guild_members(Id::new(1))
+            .limit(2)?
+            .paginate()
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let ids = members
+            .iter()
+            .map(|member| member.user.id.get())
+            .collect::<Vec<_>>();
+        assert_eq!([1, 2, 3, 4, 5].as_slice(), ids);
+        assert!(members.iter().all(|member| member.guild_id == Id::new(1)));
+        assert_eq!(
+            [None, Some(2), Some(4)].as_slice(),
+            cursors.lock().unwrap().as_slice()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_empty_last_page() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (addr, cursors) = serve_members(4);
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("Bot token".to_owned())
+            .build();
+
+        let mut stream = client.guild_members(Id::new(1)).limit(2)?.paginate();
+        let mut count = 0;
+
+        while let Some(member) = stream.next().await {
+            member?;
+            count += 1;
+        }
+
+        assert_eq!(4, count);
+        assert_eq!(
+            [None, Some(2), Some(4)].as_slice(),
+            cursors.lock().unwrap().as_slice()
+        );
+        assert!(stream.next().await.is_none());
+
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::MemberListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{marker::GuildMarker, Id};
 use twilight_validate::request::{
     search_guild_members_limit as validate_search_guild_members_limit, ValidationError,
@@ -50,6 +51,7 @@ pub struct SearchGuildMembers<'a> {
     fields: SearchGuildMembersFields<'a>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> SearchGuildMembers<'a> {
@@ -58,6 +60,7 @@ impl<'a> SearchGuildMembers<'a> {
             fields: SearchGuildMembersFields { query, limit: None },
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -81,6 +84,17 @@ impl<'a> SearchGuildMembers<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -106,6 +120,51 @@ impl TryIntoRequest for SearchGuildMembers<'_> {
             guild_id: self.guild_id.get(),
             limit: self.fields.limit,
             query: self.fields.query,
-        }))
+        })
+        .with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::id::{marker::GuildMarker, Id};
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client
+            .search_guild_members(GUILD_ID, "wump us")
+            .limit(500)?
+            .try_into_request()?;
+
+        assert!(request.body().is_none());
+        assert_eq!(Method::Get, request.method());
+        assert_eq!(
+            "guilds/1/members/search?query=wump%20us&limit=500",
+            request.path()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_limit() {
+        let client = Client::new(String::new());
+
+        assert!(client.search_guild_members(GUILD_ID, "a").limit(0).is_err());
+        assert!(client.search_guild_members(GUILD_ID, "a").limit(1).is_ok());
+        assert!(client
+            .search_guild_members(GUILD_ID, "a")
+            .limit(1000)
+            .is_ok());
+        assert!(client
+            .search_guild_members(GUILD_ID, "a")
+            .limit(1001)
+            .is_err());
     }
 }
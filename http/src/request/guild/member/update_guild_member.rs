@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     id::{
         marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
@@ -46,6 +47,7 @@ pub struct UpdateGuildMember<'a> {
     fields: UpdateGuildMemberFields<'a>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     user_id: Id<UserMarker>,
     reason: Option<&'a str>,
 }
@@ -67,6 +69,7 @@ impl<'a> UpdateGuildMember<'a> {
             },
             guild_id,
             http,
+            timeout: None,
             user_id,
             reason: None,
         }
@@ -149,6 +152,17 @@ impl<'a> UpdateGuildMember<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -190,7 +204,7 @@ impl TryIntoRequest for UpdateGuildMember<'_> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
 
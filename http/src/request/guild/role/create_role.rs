@@ -6,11 +6,14 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     guild::{Permissions, Role},
     id::{marker::GuildMarker, Id},
 };
-use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+use twilight_validate::request::{
+    audit_reason as validate_audit_reason, role_icon as validate_role_icon, ValidationError,
+};
 
 #[derive(Serialize)]
 struct CreateRoleFields<'a> {
@@ -19,7 +22,7 @@ struct CreateRoleFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     hoist: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    icon: Option<&'a [u8]>,
+    icon: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     mentionable: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -56,6 +59,7 @@ pub struct CreateRole<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateRole<'a> {
@@ -73,6 +77,7 @@ impl<'a> CreateRole<'a> {
             guild_id,
             http,
             reason: None,
+            timeout: None,
         }
     }
 
@@ -94,13 +99,25 @@ impl<'a> CreateRole<'a> {
     ///
     /// Only works if the guild has the `ROLE_ICONS` feature.
     ///
-    /// See [Discord Docs/Image Data].
+    /// The icon must be a Data URI, in the form of
+    /// `data:image/{type};base64,{data}` where `{type}` is the image MIME type
+    /// and `{data}` is the base64-encoded image. See [Discord Docs/Image Data].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`RoleIcon`] if the unicode emoji of the role
+    /// has already been set.
     ///
+    /// [`RoleIcon`]: twilight_validate::request::ValidationErrorType::RoleIcon
     /// [Discord Docs/Image Data]: https://discord.com/developers/docs/reference#image-data
-    pub const fn icon(mut self, icon: &'a [u8]) -> Self {
+    pub const fn icon(mut self, icon: &'a str) -> Result<Self, ValidationError> {
+        if let Err(source) = validate_role_icon(Some(icon), self.fields.unicode_emoji) {
+            return Err(source);
+        }
+
         self.fields.icon = Some(icon);
 
-        self
+        Ok(self)
     }
 
     /// If true, the role can be @mentioned (pinged) in chat.
@@ -127,9 +144,33 @@ impl<'a> CreateRole<'a> {
     }
 
     /// Set the unicode emoji of a role.
-    pub const fn unicode_emoji(mut self, unicode_emoji: &'a str) -> Self {
+    ///
+    /// Only works if the guild has the `ROLE_ICONS` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`RoleIcon`] if the icon of the role has
+    /// already been set.
+    ///
+    /// [`RoleIcon`]: twilight_validate::request::ValidationErrorType::RoleIcon
+    pub const fn unicode_emoji(mut self, unicode_emoji: &'a str) -> Result<Self, ValidationError> {
+        if let Err(source) = validate_role_icon(self.fields.icon, Some(unicode_emoji)) {
+            return Err(source);
+        }
+
         self.fields.unicode_emoji = Some(unicode_emoji);
 
+        Ok(self)
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
         self
     }
 
@@ -170,6 +211,60 @@ impl TryIntoRequest for CreateRole<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::id::{marker::GuildMarker, Id};
+    use twilight_validate::request::ValidationErrorType;
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+    const ICON: &str = "data:image/png;base64,aWNvbg==";
+
+    #[test]
+    fn test_icon() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client
+            .create_role(GUILD_ID)
+            .name("cats")
+            .icon(ICON)?
+            .try_into_request()?;
+
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("guilds/1/roles", request.path());
+        assert_eq!(
+            Some(br#"{"icon":"data:image/png;base64,aWNvbg==","name":"cats"}"#.as_slice()),
+            request.body()
+        );
+
+        let request = client
+            .create_role(GUILD_ID)
+            .unicode_emoji("🐈")?
+            .try_into_request()?;
+
+        assert_eq!(Some(r#"{"unicode_emoji":"🐈"}"#.as_bytes()), request.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_icon_unicode_emoji_exclusive() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        assert!(matches!(
+            client.create_role(GUILD_ID).icon(ICON)?.unicode_emoji("🐈"),
+            Err(error) if matches!(error.kind(), ValidationErrorType::RoleIcon)
+        ));
+        assert!(matches!(
+            client.create_role(GUILD_ID).unicode_emoji("🐈")?.icon(ICON),
+            Err(error) if matches!(error.kind(), ValidationErrorType::RoleIcon)
+        ));
+
+        Ok(())
     }
 }
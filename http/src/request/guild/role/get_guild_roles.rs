@@ -5,21 +5,56 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     guild::Role,
     id::{marker::GuildMarker, Id},
 };
 
 /// Get the roles of a guild.
+///
+/// # Examples
+///
+/// ```no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let roles = client.roles(Id::new(1)).exec().await?.models().await?;
+///
+/// for role in roles {
+///     println!("{}: {}", role.id, role.name);
+/// }
+/// # Ok(()) }
+/// ```
 #[must_use = "requests must be configured and executed"]
 pub struct GetGuildRoles<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetGuildRoles<'a> {
     pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>) -> Self {
-        Self { guild_id, http }
+        Self {
+            guild_id,
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -39,6 +74,27 @@ impl TryIntoRequest for GetGuildRoles<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         Ok(Request::from_route(&Route::GetGuildRoles {
             guild_id: self.guild_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client.roles(Id::new(1)).try_into_request()?;
+
+        assert_eq!(Method::Get, request.method());
+        assert_eq!("guilds/1/roles", request.path());
+        assert!(request.body().is_none());
+
+        Ok(())
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     guild::{Permissions, Role},
     id::{
@@ -13,7 +14,9 @@ use twilight_model::{
         Id,
     },
 };
-use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+use twilight_validate::request::{
+    audit_reason as validate_audit_reason, role_icon as validate_role_icon, ValidationError,
+};
 
 #[derive(Serialize)]
 struct UpdateRoleFields<'a> {
@@ -22,7 +25,7 @@ struct UpdateRoleFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     hoist: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    icon: Option<&'a [u8]>,
+    icon: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     mentionable: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -41,6 +44,7 @@ pub struct UpdateRole<'a> {
     http: &'a Client,
     role_id: Id<RoleMarker>,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateRole<'a> {
@@ -63,6 +67,7 @@ impl<'a> UpdateRole<'a> {
             http,
             role_id,
             reason: None,
+            timeout: None,
         }
     }
 
@@ -84,13 +89,25 @@ impl<'a> UpdateRole<'a> {
     ///
     /// Only works if the guild has the `ROLE_ICONS` feature.
     ///
-    /// See [Discord Docs/Image Data].
+    /// The icon must be a Data URI, in the form of
+    /// `data:image/{type};base64,{data}` where `{type}` is the image MIME type
+    /// and `{data}` is the base64-encoded image. See [Discord Docs/Image Data].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`RoleIcon`] if the unicode emoji of the role
+    /// has already been set.
     ///
+    /// [`RoleIcon`]: twilight_validate::request::ValidationErrorType::RoleIcon
     /// [Discord Docs/Image Data]: https://discord.com/developers/docs/reference#image-data
-    pub const fn icon(mut self, icon: &'a [u8]) -> Self {
+    pub const fn icon(mut self, icon: &'a str) -> Result<Self, ValidationError> {
+        if let Err(source) = validate_role_icon(Some(icon), self.fields.unicode_emoji) {
+            return Err(source);
+        }
+
         self.fields.icon = Some(icon);
 
-        self
+        Ok(self)
     }
 
     /// If true, the role can be @mentioned (pinged) in chat.
@@ -115,9 +132,33 @@ impl<'a> UpdateRole<'a> {
     }
 
     /// Set the unicode emoji of a role.
-    pub const fn unicode_emoji(mut self, unicode_emoji: &'a str) -> Self {
+    ///
+    /// Only works if the guild has the `ROLE_ICONS` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`RoleIcon`] if the icon of the role has
+    /// already been set.
+    ///
+    /// [`RoleIcon`]: twilight_validate::request::ValidationErrorType::RoleIcon
+    pub const fn unicode_emoji(mut self, unicode_emoji: &'a str) -> Result<Self, ValidationError> {
+        if let Err(source) = validate_role_icon(self.fields.icon, Some(unicode_emoji)) {
+            return Err(source);
+        }
+
         self.fields.unicode_emoji = Some(unicode_emoji);
 
+        Ok(self)
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
         self
     }
 
@@ -159,6 +200,43 @@ impl TryIntoRequest for UpdateRole<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::id::Id;
+    use twilight_validate::request::ValidationErrorType;
+
+    const ICON: &str = "data:image/png;base64,aWNvbg==";
+
+    #[test]
+    fn test_icon() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client
+            .update_role(Id::new(1), Id::new(2))
+            .icon(ICON)?
+            .try_into_request()?;
+
+        assert_eq!(Method::Patch, request.method());
+        assert_eq!("guilds/1/roles/2", request.path());
+        assert_eq!(
+            Some(br#"{"icon":"data:image/png;base64,aWNvbg=="}"#.as_slice()),
+            request.body()
+        );
+
+        assert!(matches!(
+            client
+                .update_role(Id::new(1), Id::new(2))
+                .icon(ICON)?
+                .unicode_emoji("🐈"),
+            Err(error) if matches!(error.kind(), ValidationErrorType::RoleIcon)
+        ));
+
+        Ok(())
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     guild::Role,
     id::{
@@ -21,6 +22,7 @@ pub struct UpdateRolePositions<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     roles: &'a [(Id<RoleMarker>, u64)],
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateRolePositions<'a> {
@@ -33,9 +35,21 @@ impl<'a> UpdateRolePositions<'a> {
             guild_id,
             http,
             roles,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -57,6 +71,6 @@ impl TryIntoRequest for UpdateRolePositions<'_> {
 
         request = request.json(&self.roles)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
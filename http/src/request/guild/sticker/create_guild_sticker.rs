@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::message::Sticker,
     id::{marker::GuildMarker, Id},
@@ -53,11 +54,13 @@ struct CreateGuildStickerFields<'a> {
 /// println!("{sticker:#?}");
 /// # Ok(()) }
 /// ```
+#[must_use = "requests must be configured and executed"]
 pub struct CreateGuildSticker<'a> {
     fields: CreateGuildStickerFields<'a>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateGuildSticker<'a> {
@@ -85,9 +88,21 @@ impl<'a> CreateGuildSticker<'a> {
             guild_id,
             http,
             reason: None,
+            timeout: None,
         })
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -125,6 +140,6 @@ impl TryIntoRequest for CreateGuildSticker<'_> {
 
         request = request.form(form);
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
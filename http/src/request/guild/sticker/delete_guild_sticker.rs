@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{
     marker::{GuildMarker, StickerMarker},
     Id,
@@ -31,10 +32,12 @@ use twilight_model::id::{
 ///     .await?;
 /// # Ok(()) }
 /// ```
+#[must_use = "requests must be configured and executed"]
 pub struct DeleteGuildSticker<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     sticker_id: Id<StickerMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteGuildSticker<'a> {
@@ -47,9 +50,21 @@ impl<'a> DeleteGuildSticker<'a> {
             guild_id,
             http,
             sticker_id,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -68,6 +83,7 @@ impl TryIntoRequest for DeleteGuildSticker<'_> {
         Ok(Request::from_route(&Route::DeleteGuildSticker {
             guild_id: self.guild_id.get(),
             sticker_id: self.sticker_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::message::sticker::Sticker,
     id::{
@@ -37,10 +38,12 @@ use twilight_model::{
 /// println!("{sticker:#?}");
 /// # Ok(()) }
 /// ```
+#[must_use = "requests must be configured and executed"]
 pub struct GetGuildSticker<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     sticker_id: Id<StickerMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetGuildSticker<'a> {
@@ -53,9 +56,21 @@ impl<'a> GetGuildSticker<'a> {
             guild_id,
             http,
             sticker_id,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -74,6 +89,7 @@ impl TryIntoRequest for GetGuildSticker<'_> {
         Ok(Request::from_route(&Route::GetGuildSticker {
             guild_id: self.guild_id.get(),
             sticker_id: self.sticker_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::message::sticker::Sticker,
     id::{
@@ -53,12 +54,14 @@ struct UpdateGuildStickerFields<'a> {
 /// println!("{sticker:#?}");
 /// # Ok(()) }
 /// ```
+#[must_use = "requests must be configured and executed"]
 pub struct UpdateGuildSticker<'a> {
     fields: UpdateGuildStickerFields<'a>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     reason: Option<&'a str>,
     sticker_id: Id<StickerMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateGuildSticker<'a> {
@@ -77,6 +80,7 @@ impl<'a> UpdateGuildSticker<'a> {
             http,
             reason: None,
             sticker_id,
+            timeout: None,
         }
     }
 
@@ -104,6 +108,17 @@ impl<'a> UpdateGuildSticker<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -135,6 +150,6 @@ impl TryIntoRequest for UpdateGuildSticker<'_> {
         })
         .json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
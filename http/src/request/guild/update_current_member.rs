@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::id::{marker::GuildMarker, Id};
 use twilight_validate::request::{
     audit_reason as validate_audit_reason, nickname as validate_nickname, ValidationError,
@@ -24,6 +25,7 @@ pub struct UpdateCurrentMember<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateCurrentMember<'a> {
@@ -33,6 +35,7 @@ impl<'a> UpdateCurrentMember<'a> {
             guild_id,
             http,
             reason: None,
+            timeout: None,
         }
     }
 
@@ -58,6 +61,17 @@ impl<'a> UpdateCurrentMember<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -95,6 +109,6 @@ impl TryIntoRequest for UpdateCurrentMember<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
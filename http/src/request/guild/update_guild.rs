@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     guild::{
         DefaultMessageNotificationLevel, ExplicitContentFilter, PartialGuild, SystemChannelFlags,
@@ -71,6 +72,7 @@ pub struct UpdateGuild<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateGuild<'a> {
@@ -99,6 +101,7 @@ impl<'a> UpdateGuild<'a> {
             guild_id,
             http,
             reason: None,
+            timeout: None,
         }
     }
 
@@ -296,6 +299,17 @@ impl<'a> UpdateGuild<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -333,6 +347,67 @@ impl TryIntoRequest for UpdateGuild<'_> {
             request = request.headers(header);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::{
+        guild::{DefaultMessageNotificationLevel, ExplicitContentFilter, SystemChannelFlags},
+        id::Id,
+    };
+
+    #[test]
+    fn test_explicit_content_filter() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client
+            .update_guild(Id::new(1))
+            .explicit_content_filter(Some(ExplicitContentFilter::AllMembers))
+            .try_into_request()?;
+
+        assert_eq!(Method::Patch, request.method());
+        assert_eq!("guilds/1", request.path());
+        assert_eq!(
+            Some(br#"{"explicit_content_filter":2}"#.as_slice()),
+            request.body()
+        );
+
+        let request = client
+            .update_guild(Id::new(1))
+            .explicit_content_filter(None)
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(br#"{"explicit_content_filter":null}"#.as_slice()),
+            request.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_settings() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client
+            .update_guild(Id::new(1))
+            .afk_channel_id(Some(Id::new(2)))
+            .afk_timeout(300)
+            .default_message_notifications(Some(DefaultMessageNotificationLevel::Mentions))
+            .system_channel_flags(Some(SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS))
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(
+                br#"{"afk_channel_id":"2","afk_timeout":300,"default_message_notifications":1,"system_channel_flags":1}"#
+                    .as_slice()
+            ),
+            request.body()
+        );
+
+        Ok(())
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker},
     Id,
@@ -44,6 +45,7 @@ pub struct UpdateGuildChannelPositions<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     positions: &'a [Position],
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateGuildChannelPositions<'a> {
@@ -56,9 +58,21 @@ impl<'a> UpdateGuildChannelPositions<'a> {
             guild_id,
             http,
             positions: channel_positions,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -80,6 +94,6 @@ impl TryIntoRequest for UpdateGuildChannelPositions<'_> {
 
         request = request.json(&self.positions)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
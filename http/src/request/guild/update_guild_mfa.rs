@@ -0,0 +1,119 @@
+use crate::{
+    client::Client,
+    error::Error as HttpError,
+    request::{self, AuditLogReason, Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use serde::Serialize;
+use std::time::Duration;
+use twilight_model::{
+    guild::{GuildMfa, MfaLevel},
+    id::{marker::GuildMarker, Id},
+};
+use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+
+#[derive(Serialize)]
+struct UpdateGuildMfaFields {
+    level: MfaLevel,
+}
+
+/// Update the MFA level required for moderation actions in a guild.
+///
+/// The current user must be the owner of the guild. See
+/// [Discord Docs/Modify Guild MFA Level].
+///
+/// [Discord Docs/Modify Guild MFA Level]: https://discord.com/developers/docs/resources/guild#modify-guild-mfa-level
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateGuildMfa<'a> {
+    fields: UpdateGuildMfaFields,
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+    reason: Option<&'a str>,
+    timeout: Option<Duration>,
+}
+
+impl<'a> UpdateGuildMfa<'a> {
+    pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>, level: MfaLevel) -> Self {
+        Self {
+            fields: UpdateGuildMfaFields { level },
+            guild_id,
+            http,
+            reason: None,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<GuildMfa> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl<'a> AuditLogReason<'a> for UpdateGuildMfa<'a> {
+    fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
+        validate_audit_reason(reason)?;
+
+        self.reason.replace(reason);
+
+        Ok(self)
+    }
+}
+
+impl TryIntoRequest for UpdateGuildMfa<'_> {
+    fn try_into_request(self) -> Result<Request, HttpError> {
+        let mut request = Request::builder(&Route::UpdateGuildMfa {
+            guild_id: self.guild_id.get(),
+        });
+
+        request = request.json(&self.fields)?;
+
+        if let Some(reason) = &self.reason {
+            let header = request::audit_header(reason)?;
+
+            request = request.headers(header);
+        }
+
+        Ok(request.build().with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::{guild::MfaLevel, id::Id};
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client
+            .update_guild_mfa(Id::new(1), MfaLevel::Elevated)
+            .try_into_request()?;
+
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("guilds/1/mfa", request.path());
+        assert_eq!(Some(br#"{"level":1}"#.as_slice()), request.body());
+
+        Ok(())
+    }
+}
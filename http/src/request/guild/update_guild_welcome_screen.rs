@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     id::{marker::GuildMarker, Id},
     invite::{WelcomeScreen, WelcomeScreenChannel},
@@ -31,6 +32,7 @@ pub struct UpdateGuildWelcomeScreen<'a> {
     fields: UpdateGuildWelcomeScreenFields<'a>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateGuildWelcomeScreen<'a> {
@@ -43,6 +45,7 @@ impl<'a> UpdateGuildWelcomeScreen<'a> {
             },
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -67,6 +70,17 @@ impl<'a> UpdateGuildWelcomeScreen<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -88,6 +102,6 @@ impl TryIntoRequest for UpdateGuildWelcomeScreen<'_> {
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
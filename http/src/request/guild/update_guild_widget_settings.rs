@@ -6,8 +6,9 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
-    guild::GuildWidget,
+    guild::GuildWidgetSettings,
     id::{
         marker::{ChannelMarker, GuildMarker},
         Id,
@@ -15,30 +16,40 @@ use twilight_model::{
 };
 
 #[derive(Serialize)]
-struct UpdateGuildWidgetFields {
+struct UpdateGuildWidgetSettingsFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     channel_id: Option<NullableField<Id<ChannelMarker>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     enabled: Option<bool>,
 }
 
-/// Modify the guild widget.
+/// Update the settings of a guild's widget.
+#[deprecated(since = "0.11.1", note = "use `UpdateGuildWidgetSettings` instead")]
+pub type UpdateGuildWidget<'a> = UpdateGuildWidgetSettings<'a>;
+
+/// Update the settings of a guild's widget.
+///
+/// See [Discord Docs/Modify Guild Widget].
+///
+/// [Discord Docs/Modify Guild Widget]: https://discord.com/developers/docs/resources/guild#modify-guild-widget
 #[must_use = "requests must be configured and executed"]
-pub struct UpdateGuildWidget<'a> {
-    fields: UpdateGuildWidgetFields,
+pub struct UpdateGuildWidgetSettings<'a> {
+    fields: UpdateGuildWidgetSettingsFields,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
-impl<'a> UpdateGuildWidget<'a> {
+impl<'a> UpdateGuildWidgetSettings<'a> {
     pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>) -> Self {
         Self {
-            fields: UpdateGuildWidgetFields {
+            fields: UpdateGuildWidgetSettingsFields {
                 channel_id: None,
                 enabled: None,
             },
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -56,10 +67,21 @@ impl<'a> UpdateGuildWidget<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
-    pub fn exec(self) -> ResponseFuture<GuildWidget> {
+    pub fn exec(self) -> ResponseFuture<GuildWidgetSettings> {
         let http = self.http;
 
         match self.try_into_request() {
@@ -69,14 +91,14 @@ impl<'a> UpdateGuildWidget<'a> {
     }
 }
 
-impl TryIntoRequest for UpdateGuildWidget<'_> {
+impl TryIntoRequest for UpdateGuildWidgetSettings<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        let mut request = Request::builder(&Route::UpdateGuildWidget {
+        let mut request = Request::builder(&Route::UpdateGuildWidgetSettings {
             guild_id: self.guild_id.get(),
         });
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker},
     Id,
@@ -26,6 +27,7 @@ pub struct UpdateCurrentUserVoiceState<'a> {
     fields: UpdateCurrentUserVoiceStateFields<'a>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateCurrentUserVoiceState<'a> {
@@ -42,6 +44,7 @@ impl<'a> UpdateCurrentUserVoiceState<'a> {
             },
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -76,6 +79,17 @@ impl<'a> UpdateCurrentUserVoiceState<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -97,6 +111,6 @@ impl TryIntoRequest for UpdateCurrentUserVoiceState<'_> {
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
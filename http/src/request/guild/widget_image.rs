@@ -0,0 +1,123 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+/// Style of a guild's widget image.
+///
+/// See [Discord Docs/Widget Style Options].
+///
+/// [Discord Docs/Widget Style Options]: https://discord.com/developers/docs/resources/guild#get-guild-widget-image-widget-style-options
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum GuildWidgetStyle {
+    /// Large image with the guild icon, name, and online count, and a
+    /// "Powered by Discord" footer.
+    Banner1,
+    /// Smaller image with the guild icon, name, and online count.
+    Banner2,
+    /// Large image with the guild icon, name, and online count, and a
+    /// "Chat Now" button.
+    Banner3,
+    /// Large image with a "Join My Server" header and a "Join" button.
+    Banner4,
+    /// Shield image with the Discord icon and online count.
+    ///
+    /// This is the default style.
+    Shield,
+}
+
+impl GuildWidgetStyle {
+    /// Name of the style as used in the `style` query parameter.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Banner1 => "banner1",
+            Self::Banner2 => "banner2",
+            Self::Banner3 => "banner3",
+            Self::Banner4 => "banner4",
+            Self::Shield => "shield",
+        }
+    }
+}
+
+impl Default for GuildWidgetStyle {
+    fn default() -> Self {
+        Self::Shield
+    }
+}
+
+impl Display for GuildWidgetStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+/// URL of a guild's widget PNG image.
+///
+/// The image is public and can be used directly in, for example, an embed.
+/// The guild's widget must be enabled.
+///
+/// See [Discord Docs/Get Guild Widget Image].
+///
+/// # Examples
+///
+/// ```
+/// use twilight_http::request::guild::{widget_image_url, GuildWidgetStyle};
+/// use twilight_model::id::Id;
+///
+/// let url = widget_image_url(Id::new(1), GuildWidgetStyle::Banner2);
+///
+/// assert_eq!(
+///     "https://discord.com/api/guilds/1/widget.png?style=banner2",
+///     url,
+/// );
+/// ```
+///
+/// [Discord Docs/Get Guild Widget Image]: https://discord.com/developers/docs/resources/guild#get-guild-widget-image
+pub fn widget_image_url(guild_id: Id<GuildMarker>, style: GuildWidgetStyle) -> String {
+    format!("https://discord.com/api/guilds/{guild_id}/widget.png?style={style}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{widget_image_url, GuildWidgetStyle};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(
+        GuildWidgetStyle: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_widget_image_url() {
+        let guild_id = Id::new(123);
+
+        assert_eq!(
+            "https://discord.com/api/guilds/123/widget.png?style=shield",
+            widget_image_url(guild_id, GuildWidgetStyle::default())
+        );
+        assert_eq!(
+            "https://discord.com/api/guilds/123/widget.png?style=banner1",
+            widget_image_url(guild_id, GuildWidgetStyle::Banner1)
+        );
+        assert_eq!(
+            "https://discord.com/api/guilds/123/widget.png?style=banner2",
+            widget_image_url(guild_id, GuildWidgetStyle::Banner2)
+        );
+        assert_eq!(
+            "https://discord.com/api/guilds/123/widget.png?style=banner3",
+            widget_image_url(guild_id, GuildWidgetStyle::Banner3)
+        );
+        assert_eq!(
+            "https://discord.com/api/guilds/123/widget.png?style=banner4",
+            widget_image_url(guild_id, GuildWidgetStyle::Banner4)
+        );
+    }
+}
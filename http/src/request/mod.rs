@@ -2,6 +2,7 @@ pub mod application;
 pub mod attachment;
 pub mod channel;
 pub mod guild;
+pub mod oauth;
 pub mod scheduled_event;
 pub mod sticker;
 pub mod template;
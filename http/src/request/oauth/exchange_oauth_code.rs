@@ -0,0 +1,114 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use std::time::Duration;
+use twilight_model::{
+    id::{marker::ApplicationMarker, Id},
+    oauth::AccessToken,
+};
+
+/// Exchange an authorization code, received by the redirect URI after a user
+/// authorized the application, for an access token.
+///
+/// The redirect URI must be the same one the user was redirected to.
+#[must_use = "requests must be configured and executed"]
+pub struct ExchangeOauthCode<'a> {
+    application_id: Id<ApplicationMarker>,
+    client_secret: &'a str,
+    code: &'a str,
+    http: &'a Client,
+    redirect_uri: &'a str,
+    timeout: Option<Duration>,
+}
+
+impl<'a> ExchangeOauthCode<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+        code: &'a str,
+        redirect_uri: &'a str,
+    ) -> Self {
+        Self {
+            application_id,
+            client_secret,
+            code,
+            http,
+            redirect_uri,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<AccessToken> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for ExchangeOauthCode<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(super::form_request(
+            &Route::CreateOauthToken,
+            &[
+                ("client_id", &self.application_id.to_string()),
+                ("client_secret", self.client_secret),
+                ("code", self.code),
+                ("grant_type", "authorization_code"),
+                ("redirect_uri", self.redirect_uri),
+            ],
+        )
+        .with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client
+            .oauth(Id::new(1), "secret")
+            .exchange_code("code", "https://a.b/c")
+            .try_into_request()?;
+
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("oauth2/token", request.path());
+        assert_eq!(
+            Some(
+                "client_id=1&client_secret=secret&code=code&grant_type=authorization_code&redirect_uri=https%3A%2F%2Fa.b%2Fc"
+                    .as_bytes()
+            ),
+            request.body()
+        );
+        assert!(!request.use_authorization_token());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+//! Requests to manage `OAuth2` access tokens on behalf of users.
+//!
+//! These requests authenticate with the application's client ID and secret
+//! rather than the client's bot token, and are created through the
+//! [`OauthClient`].
+//!
+//! [`OauthClient`]: crate::client::OauthClient
+
+mod exchange_oauth_code;
+mod refresh_oauth_token;
+mod revoke_oauth_token;
+
+pub use self::{
+    exchange_oauth_code::ExchangeOauthCode, refresh_oauth_token::RefreshOauthToken,
+    revoke_oauth_token::RevokeOauthToken,
+};
+
+use crate::{request::Request, routing::Route};
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::iter;
+
+/// Characters to percent encode in form names and values, leaving only
+/// unreserved characters as they are.
+const FORM: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Create a request to an `OAuth2` route with a URL encoded form body.
+///
+/// The client's authorization token is never sent, as the application
+/// authenticates via the client ID and secret in the form instead.
+fn form_request(route: &Route<'_>, fields: &[(&str, &str)]) -> Request {
+    let mut body = String::new();
+
+    for (name, value) in fields {
+        if !body.is_empty() {
+            body.push('&');
+        }
+
+        body.extend(utf8_percent_encode(name, FORM));
+        body.push('=');
+        body.extend(utf8_percent_encode(value, FORM));
+    }
+
+    let content_type = HeaderValue::from_static("application/x-www-form-urlencoded");
+
+    Request::builder(route)
+        .body(body.into_bytes())
+        .headers(iter::once((CONTENT_TYPE, content_type)))
+        .use_authorization_token(false)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::form_request;
+    use crate::routing::Route;
+    use hyper::header::CONTENT_TYPE;
+
+    #[test]
+    fn test_form_request() {
+        let request = form_request(
+            &Route::CreateOauthToken,
+            &[
+                ("grant_type", "authorization_code"),
+                ("redirect_uri", "https://a.b/c?d"),
+            ],
+        );
+
+        assert_eq!(
+            Some("grant_type=authorization_code&redirect_uri=https%3A%2F%2Fa.b%2Fc%3Fd".as_bytes()),
+            request.body()
+        );
+        assert_eq!(
+            Some("application/x-www-form-urlencoded"),
+            request
+                .headers()
+                .and_then(|headers| headers.get(CONTENT_TYPE))
+                .and_then(|value| value.to_str().ok())
+        );
+        assert!(!request.use_authorization_token());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use std::time::Duration;
+use twilight_model::{
+    id::{marker::ApplicationMarker, Id},
+    oauth::AccessToken,
+};
+
+/// Refresh an access token that is about to expire, or has expired, by its
+/// refresh token.
+///
+/// The refresh token of the returned access token replaces the one used in
+/// this request.
+#[must_use = "requests must be configured and executed"]
+pub struct RefreshOauthToken<'a> {
+    application_id: Id<ApplicationMarker>,
+    client_secret: &'a str,
+    http: &'a Client,
+    refresh_token: &'a str,
+    timeout: Option<Duration>,
+}
+
+impl<'a> RefreshOauthToken<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+        refresh_token: &'a str,
+    ) -> Self {
+        Self {
+            application_id,
+            client_secret,
+            http,
+            refresh_token,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<AccessToken> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for RefreshOauthToken<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(super::form_request(
+            &Route::CreateOauthToken,
+            &[
+                ("client_id", &self.application_id.to_string()),
+                ("client_secret", self.client_secret),
+                ("grant_type", "refresh_token"),
+                ("refresh_token", self.refresh_token),
+            ],
+        )
+        .with_timeout(self.timeout))
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::EmptyBody, ResponseFuture},
+    routing::Route,
+};
+use std::time::Duration;
+use twilight_model::id::{marker::ApplicationMarker, Id};
+
+/// Revoke an access token or refresh token, invalidating both the access
+/// token and its refresh token.
+#[must_use = "requests must be configured and executed"]
+pub struct RevokeOauthToken<'a> {
+    application_id: Id<ApplicationMarker>,
+    client_secret: &'a str,
+    http: &'a Client,
+    timeout: Option<Duration>,
+    token: &'a str,
+}
+
+impl<'a> RevokeOauthToken<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+        token: &'a str,
+    ) -> Self {
+        Self {
+            application_id,
+            client_secret,
+            http,
+            timeout: None,
+            token,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<EmptyBody> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for RevokeOauthToken<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(super::form_request(
+            &Route::RevokeOauthToken,
+            &[
+                ("client_id", &self.application_id.to_string()),
+                ("client_secret", self.client_secret),
+                ("token", self.token),
+            ],
+        )
+        .with_timeout(self.timeout))
+    }
+}
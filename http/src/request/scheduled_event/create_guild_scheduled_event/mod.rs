@@ -11,11 +11,12 @@ use super::EntityMetadataFields;
 use crate::{
     client::Client,
     error::Error,
-    request::{AuditLogReason, Request, RequestBuilder, TryIntoRequest},
+    request::{AuditLogReason, Request, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     id::{
         marker::{ChannelMarker, GuildMarker},
@@ -120,6 +121,7 @@ pub struct CreateGuildScheduledEvent<'a> {
     http: &'a Client,
     fields: CreateGuildScheduledEventFields<'a>,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateGuildScheduledEvent<'a> {
@@ -139,6 +141,7 @@ impl<'a> CreateGuildScheduledEvent<'a> {
                 scheduled_start_time: None,
             },
             reason: None,
+            timeout: None,
         }
     }
 
@@ -245,6 +248,6 @@ impl TryIntoRequest for CreateGuildScheduledEvent<'_> {
             guild_id: self.guild_id.get(),
         })
         .json(&self.fields)
-        .map(RequestBuilder::build)
+        .map(|request| request.build().with_timeout(self.timeout))
     }
 }
@@ -4,6 +4,7 @@ use crate::{
     request::{AuditLogReason, Request, TryIntoRequest},
     response::ResponseFuture,
 };
+use std::time::Duration;
 use twilight_model::{
     id::{marker::ChannelMarker, Id},
     scheduled_event::{EntityType, GuildScheduledEvent},
@@ -77,6 +78,17 @@ impl<'a> CreateGuildStageInstanceScheduledEvent<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.0.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     id::{
         marker::{GuildMarker, ScheduledEventMarker},
@@ -37,6 +38,7 @@ pub struct DeleteGuildScheduledEvent<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     scheduled_event_id: Id<ScheduledEventMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteGuildScheduledEvent<'a> {
@@ -49,9 +51,21 @@ impl<'a> DeleteGuildScheduledEvent<'a> {
             guild_id,
             http,
             scheduled_event_id,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -70,6 +84,7 @@ impl TryIntoRequest for DeleteGuildScheduledEvent<'_> {
         Ok(Request::from_route(&Route::DeleteGuildScheduledEvent {
             guild_id: self.guild_id.get(),
             scheduled_event_id: self.scheduled_event_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
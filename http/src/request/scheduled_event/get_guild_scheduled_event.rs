@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     id::{
         marker::{GuildMarker, ScheduledEventMarker},
@@ -19,6 +20,7 @@ pub struct GetGuildScheduledEvent<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     scheduled_event_id: Id<ScheduledEventMarker>,
+    timeout: Option<Duration>,
     with_user_count: bool,
 }
 
@@ -32,6 +34,7 @@ impl<'a> GetGuildScheduledEvent<'a> {
             guild_id,
             http,
             scheduled_event_id,
+            timeout: None,
             with_user_count: false,
         }
     }
@@ -43,6 +46,17 @@ impl<'a> GetGuildScheduledEvent<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -62,6 +76,7 @@ impl TryIntoRequest for GetGuildScheduledEvent<'_> {
             guild_id: self.guild_id.get(),
             scheduled_event_id: self.scheduled_event_id.get(),
             with_user_count: self.with_user_count,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
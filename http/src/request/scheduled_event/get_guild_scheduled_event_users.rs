@@ -5,6 +5,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     id::{
         marker::{GuildMarker, ScheduledEventMarker, UserMarker},
@@ -35,6 +36,7 @@ pub struct GetGuildScheduledEventUsers<'a> {
     http: &'a Client,
     limit: Option<u16>,
     scheduled_event_id: Id<ScheduledEventMarker>,
+    timeout: Option<Duration>,
     with_member: Option<bool>,
 }
 
@@ -51,6 +53,7 @@ impl<'a> GetGuildScheduledEventUsers<'a> {
             http,
             limit: None,
             scheduled_event_id,
+            timeout: None,
             with_member: None,
         }
     }
@@ -105,6 +108,17 @@ impl<'a> GetGuildScheduledEventUsers<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -127,6 +141,7 @@ impl TryIntoRequest for GetGuildScheduledEventUsers<'_> {
             limit: self.limit,
             scheduled_event_id: self.scheduled_event_id.get(),
             with_member: self.with_member.unwrap_or_default(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
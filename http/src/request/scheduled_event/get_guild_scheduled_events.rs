@@ -5,6 +5,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     id::{marker::GuildMarker, Id},
     scheduled_event::GuildScheduledEvent,
@@ -15,6 +16,7 @@ use twilight_model::{
 pub struct GetGuildScheduledEvents<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
     with_user_count: bool,
 }
 
@@ -23,6 +25,7 @@ impl<'a> GetGuildScheduledEvents<'a> {
         Self {
             guild_id,
             http,
+            timeout: None,
             with_user_count: false,
         }
     }
@@ -34,6 +37,17 @@ impl<'a> GetGuildScheduledEvents<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -52,6 +66,7 @@ impl TryIntoRequest for GetGuildScheduledEvents<'_> {
         Ok(Request::from_route(&Route::GetGuildScheduledEvents {
             guild_id: self.guild_id.get(),
             with_user_count: self.with_user_count,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
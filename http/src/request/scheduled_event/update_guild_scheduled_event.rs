@@ -2,11 +2,12 @@ use super::EntityMetadataFields;
 use crate::{
     client::Client,
     error::Error,
-    request::{AuditLogReason, NullableField, Request, RequestBuilder, TryIntoRequest},
+    request::{AuditLogReason, NullableField, Request, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     id::{
         marker::{ChannelMarker, GuildMarker, ScheduledEventMarker},
@@ -66,6 +67,7 @@ pub struct UpdateGuildScheduledEvent<'a> {
     fields: UpdateGuildScheduledEventFields<'a>,
     reason: Option<&'a str>,
     scheduled_event_id: Id<ScheduledEventMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateGuildScheduledEvent<'a> {
@@ -91,6 +93,7 @@ impl<'a> UpdateGuildScheduledEvent<'a> {
             },
             reason: None,
             scheduled_event_id,
+            timeout: None,
         }
     }
 
@@ -219,6 +222,17 @@ impl<'a> UpdateGuildScheduledEvent<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -249,6 +263,6 @@ impl TryIntoRequest for UpdateGuildScheduledEvent<'_> {
             scheduled_event_id: self.scheduled_event_id.get(),
         })
         .json(&self.fields)
-        .map(RequestBuilder::build)
+        .map(|request| request.build().with_timeout(self.timeout))
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Deserialize;
+use std::time::Duration;
 use twilight_model::channel::message::sticker::StickerPack;
 
 #[derive(Deserialize)]
@@ -32,11 +33,26 @@ pub struct StickerPackListing {
 #[must_use = "requests must be configured and executed"]
 pub struct GetNitroStickerPacks<'a> {
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetNitroStickerPacks<'a> {
     pub(crate) const fn new(http: &'a Client) -> Self {
-        Self { http }
+        Self {
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -54,6 +70,6 @@ impl<'a> GetNitroStickerPacks<'a> {
 
 impl TryIntoRequest for GetNitroStickerPacks<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        Ok(Request::from_route(&Route::GetNitroStickerPacks))
+        Ok(Request::from_route(&Route::GetNitroStickerPacks).with_timeout(self.timeout))
     }
 }
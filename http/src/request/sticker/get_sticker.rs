@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     channel::message::sticker::Sticker,
     id::{marker::StickerMarker, Id},
@@ -30,11 +31,27 @@ use twilight_model::{
 pub struct GetSticker<'a> {
     http: &'a Client,
     sticker_id: Id<StickerMarker>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetSticker<'a> {
     pub(crate) const fn new(http: &'a Client, sticker_id: Id<StickerMarker>) -> Self {
-        Self { http, sticker_id }
+        Self {
+            http,
+            sticker_id,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -54,6 +71,7 @@ impl TryIntoRequest for GetSticker<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         Ok(Request::from_route(&Route::GetSticker {
             sticker_id: self.sticker_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
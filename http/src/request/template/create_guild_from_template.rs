@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::guild::Guild;
 use twilight_validate::request::{guild_name as validate_guild_name, ValidationError};
 
@@ -30,6 +31,7 @@ pub struct CreateGuildFromTemplate<'a> {
     fields: CreateGuildFromTemplateFields<'a>,
     http: &'a Client,
     template_code: &'a str,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateGuildFromTemplate<'a> {
@@ -44,6 +46,7 @@ impl<'a> CreateGuildFromTemplate<'a> {
             fields: CreateGuildFromTemplateFields { name, icon: None },
             http,
             template_code,
+            timeout: None,
         })
     }
 
@@ -60,6 +63,17 @@ impl<'a> CreateGuildFromTemplate<'a> {
         self
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -81,6 +95,6 @@ impl TryIntoRequest for CreateGuildFromTemplate<'_> {
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
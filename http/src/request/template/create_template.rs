@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     id::{marker::GuildMarker, Id},
     template::Template,
@@ -37,6 +38,7 @@ pub struct CreateTemplate<'a> {
     fields: CreateTemplateFields<'a>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateTemplate<'a> {
@@ -54,6 +56,7 @@ impl<'a> CreateTemplate<'a> {
             },
             guild_id,
             http,
+            timeout: None,
         })
     }
 
@@ -75,6 +78,17 @@ impl<'a> CreateTemplate<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -96,6 +110,6 @@ impl TryIntoRequest for CreateTemplate<'_> {
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
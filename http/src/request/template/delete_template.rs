@@ -5,6 +5,7 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::id::{marker::GuildMarker, Id};
 
 /// Delete a template by ID and code.
@@ -13,6 +14,7 @@ pub struct DeleteTemplate<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     template_code: &'a str,
+    timeout: Option<Duration>,
 }
 
 impl<'a> DeleteTemplate<'a> {
@@ -25,9 +27,21 @@ impl<'a> DeleteTemplate<'a> {
             guild_id,
             http,
             template_code,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -46,6 +60,7 @@ impl TryIntoRequest for DeleteTemplate<'_> {
         Ok(Request::from_route(&Route::DeleteTemplate {
             guild_id: self.guild_id.get(),
             template_code: self.template_code,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
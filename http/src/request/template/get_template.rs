@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::template::Template;
 
 /// Get a template by its code.
@@ -12,6 +13,7 @@ use twilight_model::template::Template;
 pub struct GetTemplate<'a> {
     http: &'a Client,
     template_code: &'a str,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetTemplate<'a> {
@@ -19,9 +21,21 @@ impl<'a> GetTemplate<'a> {
         Self {
             http,
             template_code,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -39,6 +53,7 @@ impl TryIntoRequest for GetTemplate<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         Ok(Request::from_route(&Route::GetTemplate {
             template_code: self.template_code,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     id::{marker::GuildMarker, Id},
     template::Template,
@@ -16,6 +17,7 @@ pub struct SyncTemplate<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     template_code: &'a str,
+    timeout: Option<Duration>,
 }
 
 impl<'a> SyncTemplate<'a> {
@@ -28,9 +30,21 @@ impl<'a> SyncTemplate<'a> {
             guild_id,
             http,
             template_code,
+            timeout: None,
         }
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -49,6 +63,7 @@ impl TryIntoRequest for SyncTemplate<'_> {
         Ok(Request::from_route(&Route::SyncTemplate {
             guild_id: self.guild_id.get(),
             template_code: self.template_code,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     id::{marker::GuildMarker, Id},
     template::Template,
@@ -28,6 +29,7 @@ pub struct UpdateTemplate<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     template_code: &'a str,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateTemplate<'a> {
@@ -44,6 +46,7 @@ impl<'a> UpdateTemplate<'a> {
             guild_id,
             http,
             template_code,
+            timeout: None,
         }
     }
 
@@ -83,6 +86,17 @@ impl<'a> UpdateTemplate<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -105,6 +119,6 @@ impl TryIntoRequest for UpdateTemplate<'_> {
 
         request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
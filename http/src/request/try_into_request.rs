@@ -42,12 +42,13 @@ mod private {
                 ExecuteWebhookAndWait, GetChannelWebhooks, GetWebhook, GetWebhookMessage,
                 UpdateWebhook, UpdateWebhookMessage, UpdateWebhookWithToken,
             },
-            CreatePin, CreateTypingTrigger, DeleteChannel, DeleteChannelPermission,
-            DeleteChannelPermissionConfigured, DeletePin, FollowNewsChannel, GetChannel, GetPins,
-            UpdateChannel, UpdateChannelPermission,
+            AddGroupRecipient, CreatePin, CreateTypingTrigger, DeleteChannel,
+            DeleteChannelPermission, DeleteChannelPermissionConfigured, DeletePin,
+            FollowNewsChannel, GetChannel, GetPins, RemoveGroupRecipient, UpdateChannel,
+            UpdateChannelPermission,
         },
         guild::{
-            ban::{CreateBan, DeleteBan, GetBan, GetBans},
+            ban::{CreateBan, CreateBans, DeleteBan, GetBan, GetBans},
             emoji::{CreateEmoji, DeleteEmoji, GetEmoji, GetEmojis, UpdateEmoji},
             integration::{DeleteGuildIntegration, GetGuildIntegrations},
             member::{
@@ -63,9 +64,11 @@ mod private {
             CreateGuild, CreateGuildChannel, CreateGuildPrune, DeleteGuild, GetActiveThreads,
             GetAuditLog, GetGuild, GetGuildChannels, GetGuildInvites, GetGuildPreview,
             GetGuildPruneCount, GetGuildVanityUrl, GetGuildVoiceRegions, GetGuildWebhooks,
-            GetGuildWelcomeScreen, GetGuildWidget, UpdateCurrentMember, UpdateGuild,
-            UpdateGuildChannelPositions, UpdateGuildWelcomeScreen, UpdateGuildWidget,
+            GetGuildWelcomeScreen, GetGuildWidget, GetGuildWidgetSettings, UpdateCurrentMember,
+            UpdateGuild, UpdateGuildChannelPositions, UpdateGuildMfa, UpdateGuildWelcomeScreen,
+            UpdateGuildWidgetSettings,
         },
+        oauth::{ExchangeOauthCode, RefreshOauthToken, RevokeOauthToken},
         scheduled_event::{
             CreateGuildExternalScheduledEvent, CreateGuildScheduledEvent,
             CreateGuildStageInstanceScheduledEvent, CreateGuildVoiceScheduledEvent,
@@ -78,7 +81,7 @@ mod private {
             SyncTemplate, UpdateTemplate,
         },
         user::{
-            CreatePrivateChannel, GetCurrentUser, GetCurrentUserConnections,
+            CreateGroupDm, CreatePrivateChannel, GetCurrentUser, GetCurrentUserConnections,
             GetCurrentUserGuildMember, GetCurrentUserGuilds, GetUser, LeaveGuild,
             UpdateCurrentUser,
         },
@@ -87,16 +90,19 @@ mod private {
 
     pub trait Sealed {}
 
+    impl Sealed for AddGroupRecipient<'_> {}
     impl Sealed for AddGuildMember<'_> {}
     impl Sealed for AddRoleToMember<'_> {}
     impl Sealed for AddThreadMember<'_> {}
     impl Sealed for CreateBan<'_> {}
+    impl Sealed for CreateBans<'_> {}
     impl Sealed for CreateEmoji<'_> {}
     impl Sealed for CreateFollowup<'_> {}
     impl Sealed for CreateGlobalChatInputCommand<'_> {}
     impl Sealed for CreateGlobalCommand<'_> {}
     impl Sealed for CreateGlobalMessageCommand<'_> {}
     impl Sealed for CreateGlobalUserCommand<'_> {}
+    impl Sealed for CreateGroupDm<'_> {}
     impl Sealed for CreateGuild<'_> {}
     impl Sealed for CreateGuildChannel<'_> {}
     impl Sealed for CreateGuildChatInputCommand<'_> {}
@@ -149,6 +155,7 @@ mod private {
     impl Sealed for DeleteTemplate<'_> {}
     impl Sealed for DeleteWebhook<'_> {}
     impl Sealed for DeleteWebhookMessage<'_> {}
+    impl Sealed for ExchangeOauthCode<'_> {}
     impl Sealed for ExecuteWebhook<'_> {}
     impl Sealed for ExecuteWebhookAndWait<'_> {}
     impl Sealed for FollowNewsChannel<'_> {}
@@ -194,6 +201,7 @@ mod private {
     impl Sealed for GetGuildWebhooks<'_> {}
     impl Sealed for GetGuildWelcomeScreen<'_> {}
     impl Sealed for GetGuildWidget<'_> {}
+    impl Sealed for GetGuildWidgetSettings<'_> {}
     impl Sealed for GetInvite<'_> {}
     impl Sealed for GetJoinedPrivateArchivedThreads<'_> {}
     impl Sealed for GetMember<'_> {}
@@ -218,9 +226,12 @@ mod private {
     impl Sealed for JoinThread<'_> {}
     impl Sealed for LeaveGuild<'_> {}
     impl Sealed for LeaveThread<'_> {}
+    impl Sealed for RefreshOauthToken<'_> {}
+    impl Sealed for RemoveGroupRecipient<'_> {}
     impl Sealed for RemoveMember<'_> {}
     impl Sealed for RemoveRoleFromMember<'_> {}
     impl Sealed for RemoveThreadMember<'_> {}
+    impl Sealed for RevokeOauthToken<'_> {}
     impl Sealed for SearchGuildMembers<'_> {}
     impl Sealed for SetGlobalCommands<'_> {}
     impl Sealed for SetGuildCommands<'_> {}
@@ -235,13 +246,14 @@ mod private {
     impl Sealed for UpdateFollowup<'_> {}
     impl Sealed for UpdateGlobalCommand<'_> {}
     impl Sealed for UpdateGuild<'_> {}
+    impl Sealed for UpdateGuildMfa<'_> {}
     impl Sealed for UpdateGuildChannelPositions<'_> {}
     impl Sealed for UpdateGuildCommand<'_> {}
     impl Sealed for UpdateGuildMember<'_> {}
     impl Sealed for UpdateGuildScheduledEvent<'_> {}
     impl Sealed for UpdateGuildSticker<'_> {}
     impl Sealed for UpdateGuildWelcomeScreen<'_> {}
-    impl Sealed for UpdateGuildWidget<'_> {}
+    impl Sealed for UpdateGuildWidgetSettings<'_> {}
     impl Sealed for UpdateMessage<'_> {}
     impl Sealed for UpdateResponse<'_> {}
     impl Sealed for UpdateRole<'_> {}
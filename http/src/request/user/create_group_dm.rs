@@ -0,0 +1,122 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use serde::Serialize;
+use std::{collections::HashMap, time::Duration};
+use twilight_model::{
+    channel::Channel,
+    id::{marker::UserMarker, Id},
+};
+
+#[derive(Serialize)]
+struct CreateGroupDmFields<'a> {
+    access_tokens: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nicks: Option<&'a HashMap<Id<UserMarker>, String>>,
+}
+
+/// Create a group DM with multiple users.
+///
+/// This requires the `OAuth2` access tokens of the users, who must have
+/// authorized the application with the `gdm.join` scope. Refer to
+/// [Discord Docs/Create Group DM].
+///
+/// This endpoint is limited to 10 active group DMs.
+///
+/// [Discord Docs/Create Group DM]: https://discord.com/developers/docs/resources/user#create-group-dm
+#[must_use = "requests must be configured and executed"]
+pub struct CreateGroupDm<'a> {
+    fields: CreateGroupDmFields<'a>,
+    http: &'a Client,
+    timeout: Option<Duration>,
+}
+
+impl<'a> CreateGroupDm<'a> {
+    pub(crate) const fn new(http: &'a Client, access_tokens: &'a [&'a str]) -> Self {
+        Self {
+            fields: CreateGroupDmFields {
+                access_tokens,
+                nicks: None,
+            },
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the nicknames of users in the group DM, keyed by user ID.
+    pub const fn nicks(mut self, nicks: &'a HashMap<Id<UserMarker>, String>) -> Self {
+        self.fields.nicks = Some(nicks);
+
+        self
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<Channel> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for CreateGroupDm<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let request = Request::builder(&Route::CreateGroupDm);
+
+        let request = request.json(&self.fields)?;
+
+        Ok(request.build().with_timeout(self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CreateGroupDm;
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::{collections::HashMap, error::Error};
+    use twilight_model::id::Id;
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let nicks = HashMap::from([(Id::new(1), "nick".to_owned())]);
+
+        let request = CreateGroupDm::new(&client, &["a", "b"])
+            .nicks(&nicks)
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(br#"{"access_tokens":["a","b"],"nicks":{"1":"nick"}}"#.as_slice()),
+            request.body()
+        );
+        assert_eq!("users/@me/channels", request.path());
+
+        let request = CreateGroupDm::new(&client, &["a"]).try_into_request()?;
+
+        assert_eq!(
+            Some(br#"{"access_tokens":["a"]}"#.as_slice()),
+            request.body()
+        );
+
+        Ok(())
+    }
+}
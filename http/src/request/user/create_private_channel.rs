@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::{
     channel::Channel,
     id::{marker::UserMarker, Id},
@@ -23,6 +24,7 @@ struct CreatePrivateChannelFields {
 pub struct CreatePrivateChannel<'a> {
     fields: CreatePrivateChannelFields,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreatePrivateChannel<'a> {
@@ -30,8 +32,21 @@ impl<'a> CreatePrivateChannel<'a> {
         Self {
             fields: CreatePrivateChannelFields { recipient_id },
             http,
+            timeout: None,
         }
     }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     pub fn exec(self) -> ResponseFuture<Channel> {
         let http = self.http;
 
@@ -48,6 +63,6 @@ impl TryIntoRequest for CreatePrivateChannel<'_> {
 
         let request = request.json(&self.fields)?;
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
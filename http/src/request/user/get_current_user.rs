@@ -5,17 +5,33 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::user::CurrentUser;
 
 /// Get information about the current user.
 #[must_use = "requests must be configured and executed"]
 pub struct GetCurrentUser<'a> {
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetCurrentUser<'a> {
     pub(crate) const fn new(http: &'a Client) -> Self {
-        Self { http }
+        Self {
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -33,6 +49,6 @@ impl<'a> GetCurrentUser<'a> {
 
 impl TryIntoRequest for GetCurrentUser<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        Ok(Request::from_route(&Route::GetCurrentUser))
+        Ok(Request::from_route(&Route::GetCurrentUser).with_timeout(self.timeout))
     }
 }
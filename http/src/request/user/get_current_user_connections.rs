@@ -5,6 +5,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::user::Connection;
 
 /// Get the current user's connections.
@@ -13,11 +14,26 @@ use twilight_model::user::Connection;
 #[must_use = "requests must be configured and executed"]
 pub struct GetCurrentUserConnections<'a> {
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetCurrentUserConnections<'a> {
     pub(crate) const fn new(http: &'a Client) -> Self {
-        Self { http }
+        Self {
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -35,6 +51,6 @@ impl<'a> GetCurrentUserConnections<'a> {
 
 impl TryIntoRequest for GetCurrentUserConnections<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        Ok(Request::from_route(&Route::GetUserConnections))
+        Ok(Request::from_route(&Route::GetUserConnections).with_timeout(self.timeout))
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     routing::Route,
     Error,
 };
+use std::time::Duration;
 use twilight_model::id::{marker::GuildMarker, Id};
 
 /// Get information about the current user in a guild.
@@ -12,11 +13,27 @@ use twilight_model::id::{marker::GuildMarker, Id};
 pub struct GetCurrentUserGuildMember<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetCurrentUserGuildMember<'a> {
     pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>) -> Self {
-        Self { guild_id, http }
+        Self {
+            guild_id,
+            http,
+            timeout: None,
+        }
+    }
+
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
     }
 
     pub fn exec(self) -> ResponseFuture<MemberBody> {
@@ -39,6 +56,7 @@ impl TryIntoRequest for GetCurrentUserGuildMember<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         Ok(Request::from_route(&Route::GetCurrentUserGuildMember {
             guild_id: self.guild_id.get(),
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
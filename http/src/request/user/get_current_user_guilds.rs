@@ -5,6 +5,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use std::time::Duration;
 use twilight_model::{
     id::{marker::GuildMarker, Id},
     user::CurrentUserGuild,
@@ -48,6 +49,7 @@ struct GetCurrentUserGuildsFields {
 pub struct GetCurrentUserGuilds<'a> {
     fields: GetCurrentUserGuildsFields,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetCurrentUserGuilds<'a> {
@@ -59,6 +61,7 @@ impl<'a> GetCurrentUserGuilds<'a> {
                 limit: None,
             },
             http,
+            timeout: None,
         }
     }
 
@@ -98,6 +101,17 @@ impl<'a> GetCurrentUserGuilds<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -117,6 +131,7 @@ impl TryIntoRequest for GetCurrentUserGuilds<'_> {
             after: self.fields.after.map(Id::get),
             before: self.fields.before.map(Id::get),
             limit: self.fields.limit,
-        }))
+        })
+        .with_timeout(self.timeout))
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::time::Duration;
 use twilight_model::user::User;
 use twilight_validate::request::{
     audit_reason as validate_audit_reason, username as validate_username, ValidationError,
@@ -28,6 +29,7 @@ pub struct UpdateCurrentUser<'a> {
     fields: UpdateCurrentUserFields<'a>,
     http: &'a Client,
     reason: Option<&'a str>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> UpdateCurrentUser<'a> {
@@ -39,6 +41,7 @@ impl<'a> UpdateCurrentUser<'a> {
             },
             http,
             reason: None,
+            timeout: None,
         }
     }
 
@@ -73,6 +76,17 @@ impl<'a> UpdateCurrentUser<'a> {
         Ok(self)
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    ///
+    /// Refer to [`RequestBuilder::timeout`] for more information.
+    ///
+    /// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -106,6 +120,6 @@ impl TryIntoRequest for UpdateCurrentUser<'_> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        Ok(request.build())
+        Ok(request.build().with_timeout(self.timeout))
     }
 }
@@ -1,9 +1,16 @@
 use super::{Response, StatusCode};
 use crate::{
     api_error::ApiError,
+    client::etag::{CachedResponse, ConditionalRequest},
     error::{Error, ErrorType},
 };
-use hyper::{client::ResponseFuture as HyperResponseFuture, StatusCode as HyperStatusCode};
+use hyper::{
+    body::Bytes,
+    client::ResponseFuture as HyperResponseFuture,
+    header::{HeaderValue, ETAG, RETRY_AFTER},
+    http::response::Parts,
+    Body, Response as HyperResponse, StatusCode as HyperStatusCode,
+};
 use std::{
     future::Future,
     marker::PhantomData,
@@ -16,7 +23,7 @@ use std::{
     task::{Context, Poll},
     time::Duration,
 };
-use tokio::time::{self, Timeout};
+use tokio::time::{self, Sleep, Timeout};
 use twilight_http_ratelimiting::{ticket::TicketSender, RatelimitHeaders, WaitForTicketFuture};
 use twilight_model::id::{marker::GuildMarker, Id};
 
@@ -62,6 +69,43 @@ impl Chunking {
     }
 }
 
+/// Reading the body of a response carrying an `ETag` to cache it.
+struct Caching {
+    future: Pin<Box<dyn Future<Output = Result<Bytes, hyper::Error>> + Send + Sync + 'static>>,
+    guild_id: Option<Id<GuildMarker>>,
+    parts: Parts,
+    request: ConditionalRequest,
+}
+
+impl Caching {
+    fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
+        let bytes = match Pin::new(&mut self.future).poll(cx) {
+            Poll::Ready(Ok(bytes)) => bytes,
+            Poll::Ready(Err(source)) => {
+                return InnerPoll::Ready(Err(Error {
+                    kind: ErrorType::ChunkingResponse,
+                    source: Some(Box::new(source)),
+                }))
+            }
+            Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::Caching(self)),
+        };
+
+        self.request.cache.insert(
+            self.request.key,
+            CachedResponse::new(
+                bytes.clone(),
+                self.parts.headers[ETAG].clone(),
+                self.parts.headers.clone(),
+                self.parts.status,
+            ),
+        );
+
+        let resp = HyperResponse::from_parts(self.parts, Body::from(bytes));
+
+        InnerPoll::Ready(Ok(response(resp, self.guild_id)))
+    }
+}
+
 struct Failed {
     source: Error,
 }
@@ -73,13 +117,16 @@ impl Failed {
 }
 
 struct InFlight {
+    conditional: Option<ConditionalRequest>,
     future: Pin<Box<Timeout<HyperResponseFuture>>>,
     guild_id: Option<Id<GuildMarker>>,
     invalid_token: Option<Arc<AtomicBool>>,
+    retry: Option<Retry>,
     tx: Option<TicketSender>,
 }
 
 impl InFlight {
+    #[allow(clippy::too_many_lines)]
     fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
         let resp = match Pin::new(&mut self.future).poll(cx) {
             Poll::Ready(Ok(Ok(resp))) => resp,
@@ -102,50 +149,89 @@ impl InFlight {
         // configured token is permanently invalid and future requests must be
         // ignored to avoid API bans.
         if resp.status() == HyperStatusCode::UNAUTHORIZED {
-            if let Some(invalid_token) = self.invalid_token {
+            if let Some(invalid_token) = &self.invalid_token {
                 invalid_token.store(true, Ordering::Relaxed);
             }
         }
 
-        if let Some(tx) = self.tx {
-            let headers = resp
-                .headers()
-                .iter()
-                .map(|(key, value)| (key.as_str(), value.as_bytes()));
-
-            match RatelimitHeaders::from_pairs(headers) {
-                Ok(v) => {
-                    let _res = tx.headers(Some(v));
-                }
-                Err(source) => {
-                    tracing::warn!("header parsing failed: {source:?}; {resp:?}");
+        let headers = resp
+            .headers()
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_bytes()));
 
-                    let _res = tx.headers(None);
-                }
+        let ratelimit_headers = match RatelimitHeaders::from_pairs(headers) {
+            Ok(headers) => Some(headers),
+            Err(source) => {
+                tracing::warn!("header parsing failed: {source:?}; {resp:?}");
+
+                None
             }
+        };
+
+        if let Some(tx) = self.tx {
+            let _res = tx.headers(ratelimit_headers.clone());
         }
 
         let status = resp.status();
 
-        if status.is_success() {
-            #[cfg(feature = "decompression")]
-            let mut resp = resp;
-            // Inaccurate since end-users can only access the decompressed body.
-            #[cfg(feature = "decompression")]
-            resp.headers_mut().remove(hyper::header::CONTENT_LENGTH);
+        if status == HyperStatusCode::NOT_MODIFIED {
+            if let Some(cached) = self
+                .conditional
+                .as_ref()
+                .and_then(|request| request.cached.as_ref())
+            {
+                return InnerPoll::Ready(Ok(response(cached.to_response(), self.guild_id)));
+            }
+        }
 
-            let mut response = Response::new(resp);
+        if status.is_success() && resp.headers().contains_key(ETAG) {
+            if let Some(request) = self.conditional.take() {
+                let (parts, body) = resp.into_parts();
 
-            if let Some(guild_id) = self.guild_id {
-                response.set_guild_id(guild_id);
+                return InnerPoll::Advance(ResponseFutureStage::Caching(Caching {
+                    future: Box::pin(hyper::body::to_bytes(body)),
+                    guild_id: self.guild_id,
+                    parts,
+                    request,
+                }));
             }
+        }
 
-            return InnerPoll::Ready(Ok(response));
+        if status.is_success() {
+            return InnerPoll::Ready(Ok(response(resp, self.guild_id)));
         }
 
         match status {
             HyperStatusCode::TOO_MANY_REQUESTS => {
-                tracing::warn!("429 response: {resp:?}")
+                tracing::warn!("429 response: {resp:?}");
+
+                if let Some(retry) = self.retry.filter(|retry| retry.remaining > 0) {
+                    let retry_after = RetryAfter::from_response(
+                        ratelimit_headers.as_ref(),
+                        resp.headers().get(RETRY_AFTER).map(HeaderValue::as_bytes),
+                    );
+
+                    if let Some(retry_after) = retry_after {
+                        tracing::debug!(
+                            "retrying request after {:?} ({} ratelimit), {} retries remaining",
+                            retry_after.duration,
+                            if retry_after.global {
+                                "global"
+                            } else {
+                                "bucket"
+                            },
+                            retry.remaining - 1,
+                        );
+
+                        return InnerPoll::Advance(ResponseFutureStage::Retrying(Retrying {
+                            conditional: self.conditional,
+                            guild_id: self.guild_id,
+                            invalid_token: self.invalid_token,
+                            retry,
+                            sleep: Box::pin(time::sleep(retry_after.duration)),
+                        }));
+                    }
+                }
             }
             HyperStatusCode::SERVICE_UNAVAILABLE => {
                 return InnerPoll::Ready(Err(Error {
@@ -174,11 +260,13 @@ impl InFlight {
 }
 
 struct RatelimitQueue {
+    conditional: Option<ConditionalRequest>,
     guild_id: Option<Id<GuildMarker>>,
     invalid_token: Option<Arc<AtomicBool>>,
     pre_flight_check: Option<Box<dyn FnOnce() -> bool + Send + 'static>>,
     request_timeout: Duration,
     response_future: HyperResponseFuture,
+    retry: Option<Retry>,
     wait_for_sender: WaitForTicketFuture,
 }
 
@@ -207,20 +295,167 @@ impl RatelimitQueue {
         }
 
         InnerPoll::Advance(ResponseFutureStage::InFlight(InFlight {
+            conditional: self.conditional,
             future: Box::pin(time::timeout(self.request_timeout, self.response_future)),
             guild_id: self.guild_id,
             invalid_token: self.invalid_token,
+            retry: self.retry,
             tx: Some(tx),
         }))
     }
 }
 
+/// Request that may be resent after receiving a 429 response.
+pub(crate) struct Retry {
+    /// Number of times the request may still be resent.
+    remaining: u8,
+    /// Send a copy of the request.
+    resend: Box<dyn Fn() -> HyperResponseFuture + Send + Sync>,
+    /// Timeout of each resent request.
+    timeout: Duration,
+    /// Wait for a ratelimiter ticket to resend the request with, if a
+    /// ratelimiter is configured.
+    wait_for_ticket: Option<Box<dyn Fn() -> WaitForTicketFuture + Send + Sync>>,
+}
+
+impl Retry {
+    pub(crate) fn new(
+        max_retries: u8,
+        timeout: Duration,
+        resend: Box<dyn Fn() -> HyperResponseFuture + Send + Sync>,
+        wait_for_ticket: Option<Box<dyn Fn() -> WaitForTicketFuture + Send + Sync>>,
+    ) -> Self {
+        Self {
+            remaining: max_retries,
+            resend,
+            timeout,
+            wait_for_ticket,
+        }
+    }
+}
+
+/// Maximum duration to wait before resending a request after a 429 response.
+const RETRY_AFTER_MAX: Duration = Duration::from_secs(60);
+
+/// Backoff requested by a 429 response.
+#[derive(Debug, PartialEq)]
+struct RetryAfter {
+    /// Duration to wait before resending the request.
+    duration: Duration,
+    /// Whether the ratelimit is global rather than for a bucket.
+    global: bool,
+}
+
+impl RetryAfter {
+    /// Determine the backoff of a 429 response from its parsed ratelimit
+    /// headers and raw `Retry-After` header.
+    ///
+    /// Global ratelimits use the duration parsed by the ratelimiting crate,
+    /// while bucket ratelimits use the `Retry-After` header, which may be
+    /// fractional. Durations are clamped to [`RETRY_AFTER_MAX`]. Returns
+    /// `None` if the response doesn't specify a valid duration, in which case
+    /// the request must not be retried.
+    fn from_response(
+        headers: Option<&RatelimitHeaders>,
+        retry_after: Option<&[u8]>,
+    ) -> Option<Self> {
+        if let Some(RatelimitHeaders::GlobalLimited(global)) = headers {
+            return Some(Self {
+                duration: Duration::from_secs(global.retry_after()).min(RETRY_AFTER_MAX),
+                global: true,
+            });
+        }
+
+        let seconds = std::str::from_utf8(retry_after?)
+            .ok()?
+            .parse::<f64>()
+            .ok()
+            .filter(|seconds| seconds.is_finite() && *seconds >= 0.)?;
+
+        Some(Self {
+            // Clamped before converting, as the conversion panics on values
+            // too large for a duration.
+            duration: Duration::from_secs_f64(seconds.min(RETRY_AFTER_MAX.as_secs_f64())),
+            global: false,
+        })
+    }
+}
+
+/// Waiting for the `Retry-After` duration of a 429 response before resending
+/// the request.
+///
+/// The original request's ticket was consumed by reporting the 429's headers,
+/// so the resent request waits for a ticket of its own and reports its own
+/// response's headers, just like a request sent for the first time.
+struct Retrying {
+    conditional: Option<ConditionalRequest>,
+    guild_id: Option<Id<GuildMarker>>,
+    invalid_token: Option<Arc<AtomicBool>>,
+    retry: Retry,
+    sleep: Pin<Box<Sleep>>,
+}
+
+impl Retrying {
+    fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
+        if self.sleep.as_mut().poll(cx).is_pending() {
+            return InnerPoll::Pending(ResponseFutureStage::Retrying(self));
+        }
+
+        let mut retry = self.retry;
+        retry.remaining -= 1;
+        let future = (retry.resend)();
+
+        if let Some(wait_for_ticket) = &retry.wait_for_ticket {
+            let wait_for_sender = wait_for_ticket();
+
+            return InnerPoll::Advance(ResponseFutureStage::RatelimitQueue(RatelimitQueue {
+                conditional: self.conditional,
+                guild_id: self.guild_id,
+                invalid_token: self.invalid_token,
+                pre_flight_check: None,
+                request_timeout: retry.timeout,
+                response_future: future,
+                retry: Some(retry),
+                wait_for_sender,
+            }));
+        }
+
+        InnerPoll::Advance(ResponseFutureStage::InFlight(InFlight {
+            conditional: self.conditional,
+            future: Box::pin(time::timeout(retry.timeout, future)),
+            guild_id: self.guild_id,
+            invalid_token: self.invalid_token,
+            retry: Some(retry),
+            tx: None,
+        }))
+    }
+}
+
+/// Wrap a successful response.
+fn response<T>(resp: HyperResponse<Body>, guild_id: Option<Id<GuildMarker>>) -> Response<T> {
+    #[cfg(feature = "decompression")]
+    let mut resp = resp;
+    // Inaccurate since end-users can only access the decompressed body.
+    #[cfg(feature = "decompression")]
+    resp.headers_mut().remove(hyper::header::CONTENT_LENGTH);
+
+    let mut response = Response::new(resp);
+
+    if let Some(guild_id) = guild_id {
+        response.set_guild_id(guild_id);
+    }
+
+    response
+}
+
 enum ResponseFutureStage {
+    Caching(Caching),
     Chunking(Chunking),
     Completed,
     Failed(Failed),
     InFlight(InFlight),
     RatelimitQueue(RatelimitQueue),
+    Retrying(Retrying),
 }
 
 /// Future that will resolve to a [`Response`].
@@ -275,13 +510,17 @@ impl<T> ResponseFuture<T> {
         invalid_token: Option<Arc<AtomicBool>>,
         future: Timeout<HyperResponseFuture>,
         ratelimit_tx: Option<TicketSender>,
+        retry: Option<Retry>,
+        conditional: Option<ConditionalRequest>,
     ) -> Self {
         Self {
             phantom: PhantomData,
             stage: ResponseFutureStage::InFlight(InFlight {
+                conditional,
                 future: Box::pin(future),
                 guild_id: None,
                 invalid_token,
+                retry,
                 tx: ratelimit_tx,
             }),
         }
@@ -363,15 +602,19 @@ impl<T> ResponseFuture<T> {
         wait_for_sender: WaitForTicketFuture,
         request_timeout: Duration,
         response_future: HyperResponseFuture,
+        retry: Option<Retry>,
+        conditional: Option<ConditionalRequest>,
     ) -> Self {
         Self {
             phantom: PhantomData,
             stage: ResponseFutureStage::RatelimitQueue(RatelimitQueue {
+                conditional,
                 guild_id,
                 invalid_token,
                 pre_flight_check: None,
                 request_timeout,
                 response_future,
+                retry,
                 wait_for_sender,
             }),
         }
@@ -388,6 +631,9 @@ impl<T> ResponseFuture<T> {
             ResponseFutureStage::RatelimitQueue(stage) => {
                 stage.guild_id.replace(guild_id);
             }
+            ResponseFutureStage::Retrying(stage) => {
+                stage.guild_id.replace(guild_id);
+            }
             _ => {}
         }
     }
@@ -401,11 +647,13 @@ impl<T: Unpin> Future for ResponseFuture<T> {
             let stage = mem::replace(&mut self.stage, ResponseFutureStage::Completed);
 
             let result = match stage {
+                ResponseFutureStage::Caching(caching) => caching.poll(cx),
                 ResponseFutureStage::Chunking(chunking) => chunking.poll(cx),
                 ResponseFutureStage::Completed => panic!("future already completed"),
                 ResponseFutureStage::Failed(failed) => failed.poll(cx),
                 ResponseFutureStage::InFlight(in_flight) => in_flight.poll(cx),
                 ResponseFutureStage::RatelimitQueue(queue) => queue.poll(cx),
+                ResponseFutureStage::Retrying(retrying) => retrying.poll(cx),
             };
 
             match result {
@@ -426,3 +674,94 @@ impl<T: Unpin> Future for ResponseFuture<T> {
         }
     }
 }
+
+/// Future that will resolve to the [`Response`]s of a batch of requests.
+///
+/// Created via [`Client::request_all`]. All requests are queued with the
+/// ratelimiter at once and driven concurrently, so requests in different
+/// buckets don't wait on each other while requests sharing a bucket are still
+/// sent in the order the ratelimiter hands out tickets.
+///
+/// Resolves to the results of the requests in the order the requests were
+/// provided in. A failed request doesn't affect the other requests of the
+/// batch.
+///
+/// # Errors
+///
+/// Each request may fail for any of the reasons listed in the documentation
+/// for [`ResponseFuture`].
+///
+/// [`Client::request_all`]: crate::Client::request_all
+/// [`Response`]: super::Response
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct BatchResponseFuture<T> {
+    futures: Vec<ResponseFuture<T>>,
+    outputs: Vec<Option<Output<T>>>,
+}
+
+impl<T> BatchResponseFuture<T> {
+    pub(crate) fn new(futures: Vec<ResponseFuture<T>>) -> Self {
+        let outputs = futures.iter().map(|_| None).collect();
+
+        Self { futures, outputs }
+    }
+}
+
+impl<T: Unpin> Future for BatchResponseFuture<T> {
+    type Output = Vec<Output<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let mut pending = false;
+
+        for (future, output) in this.futures.iter_mut().zip(this.outputs.iter_mut()) {
+            if output.is_some() {
+                continue;
+            }
+
+            match Pin::new(future).poll(cx) {
+                Poll::Ready(result) => {
+                    output.replace(result);
+                }
+                Poll::Pending => pending = true,
+            }
+        }
+
+        if pending {
+            return Poll::Pending;
+        }
+
+        Poll::Ready(mem::take(&mut this.outputs).into_iter().flatten().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RetryAfter, RETRY_AFTER_MAX};
+    use std::time::Duration;
+
+    fn bucket(retry_after: &[u8]) -> Option<Duration> {
+        RetryAfter::from_response(None, Some(retry_after)).map(|retry_after| retry_after.duration)
+    }
+
+    #[test]
+    fn test_retry_after_bucket() {
+        assert_eq!(Some(Duration::from_secs(2)), bucket(b"2"));
+        assert_eq!(Some(Duration::from_millis(1500)), bucket(b"1.5"));
+        assert_eq!(Some(Duration::ZERO), bucket(b"0"));
+        assert_eq!(None, RetryAfter::from_response(None, None));
+    }
+
+    #[test]
+    fn test_retry_after_invalid() {
+        for retry_after in [&b"-1"[..], b"NaN", b"inf", b"-inf", b"soon", b"\xff"] {
+            assert_eq!(None, bucket(retry_after));
+        }
+    }
+
+    #[test]
+    fn test_retry_after_clamped() {
+        assert_eq!(Some(RETRY_AFTER_MAX), bucket(b"3600"));
+        assert_eq!(Some(RETRY_AFTER_MAX), bucket(b"1e300"));
+    }
+}
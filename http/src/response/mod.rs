@@ -50,7 +50,10 @@ pub(crate) mod future;
 
 mod status_code;
 
-pub use self::{future::ResponseFuture, status_code::StatusCode};
+pub use self::{
+    future::{BatchResponseFuture, ResponseFuture},
+    status_code::StatusCode,
+};
 
 use self::marker::{ListBody, MemberBody, MemberListBody};
 use super::json::JsonDeserializer;
@@ -69,6 +72,7 @@ use std::{
     pin::Pin,
     task::{Context, Poll},
 };
+use twilight_http_ratelimiting::RatelimitHeaders;
 use twilight_model::{
     guild::member::{Member, MemberDeserializer, MemberListDeserializer},
     id::{marker::GuildMarker, Id},
@@ -199,6 +203,51 @@ impl<T> Response<T> {
         HeaderIter(self.inner.headers().iter())
     }
 
+    /// Parsed ratelimit headers of the response.
+    ///
+    /// This includes the ratelimit bucket the request counted against and
+    /// how many requests remain, or whether the request hit the global
+    /// ratelimit. Reading the headers doesn't affect ratelimiting.
+    ///
+    /// Returns [`None`] if the response has no ratelimit headers or they
+    /// could not be parsed.
+    ///
+    /// # Examples
+    ///
+    /// Print the bucket and remaining requests of a request:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::env;
+    /// use twilight_http::Client;
+    /// use twilight_http_ratelimiting::RatelimitHeaders;
+    ///
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    /// let response = client.current_user().exec().await?;
+    ///
+    /// if let Some(RatelimitHeaders::Present(headers)) = response.ratelimit_headers() {
+    ///     println!(
+    ///         "bucket {:?} has {} requests remaining",
+    ///         headers.bucket(),
+    ///         headers.remaining(),
+    ///     );
+    /// }
+    /// # Ok(()) }
+    /// ```
+    #[must_use = "parsing the ratelimit headers has no use on its own"]
+    pub fn ratelimit_headers(&self) -> Option<RatelimitHeaders> {
+        let headers = self
+            .inner
+            .headers()
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_bytes()));
+
+        RatelimitHeaders::from_pairs(headers)
+            .ok()
+            .filter(|headers| !headers.is_none())
+    }
+
     /// Status code of the response.
     #[must_use = "retrieving the status code has no use on its own"]
     pub fn status(&self) -> StatusCode {
@@ -840,7 +889,9 @@ async fn decompress(body: Body) -> Result<Bytes, DeserializeBodyError> {
 /// Returns a [`DeserializeBodyErrorType::Deserializing`] error type if the
 /// input is not valid JSON.
 #[cfg(feature = "simd-json")]
-fn json_deserializer(input: &mut [u8]) -> Result<JsonDeserializer<'_>, DeserializeBodyError> {
+pub(crate) fn json_deserializer(
+    input: &mut [u8],
+) -> Result<JsonDeserializer<'_>, DeserializeBodyError> {
     JsonDeserializer::from_slice(input).map_err(|source| DeserializeBodyError {
         kind: DeserializeBodyErrorType::Deserializing,
         source: Some(Box::new(source)),
@@ -849,7 +900,7 @@ fn json_deserializer(input: &mut [u8]) -> Result<JsonDeserializer<'_>, Deseriali
 
 /// Create a `serde` Deserializer instance.
 #[cfg(not(feature = "simd-json"))]
-fn json_deserializer(
+pub(crate) fn json_deserializer(
     input: &mut [u8],
 ) -> Result<JsonDeserializer<serde_json::de::SliceRead<'_>>, DeserializeBodyError> {
     Ok(JsonDeserializer::from_slice(input))
@@ -862,8 +913,10 @@ mod tests {
         BytesFuture, DeserializeBodyError, DeserializeBodyErrorType, HeaderIter, MemberFuture,
         MemberListFuture, ModelFuture, Response, TextFuture,
     };
+    use hyper::{Body, Response as HyperResponse};
     use static_assertions::assert_impl_all;
     use std::{fmt::Debug, future::Future, iter::FusedIterator};
+    use twilight_http_ratelimiting::{headers::RatelimitScope, RatelimitHeaders};
     use twilight_model::{channel::Message, guild::Emoji};
 
     #[cfg(feature = "decompression")]
@@ -882,6 +935,60 @@ mod tests {
     assert_impl_all!(Response<MemberListBody>: Debug, Send, Sync);
     assert_impl_all!(TextFuture: Future);
 
+    fn response(headers: &[(&str, &str)]) -> Response<EmptyBody> {
+        let mut builder = HyperResponse::builder();
+
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+
+        Response::new(builder.body(Body::empty()).unwrap())
+    }
+
+    #[test]
+    fn test_ratelimit_headers() {
+        let present = response(&[
+            ("content-type", "application/json"),
+            ("x-ratelimit-bucket", "d721dea6054f6322373d361f98e5c38b"),
+            ("x-ratelimit-limit", "10"),
+            ("x-ratelimit-remaining", "9"),
+            ("x-ratelimit-reset", "1573795260.333"),
+            ("x-ratelimit-reset-after", "10.000"),
+        ]);
+
+        match present.ratelimit_headers() {
+            Some(RatelimitHeaders::Present(headers)) => {
+                assert_eq!(Some("d721dea6054f6322373d361f98e5c38b"), headers.bucket());
+                assert_eq!(10, headers.limit());
+                assert_eq!(9, headers.remaining());
+                assert_eq!(1_573_795_260_333, headers.reset());
+                assert_eq!(10_000, headers.reset_after());
+            }
+            other => panic!("expected present headers, got {other:?}"),
+        }
+
+        let global = response(&[
+            ("retry-after", "487"),
+            ("x-ratelimit-global", "true"),
+            ("x-ratelimit-scope", "global"),
+        ]);
+
+        match global.ratelimit_headers() {
+            Some(RatelimitHeaders::GlobalLimited(headers)) => {
+                assert_eq!(487, headers.retry_after());
+                assert_eq!(Some(RatelimitScope::Global), headers.scope());
+            }
+            other => panic!("expected global headers, got {other:?}"),
+        }
+
+        assert!(response(&[("content-type", "application/json")])
+            .ratelimit_headers()
+            .is_none());
+        assert!(response(&[("x-ratelimit-remaining", "nine")])
+            .ratelimit_headers()
+            .is_none());
+    }
+
     #[cfg(feature = "decompression")]
     #[tokio::test]
     async fn test_decompression() -> Result<(), Box<dyn Error + Send + Sync>> {
@@ -8,6 +8,13 @@ use twilight_model::id::{marker::RoleMarker, Id};
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Route<'a> {
+    /// Route information to add a user to a group DM.
+    AddGroupRecipient {
+        /// ID of the group DM.
+        channel_id: u64,
+        /// ID of the user.
+        user_id: u64,
+    },
     /// Route information to add a user to a guild.
     AddGuildMember { guild_id: u64, user_id: u64 },
     /// Route information to add a role to guild member.
@@ -36,6 +43,11 @@ pub enum Route<'a> {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to ban multiple users in a guild at once.
+    CreateBans {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to create a channel in a guild.
     CreateChannel {
         /// The ID of the guild.
@@ -51,6 +63,8 @@ pub enum Route<'a> {
         /// The ID of the owner application.
         application_id: u64,
     },
+    /// Route information to create a group DM.
+    CreateGroupDm,
     /// Route information to create a guild.
     CreateGuild,
     /// Route information to create a guild command.
@@ -105,6 +119,9 @@ pub enum Route<'a> {
         /// The ID of the channel.
         channel_id: u64,
     },
+    /// Route information to create an `OAuth2` access token, either by
+    /// exchanging an authorization code or by refreshing a token.
+    CreateOauthToken,
     /// Route information to create a private channel.
     CreatePrivateChannel,
     /// Route information to create a thread in a channel.
@@ -490,11 +507,16 @@ pub enum Route<'a> {
         /// Whether to include full localization dictionaries.
         with_localizations: Option<bool>,
     },
-    /// Route information to get a guild's widget.
+    /// Route information to get a guild's public widget.
     GetGuildWidget {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get a guild's widget settings.
+    GetGuildWidgetSettings {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a guild's integrations.
     GetGuildIntegrations {
         /// The ID of the guild.
@@ -809,6 +831,13 @@ pub enum Route<'a> {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to remove a user from a group DM.
+    RemoveGroupRecipient {
+        /// ID of the group DM.
+        channel_id: u64,
+        /// ID of the user.
+        user_id: u64,
+    },
     /// Route information to remove a role from a member.
     RemoveMemberRole {
         /// The ID of the guild.
@@ -825,6 +854,8 @@ pub enum Route<'a> {
         /// ID of the member.
         user_id: u64,
     },
+    /// Route information to revoke an `OAuth2` access or refresh token.
+    RevokeOauthToken,
     /// Route information to search for members in a guild.
     SearchGuildMembers {
         /// ID of the guild to search in.
@@ -926,8 +957,8 @@ pub enum Route<'a> {
         /// The ID of the guild.
         guild_id: u64,
     },
-    /// Route information to update a guild's widget.
-    UpdateGuildWidget {
+    /// Route information to update a guild's widget settings.
+    UpdateGuildWidgetSettings {
         /// The ID of the guild.
         guild_id: u64,
     },
@@ -938,6 +969,11 @@ pub enum Route<'a> {
         /// The ID of the integration.
         integration_id: u64,
     },
+    /// Route information to update a guild's MFA level.
+    UpdateGuildMfa {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to update a scheduled event in a guild.
     UpdateGuildScheduledEvent {
         /// ID of the guild.
@@ -1085,6 +1121,7 @@ impl<'a> Route<'a> {
             | Self::DeleteWebhook { .. }
             | Self::LeaveGuild { .. }
             | Self::LeaveThread { .. }
+            | Self::RemoveGroupRecipient { .. }
             | Self::RemoveMember { .. }
             | Self::RemoveMemberRole { .. }
             | Self::RemoveThreadMember { .. }
@@ -1129,6 +1166,7 @@ impl<'a> Route<'a> {
             | Self::GetGuildWelcomeScreen { .. }
             | Self::GetGuildWebhooks { .. }
             | Self::GetGuildWidget { .. }
+            | Self::GetGuildWidgetSettings { .. }
             | Self::GetGuilds { .. }
             | Self::GetInteractionOriginal { .. }
             | Self::GetInvite { .. }
@@ -1164,7 +1202,7 @@ impl<'a> Route<'a> {
             | Self::UpdateGuild { .. }
             | Self::UpdateGuildChannels { .. }
             | Self::UpdateGuildCommand { .. }
-            | Self::UpdateGuildWidget { .. }
+            | Self::UpdateGuildWidgetSettings { .. }
             | Self::UpdateGuildIntegration { .. }
             | Self::UpdateGuildScheduledEvent { .. }
             | Self::UpdateGuildSticker { .. }
@@ -1180,10 +1218,12 @@ impl<'a> Route<'a> {
             | Self::UpdateUserVoiceState { .. }
             | Self::UpdateWebhookMessage { .. }
             | Self::UpdateWebhook { .. } => Method::Patch,
-            Self::CreateChannel { .. }
+            Self::CreateBans { .. }
+            | Self::CreateChannel { .. }
             | Self::CreateGlobalCommand { .. }
             | Self::CreateGuildCommand { .. }
             | Self::CreateEmoji { .. }
+            | Self::CreateGroupDm
             | Self::CreateGuild
             | Self::CreateGuildFromTemplate { .. }
             | Self::CreateGuildIntegration { .. }
@@ -1192,6 +1232,7 @@ impl<'a> Route<'a> {
             | Self::CreateGuildSticker { .. }
             | Self::CreateInvite { .. }
             | Self::CreateMessage { .. }
+            | Self::CreateOauthToken
             | Self::CreatePrivateChannel
             | Self::CreateThread { .. }
             | Self::CreateThreadFromMessage { .. }
@@ -1205,8 +1246,11 @@ impl<'a> Route<'a> {
             | Self::ExecuteWebhook { .. }
             | Self::FollowNewsChannel { .. }
             | Self::InteractionCallback { .. }
-            | Self::SyncGuildIntegration { .. } => Method::Post,
-            Self::AddGuildMember { .. }
+            | Self::RevokeOauthToken
+            | Self::SyncGuildIntegration { .. }
+            | Self::UpdateGuildMfa { .. } => Method::Post,
+            Self::AddGroupRecipient { .. }
+            | Self::AddGuildMember { .. }
             | Self::AddMemberRole { .. }
             | Self::AddThreadMember { .. }
             | Self::CreateBan { .. }
@@ -1254,6 +1298,10 @@ impl<'a> Route<'a> {
     #[allow(clippy::too_many_lines)]
     pub fn to_path(&self) -> Path {
         match *self {
+            Self::AddGroupRecipient { channel_id, .. }
+            | Self::RemoveGroupRecipient { channel_id, .. } => {
+                Path::ChannelsIdRecipients(channel_id)
+            }
             Self::AddGuildMember { guild_id, .. }
             | Self::GetMember { guild_id, .. }
             | Self::RemoveMember { guild_id, .. }
@@ -1272,6 +1320,7 @@ impl<'a> Route<'a> {
             Self::CreateBan { guild_id, .. } | Self::DeleteBan { guild_id, .. } => {
                 Path::GuildsIdBansUserId(guild_id)
             }
+            Self::CreateBans { guild_id } => Path::GuildsIdBulkBan(guild_id),
             Self::CreateChannel { guild_id } => Path::GuildsIdChannels(guild_id),
             Self::CreateEmoji { guild_id } | Self::GetEmojis { guild_id } => {
                 Path::GuildsIdEmojis(guild_id)
@@ -1310,7 +1359,9 @@ impl<'a> Route<'a> {
             Self::CreateMessage { channel_id } | Self::GetMessages { channel_id, .. } => {
                 Path::ChannelsIdMessages(channel_id)
             }
-            Self::CreatePrivateChannel | Self::GetUserPrivateChannels => Path::UsersIdChannels,
+            Self::CreateGroupDm | Self::CreatePrivateChannel | Self::GetUserPrivateChannels => {
+                Path::UsersIdChannels
+            }
             Self::CreateReaction { channel_id, .. }
             | Self::DeleteReactionCurrentUser { channel_id, .. }
             | Self::DeleteReaction { channel_id, .. } => {
@@ -1456,6 +1507,8 @@ impl<'a> Route<'a> {
                 Path::ApplicationGuildCommandId(application_id)
             }
             Self::GetCurrentUserApplicationInfo => Path::OauthApplicationsMe,
+            Self::CreateOauthToken => Path::OauthToken,
+            Self::RevokeOauthToken => Path::OauthTokenRevoke,
             Self::GetCurrentUser | Self::GetUser { .. } | Self::UpdateCurrentUser => Path::UsersId,
             Self::GetCurrentUserGuildMember { .. } => Path::UsersIdGuildsIdMember,
             Self::GetEmoji { guild_id, .. } | Self::UpdateEmoji { guild_id, .. } => {
@@ -1465,9 +1518,9 @@ impl<'a> Route<'a> {
             Self::GetGuild { guild_id, .. } | Self::UpdateGuild { guild_id } => {
                 Path::GuildsId(guild_id)
             }
-            Self::GetGuildWidget { guild_id } | Self::UpdateGuildWidget { guild_id } => {
-                Path::GuildsIdWidget(guild_id)
-            }
+            Self::GetGuildWidget { guild_id }
+            | Self::GetGuildWidgetSettings { guild_id }
+            | Self::UpdateGuildWidgetSettings { guild_id } => Path::GuildsIdWidget(guild_id),
             Self::GetGuildIntegrations { guild_id } => Path::GuildsIdIntegrations(guild_id),
             Self::GetGuildInvites { guild_id } => Path::GuildsIdInvites(guild_id),
             Self::GetGuildMembers { guild_id, .. } | Self::UpdateCurrentMember { guild_id, .. } => {
@@ -1513,6 +1566,7 @@ impl<'a> Route<'a> {
             Self::UnpinMessage { channel_id, .. } => Path::ChannelsIdPinsMessageId(channel_id),
             Self::UpdateCurrentUserVoiceState { guild_id }
             | Self::UpdateUserVoiceState { guild_id, .. } => Path::GuildsIdVoiceStates(guild_id),
+            Self::UpdateGuildMfa { guild_id } => Path::GuildsIdMfa(guild_id),
             Self::UpdateMessage { channel_id, .. } => {
                 Path::ChannelsIdMessagesId(Method::Patch, channel_id)
             }
@@ -1593,6 +1647,20 @@ impl Display for Route<'_> {
 
                 Display::fmt(role_id, f)
             }
+            Route::AddGroupRecipient {
+                channel_id,
+                user_id,
+            }
+            | Route::RemoveGroupRecipient {
+                channel_id,
+                user_id,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/recipients/")?;
+
+                Display::fmt(user_id, f)
+            }
             Route::AddThreadMember {
                 channel_id,
                 user_id,
@@ -1629,6 +1697,12 @@ impl Display for Route<'_> {
 
                 Ok(())
             }
+            Route::CreateBans { guild_id } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/bulk-ban")
+            }
             Route::CreateChannel { guild_id }
             | Route::GetChannels { guild_id }
             | Route::UpdateGuildChannels { guild_id } => {
@@ -1773,7 +1847,7 @@ impl Display for Route<'_> {
 
                 f.write_str("/messages")
             }
-            Route::CreatePrivateChannel | Route::GetUserPrivateChannels => {
+            Route::CreateGroupDm | Route::CreatePrivateChannel | Route::GetUserPrivateChannels => {
                 f.write_str("users/@me/channels")
             }
             Route::CreateReaction {
@@ -2242,6 +2316,8 @@ impl Display for Route<'_> {
                 f.write_str("/permissions")
             }
             Route::GetCurrentUserApplicationInfo => f.write_str("oauth2/applications/@me"),
+            Route::CreateOauthToken => f.write_str("oauth2/token"),
+            Route::RevokeOauthToken => f.write_str("oauth2/token/revoke"),
             Route::GetCurrentUser | Route::UpdateCurrentUser => f.write_str("users/@me"),
             Route::GetCurrentUserGuildMember { guild_id } => {
                 f.write_str("users/@me/guilds/")?;
@@ -2454,7 +2530,14 @@ impl Display for Route<'_> {
 
                 f.write_str("/webhooks")
             }
-            Route::GetGuildWidget { guild_id } | Route::UpdateGuildWidget { guild_id } => {
+            Route::GetGuildWidget { guild_id } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/widget.json")
+            }
+            Route::GetGuildWidgetSettings { guild_id }
+            | Route::UpdateGuildWidgetSettings { guild_id } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
 
@@ -2751,6 +2834,12 @@ impl Display for Route<'_> {
 
                 Display::fmt(scheduled_event_id, f)
             }
+            Route::UpdateGuildMfa { guild_id } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/mfa")
+            }
             Route::UpdateNickname { guild_id } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
@@ -2936,6 +3025,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_group_recipient() {
+        let route = Route::AddGroupRecipient {
+            channel_id: CHANNEL_ID,
+            user_id: USER_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("channels/{CHANNEL_ID}/recipients/{USER_ID}")
+        );
+    }
+
+    #[test]
+    fn test_remove_group_recipient() {
+        let route = Route::RemoveGroupRecipient {
+            channel_id: CHANNEL_ID,
+            user_id: USER_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("channels/{CHANNEL_ID}/recipients/{USER_ID}")
+        );
+    }
+
     #[test]
     fn test_add_thread_member() {
         let route = Route::AddThreadMember {
@@ -3048,6 +3161,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_create_group_dm() {
+        let route = Route::CreateGroupDm;
+        assert_eq!(route.to_string(), "users/@me/channels");
+    }
+
     #[test]
     fn test_create_guild() {
         let route = Route::CreateGuild;
@@ -3175,6 +3294,12 @@ mod tests {
         assert_eq!(route.to_string(), format!("channels/{CHANNEL_ID}/messages"));
     }
 
+    #[test]
+    fn test_create_oauth_token() {
+        let route = Route::CreateOauthToken;
+        assert_eq!(route.to_string(), "oauth2/token");
+    }
+
     #[test]
     fn test_create_private_channel() {
         let route = Route::CreatePrivateChannel;
@@ -3441,6 +3566,12 @@ mod tests {
         assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}"));
     }
 
+    #[test]
+    fn test_update_guild_mfa() {
+        let route = Route::UpdateGuildMfa { guild_id: GUILD_ID };
+        assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/mfa"));
+    }
+
     #[test]
     fn test_delete_guild_command() {
         let route = Route::DeleteGuildCommand {
@@ -3997,12 +4128,18 @@ mod tests {
     #[test]
     fn test_get_guild_widget() {
         let route = Route::GetGuildWidget { guild_id: GUILD_ID };
+        assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/widget.json"));
+    }
+
+    #[test]
+    fn test_get_guild_widget_settings() {
+        let route = Route::GetGuildWidgetSettings { guild_id: GUILD_ID };
         assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/widget"));
     }
 
     #[test]
-    fn test_update_guild_widget() {
-        let route = Route::UpdateGuildWidget { guild_id: GUILD_ID };
+    fn test_update_guild_widget_settings() {
+        let route = Route::UpdateGuildWidgetSettings { guild_id: GUILD_ID };
         assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/widget"));
     }
 
@@ -4193,6 +4330,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_create_bans() {
+        let route = Route::CreateBans { guild_id: GUILD_ID };
+        assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/bulk-ban"));
+    }
+
     #[test]
     fn test_create_guild_prune_none() {
         let route = Route::CreateGuildPrune {
@@ -4429,6 +4572,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_revoke_oauth_token() {
+        let route = Route::RevokeOauthToken;
+        assert_eq!(route.to_string(), "oauth2/token/revoke");
+    }
+
     #[test]
     fn test_search_guild_members() {
         let route = Route::SearchGuildMembers {
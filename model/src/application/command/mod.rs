@@ -10,7 +10,7 @@ pub use self::{
     option::{
         BaseCommandOptionData, ChannelCommandOptionData, ChoiceCommandOptionData, CommandOption,
         CommandOptionChoice, CommandOptionType, CommandOptionValue, Number,
-        NumberCommandOptionData, OptionsCommandOptionData,
+        NumberCommandOptionData, NumberConversionError, OptionsCommandOptionData,
     },
 };
 
@@ -86,3 +86,91 @@ pub struct Command {
     /// Autoincrementing version identifier.
     pub version: Id<CommandVersionMarker>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Command, CommandType};
+    use crate::{guild::Permissions, id::Id};
+    use serde_test::Token;
+
+    #[test]
+    fn test_default_member_permissions() {
+        let value = Command {
+            application_id: None,
+            default_member_permissions: Some(Permissions::BAN_MEMBERS | Permissions::KICK_MEMBERS),
+            dm_permission: None,
+            description: "ban a member".to_owned(),
+            description_localizations: None,
+            guild_id: None,
+            id: None,
+            kind: CommandType::ChatInput,
+            name: "ban".to_owned(),
+            name_localizations: None,
+            options: Vec::new(),
+            version: Id::new(1),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Command",
+                    len: 7,
+                },
+                Token::Str("default_member_permissions"),
+                Token::Some,
+                Token::Str("6"),
+                Token::Str("dm_permission"),
+                Token::None,
+                Token::Str("description"),
+                Token::Str("ban a member"),
+                Token::Str("type"),
+                Token::U8(1),
+                Token::Str("name"),
+                Token::Str("ban"),
+                Token::Str("options"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("version"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    /// Test that a command without default member permissions, sent by Discord
+    /// as `null`, is deserialized as `None`.
+    #[test]
+    fn test_default_member_permissions_null() {
+        let input = r#"{
+            "default_member_permissions": null,
+            "description": "ping",
+            "dm_permission": true,
+            "name": "ping",
+            "type": 1,
+            "version": "1"
+        }"#;
+
+        let command = serde_json::from_str::<Command>(input).unwrap();
+        assert!(command.default_member_permissions.is_none());
+
+        let input = r#"{
+            "default_member_permissions": "8",
+            "description": "ping",
+            "dm_permission": true,
+            "name": "ping",
+            "type": 1,
+            "version": "1"
+        }"#;
+
+        let command = serde_json::from_str::<Command>(input).unwrap();
+        assert_eq!(
+            Some(Permissions::ADMINISTRATOR),
+            command.default_member_permissions
+        );
+        assert!(serde_json::to_string(&command)
+            .unwrap()
+            .contains(r#""default_member_permissions":"8""#));
+    }
+}
@@ -1,14 +1,14 @@
 use crate::{channel::ChannelType, util::is_false};
 use serde::{
-    de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
+    de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Unexpected, Visitor},
     ser::Serializer,
     Deserialize, Serialize,
 };
-use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::{
     cmp::Eq,
     collections::HashMap,
-    fmt::{Formatter, Result as FmtResult},
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
     hash::{Hash, Hasher},
 };
 
@@ -449,6 +449,12 @@ impl<'de> Visitor<'de> for OptionVisitor {
                 name_localizations: name_localizations.flatten(),
                 required,
             }),
+            CommandOptionType::Unknown { value } => {
+                return Err(DeError::invalid_value(
+                    Unexpected::Unsigned(u64::from(value)),
+                    &"a known command option type",
+                ));
+            }
         })
     }
 }
@@ -689,20 +695,23 @@ pub enum CommandOptionValue {
 }
 
 /// Type of a [`CommandOption`].
-#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum CommandOptionType {
-    SubCommand = 1,
-    SubCommandGroup = 2,
-    String = 3,
-    Integer = 4,
-    Boolean = 5,
-    User = 6,
-    Channel = 7,
-    Role = 8,
-    Mentionable = 9,
-    Number = 10,
-    Attachment = 11,
+    SubCommand,
+    SubCommandGroup,
+    String,
+    Integer,
+    Boolean,
+    User,
+    Channel,
+    Role,
+    Mentionable,
+    Number,
+    Attachment,
+    /// Variant value is unknown to the library.
+    Unknown {
+        value: u8,
+    },
 }
 
 impl CommandOptionType {
@@ -719,13 +728,103 @@ impl CommandOptionType {
             CommandOptionType::Mentionable => "Mentionable",
             CommandOptionType::Number => "Number",
             CommandOptionType::Attachment => "Attachment",
+            CommandOptionType::Unknown { .. } => "Unknown",
+        }
+    }
+
+    /// Retrieve the raw value of the type, used by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::application::command::CommandOptionType;
+    ///
+    /// assert_eq!(3, CommandOptionType::String.number());
+    /// assert_eq!(99, CommandOptionType::Unknown { value: 99 }.number());
+    /// ```
+    pub const fn number(self) -> u8 {
+        match self {
+            CommandOptionType::SubCommand => 1,
+            CommandOptionType::SubCommandGroup => 2,
+            CommandOptionType::String => 3,
+            CommandOptionType::Integer => 4,
+            CommandOptionType::Boolean => 5,
+            CommandOptionType::User => 6,
+            CommandOptionType::Channel => 7,
+            CommandOptionType::Role => 8,
+            CommandOptionType::Mentionable => 9,
+            CommandOptionType::Number => 10,
+            CommandOptionType::Attachment => 11,
+            CommandOptionType::Unknown { value } => value,
+        }
+    }
+}
+
+impl From<u8> for CommandOptionType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => CommandOptionType::SubCommand,
+            2 => CommandOptionType::SubCommandGroup,
+            3 => CommandOptionType::String,
+            4 => CommandOptionType::Integer,
+            5 => CommandOptionType::Boolean,
+            6 => CommandOptionType::User,
+            7 => CommandOptionType::Channel,
+            8 => CommandOptionType::Role,
+            9 => CommandOptionType::Mentionable,
+            10 => CommandOptionType::Number,
+            11 => CommandOptionType::Attachment,
+            value => CommandOptionType::Unknown { value },
         }
     }
 }
 
+impl<'de> Deserialize<'de> for CommandOptionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer).map(u8::into)
+    }
+}
+
+impl Serialize for CommandOptionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.number())
+    }
+}
+
+/// Floating point number of a command option.
+///
+/// Numbers created via [`Number::new`] or [`TryFrom<f64>`] are validated to be
+/// finite, as NaN and infinity can't be represented in JSON and are rejected
+/// by Discord.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::application::command::Number;
+///
+/// assert!(Number::new(2.5).is_some());
+/// assert!(Number::new(f64::NAN).is_none());
+/// assert!(Number::try_from(f64::INFINITY).is_err());
+/// ```
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct Number(pub f64);
 
+impl Number {
+    /// Create a new number, returning [`None`] if the value is NaN or
+    /// infinite.
+    pub fn new(value: f64) -> Option<Self> {
+        value.is_finite().then(|| Self(value))
+    }
+}
+
+impl TryFrom<f64> for Number {
+    type Error = NumberConversionError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::new(value).ok_or(NumberConversionError { value })
+    }
+}
+
 impl Eq for Number {}
 
 impl Hash for Number {
@@ -746,13 +845,39 @@ impl From<Number> for f64 {
     }
 }
 
+/// Converting into a [`Number`] failed.
+///
+/// This occurs only when the input value is NaN or infinite.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberConversionError {
+    value: f64,
+}
+
+impl NumberConversionError {
+    /// Retrieve a copy of the input value that couldn't be converted.
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl Display for NumberConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("value (")?;
+        Display::fmt(&self.value, f)?;
+
+        f.write_str(") isn't a finite number")
+    }
+}
+
+impl Error for NumberConversionError {}
+
 #[cfg(test)]
 mod tests {
     use super::{
         super::{Command, CommandType},
         BaseCommandOptionData, ChannelCommandOptionData, ChoiceCommandOptionData, CommandOption,
         CommandOptionChoice, CommandOptionValue, Number, NumberCommandOptionData,
-        OptionsCommandOptionData,
+        NumberConversionError, OptionsCommandOptionData,
     };
     use crate::{channel::ChannelType, guild::Permissions, id::Id};
     use serde::{Deserialize, Serialize};
@@ -1190,4 +1315,25 @@ mod tests {
 
         assert_eq!(NUMBER_1, NUMBER_2);
     }
+
+    #[test]
+    fn test_number_finite() {
+        assert_eq!(Some(Number(12.34)), Number::new(12.34));
+        assert_eq!(Ok(Number(-1.0)), Number::try_from(-1.0));
+
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(Number::new(value).is_none());
+
+            let error = Number::try_from(value).unwrap_err();
+            assert_eq!(value.to_bits(), error.value().to_bits());
+        }
+
+        assert_eq!(
+            "value (inf) isn't a finite number",
+            NumberConversionError {
+                value: f64::INFINITY
+            }
+            .to_string()
+        );
+    }
 }
@@ -28,6 +28,18 @@ pub enum ComponentType {
     ///
     /// [`TextInput`]: super::TextInput
     TextInput = 4,
+
+    /// Component is a select menu for users.
+    UserSelect = 5,
+
+    /// Component is a select menu for roles.
+    RoleSelect = 6,
+
+    /// Component is a select menu for users and roles.
+    MentionableSelect = 7,
+
+    /// Component is a select menu for channels.
+    ChannelSelect = 8,
 }
 
 impl ComponentType {
@@ -52,6 +64,10 @@ impl ComponentType {
             Self::Button => "Button",
             Self::SelectMenu => "SelectMenu",
             Self::TextInput => "TextInput",
+            Self::UserSelect => "UserSelect",
+            Self::RoleSelect => "RoleSelect",
+            Self::MentionableSelect => "MentionableSelect",
+            Self::ChannelSelect => "ChannelSelect",
         }
     }
 }
@@ -85,6 +101,11 @@ mod tests {
     const_assert_eq!(1, ComponentType::ActionRow as u8);
     const_assert_eq!(2, ComponentType::Button as u8);
     const_assert_eq!(3, ComponentType::SelectMenu as u8);
+    const_assert_eq!(4, ComponentType::TextInput as u8);
+    const_assert_eq!(5, ComponentType::UserSelect as u8);
+    const_assert_eq!(6, ComponentType::RoleSelect as u8);
+    const_assert_eq!(7, ComponentType::MentionableSelect as u8);
+    const_assert_eq!(8, ComponentType::ChannelSelect as u8);
 
     #[test]
     fn test_variants() {
@@ -93,10 +114,28 @@ mod tests {
         serde_test::assert_tokens(&ComponentType::SelectMenu, &[Token::U8(3)]);
     }
 
+    #[test]
+    fn test_variants_text_input_and_entity_select() {
+        serde_test::assert_tokens(&ComponentType::TextInput, &[Token::U8(4)]);
+        serde_test::assert_tokens(&ComponentType::UserSelect, &[Token::U8(5)]);
+        serde_test::assert_tokens(&ComponentType::RoleSelect, &[Token::U8(6)]);
+        serde_test::assert_tokens(&ComponentType::MentionableSelect, &[Token::U8(7)]);
+        serde_test::assert_tokens(&ComponentType::ChannelSelect, &[Token::U8(8)]);
+    }
+
     #[test]
     fn test_names() {
         assert_eq!("ActionRow", ComponentType::ActionRow.name());
         assert_eq!("Button", ComponentType::Button.name());
         assert_eq!("SelectMenu", ComponentType::SelectMenu.name());
     }
+
+    #[test]
+    fn test_names_text_input_and_entity_select() {
+        assert_eq!("TextInput", ComponentType::TextInput.name());
+        assert_eq!("UserSelect", ComponentType::UserSelect.name());
+        assert_eq!("RoleSelect", ComponentType::RoleSelect.name());
+        assert_eq!("MentionableSelect", ComponentType::MentionableSelect.name());
+        assert_eq!("ChannelSelect", ComponentType::ChannelSelect.name());
+    }
 }
@@ -22,7 +22,7 @@ pub use self::{
 
 use crate::{application::component::select_menu::SelectMenuOption, channel::ReactionType};
 use serde::{
-    de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
+    de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Unexpected, Visitor},
     ser::SerializeStruct,
     Deserialize, Serialize, Serializer,
 };
@@ -397,6 +397,17 @@ impl<'de> Visitor<'de> for ComponentVisitor {
                     value: value.unwrap_or_default(),
                 })
             }
+            // Entity select menus aren't modeled as message components, so
+            // deserializing one fails with an invalid value error.
+            ComponentType::UserSelect
+            | ComponentType::RoleSelect
+            | ComponentType::MentionableSelect
+            | ComponentType::ChannelSelect => {
+                return Err(DeError::invalid_value(
+                    Unexpected::Unsigned(kind as u64),
+                    &"a supported component type",
+                ));
+            }
         })
     }
 }
@@ -1,14 +1,23 @@
 use crate::{
     application::{
         command::CommandType,
-        interaction::application_command::{CommandDataOption, CommandInteractionDataResolved},
+        interaction::application_command::{
+            CommandDataOption, CommandInteractionDataResolved, CommandOptionValue,
+            InteractionChannel, InteractionMember, ResolvedMentionable,
+        },
     },
+    channel::Attachment,
+    guild::Role,
     id::{
-        marker::{CommandMarker, GenericMarker},
+        marker::{
+            AttachmentMarker, ChannelMarker, CommandMarker, GenericMarker, RoleMarker, UserMarker,
+        },
         Id,
     },
+    user::User,
 };
 use serde::{Deserialize, Serialize};
+use std::slice::Iter;
 
 /// Data received when an [`ApplicationCommand`] interaction is executed.
 ///
@@ -35,3 +44,325 @@ pub struct CommandData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_id: Option<Id<GenericMarker>>,
 }
+
+impl CommandData {
+    /// Top-level option with a name.
+    ///
+    /// Options of subcommands aren't searched; use [`option_recursive`] to
+    /// search them as well.
+    ///
+    /// [`option_recursive`]: Self::option_recursive
+    pub fn option(&self, name: &str) -> Option<&CommandDataOption> {
+        self.options.iter().find(|option| option.name == name)
+    }
+
+    /// Option with a name, searching the options of subcommands and
+    /// subcommand groups.
+    ///
+    /// Options are searched level by level, so a top-level option is returned
+    /// before an option of a subcommand with the same name.
+    pub fn option_recursive(&self, name: &str) -> Option<&CommandDataOption> {
+        find_recursive(&self.options, &|option| option.name == name)
+    }
+
+    /// Option currently focused by the user in an autocomplete interaction.
+    ///
+    /// The options of subcommands and subcommand groups are searched as well.
+    pub fn focused_option(&self) -> Option<&CommandDataOption> {
+        find_recursive(&self.options, &|option| option.focused)
+    }
+
+    /// Iterator over all options, including the options of subcommands and
+    /// subcommand groups.
+    ///
+    /// Options are yielded depth-first in the order they were received, with
+    /// subcommands and subcommand groups being yielded before their options.
+    pub fn walk_options(&self) -> impl Iterator<Item = &CommandDataOption> {
+        WalkOptions {
+            stack: Vec::from([self.options.iter()]),
+        }
+    }
+
+    /// Resolved attachment of an [`Attachment`] option.
+    ///
+    /// [`Attachment`]: CommandOptionValue::Attachment
+    pub fn resolve_attachment(&self, id: Id<AttachmentMarker>) -> Option<&Attachment> {
+        self.resolved.as_ref()?.attachments.get(&id)
+    }
+
+    /// Resolved channel of a [`Channel`] option.
+    ///
+    /// [`Channel`]: CommandOptionValue::Channel
+    pub fn resolve_channel(&self, id: Id<ChannelMarker>) -> Option<&InteractionChannel> {
+        self.resolved.as_ref()?.channels.get(&id)
+    }
+
+    /// Resolved member of a [`User`] option, if the user is a member of the
+    /// guild the command was executed in.
+    ///
+    /// [`User`]: CommandOptionValue::User
+    pub fn resolve_member(&self, id: Id<UserMarker>) -> Option<&InteractionMember> {
+        self.resolved.as_ref()?.members.get(&id)
+    }
+
+    /// Resolved role or user of a [`Mentionable`] option.
+    ///
+    /// Users are checked before roles.
+    ///
+    /// [`Mentionable`]: CommandOptionValue::Mentionable
+    pub fn resolve_mentionable(&self, id: Id<GenericMarker>) -> Option<ResolvedMentionable<'_>> {
+        let resolved = self.resolved.as_ref()?;
+
+        resolved
+            .users
+            .get(&id.cast())
+            .map(ResolvedMentionable::User)
+            .or_else(|| {
+                resolved
+                    .roles
+                    .get(&id.cast())
+                    .map(ResolvedMentionable::Role)
+            })
+    }
+
+    /// Resolved role of a [`Role`] option.
+    ///
+    /// [`Role`]: CommandOptionValue::Role
+    pub fn resolve_role(&self, id: Id<RoleMarker>) -> Option<&Role> {
+        self.resolved.as_ref()?.roles.get(&id)
+    }
+
+    /// Resolved user of a [`User`] option.
+    ///
+    /// [`User`]: CommandOptionValue::User
+    pub fn resolve_user(&self, id: Id<UserMarker>) -> Option<&User> {
+        self.resolved.as_ref()?.users.get(&id)
+    }
+}
+
+/// Depth-first iterator over options and their nested options.
+struct WalkOptions<'a> {
+    /// Iterators over the levels of options currently being walked, with the
+    /// deepest level last.
+    stack: Vec<Iter<'a, CommandDataOption>>,
+}
+
+impl<'a> Iterator for WalkOptions<'a> {
+    type Item = &'a CommandDataOption;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let level = self.stack.last_mut()?;
+
+            let option = if let Some(option) = level.next() {
+                option
+            } else {
+                self.stack.pop();
+
+                continue;
+            };
+
+            if let CommandOptionValue::SubCommand(options)
+            | CommandOptionValue::SubCommandGroup(options) = &option.value
+            {
+                self.stack.push(options.iter());
+            }
+
+            return Some(option);
+        }
+    }
+}
+
+/// Find an option matching a predicate on a level of options, or otherwise
+/// in the options of the level's subcommands and subcommand groups.
+fn find_recursive<'a>(
+    options: &'a [CommandDataOption],
+    predicate: &impl Fn(&CommandDataOption) -> bool,
+) -> Option<&'a CommandDataOption> {
+    if let Some(option) = options.iter().find(|option| predicate(option)) {
+        return Some(option);
+    }
+
+    options.iter().find_map(|option| match &option.value {
+        CommandOptionValue::SubCommand(options) | CommandOptionValue::SubCommandGroup(options) => {
+            find_recursive(options, predicate)
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandData;
+    use crate::{
+        application::{
+            command::CommandType,
+            interaction::application_command::{
+                CommandDataOption, CommandInteractionDataResolved, CommandOptionValue,
+                ResolvedMentionable,
+            },
+        },
+        guild::{Permissions, Role},
+        id::Id,
+        user::User,
+    };
+    use std::collections::HashMap;
+
+    fn option(name: &str, value: CommandOptionValue) -> CommandDataOption {
+        CommandDataOption {
+            focused: false,
+            name: name.to_owned(),
+            value,
+        }
+    }
+
+    /// Data of `/config set channel:<channel> name:<focused>`, where `config`
+    /// is a subcommand group and `set` a subcommand.
+    fn data() -> CommandData {
+        let mut name = option("name", CommandOptionValue::String("ge".to_owned()));
+        name.focused = true;
+
+        let set = option(
+            "set",
+            CommandOptionValue::SubCommand(Vec::from([
+                option("channel", CommandOptionValue::Channel(Id::new(2))),
+                name,
+            ])),
+        );
+
+        CommandData {
+            id: Id::new(1),
+            name: "settings".to_owned(),
+            kind: CommandType::ChatInput,
+            options: Vec::from([
+                option(
+                    "config",
+                    CommandOptionValue::SubCommandGroup(Vec::from([set])),
+                ),
+                option("ephemeral", CommandOptionValue::Boolean(true)),
+            ]),
+            resolved: None,
+            target_id: None,
+        }
+    }
+
+    #[test]
+    fn test_option() {
+        let data = data();
+
+        assert_eq!(
+            Some(&CommandOptionValue::Boolean(true)),
+            data.option("ephemeral").map(|option| &option.value)
+        );
+        assert!(data.option("config").is_some());
+        assert!(data.option("missing").is_none());
+        // Options of subcommands aren't searched.
+        assert!(data.option("channel").is_none());
+    }
+
+    #[test]
+    fn test_option_recursive() {
+        let data = data();
+
+        assert_eq!(
+            Some(&CommandOptionValue::Channel(Id::new(2))),
+            data.option_recursive("channel").map(|option| &option.value)
+        );
+        assert!(matches!(
+            data.option_recursive("set").map(|option| &option.value),
+            Some(CommandOptionValue::SubCommand(_))
+        ));
+        assert!(data.option_recursive("ephemeral").is_some());
+        assert!(data.option_recursive("missing").is_none());
+    }
+
+    #[test]
+    fn test_focused_option() {
+        let mut data = data();
+
+        let focused = data.focused_option().unwrap();
+        assert_eq!("name", focused.name);
+        assert_eq!(CommandOptionValue::String("ge".to_owned()), focused.value);
+
+        data.options.truncate(1);
+        data.options[0].value = CommandOptionValue::SubCommandGroup(Vec::new());
+        assert!(data.focused_option().is_none());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let role = Role {
+            color: 0,
+            hoist: false,
+            icon: None,
+            id: Id::new(4),
+            managed: false,
+            mentionable: true,
+            name: "moderators".to_owned(),
+            permissions: Permissions::empty(),
+            position: 1,
+            tags: None,
+            unicode_emoji: None,
+        };
+        let user = User {
+            accent_color: None,
+            avatar: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            id: Id::new(3),
+            locale: None,
+            mfa_enabled: None,
+            name: "twilight".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        };
+
+        let mut data = data();
+        assert!(data.resolve_user(user.id).is_none());
+
+        data.resolved = Some(CommandInteractionDataResolved {
+            attachments: HashMap::new(),
+            channels: HashMap::new(),
+            members: HashMap::new(),
+            messages: HashMap::new(),
+            roles: HashMap::from([(role.id, role.clone())]),
+            users: HashMap::from([(user.id, user.clone())]),
+        });
+
+        assert_eq!(Some(&user), data.resolve_user(Id::new(3)));
+        assert_eq!(Some(&role), data.resolve_role(Id::new(4)));
+        assert!(data.resolve_user(Id::new(5)).is_none());
+        assert!(data.resolve_member(Id::new(3)).is_none());
+        assert!(data.resolve_channel(Id::new(2)).is_none());
+
+        assert_eq!(
+            Some(ResolvedMentionable::User(&user)),
+            data.resolve_mentionable(Id::new(3))
+        );
+        assert_eq!(
+            Some(ResolvedMentionable::Role(&role)),
+            data.resolve_mentionable(Id::new(4))
+        );
+        assert!(data.resolve_mentionable(Id::new(5)).is_none());
+    }
+
+    #[test]
+    fn test_walk_options() {
+        let mut data = data();
+        assert_eq!(
+            ["config", "set", "channel", "name", "ephemeral"],
+            data.walk_options()
+                .map(|option| option.name.as_str())
+                .collect::<Vec<_>>()
+                .as_slice()
+        );
+
+        data.options.clear();
+        assert!(data.walk_options().next().is_none());
+    }
+}
@@ -5,11 +5,13 @@ mod resolved;
 pub use self::{
     data::CommandData,
     option::{CommandDataOption, CommandOptionValue},
-    resolved::{CommandInteractionDataResolved, InteractionChannel, InteractionMember},
+    resolved::{
+        CommandInteractionDataResolved, InteractionChannel, InteractionMember, ResolvedMentionable,
+    },
 };
 
 use crate::{
-    application::interaction::InteractionType,
+    application::{interaction::InteractionType, monetization::Entitlement},
     guild::PartialMember,
     id::{
         marker::{ApplicationMarker, ChannelMarker, GuildMarker, InteractionMarker, UserMarker},
@@ -32,6 +34,10 @@ pub struct ApplicationCommand {
     pub channel_id: Id<ChannelMarker>,
     /// Data from the invoked command.
     pub data: CommandData,
+    /// Entitlements of the invoking user or guild, for monetized
+    /// applications.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub entitlements: Vec<Entitlement>,
     /// ID of the guild the interaction was invoked in.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
@@ -129,6 +135,7 @@ mod tests {
                 resolved: None,
                 target_id: None,
             },
+            entitlements: Vec::new(),
             guild_id: Some(Id::<GuildMarker>::new(1)),
             guild_locale: None,
             id: Id::<InteractionMarker>::new(1),
@@ -10,12 +10,31 @@ use serde::{
     ser::SerializeStruct,
     Deserialize, Deserializer, Serialize, Serializer,
 };
+use serde_value::{DeserializerError, Value};
 use std::fmt::{Debug, Formatter, Result as FmtResult};
 
+/// Largest integer that a float can exactly represent, along with every
+/// integer below it, 2^53.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
 /// Data received when a user fills in a command option.
 ///
+/// When serializing, [`focused`] is only included if it's [`true`]. Options
+/// received with `focused` explicitly set to `false`, as Discord may do in
+/// autocomplete interactions, are therefore not serialized byte-for-byte
+/// identical to the received payload, although they deserialize back to the
+/// same value.
+///
+/// [`Number`] values are serialized as integers if they have no fractional
+/// part and are within the range of integers a float can exactly represent,
+/// from -2^53 to 2^53, so that integers Discord sends round trip in their
+/// original form. Integers outside of this range lose precision when
+/// deserialized.
+///
 /// See [Discord Docs/Application Command Object].
 ///
+/// [`focused`]: Self::focused
+///
 /// [Discord Docs/Application Command Object]: https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-interaction-data-option-structure
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CommandDataOption {
@@ -33,8 +52,16 @@ impl Serialize for CommandDataOption {
             | CommandOptionValue::SubCommandGroup(o)
                 if o.is_empty()
         );
+        let unknown_is_empty = matches!(
+            &self.value,
+            CommandOptionValue::Unknown {
+                raw: Value::Unit,
+                ..
+            }
+        );
 
-        let len = 2 + usize::from(!subcommand_is_empty) + usize::from(self.focused);
+        let len =
+            2 + usize::from(!subcommand_is_empty && !unknown_is_empty) + usize::from(self.focused);
 
         let mut state = serializer.serialize_struct("CommandDataOption", len)?;
 
@@ -52,10 +79,24 @@ impl Serialize for CommandDataOption {
             CommandOptionValue::Channel(c) => state.serialize_field("value", c)?,
             CommandOptionValue::Integer(i) => state.serialize_field("value", i)?,
             CommandOptionValue::Mentionable(m) => state.serialize_field("value", m)?,
+            // Comparing the float to its truncated self is exact, and the
+            // cast can't truncate as the value is within the range of
+            // integers it can exactly represent.
+            #[allow(clippy::cast_possible_truncation, clippy::float_cmp)]
+            CommandOptionValue::Number(Number(n))
+                if n.trunc() == *n && n.abs() <= MAX_SAFE_INTEGER =>
+            {
+                state.serialize_field("value", &(*n as i64))?
+            }
             CommandOptionValue::Number(n) => state.serialize_field("value", n)?,
             CommandOptionValue::Role(r) => state.serialize_field("value", r)?,
             CommandOptionValue::String(s) => state.serialize_field("value", s)?,
             CommandOptionValue::User(u) => state.serialize_field("value", u)?,
+            CommandOptionValue::Unknown { raw, .. } => {
+                if !unknown_is_empty {
+                    state.serialize_field("value", raw)?
+                }
+            }
             CommandOptionValue::SubCommand(s) | CommandOptionValue::SubCommandGroup(s) => {
                 if !subcommand_is_empty {
                     state.serialize_field("options", s)?
@@ -119,7 +160,7 @@ impl<'de> Deserialize<'de> for CommandDataOption {
                 let mut name_opt = None;
                 let mut kind_opt = None;
                 let mut options = Vec::new();
-                let mut value_opt = None;
+                let mut value_opt: Option<Value> = None;
                 let mut focused = None;
 
                 loop {
@@ -177,6 +218,22 @@ impl<'de> Deserialize<'de> for CommandDataOption {
                 let name = name_opt.ok_or_else(|| DeError::missing_field("name"))?;
                 let kind = kind_opt.ok_or_else(|| DeError::missing_field("type"))?;
 
+                // Values of unknown option types are kept as is, as their
+                // shape can't be known.
+                let (value_opt, raw_opt) = if let CommandOptionType::Unknown { .. } = kind {
+                    (None, value_opt)
+                } else {
+                    let value_opt = value_opt
+                        .map(|value| {
+                            value
+                                .deserialize_into::<ValueEnvelope>()
+                                .map_err(DeserializerError::into_error)
+                        })
+                        .transpose()?;
+
+                    (value_opt, None)
+                };
+
                 let value = match kind {
                     CommandOptionType::Attachment => {
                         let val = value_opt.ok_or_else(|| DeError::missing_field("value"))?;
@@ -236,9 +293,9 @@ impl<'de> Deserialize<'de> for CommandDataOption {
                             ValueEnvelope::Integer(i) => {
                                 // As json allows sending floating
                                 // points without the tailing decimals
-                                // it may be interpreted as a integer
-                                // but it is safe to cast as there can
-                                // not occur any loss.
+                                // it may be interpreted as a integer.
+                                // Integers beyond 2^53 lose precision
+                                // as they can't be represented exactly.
                                 #[allow(clippy::cast_precision_loss)]
                                 CommandOptionValue::Number(Number(i as f64))
                             }
@@ -290,6 +347,14 @@ impl<'de> Deserialize<'de> for CommandDataOption {
                             return Err(DeError::invalid_type(val.as_unexpected(), &"user id"));
                         }
                     }
+                    CommandOptionType::Unknown { value } => {
+                        tracing::trace!(kind = value, "ran into an unknown option type");
+
+                        CommandOptionValue::Unknown {
+                            kind: value,
+                            raw: raw_opt.unwrap_or(Value::Unit),
+                        }
+                    }
                 };
 
                 Ok(CommandDataOption {
@@ -318,6 +383,13 @@ pub enum CommandOptionValue {
     SubCommand(Vec<CommandDataOption>),
     SubCommandGroup(Vec<CommandDataOption>),
     User(Id<UserMarker>),
+    /// Value of an option type unknown to the library.
+    Unknown {
+        /// Raw type of the option.
+        kind: u8,
+        /// Raw value of the option, or [`Value::Unit`] if it has none.
+        raw: Value,
+    },
 }
 
 impl CommandOptionValue {
@@ -334,6 +406,120 @@ impl CommandOptionValue {
             CommandOptionValue::SubCommand(_) => CommandOptionType::SubCommand,
             CommandOptionValue::SubCommandGroup(_) => CommandOptionType::SubCommandGroup,
             CommandOptionValue::User(_) => CommandOptionType::User,
+            CommandOptionValue::Unknown { kind, .. } => CommandOptionType::Unknown { value: *kind },
+        }
+    }
+
+    /// ID of the attachment if the value is an [`Attachment`].
+    ///
+    /// [`Attachment`]: Self::Attachment
+    pub const fn as_attachment(&self) -> Option<Id<AttachmentMarker>> {
+        match self {
+            Self::Attachment(id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// Value of a [`Boolean`].
+    ///
+    /// [`Boolean`]: Self::Boolean
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// ID of the channel if the value is a [`Channel`].
+    ///
+    /// [`Channel`]: Self::Channel
+    pub const fn as_channel(&self) -> Option<Id<ChannelMarker>> {
+        match self {
+            Self::Channel(id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// Value of a [`Number`].
+    ///
+    /// [`Number`]: Self::Number
+    pub const fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(Number(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Value of an [`Integer`].
+    ///
+    /// [`Integer`]: Self::Integer
+    pub const fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// ID of the role or user if the value is a [`Mentionable`].
+    ///
+    /// Whether the ID is of a role or a user can be determined from the
+    /// interaction's resolved data.
+    ///
+    /// [`Mentionable`]: Self::Mentionable
+    pub const fn as_mentionable(&self) -> Option<Id<GenericMarker>> {
+        match self {
+            Self::Mentionable(id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// ID of the role if the value is a [`Role`].
+    ///
+    /// [`Role`]: Self::Role
+    pub const fn as_role(&self) -> Option<Id<RoleMarker>> {
+        match self {
+            Self::Role(id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// Value of a [`String`].
+    ///
+    /// [`String`]: Self::String
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Options of a [`SubCommand`].
+    ///
+    /// [`SubCommand`]: Self::SubCommand
+    pub fn as_sub_command(&self) -> Option<&[CommandDataOption]> {
+        match self {
+            Self::SubCommand(options) => Some(options),
+            _ => None,
+        }
+    }
+
+    /// Options of a [`SubCommandGroup`].
+    ///
+    /// [`SubCommandGroup`]: Self::SubCommandGroup
+    pub fn as_sub_command_group(&self) -> Option<&[CommandDataOption]> {
+        match self {
+            Self::SubCommandGroup(options) => Some(options),
+            _ => None,
+        }
+    }
+
+    /// ID of the user if the value is a [`User`].
+    ///
+    /// [`User`]: Self::User
+    pub const fn as_user(&self) -> Option<Id<UserMarker>> {
+        match self {
+            Self::User(id) => Some(*id),
+            _ => None,
         }
     }
 }
@@ -350,6 +536,78 @@ mod tests {
         id::Id,
     };
     use serde_test::Token;
+    use serde_value::Value;
+
+    #[test]
+    fn accessors() {
+        let options = Vec::from([CommandDataOption {
+            focused: false,
+            name: "member".into(),
+            value: CommandOptionValue::User(Id::new(8)),
+        }]);
+        let values = [
+            CommandOptionValue::Attachment(Id::new(1)),
+            CommandOptionValue::Boolean(true),
+            CommandOptionValue::Channel(Id::new(2)),
+            CommandOptionValue::Integer(3),
+            CommandOptionValue::Mentionable(Id::new(4)),
+            CommandOptionValue::Number(Number(5.5)),
+            CommandOptionValue::Role(Id::new(6)),
+            CommandOptionValue::String("seven".into()),
+            CommandOptionValue::SubCommand(options.clone()),
+            CommandOptionValue::SubCommandGroup(options.clone()),
+            CommandOptionValue::User(Id::new(9)),
+        ];
+
+        for value in &values {
+            let kind = value.kind();
+
+            assert_eq!(
+                (kind == CommandOptionType::Attachment).then(|| Id::new(1)),
+                value.as_attachment()
+            );
+            assert_eq!(
+                (kind == CommandOptionType::Boolean).then(|| true),
+                value.as_bool()
+            );
+            assert_eq!(
+                (kind == CommandOptionType::Channel).then(|| Id::new(2)),
+                value.as_channel()
+            );
+            assert_eq!(
+                (kind == CommandOptionType::Integer).then(|| 3),
+                value.as_i64()
+            );
+            assert_eq!(
+                (kind == CommandOptionType::Mentionable).then(|| Id::new(4)),
+                value.as_mentionable()
+            );
+            assert_eq!(
+                (kind == CommandOptionType::Number).then(|| 5.5),
+                value.as_f64()
+            );
+            assert_eq!(
+                (kind == CommandOptionType::Role).then(|| Id::new(6)),
+                value.as_role()
+            );
+            assert_eq!(
+                (kind == CommandOptionType::String).then(|| "seven"),
+                value.as_str()
+            );
+            assert_eq!(
+                (kind == CommandOptionType::SubCommand).then(|| options.as_slice()),
+                value.as_sub_command()
+            );
+            assert_eq!(
+                (kind == CommandOptionType::SubCommandGroup).then(|| options.as_slice()),
+                value.as_sub_command_group()
+            );
+            assert_eq!(
+                (kind == CommandOptionType::User).then(|| Id::new(9)),
+                value.as_user()
+            );
+        }
+    }
 
     #[test]
     fn no_options() {
@@ -418,7 +676,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("cat"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::SubCommand as u8),
+                Token::U8(CommandOptionType::SubCommand.number()),
                 Token::StructEnd,
                 Token::SeqEnd,
                 Token::StructEnd,
@@ -444,11 +702,141 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("opt"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Number as u8),
+                Token::U8(CommandOptionType::Number.number()),
                 Token::Str("value"),
                 Token::I64(5),
                 Token::StructEnd,
             ],
         );
     }
+
+    /// Test that integer numbers are serialized back as integers, exactly up to
+    /// 2^53, while other numbers are serialized as floats.
+    #[test]
+    fn numbers_round_trip() -> Result<(), serde_json::Error> {
+        for value in [
+            "0",
+            "-5",
+            "9007199254740991",
+            "9007199254740992",
+            "-9007199254740992",
+            "5.5",
+            "1e+300",
+        ] {
+            let json = format!(r#"{{"name":"opt","type":10,"value":{value}}}"#);
+            let option = serde_json::from_str::<CommandDataOption>(&json)?;
+
+            assert_eq!(json, serde_json::to_string(&option)?);
+        }
+
+        // Integers beyond 2^53 are rounded to the nearest representable float.
+        let json = r#"{"name":"opt","type":10,"value":9007199254740993}"#;
+        let option = serde_json::from_str::<CommandDataOption>(json)?;
+        assert_eq!(Some(9_007_199_254_740_992.0), option.value.as_f64());
+
+        Ok(())
+    }
+
+    /// Test that an unfocused option is serialized without the `focused` field,
+    /// even if it was present when deserializing.
+    #[test]
+    fn unfocused_round_trip() {
+        let value = CommandDataOption {
+            focused: false,
+            name: "opt".to_owned(),
+            value: CommandOptionValue::String("value".to_owned()),
+        };
+
+        serde_test::assert_de_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CommandDataOption",
+                    len: 4,
+                },
+                Token::Str("focused"),
+                Token::Some,
+                Token::Bool(false),
+                Token::Str("name"),
+                Token::Str("opt"),
+                Token::Str("type"),
+                Token::U8(CommandOptionType::String.number()),
+                Token::Str("value"),
+                Token::Str("value"),
+                Token::StructEnd,
+            ],
+        );
+
+        serde_test::assert_ser_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CommandDataOption",
+                    len: 3,
+                },
+                Token::Str("name"),
+                Token::Str("opt"),
+                Token::Str("type"),
+                Token::U8(CommandOptionType::String.number()),
+                Token::Str("value"),
+                Token::Str("value"),
+                Token::StructEnd,
+            ],
+        );
+
+        let focused = CommandDataOption {
+            focused: true,
+            ..value
+        };
+
+        serde_test::assert_ser_tokens(
+            &focused,
+            &[
+                Token::Struct {
+                    name: "CommandDataOption",
+                    len: 4,
+                },
+                Token::Str("focused"),
+                Token::Bool(true),
+                Token::Str("name"),
+                Token::Str("opt"),
+                Token::Str("type"),
+                Token::U8(CommandOptionType::String.number()),
+                Token::Str("value"),
+                Token::Str("value"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        let value = CommandDataOption {
+            focused: false,
+            name: "opt".to_owned(),
+            value: CommandOptionValue::Unknown {
+                kind: 99,
+                raw: Value::String("value".to_owned()),
+            },
+        };
+
+        assert_eq!(CommandOptionType::Unknown { value: 99 }, value.value.kind());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CommandDataOption",
+                    len: 3,
+                },
+                Token::Str("name"),
+                Token::Str("opt"),
+                Token::Str("type"),
+                Token::U8(99),
+                Token::Str("value"),
+                Token::Str("value"),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -59,6 +59,17 @@ pub struct InteractionMember {
     pub roles: Vec<Id<RoleMarker>>,
 }
 
+/// Role or user resolved from the ID of a [`Mentionable`] option.
+///
+/// [`Mentionable`]: super::CommandOptionValue::Mentionable
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResolvedMentionable<'a> {
+    /// Mentioned role.
+    Role(&'a Role),
+    /// Mentioned user.
+    User(&'a User),
+}
+
 #[cfg(test)]
 mod tests {
     use super::{CommandInteractionDataResolved, InteractionChannel, InteractionMember};
@@ -178,6 +189,8 @@ mod tests {
                     mention_everyone: false,
                     mention_roles: Vec::new(),
                     mentions: Vec::new(),
+                    message_snapshots: Vec::new(),
+                    nonce: None,
                     pinned: false,
                     reactions: Vec::new(),
                     reference: None,
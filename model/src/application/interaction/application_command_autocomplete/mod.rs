@@ -9,7 +9,7 @@ pub use self::{
 };
 
 use crate::{
-    application::interaction::InteractionType,
+    application::{interaction::InteractionType, monetization::Entitlement},
     guild::PartialMember,
     id::{
         marker::{ApplicationMarker, ChannelMarker, GuildMarker, InteractionMarker, UserMarker},
@@ -32,6 +32,10 @@ pub struct ApplicationCommandAutocomplete {
     pub channel_id: Id<ChannelMarker>,
     /// Data from the invoked command.
     pub data: ApplicationCommandAutocompleteData,
+    /// Entitlements of the invoking user or guild, for monetized
+    /// applications.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub entitlements: Vec<Entitlement>,
     /// ID of the guild the interaction was invoked in.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
@@ -124,6 +128,7 @@ mod tests {
                     }]),
                     resolved: None,
                 },
+                entitlements: Vec::new(),
                 guild_id: Some(Id::new(4)),
                 guild_locale: None,
                 id: Id::new(5),
@@ -257,6 +262,7 @@ mod tests {
                 }]),
                 resolved: None,
             },
+            entitlements: Vec::new(),
             guild_id: Some(Id::<GuildMarker>::new(1)),
             guild_locale: None,
             id: Id::<InteractionMarker>::new(1),
@@ -1,5 +1,16 @@
-use crate::application::component::ComponentType;
+use crate::{
+    application::{
+        component::ComponentType,
+        interaction::application_command::{
+            CommandInteractionDataResolved, InteractionChannel, InteractionMember,
+        },
+    },
+    guild::Role,
+    id::Id,
+    user::User,
+};
 use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
 
 /// Data received when an [`MessageComponent`] interaction is executed.
 ///
@@ -7,26 +18,115 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`MessageComponent`]: crate::application::interaction::Interaction::MessageComponent
 /// [Discord Docs/Interaction Object]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-data-structure
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct MessageComponentInteractionData {
     pub custom_id: String,
     pub component_type: ComponentType,
+    /// Resolved entities selected in an entity select menu.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<CommandInteractionDataResolved>,
     #[serde(default)]
     pub values: Vec<String>,
 }
 
+// Resolved data is keyed by maps, which can't be hashed. It is derived from the
+// values, so equal data still hashes equally without it.
+impl Hash for MessageComponentInteractionData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.custom_id.hash(state);
+        self.component_type.hash(state);
+        self.values.hash(state);
+    }
+}
+
+impl MessageComponentInteractionData {
+    /// Selected values resolved into their typed entities.
+    ///
+    /// Values of a [`SelectMenu`] are returned as strings, while values of
+    /// entity select menus are resolved against the [`resolved`] data. Values
+    /// that can't be resolved are skipped.
+    ///
+    /// [`SelectMenu`]: ComponentType::SelectMenu
+    /// [`resolved`]: Self::resolved
+    pub fn selected_values(&self) -> Vec<SelectedValue<'_>> {
+        self.values
+            .iter()
+            .filter_map(|value| self.resolve_value(value))
+            .collect()
+    }
+
+    fn resolve_value<'a>(&'a self, value: &'a str) -> Option<SelectedValue<'a>> {
+        let resolved = self.resolved.as_ref();
+
+        let user = || {
+            let resolved = resolved?;
+            let id = value.parse::<Id<_>>().ok()?;
+
+            resolved.users.get(&id).map(|user| SelectedValue::User {
+                member: resolved.members.get(&id),
+                user,
+            })
+        };
+        let role = || {
+            let id = value.parse::<Id<_>>().ok()?;
+
+            resolved?.roles.get(&id).map(SelectedValue::Role)
+        };
+
+        match self.component_type {
+            ComponentType::UserSelect => user(),
+            ComponentType::RoleSelect => role(),
+            ComponentType::MentionableSelect => user().or_else(role),
+            ComponentType::ChannelSelect => {
+                let id = value.parse::<Id<_>>().ok()?;
+
+                resolved?.channels.get(&id).map(SelectedValue::Channel)
+            }
+            _ => Some(SelectedValue::String(value)),
+        }
+    }
+}
+
+/// Value selected in a select menu, resolved into its typed entity.
+///
+/// Returned by [`MessageComponentInteractionData::selected_values`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SelectedValue<'a> {
+    /// Channel selected in a channel select menu.
+    Channel(&'a InteractionChannel),
+    /// Role selected in a role or mentionable select menu.
+    Role(&'a Role),
+    /// Value of an option selected in a string select menu.
+    String(&'a str),
+    /// User selected in a user or mentionable select menu.
+    User {
+        /// Member information of the user, if selected in a guild.
+        member: Option<&'a InteractionMember>,
+        /// Selected user.
+        user: &'a User,
+    },
+}
+
 #[cfg(test)]
 mod tests {
-    use super::MessageComponentInteractionData;
-    use crate::application::component::ComponentType;
+    use super::{MessageComponentInteractionData, SelectedValue};
+    use crate::{
+        application::{
+            component::ComponentType,
+            interaction::application_command::CommandInteractionDataResolved,
+        },
+        id::Id,
+        user::User,
+    };
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::{fmt::Debug, hash::Hash};
+    use std::{collections::HashMap, fmt::Debug, hash::Hash};
 
     assert_fields!(
         MessageComponentInteractionData: custom_id,
         component_type,
+        resolved,
         values
     );
     assert_impl_all!(
@@ -46,6 +146,7 @@ mod tests {
         let value = MessageComponentInteractionData {
             custom_id: "test".to_owned(),
             component_type: ComponentType::Button,
+            resolved: None,
             values: Vec::from(["1".to_owned(), "2".to_owned()]),
         };
 
@@ -69,4 +170,61 @@ mod tests {
             ],
         )
     }
+
+    #[test]
+    fn test_selected_values_string() {
+        let value = MessageComponentInteractionData {
+            custom_id: "test".to_owned(),
+            component_type: ComponentType::SelectMenu,
+            resolved: None,
+            values: Vec::from(["1".to_owned(), "option".to_owned()]),
+        };
+
+        assert_eq!(
+            Vec::from([SelectedValue::String("1"), SelectedValue::String("option")]),
+            value.selected_values()
+        );
+    }
+
+    #[test]
+    fn test_selected_values_user() {
+        let user = User {
+            accent_color: None,
+            avatar: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            id: Id::new(2),
+            locale: None,
+            mfa_enabled: None,
+            name: "test".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        };
+        let value = MessageComponentInteractionData {
+            custom_id: "test".to_owned(),
+            component_type: ComponentType::UserSelect,
+            resolved: Some(CommandInteractionDataResolved {
+                attachments: HashMap::new(),
+                channels: HashMap::new(),
+                members: HashMap::new(),
+                messages: HashMap::new(),
+                roles: HashMap::new(),
+                users: HashMap::from([(user.id, user.clone())]),
+            }),
+            values: Vec::from(["2".to_owned(), "3".to_owned()]),
+        };
+
+        assert_eq!(
+            Vec::from([SelectedValue::User {
+                member: None,
+                user: &user,
+            }]),
+            value.selected_values()
+        );
+    }
 }
@@ -1,9 +1,10 @@
 mod data;
 
-pub use self::data::MessageComponentInteractionData;
+pub use self::data::{MessageComponentInteractionData, SelectedValue};
 
 use super::InteractionType;
 use crate::{
+    application::monetization::Entitlement,
     channel::Message,
     guild::PartialMember,
     id::{
@@ -26,6 +27,10 @@ pub struct MessageComponentInteraction {
     pub channel_id: Id<ChannelMarker>,
     /// Data from the invoked command.
     pub data: MessageComponentInteractionData,
+    /// Entitlements of the invoking user or guild, for monetized
+    /// applications.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub entitlements: Vec<Entitlement>,
     /// ID of the guild the interaction was invoked in.
     pub guild_id: Option<Id<GuildMarker>>,
     /// Guild's preferred locale.
@@ -139,8 +144,10 @@ mod tests {
             data: MessageComponentInteractionData {
                 custom_id: "foo".to_owned(),
                 component_type: ComponentType::Button,
+                resolved: None,
                 values: Vec::from(["bar".to_owned()]),
             },
+            entitlements: Vec::new(),
             guild_id: Some(Id::new(3)),
             guild_locale: None,
             id: Id::new(4),
@@ -179,6 +186,8 @@ mod tests {
                 mention_everyone: false,
                 mention_roles: Vec::new(),
                 mentions: Vec::new(),
+                message_snapshots: Vec::new(),
+                nonce: None,
                 pinned: false,
                 reactions: Vec::new(),
                 reference: None,
@@ -197,6 +206,7 @@ mod tests {
         assert!(in_guild.is_guild());
 
         let in_dm = MessageComponentInteraction {
+            entitlements: Vec::new(),
             member: None,
             message: Message {
                 guild_id: None,
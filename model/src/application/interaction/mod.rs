@@ -8,18 +8,26 @@ pub mod modal;
 mod interaction_type;
 mod ping;
 
-use self::modal::ModalSubmitInteraction;
 pub use self::{
     application_command::ApplicationCommand,
     application_command_autocomplete::ApplicationCommandAutocomplete,
     interaction_type::InteractionType, message_component::MessageComponentInteraction, ping::Ping,
 };
+use self::{
+    application_command::CommandData,
+    application_command_autocomplete::ApplicationCommandAutocompleteData,
+    message_component::MessageComponentInteractionData,
+    modal::{ModalInteractionData, ModalSubmitInteraction},
+};
 
 use crate::{
+    application::monetization::Entitlement,
     channel::Message,
     guild::PartialMember,
     id::{
-        marker::{ApplicationMarker, ChannelMarker, GuildMarker, InteractionMarker, UserMarker},
+        marker::{
+            ApplicationMarker, ChannelMarker, GuildMarker, InteractionMarker, SkuMarker, UserMarker,
+        },
         Id,
     },
     user::User,
@@ -29,7 +37,10 @@ use serde::{
     Deserialize, Serialize,
 };
 use serde_value::{DeserializerError, Value};
-use std::fmt::{Formatter, Result as FmtResult};
+use std::{
+    fmt::{Formatter, Result as FmtResult},
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 /// Payload received when a user executes an interaction.
 ///
@@ -65,6 +76,113 @@ impl Interaction {
         }
     }
 
+    /// Data of an application command interaction.
+    ///
+    /// Returns [`None`] if this is not an [`ApplicationCommand`] interaction.
+    ///
+    /// [`ApplicationCommand`]: Self::ApplicationCommand
+    pub const fn as_command(&self) -> Option<&CommandData> {
+        match self {
+            Self::ApplicationCommand(command) => Some(&command.data),
+            _ => None,
+        }
+    }
+
+    /// Data of an application command autocomplete interaction.
+    ///
+    /// Returns [`None`] if this is not an [`ApplicationCommandAutocomplete`]
+    /// interaction.
+    ///
+    /// [`ApplicationCommandAutocomplete`]: Self::ApplicationCommandAutocomplete
+    pub const fn as_autocomplete(&self) -> Option<&ApplicationCommandAutocompleteData> {
+        match self {
+            Self::ApplicationCommandAutocomplete(command) => Some(&command.data),
+            _ => None,
+        }
+    }
+
+    /// Data of a message component interaction.
+    ///
+    /// Returns [`None`] if this is not a [`MessageComponent`] interaction.
+    ///
+    /// [`MessageComponent`]: Self::MessageComponent
+    pub const fn as_component(&self) -> Option<&MessageComponentInteractionData> {
+        match self {
+            Self::MessageComponent(component) => Some(&component.data),
+            _ => None,
+        }
+    }
+
+    /// Data of a modal submit interaction.
+    ///
+    /// Returns [`None`] if this is not a [`ModalSubmit`] interaction.
+    ///
+    /// [`ModalSubmit`]: Self::ModalSubmit
+    pub const fn as_modal_submit(&self) -> Option<&ModalInteractionData> {
+        match self {
+            Self::ModalSubmit(modal) => Some(&modal.data),
+            _ => None,
+        }
+    }
+
+    /// Entitlements of the invoking user or guild, for monetized applications.
+    ///
+    /// Empty for [`Ping`] interactions.
+    ///
+    /// [`Ping`]: Self::Ping
+    pub fn entitlements(&self) -> &[Entitlement] {
+        match self {
+            Self::Ping(_) => &[],
+            Self::ApplicationCommand(command) => &command.entitlements,
+            Self::ApplicationCommandAutocomplete(command) => &command.entitlements,
+            Self::MessageComponent(component) => &component.entitlements,
+            Self::ModalSubmit(modal) => &modal.entitlements,
+        }
+    }
+
+    /// Whether the invoking user or guild is entitled to an SKU, such as a
+    /// premium subscription, and hasn't consumed the entitlement.
+    ///
+    /// Entitlements are only considered between their [`starts_at`] and
+    /// [`ends_at`] times, compared against the current system time.
+    ///
+    /// Useful for gating premium features of commands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::{application::interaction::Interaction, id::Id};
+    ///
+    /// # fn respond(interaction: &Interaction) {
+    /// if interaction.has_entitlement(Id::new(1)) {
+    ///     // Run the premium command.
+    /// } else {
+    ///     // Ask the user to subscribe.
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`ends_at`]: Entitlement::ends_at
+    /// [`starts_at`]: Entitlement::starts_at
+    pub fn has_entitlement(&self, sku_id: Id<SkuMarker>) -> bool {
+        // A system time before the Unix epoch is treated as the epoch itself.
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |now| i64::try_from(now.as_micros()).unwrap_or(i64::MAX));
+
+        self.entitlements().iter().any(|entitlement| {
+            entitlement.sku_id == sku_id
+                && !entitlement.consumed
+                && !entitlement.deleted
+                && entitlement
+                    .starts_at
+                    .map_or(true, |starts_at| starts_at.as_micros() <= now)
+                && entitlement
+                    .ends_at
+                    .map_or(true, |ends_at| now < ends_at.as_micros())
+        })
+    }
+
     /// ID of the guild the interaction was invoked in.
     pub const fn guild_id(&self) -> Option<Id<GuildMarker>> {
         match self {
@@ -100,6 +218,23 @@ impl Interaction {
         }
     }
 
+    /// Message the interaction was invoked on.
+    ///
+    /// Present for [`MessageComponent`] interactions, and for [`ModalSubmit`]
+    /// interactions when the modal was opened from a message component. The
+    /// message may be used to read the current content and components of the
+    /// message.
+    ///
+    /// [`MessageComponent`]: Self::MessageComponent
+    /// [`ModalSubmit`]: Self::ModalSubmit
+    pub const fn message(&self) -> Option<&Message> {
+        match self {
+            Self::MessageComponent(component) => Some(&component.message),
+            Self::ModalSubmit(modal) => modal.message.as_ref(),
+            _ => None,
+        }
+    }
+
     /// Token of the interaction.
     pub fn token(&self) -> &str {
         match self {
@@ -138,6 +273,7 @@ enum InteractionField {
     ApplicationId,
     ChannelId,
     Data,
+    Entitlements,
     GuildId,
     GuildLocale,
     Id,
@@ -163,6 +299,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
         let mut application_id: Option<Id<ApplicationMarker>> = None;
         let mut channel_id: Option<Id<ChannelMarker>> = None;
         let mut data: Option<Value> = None;
+        let mut entitlements: Option<Vec<Entitlement>> = None;
         let mut guild_id: Option<Option<Id<GuildMarker>>> = None;
         let mut guild_locale: Option<Option<String>> = None;
         let mut id: Option<Id<InteractionMarker>> = None;
@@ -219,6 +356,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
                     data = Some(map.next_value()?);
                 }
+                InteractionField::Entitlements => {
+                    if entitlements.is_some() {
+                        return Err(DeError::duplicate_field("entitlements"));
+                    }
+
+                    entitlements = Some(map.next_value()?);
+                }
                 InteractionField::GuildId => {
                     if guild_id.is_some() {
                         return Err(DeError::duplicate_field("guild_id"));
@@ -317,6 +461,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     .deserialize_into()
                     .map_err(DeserializerError::into_error)?;
 
+                let entitlements = entitlements.unwrap_or_default();
                 let guild_id = guild_id.unwrap_or_default();
                 let guild_locale = guild_locale.unwrap_or_default();
                 let locale = locale.ok_or_else(|| DeError::missing_field("locale"))?;
@@ -329,6 +474,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     application_id,
                     channel_id,
                     data,
+                    entitlements,
                     guild_id,
                     guild_locale,
                     id,
@@ -348,6 +494,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     .deserialize_into()
                     .map_err(DeserializerError::into_error)?;
 
+                let entitlements = entitlements.unwrap_or_default();
                 let guild_id = guild_id.unwrap_or_default();
                 let guild_locale = guild_locale.unwrap_or_default();
                 let locale = locale.ok_or_else(|| DeError::missing_field("locale"))?;
@@ -360,6 +507,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     application_id,
                     channel_id,
                     data,
+                    entitlements,
                     guild_id,
                     guild_locale,
                     id,
@@ -382,6 +530,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     })?;
                 let message = message.ok_or_else(|| DeError::missing_field("message"))?;
 
+                let entitlements = entitlements.unwrap_or_default();
                 let guild_id = guild_id.unwrap_or_default();
                 let guild_locale = guild_locale.unwrap_or_default();
                 let locale = locale.ok_or_else(|| DeError::missing_field("locale"))?;
@@ -392,6 +541,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     application_id,
                     channel_id,
                     data,
+                    entitlements,
                     guild_id,
                     guild_locale,
                     id,
@@ -410,6 +560,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     .deserialize_into()
                     .map_err(|_| DeError::custom("expected ModalInteractionData struct"))?;
 
+                let entitlements = entitlements.unwrap_or_default();
                 let guild_id = guild_id.unwrap_or_default();
                 let guild_locale = guild_locale.unwrap_or_default();
                 let locale = locale.ok_or_else(|| DeError::missing_field("locale"))?;
@@ -420,6 +571,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     application_id,
                     channel_id,
                     data,
+                    entitlements,
                     guild_id,
                     guild_locale,
                     id,
@@ -447,6 +599,7 @@ mod tests {
                 },
                 Interaction, InteractionType,
             },
+            monetization::EntitlementType,
         },
         guild::{PartialMember, Permissions},
         id::{marker::UserMarker, Id},
@@ -454,9 +607,44 @@ mod tests {
         user::User,
         util::datetime::{Timestamp, TimestampParseError},
     };
+    use serde_json::json;
     use serde_test::Token;
     use std::{collections::HashMap, str::FromStr};
 
+    /// Interaction of the given type deserialized with the given data.
+    fn interaction(kind: InteractionType, data: &serde_json::Value) -> Interaction {
+        serde_json::from_value(json!({
+            "application_id": "1",
+            "channel_id": "2",
+            "data": data,
+            "id": "3",
+            "locale": "en-US",
+            "message": {
+                "attachments": [],
+                "author": {
+                    "discriminator": "4444",
+                    "id": "4",
+                    "username": "twilight",
+                },
+                "channel_id": "2",
+                "content": "",
+                "edited_timestamp": null,
+                "embeds": [],
+                "id": "5",
+                "mention_everyone": false,
+                "mention_roles": [],
+                "mentions": [],
+                "pinned": false,
+                "timestamp": "2021-09-19T17:30:45.000000+00:00",
+                "tts": false,
+                "type": 0,
+            },
+            "token": "token",
+            "type": kind as u8,
+        }))
+        .unwrap()
+    }
+
     pub(super) fn user(id: Id<UserMarker>) -> User {
         User {
             accent_color: None,
@@ -537,6 +725,7 @@ mod tests {
                 }),
                 target_id: None,
             },
+            entitlements: Vec::new(),
             guild_id: Some(Id::new(400)),
             guild_locale: Some("de".to_owned()),
             id: Id::new(500),
@@ -608,7 +797,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("member"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::User as u8),
+                Token::U8(CommandOptionType::User.number()),
                 Token::Str("value"),
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("600"),
@@ -741,4 +930,131 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_data_accessors() {
+        let ping = interaction(InteractionType::Ping, &serde_json::Value::Null);
+        let command = interaction(
+            InteractionType::ApplicationCommand,
+            &json!({"id": "6", "name": "command", "type": 1}),
+        );
+        let autocomplete = interaction(
+            InteractionType::ApplicationCommandAutocomplete,
+            &json!({"id": "6", "name": "command", "type": 1}),
+        );
+        let component = interaction(
+            InteractionType::MessageComponent,
+            &json!({"custom_id": "button", "component_type": 2}),
+        );
+        let modal = interaction(
+            InteractionType::ModalSubmit,
+            &json!({"components": [], "custom_id": "modal"}),
+        );
+
+        assert_eq!("command", command.as_command().unwrap().name);
+        assert_eq!("command", autocomplete.as_autocomplete().unwrap().name);
+        assert_eq!("button", component.as_component().unwrap().custom_id);
+        assert_eq!("modal", modal.as_modal_submit().unwrap().custom_id);
+
+        for interaction in [&ping, &autocomplete, &component, &modal] {
+            assert!(interaction.as_command().is_none());
+        }
+
+        for interaction in [&ping, &command, &component, &modal] {
+            assert!(interaction.as_autocomplete().is_none());
+        }
+
+        for interaction in [&ping, &command, &autocomplete, &modal] {
+            assert!(interaction.as_component().is_none());
+        }
+
+        for interaction in [&ping, &command, &autocomplete, &component] {
+            assert!(interaction.as_modal_submit().is_none());
+        }
+    }
+
+    #[test]
+    fn test_message() {
+        let command = interaction(
+            InteractionType::ApplicationCommand,
+            &json!({"id": "6", "name": "command", "type": 1}),
+        );
+        let component = interaction(
+            InteractionType::MessageComponent,
+            &json!({"custom_id": "button", "component_type": 2}),
+        );
+
+        let message = component.message().unwrap();
+        assert_eq!(Id::new(5), message.id);
+        assert_eq!(Id::new(4), message.author.id);
+        assert_eq!(Id::new(2), message.channel_id);
+        assert!(command.message().is_none());
+    }
+
+    #[test]
+    fn test_entitlements() {
+        let value = json!({
+            "application_id": "1",
+            "channel_id": "2",
+            "data": {"id": "6", "name": "premium", "type": 1},
+            "entitlements": [
+                {
+                    "application_id": "1",
+                    "consumed": false,
+                    "deleted": false,
+                    "ends_at": "2100-01-01T00:00:00.000000+00:00",
+                    "id": "7",
+                    "sku_id": "8",
+                    "starts_at": "2023-07-22T04:26:40.000000+00:00",
+                    "type": 8,
+                    "user_id": "4",
+                },
+                {
+                    "application_id": "1",
+                    "consumed": true,
+                    "deleted": false,
+                    "id": "9",
+                    "sku_id": "10",
+                    "type": 1,
+                    "user_id": "4",
+                },
+                {
+                    "application_id": "1",
+                    "consumed": false,
+                    "deleted": false,
+                    "ends_at": "2023-11-14T22:13:20.000000+00:00",
+                    "id": "11",
+                    "sku_id": "12",
+                    "starts_at": "2023-07-22T04:26:40.000000+00:00",
+                    "type": 8,
+                    "user_id": "4",
+                },
+            ],
+            "id": "3",
+            "locale": "en-US",
+            "token": "token",
+            "type": 2,
+        });
+        let command = serde_json::from_value::<Interaction>(value).unwrap();
+
+        let entitlements = command.entitlements();
+        assert_eq!(3, entitlements.len());
+        assert_eq!(Id::new(7), entitlements[0].id);
+        assert_eq!(
+            EntitlementType::ApplicationSubscription,
+            entitlements[0].kind
+        );
+        assert_eq!(Some(Id::new(4)), entitlements[0].user_id);
+
+        assert!(command.has_entitlement(Id::new(8)));
+        // Consumed entitlements no longer grant access.
+        assert!(!command.has_entitlement(Id::new(10)));
+        // Neither do expired entitlements.
+        assert!(!command.has_entitlement(Id::new(12)));
+        assert!(!command.has_entitlement(Id::new(13)));
+
+        let ping = interaction(InteractionType::Ping, &serde_json::Value::Null);
+        assert!(ping.entitlements().is_empty());
+        assert!(!ping.has_entitlement(Id::new(8)));
+    }
 }
@@ -5,7 +5,7 @@ pub use self::data::{
 };
 
 use crate::{
-    application::interaction::InteractionType,
+    application::{interaction::InteractionType, monetization::Entitlement},
     channel::Message,
     guild::PartialMember,
     id::{
@@ -28,6 +28,10 @@ pub struct ModalSubmitInteraction {
     pub channel_id: Id<ChannelMarker>,
     /// Data from the submitted modal.
     pub data: ModalInteractionData,
+    /// Entitlements of the invoking user or guild, for monetized
+    /// applications.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub entitlements: Vec<Entitlement>,
     /// ID of the guild the interaction was invoked in.
     pub guild_id: Option<Id<GuildMarker>>,
     /// Guild's preferred locale.
@@ -154,6 +158,7 @@ mod tests {
                     }]),
                 }]),
             },
+            entitlements: Vec::new(),
             guild_id: Some(Id::<GuildMarker>::new(1)),
             guild_locale: Some("de".to_owned()),
             id: Id::<InteractionMarker>::new(1),
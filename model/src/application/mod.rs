@@ -1,3 +1,4 @@
 pub mod command;
 pub mod component;
 pub mod interaction;
+pub mod monetization;
@@ -0,0 +1,130 @@
+use super::EntitlementType;
+use crate::{
+    id::{
+        marker::{ApplicationMarker, EntitlementMarker, GuildMarker, SkuMarker, UserMarker},
+        Id,
+    },
+    util::Timestamp,
+};
+use serde::{Deserialize, Serialize};
+
+/// Access of a user or guild to a premium offering of an application.
+///
+/// See [Discord Docs/Entitlement Object].
+///
+/// [Discord Docs/Entitlement Object]: https://discord.com/developers/docs/monetization/entitlements#entitlement-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Entitlement {
+    /// ID of the parent application.
+    pub application_id: Id<ApplicationMarker>,
+    /// Whether the entitlement has been consumed.
+    ///
+    /// Only applies to consumable SKUs.
+    #[serde(default)]
+    pub consumed: bool,
+    /// Whether the entitlement has been deleted.
+    #[serde(default)]
+    pub deleted: bool,
+    /// When the entitlement stops being valid.
+    ///
+    /// Not present for test entitlements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<Timestamp>,
+    /// ID of the guild that is granted access to the SKU.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// ID of the entitlement.
+    pub id: Id<EntitlementMarker>,
+    /// Type of the entitlement.
+    #[serde(rename = "type")]
+    pub kind: EntitlementType,
+    /// ID of the SKU the entitlement grants access to.
+    pub sku_id: Id<SkuMarker>,
+    /// When the entitlement starts being valid.
+    ///
+    /// Not present for test entitlements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<Timestamp>,
+    /// ID of the user that is granted access to the SKU.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<Id<UserMarker>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Entitlement, EntitlementType};
+    use crate::{id::Id, util::Timestamp};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        Entitlement: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_entitlement() -> Result<(), Box<dyn Error>> {
+        let ends_at = Timestamp::from_secs(1_700_000_000)?;
+        let starts_at = Timestamp::from_secs(1_690_000_000)?;
+
+        let value = Entitlement {
+            application_id: Id::new(1),
+            consumed: false,
+            deleted: false,
+            ends_at: Some(ends_at),
+            guild_id: None,
+            id: Id::new(2),
+            kind: EntitlementType::ApplicationSubscription,
+            sku_id: Id::new(3),
+            starts_at: Some(starts_at),
+            user_id: Some(Id::new(4)),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Entitlement",
+                    len: 9,
+                },
+                Token::Str("application_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("consumed"),
+                Token::Bool(false),
+                Token::Str("deleted"),
+                Token::Bool(false),
+                Token::Str("ends_at"),
+                Token::Some,
+                Token::Str("2023-11-14T22:13:20.000000+00:00"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("type"),
+                Token::U8(8),
+                Token::Str("sku_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("starts_at"),
+                Token::Some,
+                Token::Str("2023-07-22T04:26:40.000000+00:00"),
+                Token::Str("user_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("4"),
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+}
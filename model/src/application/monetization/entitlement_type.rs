@@ -0,0 +1,126 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Type of an [`Entitlement`].
+///
+/// [`Entitlement`]: super::Entitlement
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EntitlementType {
+    /// Entitlement was purchased by the user.
+    Purchase,
+    /// Entitlement for a Discord Nitro subscription.
+    PremiumSubscription,
+    /// Entitlement was gifted by the developer.
+    DeveloperGift,
+    /// Entitlement was purchased by a developer in application test mode.
+    TestModePurchase,
+    /// Entitlement was granted when the SKU was free.
+    FreePurchase,
+    /// Entitlement was gifted by another user.
+    UserGift,
+    /// Entitlement was claimed by a user for free as a Nitro subscriber.
+    PremiumPurchase,
+    /// Entitlement was purchased as an application subscription.
+    ApplicationSubscription,
+    /// Variant value is unknown to the library.
+    Unknown { value: u8 },
+}
+
+impl EntitlementType {
+    /// Retrieve the number of the entitlement type, used by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::application::monetization::EntitlementType;
+    ///
+    /// assert_eq!(8, EntitlementType::ApplicationSubscription.number());
+    /// ```
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::Purchase => 1,
+            Self::PremiumSubscription => 2,
+            Self::DeveloperGift => 3,
+            Self::TestModePurchase => 4,
+            Self::FreePurchase => 5,
+            Self::UserGift => 6,
+            Self::PremiumPurchase => 7,
+            Self::ApplicationSubscription => 8,
+            Self::Unknown { value } => value,
+        }
+    }
+}
+
+impl From<u8> for EntitlementType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Purchase,
+            2 => Self::PremiumSubscription,
+            3 => Self::DeveloperGift,
+            4 => Self::TestModePurchase,
+            5 => Self::FreePurchase,
+            6 => Self::UserGift,
+            7 => Self::PremiumPurchase,
+            8 => Self::ApplicationSubscription,
+            value => Self::Unknown { value },
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EntitlementType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer).map(u8::into)
+    }
+}
+
+impl Serialize for EntitlementType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.number())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntitlementType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        EntitlementType: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+
+    const MAP: &[(EntitlementType, u8)] = &[
+        (EntitlementType::Purchase, 1),
+        (EntitlementType::PremiumSubscription, 2),
+        (EntitlementType::DeveloperGift, 3),
+        (EntitlementType::TestModePurchase, 4),
+        (EntitlementType::FreePurchase, 5),
+        (EntitlementType::UserGift, 6),
+        (EntitlementType::PremiumPurchase, 7),
+        (EntitlementType::ApplicationSubscription, 8),
+    ];
+
+    #[test]
+    fn test_variants() {
+        for (kind, num) in MAP {
+            serde_test::assert_tokens(kind, &[Token::U8(*num)]);
+            assert_eq!(*kind, EntitlementType::from(*num));
+            assert_eq!(*num, kind.number());
+        }
+    }
+
+    #[test]
+    fn test_unknown() {
+        serde_test::assert_tokens(&EntitlementType::Unknown { value: 99 }, &[Token::U8(99)]);
+    }
+}
@@ -0,0 +1,6 @@
+//! Types for premium offerings of monetized applications.
+
+mod entitlement;
+mod entitlement_type;
+
+pub use self::{entitlement::Entitlement, entitlement_type::EntitlementType};
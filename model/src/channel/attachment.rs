@@ -30,6 +30,37 @@ pub struct Attachment {
     pub width: Option<u64>,
 }
 
+impl Attachment {
+    /// Prefix of the filename of attachments marked as spoilers.
+    pub const SPOILER_PREFIX: &'static str = "SPOILER_";
+
+    /// URL to download the attachment's file from.
+    ///
+    /// This is the [`url`] of the original file, falling back to the
+    /// [`proxy_url`] if it is empty. The proxy URL is served by the media
+    /// proxy, which only serves media files.
+    ///
+    /// [`proxy_url`]: Self::proxy_url
+    /// [`url`]: Self::url
+    pub fn download_url(&self) -> &str {
+        if self.url.is_empty() {
+            &self.proxy_url
+        } else {
+            &self.url
+        }
+    }
+
+    /// Whether the attachment is marked as a spoiler.
+    ///
+    /// Spoiler attachments have a filename starting with
+    /// [`SPOILER_PREFIX`].
+    ///
+    /// [`SPOILER_PREFIX`]: Self::SPOILER_PREFIX
+    pub fn is_spoiler(&self) -> bool {
+        self.filename.starts_with(Self::SPOILER_PREFIX)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Attachment;
@@ -110,4 +141,29 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_spoiler() {
+        let mut attachment = Attachment {
+            content_type: Some("image/png".to_owned()),
+            ephemeral: true,
+            filename: "SPOILER_a.png".to_owned(),
+            description: None,
+            height: Some(184),
+            id: Id::new(1),
+            proxy_url: "https://media.example.com/1.png".to_owned(),
+            size: 13_593,
+            url: "https://cdn.example.com/1.png".to_owned(),
+            width: Some(184),
+        };
+
+        assert!(attachment.is_spoiler());
+        assert_eq!("https://cdn.example.com/1.png", attachment.download_url());
+
+        attachment.filename = "a_SPOILER_.png".to_owned();
+        attachment.url = String::new();
+
+        assert!(!attachment.is_spoiler());
+        assert_eq!("https://media.example.com/1.png", attachment.download_url());
+    }
 }
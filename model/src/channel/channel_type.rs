@@ -50,6 +50,20 @@ impl ChannelType {
         )
     }
 
+    /// Whether the channel type is a direct message between two users.
+    ///
+    /// This is the case for the [`Private`][`Self::Private`] channel type.
+    pub const fn is_dm(self) -> bool {
+        matches!(self, Self::Private)
+    }
+
+    /// Whether the channel type is a direct message between a group of users.
+    ///
+    /// This is the case for the [`Group`][`Self::Group`] channel type.
+    pub const fn is_group_dm(self) -> bool {
+        matches!(self, Self::Group)
+    }
+
     /// Whether the channel type is a thread.
     ///
     /// The following channel types are considered guild channel types:
@@ -99,6 +113,14 @@ mod tests {
     const_assert!(ChannelType::GuildText.is_guild());
     const_assert!(ChannelType::GuildVoice.is_guild());
 
+    const_assert!(ChannelType::Private.is_dm());
+    const_assert!(!ChannelType::Group.is_dm());
+    const_assert!(!ChannelType::GuildText.is_dm());
+
+    const_assert!(ChannelType::Group.is_group_dm());
+    const_assert!(!ChannelType::Private.is_group_dm());
+    const_assert!(!ChannelType::GuildText.is_group_dm());
+
     const_assert!(ChannelType::GuildNewsThread.is_thread());
     const_assert!(ChannelType::GuildPublicThread.is_thread());
     const_assert!(ChannelType::GuildPrivateThread.is_thread());
@@ -0,0 +1,64 @@
+use crate::id::{marker::EmojiMarker, Id};
+use serde::{Deserialize, Serialize};
+
+/// Emoji shown in the add reaction button on posts of a forum channel.
+///
+/// Exactly one of [`emoji_id`] and [`emoji_name`] is set.
+///
+/// [`emoji_id`]: Self::emoji_id
+/// [`emoji_name`]: Self::emoji_name
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct DefaultReaction {
+    /// ID of the guild's custom emoji.
+    pub emoji_id: Option<Id<EmojiMarker>>,
+    /// Unicode character of the emoji.
+    pub emoji_name: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DefaultReaction;
+    use crate::id::Id;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(DefaultReaction: emoji_id, emoji_name);
+    assert_impl_all!(
+        DefaultReaction: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_default_reaction() {
+        let value = DefaultReaction {
+            emoji_id: Some(Id::new(1)),
+            emoji_name: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "DefaultReaction",
+                    len: 2,
+                },
+                Token::Str("emoji_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("emoji_name"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
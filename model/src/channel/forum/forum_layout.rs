@@ -0,0 +1,99 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Layout used to display posts of a forum channel.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ForumLayout {
+    /// No default has been set for the forum channel.
+    NotSet,
+    /// Display posts as a list.
+    ListView,
+    /// Display posts as a collection of tiles.
+    GalleryView,
+    /// Variant value is unknown to the library.
+    Unknown { value: u8 },
+}
+
+impl ForumLayout {
+    /// Retrieve the number of the forum layout, used by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::channel::forum::ForumLayout;
+    ///
+    /// assert_eq!(2, ForumLayout::GalleryView.number());
+    /// ```
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::NotSet => 0,
+            Self::ListView => 1,
+            Self::GalleryView => 2,
+            Self::Unknown { value } => value,
+        }
+    }
+}
+
+impl From<u8> for ForumLayout {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NotSet,
+            1 => Self::ListView,
+            2 => Self::GalleryView,
+            value => Self::Unknown { value },
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ForumLayout {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer).map(u8::into)
+    }
+}
+
+impl Serialize for ForumLayout {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.number())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ForumLayout;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        ForumLayout: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+
+    const MAP: &[(ForumLayout, u8)] = &[
+        (ForumLayout::NotSet, 0),
+        (ForumLayout::ListView, 1),
+        (ForumLayout::GalleryView, 2),
+    ];
+
+    #[test]
+    fn test_variants() {
+        for (kind, num) in MAP {
+            serde_test::assert_tokens(kind, &[Token::U8(*num)]);
+            assert_eq!(*kind, ForumLayout::from(*num));
+            assert_eq!(*num, kind.number());
+        }
+    }
+
+    #[test]
+    fn test_unknown() {
+        serde_test::assert_tokens(&ForumLayout::Unknown { value: 99 }, &[Token::U8(99)]);
+    }
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Order used to sort posts of a forum channel.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ForumSortOrder {
+    /// Sort posts by their latest activity.
+    LatestActivity,
+    /// Sort posts by when they were created, from newest to oldest.
+    CreationDate,
+    /// Variant value is unknown to the library.
+    Unknown { value: u8 },
+}
+
+impl ForumSortOrder {
+    /// Retrieve the number of the sort order, used by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::channel::forum::ForumSortOrder;
+    ///
+    /// assert_eq!(1, ForumSortOrder::CreationDate.number());
+    /// ```
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::LatestActivity => 0,
+            Self::CreationDate => 1,
+            Self::Unknown { value } => value,
+        }
+    }
+}
+
+impl From<u8> for ForumSortOrder {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::LatestActivity,
+            1 => Self::CreationDate,
+            value => Self::Unknown { value },
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ForumSortOrder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer).map(u8::into)
+    }
+}
+
+impl Serialize for ForumSortOrder {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.number())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ForumSortOrder;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        ForumSortOrder: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+
+    const MAP: &[(ForumSortOrder, u8)] = &[
+        (ForumSortOrder::LatestActivity, 0),
+        (ForumSortOrder::CreationDate, 1),
+    ];
+
+    #[test]
+    fn test_variants() {
+        for (kind, num) in MAP {
+            serde_test::assert_tokens(kind, &[Token::U8(*num)]);
+            assert_eq!(*kind, ForumSortOrder::from(*num));
+            assert_eq!(*num, kind.number());
+        }
+    }
+
+    #[test]
+    fn test_unknown() {
+        serde_test::assert_tokens(&ForumSortOrder::Unknown { value: 99 }, &[Token::U8(99)]);
+    }
+}
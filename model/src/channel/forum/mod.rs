@@ -0,0 +1,11 @@
+//! Types for configuring forum channels and their posts.
+
+mod default_reaction;
+mod forum_layout;
+mod forum_sort_order;
+mod forum_tag;
+
+pub use self::{
+    default_reaction::DefaultReaction, forum_layout::ForumLayout, forum_sort_order::ForumSortOrder,
+    forum_tag::ForumTag,
+};
@@ -29,6 +29,110 @@ pub enum MessageType {
     ThreadStarterMessage = 21,
     GuildInviteReminder = 22,
     ContextMenuCommand = 23,
+    /// Message is an auto moderation action.
+    AutoModerationAction = 24,
+    /// Message is a role subscription purchase or renewal.
+    RoleSubscriptionPurchase = 25,
+    /// Message is an upsell to a premium interaction.
+    InteractionPremiumUpsell = 26,
+    /// Message is a stage instance starting.
+    StageStart = 27,
+    /// Message is a stage instance ending.
+    StageEnd = 28,
+    /// Message is a user becoming a speaker in a stage instance.
+    StageSpeaker = 29,
+    /// Message is a stage instance's topic changing.
+    StageTopic = 31,
+    /// Message is a subscription to an application's premium offering.
+    GuildApplicationPremiumSubscription = 32,
+    /// Message is the guild's incident alert mode being enabled.
+    GuildIncidentAlertModeEnabled = 36,
+    /// Message is the guild's incident alert mode being disabled.
+    GuildIncidentAlertModeDisabled = 37,
+    /// Message is a raid being reported in the guild.
+    GuildIncidentReportRaid = 38,
+    /// Message is a reported raid being marked as a false alarm.
+    GuildIncidentReportFalseAlarm = 39,
+    /// Message is a purchase notification.
+    PurchaseNotification = 44,
+    /// Message is the result of a poll.
+    PollResult = 46,
+}
+
+impl MessageType {
+    /// Whether the message type is a guild being boosted.
+    ///
+    /// The following message types are considered boosts:
+    ///
+    /// - [`UserPremiumSub`][`Self::UserPremiumSub`]
+    /// - [`UserPremiumSubTier1`][`Self::UserPremiumSubTier1`]
+    /// - [`UserPremiumSubTier2`][`Self::UserPremiumSubTier2`]
+    /// - [`UserPremiumSubTier3`][`Self::UserPremiumSubTier3`]
+    pub const fn is_boost(self) -> bool {
+        matches!(
+            self,
+            Self::UserPremiumSub
+                | Self::UserPremiumSubTier1
+                | Self::UserPremiumSubTier2
+                | Self::UserPremiumSubTier3
+        )
+    }
+
+    /// Whether the message type is a change in a guild's discovery status.
+    ///
+    /// The following message types are considered guild discovery types:
+    ///
+    /// - [`GuildDiscoveryDisqualified`][`Self::GuildDiscoveryDisqualified`]
+    /// - [`GuildDiscoveryGracePeriodFinalWarning`][`Self::GuildDiscoveryGracePeriodFinalWarning`]
+    /// - [`GuildDiscoveryGracePeriodInitialWarning`][`Self::GuildDiscoveryGracePeriodInitialWarning`]
+    /// - [`GuildDiscoveryRequalified`][`Self::GuildDiscoveryRequalified`]
+    pub const fn is_guild_discovery(self) -> bool {
+        matches!(
+            self,
+            Self::GuildDiscoveryDisqualified
+                | Self::GuildDiscoveryGracePeriodFinalWarning
+                | Self::GuildDiscoveryGracePeriodInitialWarning
+                | Self::GuildDiscoveryRequalified
+        )
+    }
+
+    /// Whether the message type is a change to a stage instance.
+    ///
+    /// The following message types are considered stage types:
+    ///
+    /// - [`StageEnd`][`Self::StageEnd`]
+    /// - [`StageSpeaker`][`Self::StageSpeaker`]
+    /// - [`StageStart`][`Self::StageStart`]
+    /// - [`StageTopic`][`Self::StageTopic`]
+    pub const fn is_stage(self) -> bool {
+        matches!(
+            self,
+            Self::StageEnd | Self::StageSpeaker | Self::StageStart | Self::StageTopic
+        )
+    }
+
+    /// Whether the message type is a system message.
+    ///
+    /// System messages are sent by Discord to notify of an event, such as a
+    /// member joining or a message being pinned, rather than being authored by
+    /// a user or bot. All message types are system messages except for the
+    /// following:
+    ///
+    /// - [`ChatInputCommand`][`Self::ChatInputCommand`]
+    /// - [`ContextMenuCommand`][`Self::ContextMenuCommand`]
+    /// - [`Regular`][`Self::Regular`]
+    /// - [`Reply`][`Self::Reply`]
+    /// - [`ThreadStarterMessage`][`Self::ThreadStarterMessage`]
+    pub const fn is_system(self) -> bool {
+        !matches!(
+            self,
+            Self::ChatInputCommand
+                | Self::ContextMenuCommand
+                | Self::Regular
+                | Self::Reply
+                | Self::ThreadStarterMessage
+        )
+    }
 }
 
 impl TryFrom<u8> for MessageType {
@@ -59,6 +163,20 @@ impl TryFrom<u8> for MessageType {
             21 => MessageType::ThreadStarterMessage,
             22 => MessageType::GuildInviteReminder,
             23 => MessageType::ContextMenuCommand,
+            24 => MessageType::AutoModerationAction,
+            25 => MessageType::RoleSubscriptionPurchase,
+            26 => MessageType::InteractionPremiumUpsell,
+            27 => MessageType::StageStart,
+            28 => MessageType::StageEnd,
+            29 => MessageType::StageSpeaker,
+            31 => MessageType::StageTopic,
+            32 => MessageType::GuildApplicationPremiumSubscription,
+            36 => MessageType::GuildIncidentAlertModeEnabled,
+            37 => MessageType::GuildIncidentAlertModeDisabled,
+            38 => MessageType::GuildIncidentReportRaid,
+            39 => MessageType::GuildIncidentReportFalseAlarm,
+            44 => MessageType::PurchaseNotification,
+            46 => MessageType::PollResult,
             _ => return Err(ConversionError::MessageType(value)),
         };
 
@@ -102,6 +220,57 @@ mod tests {
         serde_test::assert_tokens(&MessageType::ThreadStarterMessage, &[Token::U8(21)]);
         serde_test::assert_tokens(&MessageType::GuildInviteReminder, &[Token::U8(22)]);
         serde_test::assert_tokens(&MessageType::ContextMenuCommand, &[Token::U8(23)]);
+        serde_test::assert_tokens(&MessageType::AutoModerationAction, &[Token::U8(24)]);
+        serde_test::assert_tokens(&MessageType::RoleSubscriptionPurchase, &[Token::U8(25)]);
+        serde_test::assert_tokens(&MessageType::InteractionPremiumUpsell, &[Token::U8(26)]);
+        serde_test::assert_tokens(&MessageType::StageStart, &[Token::U8(27)]);
+        serde_test::assert_tokens(&MessageType::StageEnd, &[Token::U8(28)]);
+        serde_test::assert_tokens(&MessageType::StageSpeaker, &[Token::U8(29)]);
+        serde_test::assert_tokens(&MessageType::StageTopic, &[Token::U8(31)]);
+        serde_test::assert_tokens(
+            &MessageType::GuildApplicationPremiumSubscription,
+            &[Token::U8(32)],
+        );
+        serde_test::assert_tokens(
+            &MessageType::GuildIncidentAlertModeEnabled,
+            &[Token::U8(36)],
+        );
+        serde_test::assert_tokens(
+            &MessageType::GuildIncidentAlertModeDisabled,
+            &[Token::U8(37)],
+        );
+        serde_test::assert_tokens(&MessageType::GuildIncidentReportRaid, &[Token::U8(38)]);
+        serde_test::assert_tokens(
+            &MessageType::GuildIncidentReportFalseAlarm,
+            &[Token::U8(39)],
+        );
+        serde_test::assert_tokens(&MessageType::PurchaseNotification, &[Token::U8(44)]);
+        serde_test::assert_tokens(&MessageType::PollResult, &[Token::U8(46)]);
+    }
+
+    #[test]
+    fn test_predicates() {
+        let boost = serde_json::from_str::<MessageType>("9").unwrap();
+        assert_eq!(MessageType::UserPremiumSubTier1, boost);
+        assert!(boost.is_boost());
+        assert!(boost.is_system());
+        assert!(!boost.is_stage());
+
+        let join = serde_json::from_str::<MessageType>("7").unwrap();
+        assert_eq!(MessageType::GuildMemberJoin, join);
+        assert!(join.is_system());
+        assert!(!join.is_boost());
+
+        let stage = serde_json::from_str::<MessageType>("31").unwrap();
+        assert_eq!(MessageType::StageTopic, stage);
+        assert!(stage.is_stage());
+        assert!(stage.is_system());
+
+        assert!(MessageType::GuildDiscoveryDisqualified.is_guild_discovery());
+        assert!(!MessageType::Regular.is_system());
+        assert!(!MessageType::Reply.is_system());
+        assert!(!MessageType::ChatInputCommand.is_system());
+        assert!(!MessageType::ThreadStarterMessage.is_system());
     }
 
     #[test]
@@ -186,6 +355,16 @@ mod tests {
             MessageType::try_from(23).unwrap(),
             MessageType::ContextMenuCommand
         );
+        assert_eq!(
+            MessageType::try_from(24).unwrap(),
+            MessageType::AutoModerationAction
+        );
+        assert_eq!(MessageType::try_from(31).unwrap(), MessageType::StageTopic);
+        assert_eq!(MessageType::try_from(46).unwrap(), MessageType::PollResult);
+        assert_eq!(
+            MessageType::try_from(30).unwrap_err(),
+            ConversionError::MessageType(30)
+        );
         assert_eq!(
             MessageType::try_from(250).unwrap_err(),
             ConversionError::MessageType(250)
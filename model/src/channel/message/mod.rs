@@ -8,14 +8,17 @@ mod flags;
 mod interaction;
 mod kind;
 mod mention;
+mod nonce;
 mod reaction;
 mod reference;
+mod snapshot;
 
 pub use self::{
     activity::MessageActivity, activity_type::MessageActivityType,
     allowed_mentions::AllowedMentions, application::MessageApplication, flags::MessageFlags,
-    interaction::MessageInteraction, kind::MessageType, mention::Mention,
-    reaction::MessageReaction, reference::MessageReference, sticker::Sticker,
+    interaction::MessageInteraction, kind::MessageType, mention::Mention, nonce::Nonce,
+    reaction::MessageReaction, reference::MessageReference, snapshot::MessageSnapshot,
+    snapshot::MessageSnapshotFields, sticker::Sticker,
 };
 
 use self::sticker::MessageSticker;
@@ -117,6 +120,16 @@ pub struct Message {
     pub mention_everyone: bool,
     pub mention_roles: Vec<Id<RoleMarker>>,
     pub mentions: Vec<Mention>,
+    /// Minimal copies of the messages forwarded by this message.
+    ///
+    /// Refer to [`forwarded_message`] for retrieving the forwarded message.
+    ///
+    /// [`forwarded_message`]: Self::forwarded_message
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub message_snapshots: Vec<MessageSnapshot>,
+    /// Nonce the message was sent with, used to verify that it was sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<Nonce>,
     pub pinned: bool,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub reactions: Vec<MessageReaction>,
@@ -140,12 +153,24 @@ pub struct Message {
     pub webhook_id: Option<Id<WebhookMarker>>,
 }
 
+impl Message {
+    /// Copy of the message forwarded by this message, if any.
+    ///
+    /// Forwarded messages have no content of their own; their content,
+    /// attachments, and embeds are instead contained within the snapshot.
+    pub fn forwarded_message(&self) -> Option<&MessageSnapshotFields> {
+        self.message_snapshots
+            .first()
+            .map(|snapshot| &snapshot.message)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         sticker::{MessageSticker, StickerFormatType},
         ChannelMention, Message, MessageActivity, MessageActivityType, MessageApplication,
-        MessageFlags, MessageReaction, MessageReference, MessageType,
+        MessageFlags, MessageReaction, MessageReference, MessageType, Nonce,
     };
     use crate::{
         channel::{ChannelType, ReactionType},
@@ -212,6 +237,8 @@ mod tests {
             mention_everyone: false,
             mention_roles: Vec::new(),
             mentions: Vec::new(),
+            message_snapshots: Vec::new(),
+            nonce: None,
             pinned: false,
             reactions: Vec::new(),
             reference: None,
@@ -411,6 +438,8 @@ mod tests {
             mention_everyone: false,
             mention_roles: Vec::new(),
             mentions: Vec::new(),
+            message_snapshots: Vec::new(),
+            nonce: None,
             pinned: false,
             reactions: vec![MessageReaction {
                 count: 7,
@@ -638,4 +667,192 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_message_forwarded() {
+        let raw = r#"{
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "3",
+                "username": "test"
+            },
+            "channel_id": "2",
+            "content": "",
+            "edited_timestamp": null,
+            "embeds": [],
+            "flags": 16384,
+            "id": "4",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "message_reference": {
+                "channel_id": "5",
+                "guild_id": "1",
+                "message_id": "6"
+            },
+            "message_snapshots": [
+                {
+                    "message": {
+                        "attachments": [],
+                        "content": "forwarded content",
+                        "edited_timestamp": null,
+                        "embeds": [],
+                        "flags": 0,
+                        "mention_roles": [],
+                        "mentions": [],
+                        "timestamp": "2021-09-19T17:30:45.000000+00:00",
+                        "type": 0
+                    }
+                }
+            ],
+            "pinned": false,
+            "timestamp": "2021-09-19T17:31:00.000000+00:00",
+            "tts": false,
+            "type": 0
+        }"#;
+
+        let message = serde_json::from_str::<Message>(raw).unwrap();
+        assert_eq!(1, message.message_snapshots.len());
+        assert!(message.content.is_empty());
+
+        let forwarded = message.forwarded_message().unwrap();
+        assert_eq!("forwarded content", forwarded.content);
+        assert_eq!(MessageType::Regular, forwarded.kind);
+        assert_eq!(Some(MessageFlags::empty()), forwarded.flags);
+
+        let serialized = serde_json::to_string(&message).unwrap();
+        assert_eq!(
+            message,
+            serde_json::from_str::<Message>(&serialized).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_message_forwarded_none() {
+        let raw = r#"{
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "3",
+                "username": "test"
+            },
+            "channel_id": "2",
+            "content": "ping",
+            "edited_timestamp": null,
+            "embeds": [],
+            "id": "4",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "timestamp": "2021-09-19T17:31:00.000000+00:00",
+            "tts": false,
+            "type": 0
+        }"#;
+
+        let message = serde_json::from_str::<Message>(raw).unwrap();
+        assert!(message.message_snapshots.is_empty());
+        assert!(message.forwarded_message().is_none());
+        assert!(!serde_json::to_string(&message)
+            .unwrap()
+            .contains("message_snapshots"));
+    }
+
+    #[test]
+    fn test_message_sticker_items() {
+        let raw = r#"{
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "3",
+                "username": "test"
+            },
+            "channel_id": "2",
+            "content": "",
+            "edited_timestamp": null,
+            "embeds": [],
+            "id": "4",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "sticker_items": [
+                {"format_type": 1, "id": "5", "name": "png"},
+                {"format_type": 4, "id": "6", "name": "gif"}
+            ],
+            "timestamp": "2021-09-19T17:31:00.000000+00:00",
+            "tts": false,
+            "type": 0
+        }"#;
+
+        let message = serde_json::from_str::<Message>(raw).unwrap();
+        assert_eq!(
+            [
+                MessageSticker {
+                    format_type: StickerFormatType::Png,
+                    id: Id::new(5),
+                    name: "png".to_owned(),
+                },
+                MessageSticker {
+                    format_type: StickerFormatType::Gif,
+                    id: Id::new(6),
+                    name: "gif".to_owned(),
+                },
+            ]
+            .as_slice(),
+            message.sticker_items
+        );
+
+        let serialized = serde_json::to_string(&message).unwrap();
+        assert_eq!(
+            message,
+            serde_json::from_str::<Message>(&serialized).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_message_nonce() {
+        let raw = |nonce: &str| {
+            format!(
+                r#"{{
+                    "attachments": [],
+                    "author": {{
+                        "avatar": null,
+                        "discriminator": "0001",
+                        "id": "3",
+                        "username": "test"
+                    }},
+                    "channel_id": "2",
+                    "content": "ping",
+                    "edited_timestamp": null,
+                    "embeds": [],
+                    "id": "4",
+                    "mention_everyone": false,
+                    "mention_roles": [],
+                    "mentions": [],
+                    "nonce": {nonce},
+                    "pinned": false,
+                    "timestamp": "2021-09-19T17:31:00.000000+00:00",
+                    "tts": false,
+                    "type": 0
+                }}"#
+            )
+        };
+
+        let message = serde_json::from_str::<Message>(&raw("1234567")).unwrap();
+        assert_eq!(Some(Nonce::Integer(1_234_567)), message.nonce);
+        assert!(serde_json::to_string(&message)
+            .unwrap()
+            .contains(r#""nonce":1234567"#));
+
+        let message = serde_json::from_str::<Message>(&raw(r#""1234567""#)).unwrap();
+        assert_eq!(Some(Nonce::String("1234567".to_owned())), message.nonce);
+        assert!(serde_json::to_string(&message)
+            .unwrap()
+            .contains(r#""nonce":"1234567""#));
+    }
 }
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Nonce used to verify that a message was sent.
+///
+/// Nonces may be sent as either an integer or a string, and are received in
+/// the same form they were sent in.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Nonce {
+    /// Integer nonce.
+    Integer(u64),
+    /// String nonce.
+    String(String),
+}
+
+impl Display for Nonce {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Integer(integer) => Display::fmt(integer, f),
+            Self::String(string) => f.write_str(string),
+        }
+    }
+}
+
+impl From<u64> for Nonce {
+    fn from(integer: u64) -> Self {
+        Self::Integer(integer)
+    }
+}
+
+impl From<String> for Nonce {
+    fn from(string: String) -> Self {
+        Self::String(string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Nonce;
+    use serde_test::Token;
+
+    #[test]
+    fn test_integer() {
+        let value = Nonce::Integer(1_234_567);
+
+        serde_test::assert_tokens(&value, &[Token::U64(1_234_567)]);
+        serde_test::assert_de_tokens(&value, &[Token::I64(1_234_567)]);
+        assert_eq!("1234567", value.to_string());
+    }
+
+    #[test]
+    fn test_string() {
+        let value = Nonce::String("1234567".to_owned());
+
+        serde_test::assert_tokens(&value, &[Token::Str("1234567")]);
+        assert_eq!("1234567", value.to_string());
+    }
+}
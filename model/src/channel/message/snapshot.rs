@@ -0,0 +1,158 @@
+use super::{sticker::MessageSticker, Mention, MessageFlags, MessageType};
+use crate::{
+    application::component::Component,
+    channel::{embed::Embed, Attachment},
+    id::{marker::RoleMarker, Id},
+    util::Timestamp,
+};
+use serde::{Deserialize, Serialize};
+
+/// Copy of a message forwarded into another channel.
+///
+/// Refer to [`Message::forwarded_message`] for retrieving the forwarded
+/// message of a message.
+///
+/// [`Message::forwarded_message`]: super::Message::forwarded_message
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct MessageSnapshot {
+    /// Minimal copy of the forwarded message.
+    pub message: MessageSnapshotFields,
+}
+
+/// Subset of a [`Message`]'s fields retained by a [`MessageSnapshot`].
+///
+/// [`Message`]: super::Message
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct MessageSnapshotFields {
+    /// List of attachments.
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    /// List of provided components, such as buttons.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+    /// Content of the message.
+    pub content: String,
+    /// Timestamp of when the message was last edited.
+    pub edited_timestamp: Option<Timestamp>,
+    /// List of embeds.
+    #[serde(default)]
+    pub embeds: Vec<Embed>,
+    /// Flags of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<MessageFlags>,
+    /// Type of the message.
+    #[serde(rename = "type")]
+    pub kind: MessageType,
+    /// List of mentioned roles.
+    #[serde(default)]
+    pub mention_roles: Vec<Id<RoleMarker>>,
+    /// List of mentioned users.
+    #[serde(default)]
+    pub mentions: Vec<Mention>,
+    /// Stickers within the message.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sticker_items: Vec<MessageSticker>,
+    /// Timestamp of when the message was created.
+    pub timestamp: Timestamp,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MessageSnapshot, MessageSnapshotFields};
+    use crate::{
+        channel::message::{MessageFlags, MessageType},
+        id::Id,
+        util::Timestamp,
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(MessageSnapshot: message);
+    assert_fields!(
+        MessageSnapshotFields: attachments,
+        components,
+        content,
+        edited_timestamp,
+        embeds,
+        flags,
+        kind,
+        mention_roles,
+        mentions,
+        sticker_items,
+        timestamp
+    );
+    assert_impl_all!(
+        MessageSnapshot: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_message_snapshot() {
+        let value = MessageSnapshot {
+            message: MessageSnapshotFields {
+                attachments: Vec::new(),
+                components: Vec::new(),
+                content: "forwarded".to_owned(),
+                edited_timestamp: None,
+                embeds: Vec::new(),
+                flags: Some(MessageFlags::empty()),
+                kind: MessageType::Regular,
+                mention_roles: Vec::from([Id::new(1)]),
+                mentions: Vec::new(),
+                sticker_items: Vec::new(),
+                timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+            },
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "MessageSnapshot",
+                    len: 1,
+                },
+                Token::Str("message"),
+                Token::Struct {
+                    name: "MessageSnapshotFields",
+                    len: 9,
+                },
+                Token::Str("attachments"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("content"),
+                Token::Str("forwarded"),
+                Token::Str("edited_timestamp"),
+                Token::None,
+                Token::Str("embeds"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("flags"),
+                Token::Some,
+                Token::U64(0),
+                Token::Str("type"),
+                Token::U8(0),
+                Token::Str("mention_roles"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::SeqEnd,
+                Token::Str("mentions"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("timestamp"),
+                Token::Str("2021-09-19T17:30:45.000000+00:00"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -7,25 +7,55 @@ use std::{
 /// Format type of a [`Sticker`].
 ///
 /// [`Sticker`]: super::Sticker
-#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum StickerFormatType {
     /// Sticker format is a PNG.
-    Png = 1,
+    Png,
     /// Sticker format is an APNG.
-    Apng = 2,
+    Apng,
     /// Sticker format is a LOTTIE.
-    Lottie = 3,
+    Lottie,
+    /// Sticker format is a GIF.
+    Gif,
+    /// Variant value is unknown to the library.
+    Unknown { value: u8 },
+}
+
+impl StickerFormatType {
+    /// Retrieve the number of the sticker format type, used by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::channel::message::sticker::StickerFormatType;
+    ///
+    /// assert_eq!(3, StickerFormatType::Lottie.number());
+    /// ```
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::Png => 1,
+            Self::Apng => 2,
+            Self::Lottie => 3,
+            Self::Gif => 4,
+            Self::Unknown { value } => value,
+        }
+    }
 }
 
 impl TryFrom<u8> for StickerFormatType {
     type Error = StickerFormatTypeConversionError;
 
+    /// Convert a number into a known sticker format type.
+    ///
+    /// Unlike deserialization, which maps unknown numbers to
+    /// [`StickerFormatType::Unknown`], this fails for numbers that don't map
+    /// to a known variant.
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         Ok(match value {
             1 => StickerFormatType::Png,
             2 => StickerFormatType::Apng,
             3 => StickerFormatType::Lottie,
+            4 => StickerFormatType::Gif,
             _ => return Err(StickerFormatTypeConversionError { value }),
         })
     }
@@ -57,31 +87,60 @@ impl Display for StickerFormatTypeConversionError {
 
 impl Error for StickerFormatTypeConversionError {}
 
+impl<'de> Deserialize<'de> for StickerFormatType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u8::deserialize(deserializer)?;
+
+        Ok(Self::try_from(value).unwrap_or(Self::Unknown { value }))
+    }
+}
+
+impl Serialize for StickerFormatType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.number())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::StickerFormatType;
+    use serde::{Deserialize, Serialize};
     use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        StickerFormatType: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+
+    const MAP: &[(StickerFormatType, u8)] = &[
+        (StickerFormatType::Png, 1),
+        (StickerFormatType::Apng, 2),
+        (StickerFormatType::Lottie, 3),
+        (StickerFormatType::Gif, 4),
+    ];
 
     #[test]
     fn test_variants() {
-        serde_test::assert_tokens(&StickerFormatType::Png, &[Token::U8(1)]);
-        serde_test::assert_tokens(&StickerFormatType::Apng, &[Token::U8(2)]);
-        serde_test::assert_tokens(&StickerFormatType::Lottie, &[Token::U8(3)]);
+        for (kind, num) in MAP {
+            serde_test::assert_tokens(kind, &[Token::U8(*num)]);
+            assert_eq!(*kind, StickerFormatType::try_from(*num).unwrap());
+            assert_eq!(*num, kind.number());
+        }
     }
 
     #[test]
-    fn test_conversions() {
-        assert_eq!(
-            StickerFormatType::try_from(1).unwrap(),
-            StickerFormatType::Png
-        );
-        assert_eq!(
-            StickerFormatType::try_from(2).unwrap(),
-            StickerFormatType::Apng
-        );
-        assert_eq!(
-            StickerFormatType::try_from(3).unwrap(),
-            StickerFormatType::Lottie
-        );
+    fn test_unknown() {
+        serde_test::assert_tokens(&StickerFormatType::Unknown { value: 99 }, &[Token::U8(99)]);
+        assert_eq!(5, StickerFormatType::try_from(5).unwrap_err().value());
     }
 }
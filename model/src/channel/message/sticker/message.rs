@@ -3,10 +3,19 @@ use crate::id::{marker::StickerMarker, Id};
 use serde::{Deserialize, Serialize};
 
 /// Smallest amount of data required to render a sticker.
+///
+/// Messages contain these partial stickers as their [`sticker_items`], while
+/// the full [`Sticker`] can be retrieved by its ID.
+///
+/// [`Sticker`]: super::Sticker
+/// [`sticker_items`]: crate::channel::Message::sticker_items
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct MessageSticker {
+    /// Format of the sticker's image.
     pub format_type: StickerFormatType,
+    /// ID of the sticker.
     pub id: Id<StickerMarker>,
+    /// Name of the sticker.
     pub name: String,
 }
 
@@ -49,7 +58,7 @@ mod tests {
                     len: 3,
                 },
                 Token::Str("format_type"),
-                Token::U8(StickerFormatType::Lottie as u8),
+                Token::U8(3),
                 Token::Str("id"),
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
@@ -125,7 +125,7 @@ mod tests {
                 Token::Some,
                 Token::Str("foo2"),
                 Token::Str("format_type"),
-                Token::U8(StickerFormatType::Png as u8),
+                Token::U8(1),
                 Token::Str("id"),
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
@@ -188,7 +188,7 @@ mod tests {
                 Token::Some,
                 Token::Str("sticker"),
                 Token::Str("format_type"),
-                Token::U8(StickerFormatType::Png as u8),
+                Token::U8(1),
                 Token::Str("guild_id"),
                 Token::Some,
                 Token::NewtypeStruct { name: "Id" },
@@ -1,4 +1,5 @@
 pub mod embed;
+pub mod forum;
 pub mod message;
 pub mod permission_overwrite;
 pub mod stage_instance;
@@ -28,7 +29,10 @@ pub use self::{
 };
 
 use crate::{
-    channel::thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
+    channel::{
+        forum::{DefaultReaction, ForumLayout, ForumSortOrder, ForumTag},
+        thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
+    },
     id::{
         marker::{ApplicationMarker, ChannelMarker, GuildMarker, MessageMarker, UserMarker},
         Id,
@@ -74,12 +78,30 @@ pub struct Channel {
     /// ID of the application that created the channel.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_id: Option<Id<ApplicationMarker>>,
+    /// Tags that can be applied to posts of a forum channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_tags: Option<Vec<ForumTag>>,
     /// Bitrate setting of audio channels.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bitrate: Option<u64>,
     /// Default duration before the channel's threads archive.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_auto_archive_duration: Option<AutoArchiveDuration>,
+    /// Default layout used to display posts of a forum channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_forum_layout: Option<ForumLayout>,
+    /// Default emoji shown in the add reaction button on posts of a forum
+    /// channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_reaction_emoji: Option<DefaultReaction>,
+    /// Default order used to sort posts of a forum channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_sort_order: Option<ForumSortOrder>,
+    /// Initial rate limit per user of threads created in the channel.
+    ///
+    /// The maximum is 21600 seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_thread_rate_limit_per_user: Option<u16>,
     /// ID of the guild the channel is in.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
@@ -146,6 +168,12 @@ pub struct Channel {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rate_limit_per_user: Option<u64>,
     /// Recipients of the channel.
+    ///
+    /// Present for [direct message] and [group direct message] channels,
+    /// excluding the current user.
+    ///
+    /// [direct message]: Self::is_dm
+    /// [group direct message]: Self::is_group_dm
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recipients: Option<Vec<User>>,
     /// ID of the voice region for the channel.
@@ -169,16 +197,56 @@ pub struct Channel {
     pub video_quality_mode: Option<VideoQualityMode>,
 }
 
+impl Channel {
+    /// Whether the channel is a direct message between two users.
+    ///
+    /// Refer to [`ChannelType::is_dm`] for more information.
+    pub const fn is_dm(&self) -> bool {
+        self.kind.is_dm()
+    }
+
+    /// Whether the channel is a direct message between a group of users.
+    ///
+    /// Refer to [`ChannelType::is_group_dm`] for more information.
+    pub const fn is_group_dm(&self) -> bool {
+        self.kind.is_group_dm()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{AutoArchiveDuration, Channel, ChannelType, ThreadMember, ThreadMetadata};
     use crate::{
-        channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        channel::{
+            forum::{DefaultReaction, ForumLayout, ForumSortOrder, ForumTag},
+            permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        },
         guild::Permissions,
         id::Id,
+        user::User,
         util::Timestamp,
     };
 
+    fn recipient(id: u64, name: &str) -> User {
+        User {
+            accent_color: None,
+            avatar: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            id: Id::new(id),
+            locale: None,
+            mfa_enabled: None,
+            name: name.to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
     // The deserializer for GuildChannel should skip over fields names that
     // it couldn't deserialize.
     #[test]
@@ -212,8 +280,13 @@ mod tests {
 
         let value = Channel {
             application_id: None,
+            available_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
             guild_id: Some(Id::new(1)),
             icon: None,
             id: Id::new(2),
@@ -247,8 +320,13 @@ mod tests {
     fn test_guild_category_channel_deserialization() {
         let value = Channel {
             application_id: None,
+            available_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
             guild_id: Some(Id::new(2)),
             icon: None,
             id: Id::new(1),
@@ -294,8 +372,13 @@ mod tests {
     fn test_guild_news_channel_deserialization() {
         let value = Channel {
             application_id: None,
+            available_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
             guild_id: Some(Id::new(2)),
             icon: None,
             id: Id::new(1),
@@ -341,6 +424,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_guild_forum_deserialization() {
+        let value = Channel {
+            application_id: None,
+            available_tags: Some(Vec::from([ForumTag {
+                emoji_id: None,
+                emoji_name: Some("🐛".to_owned()),
+                id: Id::new(3),
+                moderated: false,
+                name: "bug".to_owned(),
+            }])),
+            bitrate: None,
+            default_auto_archive_duration: None,
+            default_forum_layout: Some(ForumLayout::ListView),
+            default_reaction_emoji: Some(DefaultReaction {
+                emoji_id: None,
+                emoji_name: Some("👍".to_owned()),
+            }),
+            default_sort_order: Some(ForumSortOrder::LatestActivity),
+            default_thread_rate_limit_per_user: Some(30),
+            guild_id: Some(Id::new(1)),
+            icon: None,
+            id: Id::new(2),
+            invitable: None,
+            kind: ChannelType::GuildForum,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            member: None,
+            member_count: None,
+            message_count: None,
+            name: Some("forum".to_owned()),
+            newly_created: None,
+            nsfw: Some(false),
+            owner_id: None,
+            parent_id: None,
+            permission_overwrites: None,
+            position: Some(4),
+            rate_limit_per_user: None,
+            recipients: None,
+            rtc_region: None,
+            thread_metadata: None,
+            topic: None,
+            user_limit: None,
+            video_quality_mode: None,
+        };
+
+        assert_eq!(
+            value,
+            serde_json::from_value(serde_json::json!({
+                "available_tags": [{
+                    "emoji_id": null,
+                    "emoji_name": "🐛",
+                    "id": "3",
+                    "moderated": false,
+                    "name": "bug",
+                }],
+                "default_forum_layout": 1,
+                "default_reaction_emoji": {
+                    "emoji_id": null,
+                    "emoji_name": "👍",
+                },
+                "default_sort_order": 0,
+                "default_thread_rate_limit_per_user": 30,
+                "guild_id": "1",
+                "id": "2",
+                "name": "forum",
+                "nsfw": false,
+                "position": 4,
+                "type": ChannelType::GuildForum,
+            }))
+            .unwrap()
+        );
+    }
+
     #[test]
     fn test_guild_news_thread_deserialization() {
         let timestamp = Timestamp::from_secs(1_632_074_792).expect("non zero");
@@ -348,8 +505,13 @@ mod tests {
 
         let value = Channel {
             application_id: None,
+            available_tags: None,
             bitrate: None,
             default_auto_archive_duration: Some(AutoArchiveDuration::Hour),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
             guild_id: Some(Id::new(1)),
             icon: None,
             id: Id::new(6),
@@ -429,8 +591,13 @@ mod tests {
 
         let value = Channel {
             application_id: None,
+            available_tags: None,
             bitrate: None,
             default_auto_archive_duration: Some(AutoArchiveDuration::Hour),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
             guild_id: Some(Id::new(1)),
             icon: None,
             id: Id::new(6),
@@ -511,8 +678,13 @@ mod tests {
 
         let value = Channel {
             application_id: None,
+            available_tags: None,
             bitrate: None,
             default_auto_archive_duration: Some(AutoArchiveDuration::Hour),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
             guild_id: Some(Id::new(1)),
             icon: None,
             id: Id::new(6),
@@ -599,4 +771,125 @@ mod tests {
             .unwrap()
         )
     }
+
+    #[test]
+    fn test_private_channel_deserialization() {
+        let value = Channel {
+            application_id: None,
+            available_tags: None,
+            bitrate: None,
+            default_auto_archive_duration: None,
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
+            guild_id: None,
+            icon: None,
+            id: Id::new(1),
+            invitable: None,
+            kind: ChannelType::Private,
+            last_message_id: Some(Id::new(2)),
+            last_pin_timestamp: None,
+            member: None,
+            member_count: None,
+            message_count: None,
+            name: None,
+            newly_created: None,
+            nsfw: None,
+            owner_id: None,
+            parent_id: None,
+            permission_overwrites: None,
+            position: None,
+            rate_limit_per_user: None,
+            recipients: Some(Vec::from([recipient(3, "alice")])),
+            rtc_region: None,
+            thread_metadata: None,
+            topic: None,
+            user_limit: None,
+            video_quality_mode: None,
+        };
+
+        let channel: Channel = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "last_message_id": "2",
+            "recipients": [{
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "3",
+                "username": "alice",
+            }],
+            "type": 1,
+        }))
+        .unwrap();
+
+        assert_eq!(value, channel);
+        assert!(channel.is_dm());
+        assert!(!channel.is_group_dm());
+        assert!(!channel.kind.is_guild());
+    }
+
+    #[test]
+    fn test_group_channel_deserialization() {
+        let value = Channel {
+            application_id: None,
+            available_tags: None,
+            bitrate: None,
+            default_auto_archive_duration: None,
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
+            guild_id: None,
+            icon: None,
+            id: Id::new(1),
+            invitable: None,
+            kind: ChannelType::Group,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            member: None,
+            member_count: None,
+            message_count: None,
+            name: Some("friends".to_owned()),
+            newly_created: None,
+            nsfw: None,
+            owner_id: Some(Id::new(3)),
+            parent_id: None,
+            permission_overwrites: None,
+            position: None,
+            rate_limit_per_user: None,
+            recipients: Some(Vec::from([recipient(3, "alice"), recipient(4, "bob")])),
+            rtc_region: None,
+            thread_metadata: None,
+            topic: None,
+            user_limit: None,
+            video_quality_mode: None,
+        };
+
+        let channel: Channel = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "name": "friends",
+            "owner_id": "3",
+            "recipients": [
+                {
+                    "avatar": null,
+                    "discriminator": "0001",
+                    "id": "3",
+                    "username": "alice",
+                },
+                {
+                    "avatar": null,
+                    "discriminator": "0001",
+                    "id": "4",
+                    "username": "bob",
+                },
+            ],
+            "type": 3,
+        }))
+        .unwrap();
+
+        assert_eq!(value, channel);
+        assert!(channel.is_group_dm());
+        assert!(!channel.is_dm());
+        assert!(!channel.kind.is_guild());
+    }
 }
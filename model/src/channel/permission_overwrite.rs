@@ -1,6 +1,9 @@
 use crate::{
     guild::Permissions,
-    id::{marker::GenericMarker, Id},
+    id::{
+        marker::{GenericMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
 };
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
@@ -26,10 +29,161 @@ pub enum PermissionOverwriteType {
     Role = 0,
 }
 
+/// Apply a channel's permission overwrites to a member's base permissions.
+///
+/// `permissions` are the member's guild-level permissions, computed from the
+/// `@everyone` role and the member's roles. `member_roles` are the IDs of the
+/// member's roles other than `@everyone`. `overwrites` are the overwrites of
+/// the channel; overwrites targeting other members or roles the member doesn't
+/// have are ignored. The `@everyone` role's ID is the ID of the guild.
+///
+/// Overwrites are applied in the order defined by Discord:
+///
+/// 1. the `@everyone` role's overwrite;
+/// 2. the overwrites of the member's roles, with the denials of all roles
+///    being applied before the allowances of all roles, so that a role
+///    allowing a permission takes precedence over another role denying it;
+/// 3. the member's overwrite.
+///
+/// Members with the [`ADMINISTRATOR`] permission have all permissions
+/// regardless of overwrites.
+///
+/// Refer to [Discord Docs/Permission Overwrites] for more information.
+///
+/// # Examples
+///
+/// A role allowing a permission the `@everyone` role denies:
+///
+/// ```
+/// use twilight_model::{
+///     channel::permission_overwrite::{self, PermissionOverwrite, PermissionOverwriteType},
+///     guild::Permissions,
+///     id::Id,
+/// };
+///
+/// let guild_id = Id::new(1);
+/// let role_id = Id::new(2);
+/// let member_roles = [role_id];
+/// let user_id = Id::new(3);
+///
+/// let overwrites = [
+///     PermissionOverwrite {
+///         allow: Permissions::empty(),
+///         deny: Permissions::SEND_MESSAGES,
+///         id: guild_id.cast(),
+///         kind: PermissionOverwriteType::Role,
+///     },
+///     PermissionOverwrite {
+///         allow: Permissions::SEND_MESSAGES,
+///         deny: Permissions::empty(),
+///         id: role_id.cast(),
+///         kind: PermissionOverwriteType::Role,
+///     },
+/// ];
+///
+/// let base = Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL;
+/// let permissions =
+///     permission_overwrite::apply_overwrites(base, &overwrites, guild_id, &member_roles, user_id);
+///
+/// assert_eq!(base, permissions);
+///
+/// // Without the role the `@everyone` overwrite applies.
+/// let permissions =
+///     permission_overwrite::apply_overwrites(base, &overwrites, guild_id, &[], user_id);
+///
+/// assert_eq!(Permissions::VIEW_CHANNEL, permissions);
+/// ```
+///
+/// [`ADMINISTRATOR`]: Permissions::ADMINISTRATOR
+/// [Discord Docs/Permission Overwrites]: https://discord.com/developers/docs/topics/permissions#permission-overwrites
+#[must_use = "applying overwrites has no effect if the permissions are unused"]
+pub const fn apply_overwrites(
+    permissions: Permissions,
+    overwrites: &[PermissionOverwrite],
+    guild_id: Id<GuildMarker>,
+    member_roles: &[Id<RoleMarker>],
+    user_id: Id<UserMarker>,
+) -> Permissions {
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        return Permissions::all();
+    }
+
+    let mut everyone = Overwrite::NONE;
+    let mut roles = Overwrite::NONE;
+    let mut member = Overwrite::NONE;
+
+    let mut idx = 0;
+
+    while idx < overwrites.len() {
+        let overwrite = &overwrites[idx];
+        let id = overwrite.id.get();
+
+        match overwrite.kind {
+            PermissionOverwriteType::Member if id == user_id.get() => {
+                member = member.merge(overwrite);
+            }
+            PermissionOverwriteType::Role if id == guild_id.get() => {
+                everyone = everyone.merge(overwrite);
+            }
+            PermissionOverwriteType::Role if has_role(member_roles, id) => {
+                roles = roles.merge(overwrite);
+            }
+            PermissionOverwriteType::Member | PermissionOverwriteType::Role => {}
+        }
+
+        idx += 1;
+    }
+
+    member.apply(roles.apply(everyone.apply(permissions)))
+}
+
+/// Whether a member's roles contain a role.
+const fn has_role(member_roles: &[Id<RoleMarker>], role_id: u64) -> bool {
+    let mut idx = 0;
+
+    while idx < member_roles.len() {
+        if member_roles[idx].get() == role_id {
+            return true;
+        }
+
+        idx += 1;
+    }
+
+    false
+}
+
+/// Combined allowances and denials of overwrites on the same level of the
+/// hierarchy.
+///
+/// Mutable bitflag operations aren't `const`, so the bits are operated on
+/// directly.
+struct Overwrite {
+    allow: u64,
+    deny: u64,
+}
+
+impl Overwrite {
+    const NONE: Self = Self { allow: 0, deny: 0 };
+
+    const fn merge(self, overwrite: &PermissionOverwrite) -> Self {
+        Self {
+            allow: self.allow | overwrite.allow.bits(),
+            deny: self.deny | overwrite.deny.bits(),
+        }
+    }
+
+    const fn apply(&self, permissions: Permissions) -> Permissions {
+        Permissions::from_bits_truncate((permissions.bits() & !self.deny) | self.allow)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{PermissionOverwrite, PermissionOverwriteType, Permissions};
-    use crate::id::Id;
+    use super::{apply_overwrites, PermissionOverwrite, PermissionOverwriteType, Permissions};
+    use crate::id::{
+        marker::{GuildMarker, RoleMarker, UserMarker},
+        Id,
+    };
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all, const_assert_eq};
@@ -137,4 +291,132 @@ mod tests {
         serde_test::assert_tokens(&PermissionOverwriteType::Member, &[Token::U8(1)]);
         serde_test::assert_tokens(&PermissionOverwriteType::Role, &[Token::U8(0)]);
     }
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+    const ROLE_ID: Id<RoleMarker> = Id::new(2);
+    const OTHER_ROLE_ID: Id<RoleMarker> = Id::new(3);
+    const USER_ID: Id<UserMarker> = Id::new(4);
+
+    const fn role(
+        id: Id<RoleMarker>,
+        allow: Permissions,
+        deny: Permissions,
+    ) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow,
+            deny,
+            id: id.cast(),
+            kind: PermissionOverwriteType::Role,
+        }
+    }
+
+    const fn member(allow: Permissions, deny: Permissions) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow,
+            deny,
+            id: USER_ID.cast(),
+            kind: PermissionOverwriteType::Member,
+        }
+    }
+
+    /// Test Discord's example of a channel's `@everyone` overwrite denying
+    /// sending messages, with a role overwrite allowing it again.
+    #[test]
+    fn test_apply_everyone_then_roles() {
+        let base = Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL;
+        let overwrites = [
+            role(
+                GUILD_ID.cast(),
+                Permissions::empty(),
+                Permissions::SEND_MESSAGES,
+            ),
+            role(ROLE_ID, Permissions::SEND_MESSAGES, Permissions::empty()),
+        ];
+
+        assert_eq!(
+            base,
+            apply_overwrites(base, &overwrites, GUILD_ID, &[ROLE_ID], USER_ID)
+        );
+        assert_eq!(
+            Permissions::VIEW_CHANNEL,
+            apply_overwrites(base, &overwrites, GUILD_ID, &[OTHER_ROLE_ID], USER_ID)
+        );
+    }
+
+    /// Test that a role allowing a permission takes precedence over another
+    /// role denying it, regardless of the order of the overwrites.
+    #[test]
+    fn test_apply_role_allow_over_deny() {
+        let base = Permissions::VIEW_CHANNEL;
+        let overwrites = [
+            role(ROLE_ID, Permissions::ATTACH_FILES, Permissions::empty()),
+            role(
+                OTHER_ROLE_ID,
+                Permissions::empty(),
+                Permissions::ATTACH_FILES,
+            ),
+        ];
+
+        assert_eq!(
+            Permissions::ATTACH_FILES | Permissions::VIEW_CHANNEL,
+            apply_overwrites(
+                base,
+                &overwrites,
+                GUILD_ID,
+                &[ROLE_ID, OTHER_ROLE_ID],
+                USER_ID
+            )
+        );
+    }
+
+    /// Test that the member's overwrite takes precedence over role overwrites,
+    /// and that overwrites of other members are ignored.
+    #[test]
+    fn test_apply_member() {
+        let base = Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL;
+        let mut overwrites = [
+            role(ROLE_ID, Permissions::EMBED_LINKS, Permissions::empty()),
+            member(
+                Permissions::empty(),
+                Permissions::EMBED_LINKS | Permissions::SEND_MESSAGES,
+            ),
+            PermissionOverwrite {
+                allow: Permissions::all(),
+                deny: Permissions::empty(),
+                id: Id::new(5),
+                kind: PermissionOverwriteType::Member,
+            },
+        ];
+
+        assert_eq!(
+            Permissions::VIEW_CHANNEL,
+            apply_overwrites(base, &overwrites, GUILD_ID, &[ROLE_ID], USER_ID)
+        );
+
+        overwrites[1] = member(Permissions::MANAGE_MESSAGES, Permissions::empty());
+        assert_eq!(
+            base | Permissions::EMBED_LINKS | Permissions::MANAGE_MESSAGES,
+            apply_overwrites(base, &overwrites, GUILD_ID, &[ROLE_ID], USER_ID)
+        );
+    }
+
+    #[test]
+    fn test_apply_administrator() {
+        let overwrites = [role(
+            GUILD_ID.cast(),
+            Permissions::empty(),
+            Permissions::all(),
+        )];
+
+        assert_eq!(
+            Permissions::all(),
+            apply_overwrites(
+                Permissions::ADMINISTRATOR,
+                &overwrites,
+                GUILD_ID,
+                &[],
+                USER_ID
+            )
+        );
+    }
 }
@@ -3,12 +3,37 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
 #[repr(u8)]
 pub enum WebhookType {
+    /// Webhooks that post messages to channels with a token.
     Incoming = 1,
+    /// Webhooks that crosspost messages from a followed news channel.
     ChannelFollower = 2,
     /// Webhooks used with interactions.
     Application = 3,
 }
 
+impl WebhookType {
+    /// Whether the webhook type is [`Application`][`Self::Application`].
+    pub const fn is_application(self) -> bool {
+        matches!(self, Self::Application)
+    }
+
+    /// Whether the webhook type is [`ChannelFollower`][`Self::ChannelFollower`].
+    ///
+    /// Follower webhooks have their [`source_channel`] and [`source_guild`]
+    /// set to the news channel and guild being followed.
+    ///
+    /// [`source_channel`]: super::Webhook::source_channel
+    /// [`source_guild`]: super::Webhook::source_guild
+    pub const fn is_follower(self) -> bool {
+        matches!(self, Self::ChannelFollower)
+    }
+
+    /// Whether the webhook type is [`Incoming`][`Self::Incoming`].
+    pub const fn is_incoming(self) -> bool {
+        matches!(self, Self::Incoming)
+    }
+}
+
 impl Default for WebhookType {
     fn default() -> Self {
         Self::Incoming
@@ -25,6 +50,16 @@ mod tests {
         assert_eq!(WebhookType::Incoming, WebhookType::default());
     }
 
+    #[test]
+    fn test_predicates() {
+        assert!(WebhookType::Application.is_application());
+        assert!(!WebhookType::Application.is_follower());
+        assert!(WebhookType::ChannelFollower.is_follower());
+        assert!(!WebhookType::ChannelFollower.is_incoming());
+        assert!(WebhookType::Incoming.is_incoming());
+        assert!(!WebhookType::Incoming.is_application());
+    }
+
     #[test]
     fn test_variants() {
         serde_test::assert_tokens(&WebhookType::Incoming, &[Token::U8(1)]);
@@ -261,4 +261,87 @@ mod tests {
             ],
         );
     }
+
+    /// Test that a webhook following a news channel round-trips with its
+    /// source channel and guild.
+    #[test]
+    fn test_webhook_follower() {
+        let value = Webhook {
+            application_id: None,
+            avatar: None,
+            channel_id: Id::new(1),
+            guild_id: Some(Id::new(2)),
+            id: Id::new(3),
+            kind: WebhookType::ChannelFollower,
+            name: Some("news follower".to_owned()),
+            source_channel: Some(WebhookChannel {
+                id: Id::new(4),
+                name: "announcements".into(),
+            }),
+            source_guild: Some(WebhookGuild {
+                icon: None,
+                id: Id::new(5),
+                name: "news guild".into(),
+            }),
+            token: None,
+            url: None,
+            user: None,
+        };
+
+        assert!(value.kind.is_follower());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Webhook",
+                    len: 8,
+                },
+                Token::Str("avatar"),
+                Token::None,
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("type"),
+                Token::U8(2),
+                Token::Str("name"),
+                Token::Some,
+                Token::Str("news follower"),
+                Token::Str("source_channel"),
+                Token::Some,
+                Token::Struct {
+                    name: "WebhookChannel",
+                    len: 2,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("4"),
+                Token::Str("name"),
+                Token::Str("announcements"),
+                Token::StructEnd,
+                Token::Str("source_guild"),
+                Token::Some,
+                Token::Struct {
+                    name: "WebhookGuild",
+                    len: 3,
+                },
+                Token::Str("icon"),
+                Token::None,
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("5"),
+                Token::Str("name"),
+                Token::Str("news guild"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -38,9 +38,31 @@ pub enum CloseCode {
     /// An invalid intent was sent.
     InvalidIntents = 4013,
     /// A disallowed intent was sent, may need allowlisting.
+    ///
+    /// Privileged intents must be enabled for the application in the developer
+    /// portal before they can be used.
     DisallowedIntents = 4014,
 }
 
+impl CloseCode {
+    /// Whether a new session can be started after the gateway closed the
+    /// connection with this code.
+    ///
+    /// Codes caused by the configuration of the client, such as an invalid
+    /// token or disallowed intents, can't be recovered from by reconnecting.
+    pub const fn can_reconnect(self) -> bool {
+        !matches!(
+            self,
+            Self::AuthenticationFailed
+                | Self::InvalidShard
+                | Self::ShardingRequired
+                | Self::InvalidApiVersion
+                | Self::InvalidIntents
+                | Self::DisallowedIntents
+        )
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct CloseCodeConversionError {
     code: u16,
@@ -160,4 +182,14 @@ mod tests {
         );
         assert!(CloseCode::try_from(5000).is_err());
     }
+
+    #[test]
+    fn test_can_reconnect() {
+        assert!(CloseCode::UnknownError.can_reconnect());
+        assert!(CloseCode::InvalidSequence.can_reconnect());
+        assert!(CloseCode::SessionTimedOut.can_reconnect());
+        assert!(!CloseCode::AuthenticationFailed.can_reconnect());
+        assert!(!CloseCode::InvalidIntents.can_reconnect());
+        assert!(!CloseCode::DisallowedIntents.can_reconnect());
+    }
 }
@@ -22,6 +22,7 @@ pub enum DispatchEvent {
     ChannelUpdate(Box<ChannelUpdate>),
     CommandPermissionsUpdate(CommandPermissionsUpdate),
     GiftCodeUpdate,
+    GuildAuditLogEntryCreate(Box<GuildAuditLogEntryCreate>),
     GuildCreate(Box<GuildCreate>),
     GuildDelete(GuildDelete),
     GuildEmojisUpdate(GuildEmojisUpdate),
@@ -31,6 +32,10 @@ pub enum DispatchEvent {
     GuildScheduledEventUpdate(Box<GuildScheduledEventUpdate>),
     GuildScheduledEventUserAdd(GuildScheduledEventUserAdd),
     GuildScheduledEventUserRemove(GuildScheduledEventUserRemove),
+    GuildSoundboardSoundCreate(Box<GuildSoundboardSoundCreate>),
+    GuildSoundboardSoundDelete(GuildSoundboardSoundDelete),
+    GuildSoundboardSoundUpdate(Box<GuildSoundboardSoundUpdate>),
+    GuildSoundboardSoundsUpdate(GuildSoundboardSoundsUpdate),
     GuildUpdate(Box<GuildUpdate>),
     IntegrationCreate(Box<IntegrationCreate>),
     IntegrationDelete(IntegrationDelete),
@@ -57,6 +62,7 @@ pub enum DispatchEvent {
     RoleCreate(RoleCreate),
     RoleDelete(RoleDelete),
     RoleUpdate(RoleUpdate),
+    SoundboardSounds(SoundboardSounds),
     StageInstanceCreate(StageInstanceCreate),
     StageInstanceDelete(StageInstanceDelete),
     StageInstanceUpdate(StageInstanceUpdate),
@@ -69,6 +75,7 @@ pub enum DispatchEvent {
     TypingStart(Box<TypingStart>),
     UnavailableGuild(UnavailableGuild),
     UserUpdate(UserUpdate),
+    VoiceChannelEffectSend(VoiceChannelEffectSend),
     VoiceServerUpdate(VoiceServerUpdate),
     VoiceStateUpdate(Box<VoiceStateUpdate>),
     WebhooksUpdate(WebhooksUpdate),
@@ -86,6 +93,7 @@ impl DispatchEvent {
             Self::ChannelUpdate(_) => EventType::ChannelUpdate,
             Self::CommandPermissionsUpdate(_) => EventType::CommandPermissionsUpdate,
             Self::GiftCodeUpdate => EventType::GiftCodeUpdate,
+            Self::GuildAuditLogEntryCreate(_) => EventType::GuildAuditLogEntryCreate,
             Self::GuildCreate(_) => EventType::GuildCreate,
             Self::GuildDelete(_) => EventType::GuildDelete,
             Self::GuildEmojisUpdate(_) => EventType::GuildEmojisUpdate,
@@ -95,6 +103,10 @@ impl DispatchEvent {
             Self::GuildScheduledEventUpdate(_) => EventType::GuildScheduledEventUpdate,
             Self::GuildScheduledEventUserAdd(_) => EventType::GuildScheduledEventUserAdd,
             Self::GuildScheduledEventUserRemove(_) => EventType::GuildScheduledEventUserRemove,
+            Self::GuildSoundboardSoundCreate(_) => EventType::GuildSoundboardSoundCreate,
+            Self::GuildSoundboardSoundDelete(_) => EventType::GuildSoundboardSoundDelete,
+            Self::GuildSoundboardSoundUpdate(_) => EventType::GuildSoundboardSoundUpdate,
+            Self::GuildSoundboardSoundsUpdate(_) => EventType::GuildSoundboardSoundsUpdate,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
             Self::IntegrationCreate(_) => EventType::IntegrationCreate,
             Self::IntegrationDelete(_) => EventType::IntegrationDelete,
@@ -121,6 +133,7 @@ impl DispatchEvent {
             Self::RoleCreate(_) => EventType::RoleCreate,
             Self::RoleDelete(_) => EventType::RoleDelete,
             Self::RoleUpdate(_) => EventType::RoleUpdate,
+            Self::SoundboardSounds(_) => EventType::SoundboardSounds,
             Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
             Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
             Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
@@ -133,6 +146,7 @@ impl DispatchEvent {
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
             Self::UserUpdate(_) => EventType::UserUpdate,
+            Self::VoiceChannelEffectSend(_) => EventType::VoiceChannelEffectSend,
             Self::VoiceServerUpdate(_) => EventType::VoiceServerUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
             Self::WebhooksUpdate(_) => EventType::WebhooksUpdate,
@@ -153,6 +167,7 @@ impl TryFrom<Event> for DispatchEvent {
             Event::ChannelUpdate(v) => Self::ChannelUpdate(v),
             Event::CommandPermissionsUpdate(v) => Self::CommandPermissionsUpdate(v),
             Event::GiftCodeUpdate => Self::GiftCodeUpdate,
+            Event::GuildAuditLogEntryCreate(v) => Self::GuildAuditLogEntryCreate(v),
             Event::GuildCreate(v) => Self::GuildCreate(v),
             Event::GuildDelete(v) => Self::GuildDelete(v),
             Event::GuildEmojisUpdate(v) => Self::GuildEmojisUpdate(v),
@@ -162,6 +177,10 @@ impl TryFrom<Event> for DispatchEvent {
             Event::GuildScheduledEventUpdate(v) => Self::GuildScheduledEventUpdate(v),
             Event::GuildScheduledEventUserAdd(v) => Self::GuildScheduledEventUserAdd(v),
             Event::GuildScheduledEventUserRemove(v) => Self::GuildScheduledEventUserRemove(v),
+            Event::GuildSoundboardSoundCreate(v) => Self::GuildSoundboardSoundCreate(v),
+            Event::GuildSoundboardSoundDelete(v) => Self::GuildSoundboardSoundDelete(v),
+            Event::GuildSoundboardSoundUpdate(v) => Self::GuildSoundboardSoundUpdate(v),
+            Event::GuildSoundboardSoundsUpdate(v) => Self::GuildSoundboardSoundsUpdate(v),
             Event::GuildUpdate(v) => Self::GuildUpdate(v),
             Event::IntegrationCreate(v) => Self::IntegrationCreate(v),
             Event::IntegrationDelete(v) => Self::IntegrationDelete(v),
@@ -188,6 +207,7 @@ impl TryFrom<Event> for DispatchEvent {
             Event::RoleCreate(v) => Self::RoleCreate(v),
             Event::RoleDelete(v) => Self::RoleDelete(v),
             Event::RoleUpdate(v) => Self::RoleUpdate(v),
+            Event::SoundboardSounds(v) => Self::SoundboardSounds(v),
             Event::StageInstanceCreate(v) => Self::StageInstanceCreate(v),
             Event::StageInstanceDelete(v) => Self::StageInstanceDelete(v),
             Event::StageInstanceUpdate(v) => Self::StageInstanceUpdate(v),
@@ -200,6 +220,7 @@ impl TryFrom<Event> for DispatchEvent {
             Event::TypingStart(v) => Self::TypingStart(v),
             Event::UnavailableGuild(v) => Self::UnavailableGuild(v),
             Event::UserUpdate(v) => Self::UserUpdate(v),
+            Event::VoiceChannelEffectSend(v) => Self::VoiceChannelEffectSend(v),
             Event::VoiceServerUpdate(v) => Self::VoiceServerUpdate(v),
             Event::VoiceStateUpdate(v) => Self::VoiceStateUpdate(v),
             Event::WebhooksUpdate(v) => Self::WebhooksUpdate(v),
@@ -250,6 +271,9 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             }
             "GUILD_BAN_ADD" => DispatchEvent::BanAdd(BanAdd::deserialize(deserializer)?),
             "GUILD_BAN_REMOVE" => DispatchEvent::BanRemove(BanRemove::deserialize(deserializer)?),
+            "GUILD_AUDIT_LOG_ENTRY_CREATE" => DispatchEvent::GuildAuditLogEntryCreate(Box::new(
+                GuildAuditLogEntryCreate::deserialize(deserializer)?,
+            )),
             "GUILD_CREATE" => {
                 DispatchEvent::GuildCreate(Box::new(GuildCreate::deserialize(deserializer)?))
             }
@@ -275,6 +299,18 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             "GUILD_SCHEDULED_EVENT_USER_REMOVE" => DispatchEvent::GuildScheduledEventUserRemove(
                 GuildScheduledEventUserRemove::deserialize(deserializer)?,
             ),
+            "GUILD_SOUNDBOARD_SOUND_CREATE" => DispatchEvent::GuildSoundboardSoundCreate(Box::new(
+                GuildSoundboardSoundCreate::deserialize(deserializer)?,
+            )),
+            "GUILD_SOUNDBOARD_SOUND_DELETE" => DispatchEvent::GuildSoundboardSoundDelete(
+                GuildSoundboardSoundDelete::deserialize(deserializer)?,
+            ),
+            "GUILD_SOUNDBOARD_SOUND_UPDATE" => DispatchEvent::GuildSoundboardSoundUpdate(Box::new(
+                GuildSoundboardSoundUpdate::deserialize(deserializer)?,
+            )),
+            "GUILD_SOUNDBOARD_SOUNDS_UPDATE" => DispatchEvent::GuildSoundboardSoundsUpdate(
+                GuildSoundboardSoundsUpdate::deserialize(deserializer)?,
+            ),
             "GUILD_MEMBERS_CHUNK" => {
                 DispatchEvent::MemberChunk(MemberChunk::deserialize(deserializer)?)
             }
@@ -355,6 +391,9 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
 
                 DispatchEvent::Resumed
             }
+            "SOUNDBOARD_SOUNDS" => {
+                DispatchEvent::SoundboardSounds(SoundboardSounds::deserialize(deserializer)?)
+            }
             "STAGE_INSTANCE_CREATE" => {
                 DispatchEvent::StageInstanceCreate(StageInstanceCreate::deserialize(deserializer)?)
             }
@@ -386,6 +425,9 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
                 DispatchEvent::TypingStart(Box::new(TypingStart::deserialize(deserializer)?))
             }
             "USER_UPDATE" => DispatchEvent::UserUpdate(UserUpdate::deserialize(deserializer)?),
+            "VOICE_CHANNEL_EFFECT_SEND" => DispatchEvent::VoiceChannelEffectSend(
+                VoiceChannelEffectSend::deserialize(deserializer)?,
+            ),
             "VOICE_SERVER_UPDATE" => {
                 DispatchEvent::VoiceServerUpdate(VoiceServerUpdate::deserialize(deserializer)?)
             }
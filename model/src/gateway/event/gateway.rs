@@ -421,6 +421,12 @@ impl<'de> Visitor<'de> for GatewayEventVisitor<'_> {
                     VALID_OPCODES,
                 ))
             }
+            OpCode::RequestSoundboardSounds => {
+                return Err(DeError::unknown_variant(
+                    "RequestSoundboardSounds",
+                    VALID_OPCODES,
+                ))
+            }
             OpCode::Resume => return Err(DeError::unknown_variant("Resume", VALID_OPCODES)),
             OpCode::PresenceUpdate => {
                 return Err(DeError::unknown_variant("PresenceUpdate", VALID_OPCODES))
@@ -519,7 +525,10 @@ impl Serialize for GatewayEvent {
 #[cfg(test)]
 mod tests {
     use super::{DispatchEvent, GatewayEvent, GatewayEventDeserializer, OpCode};
-    use crate::{gateway::payload::incoming::RoleDelete, id::Id, test::image_hash};
+    use crate::{
+        gateway::payload::incoming::RoleDelete, guild::audit_log::AuditLogEventType, id::Id,
+        test::image_hash,
+    };
     use serde::de::DeserializeSeed;
     use serde_json::de::Deserializer;
     use serde_test::Token;
@@ -542,6 +551,41 @@ mod tests {
         assert!(matches!(event, GatewayEvent::Dispatch(7, _)));
     }
 
+    #[test]
+    fn test_deserialize_dispatch_guild_audit_log_entry_create() {
+        let input = r#"{
+            "d": {
+                "action_type": 22,
+                "guild_id": "1",
+                "id": "2",
+                "reason": "raid",
+                "target_id": "3",
+                "user_id": "4"
+            },
+            "op": 0,
+            "s": 8,
+            "t": "GUILD_AUDIT_LOG_ENTRY_CREATE"
+        }"#;
+
+        let deserializer = GatewayEventDeserializer::from_json(input).unwrap();
+        let mut json_deserializer = Deserializer::from_str(input);
+        let event = deserializer.deserialize(&mut json_deserializer).unwrap();
+
+        let entry = match event {
+            GatewayEvent::Dispatch(8, dispatch) => match *dispatch {
+                DispatchEvent::GuildAuditLogEntryCreate(entry) => entry,
+                other => panic!("unexpected dispatch event: {other:?}"),
+            },
+            other => panic!("unexpected event: {other:?}"),
+        };
+        assert_eq!(AuditLogEventType::MemberBanAdd, entry.action_type);
+        assert_eq!(Some(Id::new(1)), entry.guild_id);
+        assert_eq!(Id::new(2), entry.id);
+        assert_eq!(Some("raid"), entry.reason.as_deref());
+        assert_eq!(Some(Id::new(3)), entry.target_id);
+        assert_eq!(Some(Id::new(4)), entry.user_id);
+    }
+
     #[test]
     fn test_deserialize_dispatch_guild_update() {
         let input = format!(
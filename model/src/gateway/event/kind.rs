@@ -20,6 +20,7 @@ pub enum EventType {
     GatewayInvalidateSession,
     GatewayReconnect,
     GiftCodeUpdate,
+    GuildAuditLogEntryCreate,
     GuildCreate,
     GuildDelete,
     GuildEmojisUpdate,
@@ -29,6 +30,10 @@ pub enum EventType {
     GuildScheduledEventUpdate,
     GuildScheduledEventUserAdd,
     GuildScheduledEventUserRemove,
+    GuildSoundboardSoundCreate,
+    GuildSoundboardSoundDelete,
+    GuildSoundboardSoundUpdate,
+    GuildSoundboardSoundsUpdate,
     GuildStickersUpdate,
     GuildUpdate,
     IntegrationCreate,
@@ -74,6 +79,7 @@ pub enum EventType {
     ShardReconnecting,
     ShardPayload,
     ShardResuming,
+    SoundboardSounds,
     StageInstanceCreate,
     StageInstanceDelete,
     StageInstanceUpdate,
@@ -86,6 +92,7 @@ pub enum EventType {
     TypingStart,
     UnavailableGuild,
     UserUpdate,
+    VoiceChannelEffectSend,
     VoiceServerUpdate,
     VoiceStateUpdate,
     WebhooksUpdate,
@@ -102,6 +109,7 @@ impl EventType {
             Self::ChannelUpdate => Some("CHANNEL_UPDATE"),
             Self::CommandPermissionsUpdate => Some("APPLICATION_COMMAND_PERMISSIONS_UPDATE"),
             Self::GiftCodeUpdate => Some("GIFT_CODE_UPDATE"),
+            Self::GuildAuditLogEntryCreate => Some("GUILD_AUDIT_LOG_ENTRY_CREATE"),
             Self::GuildCreate => Some("GUILD_CREATE"),
             Self::GuildDelete => Some("GUILD_DELETE"),
             Self::GuildEmojisUpdate => Some("GUILD_EMOJIS_UPDATE"),
@@ -111,6 +119,10 @@ impl EventType {
             Self::GuildScheduledEventUpdate => Some("GUILD_SCHEDULED_EVENT_UPDATE"),
             Self::GuildScheduledEventUserAdd => Some("GUILD_SCHEDULED_EVENT_USER_ADD"),
             Self::GuildScheduledEventUserRemove => Some("GUILD_SCHEDULED_EVENT_USER_REMOVE"),
+            Self::GuildSoundboardSoundCreate => Some("GUILD_SOUNDBOARD_SOUND_CREATE"),
+            Self::GuildSoundboardSoundDelete => Some("GUILD_SOUNDBOARD_SOUND_DELETE"),
+            Self::GuildSoundboardSoundUpdate => Some("GUILD_SOUNDBOARD_SOUND_UPDATE"),
+            Self::GuildSoundboardSoundsUpdate => Some("GUILD_SOUNDBOARD_SOUNDS_UPDATE"),
             Self::GuildStickersUpdate => Some("GUILD_STICKERS_UPDATE"),
             Self::GuildUpdate => Some("GUILD_UPDATE"),
             Self::IntegrationCreate => Some("INTEGRATION_CREATE"),
@@ -138,6 +150,7 @@ impl EventType {
             Self::RoleCreate => Some("GUILD_ROLE_CREATE"),
             Self::RoleDelete => Some("GUILD_ROLE_DELETE"),
             Self::RoleUpdate => Some("GUILD_ROLE_UPDATE"),
+            Self::SoundboardSounds => Some("SOUNDBOARD_SOUNDS"),
             Self::StageInstanceCreate => Some("STAGE_INSTANCE_CREATE"),
             Self::StageInstanceDelete => Some("STAGE_INSTANCE_DELETE"),
             Self::StageInstanceUpdate => Some("STAGE_INSTANCE_UPDATE"),
@@ -150,6 +163,7 @@ impl EventType {
             Self::TypingStart => Some("TYPING_START"),
             Self::UnavailableGuild => Some("UNAVAILABLE_GUILD"),
             Self::UserUpdate => Some("USER_UPDATE"),
+            Self::VoiceChannelEffectSend => Some("VOICE_CHANNEL_EFFECT_SEND"),
             Self::VoiceServerUpdate => Some("VOICE_SERVER_UPDATE"),
             Self::VoiceStateUpdate => Some("VOICE_STATE_UPDATE"),
             Self::WebhooksUpdate => Some("WEBHOOKS_UPDATE"),
@@ -182,6 +196,7 @@ impl<'a> TryFrom<&'a str> for EventType {
             "CHANNEL_UPDATE" => Ok(Self::ChannelUpdate),
             "APPLICATION_COMMAND_PERMISSIONS_UPDATE" => Ok(Self::CommandPermissionsUpdate),
             "GIFT_CODE_UPDATE" => Ok(Self::GiftCodeUpdate),
+            "GUILD_AUDIT_LOG_ENTRY_CREATE" => Ok(Self::GuildAuditLogEntryCreate),
             "GUILD_CREATE" => Ok(Self::GuildCreate),
             "GUILD_DELETE" => Ok(Self::GuildDelete),
             "GUILD_EMOJIS_UPDATE" => Ok(Self::GuildEmojisUpdate),
@@ -191,6 +206,10 @@ impl<'a> TryFrom<&'a str> for EventType {
             "GUILD_SCHEDULED_EVENT_UPDATE" => Ok(Self::GuildScheduledEventUpdate),
             "GUILD_SCHEDULED_EVENT_USER_ADD" => Ok(Self::GuildScheduledEventUserAdd),
             "GUILD_SCHEDULED_EVENT_USER_REMOVE" => Ok(Self::GuildScheduledEventUserRemove),
+            "GUILD_SOUNDBOARD_SOUND_CREATE" => Ok(Self::GuildSoundboardSoundCreate),
+            "GUILD_SOUNDBOARD_SOUND_DELETE" => Ok(Self::GuildSoundboardSoundDelete),
+            "GUILD_SOUNDBOARD_SOUND_UPDATE" => Ok(Self::GuildSoundboardSoundUpdate),
+            "GUILD_SOUNDBOARD_SOUNDS_UPDATE" => Ok(Self::GuildSoundboardSoundsUpdate),
             "GUILD_UPDATE" => Ok(Self::GuildUpdate),
             "INTEGRATION_CREATE" => Ok(Self::IntegrationCreate),
             "INTEGRATION_DELETE" => Ok(Self::IntegrationDelete),
@@ -217,6 +236,7 @@ impl<'a> TryFrom<&'a str> for EventType {
             "GUILD_ROLE_CREATE" => Ok(Self::RoleCreate),
             "GUILD_ROLE_DELETE" => Ok(Self::RoleDelete),
             "GUILD_ROLE_UPDATE" => Ok(Self::RoleUpdate),
+            "SOUNDBOARD_SOUNDS" => Ok(Self::SoundboardSounds),
             "STAGE_INSTANCE_CREATE" => Ok(Self::StageInstanceCreate),
             "STAGE_INSTANCE_DELETE" => Ok(Self::StageInstanceDelete),
             "STAGE_INSTANCE_UPDATE" => Ok(Self::StageInstanceUpdate),
@@ -229,6 +249,7 @@ impl<'a> TryFrom<&'a str> for EventType {
             "TYPING_START" => Ok(Self::TypingStart),
             "UNAVAILABLE_GUILD" => Ok(Self::UnavailableGuild),
             "USER_UPDATE" => Ok(Self::UserUpdate),
+            "VOICE_CHANNEL_EFFECT_SEND" => Ok(Self::VoiceChannelEffectSend),
             "VOICE_SERVER_UPDATE" => Ok(Self::VoiceServerUpdate),
             "VOICE_STATE_UPDATE" => Ok(Self::VoiceStateUpdate),
             "WEBHOOKS_UPDATE" => Ok(Self::WebhooksUpdate),
@@ -253,6 +274,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn test_variants() {
         assert_variant(EventType::BanAdd, "GUILD_BAN_ADD");
         assert_variant(EventType::BanRemove, "GUILD_BAN_REMOVE");
@@ -273,6 +295,10 @@ mod tests {
         );
         assert_variant(EventType::GatewayReconnect, "GATEWAY_RECONNECT");
         assert_variant(EventType::GiftCodeUpdate, "GIFT_CODE_UPDATE");
+        assert_variant(
+            EventType::GuildAuditLogEntryCreate,
+            "GUILD_AUDIT_LOG_ENTRY_CREATE",
+        );
         assert_variant(EventType::GuildCreate, "GUILD_CREATE");
         assert_variant(EventType::GuildDelete, "GUILD_DELETE");
         assert_variant(EventType::GuildEmojisUpdate, "GUILD_EMOJIS_UPDATE");
@@ -300,6 +326,22 @@ mod tests {
             EventType::GuildScheduledEventUserRemove,
             "GUILD_SCHEDULED_EVENT_USER_REMOVE",
         );
+        assert_variant(
+            EventType::GuildSoundboardSoundCreate,
+            "GUILD_SOUNDBOARD_SOUND_CREATE",
+        );
+        assert_variant(
+            EventType::GuildSoundboardSoundDelete,
+            "GUILD_SOUNDBOARD_SOUND_DELETE",
+        );
+        assert_variant(
+            EventType::GuildSoundboardSoundUpdate,
+            "GUILD_SOUNDBOARD_SOUND_UPDATE",
+        );
+        assert_variant(
+            EventType::GuildSoundboardSoundsUpdate,
+            "GUILD_SOUNDBOARD_SOUNDS_UPDATE",
+        );
         assert_variant(EventType::GuildUpdate, "GUILD_UPDATE");
         assert_variant(EventType::IntegrationCreate, "INTEGRATION_CREATE");
         assert_variant(EventType::IntegrationDelete, "INTEGRATION_DELETE");
@@ -336,6 +378,7 @@ mod tests {
         assert_variant(EventType::ShardPayload, "SHARD_PAYLOAD");
         assert_variant(EventType::ShardReconnecting, "SHARD_RECONNECTING");
         assert_variant(EventType::ShardResuming, "SHARD_RESUMING");
+        assert_variant(EventType::SoundboardSounds, "SOUNDBOARD_SOUNDS");
         assert_variant(EventType::StageInstanceCreate, "STAGE_INSTANCE_CREATE");
         assert_variant(EventType::StageInstanceDelete, "STAGE_INSTANCE_DELETE");
         assert_variant(EventType::StageInstanceUpdate, "STAGE_INSTANCE_UPDATE");
@@ -348,6 +391,10 @@ mod tests {
         assert_variant(EventType::TypingStart, "TYPING_START");
         assert_variant(EventType::UnavailableGuild, "UNAVAILABLE_GUILD");
         assert_variant(EventType::UserUpdate, "USER_UPDATE");
+        assert_variant(
+            EventType::VoiceChannelEffectSend,
+            "VOICE_CHANNEL_EFFECT_SEND",
+        );
         assert_variant(EventType::VoiceServerUpdate, "VOICE_SERVER_UPDATE");
         assert_variant(EventType::VoiceStateUpdate, "VOICE_STATE_UPDATE");
         assert_variant(EventType::WebhooksUpdate, "WEBHOOKS_UPDATE");
@@ -51,6 +51,8 @@ pub enum Event {
     GatewayReconnect,
     /// Undocumented event, should be ignored.
     GiftCodeUpdate,
+    /// An audit log entry was created in a guild.
+    GuildAuditLogEntryCreate(Box<GuildAuditLogEntryCreate>),
     /// A guild was created.
     GuildCreate(Box<GuildCreate>),
     /// A guild was deleted or the current user was removed from a guild.
@@ -69,6 +71,14 @@ pub enum Event {
     GuildScheduledEventUserAdd(GuildScheduledEventUserAdd),
     /// A user was removed from a guild scheduled event.
     GuildScheduledEventUserRemove(GuildScheduledEventUserRemove),
+    /// A guild soundboard sound was created.
+    GuildSoundboardSoundCreate(Box<GuildSoundboardSoundCreate>),
+    /// A guild soundboard sound was deleted.
+    GuildSoundboardSoundDelete(GuildSoundboardSoundDelete),
+    /// A guild soundboard sound was updated.
+    GuildSoundboardSoundUpdate(Box<GuildSoundboardSoundUpdate>),
+    /// Multiple guild soundboard sounds were updated.
+    GuildSoundboardSoundsUpdate(GuildSoundboardSoundsUpdate),
     /// A guild's stickers were updated.
     GuildStickersUpdate(GuildStickersUpdate),
     /// A guild was updated.
@@ -143,6 +153,8 @@ pub enum Event {
     ShardPayload(Payload),
     /// A shard is now in a Resuming stage after a disconnect.
     ShardResuming(Resuming),
+    /// A guild's soundboard sounds were received in response to a request.
+    SoundboardSounds(SoundboardSounds),
     /// A stage instance was created in a stage channel.
     StageInstanceCreate(StageInstanceCreate),
     /// A stage instance was deleted in a stage channel.
@@ -168,6 +180,8 @@ pub enum Event {
     UnavailableGuild(UnavailableGuild),
     /// The current user was updated.
     UserUpdate(UserUpdate),
+    /// An effect was sent in a voice channel the current user is connected to.
+    VoiceChannelEffectSend(VoiceChannelEffectSend),
     /// A voice server update was sent.
     VoiceServerUpdate(VoiceServerUpdate),
     /// A voice state in a voice channel was updated.
@@ -192,6 +206,7 @@ impl Event {
             Self::GatewayInvalidateSession(_) => EventType::GatewayInvalidateSession,
             Self::GatewayReconnect => EventType::GatewayReconnect,
             Self::GiftCodeUpdate => EventType::GiftCodeUpdate,
+            Self::GuildAuditLogEntryCreate(_) => EventType::GuildAuditLogEntryCreate,
             Self::GuildCreate(_) => EventType::GuildCreate,
             Self::GuildDelete(_) => EventType::GuildDelete,
             Self::GuildEmojisUpdate(_) => EventType::GuildEmojisUpdate,
@@ -201,6 +216,10 @@ impl Event {
             Self::GuildScheduledEventUpdate(_) => EventType::GuildScheduledEventUpdate,
             Self::GuildScheduledEventUserAdd(_) => EventType::GuildScheduledEventUserAdd,
             Self::GuildScheduledEventUserRemove(_) => EventType::GuildScheduledEventUserRemove,
+            Self::GuildSoundboardSoundCreate(_) => EventType::GuildSoundboardSoundCreate,
+            Self::GuildSoundboardSoundDelete(_) => EventType::GuildSoundboardSoundDelete,
+            Self::GuildSoundboardSoundUpdate(_) => EventType::GuildSoundboardSoundUpdate,
+            Self::GuildSoundboardSoundsUpdate(_) => EventType::GuildSoundboardSoundsUpdate,
             Self::GuildStickersUpdate(_) => EventType::GuildStickersUpdate,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
             Self::IntegrationCreate(_) => EventType::IntegrationCreate,
@@ -235,6 +254,7 @@ impl Event {
             Self::ShardReconnecting(_) => EventType::ShardReconnecting,
             Self::ShardPayload(_) => EventType::ShardPayload,
             Self::ShardResuming(_) => EventType::ShardResuming,
+            Self::SoundboardSounds(_) => EventType::SoundboardSounds,
             Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
             Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
             Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
@@ -247,6 +267,7 @@ impl Event {
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
             Self::UserUpdate(_) => EventType::UserUpdate,
+            Self::VoiceChannelEffectSend(_) => EventType::VoiceChannelEffectSend,
             Self::VoiceServerUpdate(_) => EventType::VoiceServerUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
             Self::WebhooksUpdate(_) => EventType::WebhooksUpdate,
@@ -265,6 +286,7 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::ChannelUpdate(v) => Self::ChannelUpdate(v),
             DispatchEvent::CommandPermissionsUpdate(v) => Self::CommandPermissionsUpdate(v),
             DispatchEvent::GiftCodeUpdate => Self::GiftCodeUpdate,
+            DispatchEvent::GuildAuditLogEntryCreate(v) => Self::GuildAuditLogEntryCreate(v),
             DispatchEvent::GuildCreate(v) => Self::GuildCreate(v),
             DispatchEvent::GuildDelete(v) => Self::GuildDelete(v),
             DispatchEvent::GuildEmojisUpdate(v) => Self::GuildEmojisUpdate(v),
@@ -276,6 +298,10 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::GuildScheduledEventUserRemove(v) => {
                 Self::GuildScheduledEventUserRemove(v)
             }
+            DispatchEvent::GuildSoundboardSoundCreate(v) => Self::GuildSoundboardSoundCreate(v),
+            DispatchEvent::GuildSoundboardSoundDelete(v) => Self::GuildSoundboardSoundDelete(v),
+            DispatchEvent::GuildSoundboardSoundUpdate(v) => Self::GuildSoundboardSoundUpdate(v),
+            DispatchEvent::GuildSoundboardSoundsUpdate(v) => Self::GuildSoundboardSoundsUpdate(v),
             DispatchEvent::IntegrationCreate(v) => Self::IntegrationCreate(v),
             DispatchEvent::IntegrationDelete(v) => Self::IntegrationDelete(v),
             DispatchEvent::IntegrationUpdate(v) => Self::IntegrationUpdate(v),
@@ -302,6 +328,7 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::ReactionRemoveEmoji(v) => Self::ReactionRemoveEmoji(v),
             DispatchEvent::Ready(v) => Self::Ready(v),
             DispatchEvent::Resumed => Self::Resumed,
+            DispatchEvent::SoundboardSounds(v) => Self::SoundboardSounds(v),
             DispatchEvent::StageInstanceCreate(v) => Self::StageInstanceCreate(v),
             DispatchEvent::StageInstanceDelete(v) => Self::StageInstanceDelete(v),
             DispatchEvent::StageInstanceUpdate(v) => Self::StageInstanceUpdate(v),
@@ -314,6 +341,7 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::TypingStart(v) => Self::TypingStart(v),
             DispatchEvent::UnavailableGuild(v) => Self::UnavailableGuild(v),
             DispatchEvent::UserUpdate(v) => Self::UserUpdate(v),
+            DispatchEvent::VoiceChannelEffectSend(v) => Self::VoiceChannelEffectSend(v),
             DispatchEvent::VoiceServerUpdate(v) => Self::VoiceServerUpdate(v),
             DispatchEvent::VoiceStateUpdate(v) => Self::VoiceStateUpdate(v),
             DispatchEvent::WebhooksUpdate(v) => Self::WebhooksUpdate(v),
@@ -411,9 +439,12 @@ mod tests {
     const_assert!(mem::size_of::<ChannelCreate>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<ChannelDelete>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<ChannelUpdate>() > EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<GuildAuditLogEntryCreate>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<GuildScheduledEventCreate>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<GuildScheduledEventDelete>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<GuildScheduledEventUpdate>() > EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<GuildSoundboardSoundCreate>() > EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<GuildSoundboardSoundUpdate>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<GuildUpdate>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<IntegrationCreate>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<IntegrationUpdate>() > EVENT_THRESHOLD);
@@ -445,6 +476,8 @@ mod tests {
     const_assert!(mem::size_of::<GuildIntegrationsUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<GuildScheduledEventUserAdd>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<GuildScheduledEventUserRemove>() <= EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<GuildSoundboardSoundDelete>() <= EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<GuildSoundboardSoundsUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<Identifying>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<IntegrationDelete>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<InteractionCreate>() <= EVENT_THRESHOLD);
@@ -460,6 +493,7 @@ mod tests {
     const_assert!(mem::size_of::<RoleCreate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<RoleDelete>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<RoleUpdate>() <= EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<SoundboardSounds>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<StageInstanceCreate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<StageInstanceDelete>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<StageInstanceUpdate>() <= EVENT_THRESHOLD);
@@ -468,6 +502,7 @@ mod tests {
     const_assert!(mem::size_of::<ThreadMembersUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<UnavailableGuild>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<UserUpdate>() <= EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<VoiceChannelEffectSend>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<VoiceServerUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<WebhooksUpdate>() <= EVENT_THRESHOLD);
 }
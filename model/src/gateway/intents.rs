@@ -56,19 +56,33 @@ bitflags! {
         /// Guild bans intent.
         ///
         /// Event(s) received:
+        ///  - [`GUILD_AUDIT_LOG_ENTRY_CREATE`]
         ///  - [`GUILD_BAN_ADD`]
         ///  - [`GUILD_BAN_REMOVE`]
         ///
+        /// Receiving [`GUILD_AUDIT_LOG_ENTRY_CREATE`] events additionally
+        /// requires the [`VIEW_AUDIT_LOG`] permission.
+        ///
+        /// [`GUILD_AUDIT_LOG_ENTRY_CREATE`]: super::event::Event::GuildAuditLogEntryCreate
         /// [`GUILD_BAN_ADD`]: super::event::Event::BanAdd
         /// [`GUILD_BAN_REMOVE`]: super::event::Event::BanRemove
+        /// [`VIEW_AUDIT_LOG`]: crate::guild::Permissions::VIEW_AUDIT_LOG
         const GUILD_BANS = 1 << 2;
         /// Guild emojis and stickers intent.
         ///
         /// Event(s) received:
         ///  - [`GUILD_EMOJIS_UPDATE`]
+        ///  - [`GUILD_SOUNDBOARD_SOUND_CREATE`]
+        ///  - [`GUILD_SOUNDBOARD_SOUND_UPDATE`]
+        ///  - [`GUILD_SOUNDBOARD_SOUND_DELETE`]
+        ///  - [`GUILD_SOUNDBOARD_SOUNDS_UPDATE`]
         ///  - [`GUILD_STICKERS_UPDATE`]
         ///
         /// [`GUILD_EMOJIS_UPDATE`]: super::event::Event::GuildEmojisUpdate
+        /// [`GUILD_SOUNDBOARD_SOUND_CREATE`]: super::event::Event::GuildSoundboardSoundCreate
+        /// [`GUILD_SOUNDBOARD_SOUND_UPDATE`]: super::event::Event::GuildSoundboardSoundUpdate
+        /// [`GUILD_SOUNDBOARD_SOUND_DELETE`]: super::event::Event::GuildSoundboardSoundDelete
+        /// [`GUILD_SOUNDBOARD_SOUNDS_UPDATE`]: super::event::Event::GuildSoundboardSoundsUpdate
         /// [`GUILD_STICKERS_UPDATE`]: super::event::Event::GuildStickersUpdate
         const GUILD_EMOJIS_AND_STICKERS = 1 << 3;
         /// Guild integrations intent.
@@ -113,8 +127,10 @@ bitflags! {
         /// Guild voice states intent.
         ///
         /// Event(s) received:
+        ///  - [`VOICE_CHANNEL_EFFECT_SEND`]
         ///  - [`VOICE_STATE_UPDATE`]
         ///
+        /// [`VOICE_CHANNEL_EFFECT_SEND`]: super::event::Event::VoiceChannelEffectSend
         /// [`VOICE_STATE_UPDATE`]: super::event::Event::VoiceStateUpdate
         const GUILD_VOICE_STATES = 1 << 7;
         /// Guild presences intent.
@@ -218,6 +234,48 @@ bitflags! {
     }
 }
 
+impl Intents {
+    /// Intents that are privileged.
+    ///
+    /// Privileged intents must be enabled for the application in the developer
+    /// portal before they can be used. See [Discord Docs/Privileged Intents].
+    ///
+    /// [Discord Docs/Privileged Intents]: https://discord.com/developers/docs/topics/gateway#privileged-intents
+    pub const PRIVILEGED: Self = Self::GUILD_MEMBERS
+        .union(Self::GUILD_PRESENCES)
+        .union(Self::MESSAGE_CONTENT);
+
+    /// Whether any [privileged] intent is enabled.
+    ///
+    /// [privileged]: Self::PRIVILEGED
+    pub const fn contains_privileged(self) -> bool {
+        self.intersects(Self::PRIVILEGED)
+    }
+
+    /// Enabled intents that are [privileged].
+    ///
+    /// # Examples
+    ///
+    /// Warn which privileged intents must be enabled in the developer portal:
+    ///
+    /// ```
+    /// use twilight_model::gateway::Intents;
+    ///
+    /// let intents = Intents::GUILDS | Intents::GUILD_MEMBERS | Intents::MESSAGE_CONTENT;
+    ///
+    /// assert_eq!(
+    ///     Intents::GUILD_MEMBERS | Intents::MESSAGE_CONTENT,
+    ///     intents.privileged(),
+    /// );
+    /// ```
+    ///
+    /// [privileged]: Self::PRIVILEGED
+    #[must_use = "retrieving the privileged intents has no effect if left unused"]
+    pub const fn privileged(self) -> Self {
+        self.intersection(Self::PRIVILEGED)
+    }
+}
+
 impl<'de> Deserialize<'de> for Intents {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         Ok(Self::from_bits_truncate(u64::deserialize(deserializer)?))
@@ -232,3 +290,26 @@ impl Serialize for Intents {
         serializer.serialize_u64(self.bits())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Intents;
+
+    #[test]
+    fn test_privileged() {
+        let intents = Intents::GUILDS
+            | Intents::GUILD_PRESENCES
+            | Intents::GUILD_MESSAGES
+            | Intents::MESSAGE_CONTENT;
+
+        assert!(intents.contains_privileged());
+        assert_eq!(
+            Intents::GUILD_PRESENCES | Intents::MESSAGE_CONTENT,
+            intents.privileged()
+        );
+
+        let unprivileged = Intents::all() - Intents::PRIVILEGED;
+        assert!(!unprivileged.contains_privileged());
+        assert!(unprivileged.privileged().is_empty());
+    }
+}
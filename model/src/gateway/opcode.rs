@@ -30,6 +30,8 @@ pub enum OpCode {
     Hello = 10,
     /// Received in response to a heartbeat.
     HeartbeatAck = 11,
+    /// Request soundboard sounds of guilds.
+    RequestSoundboardSounds = 31,
 }
 
 #[cfg(test)]
@@ -51,5 +53,6 @@ mod tests {
         serde_test::assert_tokens(&OpCode::InvalidSession, &[Token::U8(9)]);
         serde_test::assert_tokens(&OpCode::Hello, &[Token::U8(10)]);
         serde_test::assert_tokens(&OpCode::HeartbeatAck, &[Token::U8(11)]);
+        serde_test::assert_tokens(&OpCode::RequestSoundboardSounds, &[Token::U8(31)]);
     }
 }
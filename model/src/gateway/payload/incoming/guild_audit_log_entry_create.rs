@@ -0,0 +1,25 @@
+use crate::guild::audit_log::AuditLogEntry;
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// Entry was created in the audit log of a guild.
+///
+/// The entry's [`guild_id`] is present.
+///
+/// [`guild_id`]: AuditLogEntry::guild_id
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildAuditLogEntryCreate(pub AuditLogEntry);
+
+impl Deref for GuildAuditLogEntryCreate {
+    type Target = AuditLogEntry;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for GuildAuditLogEntryCreate {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
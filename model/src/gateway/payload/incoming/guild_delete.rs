@@ -3,12 +3,27 @@ use serde::{Deserialize, Deserializer, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct GuildDelete {
+    /// ID of the guild.
     pub id: Id<GuildMarker>,
-    // If `unavailable` is `None` the user was removed from the guild.
+    /// Whether the guild became unavailable due to an outage.
+    ///
+    /// Discord omits this field or sends `null` when the current user was
+    /// removed from the guild, in which case this is `false`.
     #[serde(default, deserialize_with = "nullable_unavailable")]
     pub unavailable: bool,
 }
 
+impl GuildDelete {
+    /// Whether the current user was removed from the guild.
+    ///
+    /// This is `false` when the guild instead became unavailable due to an
+    /// outage, in which case the guild may be expected to be sent again once
+    /// it becomes available.
+    pub const fn is_removed(&self) -> bool {
+        !self.unavailable
+    }
+}
+
 #[allow(clippy::unnecessary_wraps)]
 fn nullable_unavailable<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
     Ok(Deserialize::deserialize(deserializer).unwrap_or_default())
@@ -57,6 +72,7 @@ mod tests {
                 Token::StructEnd,
             ],
         );
+        assert!(!expected.is_removed());
     }
 
     #[test]
@@ -120,5 +136,29 @@ mod tests {
                 Token::StructEnd,
             ],
         );
+        assert!(expected.is_removed());
+    }
+
+    #[test]
+    fn test_guild_delete_unavailable_missing() {
+        let expected = GuildDelete {
+            id: Id::new(123),
+            unavailable: false,
+        };
+
+        serde_test::assert_de_tokens(
+            &expected,
+            &[
+                Token::Struct {
+                    name: "GuildDelete",
+                    len: 1,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("123"),
+                Token::StructEnd,
+            ],
+        );
+        assert!(expected.is_removed());
     }
 }
@@ -0,0 +1,21 @@
+use crate::guild::SoundboardSound;
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// Sent when a guild soundboard sound is created.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildSoundboardSoundCreate(pub SoundboardSound);
+
+impl Deref for GuildSoundboardSoundCreate {
+    type Target = SoundboardSound;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for GuildSoundboardSoundCreate {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
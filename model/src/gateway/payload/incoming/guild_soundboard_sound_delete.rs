@@ -0,0 +1,14 @@
+use crate::id::{
+    marker::{GuildMarker, SoundboardSoundMarker},
+    Id,
+};
+use serde::{Deserialize, Serialize};
+
+/// Sent when a guild soundboard sound is deleted.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildSoundboardSoundDelete {
+    /// ID of the guild the sound was in.
+    pub guild_id: Id<GuildMarker>,
+    /// ID of the sound that was deleted.
+    pub sound_id: Id<SoundboardSoundMarker>,
+}
@@ -0,0 +1,14 @@
+use crate::{
+    guild::SoundboardSound,
+    id::{marker::GuildMarker, Id},
+};
+use serde::{Deserialize, Serialize};
+
+/// Sent when multiple guild soundboard sounds are updated.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildSoundboardSoundsUpdate {
+    /// ID of the guild the sounds are in.
+    pub guild_id: Id<GuildMarker>,
+    /// Sounds that were updated.
+    pub soundboard_sounds: Vec<SoundboardSound>,
+}
@@ -0,0 +1,16 @@
+use crate::{
+    guild::SoundboardSound,
+    id::{marker::GuildMarker, Id},
+};
+use serde::{Deserialize, Serialize};
+
+/// Sent in response to a [`RequestSoundboardSounds`] command.
+///
+/// [`RequestSoundboardSounds`]: crate::gateway::payload::outgoing::RequestSoundboardSounds
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SoundboardSounds {
+    /// ID of the guild the sounds are in.
+    pub guild_id: Id<GuildMarker>,
+    /// Sounds of the guild.
+    pub soundboard_sounds: Vec<SoundboardSound>,
+}
@@ -11,14 +11,29 @@ use serde::{
 };
 use std::fmt::{Formatter, Result as FmtResult};
 
+/// A user started typing in a channel.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
 pub struct TypingStart {
+    /// ID of the channel the user is typing in.
     pub channel_id: Id<ChannelMarker>,
+    /// ID of the guild the channel is in, if any.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
+    /// Member who started typing, if the channel is in a guild.
+    ///
+    /// The member's [`guild_id`] is set to the event's guild ID.
+    ///
+    /// [`guild_id`]: Member::guild_id
     #[serde(skip_serializing_if = "Option::is_none")]
     pub member: Option<Member>,
+    /// Unix timestamp in seconds of when the user started typing.
+    ///
+    /// Use [`Timestamp::from_secs`] to convert it to a [`Timestamp`].
+    ///
+    /// [`Timestamp`]: crate::util::Timestamp
+    /// [`Timestamp::from_secs`]: crate::util::Timestamp::from_secs
     pub timestamp: u64,
+    /// ID of the user who started typing.
     pub user_id: Id<UserMarker>,
 }
 
@@ -162,6 +177,61 @@ mod tests {
     use serde_test::Token;
     use std::str::FromStr;
 
+    #[test]
+    fn test_typing_start_json() -> Result<(), Box<dyn std::error::Error>> {
+        // The member comes before the guild ID, which still has to be set on
+        // the member.
+        let value = serde_json::from_str::<TypingStart>(
+            r#"{
+                "channel_id": "2",
+                "member": {
+                    "deaf": false,
+                    "joined_at": "2020-01-01T00:00:00.000000+00:00",
+                    "mute": false,
+                    "nick": "typing",
+                    "roles": ["4"],
+                    "user": {
+                        "avatar": null,
+                        "discriminator": "0001",
+                        "id": "3",
+                        "username": "test"
+                    }
+                },
+                "guild_id": "1",
+                "timestamp": 1500000000,
+                "user_id": "3"
+            }"#,
+        )?;
+
+        let member = value.member.expect("member present");
+        assert_eq!(Id::new(1), member.guild_id);
+        assert_eq!(Some("typing"), member.nick.as_deref());
+        assert_eq!(Id::new(3), member.user.id);
+        assert_eq!(Some(Id::new(1)), value.guild_id);
+        assert_eq!(1_500_000_000, value.timestamp);
+        assert_eq!(
+            Timestamp::from_str("2017-07-14T02:40:00.000000+00:00")?,
+            Timestamp::from_secs(value.timestamp.try_into()?)?
+        );
+
+        let value = serde_json::from_str::<TypingStart>(
+            r#"{"channel_id":"2","timestamp":1500000000,"user_id":"3"}"#,
+        )?;
+
+        assert_eq!(
+            TypingStart {
+                channel_id: Id::new(2),
+                guild_id: None,
+                member: None,
+                timestamp: 1_500_000_000,
+                user_id: Id::new(3),
+            },
+            value
+        );
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_lines)]
     #[test]
     fn test_typing_start_with_member() -> Result<(), TimestampParseError> {
@@ -0,0 +1,150 @@
+//! Gateway event payload when a voice channel effect is sent.
+
+use crate::{
+    application::command::Number,
+    channel::ReactionType,
+    id::{
+        marker::{ChannelMarker, GuildMarker, SoundboardSoundMarker, UserMarker},
+        Id,
+    },
+};
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Someone sent an effect, such as an emoji reaction or a soundboard sound, in
+/// a voice channel the current user is connected to.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct VoiceChannelEffectSend {
+    /// ID of the emoji animation, for emoji reaction and soundboard effects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation_id: Option<u64>,
+    /// Type of emoji animation, for emoji reaction and soundboard effects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation_type: Option<AnimationType>,
+    /// ID of the channel the effect was sent in.
+    pub channel_id: Id<ChannelMarker>,
+    /// Emoji sent, for emoji reaction and soundboard effects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ReactionType>,
+    /// ID of the guild the effect was sent in.
+    pub guild_id: Id<GuildMarker>,
+    /// ID of the soundboard sound, for soundboard effects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sound_id: Option<Id<SoundboardSoundMarker>>,
+    /// Volume of the soundboard sound, from 0 to 1, for soundboard effects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sound_volume: Option<Number>,
+    /// ID of the user who sent the effect.
+    pub user_id: Id<UserMarker>,
+}
+
+/// Type of animation played for a [`VoiceChannelEffectSend`].
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum AnimationType {
+    /// Fun animation, sent by a Nitro subscriber.
+    Premium = 0,
+    /// Standard animation.
+    Basic = 1,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnimationType, VoiceChannelEffectSend};
+    use crate::{application::command::Number, channel::ReactionType, id::Id};
+    use serde_test::Token;
+
+    #[test]
+    fn test_animation_type() {
+        serde_test::assert_tokens(&AnimationType::Premium, &[Token::U8(0)]);
+        serde_test::assert_tokens(&AnimationType::Basic, &[Token::U8(1)]);
+    }
+
+    #[test]
+    fn test_voice_channel_effect_send() {
+        let value = VoiceChannelEffectSend {
+            animation_id: Some(3),
+            animation_type: Some(AnimationType::Basic),
+            channel_id: Id::new(1),
+            emoji: Some(ReactionType::Unicode {
+                name: "\u{1f986}".to_owned(),
+            }),
+            guild_id: Id::new(2),
+            sound_id: Some(Id::new(4)),
+            sound_volume: Some(Number(0.5)),
+            user_id: Id::new(5),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "VoiceChannelEffectSend",
+                    len: 8,
+                },
+                Token::Str("animation_id"),
+                Token::Some,
+                Token::U64(3),
+                Token::Str("animation_type"),
+                Token::Some,
+                Token::U8(1),
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("emoji"),
+                Token::Some,
+                Token::Struct {
+                    name: "ReactionType",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Str("\u{1f986}"),
+                Token::StructEnd,
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("sound_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("4"),
+                Token::Str("sound_volume"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Number" },
+                Token::F64(0.5),
+                Token::Str("user_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("5"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    /// Test that a voice channel effect without any of the optional emoji or
+    /// soundboard fields deserializes, as sent by Discord for plain effects.
+    #[test]
+    fn test_voice_channel_effect_send_minimal() {
+        let input = r#"{
+            "channel_id": "1",
+            "guild_id": "2",
+            "user_id": "5"
+        }"#;
+
+        let value = serde_json::from_str::<VoiceChannelEffectSend>(input).unwrap();
+
+        assert_eq!(
+            value,
+            VoiceChannelEffectSend {
+                animation_id: None,
+                animation_type: None,
+                channel_id: Id::new(1),
+                emoji: None,
+                guild_id: Id::new(2),
+                sound_id: None,
+                sound_volume: None,
+                user_id: Id::new(5),
+            }
+        );
+    }
+}
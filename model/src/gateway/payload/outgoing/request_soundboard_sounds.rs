@@ -0,0 +1,83 @@
+use crate::{
+    gateway::opcode::OpCode,
+    id::{marker::GuildMarker, Id},
+};
+use serde::{Deserialize, Serialize};
+
+/// Request the soundboard sounds of a list of guilds.
+///
+/// Discord responds with a [`SoundboardSounds`] event for each guild.
+///
+/// [`SoundboardSounds`]: crate::gateway::payload::incoming::SoundboardSounds
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RequestSoundboardSounds {
+    pub d: RequestSoundboardSoundsInfo,
+    pub op: OpCode,
+}
+
+impl RequestSoundboardSounds {
+    /// Create a new request for the soundboard sounds of the provided guilds.
+    pub fn new(guild_ids: impl Into<Vec<Id<GuildMarker>>>) -> Self {
+        Self {
+            d: RequestSoundboardSoundsInfo {
+                guild_ids: guild_ids.into(),
+            },
+            op: OpCode::RequestSoundboardSounds,
+        }
+    }
+}
+
+/// Data of a [`RequestSoundboardSounds`] payload.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RequestSoundboardSoundsInfo {
+    /// IDs of the guilds to get soundboard sounds for.
+    pub guild_ids: Vec<Id<GuildMarker>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RequestSoundboardSounds, RequestSoundboardSoundsInfo};
+    use crate::{gateway::opcode::OpCode, id::Id};
+    use serde_test::Token;
+
+    #[test]
+    fn test_request_soundboard_sounds() {
+        let value = RequestSoundboardSounds::new(vec![Id::new(1), Id::new(2)]);
+
+        assert_eq!(
+            value,
+            RequestSoundboardSounds {
+                d: RequestSoundboardSoundsInfo {
+                    guild_ids: vec![Id::new(1), Id::new(2)],
+                },
+                op: OpCode::RequestSoundboardSounds,
+            }
+        );
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "RequestSoundboardSounds",
+                    len: 2,
+                },
+                Token::Str("d"),
+                Token::Struct {
+                    name: "RequestSoundboardSoundsInfo",
+                    len: 1,
+                },
+                Token::Str("guild_ids"),
+                Token::Seq { len: Some(2) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::Str("op"),
+                Token::U8(31),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
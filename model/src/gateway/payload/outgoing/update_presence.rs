@@ -1,6 +1,6 @@
 use crate::gateway::{
     opcode::OpCode,
-    presence::{Activity, Status},
+    presence::{Activity, ActivityType, Status},
 };
 use serde::{Deserialize, Serialize};
 use std::{
@@ -46,6 +46,12 @@ impl Display for UpdatePresenceError {
             UpdatePresenceErrorType::MissingActivity => {
                 f.write_str("at least one activity must be provided")
             }
+            UpdatePresenceErrorType::StreamingUrlMissing { index } => {
+                f.write_str("streaming activity at index ")?;
+                Display::fmt(&index, f)?;
+
+                f.write_str(" has no url")
+            }
         }
     }
 }
@@ -58,6 +64,11 @@ impl Error for UpdatePresenceError {}
 pub enum UpdatePresenceErrorType {
     /// No activities provided.
     MissingActivity,
+    /// Activity of type [`ActivityType::Streaming`] has no URL.
+    StreamingUrlMissing {
+        /// Index of the activity.
+        index: usize,
+    },
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -73,6 +84,9 @@ impl UpdatePresence {
     ///
     /// Returns an error of type [`UpdatePresenceErrorType::MissingActivity`] if
     /// an empty set of activities is provided.
+    ///
+    /// Returns an error of type [`UpdatePresenceErrorType::StreamingUrlMissing`]
+    /// if a streaming activity has no URL.
     pub fn new(
         activities: impl Into<Vec<Activity>>,
         afk: bool,
@@ -106,6 +120,9 @@ impl UpdatePresencePayload {
     ///
     /// Returns an [`UpdatePresenceErrorType::MissingActivity`] error type if an
     /// empty set of activities is provided.
+    ///
+    /// Returns an [`UpdatePresenceErrorType::StreamingUrlMissing`] error type
+    /// if a streaming activity has no URL.
     pub fn new(
         activities: impl Into<Vec<Activity>>,
         afk: bool,
@@ -127,6 +144,17 @@ impl UpdatePresencePayload {
             });
         }
 
+        // Discord requires the URL of the stream to display streaming
+        // activities.
+        if let Some(index) = activities
+            .iter()
+            .position(|activity| activity.kind == ActivityType::Streaming && activity.url.is_none())
+        {
+            return Err(UpdatePresenceError {
+                kind: UpdatePresenceErrorType::StreamingUrlMissing { index },
+            });
+        }
+
         Ok(Self {
             activities,
             afk,
@@ -135,3 +163,64 @@ impl UpdatePresencePayload {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{UpdatePresence, UpdatePresenceErrorType, UpdatePresencePayload};
+    use crate::gateway::{
+        opcode::OpCode,
+        presence::{Activity, ActivityType, MinimalActivity, Status},
+    };
+
+    fn activity(kind: ActivityType, url: Option<&str>) -> Activity {
+        Activity::from(MinimalActivity {
+            kind,
+            name: "twilight".to_owned(),
+            url: url.map(ToOwned::to_owned),
+        })
+    }
+
+    #[test]
+    fn test_update_presence() {
+        let value = UpdatePresence::new(
+            [activity(ActivityType::Playing, None)],
+            false,
+            None,
+            Status::Idle,
+        )
+        .unwrap();
+
+        assert_eq!(OpCode::PresenceUpdate, value.op);
+        assert_eq!(Status::Idle, value.d.status);
+    }
+
+    #[test]
+    fn test_missing_activity() {
+        let error =
+            UpdatePresencePayload::new(Vec::new(), false, None, Status::Online).unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            UpdatePresenceErrorType::MissingActivity
+        ));
+    }
+
+    #[test]
+    fn test_streaming_url_missing() {
+        let error = UpdatePresencePayload::new(
+            [
+                activity(ActivityType::Streaming, Some("https://twitch.tv/twilight")),
+                activity(ActivityType::Streaming, None),
+            ],
+            false,
+            None,
+            Status::Online,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            UpdatePresenceErrorType::StreamingUrlMissing { index: 1 }
+        ));
+    }
+}
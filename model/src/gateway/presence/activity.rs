@@ -46,4 +46,139 @@ pub struct Activity {
 #[cfg(test)]
 mod tests {
     // Custom activities is tested by the custom presence test.
+
+    use super::Activity;
+    use crate::{
+        gateway::presence::{
+            activity_button::{ActivityButtonLink, ActivityButtonText},
+            ActivityAssets, ActivityButton, ActivityParty, ActivityType,
+        },
+        id::Id,
+    };
+    use serde_test::Token;
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn test_activity_assets_buttons() {
+        let value = Activity {
+            application_id: Some(Id::new(1)),
+            assets: Some(ActivityAssets {
+                large_image: Some("mp:external/abc/https/example.com/map.png".to_owned()),
+                large_text: Some("Map".to_owned()),
+                small_image: Some("2".to_owned()),
+                small_text: Some("Rank".to_owned()),
+            }),
+            buttons: vec![
+                ActivityButton::Text(ActivityButtonText {
+                    label: "Join".to_owned(),
+                }),
+                ActivityButton::Link(ActivityButtonLink {
+                    label: "Website".to_owned(),
+                    url: "https://example.com".to_owned(),
+                }),
+            ],
+            created_at: Some(1_571_048_061_237),
+            details: Some("Ranked".to_owned()),
+            emoji: None,
+            flags: None,
+            id: None,
+            instance: None,
+            kind: ActivityType::Playing,
+            name: "a game".to_owned(),
+            party: Some(ActivityParty {
+                id: Some("party".to_owned()),
+                size: Some([2, 4]),
+            }),
+            secrets: None,
+            state: Some("In a group".to_owned()),
+            timestamps: None,
+            url: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Activity",
+                    len: 9,
+                },
+                Token::Str("application_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("assets"),
+                Token::Some,
+                Token::Struct {
+                    name: "ActivityAssets",
+                    len: 4,
+                },
+                Token::Str("large_image"),
+                Token::Some,
+                Token::Str("mp:external/abc/https/example.com/map.png"),
+                Token::Str("large_text"),
+                Token::Some,
+                Token::Str("Map"),
+                Token::Str("small_image"),
+                Token::Some,
+                Token::Str("2"),
+                Token::Str("small_text"),
+                Token::Some,
+                Token::Str("Rank"),
+                Token::StructEnd,
+                Token::Str("buttons"),
+                Token::Seq { len: Some(2) },
+                Token::Str("Join"),
+                Token::Struct {
+                    name: "ActivityButton",
+                    len: 2,
+                },
+                Token::Str("label"),
+                Token::Str("Website"),
+                Token::Str("url"),
+                Token::Str("https://example.com"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("created_at"),
+                Token::Some,
+                Token::U64(1_571_048_061_237),
+                Token::Str("details"),
+                Token::Some,
+                Token::Str("Ranked"),
+                Token::Str("type"),
+                Token::U8(0),
+                Token::Str("name"),
+                Token::Str("a game"),
+                Token::Str("party"),
+                Token::Some,
+                Token::Struct {
+                    name: "ActivityParty",
+                    len: 2,
+                },
+                Token::Str("id"),
+                Token::Some,
+                Token::Str("party"),
+                Token::Str("size"),
+                Token::Some,
+                Token::Tuple { len: 2 },
+                Token::U64(2),
+                Token::U64(4),
+                Token::TupleEnd,
+                Token::StructEnd,
+                Token::Str("state"),
+                Token::Some,
+                Token::Str("In a group"),
+                Token::StructEnd,
+            ],
+        );
+
+        let assets = value.assets.as_ref().unwrap();
+        assert_eq!(
+            Some("https://media.discordapp.net/external/abc/https/example.com/map.png"),
+            assets.large_image_url(value.application_id).as_deref()
+        );
+        assert_eq!(
+            Some("https://cdn.discordapp.com/app-assets/1/2.png"),
+            assets.small_image_url(value.application_id).as_deref()
+        );
+    }
 }
@@ -1,20 +1,80 @@
+use crate::id::{marker::ApplicationMarker, Id};
 use serde::{Deserialize, Serialize};
 
+/// Prefix of asset images proxied through Discord's media proxy.
+const MEDIA_PROXY_PREFIX: &str = "mp:";
+
+/// Images and their hover texts shown in an activity.
+///
+/// Images are either the ID of an asset uploaded to the activity's
+/// application or, when prefixed with `mp:`, the path of an external image
+/// proxied through Discord's media proxy. Use [`large_image_url`] and
+/// [`small_image_url`] to get the URLs of the images.
+///
+/// See [Discord Docs/Activity Asset Image].
+///
+/// [Discord Docs/Activity Asset Image]: https://discord.com/developers/docs/topics/gateway#activity-object-activity-asset-image
+/// [`large_image_url`]: Self::large_image_url
+/// [`small_image_url`]: Self::small_image_url
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ActivityAssets {
+    /// Large image of the activity.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub large_image: Option<String>,
+    /// Text displayed when hovering over the large image.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub large_text: Option<String>,
+    /// Small image of the activity.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub small_image: Option<String>,
+    /// Text displayed when hovering over the small image.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub small_text: Option<String>,
 }
 
+impl ActivityAssets {
+    /// URL of the large image.
+    ///
+    /// The activity's application ID is required to resolve images uploaded
+    /// as application assets. Returns [`None`] if there is no large image, or
+    /// if it is an application asset and no application ID is provided.
+    pub fn large_image_url(&self, application_id: Option<Id<ApplicationMarker>>) -> Option<String> {
+        image_url(self.large_image.as_deref()?, application_id)
+    }
+
+    /// URL of the small image.
+    ///
+    /// The activity's application ID is required to resolve images uploaded
+    /// as application assets. Returns [`None`] if there is no small image, or
+    /// if it is an application asset and no application ID is provided.
+    pub fn small_image_url(&self, application_id: Option<Id<ApplicationMarker>>) -> Option<String> {
+        image_url(self.small_image.as_deref()?, application_id)
+    }
+}
+
+/// URL of an asset image, either proxied through the media proxy or uploaded
+/// to the application.
+fn image_url(image: &str, application_id: Option<Id<ApplicationMarker>>) -> Option<String> {
+    if let Some(path) = image.strip_prefix(MEDIA_PROXY_PREFIX) {
+        return Some(format!("https://media.discordapp.net/{path}"));
+    }
+
+    // Other prefixes, such as `spotify:`, refer to images hosted by third
+    // parties.
+    if image.contains(':') {
+        return None;
+    }
+
+    Some(format!(
+        "https://cdn.discordapp.com/app-assets/{}/{image}.png",
+        application_id?
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::ActivityAssets;
+    use crate::id::Id;
     use serde_test::Token;
 
     #[test]
@@ -49,4 +109,37 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_image_url() {
+        let value = ActivityAssets {
+            large_image: Some("mp:external/abc/https/example.com/image.png".to_owned()),
+            large_text: None,
+            small_image: Some("123".to_owned()),
+            small_text: None,
+        };
+
+        assert_eq!(
+            Some("https://media.discordapp.net/external/abc/https/example.com/image.png"),
+            value.large_image_url(None).as_deref()
+        );
+        assert_eq!(
+            Some("https://cdn.discordapp.com/app-assets/1/123.png"),
+            value.small_image_url(Some(Id::new(1))).as_deref()
+        );
+        assert!(value.small_image_url(None).is_none());
+    }
+
+    #[test]
+    fn test_image_url_unsupported() {
+        let value = ActivityAssets {
+            large_image: Some("spotify:ab67616d0000b273".to_owned()),
+            large_text: None,
+            small_image: None,
+            small_text: None,
+        };
+
+        assert!(value.large_image_url(Some(Id::new(1))).is_none());
+        assert!(value.small_image_url(Some(Id::new(1))).is_none());
+    }
 }
@@ -0,0 +1,14 @@
+/// Platform a user may be connected to Discord from.
+///
+/// Used to index a [`ClientStatus`].
+///
+/// [`ClientStatus`]: super::ClientStatus
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ClientPlatform {
+    /// Desktop application.
+    Desktop,
+    /// Mobile application.
+    Mobile,
+    /// Web browser or bot.
+    Web,
+}
@@ -1,4 +1,4 @@
-use crate::gateway::presence::Status;
+use crate::gateway::presence::{ClientPlatform, Status};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -11,11 +11,95 @@ pub struct ClientStatus {
     pub web: Option<Status>,
 }
 
+impl ClientStatus {
+    /// Status of the user on a platform, if they're connected from it.
+    pub const fn get(&self, platform: ClientPlatform) -> Option<Status> {
+        match platform {
+            ClientPlatform::Desktop => self.desktop,
+            ClientPlatform::Mobile => self.mobile,
+            ClientPlatform::Web => self.web,
+        }
+    }
+
+    /// Platform the user is the most active on, and their status on it.
+    ///
+    /// [`Online`] is the most active status, followed by [`DoNotDisturb`] and
+    /// [`Idle`]. Ties are resolved in favor of the desktop, then mobile, then
+    /// web platform.
+    ///
+    /// Returns [`None`] if the user is not online on any platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::gateway::presence::{ClientPlatform, ClientStatus, Status};
+    ///
+    /// let client_status = ClientStatus {
+    ///     desktop: Some(Status::Idle),
+    ///     mobile: Some(Status::Online),
+    ///     web: None,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     Some((ClientPlatform::Mobile, Status::Online)),
+    ///     client_status.most_active(),
+    /// );
+    /// ```
+    ///
+    /// [`DoNotDisturb`]: Status::DoNotDisturb
+    /// [`Idle`]: Status::Idle
+    /// [`Online`]: Status::Online
+    pub fn most_active(&self) -> Option<(ClientPlatform, Status)> {
+        [
+            ClientPlatform::Desktop,
+            ClientPlatform::Mobile,
+            ClientPlatform::Web,
+        ]
+        .into_iter()
+        .filter_map(|platform| {
+            self.get(platform)
+                .filter(|status| status.is_online())
+                .map(|status| (platform, status))
+        })
+        // `max_by_key` returns the last of equal elements, so iterate in
+        // reverse for ties to resolve to the first platform.
+        .rev()
+        .max_by_key(|(_, status)| status.activity())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{ClientStatus, Status};
+    use super::{ClientPlatform, ClientStatus, Status};
     use serde_test::Token;
 
+    #[test]
+    fn test_most_active() {
+        let mut value = ClientStatus {
+            desktop: Some(Status::Idle),
+            mobile: Some(Status::DoNotDisturb),
+            web: Some(Status::DoNotDisturb),
+        };
+        assert_eq!(
+            Some((ClientPlatform::Mobile, Status::DoNotDisturb)),
+            value.most_active()
+        );
+
+        value.web = Some(Status::Online);
+        assert_eq!(
+            Some((ClientPlatform::Web, Status::Online)),
+            value.most_active()
+        );
+
+        let value = ClientStatus {
+            desktop: Some(Status::Offline),
+            mobile: None,
+            web: None,
+        };
+        assert_eq!(Some(Status::Offline), value.get(ClientPlatform::Desktop));
+        assert!(value.most_active().is_none());
+    }
+
     #[test]
     fn test_mobile_online() {
         let value = ClientStatus {
@@ -8,6 +8,7 @@ mod activity_party;
 mod activity_secrets;
 mod activity_timestamps;
 mod activity_type;
+mod client_platform;
 mod client_status;
 mod minimal_activity;
 mod status;
@@ -16,8 +17,8 @@ pub use self::{
     activity::Activity, activity_assets::ActivityAssets, activity_button::ActivityButton,
     activity_emoji::ActivityEmoji, activity_flags::ActivityFlags, activity_party::ActivityParty,
     activity_secrets::ActivitySecrets, activity_timestamps::ActivityTimestamps,
-    activity_type::ActivityType, client_status::ClientStatus, minimal_activity::MinimalActivity,
-    status::Status,
+    activity_type::ActivityType, client_platform::ClientPlatform, client_status::ClientStatus,
+    minimal_activity::MinimalActivity, status::Status,
 };
 
 use crate::{
@@ -170,8 +171,8 @@ impl<'de> DeserializeSeed<'de> for PresenceListDeserializer {
 #[cfg(test)]
 mod tests {
     use super::{
-        Activity, ActivityEmoji, ActivityType, ClientStatus, Presence, PresenceListDeserializer,
-        Status, UserOrId,
+        Activity, ActivityEmoji, ActivityType, ClientPlatform, ClientStatus, Presence,
+        PresenceListDeserializer, Status, UserOrId,
     };
     use crate::id::Id;
     use serde::de::DeserializeSeed;
@@ -323,4 +324,39 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_mixed_client_status() -> Result<(), serde_json::Error> {
+        let input = r#"{
+            "activities": [],
+            "client_status": {
+                "desktop": "idle",
+                "mobile": "online",
+                "web": "dnd"
+            },
+            "guild_id": "2",
+            "status": "online",
+            "user": {
+                "id": "1"
+            }
+        }"#;
+
+        let presence = serde_json::from_str::<Presence>(input)?;
+
+        assert!(presence.status.is_online());
+        assert_eq!(
+            ClientStatus {
+                desktop: Some(Status::Idle),
+                mobile: Some(Status::Online),
+                web: Some(Status::DoNotDisturb),
+            },
+            presence.client_status
+        );
+        assert_eq!(
+            Some((ClientPlatform::Mobile, Status::Online)),
+            presence.client_status.most_active()
+        );
+
+        Ok(())
+    }
 }
@@ -14,11 +14,58 @@ pub enum Status {
     Online,
 }
 
+impl Status {
+    /// Whether the user is shown as being connected.
+    ///
+    /// This is the case for all statuses other than [`Invisible`] and
+    /// [`Offline`]. Invisible users are sent as being offline, so the
+    /// distinction can only be made for the current user.
+    ///
+    /// [`Invisible`]: Self::Invisible
+    /// [`Offline`]: Self::Offline
+    pub const fn is_online(self) -> bool {
+        !self.is_offline()
+    }
+
+    /// Whether the user is shown as not being connected, meaning the status is
+    /// [`Invisible`] or [`Offline`].
+    ///
+    /// [`Invisible`]: Self::Invisible
+    /// [`Offline`]: Self::Offline
+    pub const fn is_offline(self) -> bool {
+        matches!(self, Self::Invisible | Self::Offline)
+    }
+
+    /// How active the status is, from [`Online`] being the most active, to
+    /// [`DoNotDisturb`], [`Idle`], and offline statuses being the least.
+    ///
+    /// [`DoNotDisturb`]: Self::DoNotDisturb
+    /// [`Idle`]: Self::Idle
+    /// [`Online`]: Self::Online
+    pub(crate) const fn activity(self) -> u8 {
+        match self {
+            Self::Online => 3,
+            Self::DoNotDisturb => 2,
+            Self::Idle => 1,
+            Self::Invisible | Self::Offline => 0,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Status;
     use serde_test::Token;
 
+    #[test]
+    fn test_online() {
+        assert!(Status::DoNotDisturb.is_online());
+        assert!(Status::Idle.is_online());
+        assert!(Status::Online.is_online());
+        assert!(Status::Invisible.is_offline());
+        assert!(Status::Offline.is_offline());
+    }
+
     #[test]
     fn test_variants() {
         serde_test::assert_tokens(
@@ -1,6 +1,6 @@
 use super::{AuditLogChange, AuditLogEventType, AuditLogOptionalEntryInfo};
 use crate::id::{
-    marker::{AuditLogEntryMarker, GenericMarker, UserMarker},
+    marker::{AuditLogEntryMarker, GenericMarker, GuildMarker, UserMarker},
     Id,
 };
 use serde::{Deserialize, Serialize};
@@ -15,6 +15,13 @@ pub struct AuditLogEntry {
     /// List of changes included in the entry.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub changes: Vec<AuditLogChange>,
+    /// ID of the guild the entry was created in.
+    ///
+    /// This is only present in [`GuildAuditLogEntryCreate`] events.
+    ///
+    /// [`GuildAuditLogEntryCreate`]: crate::gateway::payload::incoming::GuildAuditLogEntryCreate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
     /// ID of the entire entry.
     pub id: Id<AuditLogEntryMarker>,
     /// Optional information about the entry.
@@ -74,6 +81,7 @@ mod tests {
                 new: None,
                 old: Some(image_hash::ICON),
             }]),
+            guild_id: None,
             id: Id::new(3),
             options: None,
             reason: Some("some reason".to_owned()),
@@ -0,0 +1,49 @@
+use crate::id::{marker::UserMarker, Id};
+use serde::{Deserialize, Serialize};
+
+/// Result of banning multiple users from a guild at once.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildBulkBan {
+    /// Users that were banned.
+    pub banned_users: Vec<Id<UserMarker>>,
+    /// Users that couldn't be banned.
+    pub failed_users: Vec<Id<UserMarker>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildBulkBan;
+    use crate::id::Id;
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_bulk_ban() {
+        let bulk_ban = GuildBulkBan {
+            banned_users: vec![Id::new(1), Id::new(2)],
+            failed_users: vec![Id::new(3)],
+        };
+
+        serde_test::assert_tokens(
+            &bulk_ban,
+            &[
+                Token::Struct {
+                    name: "GuildBulkBan",
+                    len: 2,
+                },
+                Token::Str("banned_users"),
+                Token::Seq { len: Some(2) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::SeqEnd,
+                Token::Str("failed_users"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use super::{IntegrationAccount, IntegrationApplication, IntegrationExpireBehavior};
+use super::{
+    GuildIntegrationType, IntegrationAccount, IntegrationApplication, IntegrationExpireBehavior,
+};
 use crate::{
     id::{
         marker::{GuildMarker, IntegrationMarker, RoleMarker},
@@ -9,34 +11,65 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+/// Integration of a guild with a service, such as Twitch.
+///
+/// Returned when getting the integrations of a guild and received in
+/// [`IntegrationCreate`] and [`IntegrationUpdate`] events. Fields other than
+/// the ID, name, type, account, and whether the integration is enabled are
+/// only present for some types of integrations.
+///
+/// [`IntegrationCreate`]: crate::gateway::payload::incoming::IntegrationCreate
+/// [`IntegrationUpdate`]: crate::gateway::payload::incoming::IntegrationUpdate
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct GuildIntegration {
+    /// Account of the service the integration is for.
     pub account: IntegrationAccount,
+    /// Application of a [`Discord`] integration.
+    ///
+    /// [`Discord`]: GuildIntegrationType::Discord
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application: Option<IntegrationApplication>,
+    /// Whether emoticons of the service are synced to the guild.
+    ///
+    /// Only present for [`Twitch`] integrations.
+    ///
+    /// [`Twitch`]: GuildIntegrationType::Twitch
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enable_emoticons: Option<bool>,
+    /// Whether the integration is enabled.
     pub enabled: bool,
+    /// Behavior of expiring subscribers to the integration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expire_behavior: Option<IntegrationExpireBehavior>,
+    /// Grace period before expiring subscribers, in days.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expire_grace_period: Option<u64>,
+    /// ID of the guild, only present in integration events.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
+    /// ID of the integration.
     pub id: Id<IntegrationMarker>,
+    /// Service the integration is provided by.
     #[serde(rename = "type")]
-    pub kind: String,
+    pub kind: GuildIntegrationType,
+    /// Name of the integration.
     pub name: String,
+    /// Whether the integration has been revoked.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub revoked: Option<bool>,
+    /// ID of the role given to subscribers of the integration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role_id: Option<Id<RoleMarker>>,
+    /// Number of subscribers of the integration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscriber_count: Option<u64>,
+    /// When the integration was last synced.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub synced_at: Option<Timestamp>,
+    /// Whether the integration is syncing.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub syncing: Option<bool>,
+    /// User who added the integration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<User>,
 }
@@ -44,8 +77,8 @@ pub struct GuildIntegration {
 #[cfg(test)]
 mod tests {
     use super::{
-        GuildIntegration, IntegrationAccount, IntegrationApplication, IntegrationExpireBehavior,
-        User,
+        GuildIntegration, GuildIntegrationType, IntegrationAccount, IntegrationApplication,
+        IntegrationExpireBehavior, User,
     };
     use crate::{
         id::Id,
@@ -72,7 +105,7 @@ mod tests {
             expire_grace_period: Some(3_600),
             guild_id: None,
             id: Id::new(2),
-            kind: "a".to_owned(),
+            kind: GuildIntegrationType::Unknown("a".to_owned()),
             name: "integration name".to_owned(),
             revoked: Some(false),
             role_id: Some(Id::new(3)),
@@ -202,7 +235,7 @@ mod tests {
             expire_grace_period: Some(3_600),
             guild_id: None,
             id: Id::new(2),
-            kind: "a".to_owned(),
+            kind: GuildIntegrationType::Unknown("a".to_owned()),
             name: "integration name".to_owned(),
             revoked: Some(false),
             role_id: Some(Id::new(3)),
@@ -324,4 +357,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_guild_integration_twitch() -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::json!({
+            "account": {"id": "twilight_rs", "name": "Twilight"},
+            "enable_emoticons": true,
+            "enabled": true,
+            "expire_behavior": 0,
+            "expire_grace_period": 7,
+            "id": "2",
+            "name": "twilight_rs",
+            "revoked": false,
+            "role_id": "3",
+            "subscriber_count": 42,
+            "synced_at": "2021-01-01T01:01:01.000000+00:00",
+            "syncing": false,
+            "type": "twitch",
+        });
+
+        let integration = serde_json::from_value::<GuildIntegration>(json.clone())?;
+        assert_eq!(GuildIntegrationType::Twitch, integration.kind);
+        assert_eq!("twilight_rs", integration.account.id);
+        assert_eq!(
+            Some(IntegrationExpireBehavior::RemoveRole),
+            integration.expire_behavior
+        );
+        assert_eq!(Some(42), integration.subscriber_count);
+
+        assert_eq!(json, serde_json::to_value(&integration)?);
+
+        Ok(())
+    }
 }
@@ -1,8 +1,14 @@
 use serde::{Deserialize, Serialize};
 
+/// Account of the service a [`GuildIntegration`] is for, such as a Twitch
+/// channel.
+///
+/// [`GuildIntegration`]: super::GuildIntegration
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct IntegrationAccount {
+    /// ID of the account on the service.
     pub id: String,
+    /// Name of the account.
     pub name: String,
 }
 
@@ -0,0 +1,141 @@
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Service a [`GuildIntegration`] is provided by.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::guild::GuildIntegrationType;
+///
+/// assert_eq!(GuildIntegrationType::Twitch, GuildIntegrationType::from("twitch"));
+/// assert_eq!("youtube", GuildIntegrationType::YouTube.as_str());
+///
+/// // Integration types Discord has added since are retained as they are.
+/// assert_eq!(
+///     GuildIntegrationType::Unknown("other".to_owned()),
+///     GuildIntegrationType::from("other"),
+/// );
+/// ```
+///
+/// [`GuildIntegration`]: super::GuildIntegration
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum GuildIntegrationType {
+    /// Integration of a Discord application, such as a bot, `discord`.
+    Discord,
+    /// Server subscriptions of the guild, `guild_subscription`.
+    GuildSubscription,
+    /// Twitch subscriptions of a streamer, `twitch`.
+    Twitch,
+    /// Channel memberships of a video platform, `youtube`.
+    YouTube,
+    /// Integration type unknown to Twilight.
+    Unknown(String),
+}
+
+impl GuildIntegrationType {
+    /// Name of the integration type used by Discord, such as `twitch`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Discord => "discord",
+            Self::GuildSubscription => "guild_subscription",
+            Self::Twitch => "twitch",
+            Self::YouTube => "youtube",
+            Self::Unknown(kind) => kind,
+        }
+    }
+
+    /// Parse a known integration type, returning [`None`] if it is unknown.
+    fn from_known(kind: &str) -> Option<Self> {
+        Some(match kind {
+            "discord" => Self::Discord,
+            "guild_subscription" => Self::GuildSubscription,
+            "twitch" => Self::Twitch,
+            "youtube" => Self::YouTube,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for GuildIntegrationType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for GuildIntegrationType {
+    fn from(kind: &str) -> Self {
+        Self::from_known(kind).unwrap_or_else(|| Self::Unknown(kind.to_owned()))
+    }
+}
+
+impl From<String> for GuildIntegrationType {
+    fn from(kind: String) -> Self {
+        Self::from_known(&kind).unwrap_or(Self::Unknown(kind))
+    }
+}
+
+impl<'de> Deserialize<'de> for GuildIntegrationType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for GuildIntegrationType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildIntegrationType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        GuildIntegrationType: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_variants() {
+        const MAP: &[(GuildIntegrationType, &str)] = &[
+            (GuildIntegrationType::Discord, "discord"),
+            (
+                GuildIntegrationType::GuildSubscription,
+                "guild_subscription",
+            ),
+            (GuildIntegrationType::Twitch, "twitch"),
+            (GuildIntegrationType::YouTube, "youtube"),
+        ];
+
+        for (kind, name) in MAP {
+            serde_test::assert_tokens(kind, &[Token::Str(name)]);
+            assert_eq!(*kind, GuildIntegrationType::from(*name));
+            assert_eq!(*kind, GuildIntegrationType::from((*name).to_owned()));
+            assert_eq!(*name, kind.to_string());
+        }
+    }
+
+    #[test]
+    fn test_unknown() {
+        let kind = GuildIntegrationType::Unknown("other".to_owned());
+
+        serde_test::assert_tokens(&kind, &[Token::Str("other")]);
+        assert_eq!(kind, GuildIntegrationType::from("other"));
+        assert_eq!("other", kind.as_str());
+    }
+}
@@ -0,0 +1,211 @@
+use super::{Member, PartialMember};
+use crate::{
+    application::interaction::application_command::InteractionMember,
+    id::{marker::RoleMarker, Id},
+    util::Timestamp,
+};
+
+/// Fields common to the different representations of a guild member.
+///
+/// Members are sent in different shapes depending on where they come from:
+/// a [`Member`] is received from the gateway and HTTP API, while interactions
+/// contain a [`PartialMember`] for the invoking member and
+/// [`InteractionMember`]s for resolved members. This trait allows code that
+/// only cares about the common fields to be generic over the shape.
+///
+/// # Examples
+///
+/// Check whether a member has a role, regardless of the member's shape:
+///
+/// ```
+/// use twilight_model::{
+///     guild::MemberLike,
+///     id::{marker::RoleMarker, Id},
+/// };
+///
+/// fn has_role(member: &impl MemberLike, role_id: Id<RoleMarker>) -> bool {
+///     member.roles().contains(&role_id)
+/// }
+/// ```
+pub trait MemberLike {
+    /// When the member's timeout will expire, if the member is timed out.
+    fn communication_disabled_until(&self) -> Option<Timestamp>;
+
+    /// When the member joined the guild.
+    fn joined_at(&self) -> Timestamp;
+
+    /// Nickname of the member in the guild.
+    fn nick(&self) -> Option<&str>;
+
+    /// When the member started boosting the guild.
+    fn premium_since(&self) -> Option<Timestamp>;
+
+    /// IDs of the roles the member has.
+    fn roles(&self) -> &[Id<RoleMarker>];
+}
+
+impl MemberLike for InteractionMember {
+    fn communication_disabled_until(&self) -> Option<Timestamp> {
+        self.communication_disabled_until
+    }
+
+    fn joined_at(&self) -> Timestamp {
+        self.joined_at
+    }
+
+    fn nick(&self) -> Option<&str> {
+        self.nick.as_deref()
+    }
+
+    fn premium_since(&self) -> Option<Timestamp> {
+        self.premium_since
+    }
+
+    fn roles(&self) -> &[Id<RoleMarker>] {
+        &self.roles
+    }
+}
+
+impl MemberLike for Member {
+    fn communication_disabled_until(&self) -> Option<Timestamp> {
+        self.communication_disabled_until
+    }
+
+    fn joined_at(&self) -> Timestamp {
+        self.joined_at
+    }
+
+    fn nick(&self) -> Option<&str> {
+        self.nick.as_deref()
+    }
+
+    fn premium_since(&self) -> Option<Timestamp> {
+        self.premium_since
+    }
+
+    fn roles(&self) -> &[Id<RoleMarker>] {
+        &self.roles
+    }
+}
+
+impl MemberLike for PartialMember {
+    fn communication_disabled_until(&self) -> Option<Timestamp> {
+        self.communication_disabled_until
+    }
+
+    fn joined_at(&self) -> Timestamp {
+        self.joined_at
+    }
+
+    fn nick(&self) -> Option<&str> {
+        self.nick.as_deref()
+    }
+
+    fn premium_since(&self) -> Option<Timestamp> {
+        self.premium_since
+    }
+
+    fn roles(&self) -> &[Id<RoleMarker>] {
+        &self.roles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemberLike;
+    use crate::{
+        application::interaction::application_command::InteractionMember,
+        guild::{Member, PartialMember, Permissions},
+        id::{marker::RoleMarker, Id},
+        user::User,
+        util::Timestamp,
+    };
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(InteractionMember: MemberLike);
+    assert_impl_all!(Member: MemberLike);
+    assert_impl_all!(PartialMember: MemberLike);
+
+    const JOINED_AT: i64 = 1_632_072_645;
+    const ROLE_ID: Id<RoleMarker> = Id::new(2);
+
+    /// Generic function only using the common accessors.
+    fn summary(member: &impl MemberLike) -> (Option<&str>, Timestamp, bool, bool) {
+        (
+            member.nick(),
+            member.joined_at(),
+            member.roles().contains(&ROLE_ID),
+            member.premium_since().is_some(),
+        )
+    }
+
+    fn user() -> User {
+        User {
+            accent_color: None,
+            avatar: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            id: Id::new(1),
+            locale: None,
+            mfa_enabled: None,
+            name: "twilight".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    #[test]
+    fn test_common_accessors() {
+        let joined_at = Timestamp::from_secs(JOINED_AT).unwrap();
+        let premium_since = Some(Timestamp::from_secs(JOINED_AT + 60).unwrap());
+
+        let member = Member {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: false,
+            guild_id: Id::new(3),
+            joined_at,
+            mute: false,
+            nick: Some("nick".to_owned()),
+            pending: false,
+            premium_since,
+            roles: Vec::from([ROLE_ID]),
+            user: user(),
+        };
+        let partial_member = PartialMember {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: false,
+            joined_at,
+            mute: false,
+            nick: Some("nick".to_owned()),
+            permissions: Some(Permissions::empty()),
+            premium_since,
+            roles: Vec::from([ROLE_ID]),
+            user: Some(user()),
+        };
+        let interaction_member = InteractionMember {
+            avatar: None,
+            communication_disabled_until: None,
+            joined_at,
+            nick: None,
+            pending: false,
+            permissions: Permissions::empty(),
+            premium_since: None,
+            roles: Vec::new(),
+        };
+
+        let expected = (Some("nick"), joined_at, true, true);
+        assert_eq!(expected, summary(&member));
+        assert_eq!(expected, summary(&partial_member));
+        assert_eq!(
+            (None, joined_at, false, false),
+            summary(&interaction_member)
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use super::MfaLevel;
+use serde::{Deserialize, Serialize};
+
+/// MFA level of a guild, returned after updating it.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildMfa {
+    /// MFA level required for moderation actions.
+    pub level: MfaLevel,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildMfa, MfaLevel};
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_mfa() {
+        let value = GuildMfa {
+            level: MfaLevel::Elevated,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildMfa",
+                    len: 1,
+                },
+                Token::Str("level"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
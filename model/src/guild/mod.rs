@@ -2,6 +2,7 @@ pub mod audit_log;
 pub mod member;
 
 mod ban;
+mod bulk_ban;
 mod default_message_notification_level;
 mod emoji;
 mod explicit_content_filter;
@@ -10,6 +11,9 @@ mod integration;
 mod integration_account;
 mod integration_application;
 mod integration_expire_behavior;
+mod integration_type;
+mod member_like;
+mod mfa;
 mod mfa_level;
 mod nsfw_level;
 mod partial_guild;
@@ -20,6 +24,7 @@ mod preview;
 mod prune;
 mod role;
 mod role_tags;
+mod soundboard_sound;
 mod system_channel_flags;
 mod unavailable_guild;
 mod vanity_url;
@@ -27,15 +32,36 @@ mod verification_level;
 mod widget;
 
 pub use self::{
-    ban::Ban, default_message_notification_level::DefaultMessageNotificationLevel, emoji::Emoji,
-    explicit_content_filter::ExplicitContentFilter, info::GuildInfo, integration::GuildIntegration,
-    integration_account::IntegrationAccount, integration_application::IntegrationApplication,
-    integration_expire_behavior::IntegrationExpireBehavior, member::Member, mfa_level::MfaLevel,
-    nsfw_level::NSFWLevel, partial_guild::PartialGuild, partial_member::PartialMember,
-    permissions::Permissions, premium_tier::PremiumTier, preview::GuildPreview, prune::GuildPrune,
-    role::Role, role_tags::RoleTags, system_channel_flags::SystemChannelFlags,
-    unavailable_guild::UnavailableGuild, vanity_url::VanityUrl,
-    verification_level::VerificationLevel, widget::GuildWidget,
+    ban::Ban,
+    bulk_ban::GuildBulkBan,
+    default_message_notification_level::DefaultMessageNotificationLevel,
+    emoji::Emoji,
+    explicit_content_filter::ExplicitContentFilter,
+    info::GuildInfo,
+    integration::GuildIntegration,
+    integration_account::IntegrationAccount,
+    integration_application::IntegrationApplication,
+    integration_expire_behavior::IntegrationExpireBehavior,
+    integration_type::GuildIntegrationType,
+    member::Member,
+    member_like::MemberLike,
+    mfa::GuildMfa,
+    mfa_level::MfaLevel,
+    nsfw_level::NSFWLevel,
+    partial_guild::PartialGuild,
+    partial_member::PartialMember,
+    permissions::Permissions,
+    premium_tier::PremiumTier,
+    preview::GuildPreview,
+    prune::GuildPrune,
+    role::Role,
+    role_tags::RoleTags,
+    soundboard_sound::SoundboardSound,
+    system_channel_flags::SystemChannelFlags,
+    unavailable_guild::UnavailableGuild,
+    vanity_url::VanityUrl,
+    verification_level::VerificationLevel,
+    widget::{GuildWidget, GuildWidgetChannel, GuildWidgetMember, GuildWidgetSettings},
 };
 
 use self::member::MemberListDeserializer;
@@ -6,17 +6,35 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+/// Preview of a guild.
+///
+/// Previews are available for discoverable guilds without the current user
+/// being a member, and for any guild the current user is a member of.
+///
+/// See [Discord Docs/Guild Preview].
+///
+/// [Discord Docs/Guild Preview]: https://discord.com/developers/docs/resources/guild#guild-preview-object
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct GuildPreview {
+    /// Approximate number of members in the guild.
     pub approximate_member_count: u64,
+    /// Approximate number of online members in the guild.
     pub approximate_presence_count: u64,
+    /// Description of the guild, if it is discoverable.
     pub description: Option<String>,
+    /// Hash of the guild's discovery splash.
     pub discovery_splash: Option<ImageHash>,
+    /// Guild's custom emojis.
     pub emojis: Vec<Emoji>,
+    /// Enabled guild features.
     pub features: Vec<String>,
+    /// ID of the guild.
     pub id: Id<GuildMarker>,
+    /// Name of the guild.
     pub name: String,
+    /// Hash of the guild's icon.
     pub icon: Option<ImageHash>,
+    /// Hash of the guild's splash.
     pub splash: Option<ImageHash>,
     /// Guild's custom stickers.
     pub stickers: Vec<Sticker>,
@@ -113,4 +131,63 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_guild_preview_payload() {
+        let raw = r#"{
+            "id": "197038439483310086",
+            "name": "Discord Testers",
+            "icon": null,
+            "splash": null,
+            "discovery_splash": null,
+            "emojis": [
+                {
+                    "name": "animated",
+                    "roles": [],
+                    "id": "3",
+                    "require_colons": true,
+                    "managed": false,
+                    "animated": true,
+                    "available": true
+                }
+            ],
+            "features": ["DISCOVERABLE", "COMMUNITY"],
+            "approximate_member_count": 60814,
+            "approximate_presence_count": 20034,
+            "description": null,
+            "stickers": []
+        }"#;
+
+        let value = GuildPreview {
+            approximate_member_count: 60_814,
+            approximate_presence_count: 20_034,
+            description: None,
+            discovery_splash: None,
+            emojis: Vec::from([Emoji {
+                animated: true,
+                available: true,
+                id: Id::new(3),
+                managed: false,
+                name: "animated".to_owned(),
+                require_colons: true,
+                roles: Vec::new(),
+                user: None,
+            }]),
+            features: Vec::from(["DISCOVERABLE".to_owned(), "COMMUNITY".to_owned()]),
+            id: Id::new(197_038_439_483_310_086),
+            name: "Discord Testers".to_owned(),
+            icon: None,
+            splash: None,
+            stickers: Vec::new(),
+        };
+
+        let deserialized = serde_json::from_str::<GuildPreview>(raw).unwrap();
+        assert_eq!(value, deserialized);
+
+        let serialized = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            value,
+            serde_json::from_str::<GuildPreview>(&serialized).unwrap()
+        );
+    }
 }
@@ -0,0 +1,87 @@
+use crate::{
+    application::command::Number,
+    id::{
+        marker::{EmojiMarker, GuildMarker, SoundboardSoundMarker},
+        Id,
+    },
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+
+/// Sound that can be played in a voice channel through the soundboard.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SoundboardSound {
+    /// Whether the sound can be used.
+    ///
+    /// May be `false` due to loss of server boosts.
+    pub available: bool,
+    /// ID of the sound's custom emoji.
+    pub emoji_id: Option<Id<EmojiMarker>>,
+    /// Unicode character of the sound's standard emoji.
+    pub emoji_name: Option<String>,
+    /// ID of the guild the sound is in.
+    ///
+    /// Not present for default sounds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// Name of the sound.
+    pub name: String,
+    /// ID of the sound.
+    pub sound_id: Id<SoundboardSoundMarker>,
+    /// User who created the sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// Volume of the sound, from 0 to 1.
+    pub volume: Number,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SoundboardSound;
+    use crate::{application::command::Number, id::Id};
+    use serde_test::Token;
+
+    #[test]
+    fn test_soundboard_sound() {
+        let value = SoundboardSound {
+            available: true,
+            emoji_id: None,
+            emoji_name: Some("\u{1f986}".to_owned()),
+            guild_id: Some(Id::new(1)),
+            name: "quack".to_owned(),
+            sound_id: Id::new(2),
+            user: None,
+            volume: Number(0.5),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "SoundboardSound",
+                    len: 7,
+                },
+                Token::Str("available"),
+                Token::Bool(true),
+                Token::Str("emoji_id"),
+                Token::None,
+                Token::Str("emoji_name"),
+                Token::Some,
+                Token::Str("\u{1f986}"),
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("quack"),
+                Token::Str("sound_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("volume"),
+                Token::NewtypeStruct { name: "Number" },
+                Token::F64(0.5),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
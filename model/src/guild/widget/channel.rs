@@ -0,0 +1,49 @@
+use crate::id::{marker::ChannelMarker, Id};
+use serde::{Deserialize, Serialize};
+
+/// Voice channel listed in a [`GuildWidget`].
+///
+/// [`GuildWidget`]: super::GuildWidget
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildWidgetChannel {
+    /// ID of the channel.
+    pub id: Id<ChannelMarker>,
+    /// Name of the channel.
+    pub name: String,
+    /// Sorting position of the channel.
+    pub position: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildWidgetChannel;
+    use crate::id::Id;
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_widget_channel() {
+        let value = GuildWidgetChannel {
+            id: Id::new(1),
+            name: "General".to_owned(),
+            position: 0,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildWidgetChannel",
+                    len: 3,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("General"),
+                Token::Str("position"),
+                Token::I64(0),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
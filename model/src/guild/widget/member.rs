@@ -0,0 +1,69 @@
+use crate::{gateway::presence::Status, util::image_hash::ImageHash};
+use serde::{Deserialize, Serialize};
+
+/// Online member listed in a [`GuildWidget`].
+///
+/// Members are anonymized, so their IDs and discriminators don't correspond
+/// to their user accounts.
+///
+/// [`GuildWidget`]: super::GuildWidget
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildWidgetMember {
+    /// Hash of the member's avatar.
+    pub avatar: Option<ImageHash>,
+    /// URL of the member's avatar.
+    pub avatar_url: String,
+    /// Anonymized discriminator of the member.
+    pub discriminator: String,
+    /// Anonymized ID of the member, unique only within the widget.
+    pub id: String,
+    /// Online status of the member.
+    pub status: Status,
+    /// Username of the member, or their nickname if they have one.
+    pub username: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildWidgetMember;
+    use crate::gateway::presence::Status;
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_widget_member() {
+        let value = GuildWidgetMember {
+            avatar: None,
+            avatar_url: "https://cdn.discordapp.com/widget-avatars/a/b".to_owned(),
+            discriminator: "0000".to_owned(),
+            id: "0".to_owned(),
+            status: Status::Online,
+            username: "twilight".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildWidgetMember",
+                    len: 6,
+                },
+                Token::Str("avatar"),
+                Token::None,
+                Token::Str("avatar_url"),
+                Token::Str("https://cdn.discordapp.com/widget-avatars/a/b"),
+                Token::Str("discriminator"),
+                Token::Str("0000"),
+                Token::Str("id"),
+                Token::Str("0"),
+                Token::Str("status"),
+                Token::UnitVariant {
+                    name: "Status",
+                    variant: "online",
+                },
+                Token::Str("username"),
+                Token::Str("twilight"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
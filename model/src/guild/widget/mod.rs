@@ -0,0 +1,119 @@
+mod channel;
+mod member;
+mod settings;
+
+pub use self::{
+    channel::GuildWidgetChannel, member::GuildWidgetMember, settings::GuildWidgetSettings,
+};
+
+use crate::id::{marker::GuildMarker, Id};
+use serde::{Deserialize, Serialize};
+
+/// Public widget of a guild.
+///
+/// Only available if the guild's widget is enabled in its
+/// [`GuildWidgetSettings`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildWidget {
+    /// Voice channels that members can join.
+    pub channels: Vec<GuildWidgetChannel>,
+    /// ID of the guild.
+    pub id: Id<GuildMarker>,
+    /// URL of the widget's instant invite, if its channel is set.
+    pub instant_invite: Option<String>,
+    /// Online members, limited to the first 100.
+    pub members: Vec<GuildWidgetMember>,
+    /// Name of the guild.
+    pub name: String,
+    /// Number of online members in the guild.
+    pub presence_count: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildWidget, GuildWidgetChannel, GuildWidgetMember};
+    use crate::{gateway::presence::Status, id::Id};
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_widget() {
+        let value = GuildWidget {
+            channels: vec![GuildWidgetChannel {
+                id: Id::new(2),
+                name: "General".to_owned(),
+                position: 0,
+            }],
+            id: Id::new(1),
+            instant_invite: Some("https://discord.com/invite/twilight".to_owned()),
+            members: vec![GuildWidgetMember {
+                avatar: None,
+                avatar_url: "https://cdn.discordapp.com/widget-avatars/a/b".to_owned(),
+                discriminator: "0000".to_owned(),
+                id: "0".to_owned(),
+                status: Status::Idle,
+                username: "twilight".to_owned(),
+            }],
+            name: "Twilight".to_owned(),
+            presence_count: 1,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildWidget",
+                    len: 6,
+                },
+                Token::Str("channels"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "GuildWidgetChannel",
+                    len: 3,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("name"),
+                Token::Str("General"),
+                Token::Str("position"),
+                Token::I64(0),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("instant_invite"),
+                Token::Some,
+                Token::Str("https://discord.com/invite/twilight"),
+                Token::Str("members"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "GuildWidgetMember",
+                    len: 6,
+                },
+                Token::Str("avatar"),
+                Token::None,
+                Token::Str("avatar_url"),
+                Token::Str("https://cdn.discordapp.com/widget-avatars/a/b"),
+                Token::Str("discriminator"),
+                Token::Str("0000"),
+                Token::Str("id"),
+                Token::Str("0"),
+                Token::Str("status"),
+                Token::UnitVariant {
+                    name: "Status",
+                    variant: "idle",
+                },
+                Token::Str("username"),
+                Token::Str("twilight"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("name"),
+                Token::Str("Twilight"),
+                Token::Str("presence_count"),
+                Token::U64(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
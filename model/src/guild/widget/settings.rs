@@ -1,21 +1,24 @@
 use crate::id::{marker::ChannelMarker, Id};
 use serde::{Deserialize, Serialize};
 
+/// Settings of a guild's widget.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-pub struct GuildWidget {
+pub struct GuildWidgetSettings {
+    /// ID of the channel that invites generated by the widget point to.
     pub channel_id: Option<Id<ChannelMarker>>,
+    /// Whether the widget is enabled.
     pub enabled: bool,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::GuildWidget;
+    use super::GuildWidgetSettings;
     use crate::id::Id;
     use serde_test::Token;
 
     #[test]
-    fn test_guild_widget() {
-        let value = GuildWidget {
+    fn test_guild_widget_settings() {
+        let value = GuildWidgetSettings {
             channel_id: Some(Id::new(111_111_111_111_111_111)),
             enabled: true,
         };
@@ -24,7 +27,7 @@ mod tests {
             &value,
             &[
                 Token::Struct {
-                    name: "GuildWidget",
+                    name: "GuildWidgetSettings",
                     len: 2,
                 },
                 Token::Str("channel_id"),
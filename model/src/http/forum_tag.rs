@@ -0,0 +1,136 @@
+//! Models for sending forum tags to Discord.
+
+use crate::id::{
+    marker::{EmojiMarker, TagMarker},
+    Id,
+};
+use serde::{Deserialize, Serialize};
+
+/// Tag of a forum channel to create or keep.
+///
+/// Tags without an ID are created, while tags with the ID of an existing tag
+/// update it.
+///
+/// At most one of [`emoji_id`] and [`emoji_name`] is set.
+///
+/// [`emoji_id`]: Self::emoji_id
+/// [`emoji_name`]: Self::emoji_name
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ForumTag {
+    /// ID of the guild's custom emoji of the tag.
+    pub emoji_id: Option<Id<EmojiMarker>>,
+    /// Unicode character of the emoji of the tag.
+    pub emoji_name: Option<String>,
+    /// ID of the tag, if it already exists.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id<TagMarker>>,
+    /// Whether the tag can only be applied by members with the
+    /// [`MANAGE_THREADS`] permission.
+    ///
+    /// [`MANAGE_THREADS`]: crate::guild::Permissions::MANAGE_THREADS
+    pub moderated: bool,
+    /// Name of the tag.
+    pub name: String,
+}
+
+impl From<crate::channel::forum::ForumTag> for ForumTag {
+    fn from(tag: crate::channel::forum::ForumTag) -> Self {
+        Self {
+            emoji_id: tag.emoji_id,
+            emoji_name: tag.emoji_name,
+            id: Some(tag.id),
+            moderated: tag.moderated,
+            name: tag.name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ForumTag;
+    use crate::id::Id;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(ForumTag: emoji_id, emoji_name, id, moderated, name);
+    assert_impl_all!(
+        ForumTag: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_forum_tag_new() {
+        let value = ForumTag {
+            emoji_id: None,
+            emoji_name: Some("🐛".to_owned()),
+            id: None,
+            moderated: false,
+            name: "bug".to_owned(),
+        };
+
+        serde_test::assert_ser_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ForumTag",
+                    len: 4,
+                },
+                Token::Str("emoji_id"),
+                Token::None,
+                Token::Str("emoji_name"),
+                Token::Some,
+                Token::Str("🐛"),
+                Token::Str("moderated"),
+                Token::Bool(false),
+                Token::Str("name"),
+                Token::Str("bug"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_forum_tag_existing() {
+        let value = ForumTag::from(crate::channel::forum::ForumTag {
+            emoji_id: Some(Id::new(2)),
+            emoji_name: None,
+            id: Id::new(1),
+            moderated: true,
+            name: "bug".to_owned(),
+        });
+
+        serde_test::assert_ser_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ForumTag",
+                    len: 5,
+                },
+                Token::Str("emoji_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("emoji_name"),
+                Token::None,
+                Token::Str("id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("moderated"),
+                Token::Bool(true),
+                Token::Str("name"),
+                Token::Str("bug"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
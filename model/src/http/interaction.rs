@@ -37,6 +37,82 @@ pub struct InteractionResponse {
     pub data: Option<InteractionResponseData>,
 }
 
+impl InteractionResponse {
+    /// Acknowledge an interaction, showing a loading state to the user, and
+    /// respond with a message later.
+    ///
+    /// The response has a type of
+    /// [`DeferredChannelMessageWithSource`]. Pass [`MessageFlags::EPHEMERAL`]
+    /// to make the eventual message only visible to the user who invoked the
+    /// interaction, or [`MessageFlags::empty`] otherwise.
+    ///
+    /// This is valid for all interactions that can be responded to with a
+    /// message, such as commands. Use [`defer_update`] to acknowledge a
+    /// component interaction without sending a new message.
+    ///
+    /// # Examples
+    ///
+    /// Defer a command's response, which will be ephemeral:
+    ///
+    /// ```
+    /// use twilight_model::{
+    ///     channel::message::MessageFlags,
+    ///     http::interaction::{InteractionResponse, InteractionResponseType},
+    /// };
+    ///
+    /// let response = InteractionResponse::defer(MessageFlags::EPHEMERAL);
+    ///
+    /// assert_eq!(
+    ///     InteractionResponseType::DeferredChannelMessageWithSource,
+    ///     response.kind,
+    /// );
+    /// ```
+    ///
+    /// [`DeferredChannelMessageWithSource`]: InteractionResponseType::DeferredChannelMessageWithSource
+    /// [`defer_update`]: Self::defer_update
+    pub const fn defer(flags: MessageFlags) -> Self {
+        let data = if flags.is_empty() {
+            None
+        } else {
+            Some(InteractionResponseData {
+                allowed_mentions: None,
+                attachments: None,
+                choices: None,
+                components: None,
+                content: None,
+                custom_id: None,
+                embeds: None,
+                flags: Some(flags),
+                title: None,
+                tts: None,
+            })
+        };
+
+        Self {
+            kind: InteractionResponseType::DeferredChannelMessageWithSource,
+            data,
+        }
+    }
+
+    /// Acknowledge a component or modal submit interaction and edit the
+    /// message it is attached to later.
+    ///
+    /// The response has a type of [`DeferredUpdateMessage`], for which no
+    /// loading state is shown to the user.
+    ///
+    /// This is only valid for component and modal submit interactions. Use
+    /// [`defer`] to acknowledge other interactions, such as commands.
+    ///
+    /// [`DeferredUpdateMessage`]: InteractionResponseType::DeferredUpdateMessage
+    /// [`defer`]: Self::defer
+    pub const fn defer_update() -> Self {
+        Self {
+            kind: InteractionResponseType::DeferredUpdateMessage,
+            data: None,
+        }
+    }
+}
+
 /// Data included in an interaction response.
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 pub struct InteractionResponseData {
@@ -183,6 +259,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_interaction_response_defer() {
+        serde_test::assert_tokens(
+            &InteractionResponse::defer(MessageFlags::empty()),
+            &[
+                Token::Struct {
+                    name: "InteractionResponse",
+                    len: 1,
+                },
+                Token::Str("type"),
+                Token::U8(5),
+                Token::StructEnd,
+            ],
+        );
+
+        serde_test::assert_tokens(
+            &InteractionResponse::defer(MessageFlags::EPHEMERAL),
+            &[
+                Token::Struct {
+                    name: "InteractionResponse",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(5),
+                Token::Str("data"),
+                Token::Some,
+                Token::Struct {
+                    name: "InteractionResponseData",
+                    len: 1,
+                },
+                Token::Str("flags"),
+                Token::Some,
+                Token::U64(64),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_interaction_response_defer_update() {
+        serde_test::assert_tokens(
+            &InteractionResponse::defer_update(),
+            &[
+                Token::Struct {
+                    name: "InteractionResponse",
+                    len: 1,
+                },
+                Token::Str("type"),
+                Token::U8(6),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn test_interaction_response_with_attachments() {
         let value = InteractionResponse {
@@ -1,5 +1,6 @@
 //! Models used when sending data to Discord.
 
 pub mod attachment;
+pub mod forum_tag;
 pub mod interaction;
 pub mod permission_overwrite;
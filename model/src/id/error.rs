@@ -0,0 +1,99 @@
+//! Error detail implementation for [`Id`] parsing.
+//!
+//! [`Id`]: super::Id
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    num::{IntErrorKind, ParseIntError},
+};
+
+/// Reason that a string couldn't be parsed as an [`Id`].
+///
+/// [`Id`]: super::Id
+#[derive(Debug)]
+pub struct IdParseError {
+    /// Type of error that occurred.
+    kind: IdParseErrorType,
+    /// Source of the error, if there is any.
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl IdParseError {
+    /// Error that was caused by the value being zero.
+    pub(super) const ZERO: IdParseError = IdParseError {
+        kind: IdParseErrorType::Zero,
+        source: None,
+    };
+
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &IdParseErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (IdParseErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    /// Create a new error from a [`ParseIntError`].
+    pub(super) fn from_parse_int(source: ParseIntError) -> Self {
+        let kind = if matches!(source.kind(), IntErrorKind::PosOverflow) {
+            IdParseErrorType::Overflow
+        } else {
+            IdParseErrorType::Invalid
+        };
+
+        Self {
+            kind,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for IdParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            IdParseErrorType::Invalid => f.write_str("value is not an unsigned integer"),
+            IdParseErrorType::Overflow => f.write_str("value is too large to fit in a u64"),
+            IdParseErrorType::Zero => f.write_str("value is zero"),
+        }
+    }
+}
+
+impl Error for IdParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`IdParseError`] that occurred.
+#[derive(Debug, Eq, PartialEq)]
+pub enum IdParseErrorType {
+    /// Value is empty or contains characters other than ASCII digits.
+    Invalid,
+    /// Value is larger than [`u64::MAX`].
+    Overflow,
+    /// Value is zero.
+    Zero,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IdParseError, IdParseErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(IdParseErrorType: Debug, Send, Sync);
+    assert_impl_all!(IdParseError: Error, Send, Sync);
+}
@@ -76,6 +76,15 @@ pub struct CommandVersionMarker;
 #[non_exhaustive]
 pub struct EmojiMarker;
 
+/// Marker for entitlement IDs.
+///
+/// Types such as [`Entitlement`] use this ID marker.
+///
+/// [`Entitlement`]: crate::application::monetization::Entitlement
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct EntitlementMarker;
+
 /// Marker for generic IDs.
 ///
 /// Types such as [`AuditLogChange::Id`] or [`CommandOptionValue`] use this
@@ -175,6 +184,24 @@ pub struct ScheduledEventMarker;
 #[non_exhaustive]
 pub struct ScheduledEventEntityMarker;
 
+/// Marker for SKU IDs.
+///
+/// Types such as [`Entitlement`] use this ID marker.
+///
+/// [`Entitlement`]: crate::application::monetization::Entitlement
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct SkuMarker;
+
+/// Marker for soundboard sound IDs.
+///
+/// Types such as [`SoundboardSound`] use this ID marker.
+///
+/// [`SoundboardSound`]: crate::guild::SoundboardSound
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct SoundboardSoundMarker;
+
 /// Marker for stage IDs.
 ///
 /// Types such as [`StageInstance`] use this ID marker.
@@ -222,6 +249,15 @@ pub struct StickerPackMarker;
 #[non_exhaustive]
 pub struct StickerPackSkuMarker;
 
+/// Marker for forum tag IDs.
+///
+/// Types such as [`ForumTag`] use this ID marker.
+///
+/// [`ForumTag`]: crate::channel::forum::ForumTag
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TagMarker;
+
 /// Marker for user IDs.
 ///
 /// Types such as [`Channel`] or [`User`] use this ID marker.
@@ -40,9 +40,13 @@
 
 pub mod marker;
 
+mod error;
 mod r#type;
 
-pub use self::r#type::*;
+pub use self::{
+    error::{IdParseError, IdParseErrorType},
+    r#type::*,
+};
 
 use serde::{
     de::{Deserialize, Deserializer, Error as DeError, Unexpected, Visitor},
@@ -54,7 +58,7 @@ use std::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     hash::{Hash, Hasher},
     marker::PhantomData,
-    num::{NonZeroI64, NonZeroU64, ParseIntError, TryFromIntError},
+    num::{NonZeroI64, NonZeroU64, TryFromIntError},
     str::FromStr,
 };
 
@@ -312,10 +316,23 @@ impl<T> From<Id<T>> for NonZeroU64 {
 }
 
 impl<T> FromStr for Id<T> {
-    type Err = ParseIntError;
+    type Err = IdParseError;
 
+    /// Parse an ID from a string of its integer value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IdParseErrorType::Invalid`] error type if the string is
+    /// empty or isn't an unsigned integer.
+    ///
+    /// Returns an [`IdParseErrorType::Overflow`] error type if the value is
+    /// larger than [`u64::MAX`].
+    ///
+    /// Returns an [`IdParseErrorType::Zero`] error type if the value is zero.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        NonZeroU64::from_str(s).map(Self::from_nonzero)
+        let value = u64::from_str(s).map_err(IdParseError::from_parse_int)?;
+
+        Self::new_checked(value).ok_or(IdParseError::ZERO)
     }
 }
 
@@ -408,7 +425,7 @@ mod tests {
             CommandVersionMarker, EmojiMarker, GenericMarker, GuildMarker, IntegrationMarker,
             InteractionMarker, MessageMarker, RoleMarker, StageMarker, UserMarker, WebhookMarker,
         },
-        Id,
+        Id, IdParseErrorType,
     };
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
@@ -476,6 +493,33 @@ mod tests {
         Ok(())
     }
 
+    /// Test that parsing IDs from strings rejects non-numeric, overflowing, and
+    /// zero values with the corresponding error types.
+    #[test]
+    fn test_from_str_errors() {
+        fn kind(s: &str) -> IdParseErrorType {
+            Id::<GenericMarker>::from_str(s).unwrap_err().into_parts().0
+        }
+
+        assert_eq!(
+            Some(1),
+            Id::<GenericMarker>::from_str("1").ok().map(Id::get)
+        );
+        assert_eq!(
+            Some(u64::MAX),
+            Id::<GenericMarker>::from_str("18446744073709551615")
+                .ok()
+                .map(Id::get)
+        );
+
+        assert_eq!(IdParseErrorType::Invalid, kind(""));
+        assert_eq!(IdParseErrorType::Invalid, kind("-1"));
+        assert_eq!(IdParseErrorType::Invalid, kind("123a"));
+        assert_eq!(IdParseErrorType::Overflow, kind("18446744073709551616"));
+        assert_eq!(IdParseErrorType::Zero, kind("0"));
+        assert_eq!(IdParseErrorType::Zero, kind("000"));
+    }
+
     /// Test that conversion methods are correct.
     #[test]
     fn test_conversions() {
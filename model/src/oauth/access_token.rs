@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+/// Access token granted to an application through `OAuth2`.
+///
+/// Returned when exchanging an authorization code or refreshing an access
+/// token. Refer to [Discord Docs/Access Token Response].
+///
+/// [Discord Docs/Access Token Response]: https://discord.com/developers/docs/topics/oauth2#authorization-code-grant-access-token-response
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AccessToken {
+    /// Token to use in requests on behalf of the user.
+    pub access_token: String,
+    /// Number of seconds from when the token was granted until it expires.
+    pub expires_in: u64,
+    /// Token to use to refresh the access token once it expires.
+    ///
+    /// Not present for tokens granted through the client credentials grant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Space separated list of scopes granted to the token.
+    pub scope: String,
+    /// Type of the token, which is "Bearer".
+    pub token_type: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AccessToken;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        AccessToken: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_access_token() {
+        let value = AccessToken {
+            access_token: "6qrZcUqja7812RVdnEKjpzOL4CvHBFG".to_owned(),
+            expires_in: 604_800,
+            refresh_token: Some("D43f5y0ahjqew82jZ4NViEr2YafMKhue".to_owned()),
+            scope: "identify".to_owned(),
+            token_type: "Bearer".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AccessToken",
+                    len: 5,
+                },
+                Token::Str("access_token"),
+                Token::Str("6qrZcUqja7812RVdnEKjpzOL4CvHBFG"),
+                Token::Str("expires_in"),
+                Token::U64(604_800),
+                Token::Str("refresh_token"),
+                Token::Some,
+                Token::Str("D43f5y0ahjqew82jZ4NViEr2YafMKhue"),
+                Token::Str("scope"),
+                Token::Str("identify"),
+                Token::Str("token_type"),
+                Token::Str("Bearer"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
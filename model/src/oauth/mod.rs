@@ -8,14 +8,15 @@ pub mod current_application_info {
     pub type CurrentApplicationInfo = super::Application;
 }
 
+mod access_token;
 mod application;
 mod application_flags;
 mod install_params;
 mod partial_application;
 
 pub use self::{
-    application::Application, application_flags::ApplicationFlags, install_params::InstallParams,
-    partial_application::PartialApplication,
+    access_token::AccessToken, application::Application, application_flags::ApplicationFlags,
+    install_params::InstallParams, partial_application::PartialApplication,
 };
 
 #[allow(deprecated)]
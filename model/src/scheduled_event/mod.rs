@@ -1,8 +1,12 @@
 //! Types for interacting with scheduled events.
 
+mod recurrence_rule;
 mod user;
 
-pub use self::user::GuildScheduledEventUser;
+pub use self::{
+    recurrence_rule::{Frequency, Month, NWeekday, RecurrenceRule, Weekday},
+    user::GuildScheduledEventUser,
+};
 
 use crate::{
     id::{
@@ -67,6 +71,9 @@ pub struct GuildScheduledEvent {
     pub name: String,
     /// Privacy level of the event.
     pub privacy_level: PrivacyLevel,
+    /// Rule describing how often the event repeats, if it does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence_rule: Option<RecurrenceRule>,
     /// Scheduled end time of the event.
     ///
     /// Required on events of type [`EntityType::External`]. It also may be
@@ -127,6 +134,29 @@ pub enum Status {
     Cancelled = 4,
 }
 
+impl Status {
+    /// Whether an event with this status may be updated to another status.
+    ///
+    /// Scheduled events may be made active or cancelled and active events may
+    /// be made complete. Completed and cancelled events can't be updated.
+    /// Updating an event to its current status is not a transition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::scheduled_event::Status;
+    ///
+    /// assert!(Status::Scheduled.can_transition_to(Status::Active));
+    /// assert!(!Status::Active.can_transition_to(Status::Cancelled));
+    /// ```
+    pub const fn can_transition_to(self, status: Self) -> bool {
+        matches!(
+            (self, status),
+            (Self::Scheduled, Self::Active | Self::Cancelled) | (Self::Active, Self::Completed)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,6 +181,7 @@ mod tests {
             image: Some(COVER),
             name: "garfield dance party".into(),
             privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
             scheduled_end_time: None,
             scheduled_start_time,
             status: Status::Completed,
@@ -203,4 +234,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_status_transitions() {
+        assert!(Status::Scheduled.can_transition_to(Status::Active));
+        assert!(Status::Scheduled.can_transition_to(Status::Cancelled));
+        assert!(Status::Active.can_transition_to(Status::Completed));
+
+        assert!(!Status::Scheduled.can_transition_to(Status::Completed));
+        assert!(!Status::Scheduled.can_transition_to(Status::Scheduled));
+        assert!(!Status::Active.can_transition_to(Status::Scheduled));
+        assert!(!Status::Active.can_transition_to(Status::Cancelled));
+        assert!(!Status::Completed.can_transition_to(Status::Active));
+        assert!(!Status::Cancelled.can_transition_to(Status::Scheduled));
+    }
 }
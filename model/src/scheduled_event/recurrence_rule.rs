@@ -0,0 +1,255 @@
+use crate::util::Timestamp;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Rule describing how often a [`GuildScheduledEvent`] repeats.
+///
+/// Discord only supports a subset of the rules representable by this type;
+/// refer to [Discord Docs/Recurrence Rule] for the constraints.
+///
+/// [`GuildScheduledEvent`]: super::GuildScheduledEvent
+/// [Discord Docs/Recurrence Rule]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RecurrenceRule {
+    /// Specific months of the year to repeat on.
+    ///
+    /// Only valid for [`Frequency::Yearly`] and must be paired with
+    /// [`by_month_day`].
+    ///
+    /// [`by_month_day`]: Self::by_month_day
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_month: Option<Vec<Month>>,
+    /// Specific days of the month to repeat on.
+    ///
+    /// Only valid for [`Frequency::Yearly`] and must be paired with
+    /// [`by_month`].
+    ///
+    /// [`by_month`]: Self::by_month
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_month_day: Option<Vec<u8>>,
+    /// Specific days within a specific week of the month to repeat on.
+    ///
+    /// Only valid for [`Frequency::Monthly`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_n_weekday: Option<Vec<NWeekday>>,
+    /// Specific days of the week to repeat on.
+    ///
+    /// Only valid for [`Frequency::Daily`] and [`Frequency::Weekly`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_weekday: Option<Vec<Weekday>>,
+    /// Specific days of the year to repeat on.
+    ///
+    /// Set by Discord and can't be set when creating or updating an event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_year_day: Option<Vec<u16>>,
+    /// Total number of times the event is allowed to repeat.
+    ///
+    /// Set by Discord and can't be set when creating or updating an event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    /// When the recurrence ends.
+    ///
+    /// Set by Discord and can't be set when creating or updating an event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<Timestamp>,
+    /// How often the event repeats.
+    pub frequency: Frequency,
+    /// Spacing between events, in units of the [`frequency`].
+    ///
+    /// For example, a [`Frequency::Weekly`] rule with an interval of 2 repeats
+    /// every other week.
+    ///
+    /// [`frequency`]: Self::frequency
+    pub interval: u16,
+    /// When the recurrence starts.
+    pub start: Timestamp,
+}
+
+/// How often a [`RecurrenceRule`] repeats.
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum Frequency {
+    /// Event repeats every year.
+    Yearly = 0,
+    /// Event repeats every month.
+    Monthly = 1,
+    /// Event repeats every week.
+    Weekly = 2,
+    /// Event repeats every day.
+    Daily = 3,
+}
+
+/// Month of the year a [`RecurrenceRule`] repeats in.
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum Month {
+    /// January.
+    January = 1,
+    /// February.
+    February = 2,
+    /// March.
+    March = 3,
+    /// April.
+    April = 4,
+    /// May.
+    May = 5,
+    /// June.
+    June = 6,
+    /// July.
+    July = 7,
+    /// August.
+    August = 8,
+    /// September.
+    September = 9,
+    /// October.
+    October = 10,
+    /// November.
+    November = 11,
+    /// December.
+    December = 12,
+}
+
+/// Day within a specific week of the month a [`RecurrenceRule`] repeats on.
+///
+/// For example, the second Tuesday of the month.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct NWeekday {
+    /// Day of the week.
+    pub day: Weekday,
+    /// Week of the month, from 1 to 5.
+    pub n: u8,
+}
+
+/// Day of the week a [`RecurrenceRule`] repeats on.
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum Weekday {
+    /// Monday.
+    Monday = 0,
+    /// Tuesday.
+    Tuesday = 1,
+    /// Wednesday.
+    Wednesday = 2,
+    /// Thursday.
+    Thursday = 3,
+    /// Friday.
+    Friday = 4,
+    /// Saturday.
+    Saturday = 5,
+    /// Sunday.
+    Sunday = 6,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frequency, Month, NWeekday, RecurrenceRule, Weekday};
+    use crate::util::Timestamp;
+    use serde_test::Token;
+    use std::error::Error;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&Frequency::Yearly, &[Token::U8(0)]);
+        serde_test::assert_tokens(&Frequency::Monthly, &[Token::U8(1)]);
+        serde_test::assert_tokens(&Frequency::Weekly, &[Token::U8(2)]);
+        serde_test::assert_tokens(&Frequency::Daily, &[Token::U8(3)]);
+        serde_test::assert_tokens(&Month::January, &[Token::U8(1)]);
+        serde_test::assert_tokens(&Month::December, &[Token::U8(12)]);
+        serde_test::assert_tokens(&Weekday::Monday, &[Token::U8(0)]);
+        serde_test::assert_tokens(&Weekday::Sunday, &[Token::U8(6)]);
+    }
+
+    #[test]
+    fn test_n_weekday() {
+        let value = NWeekday {
+            day: Weekday::Tuesday,
+            n: 2,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "NWeekday",
+                    len: 2,
+                },
+                Token::Str("day"),
+                Token::U8(1),
+                Token::Str("n"),
+                Token::U8(2),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_weekly() -> Result<(), Box<dyn Error>> {
+        let start = Timestamp::parse("2024-08-06T18:00:00.000000+00:00")?;
+
+        let value = RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: None,
+            by_weekday: Some(Vec::from([Weekday::Tuesday])),
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: Frequency::Weekly,
+            interval: 2,
+            start,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "RecurrenceRule",
+                    len: 4,
+                },
+                Token::Str("by_weekday"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::U8(1),
+                Token::SeqEnd,
+                Token::Str("frequency"),
+                Token::U8(2),
+                Token::Str("interval"),
+                Token::U16(2),
+                Token::Str("start"),
+                Token::Str("2024-08-06T18:00:00.000000+00:00"),
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+
+    /// Test that a weekly rule as sent by Discord, with all of the unused
+    /// fields set to null, deserializes.
+    #[test]
+    fn test_weekly_nulls() -> Result<(), Box<dyn Error>> {
+        let input = r#"{
+            "start": "2024-08-06T18:00:00+00:00",
+            "end": null,
+            "frequency": 2,
+            "interval": 1,
+            "by_weekday": [1],
+            "by_n_weekday": null,
+            "by_month": null,
+            "by_month_day": null,
+            "by_year_day": null,
+            "count": null
+        }"#;
+
+        let value = serde_json::from_str::<RecurrenceRule>(input)?;
+
+        assert_eq!(Frequency::Weekly, value.frequency);
+        assert_eq!(1, value.interval);
+        assert_eq!(Some(Vec::from([Weekday::Tuesday])), value.by_weekday);
+        assert_eq!(Timestamp::parse("2024-08-06T18:00:00+00:00")?, value.start);
+        assert!(value.by_n_weekday.is_none());
+        assert!(value.count.is_none());
+
+        Ok(())
+    }
+}
@@ -205,8 +205,13 @@ mod tests {
                 channels: Vec::from([
                     Channel {
                         application_id: None,
+                        available_tags: None,
                         bitrate: None,
                         default_auto_archive_duration: None,
+                        default_forum_layout: None,
+                        default_reaction_emoji: None,
+                        default_sort_order: None,
+                        default_thread_rate_limit_per_user: None,
                         guild_id: None,
                         icon: None,
                         id: Id::new(1),
@@ -234,8 +239,13 @@ mod tests {
                     },
                     Channel {
                         application_id: None,
+                        available_tags: None,
                         bitrate: None,
                         default_auto_archive_duration: None,
+                        default_forum_layout: None,
+                        default_reaction_emoji: None,
+                        default_sort_order: None,
+                        default_thread_rate_limit_per_user: None,
                         guild_id: None,
                         icon: None,
                         id: Id::new(2),
@@ -276,8 +286,13 @@ mod tests {
                     },
                     Channel {
                         application_id: None,
+                        available_tags: None,
                         bitrate: None,
                         default_auto_archive_duration: None,
+                        default_forum_layout: None,
+                        default_reaction_emoji: None,
+                        default_sort_order: None,
+                        default_thread_rate_limit_per_user: None,
                         guild_id: None,
                         icon: None,
                         id: Id::new(3),
@@ -305,8 +320,13 @@ mod tests {
                     },
                     Channel {
                         application_id: None,
+                        available_tags: None,
                         bitrate: Some(64000),
                         default_auto_archive_duration: None,
+                        default_forum_layout: None,
+                        default_reaction_emoji: None,
+                        default_sort_order: None,
+                        default_thread_rate_limit_per_user: None,
                         guild_id: None,
                         icon: None,
                         id: Id::new(4),
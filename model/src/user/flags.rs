@@ -35,6 +35,48 @@ bitflags! {
         /// Bot uses only HTTP interactions and is shown in the online member
         /// list.
         const BOT_HTTP_INTERACTIONS = 1 << 19;
+        /// Active developer.
+        const ACTIVE_DEVELOPER = 1 << 22;
+    }
+}
+
+impl UserFlags {
+    /// Bug hunter flags of any level.
+    pub const BUG_HUNTER: Self = Self::BUG_HUNTER_LEVEL_1.union(Self::BUG_HUNTER_LEVEL_2);
+
+    /// Hypesquad Online house flags.
+    pub const HYPESQUAD_ONLINE_HOUSES: Self = Self::HYPESQUAD_ONLINE_HOUSE_1
+        .union(Self::HYPESQUAD_ONLINE_HOUSE_2)
+        .union(Self::HYPESQUAD_ONLINE_HOUSE_3);
+
+    /// Whether the user is an active developer.
+    pub const fn is_active_developer(self) -> bool {
+        self.contains(Self::ACTIVE_DEVELOPER)
+    }
+
+    /// Whether the user is a bug hunter of any level.
+    pub const fn is_bug_hunter(self) -> bool {
+        self.intersects(Self::BUG_HUNTER)
+    }
+
+    /// Whether the user is a member of any Hypesquad Online house.
+    pub const fn is_hypesquad_house_member(self) -> bool {
+        self.intersects(Self::HYPESQUAD_ONLINE_HOUSES)
+    }
+
+    /// Whether the user is a partnered server owner.
+    pub const fn is_partner(self) -> bool {
+        self.contains(Self::PARTNER)
+    }
+
+    /// Whether the user is a Discord employee.
+    pub const fn is_staff(self) -> bool {
+        self.contains(Self::STAFF)
+    }
+
+    /// Whether the user is a verified bot.
+    pub const fn is_verified_bot(self) -> bool {
+        self.contains(Self::VERIFIED_BOT)
     }
 }
 
@@ -52,3 +94,79 @@ impl Serialize for UserFlags {
         serializer.serialize_u64(self.bits())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::UserFlags;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_impl_all, const_assert_eq};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        UserFlags: Copy,
+        Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    const_assert_eq!(UserFlags::STAFF.bits(), 1);
+    const_assert_eq!(UserFlags::PARTNER.bits(), 1 << 1);
+    const_assert_eq!(UserFlags::HYPESQUAD.bits(), 1 << 2);
+    const_assert_eq!(UserFlags::BUG_HUNTER_LEVEL_1.bits(), 1 << 3);
+    const_assert_eq!(UserFlags::HYPESQUAD_ONLINE_HOUSE_1.bits(), 1 << 6);
+    const_assert_eq!(UserFlags::HYPESQUAD_ONLINE_HOUSE_2.bits(), 1 << 7);
+    const_assert_eq!(UserFlags::HYPESQUAD_ONLINE_HOUSE_3.bits(), 1 << 8);
+    const_assert_eq!(UserFlags::PREMIUM_EARLY_SUPPORTER.bits(), 1 << 9);
+    const_assert_eq!(UserFlags::TEAM_PSEUDO_USER.bits(), 1 << 10);
+    const_assert_eq!(UserFlags::BUG_HUNTER_LEVEL_2.bits(), 1 << 14);
+    const_assert_eq!(UserFlags::VERIFIED_BOT.bits(), 1 << 16);
+    const_assert_eq!(UserFlags::VERIFIED_DEVELOPER.bits(), 1 << 17);
+    const_assert_eq!(UserFlags::CERTIFIED_MODERATOR.bits(), 1 << 18);
+    const_assert_eq!(UserFlags::BOT_HTTP_INTERACTIONS.bits(), 1 << 19);
+    const_assert_eq!(UserFlags::ACTIVE_DEVELOPER.bits(), 1 << 22);
+
+    #[test]
+    fn test_serde() {
+        let value = UserFlags::PARTNER | UserFlags::HYPESQUAD_ONLINE_HOUSE_2;
+
+        serde_test::assert_tokens(&value, &[Token::U64(130)]);
+    }
+
+    #[test]
+    fn test_deserialize_unknown_bits() {
+        // Undocumented bits are truncated.
+        serde_test::assert_de_tokens(
+            &(UserFlags::STAFF | UserFlags::ACTIVE_DEVELOPER),
+            &[Token::U64(1 | 1 << 22 | 1 << 40)],
+        );
+    }
+
+    #[test]
+    fn test_predicates() {
+        let flags = UserFlags::BUG_HUNTER_LEVEL_2
+            | UserFlags::HYPESQUAD_ONLINE_HOUSE_3
+            | UserFlags::ACTIVE_DEVELOPER;
+
+        assert!(flags.is_active_developer());
+        assert!(flags.is_bug_hunter());
+        assert!(flags.is_hypesquad_house_member());
+        assert!(!flags.is_partner());
+        assert!(!flags.is_staff());
+        assert!(!flags.is_verified_bot());
+
+        let flags = UserFlags::STAFF | UserFlags::PARTNER | UserFlags::VERIFIED_BOT;
+
+        assert!(!flags.is_active_developer());
+        assert!(!flags.is_bug_hunter());
+        assert!(!flags.is_hypesquad_house_member());
+        assert!(flags.is_partner());
+        assert!(flags.is_staff());
+        assert!(flags.is_verified_bot());
+    }
+}
@@ -0,0 +1,255 @@
+//! Locales supported by Discord.
+
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Locale supported by Discord, such as the locale of a user or the keys of
+/// command localizations.
+///
+/// Refer to [Discord Docs/Locales].
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::util::Locale;
+///
+/// assert_eq!(Locale::EnglishUs, Locale::from("en-US"));
+/// assert_eq!("pt-BR", Locale::PortugueseBrazilian.as_str());
+///
+/// // Locales Discord has added since are retained as they are.
+/// assert_eq!(Locale::Unknown("xx".to_owned()), Locale::from("xx"));
+/// ```
+///
+/// [Discord Docs/Locales]: https://discord.com/developers/docs/reference#locales
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Locale {
+    /// Indonesian, `id`.
+    Indonesian,
+    /// Danish, `da`.
+    Danish,
+    /// German, `de`.
+    German,
+    /// English (United Kingdom), `en-GB`.
+    EnglishUk,
+    /// English (United States), `en-US`.
+    EnglishUs,
+    /// Spanish (Spain), `es-ES`.
+    Spanish,
+    /// Spanish (Latin America), `es-419`.
+    SpanishLatam,
+    /// French, `fr`.
+    French,
+    /// Croatian, `hr`.
+    Croatian,
+    /// Italian, `it`.
+    Italian,
+    /// Lithuanian, `lt`.
+    Lithuanian,
+    /// Hungarian, `hu`.
+    Hungarian,
+    /// Dutch, `nl`.
+    Dutch,
+    /// Norwegian, `no`.
+    Norwegian,
+    /// Polish, `pl`.
+    Polish,
+    /// Portuguese (Brazil), `pt-BR`.
+    PortugueseBrazilian,
+    /// Romanian, `ro`.
+    Romanian,
+    /// Finnish, `fi`.
+    Finnish,
+    /// Swedish, `sv-SE`.
+    Swedish,
+    /// Vietnamese, `vi`.
+    Vietnamese,
+    /// Turkish, `tr`.
+    Turkish,
+    /// Czech, `cs`.
+    Czech,
+    /// Greek, `el`.
+    Greek,
+    /// Bulgarian, `bg`.
+    Bulgarian,
+    /// Russian, `ru`.
+    Russian,
+    /// Ukrainian, `uk`.
+    Ukrainian,
+    /// Hindi, `hi`.
+    Hindi,
+    /// Thai, `th`.
+    Thai,
+    /// Chinese (China), `zh-CN`.
+    ChineseChina,
+    /// Japanese, `ja`.
+    Japanese,
+    /// Chinese (Taiwan), `zh-TW`.
+    ChineseTaiwan,
+    /// Korean, `ko`.
+    Korean,
+    /// Locale unknown to Twilight.
+    Unknown(String),
+}
+
+impl Locale {
+    /// Locale code used by Discord, such as `en-US`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Indonesian => "id",
+            Self::Danish => "da",
+            Self::German => "de",
+            Self::EnglishUk => "en-GB",
+            Self::EnglishUs => "en-US",
+            Self::Spanish => "es-ES",
+            Self::SpanishLatam => "es-419",
+            Self::French => "fr",
+            Self::Croatian => "hr",
+            Self::Italian => "it",
+            Self::Lithuanian => "lt",
+            Self::Hungarian => "hu",
+            Self::Dutch => "nl",
+            Self::Norwegian => "no",
+            Self::Polish => "pl",
+            Self::PortugueseBrazilian => "pt-BR",
+            Self::Romanian => "ro",
+            Self::Finnish => "fi",
+            Self::Swedish => "sv-SE",
+            Self::Vietnamese => "vi",
+            Self::Turkish => "tr",
+            Self::Czech => "cs",
+            Self::Greek => "el",
+            Self::Bulgarian => "bg",
+            Self::Russian => "ru",
+            Self::Ukrainian => "uk",
+            Self::Hindi => "hi",
+            Self::Thai => "th",
+            Self::ChineseChina => "zh-CN",
+            Self::Japanese => "ja",
+            Self::ChineseTaiwan => "zh-TW",
+            Self::Korean => "ko",
+            Self::Unknown(locale) => locale,
+        }
+    }
+
+    /// Parse a known locale code, returning [`None`] if it is unknown.
+    fn from_known(locale: &str) -> Option<Self> {
+        Some(match locale {
+            "id" => Self::Indonesian,
+            "da" => Self::Danish,
+            "de" => Self::German,
+            "en-GB" => Self::EnglishUk,
+            "en-US" => Self::EnglishUs,
+            "es-ES" => Self::Spanish,
+            "es-419" => Self::SpanishLatam,
+            "fr" => Self::French,
+            "hr" => Self::Croatian,
+            "it" => Self::Italian,
+            "lt" => Self::Lithuanian,
+            "hu" => Self::Hungarian,
+            "nl" => Self::Dutch,
+            "no" => Self::Norwegian,
+            "pl" => Self::Polish,
+            "pt-BR" => Self::PortugueseBrazilian,
+            "ro" => Self::Romanian,
+            "fi" => Self::Finnish,
+            "sv-SE" => Self::Swedish,
+            "vi" => Self::Vietnamese,
+            "tr" => Self::Turkish,
+            "cs" => Self::Czech,
+            "el" => Self::Greek,
+            "bg" => Self::Bulgarian,
+            "ru" => Self::Russian,
+            "uk" => Self::Ukrainian,
+            "hi" => Self::Hindi,
+            "th" => Self::Thai,
+            "zh-CN" => Self::ChineseChina,
+            "ja" => Self::Japanese,
+            "zh-TW" => Self::ChineseTaiwan,
+            "ko" => Self::Korean,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for Locale {
+    fn from(locale: &str) -> Self {
+        Self::from_known(locale).unwrap_or_else(|| Self::Unknown(locale.to_owned()))
+    }
+}
+
+impl From<String> for Locale {
+    fn from(locale: String) -> Self {
+        Self::from_known(&locale).unwrap_or(Self::Unknown(locale))
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for Locale {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Locale;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        Locale: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_known() {
+        const MAP: &[(Locale, &str)] = &[
+            (Locale::EnglishUs, "en-US"),
+            (Locale::SpanishLatam, "es-419"),
+            (Locale::German, "de"),
+            (Locale::ChineseTaiwan, "zh-TW"),
+        ];
+
+        for (locale, code) in MAP {
+            serde_test::assert_tokens(locale, &[Token::Str(code)]);
+            assert_eq!(*locale, Locale::from(*code));
+            assert_eq!(*locale, Locale::from((*code).to_owned()));
+            assert_eq!(*code, locale.to_string());
+        }
+    }
+
+    #[test]
+    fn test_unknown() {
+        let locale = Locale::Unknown("tlh".to_owned());
+
+        serde_test::assert_tokens(&locale, &[Token::Str("tlh")]);
+        assert_eq!(locale, Locale::from("tlh"));
+        assert_eq!("tlh", locale.as_str());
+
+        // Locale codes are case-sensitive.
+        assert_eq!(Locale::Unknown("en-us".to_owned()), Locale::from("en-us"));
+    }
+}
@@ -2,8 +2,9 @@
 
 pub mod datetime;
 pub mod image_hash;
+pub mod locale;
 
-pub use self::{datetime::Timestamp, image_hash::ImageHash};
+pub use self::{datetime::Timestamp, image_hash::ImageHash, locale::Locale};
 
 #[allow(clippy::trivially_copy_pass_by_ref)]
 pub(crate) fn is_false(value: &bool) -> bool {
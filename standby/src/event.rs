@@ -27,6 +27,7 @@ pub const fn guild_id(event: &Event) -> Option<Id<GuildMarker>> {
         Event::ChannelDelete(e) => e.0.guild_id,
         Event::ChannelUpdate(e) => e.0.guild_id,
         Event::CommandPermissionsUpdate(e) => Some(e.0.guild_id),
+        Event::GuildAuditLogEntryCreate(e) => e.0.guild_id,
         Event::GuildCreate(e) => Some(e.0.id),
         Event::GuildDelete(e) => Some(e.id),
         Event::GuildEmojisUpdate(e) => Some(e.guild_id),
@@ -36,6 +37,10 @@ pub const fn guild_id(event: &Event) -> Option<Id<GuildMarker>> {
         Event::GuildScheduledEventUpdate(e) => Some(e.0.guild_id),
         Event::GuildScheduledEventUserAdd(e) => Some(e.guild_id),
         Event::GuildScheduledEventUserRemove(e) => Some(e.guild_id),
+        Event::GuildSoundboardSoundCreate(e) => e.0.guild_id,
+        Event::GuildSoundboardSoundDelete(e) => Some(e.guild_id),
+        Event::GuildSoundboardSoundUpdate(e) => e.0.guild_id,
+        Event::GuildSoundboardSoundsUpdate(e) => Some(e.guild_id),
         Event::GuildStickersUpdate(e) => Some(e.guild_id),
         Event::GuildUpdate(e) => Some(e.0.id),
         Event::IntegrationCreate(e) => e.0.guild_id,
@@ -57,6 +62,7 @@ pub const fn guild_id(event: &Event) -> Option<Id<GuildMarker>> {
         Event::RoleCreate(e) => Some(e.guild_id),
         Event::RoleDelete(e) => Some(e.guild_id),
         Event::RoleUpdate(e) => Some(e.guild_id),
+        Event::SoundboardSounds(e) => Some(e.guild_id),
         Event::StageInstanceCreate(e) => Some(e.0.guild_id),
         Event::StageInstanceDelete(e) => Some(e.0.guild_id),
         Event::StageInstanceUpdate(e) => Some(e.0.guild_id),
@@ -67,6 +73,7 @@ pub const fn guild_id(event: &Event) -> Option<Id<GuildMarker>> {
         Event::ThreadUpdate(e) => e.0.guild_id,
         Event::TypingStart(e) => e.guild_id,
         Event::UnavailableGuild(e) => Some(e.id),
+        Event::VoiceChannelEffectSend(e) => Some(e.guild_id),
         Event::VoiceServerUpdate(e) => e.guild_id,
         Event::VoiceStateUpdate(e) => e.0.guild_id,
         Event::WebhooksUpdate(e) => Some(e.guild_id),
@@ -1245,6 +1245,8 @@ mod tests {
             mention_everyone: false,
             mention_roles: Vec::new(),
             mentions: Vec::new(),
+            message_snapshots: Vec::new(),
+            nonce: None,
             pinned: false,
             reactions: Vec::new(),
             reference: None,
@@ -1277,8 +1279,10 @@ mod tests {
             data: MessageComponentInteractionData {
                 custom_id: String::from("Click"),
                 component_type: ComponentType::Button,
+                resolved: None,
                 values: vec![],
             },
+            entitlements: Vec::new(),
             guild_id: Some(Id::new(3)),
             guild_locale: None,
             id: Id::new(4),
@@ -1450,6 +1454,7 @@ mod tests {
                 id: Id::new(1),
             },
             guilds: Vec::new(),
+            resume_gateway_url: None,
             session_id: String::new(),
             shard: Some([5, 7]),
             user: CurrentUser {
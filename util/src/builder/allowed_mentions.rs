@@ -0,0 +1,60 @@
+//! Common [`AllowedMentions`] configurations.
+//!
+//! # Examples
+//!
+//! Reply to a message, pinging only the author of the replied-to message:
+//!
+//! ```
+//! use twilight_util::builder::allowed_mentions;
+//!
+//! let allowed_mentions = allowed_mentions::replied_user_only();
+//!
+//! assert!(allowed_mentions.parse.is_empty());
+//! assert!(allowed_mentions.replied_user);
+//! ```
+
+use twilight_model::channel::message::AllowedMentions;
+
+/// Create an [`AllowedMentions`] that pings no one at all.
+///
+/// Mentions of `@everyone`, roles, and users in the message content are
+/// displayed but don't ping, and replies don't ping the replied-to user.
+#[must_use = "has no effect if not used in a message"]
+pub fn none() -> AllowedMentions {
+    AllowedMentions::builder().build()
+}
+
+/// Create an [`AllowedMentions`] that only pings the author of the message
+/// being replied to.
+///
+/// Mentions of `@everyone`, roles, and users in the message content are
+/// displayed but don't ping.
+#[must_use = "has no effect if not used in a message"]
+pub fn replied_user_only() -> AllowedMentions {
+    AllowedMentions::builder().replied_user().build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{none, replied_user_only};
+
+    #[test]
+    fn test_none() {
+        let value = none();
+
+        assert!(value.parse.is_empty());
+        assert!(value.roles.is_empty());
+        assert!(value.users.is_empty());
+        assert!(!value.replied_user);
+    }
+
+    #[test]
+    fn test_replied_user_only() {
+        let value = replied_user_only();
+
+        assert!(value.parse.is_empty());
+        assert!(value.roles.is_empty());
+        assert!(value.users.is_empty());
+        assert!(value.replied_user);
+    }
+}
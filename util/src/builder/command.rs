@@ -37,7 +37,10 @@ use twilight_model::{
     guild::Permissions,
     id::{marker::GuildMarker, Id},
 };
-use twilight_validate::command::{command as validate_command, CommandValidationError};
+use twilight_validate::command::{
+    chat_input_name as validate_chat_input_name, command as validate_command,
+    CommandValidationError,
+};
 
 /// Builder to create a [`Command`].
 #[allow(clippy::module_name_repetitions)]
@@ -1034,6 +1037,48 @@ impl From<UserBuilder> for CommandOption {
     }
 }
 
+/// Normalize a proposed name of a [`ChatInput`] command or a
+/// [`CommandOption`], such as `Ban User`, into a valid name, such as
+/// `ban-user`.
+///
+/// The name is lowercased and runs of whitespace are replaced with `-`. Names
+/// must be between 1 and 32 characters long and may only contain lowercase
+/// letters and numbers of any script, `-`, and `_`.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_util::builder::command;
+///
+/// assert_eq!("ban-user", command::normalize_name(" Ban User ")?);
+/// assert_eq!("größe", command::normalize_name("Größe")?);
+/// assert!(command::normalize_name("ban!").is_err());
+/// # Ok::<(), twilight_validate::command::CommandValidationError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error of type [`NameLengthInvalid`] if the normalized name is
+/// empty or longer than 32 characters.
+///
+/// Returns an error of type [`NameCharacterInvalid`] if the name contains a
+/// character that isn't allowed, such as punctuation other than `-` and `_`.
+///
+/// [`ChatInput`]: CommandType::ChatInput
+/// [`NameCharacterInvalid`]: twilight_validate::command::CommandValidationErrorType::NameCharacterInvalid
+/// [`NameLengthInvalid`]: twilight_validate::command::CommandValidationErrorType::NameLengthInvalid
+pub fn normalize_name(name: &str) -> Result<String, CommandValidationError> {
+    let name = name
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+
+    validate_chat_input_name(&name)?;
+
+    Ok(name)
+}
+
 fn set_choice_localizations(
     choice: &mut CommandOptionChoice,
     localizations: HashMap<String, String>,
@@ -1058,6 +1103,7 @@ mod tests {
     use super::*;
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
+    use twilight_validate::command::CommandValidationErrorType;
 
     assert_impl_all!(AttachmentBuilder: Clone, Debug, Send, Sync);
     assert_impl_all!(CommandBuilder: Clone, Debug, Send, Sync);
@@ -1071,6 +1117,49 @@ mod tests {
     assert_impl_all!(SubCommandGroupBuilder: Clone, Debug, Send, Sync);
     assert_impl_all!(UserBuilder: Clone, Debug, Send, Sync);
 
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!("ping", normalize_name("ping").unwrap());
+        assert_eq!("ban_user-2", normalize_name("ban_user-2").unwrap());
+        assert_eq!("ban-user", normalize_name("  Ban \t User ").unwrap());
+        assert_eq!("größe", normalize_name("Größe").unwrap());
+        assert_eq!("検索", normalize_name("検索").unwrap());
+    }
+
+    #[test]
+    fn test_normalize_name_uppercase() {
+        // Uppercase names are rejected by Discord, so are only valid once
+        // normalized.
+        let builder = CommandBuilder::new("Ping".into(), "ping".into(), CommandType::ChatInput);
+        assert!(matches!(
+            builder.validate().unwrap_err().kind(),
+            CommandValidationErrorType::NameCharacterInvalid { character: 'P' }
+        ));
+
+        let name = normalize_name("Ping").unwrap();
+        let builder = CommandBuilder::new(name, "ping".into(), CommandType::ChatInput);
+        assert!(builder.validate().is_ok());
+    }
+
+    #[test]
+    fn test_normalize_name_invalid() {
+        for (name, character) in [("ping!", '!'), ("user.name", '.'), ("a+b", '+')] {
+            assert!(matches!(
+                normalize_name(name).unwrap_err().kind(),
+                CommandValidationErrorType::NameCharacterInvalid { character: c } if *c == character
+            ));
+        }
+
+        assert!(matches!(
+            normalize_name(" ").unwrap_err().kind(),
+            CommandValidationErrorType::NameLengthInvalid
+        ));
+        assert!(matches!(
+            normalize_name(&"a".repeat(33)).unwrap_err().kind(),
+            CommandValidationErrorType::NameLengthInvalid
+        ));
+    }
+
     #[test]
     #[allow(clippy::too_many_lines)]
     fn construct_command_with_builder() {
@@ -1298,4 +1387,36 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_validate_localizations() {
+        let command =
+            CommandBuilder::new("name".into(), "description".into(), CommandType::ChatInput)
+                .description_localizations(HashMap::from([(
+                    "de".to_owned(),
+                    "Beschreibung".to_owned(),
+                )]))
+                .name_localizations(HashMap::from([("fr".to_owned(), "nom".to_owned())]))
+                .validate()
+                .unwrap()
+                .build();
+
+        assert_eq!(
+            Some(&"nom".to_owned()),
+            command
+                .name_localizations
+                .as_ref()
+                .and_then(|localizations| localizations.get("fr"))
+        );
+
+        let result =
+            CommandBuilder::new("name".into(), "description".into(), CommandType::ChatInput)
+                .name_localizations(HashMap::from([("french".to_owned(), "nom".to_owned())]))
+                .validate();
+
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            CommandValidationErrorType::LocaleInvalid { locale } if locale == "french"
+        ));
+    }
 }
@@ -0,0 +1,223 @@
+use twilight_model::{
+    application::{
+        command::Number,
+        interaction::application_command::{CommandDataOption, CommandOptionValue},
+    },
+    id::{
+        marker::{AttachmentMarker, ChannelMarker, GenericMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Create a [`CommandDataOption`] with a builder.
+///
+/// This is primarily useful for constructing interactions in tests or when
+/// mocking them.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::id::Id;
+/// use twilight_util::builder::CommandDataOptionBuilder;
+///
+/// let option = CommandDataOptionBuilder::new("ban".into())
+///     .sub_command([
+///         CommandDataOptionBuilder::new("user".into())
+///             .user(Id::new(1))
+///             .build(),
+///         CommandDataOptionBuilder::new("reason".into())
+///             .string("spam".into())
+///             .build(),
+///     ])
+///     .build();
+///
+/// let options = option.value.as_sub_command().unwrap();
+/// assert_eq!(Some(Id::new(1)), options[0].value.as_user());
+/// assert_eq!(Some("spam"), options[1].value.as_str());
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "builders have no effect if unused"]
+pub struct CommandDataOptionBuilder(CommandDataOption);
+
+impl CommandDataOptionBuilder {
+    /// Create a new builder to construct a [`CommandDataOption`].
+    ///
+    /// The option defaults to an unfocused subcommand without options.
+    pub const fn new(name: String) -> Self {
+        Self(CommandDataOption {
+            focused: false,
+            name,
+            value: CommandOptionValue::SubCommand(Vec::new()),
+        })
+    }
+
+    /// Consume the builder, returning a [`CommandDataOption`].
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "builders have no effect if unused"]
+    pub fn build(self) -> CommandDataOption {
+        self.0
+    }
+
+    /// Set the value to an attachment.
+    pub fn attachment(mut self, attachment_id: Id<AttachmentMarker>) -> Self {
+        self.0.value = CommandOptionValue::Attachment(attachment_id);
+
+        self
+    }
+
+    /// Set the value to a boolean.
+    pub fn boolean(mut self, value: bool) -> Self {
+        self.0.value = CommandOptionValue::Boolean(value);
+
+        self
+    }
+
+    /// Set the value to a channel.
+    pub fn channel(mut self, channel_id: Id<ChannelMarker>) -> Self {
+        self.0.value = CommandOptionValue::Channel(channel_id);
+
+        self
+    }
+
+    /// Set whether the option is focused in an autocomplete interaction.
+    ///
+    /// Defaults to [`false`].
+    pub const fn focused(mut self, focused: bool) -> Self {
+        self.0.focused = focused;
+
+        self
+    }
+
+    /// Set the value to an integer.
+    pub fn integer(mut self, value: i64) -> Self {
+        self.0.value = CommandOptionValue::Integer(value);
+
+        self
+    }
+
+    /// Set the value to a mentionable role or user.
+    pub fn mentionable(mut self, id: Id<GenericMarker>) -> Self {
+        self.0.value = CommandOptionValue::Mentionable(id);
+
+        self
+    }
+
+    /// Set the value to a number.
+    pub fn number(mut self, value: f64) -> Self {
+        self.0.value = CommandOptionValue::Number(Number(value));
+
+        self
+    }
+
+    /// Set the value to a role.
+    pub fn role(mut self, role_id: Id<RoleMarker>) -> Self {
+        self.0.value = CommandOptionValue::Role(role_id);
+
+        self
+    }
+
+    /// Set the value to a string.
+    pub fn string(mut self, value: String) -> Self {
+        self.0.value = CommandOptionValue::String(value);
+
+        self
+    }
+
+    /// Set the value to a subcommand with options.
+    pub fn sub_command(mut self, options: impl IntoIterator<Item = CommandDataOption>) -> Self {
+        self.0.value = CommandOptionValue::SubCommand(options.into_iter().collect());
+
+        self
+    }
+
+    /// Set the value to a subcommand group with subcommands.
+    pub fn sub_command_group(
+        mut self,
+        options: impl IntoIterator<Item = CommandDataOption>,
+    ) -> Self {
+        self.0.value = CommandOptionValue::SubCommandGroup(options.into_iter().collect());
+
+        self
+    }
+
+    /// Set the value to a user.
+    pub fn user(mut self, user_id: Id<UserMarker>) -> Self {
+        self.0.value = CommandOptionValue::User(user_id);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(CommandDataOptionBuilder: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn values() {
+        let value = CommandDataOptionBuilder::new("animal".into())
+            .string("cat".into())
+            .focused(true)
+            .build();
+
+        let expected = CommandDataOption {
+            focused: true,
+            name: "animal".to_owned(),
+            value: CommandOptionValue::String("cat".to_owned()),
+        };
+
+        assert_eq!(value, expected);
+
+        let value = CommandDataOptionBuilder::new("count".into())
+            .integer(3)
+            .build();
+
+        let expected = CommandDataOption {
+            focused: false,
+            name: "count".to_owned(),
+            value: CommandOptionValue::Integer(3),
+        };
+
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn sub_command() {
+        let value = CommandDataOptionBuilder::new("settings".into())
+            .sub_command_group([CommandDataOptionBuilder::new("set".into())
+                .sub_command([CommandDataOptionBuilder::new("enabled".into())
+                    .boolean(true)
+                    .build()])
+                .build()])
+            .build();
+
+        let expected = CommandDataOption {
+            focused: false,
+            name: "settings".to_owned(),
+            value: CommandOptionValue::SubCommandGroup(Vec::from([CommandDataOption {
+                focused: false,
+                name: "set".to_owned(),
+                value: CommandOptionValue::SubCommand(Vec::from([CommandDataOption {
+                    focused: false,
+                    name: "enabled".to_owned(),
+                    value: CommandOptionValue::Boolean(true),
+                }])),
+            }])),
+        };
+
+        assert_eq!(value, expected);
+
+        let value = CommandDataOptionBuilder::new("ping".into()).build();
+
+        let expected = CommandDataOption {
+            focused: false,
+            name: "ping".to_owned(),
+            value: CommandOptionValue::SubCommand(Vec::new()),
+        };
+
+        assert_eq!(value, expected);
+    }
+}
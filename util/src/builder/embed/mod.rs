@@ -17,6 +17,12 @@ use twilight_model::{
 };
 use twilight_validate::embed::{embed as validate_embed, EmbedValidationError};
 
+/// Text of blank fields.
+///
+/// Discord doesn't accept empty field names and values, so a zero-width space
+/// is used instead.
+const BLANK_FIELD: &str = "\u{200b}";
+
 /// Create an [`Embed`] with a builder.
 ///
 /// # Examples
@@ -54,6 +60,9 @@ use twilight_validate::embed::{embed as validate_embed, EmbedValidationError};
 pub struct EmbedBuilder(Embed);
 
 impl EmbedBuilder {
+    /// The maximum number of inline fields displayed in a row.
+    pub const INLINE_FIELD_ROW_LENGTH: usize = 3;
+
     /// Create a new embed builder.
     pub fn new() -> Self {
         EmbedBuilder(Embed {
@@ -192,6 +201,63 @@ impl EmbedBuilder {
         self
     }
 
+    /// Add fields to the embed as inline fields, laid out in rows of up to
+    /// [`INLINE_FIELD_ROW_LENGTH`] fields.
+    ///
+    /// If `pad` is true and the last row is incomplete, blank inline fields
+    /// are added to complete it so that the fields of each row are aligned.
+    /// Rows start at the first provided field.
+    ///
+    /// # Examples
+    ///
+    /// Add a row and a half of fields, completing the second row:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_util::builder::embed::EmbedBuilder;
+    ///
+    /// let fields = [("a", "1"), ("b", "2"), ("c", "3"), ("d", "4")];
+    ///
+    /// let embed = EmbedBuilder::new()
+    ///     .description("this is an embed")
+    ///     .inline_fields(fields, true)
+    ///     .validate()?
+    ///     .build();
+    ///
+    /// assert_eq!(6, embed.fields.len());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`INLINE_FIELD_ROW_LENGTH`]: Self::INLINE_FIELD_ROW_LENGTH
+    pub fn inline_fields<N: Into<String>, V: Into<String>>(
+        mut self,
+        fields: impl IntoIterator<Item = (N, V)>,
+        pad: bool,
+    ) -> Self {
+        let mut count = 0;
+
+        for (name, value) in fields {
+            self.0.fields.push(EmbedField {
+                inline: true,
+                name: name.into(),
+                value: value.into(),
+            });
+            count += 1;
+        }
+
+        if pad && count % Self::INLINE_FIELD_ROW_LENGTH != 0 {
+            for _ in count % Self::INLINE_FIELD_ROW_LENGTH..Self::INLINE_FIELD_ROW_LENGTH {
+                self.0.fields.push(EmbedField {
+                    inline: true,
+                    name: BLANK_FIELD.to_owned(),
+                    value: BLANK_FIELD.to_owned(),
+                });
+            }
+        }
+
+        self
+    }
+
     /// Set the footer of the embed.
     ///
     /// # Examples
@@ -364,19 +430,68 @@ impl TryFrom<EmbedBuilder> for Embed {
     }
 }
 
+/// Count the characters of an embed that count towards Discord's limit on the
+/// combined length of an embed.
+///
+/// The title, description, field names and values, footer text, and author
+/// name are counted. Characters are counted as Unicode scalar values rather
+/// than bytes, so a multibyte character counts once.
+///
+/// Use this to decide how much more can be added to an embed, such as when
+/// paginating content across several embeds. Refer to
+/// [`EMBED_TOTAL_LENGTH`] for the limit.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_util::builder::embed::{embed_char_count, EmbedBuilder, EmbedFieldBuilder};
+///
+/// let embed = EmbedBuilder::new()
+///     .title("Ponies")
+///     .field(EmbedFieldBuilder::new("Twilight", "Sparkle"))
+///     .build();
+///
+/// assert_eq!(21, embed_char_count(&embed));
+/// ```
+///
+/// [`EMBED_TOTAL_LENGTH`]: twilight_validate::embed::EMBED_TOTAL_LENGTH
+#[must_use = "counting the characters of an embed has no effect if left unused"]
+pub fn embed_char_count(embed: &Embed) -> usize {
+    let author = embed.author.as_ref().map(|author| author.name.as_str());
+    let footer = embed.footer.as_ref().map(|footer| footer.text.as_str());
+    let fields = embed
+        .fields
+        .iter()
+        .flat_map(|field| [field.name.as_str(), field.value.as_str()]);
+
+    [
+        embed.title.as_deref(),
+        embed.description.as_deref(),
+        footer,
+        author,
+    ]
+    .into_iter()
+    .flatten()
+    .chain(fields)
+    .map(|text| text.chars().count())
+    .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::builder::embed::{EmbedFieldBuilder, EmbedFooterBuilder};
-    use static_assertions::assert_impl_all;
+    use crate::builder::embed::{EmbedAuthorBuilder, EmbedFieldBuilder, EmbedFooterBuilder};
+    use static_assertions::{assert_impl_all, const_assert};
     use std::fmt::Debug;
     use twilight_model::{
         channel::embed::{Embed, EmbedField, EmbedFooter},
         util::Timestamp,
     };
+    use twilight_validate::embed::EMBED_TOTAL_LENGTH;
 
     assert_impl_all!(EmbedBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
     assert_impl_all!(Embed: TryFrom<EmbedBuilder>);
+    const_assert!(EmbedBuilder::INLINE_FIELD_ROW_LENGTH == 3);
 
     #[test]
     fn test_builder() {
@@ -424,4 +539,94 @@ mod tests {
 
         assert_eq!(embed, expected);
     }
+
+    #[test]
+    fn test_embed_char_count() {
+        assert_eq!(0, embed_char_count(&EmbedBuilder::new().build()));
+
+        // URLs aren't counted.
+        let embed = EmbedBuilder::new()
+            .author(EmbedAuthorBuilder::new("author".to_owned()))
+            .description("description")
+            .field(EmbedFieldBuilder::new("name", "value"))
+            .field(EmbedFieldBuilder::new("name 2", "value 2"))
+            .footer(EmbedFooterBuilder::new("footer"))
+            .title("title")
+            .url("https://twilight.rs")
+            .build();
+        assert_eq!(6 + 11 + 4 + 5 + 6 + 7 + 6 + 5, embed_char_count(&embed));
+
+        // Multibyte characters count once.
+        let embed = EmbedBuilder::new()
+            .description("\u{1f986}".repeat(4096))
+            .title("d\u{e9}j\u{e0} vu")
+            .build();
+        assert_eq!(4096 + 7, embed_char_count(&embed));
+    }
+
+    #[test]
+    fn test_embed_char_count_limit() {
+        let embed = EmbedBuilder::new()
+            .description("a".repeat(4096))
+            .field(EmbedFieldBuilder::new("b".repeat(256), "c".repeat(1024)))
+            .title("d".repeat(256))
+            .footer(EmbedFooterBuilder::new("e".repeat(368)))
+            .build();
+
+        assert_eq!(EMBED_TOTAL_LENGTH, embed_char_count(&embed));
+        assert!(EmbedBuilder::from(embed.clone()).validate().is_ok());
+
+        let embed = EmbedBuilder::from(embed)
+            .author(EmbedAuthorBuilder::new("f".to_owned()))
+            .build();
+        assert_eq!(EMBED_TOTAL_LENGTH + 1, embed_char_count(&embed));
+        assert!(EmbedBuilder::from(embed).validate().is_err());
+    }
+
+    #[test]
+    fn test_inline_fields() {
+        let fields = [("a", "1"), ("b", "2"), ("c", "3"), ("d", "4")];
+
+        let embed = EmbedBuilder::new()
+            .field(EmbedFieldBuilder::new("name", "value"))
+            .inline_fields(fields, false)
+            .build();
+
+        assert_eq!(5, embed.fields.len());
+        assert!(!embed.fields[0].inline);
+        assert!(embed.fields[1..].iter().all(|field| field.inline));
+        assert_eq!(
+            ["a", "b", "c", "d"].as_slice(),
+            embed.fields[1..]
+                .iter()
+                .map(|field| field.name.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_inline_fields_pad() {
+        let blank = EmbedField {
+            inline: true,
+            name: BLANK_FIELD.to_owned(),
+            value: BLANK_FIELD.to_owned(),
+        };
+
+        let embed = EmbedBuilder::new()
+            .inline_fields([("a", "1"), ("b", "2"), ("c", "3"), ("d", "4")], true)
+            .build();
+
+        assert_eq!(6, embed.fields.len());
+        assert!(embed.fields.iter().all(|field| field.inline));
+        assert_eq!("d", embed.fields[3].name);
+        assert_eq!([blank.clone(), blank], embed.fields[4..]);
+
+        // Complete rows aren't padded.
+        let embed = EmbedBuilder::new()
+            .inline_fields([("a", "1"), ("b", "2"), ("c", "3")], true)
+            .build();
+
+        assert_eq!(3, embed.fields.len());
+        assert!(!embed.fields.iter().any(|field| field.name == BLANK_FIELD));
+    }
 }
@@ -1,9 +1,17 @@
 //! Builders for large structs.
 #![allow(clippy::module_name_repetitions)]
 
+pub mod allowed_mentions;
 pub mod command;
 pub mod embed;
 
+mod command_data_option;
 mod interaction_response_data;
+mod modal;
+mod presence;
 
-pub use self::interaction_response_data::InteractionResponseDataBuilder;
+pub use self::{
+    command_data_option::CommandDataOptionBuilder,
+    interaction_response_data::InteractionResponseDataBuilder, modal::ModalBuilder,
+    presence::UpdatePresenceBuilder,
+};
@@ -0,0 +1,212 @@
+use twilight_model::{
+    application::component::{ActionRow, Component, TextInput},
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+};
+use twilight_validate::component::{modal as validate_modal, ComponentValidationError};
+
+/// Create an [`InteractionResponse`] of type [`Modal`] with a builder.
+///
+/// Each text input is placed in its own action row, as modals only allow one
+/// text input per row.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::application::component::{text_input::TextInputStyle, TextInput};
+/// use twilight_util::builder::ModalBuilder;
+///
+/// let response = ModalBuilder::new("report".into(), "Report a message".into())
+///     .text_input(TextInput {
+///         custom_id: "reason".into(),
+///         label: "Reason".into(),
+///         max_length: Some(500),
+///         min_length: Some(10),
+///         placeholder: Some("Why should this message be removed?".into()),
+///         required: Some(true),
+///         style: TextInputStyle::Paragraph,
+///         value: None,
+///     })
+///     .validate()?
+///     .build();
+///
+/// let data = response.data.unwrap();
+/// assert_eq!(Some("report"), data.custom_id.as_deref());
+/// assert_eq!(1, data.components.unwrap().len());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`Modal`]: InteractionResponseType::Modal
+#[derive(Clone, Debug)]
+#[must_use = "builders have no effect if unused"]
+pub struct ModalBuilder {
+    components: Vec<Component>,
+    custom_id: String,
+    title: String,
+}
+
+impl ModalBuilder {
+    /// Create a new builder to construct a modal with a custom ID and title.
+    pub const fn new(custom_id: String, title: String) -> Self {
+        Self {
+            components: Vec::new(),
+            custom_id,
+            title,
+        }
+    }
+
+    /// Consume the builder, returning an [`InteractionResponse`] of type
+    /// [`Modal`].
+    ///
+    /// [`Modal`]: InteractionResponseType::Modal
+    #[must_use = "builders have no effect if unused"]
+    pub fn build(self) -> InteractionResponse {
+        InteractionResponse {
+            kind: InteractionResponseType::Modal,
+            data: Some(InteractionResponseData {
+                allowed_mentions: None,
+                attachments: None,
+                choices: None,
+                components: Some(self.components),
+                content: None,
+                custom_id: Some(self.custom_id),
+                embeds: None,
+                flags: None,
+                title: Some(self.title),
+                tts: None,
+            }),
+        }
+    }
+
+    /// Ensure the modal is valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of [`twilight_validate::component::modal`]
+    /// for possible errors.
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        validate_modal(&self.custom_id, &self.title, &self.components)?;
+
+        Ok(self)
+    }
+
+    /// Add an action row as-is, such as one from an existing modal.
+    ///
+    /// Prefer [`text_input`] to add text inputs, which ensures each is placed
+    /// in its own action row.
+    ///
+    /// [`text_input`]: Self::text_input
+    pub fn action_row(mut self, action_row: ActionRow) -> Self {
+        self.components.push(Component::ActionRow(action_row));
+
+        self
+    }
+
+    /// Add a text input in its own action row.
+    pub fn text_input(mut self, text_input: TextInput) -> Self {
+        self.components.push(Component::ActionRow(ActionRow {
+            components: Vec::from([Component::TextInput(text_input)]),
+        }));
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModalBuilder;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        application::component::{text_input::TextInputStyle, ActionRow, Component, TextInput},
+        http::interaction::{
+            InteractionResponse, InteractionResponseData, InteractionResponseType,
+        },
+    };
+    use twilight_validate::component::ComponentValidationErrorType;
+
+    assert_impl_all!(ModalBuilder: Clone, Debug, Send, Sync);
+
+    fn text_input(custom_id: &str) -> TextInput {
+        TextInput {
+            custom_id: custom_id.to_owned(),
+            label: "Label".to_owned(),
+            max_length: Some(100),
+            min_length: None,
+            placeholder: None,
+            required: Some(false),
+            style: TextInputStyle::Short,
+            value: None,
+        }
+    }
+
+    #[test]
+    fn test_modal() {
+        let response = ModalBuilder::new("feedback".to_owned(), "Feedback".to_owned())
+            .text_input(text_input("name"))
+            .text_input(text_input("comment"))
+            .validate()
+            .unwrap()
+            .build();
+
+        let row = |input| {
+            Component::ActionRow(ActionRow {
+                components: Vec::from([Component::TextInput(input)]),
+            })
+        };
+
+        let expected = InteractionResponse {
+            kind: InteractionResponseType::Modal,
+            data: Some(InteractionResponseData {
+                allowed_mentions: None,
+                attachments: None,
+                choices: None,
+                components: Some(Vec::from([
+                    row(text_input("name")),
+                    row(text_input("comment")),
+                ])),
+                content: None,
+                custom_id: Some("feedback".to_owned()),
+                embeds: None,
+                flags: None,
+                title: Some("Feedback".to_owned()),
+                tts: None,
+            }),
+        };
+
+        assert_eq!(expected, response);
+    }
+
+    #[test]
+    fn test_one_input_per_row() {
+        let error = ModalBuilder::new("feedback".to_owned(), "Feedback".to_owned())
+            .action_row(ActionRow {
+                components: Vec::from([
+                    Component::TextInput(text_input("name")),
+                    Component::TextInput(text_input("comment")),
+                ]),
+            })
+            .validate()
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ComponentValidationErrorType::ModalActionRowComponentCount { count: 2 }
+        ));
+    }
+
+    #[test]
+    fn test_row_count() {
+        let mut builder = ModalBuilder::new("feedback".to_owned(), "Feedback".to_owned());
+
+        for index in 0..5 {
+            builder = builder.text_input(text_input(&index.to_string()));
+        }
+
+        let builder = builder.validate().unwrap().text_input(text_input("5"));
+
+        assert!(matches!(
+            builder.validate().unwrap_err().kind(),
+            ComponentValidationErrorType::ModalComponentCount { count: 6 }
+        ));
+    }
+}
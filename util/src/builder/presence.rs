@@ -0,0 +1,170 @@
+use twilight_model::gateway::{
+    payload::outgoing::update_presence::{UpdatePresenceError, UpdatePresencePayload},
+    presence::{Activity, Status},
+};
+
+/// Create an [`UpdatePresencePayload`] with a builder.
+///
+/// The payload can be used as the initial presence of a shard when
+/// identifying, or sent to update the presence of a connected shard.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::gateway::presence::{Activity, ActivityType, MinimalActivity, Status};
+/// use twilight_util::builder::UpdatePresenceBuilder;
+///
+/// let presence = UpdatePresenceBuilder::new(Status::DoNotDisturb)
+///     .activity(Activity::from(MinimalActivity {
+///         kind: ActivityType::Listening,
+///         name: "music".to_owned(),
+///         url: None,
+///     }))
+///     .afk(true)
+///     .build()?;
+///
+/// assert_eq!(Status::DoNotDisturb, presence.status);
+/// assert_eq!(1, presence.activities.len());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "builders have no effect if unused"]
+pub struct UpdatePresenceBuilder {
+    activities: Vec<Activity>,
+    afk: bool,
+    since: Option<u64>,
+    status: Status,
+}
+
+impl UpdatePresenceBuilder {
+    /// Create a new builder to construct a presence with a status.
+    pub const fn new(status: Status) -> Self {
+        Self {
+            activities: Vec::new(),
+            afk: false,
+            since: None,
+            status,
+        }
+    }
+
+    /// Consume the builder, returning a validated [`UpdatePresencePayload`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`MissingActivity`] if no activities were
+    /// added.
+    ///
+    /// Returns an error of type [`StreamingUrlMissing`] if a streaming
+    /// activity has no URL.
+    ///
+    /// [`MissingActivity`]: twilight_model::gateway::payload::outgoing::update_presence::UpdatePresenceErrorType::MissingActivity
+    /// [`StreamingUrlMissing`]: twilight_model::gateway::payload::outgoing::update_presence::UpdatePresenceErrorType::StreamingUrlMissing
+    pub fn build(self) -> Result<UpdatePresencePayload, UpdatePresenceError> {
+        UpdatePresencePayload::new(self.activities, self.afk, self.since, self.status)
+    }
+
+    /// Add an activity to the presence.
+    ///
+    /// At least one activity is required.
+    pub fn activity(mut self, activity: Activity) -> Self {
+        self.activities.push(activity);
+
+        self
+    }
+
+    /// Add multiple activities to the presence.
+    pub fn activities(mut self, activities: impl IntoIterator<Item = Activity>) -> Self {
+        self.activities.extend(activities);
+
+        self
+    }
+
+    /// Set whether the client is AFK.
+    ///
+    /// Defaults to `false`.
+    pub const fn afk(mut self, afk: bool) -> Self {
+        self.afk = afk;
+
+        self
+    }
+
+    /// Set the Unix time in milliseconds of when the client went idle.
+    pub const fn since(mut self, since: u64) -> Self {
+        self.since = Some(since);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpdatePresenceBuilder;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::gateway::{
+        payload::outgoing::{update_presence::UpdatePresenceErrorType, UpdatePresence},
+        presence::{Activity, ActivityType, MinimalActivity, Status},
+        OpCode,
+    };
+
+    assert_impl_all!(UpdatePresenceBuilder: Clone, Debug, Send, Sync);
+
+    fn activity(kind: ActivityType, url: Option<&str>) -> Activity {
+        Activity::from(MinimalActivity {
+            kind,
+            name: "twilight".to_owned(),
+            url: url.map(ToOwned::to_owned),
+        })
+    }
+
+    #[test]
+    fn test_update_presence_builder() {
+        let presence = UpdatePresenceBuilder::new(Status::Idle)
+            .activity(activity(ActivityType::Playing, None))
+            .activities([activity(
+                ActivityType::Streaming,
+                Some("https://twitch.tv/twilight"),
+            )])
+            .afk(true)
+            .since(1_000)
+            .build()
+            .unwrap();
+
+        let value = serde_json::to_value(UpdatePresence {
+            d: presence,
+            op: OpCode::PresenceUpdate,
+        })
+        .unwrap();
+
+        assert_eq!(3, value["op"]);
+        assert_eq!("idle", value["d"]["status"]);
+        assert_eq!(true, value["d"]["afk"]);
+        assert_eq!(1_000, value["d"]["since"]);
+
+        let activities = value["d"]["activities"].as_array().unwrap();
+        assert_eq!(2, activities.len());
+        assert_eq!(0, activities[0]["type"]);
+        assert_eq!(1, activities[1]["type"]);
+        assert_eq!("https://twitch.tv/twilight", activities[1]["url"]);
+    }
+
+    #[test]
+    fn test_update_presence_builder_invalid() {
+        let error = UpdatePresenceBuilder::new(Status::Online)
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            UpdatePresenceErrorType::MissingActivity
+        ));
+
+        let error = UpdatePresenceBuilder::new(Status::Online)
+            .activity(activity(ActivityType::Streaming, None))
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            UpdatePresenceErrorType::StreamingUrlMissing { index: 0 }
+        ));
+    }
+}
@@ -0,0 +1,306 @@
+//! Compare sets of application command definitions.
+//!
+//! Registering commands on every start of a bot is wasteful and may run into
+//! ratelimits. Diffing the commands currently registered against the desired
+//! definitions allows only creating, updating, and deleting the commands that
+//! need it.
+//!
+//! Commands are matched by their type and name. Fields assigned by Discord,
+//! such as the ID, application ID, guild ID, and version, are ignored.
+
+use std::collections::HashMap;
+use twilight_model::application::command::Command;
+
+/// Field of a [`Command`] that differs between two definitions.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CommandField {
+    /// [`Command::default_member_permissions`] differs.
+    DefaultMemberPermissions,
+    /// [`Command::description`] differs.
+    Description,
+    /// [`Command::description_localizations`] differs.
+    DescriptionLocalizations,
+    /// [`Command::dm_permission`] differs.
+    DmPermission,
+    /// [`Command::name_localizations`] differs.
+    NameLocalizations,
+    /// [`Command::options`] differs.
+    Options,
+}
+
+/// Command whose definition has changed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommandChange<'a> {
+    /// Currently registered definition of the command.
+    pub current: &'a Command,
+    /// Desired definition of the command.
+    pub desired: &'a Command,
+    /// Fields that differ between the definitions, in declaration order.
+    pub fields: Vec<CommandField>,
+}
+
+/// Difference between two sets of commands, created via [`diff`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CommandDiff<'a> {
+    /// Desired commands that are not currently registered.
+    pub added: Vec<&'a Command>,
+    /// Commands that are registered but whose definition has changed.
+    pub changed: Vec<CommandChange<'a>>,
+    /// Currently registered commands that are no longer desired.
+    pub removed: Vec<&'a Command>,
+}
+
+impl CommandDiff<'_> {
+    /// Whether the sets of commands are equivalent, meaning no commands need
+    /// to be registered.
+    #[must_use = "checking whether the diff is empty has no effect if unused"]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Compare the currently registered commands against the desired commands.
+///
+/// Commands are matched by their type and name. Missing localizations are
+/// considered equal to empty localizations, and a missing DM permission is
+/// considered equal to Discord's default of allowing the command in DMs.
+///
+/// Added and changed commands are in the order of `desired`, and removed
+/// commands are in the order of `current`.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{
+///     application::command::{Command, CommandType},
+///     id::Id,
+/// };
+/// use twilight_util::command_diff::{self, CommandField};
+///
+/// let command = Command {
+///     application_id: Some(Id::new(1)),
+///     default_member_permissions: None,
+///     dm_permission: None,
+///     description: "ping the bot".to_owned(),
+///     description_localizations: None,
+///     guild_id: None,
+///     id: Some(Id::new(2)),
+///     kind: CommandType::ChatInput,
+///     name: "ping".to_owned(),
+///     name_localizations: None,
+///     options: Vec::new(),
+///     version: Id::new(3),
+/// };
+///
+/// let mut desired = command.clone();
+/// desired.description = "check the bot's latency".to_owned();
+///
+/// let current = [command];
+/// let desired = [desired];
+/// let diff = command_diff::diff(&current, &desired);
+///
+/// assert!(diff.added.is_empty() && diff.removed.is_empty());
+/// assert_eq!([CommandField::Description], diff.changed[0].fields.as_slice());
+/// ```
+#[must_use = "diffing commands has no effect if the result is unused"]
+pub fn diff<'a>(current: &'a [Command], desired: &'a [Command]) -> CommandDiff<'a> {
+    let mut current_by_key = current
+        .iter()
+        .map(|command| ((command.kind, command.name.as_str()), command))
+        .collect::<HashMap<_, _>>();
+
+    let mut diff = CommandDiff::default();
+
+    for command in desired {
+        let existing =
+            if let Some(existing) = current_by_key.remove(&(command.kind, command.name.as_str())) {
+                existing
+            } else {
+                diff.added.push(command);
+
+                continue;
+            };
+
+        let fields = changed_fields(existing, command);
+
+        if !fields.is_empty() {
+            diff.changed.push(CommandChange {
+                current: existing,
+                desired: command,
+                fields,
+            });
+        }
+    }
+
+    diff.removed = current
+        .iter()
+        .filter(|command| current_by_key.contains_key(&(command.kind, command.name.as_str())))
+        .collect();
+
+    diff
+}
+
+/// Fields that differ between two definitions of the same command.
+fn changed_fields(current: &Command, desired: &Command) -> Vec<CommandField> {
+    let mut fields = Vec::new();
+
+    if current.default_member_permissions != desired.default_member_permissions {
+        fields.push(CommandField::DefaultMemberPermissions);
+    }
+
+    if current.description != desired.description {
+        fields.push(CommandField::Description);
+    }
+
+    if !localizations_eq(
+        current.description_localizations.as_ref(),
+        desired.description_localizations.as_ref(),
+    ) {
+        fields.push(CommandField::DescriptionLocalizations);
+    }
+
+    if current.dm_permission.unwrap_or(true) != desired.dm_permission.unwrap_or(true) {
+        fields.push(CommandField::DmPermission);
+    }
+
+    if !localizations_eq(
+        current.name_localizations.as_ref(),
+        desired.name_localizations.as_ref(),
+    ) {
+        fields.push(CommandField::NameLocalizations);
+    }
+
+    if current.options != desired.options {
+        fields.push(CommandField::Options);
+    }
+
+    fields
+}
+
+/// Whether two localization dictionaries are equal, treating a missing
+/// dictionary as an empty one.
+fn localizations_eq(
+    a: Option<&HashMap<String, String>>,
+    b: Option<&HashMap<String, String>>,
+) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a == b,
+        (Some(map), None) | (None, Some(map)) => map.is_empty(),
+        (None, None) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, CommandDiff, CommandField};
+    use static_assertions::assert_impl_all;
+    use std::{collections::HashMap, fmt::Debug};
+    use twilight_model::{
+        application::command::{BaseCommandOptionData, Command, CommandOption, CommandType},
+        id::Id,
+    };
+
+    assert_impl_all!(CommandDiff<'_>: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(CommandField: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+
+    fn command(name: &str, description: &str) -> Command {
+        Command {
+            application_id: None,
+            default_member_permissions: None,
+            dm_permission: None,
+            description: description.to_owned(),
+            description_localizations: None,
+            guild_id: None,
+            id: None,
+            kind: CommandType::ChatInput,
+            name: name.to_owned(),
+            name_localizations: None,
+            options: Vec::new(),
+            version: Id::new(1),
+        }
+    }
+
+    fn option(name: &str, required: bool) -> CommandOption {
+        CommandOption::User(BaseCommandOptionData {
+            description: "user".to_owned(),
+            description_localizations: None,
+            name: name.to_owned(),
+            name_localizations: None,
+            required,
+        })
+    }
+
+    /// Registered commands with server-assigned fields.
+    fn registered(mut command: Command) -> Command {
+        command.application_id = Some(Id::new(2));
+        command.dm_permission = Some(true);
+        command.id = Some(Id::new(3));
+        command.version = Id::new(4);
+
+        command
+    }
+
+    #[test]
+    fn test_unchanged() {
+        let mut desired = command("ping", "ping the bot");
+        desired.name_localizations = Some(HashMap::new());
+        let current = [registered(command("ping", "ping the bot"))];
+
+        assert!(diff(&current, &[desired]).is_empty());
+    }
+
+    #[test]
+    fn test_description() {
+        let current = [registered(command("ping", "ping the bot"))];
+        let desired = [command("ping", "check latency")];
+
+        let diff = diff(&current, &desired);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(1, diff.changed.len());
+        assert_eq!(&current[0], diff.changed[0].current);
+        assert_eq!(&desired[0], diff.changed[0].desired);
+        assert_eq!(
+            [CommandField::Description],
+            diff.changed[0].fields.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let mut current = command("ban", "ban a user");
+        current.options = Vec::from([option("user", false)]);
+        let mut desired = command("ban", "ban users");
+        desired.options = Vec::from([option("user", true)]);
+
+        let current = [registered(current)];
+        let desired = [desired];
+        let diff = diff(&current, &desired);
+        assert_eq!(
+            [CommandField::Description, CommandField::Options],
+            diff.changed[0].fields.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_added_removed() {
+        let mut user = command("ping", "");
+        user.kind = CommandType::User;
+
+        let current = [
+            registered(command("ping", "ping the bot")),
+            registered(command("old", "removed command")),
+        ];
+        let desired = [
+            command("ping", "ping the bot"),
+            user,
+            command("new", "added"),
+        ];
+
+        let diff = diff(&current, &desired);
+        assert_eq!([&desired[1], &desired[2]], diff.added.as_slice());
+        assert!(diff.changed.is_empty());
+        assert_eq!([&current[1]], diff.removed.as_slice());
+    }
+}
@@ -0,0 +1,269 @@
+//! Format usage strings of application commands, such as for help commands.
+//!
+//! A chat input command is formatted as a slash followed by its name and the
+//! path to each of its subcommands, followed by its options. Required options
+//! are formatted as `<name:type>` and optional options as `[name:type]`:
+//!
+//! ```text
+//! /config set volume <level:int> [persist:bool]
+//! ```
+//!
+//! User and message commands have no options, so only their name is
+//! formatted.
+
+use twilight_model::application::command::{Command, CommandOption, CommandType};
+
+/// Format the usage of a command.
+///
+/// Commands with subcommands have a line for each subcommand, in the order
+/// they are defined, separated by newlines. Subcommand groups are walked to
+/// their subcommands.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{
+///     application::command::{
+///         Command, CommandOption, CommandType, NumberCommandOptionData,
+///     },
+///     id::Id,
+/// };
+/// use twilight_util::command_usage;
+///
+/// let command = Command {
+///     application_id: None,
+///     default_member_permissions: None,
+///     dm_permission: None,
+///     description: "Configure the bot".to_owned(),
+///     description_localizations: None,
+///     guild_id: None,
+///     id: None,
+///     kind: CommandType::ChatInput,
+///     name: "volume".to_owned(),
+///     name_localizations: None,
+///     options: Vec::from([CommandOption::Integer(NumberCommandOptionData {
+///         description: "Volume to play at".to_owned(),
+///         name: "level".to_owned(),
+///         required: true,
+///         ..NumberCommandOptionData::default()
+///     })]),
+///     version: Id::new(1),
+/// };
+///
+/// assert_eq!("/volume <level:int>", command_usage::usage(&command));
+/// ```
+#[must_use = "formatting the usage has no effect if left unused"]
+pub fn usage(command: &Command) -> String {
+    if command.kind != CommandType::ChatInput {
+        return command.name.clone();
+    }
+
+    let mut lines = Vec::new();
+    push_lines(&mut lines, &format!("/{}", command.name), &command.options);
+
+    lines.join("\n")
+}
+
+/// Push the usage lines of a command or subcommand with a path and options.
+///
+/// Options are either all subcommands and subcommand groups, which are
+/// walked, or all value options, which are formatted on a single line.
+fn push_lines(lines: &mut Vec<String>, path: &str, options: &[CommandOption]) {
+    let mut line = path.to_owned();
+
+    for option in options {
+        match option {
+            CommandOption::SubCommand(data) | CommandOption::SubCommandGroup(data) => {
+                push_lines(lines, &format!("{path} {}", data.name), &data.options);
+            }
+            _ => push_option(&mut line, option),
+        }
+    }
+
+    // A command with subcommands is only usable through them.
+    if line.len() > path.len() || !options.iter().any(is_subcommand) {
+        lines.push(line);
+    }
+}
+
+/// Push an option to a usage line, marking whether it is required.
+fn push_option(line: &mut String, option: &CommandOption) {
+    let (open, close) = if option.is_required() {
+        ('<', '>')
+    } else {
+        ('[', ']')
+    };
+
+    line.push(' ');
+    line.push(open);
+    line.push_str(name(option));
+    line.push(':');
+    line.push_str(type_name(option));
+    line.push(close);
+}
+
+/// Name of an option.
+fn name(option: &CommandOption) -> &str {
+    match option {
+        CommandOption::Attachment(data)
+        | CommandOption::Boolean(data)
+        | CommandOption::Mentionable(data)
+        | CommandOption::Role(data)
+        | CommandOption::User(data) => &data.name,
+        CommandOption::Channel(data) => &data.name,
+        CommandOption::Integer(data) | CommandOption::Number(data) => &data.name,
+        CommandOption::String(data) => &data.name,
+        CommandOption::SubCommand(data) | CommandOption::SubCommandGroup(data) => &data.name,
+    }
+}
+
+/// Whether an option is a subcommand or subcommand group.
+const fn is_subcommand(option: &CommandOption) -> bool {
+    matches!(
+        option,
+        CommandOption::SubCommand(_) | CommandOption::SubCommandGroup(_)
+    )
+}
+
+/// Short name of the type of a value option.
+const fn type_name(option: &CommandOption) -> &'static str {
+    match option {
+        CommandOption::Attachment(_) => "attachment",
+        CommandOption::Boolean(_) => "bool",
+        CommandOption::Channel(_) => "channel",
+        CommandOption::Integer(_) => "int",
+        CommandOption::Mentionable(_) => "mentionable",
+        CommandOption::Number(_) => "number",
+        CommandOption::Role(_) => "role",
+        CommandOption::String(_) => "string",
+        CommandOption::User(_) => "user",
+        CommandOption::SubCommand(_) => "subcommand",
+        CommandOption::SubCommandGroup(_) => "subcommand group",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::{
+        application::command::{
+            BaseCommandOptionData, ChannelCommandOptionData, ChoiceCommandOptionData, Command,
+            CommandOption, CommandType, NumberCommandOptionData, OptionsCommandOptionData,
+        },
+        id::Id,
+    };
+
+    fn command(kind: CommandType, name: &str, options: Vec<CommandOption>) -> Command {
+        Command {
+            application_id: None,
+            default_member_permissions: None,
+            dm_permission: None,
+            description: String::new(),
+            description_localizations: None,
+            guild_id: None,
+            id: None,
+            kind,
+            name: name.to_owned(),
+            name_localizations: None,
+            options,
+            version: Id::new(1),
+        }
+    }
+
+    fn subcommand(name: &str, options: Vec<CommandOption>) -> OptionsCommandOptionData {
+        OptionsCommandOptionData {
+            name: name.to_owned(),
+            options,
+            ..OptionsCommandOptionData::default()
+        }
+    }
+
+    fn base(name: &str, required: bool) -> BaseCommandOptionData {
+        BaseCommandOptionData {
+            name: name.to_owned(),
+            required,
+            ..BaseCommandOptionData::default()
+        }
+    }
+
+    #[test]
+    fn test_usage_subcommands() {
+        let command = command(
+            CommandType::ChatInput,
+            "config",
+            Vec::from([
+                CommandOption::SubCommandGroup(subcommand(
+                    "set",
+                    Vec::from([
+                        CommandOption::SubCommand(subcommand(
+                            "volume",
+                            Vec::from([
+                                CommandOption::Integer(NumberCommandOptionData {
+                                    name: "level".to_owned(),
+                                    required: true,
+                                    ..NumberCommandOptionData::default()
+                                }),
+                                CommandOption::Boolean(base("persist", false)),
+                            ]),
+                        )),
+                        CommandOption::SubCommand(subcommand(
+                            "channel",
+                            Vec::from([CommandOption::Channel(ChannelCommandOptionData {
+                                name: "target".to_owned(),
+                                required: true,
+                                ..ChannelCommandOptionData::default()
+                            })]),
+                        )),
+                    ]),
+                )),
+                CommandOption::SubCommand(subcommand(
+                    "show",
+                    Vec::from([CommandOption::String(ChoiceCommandOptionData {
+                        name: "key".to_owned(),
+                        ..ChoiceCommandOptionData::default()
+                    })]),
+                )),
+                CommandOption::SubCommand(subcommand("reset", Vec::new())),
+            ]),
+        );
+
+        assert_eq!(
+            "/config set volume <level:int> [persist:bool]\n\
+            /config set channel <target:channel>\n\
+            /config show [key:string]\n\
+            /config reset",
+            super::usage(&command)
+        );
+    }
+
+    #[test]
+    fn test_usage_options() {
+        let command = command(
+            CommandType::ChatInput,
+            "ban",
+            Vec::from([
+                CommandOption::User(base("user", true)),
+                CommandOption::Number(NumberCommandOptionData {
+                    name: "days".to_owned(),
+                    ..NumberCommandOptionData::default()
+                }),
+                CommandOption::Attachment(base("evidence", false)),
+            ]),
+        );
+
+        assert_eq!(
+            "/ban <user:user> [days:number] [evidence:attachment]",
+            super::usage(&command)
+        );
+        assert_eq!(
+            "/ping",
+            super::usage(&self::command(CommandType::ChatInput, "ping", Vec::new()))
+        );
+    }
+
+    #[test]
+    fn test_usage_context_menu() {
+        let command = command(CommandType::User, "Show Profile", Vec::new());
+
+        assert_eq!("Show Profile", super::usage(&command));
+    }
+}
@@ -0,0 +1,442 @@
+//! Ask a user to confirm an action via buttons.
+//!
+//! A [`Confirmation`] sends a message with a confirm and a cancel button and
+//! waits for the user to click one of them, disabling the buttons afterwards.
+//! Clicks are received through a [`Standby`], which must be processing the
+//! events of the shard receiving the interactions.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::Duration,
+};
+use twilight_http::Client;
+use twilight_model::{
+    application::{
+        component::{button::ButtonStyle, ActionRow, Button, Component},
+        interaction::message_component::MessageComponentInteraction,
+    },
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+    id::{
+        marker::{ChannelMarker, MessageMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_standby::Standby;
+
+/// Custom ID of the cancel button.
+const CANCEL_ID: &str = "confirmation-cancel";
+
+/// Custom ID of the confirm button.
+const CONFIRM_ID: &str = "confirmation-confirm";
+
+/// Default time to wait for the user to respond.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Response of the user to a [`Confirmation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ConfirmationResponse {
+    /// User clicked the cancel button.
+    Cancel,
+    /// User clicked the confirm button.
+    Confirm,
+    /// User didn't respond in time.
+    Timeout,
+}
+
+impl ConfirmationResponse {
+    /// Whether the user confirmed the action.
+    #[must_use = "checking whether the action was confirmed has no effect if unused"]
+    pub const fn is_confirmed(self) -> bool {
+        matches!(self, Self::Confirm)
+    }
+}
+
+/// Asking for confirmation failed.
+#[derive(Debug)]
+pub struct ConfirmationError {
+    kind: ConfirmationErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ConfirmationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ConfirmationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ConfirmationErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn request(source: impl Error + Send + Sync + 'static) -> Self {
+        Self {
+            kind: ConfirmationErrorType::Request,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for ConfirmationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            ConfirmationErrorType::Canceled => {
+                f.write_str("standby was dropped before the user responded")
+            }
+            ConfirmationErrorType::Request => f.write_str("sending a request failed"),
+        }
+    }
+}
+
+impl Error for ConfirmationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ConfirmationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConfirmationErrorType {
+    /// [`Standby`] was dropped before the user responded.
+    Canceled,
+    /// Sending the message or updating it failed.
+    ///
+    /// This includes the message being invalid, such as the content being too
+    /// long.
+    Request,
+}
+
+/// Ask a user to confirm an action with a confirm and a cancel button.
+///
+/// Only clicks by the user are accepted. Once the user clicks a button or the
+/// timeout elapses the buttons are disabled.
+///
+/// # Examples
+///
+/// Ask user 2 in channel 1 whether to ban a user:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::time::Duration;
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+/// use twilight_standby::Standby;
+/// use twilight_util::confirmation::Confirmation;
+///
+/// let client = Client::new("my token".to_owned());
+/// let standby = Standby::new();
+///
+/// let response = Confirmation::new(&client, &standby, Id::new(1), Id::new(2))
+///     .content("Ban the user?")
+///     .timeout(Duration::from_secs(30))
+///     .ask()
+///     .await?;
+///
+/// if response.is_confirmed() {
+///     println!("banning the user");
+/// }
+/// # Ok(()) }
+/// ```
+#[must_use = "confirmations must be asked"]
+pub struct Confirmation<'a> {
+    cancel_label: &'a str,
+    channel_id: Id<ChannelMarker>,
+    confirm_label: &'a str,
+    content: &'a str,
+    http: &'a Client,
+    standby: &'a Standby,
+    timeout: Duration,
+    user_id: Id<UserMarker>,
+}
+
+impl<'a> Confirmation<'a> {
+    /// Create a new confirmation asking a user in a channel.
+    pub const fn new(
+        http: &'a Client,
+        standby: &'a Standby,
+        channel_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Self {
+        Self {
+            cancel_label: "Cancel",
+            channel_id,
+            confirm_label: "Confirm",
+            content: "Are you sure?",
+            http,
+            standby,
+            timeout: DEFAULT_TIMEOUT,
+            user_id,
+        }
+    }
+
+    /// Set the label of the cancel button.
+    ///
+    /// Defaults to "Cancel".
+    pub const fn cancel_label(mut self, cancel_label: &'a str) -> Self {
+        self.cancel_label = cancel_label;
+
+        self
+    }
+
+    /// Set the label of the confirm button.
+    ///
+    /// Defaults to "Confirm".
+    pub const fn confirm_label(mut self, confirm_label: &'a str) -> Self {
+        self.confirm_label = confirm_label;
+
+        self
+    }
+
+    /// Set the content of the message.
+    ///
+    /// Defaults to "Are you sure?".
+    pub const fn content(mut self, content: &'a str) -> Self {
+        self.content = content;
+
+        self
+    }
+
+    /// Set the time to wait for the user to respond.
+    ///
+    /// Defaults to 60 seconds.
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    /// Send the message and wait for the user to respond.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Canceled`] if the [`Standby`] was dropped
+    /// before the user responded.
+    ///
+    /// Returns an error of type [`Request`] if the message is invalid or
+    /// sending or updating it failed.
+    ///
+    /// [`Canceled`]: ConfirmationErrorType::Canceled
+    /// [`Request`]: ConfirmationErrorType::Request
+    pub async fn ask(self) -> Result<ConfirmationResponse, ConfirmationError> {
+        let components = [buttons(self.confirm_label, self.cancel_label, false)];
+
+        let message = self
+            .http
+            .create_message(self.channel_id)
+            .content(self.content)
+            .map_err(ConfirmationError::request)?
+            .components(&components)
+            .map_err(ConfirmationError::request)?
+            .exec()
+            .await
+            .map_err(ConfirmationError::request)?
+            .model()
+            .await
+            .map_err(ConfirmationError::request)?;
+
+        let clicked = wait(self.standby, message.id, self.user_id, self.timeout).await?;
+        let disabled = Vec::from([buttons(self.confirm_label, self.cancel_label, true)]);
+
+        let (response, interaction) = if let Some(clicked) = clicked {
+            clicked
+        } else {
+            self.http
+                .update_message(self.channel_id, message.id)
+                .components(Some(&disabled))
+                .map_err(ConfirmationError::request)?
+                .exec()
+                .await
+                .map_err(ConfirmationError::request)?;
+
+            return Ok(ConfirmationResponse::Timeout);
+        };
+
+        let update = InteractionResponse {
+            kind: InteractionResponseType::UpdateMessage,
+            data: Some(InteractionResponseData {
+                components: Some(disabled),
+                ..InteractionResponseData::default()
+            }),
+        };
+
+        self.http
+            .interaction(interaction.application_id)
+            .create_response(interaction.id, &interaction.token, &update)
+            .exec()
+            .await
+            .map_err(ConfirmationError::request)?;
+
+        Ok(response)
+    }
+}
+
+/// Action row with the confirm and cancel buttons.
+fn buttons(confirm_label: &str, cancel_label: &str, disabled: bool) -> Component {
+    let button = |custom_id: &str, label: &str, style| {
+        Component::Button(Button {
+            custom_id: Some(custom_id.to_owned()),
+            disabled,
+            emoji: None,
+            label: Some(label.to_owned()),
+            style,
+            url: None,
+        })
+    };
+
+    Component::ActionRow(ActionRow {
+        components: Vec::from([
+            button(CONFIRM_ID, confirm_label, ButtonStyle::Success),
+            button(CANCEL_ID, cancel_label, ButtonStyle::Danger),
+        ]),
+    })
+}
+
+/// Wait for the user to click one of the buttons on a message, returning
+/// [`None`] if the timeout elapsed.
+async fn wait(
+    standby: &Standby,
+    message_id: Id<MessageMarker>,
+    user_id: Id<UserMarker>,
+    timeout: Duration,
+) -> Result<Option<(ConfirmationResponse, MessageComponentInteraction)>, ConfirmationError> {
+    let future = standby.wait_for_component(
+        message_id,
+        move |interaction: &MessageComponentInteraction| {
+            interaction.author_id() == Some(user_id)
+                && [CANCEL_ID, CONFIRM_ID].contains(&interaction.data.custom_id.as_str())
+        },
+    );
+
+    let interaction = match tokio::time::timeout(timeout, future).await {
+        Ok(Ok(interaction)) => interaction,
+        Ok(Err(source)) => {
+            return Err(ConfirmationError {
+                kind: ConfirmationErrorType::Canceled,
+                source: Some(Box::new(source)),
+            })
+        }
+        Err(_) => return Ok(None),
+    };
+
+    let response = if interaction.data.custom_id == CONFIRM_ID {
+        ConfirmationResponse::Confirm
+    } else {
+        ConfirmationResponse::Cancel
+    };
+
+    Ok(Some((response, interaction)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{buttons, wait, Confirmation, ConfirmationError, ConfirmationResponse, CONFIRM_ID};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, time::Duration};
+    use twilight_model::{
+        application::{component::Component, interaction::Interaction},
+        gateway::{event::Event, payload::incoming::InteractionCreate},
+        id::Id,
+    };
+    use twilight_standby::Standby;
+
+    assert_impl_all!(Confirmation<'_>: Send, Sync);
+    assert_impl_all!(ConfirmationError: Debug, Error, Send, Sync);
+    assert_impl_all!(ConfirmationResponse: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+
+    /// Click of a button on message 3 by a user.
+    fn click(user_id: u64, custom_id: &str) -> Event {
+        let interaction = serde_json::from_value::<Interaction>(serde_json::json!({
+            "application_id": "1",
+            "channel_id": "2",
+            "data": {"component_type": 2, "custom_id": custom_id},
+            "id": "4",
+            "locale": "en-US",
+            "message": {
+                "attachments": [],
+                "author": {"discriminator": "0001", "id": "5", "username": "twilight"},
+                "channel_id": "2",
+                "content": "Are you sure?",
+                "edited_timestamp": null,
+                "embeds": [],
+                "id": "3",
+                "mention_everyone": false,
+                "mention_roles": [],
+                "mentions": [],
+                "pinned": false,
+                "timestamp": "2021-09-19T17:30:45.000000+00:00",
+                "tts": false,
+                "type": 0,
+            },
+            "token": "token",
+            "type": 3,
+            "user": {"discriminator": "0001", "id": user_id.to_string(), "username": "user"},
+        }))
+        .unwrap();
+
+        Event::InteractionCreate(InteractionCreate(interaction))
+    }
+
+    #[test]
+    fn test_buttons() {
+        let disabled = buttons("Yes", "No", true);
+
+        let row = if let Component::ActionRow(row) = disabled {
+            row
+        } else {
+            panic!("expected an action row");
+        };
+
+        assert_eq!(2, row.components.len());
+        assert!(row
+            .components
+            .iter()
+            .all(|component| matches!(component, Component::Button(button) if button.disabled)));
+    }
+
+    #[tokio::test]
+    async fn test_confirm() -> Result<(), ConfirmationError> {
+        let standby = Standby::new();
+        let future = wait(&standby, Id::new(3), Id::new(6), Duration::from_secs(10));
+
+        // Clicks by other users are ignored.
+        let process = async {
+            standby.process(&click(7, CONFIRM_ID));
+            standby.process(&click(6, CONFIRM_ID));
+        };
+
+        let (clicked, ()) = tokio::join!(future, process);
+        let (response, interaction) = clicked?.unwrap();
+
+        assert_eq!(ConfirmationResponse::Confirm, response);
+        assert_eq!(Some(Id::new(6)), interaction.author_id());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_timeout() -> Result<(), ConfirmationError> {
+        let standby = Standby::new();
+        let future = wait(&standby, Id::new(3), Id::new(6), Duration::from_millis(50));
+
+        let process = async {
+            standby.process(&click(7, CONFIRM_ID));
+        };
+
+        let (clicked, ()) = tokio::join!(future, process);
+        assert!(clicked?.is_none());
+
+        Ok(())
+    }
+}
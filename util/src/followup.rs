@@ -0,0 +1,444 @@
+//! Send interaction followups that are deleted after a duration.
+//!
+//! An [`ExpiringFollowup`] sends a followup message and schedules its
+//! deletion, such as for progress messages that are only relevant for a short
+//! time. Followups are deleted via the interaction token, so they are deleted
+//! before the token expires if the duration would exceed its lifetime.
+
+use crate::snowflake::Snowflake;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::{task::JoinHandle, time::Instant};
+use twilight_http::{request::application::interaction::CreateFollowup, Client};
+use twilight_model::{
+    channel::Message,
+    id::{
+        marker::{ApplicationMarker, InteractionMarker},
+        Id,
+    },
+};
+
+/// Default time after which followups are deleted.
+const DEFAULT_DELETE_AFTER: Duration = Duration::from_secs(10);
+
+/// Time an interaction token is valid for after the interaction was created.
+const TOKEN_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+/// Time before the token expires at which followups are deleted at the
+/// latest, so that the deletion request arrives while the token is valid.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(5);
+
+/// Sending an expiring followup failed.
+#[derive(Debug)]
+pub struct ExpiringFollowupError {
+    kind: ExpiringFollowupErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ExpiringFollowupError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ExpiringFollowupErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ExpiringFollowupErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+
+    fn request(source: impl Error + Send + Sync + 'static) -> Self {
+        Self {
+            kind: ExpiringFollowupErrorType::Request,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for ExpiringFollowupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            ExpiringFollowupErrorType::Request => f.write_str("sending the followup failed"),
+        }
+    }
+}
+
+impl Error for ExpiringFollowupError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ExpiringFollowupError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ExpiringFollowupErrorType {
+    /// Sending the followup or deserializing the created message failed.
+    Request,
+}
+
+/// Scheduled deletion of a followup sent by an [`ExpiringFollowup`].
+///
+/// Dropping the handle doesn't cancel the deletion.
+#[derive(Debug)]
+pub struct FollowupDeletion {
+    handle: JoinHandle<()>,
+    message: Message,
+}
+
+impl FollowupDeletion {
+    /// Cancel the deletion, keeping the followup.
+    ///
+    /// Has no effect if the followup has already been deleted.
+    pub fn cancel(self) {
+        self.handle.abort();
+    }
+
+    /// Immutable reference to the sent followup.
+    #[must_use = "retrieving the message has no effect if left unused"]
+    pub const fn message(&self) -> &Message {
+        &self.message
+    }
+}
+
+/// Send a followup to an interaction and delete it after a duration.
+///
+/// The followup is deleted in the background by a spawned task, so it must be
+/// sent from within a Tokio runtime. If the deletion fails, such as because
+/// the followup was already deleted, then the error is discarded.
+///
+/// Interaction tokens are only valid for 15 minutes after the interaction was
+/// created, which is determined from the interaction's ID. If the followup
+/// would be deleted after the token expired, then it is deleted shortly before
+/// the token expires instead.
+///
+/// # Examples
+///
+/// Send an ephemeral progress message and delete it after 30 seconds:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::{sync::Arc, time::Duration};
+/// use twilight_http::Client;
+/// use twilight_model::{channel::message::MessageFlags, id::Id};
+/// use twilight_util::followup::ExpiringFollowup;
+///
+/// let client = Arc::new(Client::new("my token".to_owned()));
+/// let application_id = Id::new(1);
+/// let interaction_id = Id::new(2);
+/// let token = "interaction token".to_owned();
+///
+/// let interaction = client.interaction(application_id);
+/// let request = interaction
+///     .create_followup(&token)
+///     .content("Working on it...")?
+///     .flags(MessageFlags::EPHEMERAL);
+///
+/// let deletion = ExpiringFollowup::new(
+///     Arc::clone(&client),
+///     application_id,
+///     interaction_id,
+///     token.clone(),
+/// )
+/// .delete_after(Duration::from_secs(30))
+/// .send(request)
+/// .await?;
+///
+/// println!("sent followup {}", deletion.message().id);
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+#[must_use = "expiring followups must be sent"]
+pub struct ExpiringFollowup {
+    application_id: Id<ApplicationMarker>,
+    delete_after: Duration,
+    http: Arc<Client>,
+    interaction_id: Id<InteractionMarker>,
+    token: String,
+}
+
+impl ExpiringFollowup {
+    /// Create a new expiring followup to an interaction, by its ID and token.
+    pub const fn new(
+        http: Arc<Client>,
+        application_id: Id<ApplicationMarker>,
+        interaction_id: Id<InteractionMarker>,
+        token: String,
+    ) -> Self {
+        Self {
+            application_id,
+            delete_after: DEFAULT_DELETE_AFTER,
+            http,
+            interaction_id,
+            token,
+        }
+    }
+
+    /// Set the time after which the followup is deleted.
+    ///
+    /// Defaults to 10 seconds.
+    pub const fn delete_after(mut self, delete_after: Duration) -> Self {
+        self.delete_after = delete_after;
+
+        self
+    }
+
+    /// Send the followup and schedule its deletion.
+    ///
+    /// The request must be a followup to the same interaction token, created
+    /// with the same client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Request`] if sending the followup failed, in
+    /// which case no deletion is scheduled.
+    ///
+    /// [`Request`]: ExpiringFollowupErrorType::Request
+    pub async fn send(
+        self,
+        request: CreateFollowup<'_>,
+    ) -> Result<FollowupDeletion, ExpiringFollowupError> {
+        let message = request
+            .exec()
+            .await
+            .map_err(ExpiringFollowupError::request)?
+            .model()
+            .await
+            .map_err(ExpiringFollowupError::request)?;
+
+        let deadline =
+            Instant::now() + delay(self.interaction_id, self.delete_after, SystemTime::now());
+        let message_id = message.id;
+
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep_until(deadline).await;
+
+            let _response = self
+                .http
+                .interaction(self.application_id)
+                .delete_followup(&self.token, message_id)
+                .exec()
+                .await;
+        });
+
+        Ok(FollowupDeletion { handle, message })
+    }
+}
+
+/// Time to wait before deleting a followup, deleting it before the
+/// interaction token expires at the latest.
+fn delay(
+    interaction_id: Id<InteractionMarker>,
+    delete_after: Duration,
+    now: SystemTime,
+) -> Duration {
+    let created = u64::try_from(interaction_id.timestamp()).unwrap_or_default();
+    let expires = UNIX_EPOCH + Duration::from_millis(created) + TOKEN_LIFETIME;
+
+    let remaining = expires
+        .duration_since(now)
+        .unwrap_or_default()
+        .saturating_sub(TOKEN_EXPIRY_MARGIN);
+
+    delete_after.min(remaining)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{delay, ExpiringFollowup, ExpiringFollowupError, FollowupDeletion};
+    use crate::test_server;
+    use hyper::{Body, Method, Request as HyperRequest, Response as HyperResponse};
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::Infallible,
+        error::Error,
+        fmt::Debug,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+    use twilight_http::Client;
+    use twilight_model::id::{marker::InteractionMarker, Id};
+
+    assert_impl_all!(ExpiringFollowup: Debug, Send, Sync);
+    assert_impl_all!(ExpiringFollowupError: Debug, Error, Send, Sync);
+    assert_impl_all!(FollowupDeletion: Debug, Send, Sync);
+
+    /// Requests received by the server, as their method and path.
+    type Requests = Arc<Mutex<Vec<(Method, String)>>>;
+
+    const MESSAGE: &str = r#"{
+        "attachments": [],
+        "author": {"avatar": null, "discriminator": "0001", "id": "1", "username": "bot"},
+        "channel_id": "2",
+        "content": "Working on it...",
+        "edited_timestamp": null,
+        "embeds": [],
+        "id": "3",
+        "mention_everyone": false,
+        "mention_roles": [],
+        "mentions": [],
+        "pinned": false,
+        "timestamp": "2021-09-19T17:30:45.000000+00:00",
+        "tts": false,
+        "type": 0
+    }"#;
+
+    /// Interaction ID created at a time.
+    fn interaction_id(created: SystemTime) -> Id<InteractionMarker> {
+        let millis = created.duration_since(UNIX_EPOCH).unwrap().as_millis();
+        let millis = u64::try_from(millis).unwrap() - 1_420_070_400_000;
+
+        Id::new(millis << 22 | 1)
+    }
+
+    /// Spawn a server responding to every request with a message, recording
+    /// the requests.
+    fn serve() -> (SocketAddr, Requests) {
+        let requests = Requests::default();
+        let server_requests = Arc::clone(&requests);
+
+        let addr = test_server::serve(move |request: HyperRequest<Body>| {
+            server_requests
+                .lock()
+                .unwrap()
+                .push((request.method().clone(), request.uri().path().to_owned()));
+
+            async move { Ok::<_, Infallible>(HyperResponse::new(Body::from(MESSAGE))) }
+        });
+
+        (addr, requests)
+    }
+
+    #[test]
+    fn test_delay() {
+        // Snowflakes have millisecond precision.
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        let now = UNIX_EPOCH + Duration::from_millis(u64::try_from(millis).unwrap());
+        let delete_after = Duration::from_secs(60);
+
+        assert_eq!(delete_after, delay(interaction_id(now), delete_after, now));
+
+        // The token expires 15 minutes after the interaction was created.
+        let created = now - Duration::from_secs(14 * 60 + 30);
+        assert_eq!(
+            Duration::from_secs(25),
+            delay(interaction_id(created), delete_after, now)
+        );
+
+        let created = now - Duration::from_secs(20 * 60);
+        assert_eq!(
+            Duration::ZERO,
+            delay(interaction_id(created), delete_after, now)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_after() -> Result<(), Box<dyn Error + Send + Sync>> {
+        const DELETE_AFTER: Duration = Duration::from_secs(30);
+
+        let (addr, requests) = serve();
+        let client = Arc::new(
+            Client::builder()
+                .proxy(addr.to_string(), true)
+                .token("Bot token".to_owned())
+                .build(),
+        );
+        let deletions = |requests: &Requests| {
+            requests
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(method, _)| *method == Method::DELETE)
+                .map(|(_, path)| path.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let interaction = client.interaction(Id::new(1));
+        let request = interaction.create_followup("token");
+        let deletion = ExpiringFollowup::new(
+            Arc::clone(&client),
+            Id::new(1),
+            interaction_id(SystemTime::now()),
+            "token".to_owned(),
+        )
+        .delete_after(DELETE_AFTER)
+        .send(request.content("Working on it...")?)
+        .await?;
+        assert_eq!(Id::new(3), deletion.message().id);
+
+        tokio::time::pause();
+        tokio::time::advance(Duration::from_secs(29)).await;
+        assert!(deletions(&requests).is_empty());
+
+        tokio::time::advance(Duration::from_secs(1)).await;
+        tokio::time::resume();
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while deletions(&requests).is_empty() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await?;
+
+        assert_eq!(
+            ["/api/v10/webhooks/1/token/messages/3".to_owned()].as_slice(),
+            deletions(&requests)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (addr, requests) = serve();
+        let client = Arc::new(
+            Client::builder()
+                .proxy(addr.to_string(), true)
+                .token("Bot token".to_owned())
+                .build(),
+        );
+
+        let interaction = client.interaction(Id::new(1));
+        let request = interaction.create_followup("token");
+        ExpiringFollowup::new(
+            Arc::clone(&client),
+            Id::new(1),
+            interaction_id(SystemTime::now()),
+            "token".to_owned(),
+        )
+        .delete_after(Duration::from_millis(50))
+        .send(request.content("Working on it...")?)
+        .await?
+        .cancel();
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(1, requests.len());
+        assert_eq!(Method::POST, requests[0].0);
+
+        Ok(())
+    }
+}
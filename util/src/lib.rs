@@ -11,10 +11,30 @@
 //!
 //! Provides builders for large structs.
 //!
+//! ### `command-diff`
+//!
+//! Provides a function to compare sets of application commands, such as the
+//! registered and desired commands, to only register those that changed.
+//!
+//! ### `command-usage`
+//!
+//! Provides a function to format the usage of an application command, such as
+//! for help commands listing the subcommands and options of each command.
+//!
+//! ### `confirmation`
+//!
+//! Provides a prompt asking a user to confirm an action by clicking a button,
+//! waiting for the click via `twilight-standby`.
+//!
+//! ### `followup`
+//!
+//! Provides a helper sending an interaction followup and deleting it after a
+//! duration, such as for progress messages.
+//!
 //! ### `link`
 //!
 //! Provides implementations for parsing and formatting entities' URLs, such as
-//! webhook URLs.
+//! webhook URLs and CDN URLs of avatars and icons.
 //!
 //! ### `permission-calculator`
 //!
@@ -26,6 +46,21 @@
 //! Allows the use of the `Snowflake` trait, which provides methods for the extraction of
 //! structured information from [Discord snowflakes].
 //!
+//! ### `throttle`
+//!
+//! Provides a sender pacing the messages sent to each channel, queuing
+//! messages up to a limit and dropping further ones.
+//!
+//! ### `token`
+//!
+//! Provides a function to extract the ID of the user a bot token belongs to,
+//! without validating the token.
+//!
+//! ### `truncate`
+//!
+//! Provides a function to truncate text, such as message content, to a
+//! maximum number of characters without splitting multibyte characters.
+//!
 //! [`twilight-rs`]: https://github.com/twilight-rs/twilight
 //! [codecov badge]: https://img.shields.io/codecov/c/gh/twilight-rs/twilight?logo=codecov&style=for-the-badge&token=E9ERLJL0L2
 //! [codecov link]: https://app.codecov.io/gh/twilight-rs/twilight/
@@ -55,6 +90,18 @@
 #[cfg(feature = "builder")]
 pub mod builder;
 
+#[cfg(feature = "command-diff")]
+pub mod command_diff;
+
+#[cfg(feature = "command-usage")]
+pub mod command_usage;
+
+#[cfg(feature = "confirmation")]
+pub mod confirmation;
+
+#[cfg(feature = "followup")]
+pub mod followup;
+
 #[cfg(feature = "link")]
 pub mod link;
 
@@ -63,3 +110,15 @@ pub mod permission_calculator;
 
 #[cfg(feature = "snowflake")]
 pub mod snowflake;
+
+#[cfg(feature = "throttle")]
+pub mod throttle;
+
+#[cfg(feature = "token")]
+pub mod token;
+
+#[cfg(feature = "truncate")]
+pub mod truncate;
+
+#[cfg(all(test, any(feature = "followup", feature = "throttle")))]
+mod test_server;
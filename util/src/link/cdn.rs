@@ -0,0 +1,426 @@
+//! Utilities for formatting CDN URLs of images, such as avatars and icons.
+//!
+//! Animated images, whose hash starts with `a_`, are linked as GIFs, while
+//! static images are linked as PNGs. Users without an avatar are linked to
+//! their default avatar. Stickers are linked by their format type.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::message::sticker::StickerFormatType,
+    id::{
+        marker::{GuildMarker, StickerMarker, UserMarker},
+        Id,
+    },
+    user::User,
+    util::ImageHash,
+};
+
+/// Base URL of the CDN.
+const BASE: &str = "https://cdn.discordapp.com";
+
+/// Base URL of the media proxy, which serves GIF stickers.
+const MEDIA_BASE: &str = "https://media.discordapp.net";
+
+/// Maximum size of an image.
+const SIZE_MAX: u16 = 4096;
+
+/// Minimum size of an image.
+const SIZE_MIN: u16 = 16;
+
+/// Error when formatting a CDN URL.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct CdnUrlError {
+    kind: CdnUrlErrorType,
+}
+
+impl CdnUrlError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CdnUrlErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CdnUrlErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for CdnUrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            CdnUrlErrorType::SizeInvalid { size } => {
+                f.write_str("image size ")?;
+                Display::fmt(&size, f)?;
+                f.write_str(" isn't a power of two between ")?;
+                Display::fmt(&SIZE_MIN, f)?;
+                f.write_str(" and ")?;
+
+                Display::fmt(&SIZE_MAX, f)
+            }
+        }
+    }
+}
+
+impl Error for CdnUrlError {}
+
+/// Type of [`CdnUrlError`] that occurred.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CdnUrlErrorType {
+    /// Size isn't a power of two between 16 and 4096.
+    SizeInvalid {
+        /// Provided size.
+        size: u16,
+    },
+}
+
+/// URL of a user's avatar, or of their default avatar if they have none.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{id::Id, user::User, util::ImageHash};
+/// use twilight_util::link::cdn;
+///
+/// let mut user = User {
+///     accent_color: None,
+///     avatar: Some(ImageHash::parse(b"a_1269e74af4df7417b13759eae50c83dc")?),
+///     banner: None,
+///     bot: false,
+///     discriminator: 0,
+///     email: None,
+///     flags: None,
+///     id: Id::new(80351110224678912),
+///     locale: None,
+///     mfa_enabled: None,
+///     name: "nelly".to_owned(),
+///     premium_type: None,
+///     public_flags: None,
+///     system: None,
+///     verified: None,
+/// };
+///
+/// assert_eq!(
+///     "https://cdn.discordapp.com/avatars/80351110224678912/a_1269e74af4df7417b13759eae50c83dc.gif?size=128",
+///     cdn::user_avatar_url(&user, 128)?,
+/// );
+///
+/// user.avatar = None;
+/// assert_eq!(
+///     "https://cdn.discordapp.com/embed/avatars/5.png",
+///     cdn::user_avatar_url(&user, 128)?,
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error of type [`SizeInvalid`] if the size isn't a power of two
+/// between 16 and 4096.
+///
+/// [`SizeInvalid`]: CdnUrlErrorType::SizeInvalid
+pub fn user_avatar_url(user: &User, size: u16) -> Result<String, CdnUrlError> {
+    validate_size(size)?;
+
+    Ok(match user.avatar {
+        Some(avatar) => image_url(&format!("avatars/{}", user.id), avatar, size),
+        None => default_avatar_url(user.id, user.discriminator),
+    })
+}
+
+/// URL of a user's banner, if they have one.
+///
+/// # Errors
+///
+/// Returns an error of type [`SizeInvalid`] if the size isn't a power of two
+/// between 16 and 4096.
+///
+/// [`SizeInvalid`]: CdnUrlErrorType::SizeInvalid
+pub fn user_banner_url(user: &User, size: u16) -> Result<Option<String>, CdnUrlError> {
+    validate_size(size)?;
+
+    Ok(user
+        .banner
+        .map(|banner| image_url(&format!("banners/{}", user.id), banner, size)))
+}
+
+/// URL of the default avatar of a user without an avatar.
+///
+/// Users that migrated to the username system without discriminators have a
+/// discriminator of 0, in which case the default avatar is determined from
+/// their ID. Otherwise it is determined from their discriminator.
+///
+/// Default avatars are only available in a single size.
+#[must_use = "formatting the URL has no effect if left unused"]
+pub fn default_avatar_url(user_id: Id<UserMarker>, discriminator: u16) -> String {
+    let index = if discriminator == 0 {
+        (user_id.get() >> 22) % 6
+    } else {
+        u64::from(discriminator % 5)
+    };
+
+    format!("{BASE}/embed/avatars/{index}.png")
+}
+
+/// URL of a member's guild-specific avatar.
+///
+/// # Errors
+///
+/// Returns an error of type [`SizeInvalid`] if the size isn't a power of two
+/// between 16 and 4096.
+///
+/// [`SizeInvalid`]: CdnUrlErrorType::SizeInvalid
+pub fn member_avatar_url(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    avatar: ImageHash,
+    size: u16,
+) -> Result<String, CdnUrlError> {
+    validate_size(size)?;
+
+    Ok(image_url(
+        &format!("guilds/{guild_id}/users/{user_id}/avatars"),
+        avatar,
+        size,
+    ))
+}
+
+/// URL of a guild's icon.
+///
+/// # Errors
+///
+/// Returns an error of type [`SizeInvalid`] if the size isn't a power of two
+/// between 16 and 4096.
+///
+/// [`SizeInvalid`]: CdnUrlErrorType::SizeInvalid
+pub fn guild_icon_url(
+    guild_id: Id<GuildMarker>,
+    icon: ImageHash,
+    size: u16,
+) -> Result<String, CdnUrlError> {
+    validate_size(size)?;
+
+    Ok(image_url(&format!("icons/{guild_id}"), icon, size))
+}
+
+/// URL of a guild's banner.
+///
+/// # Errors
+///
+/// Returns an error of type [`SizeInvalid`] if the size isn't a power of two
+/// between 16 and 4096.
+///
+/// [`SizeInvalid`]: CdnUrlErrorType::SizeInvalid
+pub fn guild_banner_url(
+    guild_id: Id<GuildMarker>,
+    banner: ImageHash,
+    size: u16,
+) -> Result<String, CdnUrlError> {
+    validate_size(size)?;
+
+    Ok(image_url(&format!("banners/{guild_id}"), banner, size))
+}
+
+/// URL of a sticker's image, if its format type is known.
+///
+/// APNG stickers are served with the `png` extension and LOTTIE stickers are
+/// served as JSON. GIF stickers are served by the media proxy rather than
+/// the CDN.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{channel::message::sticker::StickerFormatType, id::Id};
+/// use twilight_util::link::cdn;
+///
+/// assert_eq!(
+///     Some("https://media.discordapp.net/stickers/1.gif"),
+///     cdn::sticker_url(Id::new(1), StickerFormatType::Gif).as_deref(),
+/// );
+/// ```
+#[must_use = "formatting the URL has no effect if left unused"]
+pub fn sticker_url(
+    sticker_id: Id<StickerMarker>,
+    format_type: StickerFormatType,
+) -> Option<String> {
+    let (base, extension) = match format_type {
+        StickerFormatType::Png | StickerFormatType::Apng => (BASE, "png"),
+        StickerFormatType::Lottie => (BASE, "json"),
+        StickerFormatType::Gif => (MEDIA_BASE, "gif"),
+        StickerFormatType::Unknown { .. } => return None,
+    };
+
+    Some(format!("{base}/stickers/{sticker_id}.{extension}"))
+}
+
+/// Format the URL of an image, choosing the extension by whether it is
+/// animated.
+fn image_url(path: &str, hash: ImageHash, size: u16) -> String {
+    let extension = if hash.is_animated() { "gif" } else { "png" };
+
+    format!("{BASE}/{path}/{hash}.{extension}?size={size}")
+}
+
+/// Ensure an image size is supported by the CDN.
+const fn validate_size(size: u16) -> Result<(), CdnUrlError> {
+    if size >= SIZE_MIN && size <= SIZE_MAX && size.is_power_of_two() {
+        Ok(())
+    } else {
+        Err(CdnUrlError {
+            kind: CdnUrlErrorType::SizeInvalid { size },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CdnUrlError, CdnUrlErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        channel::message::sticker::StickerFormatType, id::Id, user::User, util::ImageHash,
+    };
+
+    assert_impl_all!(CdnUrlErrorType: Debug, Send, Sync);
+    assert_impl_all!(CdnUrlError: Error, Send, Sync);
+
+    const ANIMATED: &[u8] = b"a_1269e74af4df7417b13759eae50c83dc";
+    const STATIC: &[u8] = b"1269e74af4df7417b13759eae50c83dc";
+
+    fn user(avatar: Option<ImageHash>, discriminator: u16) -> User {
+        User {
+            accent_color: None,
+            avatar,
+            banner: None,
+            bot: false,
+            discriminator,
+            email: None,
+            flags: None,
+            id: Id::new(80_351_110_224_678_912),
+            locale: None,
+            mfa_enabled: None,
+            name: "nelly".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    #[test]
+    fn test_animated() -> Result<(), Box<dyn Error>> {
+        let hash = ImageHash::parse(ANIMATED)?;
+
+        assert_eq!(
+            "https://cdn.discordapp.com/avatars/80351110224678912/a_1269e74af4df7417b13759eae50c83dc.gif?size=64",
+            super::user_avatar_url(&user(Some(hash), 0), 64)?
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/icons/1/a_1269e74af4df7417b13759eae50c83dc.gif?size=1024",
+            super::guild_icon_url(Id::new(1), hash, 1024)?
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/guilds/1/users/2/avatars/a_1269e74af4df7417b13759eae50c83dc.gif?size=16",
+            super::member_avatar_url(Id::new(1), Id::new(2), hash, 16)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_static() -> Result<(), Box<dyn Error>> {
+        let hash = ImageHash::parse(STATIC)?;
+
+        assert_eq!(
+            "https://cdn.discordapp.com/avatars/80351110224678912/1269e74af4df7417b13759eae50c83dc.png?size=4096",
+            super::user_avatar_url(&user(Some(hash), 0), 4096)?
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/banners/1/1269e74af4df7417b13759eae50c83dc.png?size=512",
+            super::guild_banner_url(Id::new(1), hash, 512)?
+        );
+
+        let mut with_banner = user(None, 0);
+        assert!(super::user_banner_url(&with_banner, 512)?.is_none());
+
+        with_banner.banner = Some(hash);
+        assert_eq!(
+            Some("https://cdn.discordapp.com/banners/80351110224678912/1269e74af4df7417b13759eae50c83dc.png?size=512"),
+            super::user_banner_url(&with_banner, 512)?.as_deref()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_avatar() -> Result<(), Box<dyn Error>> {
+        // Users of the username system without discriminators have a default
+        // avatar determined from their ID.
+        assert_eq!(
+            "https://cdn.discordapp.com/embed/avatars/5.png",
+            super::user_avatar_url(&user(None, 0), 128)?
+        );
+
+        // Legacy users have a default avatar determined from their
+        // discriminator.
+        assert_eq!(
+            "https://cdn.discordapp.com/embed/avatars/2.png",
+            super::user_avatar_url(&user(None, 1337), 128)?
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/embed/avatars/0.png",
+            super::default_avatar_url(Id::new(1), 5)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sticker() {
+        let url = |format_type| super::sticker_url(Id::new(1), format_type);
+
+        assert_eq!(
+            Some("https://cdn.discordapp.com/stickers/1.png"),
+            url(StickerFormatType::Png).as_deref()
+        );
+        assert_eq!(
+            Some("https://cdn.discordapp.com/stickers/1.png"),
+            url(StickerFormatType::Apng).as_deref()
+        );
+        assert_eq!(
+            Some("https://cdn.discordapp.com/stickers/1.json"),
+            url(StickerFormatType::Lottie).as_deref()
+        );
+        assert_eq!(
+            Some("https://media.discordapp.net/stickers/1.gif"),
+            url(StickerFormatType::Gif).as_deref()
+        );
+        assert!(url(StickerFormatType::Unknown { value: 99 }).is_none());
+    }
+
+    #[test]
+    fn test_size_invalid() {
+        for size in [0, 8, 100, 8192] {
+            assert!(matches!(
+                super::guild_icon_url(Id::new(1), ImageHash::parse(STATIC).unwrap(), size)
+                    .unwrap_err()
+                    .kind(),
+                CdnUrlErrorType::SizeInvalid { size: invalid } if *invalid == size
+            ));
+        }
+    }
+}
@@ -1,5 +1,12 @@
 //! Calculate the permissions of a member on a guild-level or a channel-level.
 //!
+//! Channel-level permissions apply overwrites in the order of
+//! [`apply_overwrites`], additionally removing permissions that are implied
+//! by a denied [View Channel] or [Send Messages] permission or that don't
+//! apply to the type of channel. To only apply overwrites, such as to
+//! permissions that weren't calculated by the calculator, use
+//! [`apply_overwrites`] directly.
+//!
 //! # Examples
 //!
 //! ## Calculating member permissions in a channel
@@ -97,6 +104,7 @@
 //! assert_eq!(expected, calculated_permissions);
 //! ```
 //!
+//! [`apply_overwrites`]: twilight_model::channel::permission_overwrite::apply_overwrites
 //! [Add Reactions]: twilight_model::guild::Permissions::ADD_REACTIONS
 //! [Embed Links]: twilight_model::guild::Permissions::EMBED_LINKS
 //! [Send Messages]: twilight_model::guild::Permissions::SEND_MESSAGES
@@ -111,7 +119,7 @@ use self::preset::{
 };
 use twilight_model::{
     channel::{
-        permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        permission_overwrite::{apply_overwrites, PermissionOverwrite, PermissionOverwriteType},
         ChannelType,
     },
     guild::Permissions,
@@ -365,7 +373,7 @@ impl<'a> PermissionCalculator<'a> {
     /// [View Audit Log]: twilight_model::guild::Permissions::VIEW_AUDIT_LOG
     /// [View Guild Insights]: twilight_model::guild::Permissions::VIEW_GUILD_INSIGHTS
     #[must_use = "calculating permissions is only useful if they're used"]
-    pub const fn in_channel(
+    pub fn in_channel(
         self,
         channel_type: ChannelType,
         channel_overwrites: &[PermissionOverwrite],
@@ -428,15 +436,19 @@ const fn has_role(roles: &[(Id<RoleMarker>, Permissions)], role_id: Id<RoleMarke
     false
 }
 
-const fn process_permission_overwrites(
-    mut permissions: Permissions,
+/// Apply a channel's overwrites, removing the permissions implied by a
+/// [View Channel] or [Send Messages] permission denied by the overwrites of
+/// the member or their roles.
+///
+/// [Send Messages]: Permissions::SEND_MESSAGES
+/// [View Channel]: Permissions::VIEW_CHANNEL
+fn process_permission_overwrites(
+    permissions: Permissions,
     channel_overwrites: &[PermissionOverwrite],
     member_roles: &[(Id<RoleMarker>, Permissions)],
     configured_guild_id: Id<GuildMarker>,
     configured_user_id: Id<UserMarker>,
 ) -> Permissions {
-    // Hierarchy documentation:
-    // <https://discord.com/developers/docs/topics/permissions>
     let mut member_allow = Permissions::empty();
     let mut member_deny = Permissions::empty();
     let mut roles_allow = Permissions::empty();
@@ -449,27 +461,14 @@ const fn process_permission_overwrites(
         let overwrite = &channel_overwrites[idx];
 
         match overwrite.kind {
+            // The `@everyone` role's overwrite doesn't imply removals.
             PermissionOverwriteType::Role => {
-                // We need to process the @everyone role first, so apply it
-                // straight to the permissions. The other roles' permissions
-                // will be applied later.
-                if overwrite.id.get() == configured_guild_id.get() {
-                    permissions = bitops::remove(permissions, overwrite.deny);
-                    permissions = bitops::insert(permissions, overwrite.allow);
-
-                    idx += 1;
-
-                    continue;
+                if overwrite.id.get() != configured_guild_id.get()
+                    && has_role(member_roles, overwrite.id.cast())
+                {
+                    roles_allow = bitops::insert(roles_allow, overwrite.allow);
+                    roles_deny = bitops::insert(roles_deny, overwrite.deny);
                 }
-
-                if !has_role(member_roles, overwrite.id.cast()) {
-                    idx += 1;
-
-                    continue;
-                }
-
-                roles_allow = bitops::insert(roles_allow, overwrite.allow);
-                roles_deny = bitops::insert(roles_deny, overwrite.deny);
             }
             PermissionOverwriteType::Member => {
                 if overwrite.id.get() == configured_user_id.get() {
@@ -492,6 +491,19 @@ const fn process_permission_overwrites(
         return Permissions::empty();
     }
 
+    let member_role_ids = member_roles
+        .iter()
+        .map(|(role_id, _)| *role_id)
+        .collect::<Vec<_>>();
+
+    let permissions = apply_overwrites(
+        permissions,
+        channel_overwrites,
+        configured_guild_id,
+        &member_role_ids,
+        configured_user_id,
+    );
+
     // If the member or any of their roles denies the Send Messages
     // permission, then the rest of the messaging-related permissions can be
     // removed.
@@ -502,18 +514,9 @@ const fn process_permission_overwrites(
         && !member_allow.contains(Permissions::SEND_MESSAGES);
 
     if user_send_denied || role_send_denied {
-        member_allow = bitops::remove(member_allow, PERMISSIONS_MESSAGING);
-        roles_allow = bitops::remove(roles_allow, PERMISSIONS_MESSAGING);
-        permissions = bitops::remove(permissions, PERMISSIONS_MESSAGING);
+        return bitops::remove(permissions, PERMISSIONS_MESSAGING);
     }
 
-    // Member overwrites take precedence over role overwrites. Permission
-    // allows take precedence over denies.
-    permissions = bitops::remove(permissions, roles_deny);
-    permissions = bitops::insert(permissions, roles_allow);
-    permissions = bitops::remove(permissions, member_deny);
-    permissions = bitops::insert(permissions, member_allow);
-
     permissions
 }
 
@@ -5,8 +5,8 @@ use twilight_model::id::{
         ApplicationMarker, AttachmentMarker, AuditLogEntryMarker, ChannelMarker, CommandMarker,
         CommandVersionMarker, EmojiMarker, GenericMarker, GuildMarker, IntegrationMarker,
         InteractionMarker, MessageMarker, OauthSkuMarker, OauthTeamMarker, RoleMarker,
-        ScheduledEventEntityMarker, ScheduledEventMarker, StageMarker, StickerMarker,
-        StickerPackMarker, StickerPackSkuMarker, UserMarker, WebhookMarker,
+        ScheduledEventEntityMarker, ScheduledEventMarker, SoundboardSoundMarker, StageMarker,
+        StickerMarker, StickerPackMarker, StickerPackSkuMarker, UserMarker, WebhookMarker,
     },
     Id,
 };
@@ -193,6 +193,12 @@ impl Snowflake for Id<ScheduledEventEntityMarker> {
     }
 }
 
+impl Snowflake for Id<SoundboardSoundMarker> {
+    fn id(&self) -> u64 {
+        self.get()
+    }
+}
+
 impl Snowflake for Id<StageMarker> {
     fn id(&self) -> u64 {
         self.get()
@@ -251,6 +257,7 @@ mod tests {
     assert_impl_all!(Id<RoleMarker>: Snowflake);
     assert_impl_all!(Id<ScheduledEventMarker>: Snowflake);
     assert_impl_all!(Id<ScheduledEventEntityMarker>: Snowflake);
+    assert_impl_all!(Id<SoundboardSoundMarker>: Snowflake);
     assert_impl_all!(Id<StageMarker>: Snowflake);
     assert_impl_all!(Id<StickerMarker>: Snowflake);
     assert_impl_all!(Id<StickerPackMarker>: Snowflake);
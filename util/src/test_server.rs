@@ -0,0 +1,30 @@
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server,
+};
+use std::{convert::Infallible, error::Error, future::Future, net::SocketAddr, sync::Arc};
+
+/// Spawn a local server responding to every request with the response of the
+/// handler, returning the address it's listening on.
+///
+/// Clients are pointed at the server by using its address as their proxy.
+pub fn serve<F, R, E>(handler: F) -> SocketAddr
+where
+    F: Fn(Request<Body>) -> R + Send + Sync + 'static,
+    R: Future<Output = Result<Response<Body>, E>> + Send + 'static,
+    E: Into<Box<dyn Error + Send + Sync>>,
+{
+    let handler = Arc::new(handler);
+
+    let make_service = make_service_fn(move |_| {
+        let handler = Arc::clone(&handler);
+
+        async move { Ok::<_, Infallible>(service_fn(move |request| handler(request))) }
+    });
+
+    let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+    let addr = server.local_addr();
+    tokio::spawn(server);
+
+    addr
+}
@@ -0,0 +1,440 @@
+//! Pace the messages sent to channels.
+//!
+//! A [`ChannelThrottle`] sends messages to each channel at most once per
+//! interval, queuing messages sent in quick succession. This is pacing on the
+//! application's terms, on top of the HTTP client's ratelimiter which only
+//! ensures that Discord's ratelimits aren't exceeded.
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc::{
+        self,
+        error::{TryRecvError, TrySendError},
+        Receiver, Sender,
+    },
+    time::Instant,
+};
+use twilight_http::Client;
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_validate::message::content as validate_content;
+
+/// Default number of messages that can be queued per channel.
+const DEFAULT_CAPACITY: usize = 10;
+
+/// Default minimum time between two messages sent to a channel.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Queueing a message failed.
+#[derive(Debug)]
+pub struct ThrottleError {
+    kind: ThrottleErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ThrottleError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ThrottleErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ThrottleErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ThrottleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            ThrottleErrorType::ContentInvalid => f.write_str("message content is invalid"),
+            ThrottleErrorType::QueueFull { capacity } => {
+                f.write_str("queue of the channel is full with ")?;
+                Display::fmt(&capacity, f)?;
+
+                f.write_str(" messages")
+            }
+        }
+    }
+}
+
+impl Error for ThrottleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ThrottleError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ThrottleErrorType {
+    /// Content of the message is invalid, such as being too long.
+    ContentInvalid,
+    /// Queue of the channel is full, so the message was dropped.
+    ///
+    /// The message is counted by [`ChannelThrottle::dropped`].
+    QueueFull {
+        /// Number of messages that can be queued per channel.
+        capacity: usize,
+    },
+}
+
+/// Senders of messages to the workers of channels with queued messages.
+type Queues = Arc<Mutex<HashMap<Id<ChannelMarker>, Sender<String>>>>;
+
+/// Send messages to channels at a limited rate.
+///
+/// Every channel has its own queue, from which messages are sent one after
+/// another with at least the configured interval between the start of two
+/// requests. Once the queue of a channel is full, further messages are
+/// dropped and counted until queued messages have been sent.
+///
+/// Messages are sent in the background by a task spawned per channel, so
+/// messages must be queued from within a Tokio runtime. Once a channel's queue
+/// has been emptied and the interval has elapsed, its task stops and its queue
+/// is removed. The responses of the requests, including errors, are discarded.
+///
+/// # Examples
+///
+/// Send at most one message every two seconds to channel 1:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::{sync::Arc, time::Duration};
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+/// use twilight_util::throttle::ChannelThrottle;
+///
+/// let client = Arc::new(Client::new("my token".to_owned()));
+/// let throttle = ChannelThrottle::new(client).interval(Duration::from_secs(2));
+///
+/// for count in 1..=3 {
+///     throttle.send(Id::new(1), format!("message {count}"))?;
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+#[must_use = "throttles send no messages unless used"]
+pub struct ChannelThrottle {
+    capacity: usize,
+    dropped: AtomicU64,
+    http: Arc<Client>,
+    interval: Duration,
+    queues: Queues,
+}
+
+impl ChannelThrottle {
+    /// Create a new throttle sending messages with an HTTP client.
+    pub fn new(http: Arc<Client>) -> Self {
+        Self {
+            capacity: DEFAULT_CAPACITY,
+            dropped: AtomicU64::new(0),
+            http,
+            interval: DEFAULT_INTERVAL,
+            queues: Queues::default(),
+        }
+    }
+
+    /// Set the number of messages that can be queued per channel.
+    ///
+    /// Messages are queued while the previous message of the channel is being
+    /// sent or the interval hasn't elapsed. A capacity of 0 is treated as 1.
+    ///
+    /// Defaults to 10.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+
+        self
+    }
+
+    /// Set the minimum time between two messages sent to a channel.
+    ///
+    /// Defaults to 1 second.
+    pub const fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+
+        self
+    }
+
+    /// Number of messages dropped because the queue of their channel was
+    /// full.
+    #[must_use = "retrieving the count has no effect if left unused"]
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Queue a message with content to be sent to a channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ContentInvalid`] if the content is invalid.
+    ///
+    /// Returns an error of type [`QueueFull`] if the queue of the channel is
+    /// full, in which case the message is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a Tokio runtime.
+    ///
+    /// [`ContentInvalid`]: ThrottleErrorType::ContentInvalid
+    /// [`QueueFull`]: ThrottleErrorType::QueueFull
+    pub fn send(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        content: String,
+    ) -> Result<(), ThrottleError> {
+        if let Err(source) = validate_content(&content) {
+            return Err(ThrottleError {
+                kind: ThrottleErrorType::ContentInvalid,
+                source: Some(Box::new(source)),
+            });
+        }
+
+        let mut queues = self.queues.lock().unwrap_or_else(PoisonError::into_inner);
+        let sender = queues
+            .entry(channel_id)
+            .or_insert_with(|| self.spawn(channel_id));
+
+        let content = match sender.try_send(content) {
+            Ok(()) => return Ok(()),
+            Err(TrySendError::Full(_)) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+
+                return Err(ThrottleError {
+                    kind: ThrottleErrorType::QueueFull {
+                        capacity: self.capacity,
+                    },
+                    source: None,
+                });
+            }
+            Err(TrySendError::Closed(content)) => content,
+        };
+
+        // The worker stopped, such as when the runtime it was spawned on shut
+        // down, so start a new one.
+        *sender = self.spawn(channel_id);
+
+        sender.try_send(content).map_err(|_| ThrottleError {
+            kind: ThrottleErrorType::QueueFull {
+                capacity: self.capacity,
+            },
+            source: None,
+        })
+    }
+
+    /// Spawn the worker of a channel, returning the sender of its queue.
+    fn spawn(&self, channel_id: Id<ChannelMarker>) -> Sender<String> {
+        let (sender, receiver) = mpsc::channel(self.capacity);
+
+        tokio::spawn(run(
+            Arc::clone(&self.http),
+            channel_id,
+            self.interval,
+            Arc::clone(&self.queues),
+            receiver,
+        ));
+
+        sender
+    }
+}
+
+/// Send the queued messages of a channel until its queue is empty, then
+/// remove the queue.
+async fn run(
+    http: Arc<Client>,
+    channel_id: Id<ChannelMarker>,
+    interval: Duration,
+    queues: Queues,
+    mut receiver: Receiver<String>,
+) {
+    loop {
+        let content = match receiver.try_recv() {
+            Ok(content) => content,
+            Err(TryRecvError::Disconnected) => return,
+            Err(TryRecvError::Empty) => {
+                // Checked again while holding the lock so a message can't be
+                // queued after the queue was removed.
+                let mut queues = queues.lock().unwrap_or_else(PoisonError::into_inner);
+
+                if let Ok(content) = receiver.try_recv() {
+                    content
+                } else {
+                    queues.remove(&channel_id);
+
+                    return;
+                }
+            }
+        };
+
+        let started = Instant::now();
+
+        // The content was validated when it was queued.
+        if let Ok(request) = http.create_message(channel_id).content(&content) {
+            let _response = request.exec().await;
+        }
+
+        tokio::time::sleep_until(started + interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChannelThrottle, ThrottleError, ThrottleErrorType};
+    use crate::test_server;
+    use hyper::{Body, Request as HyperRequest, Response as HyperResponse, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::Infallible,
+        error::Error,
+        fmt::Debug,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+    use tokio::time::Instant;
+    use twilight_http::Client;
+    use twilight_model::id::Id;
+
+    assert_impl_all!(ChannelThrottle: Debug, Send, Sync);
+    assert_impl_all!(ThrottleError: Debug, Error, Send, Sync);
+
+    /// Requests received by the server, as their path and time of receipt.
+    type Requests = Arc<Mutex<Vec<(String, Instant)>>>;
+
+    /// Spawn a server responding to every request with no content, recording
+    /// the requests.
+    fn serve() -> (SocketAddr, Requests) {
+        let requests = Requests::default();
+        let server_requests = Arc::clone(&requests);
+
+        let addr = test_server::serve(move |request: HyperRequest<Body>| {
+            server_requests
+                .lock()
+                .unwrap()
+                .push((request.uri().path().to_owned(), Instant::now()));
+
+            let mut response = HyperResponse::new(Body::empty());
+            *response.status_mut() = StatusCode::NO_CONTENT;
+
+            async move { Ok::<_, Infallible>(response) }
+        });
+
+        (addr, requests)
+    }
+
+    /// Yield until a condition holds, without advancing the paused time.
+    async fn wait_until(condition: impl Fn() -> bool) {
+        for _ in 0..10_000 {
+            if condition() {
+                return;
+            }
+
+            tokio::task::yield_now().await;
+        }
+
+        panic!("condition didn't hold in time");
+    }
+
+    #[tokio::test]
+    async fn test_burst() -> Result<(), Box<dyn Error + Send + Sync>> {
+        const INTERVAL: Duration = Duration::from_millis(200);
+        // Timers have a granularity of a millisecond, so time is advanced a
+        // millisecond past the interval.
+        const STEP: Duration = Duration::from_millis(201);
+
+        tokio::time::pause();
+
+        let (addr, requests) = serve();
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("Bot token".to_owned())
+            .build();
+        let throttle = ChannelThrottle::new(Arc::new(client))
+            .capacity(3)
+            .interval(INTERVAL);
+
+        // Queueing doesn't yield, so the workers only start sending once the
+        // whole burst has been queued.
+        let mut full = 0;
+
+        for count in 0..5 {
+            if let Err(error) = throttle.send(Id::new(1), count.to_string()) {
+                assert!(matches!(
+                    error.kind(),
+                    ThrottleErrorType::QueueFull { capacity: 3 }
+                ));
+                full += 1;
+            }
+        }
+
+        throttle.send(Id::new(2), "other channel".to_owned())?;
+
+        assert_eq!(2, full);
+        assert_eq!(2, throttle.dropped());
+
+        // Time only advances when the test advances it, so requests are
+        // received at the instant their worker sends them.
+        let received = |channel_id: u64| {
+            let path = format!("/api/v10/channels/{channel_id}/messages");
+
+            requests
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(request_path, _)| *request_path == path)
+                .map(|(_, received)| *received)
+                .collect::<Vec<_>>()
+        };
+
+        wait_until(|| received(1).len() == 1 && received(2).len() == 1).await;
+
+        for count in 2..=3 {
+            tokio::time::advance(STEP).await;
+            wait_until(|| received(1).len() == count).await;
+        }
+
+        // Queues are removed once they have been emptied.
+        tokio::time::advance(STEP).await;
+        wait_until(|| throttle.queues.lock().unwrap().is_empty()).await;
+
+        let channel_1 = received(1);
+        assert_eq!(3, channel_1.len());
+
+        for pair in channel_1.windows(2) {
+            assert!(pair[1] - pair[0] >= INTERVAL);
+        }
+
+        // Channels are throttled independently.
+        assert_eq!(1, received(2).len());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_content_invalid() {
+        let throttle = ChannelThrottle::new(Arc::new(Client::new("token".to_owned())));
+        let error = throttle.send(Id::new(1), "a".repeat(2001)).unwrap_err();
+
+        assert!(matches!(error.kind(), ThrottleErrorType::ContentInvalid));
+        assert_eq!(0, throttle.dropped());
+        assert!(throttle.queues.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+//! Extract information from Discord tokens.
+//!
+//! Bot tokens consist of three segments separated by dots: the base64 encoded
+//! ID of the bot's user, a timestamp, and a signature. Only the signature is
+//! secret, so the user a token belongs to can be read without contacting
+//! Discord.
+
+use std::str;
+use twilight_model::id::{marker::UserMarker, Id};
+
+/// Prefix of bot tokens in authorization headers.
+const BOT_PREFIX: &str = "Bot ";
+
+/// Extract the ID of the user a bot token belongs to.
+///
+/// The token may be prefixed with `Bot `. This does not validate the token's
+/// signature, so a valid ID does not mean the token is valid; it only allows
+/// checking which account a token claims to belong to.
+///
+/// Returns [`None`] if the token is malformed.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::id::Id;
+/// use twilight_util::token;
+///
+/// let token = "ODAzNTExMTAyMjQ2Nzg5MTI.YbSKqw.signature";
+///
+/// assert_eq!(Some(Id::new(80_351_110_224_678_912)), token::user_id(token));
+/// assert_eq!(None, token::user_id("not a token"));
+/// ```
+#[must_use = "extracting the user ID has no effect if the result is unused"]
+pub fn user_id(token: &str) -> Option<Id<UserMarker>> {
+    let token = token.strip_prefix(BOT_PREFIX).unwrap_or(token);
+    let mut segments = token.split('.');
+
+    let id = match (segments.next(), segments.next(), segments.next()) {
+        (Some(id), Some(_), Some(_)) if segments.next().is_none() => id,
+        _ => return None,
+    };
+
+    let decoded = decode_base64(id)?;
+    let id = str::from_utf8(&decoded).ok()?;
+
+    if id.is_empty() || !id.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    Id::new_checked(id.parse().ok()?)
+}
+
+/// Decode base64 with either the standard or URL-safe alphabet, with optional
+/// padding.
+fn decode_base64(input: &str) -> Option<Vec<u8>> {
+    let input = input.trim_end_matches('=');
+    let mut output = Vec::with_capacity(input.len() * 3 / 4);
+
+    // Decoded bits not yet written to the output.
+    let mut buffer = 0_u32;
+    let mut bits = 0;
+
+    for byte in input.bytes() {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None,
+        };
+
+        buffer = buffer << 6 | u32::from(value);
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            output.push(u8::try_from(buffer >> bits).ok()?);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_base64, user_id};
+    use twilight_model::id::Id;
+
+    #[test]
+    fn test_decode_base64() {
+        assert_eq!(Some(b"abc".to_vec()), decode_base64("YWJj"));
+        assert_eq!(Some(b"0".to_vec()), decode_base64("MA=="));
+        assert_eq!(Some(b"0".to_vec()), decode_base64("MA"));
+        assert_eq!(None, decode_base64("YW*j"));
+    }
+
+    #[test]
+    fn test_user_id() {
+        const ID: u64 = 80_351_110_224_678_912;
+
+        assert_eq!(
+            Some(Id::new(ID)),
+            user_id("ODAzNTExMTAyMjQ2Nzg5MTI.YbSKqw.signature")
+        );
+        assert_eq!(
+            Some(Id::new(ID)),
+            user_id("ODAzNTExMTAyMjQ2Nzg5MTI=.YbSKqw.signature")
+        );
+        assert_eq!(
+            Some(Id::new(ID)),
+            user_id("Bot ODAzNTExMTAyMjQ2Nzg5MTI.YbSKqw.signature")
+        );
+    }
+
+    #[test]
+    fn test_user_id_malformed() {
+        // Missing segments.
+        assert!(user_id("").is_none());
+        assert!(user_id("ODAzNTExMTAyMjQ2Nzg5MTI").is_none());
+        assert!(user_id("ODAzNTExMTAyMjQ2Nzg5MTI.YbSKqw").is_none());
+        // Too many segments.
+        assert!(user_id("ODAzNTExMTAyMjQ2Nzg5MTI.YbSKqw.signature.extra").is_none());
+        // Invalid base64.
+        assert!(user_id("ODAz*TExMTAyMjQ2Nzg5MTI.YbSKqw.signature").is_none());
+        // Not an ID.
+        assert!(user_id("YWJj.YbSKqw.signature").is_none());
+        assert!(user_id(".YbSKqw.signature").is_none());
+        assert!(user_id("MA.YbSKqw.signature").is_none());
+    }
+}
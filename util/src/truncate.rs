@@ -0,0 +1,103 @@
+//! Truncate text to a maximum number of characters.
+//!
+//! Discord limits text such as message content by the number of characters,
+//! not bytes, so truncating a string by slicing it at a byte index may either
+//! leave it too long or split a multibyte character.
+
+use std::borrow::Cow;
+
+/// Ellipsis appended to truncated text.
+pub const ELLIPSIS: char = '\u{2026}';
+
+/// Truncate text to at most `max` characters, replacing the end of the text
+/// with an [`ELLIPSIS`] if it was shortened.
+///
+/// The ellipsis counts towards `max`, so the returned text is never longer
+/// than `max` characters. Text is only ever cut between characters, so a
+/// multibyte character is never split. Note that characters in this context
+/// are Unicode scalar values; a grapheme cluster made up of several
+/// characters, such as a family emoji, may still be cut apart.
+///
+/// Returns the text unmodified if it already fits.
+///
+/// # Examples
+///
+/// Truncate message content to Discord's limit of 2000 characters:
+///
+/// ```
+/// use twilight_util::truncate;
+///
+/// let content = "\u{1f986}".repeat(2500);
+/// let truncated = truncate::truncate(&content, 2000);
+///
+/// assert_eq!(2000, truncated.chars().count());
+/// assert!(truncated.ends_with(truncate::ELLIPSIS));
+/// ```
+#[must_use = "truncating text has no effect if the result is unused"]
+pub fn truncate(text: &str, max: usize) -> Cow<'_, str> {
+    if max == 0 {
+        return Cow::Borrowed("");
+    }
+
+    // Byte index of the last character that fits alongside the ellipsis,
+    // provided that the text doesn't fit as is.
+    let mut chars = text.char_indices().skip(max - 1);
+
+    let end = match (chars.next(), chars.next()) {
+        (Some((end, _)), Some(_)) => end,
+        _ => return Cow::Borrowed(text),
+    };
+
+    let mut truncated = String::with_capacity(end + ELLIPSIS.len_utf8());
+    truncated.push_str(&text[..end]);
+    truncated.push(ELLIPSIS);
+
+    Cow::Owned(truncated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{truncate, ELLIPSIS};
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_fits() {
+        assert!(matches!(truncate("twilight", 8), Cow::Borrowed("twilight")));
+        assert!(matches!(
+            truncate("twilight", 100),
+            Cow::Borrowed("twilight")
+        ));
+        assert!(matches!(truncate("", 0), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!("twil\u{2026}", truncate("twilight", 5));
+        assert_eq!("twilig\u{2026}", truncate("twilight", 7));
+        assert_eq!("\u{2026}", truncate("twilight", 1));
+        assert_eq!("", truncate("twilight", 0));
+    }
+
+    #[test]
+    fn test_multibyte() {
+        // Each character is 4 bytes long.
+        let ducks = "\u{1f986}\u{1f986}\u{1f986}";
+        assert!(matches!(truncate(ducks, 3), Cow::Borrowed(_)));
+        assert_eq!("\u{1f986}\u{2026}", truncate(ducks, 2));
+
+        // Multibyte character right at the boundary.
+        assert_eq!("ab\u{2026}", truncate("ab\u{e9}\u{e9}", 3));
+        assert_eq!("ab\u{e9}\u{e9}", truncate("ab\u{e9}\u{e9}", 4));
+        assert_eq!("a\u{e9}\u{2026}", truncate("a\u{e9}\u{1f986}z", 3));
+    }
+
+    #[test]
+    fn test_message_content() {
+        let content = "\u{1f986}".repeat(2001);
+        let truncated = truncate(&content, 2000);
+
+        assert_eq!(2000, truncated.chars().count());
+        assert!(truncated.ends_with(ELLIPSIS));
+        assert_eq!(content[..1999 * 4], truncated[..1999 * 4]);
+    }
+}
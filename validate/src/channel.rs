@@ -4,7 +4,13 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::ChannelType;
+use twilight_model::{channel::ChannelType, http::forum_tag::ForumTag};
+
+/// Maximum number of tags of a forum channel.
+pub const CHANNEL_FORUM_TAG_COUNT_MAX: usize = 20;
+
+/// Maximum length of a forum tag's name.
+pub const CHANNEL_FORUM_TAG_NAME_LENGTH_MAX: usize = 20;
 
 /// Maximum length of a channel's name.
 pub const CHANNEL_NAME_LENGTH_MAX: usize = 100;
@@ -54,6 +60,21 @@ impl ChannelValidationError {
 impl Display for ChannelValidationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ChannelValidationErrorType::ForumTagCount { count } => {
+                f.write_str("a forum channel has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" tags, but only ")?;
+                Display::fmt(&CHANNEL_FORUM_TAG_COUNT_MAX, f)?;
+
+                f.write_str(" are allowed")
+            }
+            ChannelValidationErrorType::ForumTagName { chars } => {
+                f.write_str("a forum tag's name is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&CHANNEL_FORUM_TAG_NAME_LENGTH_MAX, f)
+            }
             ChannelValidationErrorType::NameInvalid => {
                 f.write_str("the length of the name is invalid")
             }
@@ -76,6 +97,16 @@ impl Error for ChannelValidationError {}
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ChannelValidationErrorType {
+    /// Too many tags were provided for a forum channel.
+    ForumTagCount {
+        /// Number of tags provided.
+        count: usize,
+    },
+    /// The length of a forum tag's name is more than 20 characters.
+    ForumTagName {
+        /// Number of characters in the provided name.
+        chars: usize,
+    },
     /// The length of the name is either fewer than 1 UTF-16 characters or
     /// more than 100 UTF-16 characters.
     NameInvalid,
@@ -93,6 +124,41 @@ pub enum ChannelValidationErrorType {
     },
 }
 
+/// Ensure the tags of a forum channel are correct.
+///
+/// There must be at most [`CHANNEL_FORUM_TAG_COUNT_MAX`] tags, each with a
+/// name of at most [`CHANNEL_FORUM_TAG_NAME_LENGTH_MAX`] characters. This is
+/// based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`ForumTagCount`] if there are too many tags.
+///
+/// Returns an error of type [`ForumTagName`] if a tag's name is too long.
+///
+/// [`ForumTagCount`]: ChannelValidationErrorType::ForumTagCount
+/// [`ForumTagName`]: ChannelValidationErrorType::ForumTagName
+/// [this documentation entry]: https://discord.com/developers/docs/resources/channel#forum-tag-object
+pub fn forum_tags(tags: &[ForumTag]) -> Result<(), ChannelValidationError> {
+    if tags.len() > CHANNEL_FORUM_TAG_COUNT_MAX {
+        return Err(ChannelValidationError {
+            kind: ChannelValidationErrorType::ForumTagCount { count: tags.len() },
+        });
+    }
+
+    for tag in tags {
+        let chars = tag.name.chars().count();
+
+        if chars > CHANNEL_FORUM_TAG_NAME_LENGTH_MAX {
+            return Err(ChannelValidationError {
+                kind: ChannelValidationErrorType::ForumTagName { chars },
+            });
+        }
+    }
+
+    Ok(())
+}
+
 /// Ensure a channel is a thread.
 ///
 /// # Errors
@@ -186,6 +252,32 @@ pub fn topic(value: impl AsRef<str>) -> Result<(), ChannelValidationError> {
 mod tests {
     use super::*;
 
+    fn tag(name: impl Into<String>) -> ForumTag {
+        ForumTag {
+            emoji_id: None,
+            emoji_name: None,
+            id: None,
+            moderated: false,
+            name: name.into(),
+        }
+    }
+
+    #[test]
+    fn test_forum_tags() {
+        assert!(forum_tags(&[]).is_ok());
+        assert!(forum_tags(&[tag("a".repeat(20))]).is_ok());
+        assert!(forum_tags(&vec![tag("a"); 20]).is_ok());
+
+        assert!(matches!(
+            forum_tags(&[tag("a".repeat(21))]).unwrap_err().kind(),
+            ChannelValidationErrorType::ForumTagName { chars: 21 }
+        ));
+        assert!(matches!(
+            forum_tags(&vec![tag("a"); 21]).unwrap_err().kind(),
+            ChannelValidationErrorType::ForumTagCount { count: 21 }
+        ));
+    }
+
     #[test]
     fn test_is_thread() {
         assert!(is_thread(ChannelType::GuildNewsThread).is_ok());
@@ -1,10 +1,14 @@
 //! Constants, error types, and functions for validating [`Command`]s.
 
 use std::{
+    collections::HashMap,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::application::command::{Command, CommandOption, CommandType};
+use twilight_model::{
+    application::command::{Command, CommandOption, CommandOptionChoice, CommandType},
+    util::Locale,
+};
 
 /// Maximum number of choices an option can have.
 pub const CHOICES_LIMIT: usize = 25;
@@ -112,6 +116,12 @@ impl Display for CommandValidationError {
 
                 f.write_str(" characters")
             }
+            CommandValidationErrorType::LocaleInvalid { locale } => {
+                f.write_str("`")?;
+                Display::fmt(locale, f)?;
+
+                f.write_str("` is not a locale accepted by Discord")
+            }
             CommandValidationErrorType::NameLengthInvalid => {
                 f.write_str("command name must be between ")?;
                 Display::fmt(&NAME_LENGTH_MIN, f)?;
@@ -180,6 +190,13 @@ pub enum CommandValidationErrorType {
     CountInvalid,
     /// Command description is invalid.
     DescriptionInvalid,
+    /// Locale of a localization dictionary is not accepted by Discord.
+    ///
+    /// The accepted locales are the known variants of [`Locale`].
+    LocaleInvalid {
+        /// Invalid locale.
+        locale: String,
+    },
     /// Command name length is invalid.
     NameLengthInvalid,
     /// Command name contain an invalid character.
@@ -214,10 +231,16 @@ pub enum CommandValidationErrorType {
 /// Returns an error of type [`DescriptionInvalid`] if the description is
 /// invalid.
 ///
+/// Returns an error of type [`LocaleInvalid`] if a localization dictionary
+/// contains a locale that is not accepted by Discord.
+///
 /// Returns an error of type [`NameLengthInvalid`] or [`NameCharacterInvalid`]
 /// if the name is invalid.
 ///
+/// Localized descriptions and names are validated the same way.
+///
 /// [`DescriptionInvalid`]: CommandValidationErrorType::DescriptionInvalid
+/// [`LocaleInvalid`]: CommandValidationErrorType::LocaleInvalid
 /// [`NameLengthInvalid`]: CommandValidationErrorType::NameLengthInvalid
 /// [`NameCharacterInvalid`]: CommandValidationErrorType::NameCharacterInvalid
 pub fn command(value: &Command) -> Result<(), CommandValidationError> {
@@ -233,13 +256,16 @@ pub fn command(value: &Command) -> Result<(), CommandValidationError> {
     self::description(description)?;
 
     if let Some(description_localizations) = description_localizations {
-        for description in description_localizations.values() {
+        for (locale, description) in description_localizations {
+            self::locale(locale)?;
             self::description(description)?;
         }
     }
 
     if let Some(name_localizations) = name_localizations {
-        for name in name_localizations.values() {
+        for (locale, name) in name_localizations {
+            self::locale(locale)?;
+
             match kind {
                 CommandType::ChatInput => self::chat_input_name(name)?,
                 CommandType::User | CommandType::Message => self::name(name)?,
@@ -277,6 +303,25 @@ pub fn description(value: impl AsRef<str>) -> Result<(), CommandValidationError>
     }
 }
 
+/// Validate the locale of a localization dictionary entry.
+///
+/// The locale must be accepted by Discord, which are the known variants of
+/// [`Locale`].
+///
+/// # Errors
+///
+/// Returns an error of type [`LocaleInvalid`] if the locale is not accepted.
+///
+/// [`LocaleInvalid`]: CommandValidationErrorType::LocaleInvalid
+pub fn locale(value: impl AsRef<str>) -> Result<(), CommandValidationError> {
+    match Locale::from(value.as_ref()) {
+        Locale::Unknown(locale) => Err(CommandValidationError {
+            kind: CommandValidationErrorType::LocaleInvalid { locale },
+        }),
+        _ => Ok(()),
+    }
+}
+
 /// Validate the name of a [`User`] or [`Message`] command.
 ///
 /// The length of the name must be more than [`NAME_LENGTH_MIN`] and less than
@@ -400,40 +445,118 @@ fn name_characters(value: impl AsRef<str>) -> Result<(), CommandValidationError>
 ///
 /// # Errors
 ///
+/// Returns an error of type [`LocaleInvalid`] if a localization dictionary of
+/// the option or of one of its choices contains a locale that is not accepted
+/// by Discord.
+///
 /// Returns an error of type [`OptionDescriptionInvalid`] if the description is
 /// invalid.
 ///
 /// Returns an error of type [`OptionNameLengthInvalid`] or [`OptionNameCharacterInvalid`]
 /// if the name is invalid.
 ///
+/// Localized descriptions and names are validated the same way.
+///
+/// [`LocaleInvalid`]: CommandValidationErrorType::LocaleInvalid
 /// [`OptionDescriptionInvalid`]: CommandValidationErrorType::OptionDescriptionInvalid
 /// [`OptionNameLengthInvalid`]: CommandValidationErrorType::OptionNameLengthInvalid
 /// [`OptionNameCharacterInvalid`]: CommandValidationErrorType::OptionNameCharacterInvalid
 pub fn option(option: &CommandOption) -> Result<(), CommandValidationError> {
-    let (description, name) = match option {
-        CommandOption::SubCommand(_) | CommandOption::SubCommandGroup(_) => return Ok(()),
-        CommandOption::String(data) => (&data.description, &data.name),
-        CommandOption::Integer(data) | CommandOption::Number(data) => {
-            (&data.description, &data.name)
+    let (description, description_localizations, name, name_localizations, choices) = match option {
+        CommandOption::SubCommand(data) | CommandOption::SubCommandGroup(data) => {
+            self::localizations(data.description_localizations.as_ref())?;
+
+            return self::localizations(data.name_localizations.as_ref());
         }
-        CommandOption::Channel(data) => (&data.description, &data.name),
+        CommandOption::String(data) => (
+            &data.description,
+            &data.description_localizations,
+            &data.name,
+            &data.name_localizations,
+            data.choices.as_slice(),
+        ),
+        CommandOption::Integer(data) | CommandOption::Number(data) => (
+            &data.description,
+            &data.description_localizations,
+            &data.name,
+            &data.name_localizations,
+            data.choices.as_slice(),
+        ),
+        CommandOption::Channel(data) => (
+            &data.description,
+            &data.description_localizations,
+            &data.name,
+            &data.name_localizations,
+            [].as_slice(),
+        ),
         CommandOption::Boolean(data)
         | CommandOption::User(data)
         | CommandOption::Role(data)
         | CommandOption::Mentionable(data)
-        | CommandOption::Attachment(data) => (&data.description, &data.name),
+        | CommandOption::Attachment(data) => (
+            &data.description,
+            &data.description_localizations,
+            &data.name,
+            &data.name_localizations,
+            [].as_slice(),
+        ),
     };
 
+    self::option_description(description)?;
+
+    if let Some(description_localizations) = description_localizations {
+        for (locale, description) in description_localizations {
+            self::locale(locale)?;
+            self::option_description(description)?;
+        }
+    }
+
+    if let Some(name_localizations) = name_localizations {
+        for (locale, name) in name_localizations {
+            self::locale(locale)?;
+            self::option_name(name)?;
+        }
+    }
+
+    for choice in choices {
+        let name_localizations = match choice {
+            CommandOptionChoice::String {
+                name_localizations, ..
+            }
+            | CommandOptionChoice::Int {
+                name_localizations, ..
+            }
+            | CommandOptionChoice::Number {
+                name_localizations, ..
+            } => name_localizations,
+        };
+
+        self::localizations(name_localizations.as_ref())?;
+    }
+
+    self::option_name(name)
+}
+
+/// Validate the locales of a localization dictionary.
+fn localizations(
+    localizations: Option<&HashMap<String, String>>,
+) -> Result<(), CommandValidationError> {
+    localizations
+        .into_iter()
+        .flat_map(HashMap::keys)
+        .try_for_each(self::locale)
+}
+
+/// Validate the description of a [`CommandOption`].
+fn option_description(description: &str) -> Result<(), CommandValidationError> {
     let description_len = description.chars().count();
-    if description_len > OPTION_DESCRIPTION_LENGTH_MAX
-        && description_len < OPTION_DESCRIPTION_LENGTH_MIN
-    {
+    if !(OPTION_DESCRIPTION_LENGTH_MIN..=OPTION_DESCRIPTION_LENGTH_MAX).contains(&description_len) {
         return Err(CommandValidationError {
             kind: CommandValidationErrorType::OptionDescriptionInvalid,
         });
     }
 
-    self::option_name(name)
+    Ok(())
 }
 
 /// Validate a list of command options for count, order, and internal validity.
@@ -470,11 +593,15 @@ pub fn options(options: &[CommandOption]) -> Result<(), CommandValidationError>
         })?;
 
     // Validate that each option is correct.
-    options.iter().try_for_each(|option| match option {
-        CommandOption::SubCommandGroup(data) | CommandOption::SubCommand(data) => {
-            self::options(data.options.as_ref())
+    options.iter().try_for_each(|option| {
+        self::option(option)?;
+
+        match option {
+            CommandOption::SubCommandGroup(data) | CommandOption::SubCommand(data) => {
+                self::options(data.options.as_ref())
+            }
+            _ => Ok(()),
         }
-        other => self::option(other),
     })?;
 
     Ok(())
@@ -508,7 +635,13 @@ mod tests {
 
     use super::*;
     use std::collections::HashMap;
-    use twilight_model::{application::command::CommandType, id::Id};
+    use twilight_model::{
+        application::command::{
+            BaseCommandOptionData, ChoiceCommandOptionData, CommandType, NumberCommandOptionData,
+            OptionsCommandOptionData,
+        },
+        id::Id,
+    };
 
     // This tests [`description`] and [`name`] by proxy.
     #[test]
@@ -542,6 +675,155 @@ mod tests {
         assert!(command(&invalid_command).is_err());
     }
 
+    #[test]
+    fn test_command_localizations() {
+        let valid_command = Command {
+            application_id: None,
+            default_member_permissions: None,
+            dm_permission: None,
+            description: "description".to_owned(),
+            description_localizations: Some(HashMap::from([(
+                "de".to_owned(),
+                "Beschreibung".to_owned(),
+            )])),
+            guild_id: None,
+            id: None,
+            kind: CommandType::ChatInput,
+            name: "name".to_owned(),
+            name_localizations: Some(HashMap::from([("fr".to_owned(), "nom".to_owned())])),
+            options: Vec::new(),
+            version: Id::new(1),
+        };
+
+        assert!(command(&valid_command).is_ok());
+
+        let invalid_locale = Command {
+            name_localizations: Some(HashMap::from([("fr-FR".to_owned(), "nom".to_owned())])),
+            ..valid_command.clone()
+        };
+
+        assert!(matches!(
+            command(&invalid_locale).unwrap_err().kind(),
+            CommandValidationErrorType::LocaleInvalid { locale } if locale == "fr-FR"
+        ));
+
+        let invalid_length = Command {
+            description_localizations: Some(HashMap::from([("de".to_owned(), "a".repeat(101))])),
+            ..valid_command
+        };
+
+        assert!(matches!(
+            command(&invalid_length).unwrap_err().kind(),
+            CommandValidationErrorType::DescriptionInvalid
+        ));
+    }
+
+    #[test]
+    fn test_option_localizations() {
+        let choice = CommandOptionChoice::String {
+            name: "choice".to_owned(),
+            name_localizations: Some(HashMap::from([("es-419".to_owned(), "opción".to_owned())])),
+            value: "choice".to_owned(),
+        };
+        let valid_option = CommandOption::String(ChoiceCommandOptionData {
+            choices: Vec::from([choice]),
+            description: "description".to_owned(),
+            description_localizations: Some(HashMap::from([(
+                "de".to_owned(),
+                "Beschreibung".to_owned(),
+            )])),
+            name: "name".to_owned(),
+            name_localizations: Some(HashMap::from([("fr".to_owned(), "nom".to_owned())])),
+            ..ChoiceCommandOptionData::default()
+        });
+
+        assert!(option(&valid_option).is_ok());
+
+        let invalid_name_locale = CommandOption::User(BaseCommandOptionData {
+            description: "description".to_owned(),
+            name: "name".to_owned(),
+            name_localizations: Some(HashMap::from([("fr-FR".to_owned(), "nom".to_owned())])),
+            ..BaseCommandOptionData::default()
+        });
+
+        assert!(matches!(
+            option(&invalid_name_locale).unwrap_err().kind(),
+            CommandValidationErrorType::LocaleInvalid { locale } if locale == "fr-FR"
+        ));
+
+        let invalid_name = CommandOption::User(BaseCommandOptionData {
+            description: "description".to_owned(),
+            name: "name".to_owned(),
+            name_localizations: Some(HashMap::from([("fr".to_owned(), "Nom".to_owned())])),
+            ..BaseCommandOptionData::default()
+        });
+
+        assert!(matches!(
+            option(&invalid_name).unwrap_err().kind(),
+            CommandValidationErrorType::NameCharacterInvalid { character: 'N' }
+        ));
+
+        let invalid_description = CommandOption::User(BaseCommandOptionData {
+            description: "description".to_owned(),
+            description_localizations: Some(HashMap::from([(
+                "de".to_owned(),
+                "b".repeat(OPTION_DESCRIPTION_LENGTH_MAX + 1),
+            )])),
+            name: "name".to_owned(),
+            ..BaseCommandOptionData::default()
+        });
+
+        assert!(matches!(
+            option(&invalid_description).unwrap_err().kind(),
+            CommandValidationErrorType::OptionDescriptionInvalid
+        ));
+
+        let invalid_choice_locale = CommandOption::Integer(NumberCommandOptionData {
+            choices: Vec::from([CommandOptionChoice::Int {
+                name: "one".to_owned(),
+                name_localizations: Some(HashMap::from([("xx".to_owned(), "un".to_owned())])),
+                value: 1,
+            }]),
+            description: "description".to_owned(),
+            name: "number".to_owned(),
+            ..NumberCommandOptionData::default()
+        });
+
+        assert!(matches!(
+            option(&invalid_choice_locale).unwrap_err().kind(),
+            CommandValidationErrorType::LocaleInvalid { locale } if locale == "xx"
+        ));
+
+        // Localizations of subcommands are validated along with their
+        // options.
+        let invalid_subcommand = CommandOption::SubCommand(OptionsCommandOptionData {
+            description: "description".to_owned(),
+            description_localizations: Some(HashMap::from([(
+                "de-DE".to_owned(),
+                "Beschreibung".to_owned(),
+            )])),
+            name: "subcommand".to_owned(),
+            options: Vec::from([valid_option]),
+            ..OptionsCommandOptionData::default()
+        });
+
+        assert!(matches!(
+            options(&[invalid_subcommand]).unwrap_err().kind(),
+            CommandValidationErrorType::LocaleInvalid { locale } if locale == "de-DE"
+        ));
+    }
+
+    #[test]
+    fn test_locale() {
+        assert!(locale("en-US").is_ok());
+        assert!(locale("zh-TW").is_ok());
+        assert!(locale("es-419").is_ok());
+
+        assert!(locale("").is_err());
+        assert!(locale("en").is_err());
+        assert!(locale("en-us").is_err());
+    }
+
     #[test]
     fn test_name_characters() {
         assert!(name_characters("hello-command").is_ok()); // Latin language
@@ -49,6 +49,32 @@ pub const COMPONENT_CUSTOM_ID_LENGTH: usize = 100;
 /// [1]: https://discord.com/developers/docs/interactions/message-components#component-object-component-structure
 pub const COMPONENT_BUTTON_LABEL_LENGTH: usize = 80;
 
+/// Maximum number of [`ActionRow`]s in a modal.
+///
+/// This is defined in Discord's documentation, per
+/// [Discord Docs/Interaction Response][1].
+///
+/// [`ActionRow`]: twilight_model::application::component::ActionRow
+/// [1]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal
+pub const MODAL_COMPONENT_COUNT_MAX: usize = 5;
+
+/// Minimum number of [`ActionRow`]s in a modal.
+///
+/// This is defined in Discord's documentation, per
+/// [Discord Docs/Interaction Response][1].
+///
+/// [`ActionRow`]: twilight_model::application::component::ActionRow
+/// [1]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal
+pub const MODAL_COMPONENT_COUNT_MIN: usize = 1;
+
+/// Maximum length of a modal's title in codepoints.
+///
+/// This is defined in Discord's documentation, per
+/// [Discord Docs/Interaction Response][1].
+///
+/// [1]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal
+pub const MODAL_TITLE_LENGTH: usize = 45;
+
 /// Maximum number of [`SelectMenuOption`]s that can be chosen in a
 /// [`SelectMenu`].
 ///
@@ -262,6 +288,34 @@ impl Display for ComponentValidationError {
 
                 f.write_str("' component was provided, but can not be a root component")
             }
+            ComponentValidationErrorType::ModalActionRowComponentCount { count } => {
+                f.write_str("a modal's action row has ")?;
+                Display::fmt(count, f)?;
+
+                f.write_str(" components, but must have exactly one text input")
+            }
+            ComponentValidationErrorType::ModalChildComponent { kind } => {
+                f.write_str("a '")?;
+                Display::fmt(kind, f)?;
+
+                f.write_str("' component was provided in a modal, but only text inputs are allowed")
+            }
+            ComponentValidationErrorType::ModalComponentCount { count } => {
+                f.write_str("a modal has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" action rows, but it must have at least ")?;
+                Display::fmt(&MODAL_COMPONENT_COUNT_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&MODAL_COMPONENT_COUNT_MAX, f)
+            }
+            ComponentValidationErrorType::ModalTitleLength { chars } => {
+                f.write_str("a modal's title is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&MODAL_TITLE_LENGTH, f)
+            }
             ComponentValidationErrorType::SelectMaximumValuesCount { count } => {
                 f.write_str("maximum number of values that can be chosen is ")?;
                 Display::fmt(count, f)?;
@@ -407,6 +461,28 @@ pub enum ComponentValidationErrorType {
         /// Type of provided component.
         kind: ComponentType,
     },
+    /// Action row of a modal doesn't contain exactly one component.
+    ModalActionRowComponentCount {
+        /// Number of components within the action row.
+        count: usize,
+    },
+    /// Component within an action row of a modal is not a text input.
+    ModalChildComponent {
+        /// Type of provided component.
+        kind: ComponentType,
+    },
+    /// Number of action rows of a modal is smaller than
+    /// [the minimum][`MODAL_COMPONENT_COUNT_MIN`] or larger than
+    /// [the maximum][`MODAL_COMPONENT_COUNT_MAX`].
+    ModalComponentCount {
+        /// Number of action rows that were provided.
+        count: usize,
+    },
+    /// Title of a modal is larger than [the maximum][`MODAL_TITLE_LENGTH`].
+    ModalTitleLength {
+        /// Number of codepoints that were provided.
+        chars: usize,
+    },
     /// Maximum number of items that can be chosen is smaller than
     /// [the minimum][`SELECT_MAXIMUM_VALUES_REQUIREMENT`] or larger than
     /// [the maximum][`SELECT_MAXIMUM_VALUES_LIMIT`].
@@ -733,6 +809,93 @@ pub fn text_input(text_input: &TextInput) -> Result<(), ComponentValidationError
     Ok(())
 }
 
+/// Ensure that the contents of a modal are correct.
+///
+/// A modal must have between [`MODAL_COMPONENT_COUNT_MIN`] and
+/// [`MODAL_COMPONENT_COUNT_MAX`] action rows, each containing exactly one
+/// text input.
+///
+/// # Errors
+///
+/// Returns an error of type [`ComponentCustomIdLength`] if the provided custom
+/// ID is too long.
+///
+/// Returns an error of type [`ModalTitleLength`] if the provided title is too
+/// long.
+///
+/// Returns an error of type [`ModalComponentCount`] if too few or too many
+/// action rows are provided.
+///
+/// Returns an error of type [`InvalidRootComponent`] if a provided component
+/// is not an [`ActionRow`].
+///
+/// Returns an error of type [`ModalActionRowComponentCount`] if an action row
+/// doesn't contain exactly one component.
+///
+/// Returns an error of type [`ModalChildComponent`] if a component within an
+/// action row is not a [`TextInput`].
+///
+/// Refer to [`text_input`] for potential errors when validating a text input.
+///
+/// [`ComponentCustomIdLength`]: ComponentValidationErrorType::ComponentCustomIdLength
+/// [`InvalidRootComponent`]: ComponentValidationErrorType::InvalidRootComponent
+/// [`ModalActionRowComponentCount`]: ComponentValidationErrorType::ModalActionRowComponentCount
+/// [`ModalChildComponent`]: ComponentValidationErrorType::ModalChildComponent
+/// [`ModalComponentCount`]: ComponentValidationErrorType::ModalComponentCount
+/// [`ModalTitleLength`]: ComponentValidationErrorType::ModalTitleLength
+pub fn modal(
+    custom_id: &str,
+    title: &str,
+    components: &[Component],
+) -> Result<(), ComponentValidationError> {
+    self::component_custom_id(custom_id)?;
+
+    let chars = title.chars().count();
+
+    if chars > MODAL_TITLE_LENGTH {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ModalTitleLength { chars },
+        });
+    }
+
+    let count = components.len();
+
+    if !(MODAL_COMPONENT_COUNT_MIN..=MODAL_COMPONENT_COUNT_MAX).contains(&count) {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ModalComponentCount { count },
+        });
+    }
+
+    for component in components {
+        let action_row = match component {
+            Component::ActionRow(action_row) => action_row,
+            other => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidRootComponent { kind: other.kind() },
+                });
+            }
+        };
+
+        match action_row.components.as_slice() {
+            [Component::TextInput(text_input)] => self::text_input(text_input)?,
+            [other] => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::ModalChildComponent { kind: other.kind() },
+                });
+            }
+            components => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::ModalActionRowComponentCount {
+                        count: components.len(),
+                    },
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Validate that an [`ActionRow`] does not contain too many components.
 ///
 /// [`ActionRow`]s may only have so many components within it, defined by
@@ -1078,8 +1241,8 @@ mod tests {
     use std::fmt::Debug;
     use twilight_model::{
         application::component::{
-            button::ButtonStyle, select_menu::SelectMenuOption, ActionRow, Button, Component,
-            SelectMenu,
+            button::ButtonStyle, select_menu::SelectMenuOption, text_input::TextInputStyle,
+            ActionRow, Button, Component, SelectMenu,
         },
         channel::ReactionType,
     };
@@ -1090,6 +1253,10 @@ mod tests {
     assert_fields!(ComponentValidationErrorType::ComponentLabelLength: chars);
     assert_fields!(ComponentValidationErrorType::InvalidChildComponent: kind);
     assert_fields!(ComponentValidationErrorType::InvalidRootComponent: kind);
+    assert_fields!(ComponentValidationErrorType::ModalActionRowComponentCount: count);
+    assert_fields!(ComponentValidationErrorType::ModalChildComponent: kind);
+    assert_fields!(ComponentValidationErrorType::ModalComponentCount: count);
+    assert_fields!(ComponentValidationErrorType::ModalTitleLength: chars);
     assert_fields!(ComponentValidationErrorType::SelectMaximumValuesCount: count);
     assert_fields!(ComponentValidationErrorType::SelectMinimumValuesCount: count);
     assert_fields!(ComponentValidationErrorType::SelectOptionDescriptionLength: chars);
@@ -1361,4 +1528,89 @@ mod tests {
 
         assert!(component_text_input_min(4001).is_err());
     }
+
+    #[test]
+    fn test_modal() {
+        let text_input = |custom_id: &str| {
+            Component::ActionRow(ActionRow {
+                components: Vec::from([Component::TextInput(TextInput {
+                    custom_id: custom_id.to_owned(),
+                    label: "Reason".to_owned(),
+                    max_length: None,
+                    min_length: None,
+                    placeholder: None,
+                    required: None,
+                    style: TextInputStyle::Short,
+                    value: None,
+                })]),
+            })
+        };
+        let rows = Vec::from([text_input("a"), text_input("b")]);
+
+        assert!(modal("modal", "Report", &rows).is_ok());
+        assert!(modal("modal", &"a".repeat(45), &rows).is_ok());
+
+        assert!(matches!(
+            modal(&"a".repeat(101), "Report", &rows).unwrap_err().kind(),
+            ComponentValidationErrorType::ComponentCustomIdLength { chars: 101 }
+        ));
+        assert!(matches!(
+            modal("modal", &"a".repeat(46), &rows).unwrap_err().kind(),
+            ComponentValidationErrorType::ModalTitleLength { chars: 46 }
+        ));
+        assert!(matches!(
+            modal("modal", "Report", &[]).unwrap_err().kind(),
+            ComponentValidationErrorType::ModalComponentCount { count: 0 }
+        ));
+        assert!(matches!(
+            modal("modal", "Report", &vec![text_input("a"); 6])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ModalComponentCount { count: 6 }
+        ));
+
+        let row = |components| Component::ActionRow(ActionRow { components });
+        let inputs = rows
+            .iter()
+            .flat_map(|row| match row {
+                Component::ActionRow(action_row) => action_row.components.clone(),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+
+        assert!(matches!(
+            modal("modal", "Report", &[row(inputs)]).unwrap_err().kind(),
+            ComponentValidationErrorType::ModalActionRowComponentCount { count: 2 }
+        ));
+        assert!(matches!(
+            modal("modal", "Report", &[row(Vec::new())])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ModalActionRowComponentCount { count: 0 }
+        ));
+
+        let button = Component::Button(Button {
+            custom_id: Some("button".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Click".to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+        });
+
+        assert!(matches!(
+            modal("modal", "Report", &[row(Vec::from([button.clone()]))])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ModalChildComponent {
+                kind: ComponentType::Button
+            }
+        ));
+        assert!(matches!(
+            modal("modal", "Report", &[button]).unwrap_err().kind(),
+            ComponentValidationErrorType::InvalidRootComponent {
+                kind: ComponentType::Button
+            }
+        ));
+    }
 }
@@ -3,6 +3,7 @@
 //! [`Message`]: twilight_model::channel::Message
 
 use crate::{
+    channel::name as channel_name,
     component::{ComponentValidationErrorType, COMPONENT_COUNT},
     embed::{chars as embed_chars, EmbedValidationErrorType, EMBED_TOTAL_LENGTH},
     request::ValidationError,
@@ -14,9 +15,16 @@ use std::{
 use twilight_model::{
     application::component::Component,
     channel::embed::Embed,
-    id::{marker::StickerMarker, Id},
+    http::attachment::Attachment,
+    id::{
+        marker::{ChannelMarker, StickerMarker},
+        Id,
+    },
 };
 
+/// Maximum length of an attachment's description.
+pub const ATTACHMENT_DESCRIPTION_LENGTH_MAX: usize = 1024;
+
 /// Maximum number of embeds that a message may have.
 pub const EMBED_COUNT_LIMIT: usize = 10;
 
@@ -84,6 +92,13 @@ impl MessageValidationError {
 impl Display for MessageValidationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            MessageValidationErrorType::AttachmentDescriptionTooLarge { chars } => {
+                f.write_str("the attachment's description is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&ATTACHMENT_DESCRIPTION_LENGTH_MAX, f)
+            }
             MessageValidationErrorType::AttachmentFilename { filename } => {
                 f.write_str("attachment filename `")?;
                 Display::fmt(filename, f)?;
@@ -117,6 +132,16 @@ impl Display for MessageValidationError {
             MessageValidationErrorType::TooManyEmbeds { .. } => {
                 f.write_str("message has too many embeds")
             }
+            MessageValidationErrorType::WebhookThreadName => {
+                if let Some(source) = self.source() {
+                    Display::fmt(&source, f)
+                } else {
+                    f.write_str("webhook thread name is invalid")
+                }
+            }
+            MessageValidationErrorType::WebhookThreadNameAndId => {
+                f.write_str("a thread name and a thread id were both provided")
+            }
             MessageValidationErrorType::WebhookUsername { .. } => {
                 if let Some(source) = self.source() {
                     Display::fmt(&source, f)
@@ -133,6 +158,11 @@ impl Error for MessageValidationError {}
 /// Type of [`MessageValidationError`] that occurred.
 #[derive(Debug)]
 pub enum MessageValidationErrorType {
+    /// Attachment description is too large.
+    AttachmentDescriptionTooLarge {
+        /// Provided number of codepoints.
+        chars: usize,
+    },
     /// Attachment filename is not valid.
     AttachmentFilename {
         /// Invalid filename.
@@ -168,10 +198,61 @@ pub enum MessageValidationErrorType {
     ///
     /// A followup message can have up to 10 embeds.
     TooManyEmbeds,
+    /// Provided name of the thread to create by executing a webhook was
+    /// invalid.
+    WebhookThreadName,
+    /// Both the ID of a thread to execute a webhook in and the name of a
+    /// thread to create were provided.
+    WebhookThreadNameAndId,
     /// Provided webhook username was invalid.
     WebhookUsername,
 }
 
+/// Ensure an attachment is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`AttachmentDescriptionTooLarge`] if the
+/// description is too large.
+///
+/// Returns an error of type [`AttachmentFilename`] if the filename is invalid.
+///
+/// [`AttachmentDescriptionTooLarge`]: MessageValidationErrorType::AttachmentDescriptionTooLarge
+/// [`AttachmentFilename`]: MessageValidationErrorType::AttachmentFilename
+pub fn attachment(attachment: &Attachment) -> Result<(), MessageValidationError> {
+    attachment_filename(&attachment.filename)?;
+
+    if let Some(description) = &attachment.description {
+        attachment_description(description)?;
+    }
+
+    Ok(())
+}
+
+/// Ensure an attachment's description is correct.
+///
+/// The length of the description must be at most
+/// [`ATTACHMENT_DESCRIPTION_LENGTH_MAX`].
+///
+/// # Errors
+///
+/// Returns an error of type [`AttachmentDescriptionTooLarge`] if the
+/// description is too large.
+///
+/// [`AttachmentDescriptionTooLarge`]: MessageValidationErrorType::AttachmentDescriptionTooLarge
+pub fn attachment_description(description: impl AsRef<str>) -> Result<(), MessageValidationError> {
+    let chars = description.as_ref().chars().count();
+
+    if chars <= ATTACHMENT_DESCRIPTION_LENGTH_MAX {
+        Ok(())
+    } else {
+        Err(MessageValidationError {
+            kind: MessageValidationErrorType::AttachmentDescriptionTooLarge { chars },
+            source: None,
+        })
+    }
+}
+
 /// Ensure an attachment's filename is correct.
 ///
 /// The filename can contain ASCII alphanumeric characters, dots, dashes, and
@@ -324,6 +405,46 @@ pub fn sticker_ids(sticker_ids: &[Id<StickerMarker>]) -> Result<(), MessageValid
     }
 }
 
+/// Ensure that the thread a webhook is executed in is correct.
+///
+/// A webhook may either be executed in an existing thread or create a new
+/// thread in a forum channel, but not both. The name of a new thread must be
+/// a valid [channel name].
+///
+/// # Errors
+///
+/// Returns an error of type [`WebhookThreadNameAndId`] if both a thread ID and
+/// thread name are provided.
+///
+/// Returns an error of type [`WebhookThreadName`] if the thread name is
+/// invalid.
+///
+/// [channel name]: crate::channel::name
+/// [`WebhookThreadName`]: MessageValidationErrorType::WebhookThreadName
+/// [`WebhookThreadNameAndId`]: MessageValidationErrorType::WebhookThreadNameAndId
+pub fn webhook_thread(
+    thread_id: Option<Id<ChannelMarker>>,
+    thread_name: Option<&str>,
+) -> Result<(), MessageValidationError> {
+    let thread_name = if let Some(thread_name) = thread_name {
+        thread_name
+    } else {
+        return Ok(());
+    };
+
+    if thread_id.is_some() {
+        return Err(MessageValidationError {
+            kind: MessageValidationErrorType::WebhookThreadNameAndId,
+            source: None,
+        });
+    }
+
+    channel_name(thread_name).map_err(|source| MessageValidationError {
+        kind: MessageValidationErrorType::WebhookThreadName,
+        source: Some(Box::new(source)),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,6 +459,27 @@ mod tests {
         assert!(attachment_filename("????????").is_err());
     }
 
+    #[test]
+    fn test_attachment_description() {
+        assert!(attachment_description("").is_ok());
+        assert!(attachment_description("a".repeat(1024)).is_ok());
+
+        assert!(attachment_description("a".repeat(1025)).is_err());
+    }
+
+    #[test]
+    fn test_attachment() {
+        let mut value = Attachment::from_bytes("image.png".to_owned(), Vec::new(), 1);
+        value.description("a".repeat(1024));
+        assert!(attachment(&value).is_ok());
+
+        value.description("a".repeat(1025));
+        assert!(matches!(
+            attachment(&value).unwrap_err().kind(),
+            MessageValidationErrorType::AttachmentDescriptionTooLarge { chars: 1025 }
+        ));
+    }
+
     #[test]
     fn test_content() {
         assert!(content("").is_ok());
@@ -345,4 +487,22 @@ mod tests {
 
         assert!(content("a".repeat(2001)).is_err());
     }
+
+    #[test]
+    fn test_webhook_thread() {
+        assert!(webhook_thread(None, None).is_ok());
+        assert!(webhook_thread(Some(Id::new(1)), None).is_ok());
+        assert!(webhook_thread(None, Some("thread")).is_ok());
+
+        assert!(matches!(
+            webhook_thread(None, Some("")).unwrap_err().kind(),
+            MessageValidationErrorType::WebhookThreadName
+        ));
+        assert!(matches!(
+            webhook_thread(Some(Id::new(1)), Some("thread"))
+                .unwrap_err()
+                .kind(),
+            MessageValidationErrorType::WebhookThreadNameAndId
+        ));
+    }
 }
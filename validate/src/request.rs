@@ -7,7 +7,11 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     time::{SystemTime, UNIX_EPOCH},
 };
-use twilight_model::util::Timestamp;
+use twilight_model::{
+    id::{marker::UserMarker, Id},
+    scheduled_event::{Frequency, RecurrenceRule},
+    util::Timestamp,
+};
 
 /// The maximum audit log reason length in UTF-16 codepoints.
 pub const AUDIT_REASON_MAX: usize = 512;
@@ -15,6 +19,15 @@ pub const AUDIT_REASON_MAX: usize = 512;
 /// Maximum amount of days for messages to be deleted upon ban.
 pub const CREATE_GUILD_BAN_DELETE_MESSAGE_DAYS_MAX: u16 = 7;
 
+/// Maximum amount of seconds for messages to be deleted upon ban.
+pub const CREATE_GUILD_BAN_DELETE_MESSAGE_SECONDS_MAX: u32 = 604_800;
+
+/// Maximum amount of users to ban at once.
+pub const CREATE_GUILD_BANS_USERS_MAX: usize = 200;
+
+/// Minimum amount of users to ban at once.
+pub const CREATE_GUILD_BANS_USERS_MIN: usize = 1;
+
 /// Maximum amount of time a member can be timed out for.
 pub const COMMUNICATION_DISABLED_MAX_DURATION: i64 = 28 * 24 * 60 * 60;
 
@@ -87,6 +100,14 @@ pub const SCHEDULED_EVENT_GET_USERS_MAX: u16 = 100;
 /// Minimum amount of scheduled event users to get.
 pub const SCHEDULED_EVENT_GET_USERS_MIN: u16 = 1;
 
+/// Maximum week of the month of a scheduled event recurrence rule's
+/// `by_n_weekday`.
+pub const SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MAX: u8 = 5;
+
+/// Minimum week of the month of a scheduled event recurrence rule's
+/// `by_n_weekday`.
+pub const SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MIN: u8 = 1;
+
 /// Maximum length of a scheduled event's name.
 pub const SCHEDULED_EVENT_NAME_MAX: usize = 100;
 
@@ -183,6 +204,22 @@ impl Display for ValidationError {
 
                 Display::fmt(&CREATE_GUILD_BAN_DELETE_MESSAGE_DAYS_MAX, f)
             }
+            ValidationErrorType::CreateGuildBanDeleteMessageSeconds { seconds } => {
+                f.write_str("provided create guild ban delete_message_seconds is ")?;
+                Display::fmt(seconds, f)?;
+                f.write_str(", but it must be at most ")?;
+
+                Display::fmt(&CREATE_GUILD_BAN_DELETE_MESSAGE_SECONDS_MAX, f)
+            }
+            ValidationErrorType::CreateGuildBansUsers { count } => {
+                f.write_str("provided create guild bans user count is ")?;
+                Display::fmt(count, f)?;
+                f.write_str(", but it must be at least ")?;
+                Display::fmt(&CREATE_GUILD_BANS_USERS_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&CREATE_GUILD_BANS_USERS_MAX, f)
+            }
             ValidationErrorType::CommunicationDisabledUntil { .. } => {
                 f.write_str("provided timestamp is too far in the future")
             }
@@ -279,6 +316,9 @@ impl Display for ValidationError {
 
                 Display::fmt(&NICKNAME_LIMIT_MAX, f)
             }
+            ValidationErrorType::RoleIcon => {
+                f.write_str("a role's icon and unicode emoji can't both be set")
+            }
             ValidationErrorType::ScheduledEventDescription { len } => {
                 f.write_str("provided scheduled event description is length is ")?;
                 Display::fmt(len, f)?;
@@ -306,6 +346,11 @@ impl Display for ValidationError {
 
                 Display::fmt(&SCHEDULED_EVENT_NAME_MAX, f)
             }
+            ValidationErrorType::ScheduledEventRecurrenceRule { reason } => {
+                f.write_str("provided scheduled event recurrence rule is invalid: ")?;
+
+                f.write_str(reason)
+            }
             ValidationErrorType::SearchGuildMembers { limit } => {
                 f.write_str("provided search guild members limit is ")?;
                 Display::fmt(limit, f)?;
@@ -383,6 +428,16 @@ pub enum ValidationErrorType {
         /// Invalid days.
         days: u16,
     },
+    /// Provided create guild ban delete message seconds was invalid.
+    CreateGuildBanDeleteMessageSeconds {
+        /// Invalid seconds.
+        seconds: u32,
+    },
+    /// Provided number of users to ban at once was invalid.
+    CreateGuildBansUsers {
+        /// Invalid number of users.
+        count: usize,
+    },
     /// Provided timestamp is too far in the future.
     CommunicationDisabledUntil {
         /// Invalid timestamp.
@@ -443,6 +498,8 @@ pub enum ValidationErrorType {
         /// Invalid length.
         len: usize,
     },
+    /// Role icon and unicode emoji were both set.
+    RoleIcon,
     /// Scheduled event description is invalid.
     ScheduledEventDescription {
         /// Invalid length.
@@ -458,6 +515,11 @@ pub enum ValidationErrorType {
         /// Invalid length.
         len: usize,
     },
+    /// Scheduled event recurrence rule is invalid.
+    ScheduledEventRecurrenceRule {
+        /// Constraint of the recurrence rule that was violated.
+        reason: &'static str,
+    },
     /// Provided search guild members limit was invalid.
     SearchGuildMembers {
         /// Invalid limit.
@@ -540,6 +602,54 @@ pub const fn create_guild_ban_delete_message_days(days: u16) -> Result<(), Valid
     }
 }
 
+/// Ensure that the delete message seconds amount for the Create Guild Ban and
+/// Bulk Guild Ban requests is correct.
+///
+/// The seconds must be at most [`CREATE_GUILD_BAN_DELETE_MESSAGE_SECONDS_MAX`].
+/// This is based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`CreateGuildBanDeleteMessageSeconds`] if the
+/// seconds is invalid.
+///
+/// [`CreateGuildBanDeleteMessageSeconds`]: ValidationErrorType::CreateGuildBanDeleteMessageSeconds
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild#bulk-guild-ban
+pub const fn create_guild_ban_delete_message_seconds(seconds: u32) -> Result<(), ValidationError> {
+    if seconds <= CREATE_GUILD_BAN_DELETE_MESSAGE_SECONDS_MAX {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::CreateGuildBanDeleteMessageSeconds { seconds },
+        })
+    }
+}
+
+/// Ensure that the users of the Bulk Guild Ban request are correct.
+///
+/// The number of users must be at least [`CREATE_GUILD_BANS_USERS_MIN`] and
+/// at most [`CREATE_GUILD_BANS_USERS_MAX`]. This is based on
+/// [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`CreateGuildBansUsers`] if the number of users
+/// is invalid.
+///
+/// [`CreateGuildBansUsers`]: ValidationErrorType::CreateGuildBansUsers
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild#bulk-guild-ban
+pub const fn create_guild_bans_users(user_ids: &[Id<UserMarker>]) -> Result<(), ValidationError> {
+    let count = user_ids.len();
+
+    if count >= CREATE_GUILD_BANS_USERS_MIN && count <= CREATE_GUILD_BANS_USERS_MAX {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::CreateGuildBansUsers { count },
+        })
+    }
+}
+
 /// Validate that a timeout time is not too far in the future.
 ///
 /// The time must not be farther than 28 days in the future.
@@ -805,6 +915,31 @@ pub fn nickname(nickname: impl AsRef<str>) -> Result<(), ValidationError> {
     }
 }
 
+/// Ensure that at most one of a role's icon and unicode emoji is set.
+///
+/// A role may either have a custom icon image or a unicode emoji as its icon,
+/// but not both. This is based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`RoleIcon`] if both the icon and the unicode
+/// emoji are set.
+///
+/// [`RoleIcon`]: ValidationErrorType::RoleIcon
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild#modify-guild-role-json-params
+pub const fn role_icon(
+    icon: Option<&str>,
+    unicode_emoji: Option<&str>,
+) -> Result<(), ValidationError> {
+    if icon.is_some() && unicode_emoji.is_some() {
+        Err(ValidationError {
+            kind: ValidationErrorType::RoleIcon,
+        })
+    } else {
+        Ok(())
+    }
+}
+
 /// Ensure that a scheduled event's description is correct.
 ///
 /// The length must be at least [`SCHEDULED_EVENT_DESCRIPTION_MIN`] and at most
@@ -875,6 +1010,91 @@ pub fn scheduled_event_name(name: impl AsRef<str>) -> Result<(), ValidationError
     }
 }
 
+/// Ensure that a scheduled event's recurrence rule is correct.
+///
+/// Discord only supports a subset of recurrence rules. This is based on
+/// [this documentation entry]:
+///
+/// - `by_year_day`, `count`, and `end` can't be set;
+/// - only one of `by_weekday`, `by_n_weekday`, or the pair of `by_month` and
+///   `by_month_day` can be set;
+/// - the interval must be 1, except for [`Frequency::Weekly`] rules which
+///   may also have an interval of 2;
+/// - `by_weekday` is only valid for [`Frequency::Daily`] rules, or for
+///   [`Frequency::Weekly`] rules with a single weekday;
+/// - [`Frequency::Monthly`] rules must have a single `by_n_weekday` with a
+///   week of at least [`SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MIN`] and at
+///   most [`SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MAX`];
+/// - [`Frequency::Yearly`] rules must have a single `by_month` and a single
+///   `by_month_day`.
+///
+/// # Errors
+///
+/// Returns an error of type [`ScheduledEventRecurrenceRule`] if the rule is
+/// invalid.
+///
+/// [`ScheduledEventRecurrenceRule`]: ValidationErrorType::ScheduledEventRecurrenceRule
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-structure
+pub fn scheduled_event_recurrence_rule(rule: &RecurrenceRule) -> Result<(), ValidationError> {
+    let error = |reason| {
+        Err(ValidationError {
+            kind: ValidationErrorType::ScheduledEventRecurrenceRule { reason },
+        })
+    };
+
+    if rule.by_year_day.is_some() || rule.count.is_some() || rule.end.is_some() {
+        return error("by_year_day, count, and end can't be set");
+    }
+
+    let by_month = rule.by_month.is_some() || rule.by_month_day.is_some();
+    let sets = [
+        rule.by_weekday.is_some(),
+        rule.by_n_weekday.is_some(),
+        by_month,
+    ];
+
+    if sets.into_iter().filter(|set| *set).count() > 1 {
+        return error(
+            "only one of by_weekday, by_n_weekday, or by_month and by_month_day can be set",
+        );
+    }
+
+    if rule.interval != 1 && !(rule.frequency == Frequency::Weekly && rule.interval == 2) {
+        return error("interval must be 1, or 2 for weekly rules");
+    }
+
+    match rule.frequency {
+        Frequency::Daily if rule.by_n_weekday.is_some() || by_month => {
+            error("daily rules can only set by_weekday")
+        }
+        Frequency::Weekly if !matches!(rule.by_weekday.as_deref(), None | Some([_])) => {
+            error("weekly rules must have a single by_weekday")
+        }
+        Frequency::Weekly if rule.by_n_weekday.is_some() || by_month => {
+            error("weekly rules can only set by_weekday")
+        }
+        Frequency::Monthly => match rule.by_n_weekday.as_deref() {
+            Some([n_weekday])
+                if (SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MIN
+                    ..=SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MAX)
+                    .contains(&n_weekday.n) =>
+            {
+                Ok(())
+            }
+            _ => error("monthly rules must have a single by_n_weekday in weeks 1 to 5"),
+        },
+        Frequency::Yearly
+            if !matches!(
+                (rule.by_month.as_deref(), rule.by_month_day.as_deref()),
+                (Some([_]), Some([_]))
+            ) =>
+        {
+            error("yearly rules must have a single by_month and by_month_day")
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Ensure that the limit for the Search Guild Members endpoint is correct.
 ///
 /// The limit must be at least [`SEARCH_GUILD_MEMBERS_LIMIT_MIN`] and at most
@@ -1044,6 +1264,7 @@ pub fn webhook_username(value: impl AsRef<str>) -> Result<(), ValidationError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use twilight_model::scheduled_event::{Month, NWeekday, Weekday};
 
     #[test]
     fn test_username_variants() {
@@ -1100,6 +1321,25 @@ mod tests {
         assert!(create_guild_ban_delete_message_days(8).is_err());
     }
 
+    #[test]
+    fn test_create_guild_ban_delete_message_seconds() {
+        assert!(create_guild_ban_delete_message_seconds(0).is_ok());
+        assert!(create_guild_ban_delete_message_seconds(604_800).is_ok());
+
+        assert!(create_guild_ban_delete_message_seconds(604_801).is_err());
+    }
+
+    #[test]
+    fn test_create_guild_bans_users() {
+        let user_ids = (1..=201).map(Id::new).collect::<Vec<_>>();
+
+        assert!(create_guild_bans_users(&user_ids[..1]).is_ok());
+        assert!(create_guild_bans_users(&user_ids[..200]).is_ok());
+
+        assert!(create_guild_bans_users(&[]).is_err());
+        assert!(create_guild_bans_users(&user_ids).is_err());
+    }
+
     #[test]
     fn test_communication_disabled_until() {
         #[allow(clippy::cast_possible_wrap)]
@@ -1216,6 +1456,18 @@ mod tests {
         assert!(nickname("a".repeat(33)).is_err());
     }
 
+    #[test]
+    fn test_role_icon() {
+        assert!(role_icon(None, None).is_ok());
+        assert!(role_icon(Some("data:image/png;base64,aGk="), None).is_ok());
+        assert!(role_icon(None, Some("🐈")).is_ok());
+
+        assert!(matches!(
+            role_icon(Some("data:image/png;base64,aGk="), Some("🐈")),
+            Err(error) if matches!(error.kind(), ValidationErrorType::RoleIcon)
+        ));
+    }
+
     #[test]
     fn test_scheduled_event_description() {
         assert!(scheduled_event_description("a").is_ok());
@@ -1234,6 +1486,97 @@ mod tests {
         assert!(scheduled_event_name("a".repeat(101)).is_err());
     }
 
+    #[test]
+    fn test_scheduled_event_recurrence_rule() {
+        let weekly = RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: None,
+            by_weekday: Some(Vec::from([Weekday::Tuesday])),
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: Frequency::Weekly,
+            interval: 1,
+            start: Timestamp::from_secs(1_722_967_200).unwrap(),
+        };
+        let n_weekday = |n| {
+            Some(Vec::from([NWeekday {
+                day: Weekday::Tuesday,
+                n,
+            }]))
+        };
+
+        assert!(scheduled_event_recurrence_rule(&weekly).is_ok());
+        assert!(scheduled_event_recurrence_rule(&RecurrenceRule {
+            interval: 2,
+            ..weekly.clone()
+        })
+        .is_ok());
+        assert!(scheduled_event_recurrence_rule(&RecurrenceRule {
+            by_weekday: Some(Vec::from([
+                Weekday::Monday,
+                Weekday::Tuesday,
+                Weekday::Wednesday,
+                Weekday::Thursday,
+                Weekday::Friday,
+            ])),
+            frequency: Frequency::Daily,
+            ..weekly.clone()
+        })
+        .is_ok());
+        assert!(scheduled_event_recurrence_rule(&RecurrenceRule {
+            by_n_weekday: n_weekday(2),
+            by_weekday: None,
+            frequency: Frequency::Monthly,
+            ..weekly.clone()
+        })
+        .is_ok());
+        assert!(scheduled_event_recurrence_rule(&RecurrenceRule {
+            by_month: Some(Vec::from([Month::August])),
+            by_month_day: Some(Vec::from([6])),
+            by_weekday: None,
+            frequency: Frequency::Yearly,
+            ..weekly.clone()
+        })
+        .is_ok());
+
+        assert!(scheduled_event_recurrence_rule(&RecurrenceRule {
+            interval: 3,
+            ..weekly.clone()
+        })
+        .is_err());
+        assert!(scheduled_event_recurrence_rule(&RecurrenceRule {
+            count: Some(5),
+            ..weekly.clone()
+        })
+        .is_err());
+        assert!(scheduled_event_recurrence_rule(&RecurrenceRule {
+            by_weekday: Some(Vec::from([Weekday::Monday, Weekday::Tuesday])),
+            ..weekly.clone()
+        })
+        .is_err());
+        assert!(scheduled_event_recurrence_rule(&RecurrenceRule {
+            by_n_weekday: n_weekday(2),
+            ..weekly.clone()
+        })
+        .is_err());
+        assert!(scheduled_event_recurrence_rule(&RecurrenceRule {
+            by_n_weekday: n_weekday(6),
+            by_weekday: None,
+            frequency: Frequency::Monthly,
+            ..weekly.clone()
+        })
+        .is_err());
+        assert!(scheduled_event_recurrence_rule(&RecurrenceRule {
+            by_month: Some(Vec::from([Month::August])),
+            by_weekday: None,
+            frequency: Frequency::Yearly,
+            ..weekly
+        })
+        .is_err());
+    }
+
     #[test]
     fn test_search_guild_members_limit() {
         assert!(search_guild_members_limit(1).is_ok());